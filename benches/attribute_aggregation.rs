@@ -0,0 +1,111 @@
+//! Benchmarks for attribute modifier aggregation.
+//!
+//! Every owner has four attributes and one infinite effect with three
+//! modifiers. `all_dirty` changes every base value before each frame, so every
+//! attribute is re-aggregated (in parallel); `at_rest` changes nothing, so
+//! aggregation has no work to do.
+//!
+//! Run with `cargo bench --bench attribute_aggregation`.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{GasPlugin, attributes::*, effects::*};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+
+struct BenchAttributes;
+
+impl AttributeSetDefinition for BenchAttributes {
+    fn attribute_names() -> &'static [&'static str] {
+        &["Health", "Mana", "AttackPower", "Armor"]
+    }
+
+    fn attribute_metadata(name: &str) -> Option<AttributeMetadata> {
+        Some(AttributeMetadata::new(name))
+    }
+
+    fn default_value(_name: &str) -> f32 {
+        100.0
+    }
+}
+
+fn setup(owners: usize) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("bench_buff")
+                .with_duration_policy(DurationPolicy::Infinite)
+                .add_modifier(ModifierInfo::new(
+                    "Health",
+                    ModifierOperation::AddCurrent,
+                    MagnitudeCalculation::scalar(10.0),
+                ))
+                .add_modifier(ModifierInfo::new(
+                    "AttackPower",
+                    ModifierOperation::MultiplyAdditive,
+                    MagnitudeCalculation::scalar(0.5),
+                ))
+                .add_modifier(
+                    ModifierInfo::new(
+                        "Armor",
+                        ModifierOperation::MultiplyMultiplicative,
+                        MagnitudeCalculation::scalar(0.2),
+                    )
+                    .with_channel(EvaluationChannel::Channel1),
+                ),
+        );
+
+    for _ in 0..owners {
+        let owner = {
+            let mut commands = app.world_mut().commands();
+            let owner = commands.spawn_empty().id();
+            BenchAttributes::create_attributes(&mut commands, owner);
+            owner
+        };
+        app.world_mut().flush();
+        app.world_mut()
+            .trigger(ApplyGameplayEffectEvent::new("bench_buff", owner));
+    }
+    app.update();
+    app.update();
+    app
+}
+
+fn bench_attribute_aggregation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("attribute_aggregation");
+    for owners in [1_000, 5_000, 10_000] {
+        group.bench_with_input(
+            BenchmarkId::new("all_dirty", owners),
+            &owners,
+            |b, &owners| {
+                let mut app = setup(owners);
+                let mut attributes = app.world_mut().query::<&mut AttributeData>();
+                b.iter(|| {
+                    for mut data in attributes.iter_mut(app.world_mut()) {
+                        data.base_value += 1.0;
+                    }
+                    app.update();
+                });
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("at_rest", owners),
+            &owners,
+            |b, &owners| {
+                let mut app = setup(owners);
+                b.iter(|| app.update());
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_attribute_aggregation);
+criterion_main!(benches);
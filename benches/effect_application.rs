@@ -0,0 +1,107 @@
+//! Benchmarks for applying one effect to many targets.
+//!
+//! Every frame the caster's aura ticks an instant effect on every target,
+//! scaled by the caster's spell power. `triggered` triggers one
+//! `ApplyGameplayEffectEvent` per target; `batched` pushes them to
+//! `BatchedEvents`, where they form one group sharing the definition lookup
+//! and the magnitude evaluation.
+//!
+//! Run with `cargo bench --bench effect_application`.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{GasPlugin, attributes::*, core::BatchedEvents, effects::*};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+
+fn spawn_attribute(app: &mut App, owner: Entity, name: &str, value: f32) {
+    app.world_mut().spawn((
+        AttributeName::new(name),
+        AttributeData::new(value),
+        ChildOf(owner),
+    ));
+}
+
+fn setup(targets: usize) -> (App, Entity, Vec<Entity>) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("bench_aura_tick")
+                .add_modifier(ModifierInfo::new(
+                    "Health",
+                    ModifierOperation::AddBase,
+                    MagnitudeCalculation::from_source_attribute("SpellPower", -0.1),
+                ))
+                .add_modifier(ModifierInfo::new(
+                    "Mana",
+                    ModifierOperation::AddBase,
+                    MagnitudeCalculation::scaled(-1.0, 1.1),
+                )),
+        );
+
+    let caster = app.world_mut().spawn_empty().id();
+    spawn_attribute(&mut app, caster, "SpellPower", 50.0);
+    let targets = (0..targets)
+        .map(|_| {
+            let target = app.world_mut().spawn_empty().id();
+            spawn_attribute(&mut app, target, "Health", 1_000_000.0);
+            spawn_attribute(&mut app, target, "Mana", 1_000_000.0);
+            target
+        })
+        .collect();
+    app.update();
+    (app, caster, targets)
+}
+
+fn aura_tick(caster: Entity, target: Entity) -> ApplyGameplayEffectEvent {
+    ApplyGameplayEffectEvent::new("bench_aura_tick", target)
+        .with_level(3)
+        .with_source(caster)
+        .with_instigator(caster)
+}
+
+fn bench_effect_application(c: &mut Criterion) {
+    let mut group = c.benchmark_group("effect_application");
+    for targets in [100, 1_000] {
+        group.bench_with_input(
+            BenchmarkId::new("triggered", targets),
+            &targets,
+            |b, &targets| {
+                let (mut app, caster, targets) = setup(targets);
+                b.iter(|| {
+                    for &target in &targets {
+                        app.world_mut().trigger(aura_tick(caster, target));
+                    }
+                    app.update();
+                });
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("batched", targets),
+            &targets,
+            |b, &targets| {
+                let (mut app, caster, targets) = setup(targets);
+                b.iter(|| {
+                    let mut batch = app
+                        .world_mut()
+                        .resource_mut::<BatchedEvents<ApplyGameplayEffectEvent>>();
+                    for &target in &targets {
+                        batch.push(aura_tick(caster, target));
+                    }
+                    app.update();
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_effect_application);
+criterion_main!(benches);
@@ -9,6 +9,7 @@
 //! - Stacking effects
 //! - Periodic effects (DoT/HoT)
 //! - Cooldowns and costs
+//! - Combat log events
 
 use bevy::ecs::relationship::Relationship;
 use bevy::prelude::*;
@@ -27,6 +28,8 @@ fn main() {
         .add_systems(Startup, register_effects)
         .add_systems(Startup, register_abilities)
         .add_systems(Update, (simulate_combat, check_results))
+        .add_observer(log_damage)
+        .add_observer(log_healing)
         .run();
 }
 
@@ -436,6 +439,39 @@ fn get_attribute_value(
     0.0
 }
 
+fn log_damage(ev: On<DamageDealtEvent>, names: Query<&Name>) {
+    let name = |entity: Option<Entity>| {
+        entity
+            .and_then(|entity| names.get(entity).ok())
+            .map_or("Unknown", Name::as_str)
+    };
+    info!(
+        "[Combat] {} hits {} with {}: {:.1} damage ({:.1} mitigated){}",
+        name(ev.source),
+        name(Some(ev.target)),
+        ev.effect_id,
+        ev.post_mitigation,
+        ev.mitigated(),
+        if ev.critical { " CRIT" } else { "" }
+    );
+}
+
+fn log_healing(ev: On<HealingDoneEvent>, names: Query<&Name>) {
+    let name = |entity: Option<Entity>| {
+        entity
+            .and_then(|entity| names.get(entity).ok())
+            .map_or("Unknown", Name::as_str)
+    };
+    info!(
+        "[Combat] {} heals {} with {}: {:.1} {}",
+        name(ev.source),
+        name(Some(ev.target)),
+        ev.effect_id,
+        ev.amount,
+        ev.attribute
+    );
+}
+
 fn check_results(
     time: Res<Time>,
     attributes: Query<(&AttributeData, &AttributeName, &ChildOf)>,
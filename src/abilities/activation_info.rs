@@ -5,7 +5,9 @@
 //! and FGameplayAbilityActorInfo.
 
 use bevy::prelude::*;
+use std::sync::Arc;
 
+use super::prediction::PredictionKey;
 use super::target_data::GameplayAbilityTargetData;
 
 /// Unified activation information passed through the ability activation flow.
@@ -31,6 +33,9 @@ pub struct AbilityActivationInfo {
 
     /// Optional event payload that triggered this ability (for event-driven abilities).
     pub event_payload: Option<GameplayEventData>,
+
+    /// Key of the prediction this activation runs under, if predicted.
+    pub prediction_key: Option<PredictionKey>,
 }
 
 impl AbilityActivationInfo {
@@ -42,6 +47,7 @@ impl AbilityActivationInfo {
             target_data,
             level: 1,
             event_payload: None,
+            prediction_key: None,
         }
     }
 
@@ -63,6 +69,12 @@ impl AbilityActivationInfo {
         self
     }
 
+    /// Set the prediction key.
+    pub fn with_prediction_key(mut self, key: PredictionKey) -> Self {
+        self.prediction_key = Some(key);
+        self
+    }
+
     /// Get the primary target entity, if any.
     pub fn primary_target(&self) -> Option<Entity> {
         self.target_data.primary_target()
@@ -90,6 +102,12 @@ pub struct GameplayEventData {
 
     /// Optional tag associated with this event.
     pub event_tag: Option<String>,
+
+    /// Optional targeting data.
+    pub target_data: Option<GameplayAbilityTargetData>,
+
+    /// Optional game-specific data, shared between the receivers.
+    pub data: Option<Arc<dyn Reflect>>,
 }
 
 impl GameplayEventData {
@@ -100,6 +118,8 @@ impl GameplayEventData {
             target: None,
             magnitude: None,
             event_tag: None,
+            target_data: None,
+            data: None,
         }
     }
 
@@ -120,4 +140,21 @@ impl GameplayEventData {
         self.event_tag = Some(tag);
         self
     }
+
+    /// Set the target data.
+    pub fn with_target_data(mut self, target_data: GameplayAbilityTargetData) -> Self {
+        self.target_data = Some(target_data);
+        self
+    }
+
+    /// Set the game-specific data.
+    pub fn with_data(mut self, data: impl Reflect) -> Self {
+        self.data = Some(Arc::new(data));
+        self
+    }
+
+    /// Returns the game-specific data if it is a `T`.
+    pub fn data_as<T: Reflect>(&self) -> Option<&T> {
+        self.data.as_deref()?.downcast_ref::<T>()
+    }
 }
@@ -0,0 +1,402 @@
+//! AI scorers and actions driven by GAS state.
+//!
+//! Only compiled with the `ai` feature. The scorers and actions are plain
+//! components with no dependency on an AI crate: the game's own scorer and
+//! action systems forward the actor, copy the score and keep the action's
+//! [`GasActionState`]:
+//!
+//! | Scorer | Score |
+//! |---|---|
+//! | [`AttributePercentScorer`] | `Health / MaxHealth`, clamped to `0..=1` (optionally inverted) |
+//! | [`HasTagScorer`] | `score` while the actor has the tag, else `0` |
+//! | [`EffectActiveScorer`] | `score` while an effect with the id targets the actor, else `0` |
+//!
+//! | Action | Succeeds when |
+//! |---|---|
+//! | [`ActivateAbilityAction`] | the ability activated and has ended; fails if activation is rejected |
+//! | [`WaitForCooldownAction`] | the ability's cooldown effect is gone |
+//!
+//! `big_brain` is not supported: its latest release (0.22) targets Bevy 0.15,
+//! and this crate ships no adapter for it.
+//!
+//! Add [`GasAiPlugin`] so [`ActivateAbilityAction`] learns whether its
+//! activation was accepted.
+//!
+//! # Example
+//!
+//! ```ignore
+//! #[derive(Component)]
+//! struct Brain {
+//!     actor: Entity,
+//!     flee: AttributePercentScorer,
+//!     cast: ActivateAbilityAction,
+//!     state: GasActionState,
+//! }
+//!
+//! fn think(ai: GasAi, mut commands: Commands, mut brains: Query<&mut Brain>) {
+//!     for mut brain in &mut brains {
+//!         let brain = &mut *brain;
+//!         if brain.flee.score(&ai, brain.actor) > 0.8 {
+//!             continue;
+//!         }
+//!         brain.state = brain.cast.step(&ai, &mut commands, brain.actor, brain.state);
+//!     }
+//! }
+//! ```
+
+use crate::abilities::components::AbilityActiveState;
+use crate::abilities::definition::AbilityRegistry;
+use crate::abilities::systems::{
+    AbilityActivatedEvent, AbilityActivationFailedEvent, CancelAbilityEvent,
+    TryActivateAbilityEvent,
+};
+use crate::effects::components::EffectDuration;
+use crate::utils::Gas;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use string_cache::DefaultAtom as Atom;
+
+/// Plugin reporting activation results to [`ActivateAbilityAction`]s.
+pub struct GasAiPlugin;
+
+impl Plugin for GasAiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_ability_activated)
+            .add_observer(on_ability_activation_failed);
+    }
+}
+
+/// Read access to the GAS state scorers and actions look at.
+#[derive(SystemParam)]
+pub struct GasAi<'w, 's> {
+    pub gas: Gas<'w, 's>,
+    pub abilities: Res<'w, AbilityRegistry>,
+    pub durations: Query<'w, 's, &'static EffectDuration>,
+    pub active_states: Query<'w, 's, &'static AbilityActiveState>,
+}
+
+impl GasAi<'_, '_> {
+    /// Returns the spec of the ability `ability_id` granted to `actor`.
+    pub fn ability_spec(&self, actor: Entity, ability_id: &Atom) -> Option<Entity> {
+        self.gas
+            .abilities(actor)
+            .find(|(_, spec)| &spec.definition_id == ability_id)
+            .map(|(entity, _)| entity)
+    }
+
+    /// Returns the cooldown time left for `ability_id` on `actor`, or `None`
+    /// when it is ready. Cooldown effects without a duration report infinity.
+    pub fn cooldown_remaining(&self, actor: Entity, ability_id: &Atom) -> Option<f32> {
+        let cooldown_effect = self.abilities.get(ability_id)?.cooldown_effect.as_ref()?;
+        self.effect_remaining(actor, cooldown_effect)
+    }
+
+    /// Returns the time left on the shared cooldown of `category` on
+    /// `actor`, or `None` when the category is ready.
+    pub fn cooldown_category_remaining(&self, actor: Entity, category: &Atom) -> Option<f32> {
+        let cooldown_effect = self.abilities.cooldown_category(category)?;
+        self.effect_remaining(actor, cooldown_effect)
+    }
+
+    /// Returns the longest time left among the `effect_id` effects on
+    /// `actor`.
+    fn effect_remaining(&self, actor: Entity, effect_id: &Atom) -> Option<f32> {
+        self.gas
+            .active_effects(actor)
+            .filter(|(_, effect)| &effect.definition_id == effect_id)
+            .map(|(effect, _)| {
+                self.durations
+                    .get(effect)
+                    .map_or(f32::INFINITY, |duration| duration.remaining)
+            })
+            .reduce(f32::max)
+    }
+
+    /// Returns whether `ability_id` is cooling down on `actor`.
+    pub fn is_on_cooldown(&self, actor: Entity, ability_id: &Atom) -> bool {
+        self.cooldown_remaining(actor, ability_id).is_some()
+    }
+
+    /// Returns whether an instance of the ability spec `spec` is running.
+    pub fn is_ability_active(&self, spec: Entity) -> bool {
+        self.active_states
+            .get(spec)
+            .is_ok_and(|state| state.is_active)
+    }
+}
+
+/// A consideration scored from an actor's GAS state.
+pub trait GasScorer {
+    /// Scores `actor`, from `0.0` to `1.0`.
+    fn score(&self, ai: &GasAi, actor: Entity) -> f32;
+}
+
+/// Scores how full an attribute is relative to its maximum.
+#[derive(Component, Debug, Clone)]
+pub struct AttributePercentScorer {
+    /// The attribute to score, e.g. `Health`.
+    pub attribute: Atom,
+    /// The attribute holding the maximum, e.g. `MaxHealth`. Without one the
+    /// attribute's base value is the maximum.
+    pub max_attribute: Option<Atom>,
+    /// Scores `1 - percent`, so an emptier attribute scores higher.
+    pub invert: bool,
+}
+
+impl AttributePercentScorer {
+    /// Scores `attribute` against its base value.
+    pub fn new(attribute: impl Into<Atom>) -> Self {
+        Self {
+            attribute: attribute.into(),
+            max_attribute: None,
+            invert: false,
+        }
+    }
+
+    /// Scores against the current value of `max_attribute`.
+    pub fn with_max(mut self, max_attribute: impl Into<Atom>) -> Self {
+        self.max_attribute = Some(max_attribute.into());
+        self
+    }
+
+    /// Scores `1 - percent`.
+    pub fn inverted(mut self) -> Self {
+        self.invert = true;
+        self
+    }
+}
+
+impl GasScorer for AttributePercentScorer {
+    fn score(&self, ai: &GasAi, actor: Entity) -> f32 {
+        let Some(data) = ai.gas.attribute(actor, &self.attribute) else {
+            return 0.0;
+        };
+        let max = match &self.max_attribute {
+            Some(max_attribute) => ai.gas.attribute_value(actor, max_attribute),
+            None => Some(data.base_value),
+        };
+        let percent = match max {
+            Some(max) if max > 0.0 => (data.current_value / max).clamp(0.0, 1.0),
+            _ => 0.0,
+        };
+        if self.invert { 1.0 - percent } else { percent }
+    }
+}
+
+/// Scores whether the actor has a gameplay tag (or one of its children).
+#[derive(Component, Debug, Clone)]
+pub struct HasTagScorer {
+    /// The tag to look for.
+    pub tag: GameplayTag,
+    /// The score while the actor has the tag.
+    pub score: f32,
+}
+
+impl HasTagScorer {
+    /// Scores `1.0` while the actor has `tag`.
+    pub fn new(tag: GameplayTag) -> Self {
+        Self { tag, score: 1.0 }
+    }
+
+    /// Scores `score` instead of `1.0`.
+    pub fn with_score(mut self, score: f32) -> Self {
+        self.score = score;
+        self
+    }
+}
+
+impl GasScorer for HasTagScorer {
+    fn score(&self, ai: &GasAi, actor: Entity) -> f32 {
+        if ai.gas.has_tag(actor, &self.tag) {
+            self.score
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Scores whether an effect is active on the actor.
+#[derive(Component, Debug, Clone)]
+pub struct EffectActiveScorer {
+    /// The effect definition to look for.
+    pub effect_id: Atom,
+    /// The score while the effect is active.
+    pub score: f32,
+}
+
+impl EffectActiveScorer {
+    /// Scores `1.0` while `effect_id` is active.
+    pub fn new(effect_id: impl Into<Atom>) -> Self {
+        Self {
+            effect_id: effect_id.into(),
+            score: 1.0,
+        }
+    }
+
+    /// Scores `score` instead of `1.0`.
+    pub fn with_score(mut self, score: f32) -> Self {
+        self.score = score;
+        self
+    }
+}
+
+impl GasScorer for EffectActiveScorer {
+    fn score(&self, ai: &GasAi, actor: Entity) -> f32 {
+        if ai
+            .gas
+            .active_effects(actor)
+            .any(|(_, effect)| effect.definition_id == self.effect_id)
+        {
+            self.score
+        } else {
+            0.0
+        }
+    }
+}
+
+/// State of an AI action, from being picked to finishing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GasActionState {
+    /// Not picked yet.
+    #[default]
+    Init,
+    /// Picked; the action should start.
+    Requested,
+    /// Running.
+    Executing,
+    /// The AI switched away; the action should stop.
+    Cancelled,
+    /// Finished successfully.
+    Success,
+    /// Finished unsuccessfully.
+    Failure,
+}
+
+/// Where an [`ActivateAbilityAction`]'s request stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ActivationRequest {
+    Pending(Entity),
+    Activated(Entity),
+    Failed,
+}
+
+/// Activates an ability by id and runs until it ends.
+///
+/// Stays [`GasActionState::Executing`] until the activation is answered by an
+/// `AbilityActivatedEvent` or `AbilityActivationFailedEvent`.
+#[derive(Component, Debug, Clone)]
+pub struct ActivateAbilityAction {
+    /// The ability definition to activate.
+    pub ability_id: Atom,
+    request: Option<ActivationRequest>,
+}
+
+impl ActivateAbilityAction {
+    /// Activates `ability_id`.
+    pub fn new(ability_id: impl Into<Atom>) -> Self {
+        Self {
+            ability_id: ability_id.into(),
+            request: None,
+        }
+    }
+
+    /// Advances the action for `actor` and returns its next state.
+    pub fn step(
+        &mut self,
+        ai: &GasAi,
+        commands: &mut Commands,
+        actor: Entity,
+        state: GasActionState,
+    ) -> GasActionState {
+        match state {
+            GasActionState::Requested => {
+                self.request = None;
+                let Some(spec) = ai.ability_spec(actor, &self.ability_id) else {
+                    return GasActionState::Failure;
+                };
+                if ai.is_on_cooldown(actor, &self.ability_id) {
+                    return GasActionState::Failure;
+                }
+                self.request = Some(ActivationRequest::Pending(spec));
+                commands.trigger(TryActivateAbilityEvent::new(spec, actor));
+                GasActionState::Executing
+            }
+            GasActionState::Executing => match self.request {
+                Some(ActivationRequest::Pending(_)) => GasActionState::Executing,
+                Some(ActivationRequest::Activated(spec)) if ai.is_ability_active(spec) => {
+                    GasActionState::Executing
+                }
+                Some(ActivationRequest::Activated(_)) => GasActionState::Success,
+                Some(ActivationRequest::Failed) | None => GasActionState::Failure,
+            },
+            GasActionState::Cancelled => {
+                if let Some(ActivationRequest::Activated(spec)) = self.request
+                    && ai.is_ability_active(spec)
+                {
+                    commands.trigger(CancelAbilityEvent {
+                        instance: None,
+                        ability_spec: spec,
+                        owner: actor,
+                    });
+                }
+                self.request = None;
+                GasActionState::Failure
+            }
+            other => other,
+        }
+    }
+}
+
+/// Waits until an ability's cooldown has expired.
+#[derive(Component, Debug, Clone)]
+pub struct WaitForCooldownAction {
+    /// The ability whose cooldown to wait for.
+    pub ability_id: Atom,
+}
+
+impl WaitForCooldownAction {
+    /// Waits for `ability_id`.
+    pub fn new(ability_id: impl Into<Atom>) -> Self {
+        Self {
+            ability_id: ability_id.into(),
+        }
+    }
+
+    /// Advances the action for `actor` and returns its next state.
+    pub fn step(&self, ai: &GasAi, actor: Entity, state: GasActionState) -> GasActionState {
+        match state {
+            GasActionState::Requested | GasActionState::Executing => {
+                if ai.is_on_cooldown(actor, &self.ability_id) {
+                    GasActionState::Executing
+                } else {
+                    GasActionState::Success
+                }
+            }
+            GasActionState::Cancelled => GasActionState::Failure,
+            other => other,
+        }
+    }
+}
+
+fn on_ability_activated(
+    ev: On<AbilityActivatedEvent>,
+    mut actions: Query<&mut ActivateAbilityAction>,
+) {
+    for mut action in &mut actions {
+        if action.request == Some(ActivationRequest::Pending(ev.ability_spec)) {
+            action.request = Some(ActivationRequest::Activated(ev.ability_spec));
+        }
+    }
+}
+
+fn on_ability_activation_failed(
+    ev: On<AbilityActivationFailedEvent>,
+    mut actions: Query<&mut ActivateAbilityAction>,
+) {
+    for mut action in &mut actions {
+        if action.request == Some(ActivationRequest::Pending(ev.ability_spec)) {
+            action.request = Some(ActivationRequest::Failed);
+        }
+    }
+}
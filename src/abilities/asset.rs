@@ -0,0 +1,413 @@
+//! Loading ability definitions from asset files.
+//!
+//! With the `ability_assets` feature, `.ability.ron` files hold one
+//! [`AbilityDefinition`] or a list of them, in the format produced by the
+//! `serde` feature. Tags in a file are resolved through the
+//! [`GameplayTagsManager`] when it loads; a definition naming an unknown tag
+//! is not registered.
+//!
+//! Loaded definitions wait in [`PendingAbilityDefinitions`] until they can be
+//! applied safely:
+//! - their cost and cooldown effects are in the [`GameplayEffectRegistry`],
+//!   so files may load in any order;
+//! - no spec of the ability is active, since ending an ability removes the
+//!   tags its current definition grants.
+//!
+//! The `version` field is handled as described in
+//! [`migration`](crate::serialization::migration).
+//!
+//! Behaviors are not stored in files. When a file replaces a definition, the
+//! previous definition's behavior is kept.
+
+use super::components::{AbilityActiveState, AbilitySpec};
+use super::definition::{AbilityDefinition, AbilityRegistry};
+use super::target_filter::TargetFilterRule;
+use crate::core::validation::GasValidationAssets;
+use crate::effects::GameplayEffectRegistry;
+use crate::serialization::migration::{
+    DefinitionFormat, DefinitionMigrationError, DefinitionMigrations,
+};
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use bevy_gameplay_tag::{GameplayTagsManager, gameplay_tag::GameplayTag};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use string_cache::DefaultAtom as Atom;
+
+/// Ability definitions loaded from one file.
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct AbilityDefinitionAsset {
+    /// The definitions in the file, in order.
+    pub definitions: Vec<AbilityDefinition>,
+}
+
+/// The contents of an ability file: a versioned list, one definition or a list.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AbilityFile {
+    Versioned {
+        #[serde(rename = "version")]
+        _version: u32,
+        definitions: Vec<AbilityDefinition>,
+    },
+    Many(Vec<AbilityDefinition>),
+    One(Box<AbilityDefinition>),
+}
+
+/// Error loading an ability file.
+#[derive(Debug)]
+pub enum AbilityDefinitionAssetError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// The file is not valid RON.
+    Ron(ron::error::SpannedError),
+    /// The file could not be migrated to the current format version.
+    Migration(DefinitionMigrationError),
+}
+
+impl fmt::Display for AbilityDefinitionAssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "Could not read ability file: {error}"),
+            Self::Ron(error) => write!(f, "Invalid RON ability file: {error}"),
+            Self::Migration(error) => write!(f, "Outdated ability file: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for AbilityDefinitionAssetError {}
+
+/// Loads [`AbilityDefinitionAsset`]s from `.ability.ron` files.
+#[derive(TypePath, Default)]
+pub struct AbilityDefinitionAssetLoader {
+    /// Migrations applied to outdated files.
+    pub migrations: DefinitionMigrations,
+}
+
+impl AbilityDefinitionAssetLoader {
+    /// Migrates and parses the RON contents of an ability file.
+    pub fn migrate_and_parse(
+        &self,
+        bytes: &[u8],
+    ) -> Result<AbilityDefinitionAsset, AbilityDefinitionAssetError> {
+        let bytes = self
+            .migrations
+            .migrate(DefinitionFormat::Ability, bytes, false)
+            .map_err(AbilityDefinitionAssetError::Migration)?;
+        Self::parse(&bytes)
+    }
+
+    /// Parses the RON contents of an ability file in the current format.
+    pub fn parse(bytes: &[u8]) -> Result<AbilityDefinitionAsset, AbilityDefinitionAssetError> {
+        let definitions =
+            match ron::de::from_bytes(bytes).map_err(AbilityDefinitionAssetError::Ron)? {
+                AbilityFile::Versioned { definitions, .. } | AbilityFile::Many(definitions) => {
+                    definitions
+                }
+                AbilityFile::One(definition) => vec![*definition],
+            };
+        Ok(AbilityDefinitionAsset { definitions })
+    }
+}
+
+impl AssetLoader for AbilityDefinitionAssetLoader {
+    type Asset = AbilityDefinitionAsset;
+    type Settings = ();
+    type Error = AbilityDefinitionAssetError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(AbilityDefinitionAssetError::Io)?;
+        self.migrate_and_parse(&bytes)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ability.ron"]
+    }
+}
+
+/// Handles of the ability files loaded by [`AbilityAssetPlugin`].
+#[derive(Resource, Debug, Default)]
+pub struct AbilityAssetHandles(pub Vec<Handle<AbilityDefinitionAsset>>);
+
+/// Loaded definitions not yet applied to the [`AbilityRegistry`], by ID.
+///
+/// `None` removes the definition once no spec of it is active.
+#[derive(Resource, Debug, Default)]
+pub struct PendingAbilityDefinitions(pub HashMap<Atom, Option<AbilityDefinition>>);
+
+/// IDs each loaded ability file provided, so a reload can drop removed ones.
+#[derive(Resource, Debug, Default)]
+pub struct LoadedAbilityDefinitions(pub HashMap<AssetId<AbilityDefinitionAsset>, Vec<Atom>>);
+
+/// Plugin that loads ability definitions from asset files.
+///
+/// Requires Bevy's `AssetPlugin` and the `GameplayTagsPlugin`. Files listed
+/// with [`Self::with_path`] are loaded at startup; any other
+/// [`AbilityDefinitionAsset`] handle kept alive is registered too.
+#[derive(Default)]
+pub struct AbilityAssetPlugin {
+    /// Asset paths loaded at startup.
+    pub paths: Vec<String>,
+}
+
+impl AbilityAssetPlugin {
+    /// Creates a plugin that loads no files by itself.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an ability file to load at startup.
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.paths.push(path.into());
+        self
+    }
+}
+
+impl Plugin for AbilityAssetPlugin {
+    fn build(&self, app: &mut App) {
+        let paths = self.paths.clone();
+        let migrations = app
+            .world_mut()
+            .get_resource_or_init::<DefinitionMigrations>()
+            .clone();
+        app.init_asset::<AbilityDefinitionAsset>()
+            .register_asset_loader(AbilityDefinitionAssetLoader { migrations })
+            .init_resource::<AbilityRegistry>()
+            .init_resource::<GameplayEffectRegistry>()
+            .init_resource::<AbilityAssetHandles>()
+            .init_resource::<GasValidationAssets>()
+            .init_resource::<PendingAbilityDefinitions>()
+            .init_resource::<LoadedAbilityDefinitions>()
+            .add_systems(
+                Startup,
+                move |asset_server: Res<AssetServer>,
+                      mut handles: ResMut<AbilityAssetHandles>,
+                      mut validation: ResMut<GasValidationAssets>| {
+                    for path in &paths {
+                        let handle = asset_server.load(path.clone());
+                        validation.0.push(handle.id().untyped());
+                        handles.0.push(handle);
+                    }
+                },
+            )
+            .add_systems(
+                PreUpdate,
+                (
+                    queue_loaded_abilities_system,
+                    apply_pending_abilities_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// System that queues definitions from loaded and reloaded ability files.
+pub fn queue_loaded_abilities_system(
+    mut events: MessageReader<AssetEvent<AbilityDefinitionAsset>>,
+    assets: Res<Assets<AbilityDefinitionAsset>>,
+    tags_manager: Res<GameplayTagsManager>,
+    effect_registry: Res<GameplayEffectRegistry>,
+    mut pending: ResMut<PendingAbilityDefinitions>,
+    mut loaded: ResMut<LoadedAbilityDefinitions>,
+) {
+    for event in events.read() {
+        match *event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => {
+                let Some(asset) = assets.get(id) else {
+                    continue;
+                };
+                let previous = loaded.0.remove(&id).unwrap_or_default();
+                for ability_id in &previous {
+                    if !asset
+                        .definitions
+                        .iter()
+                        .any(|definition| definition.id == *ability_id)
+                    {
+                        pending.0.insert(ability_id.clone(), None);
+                    }
+                }
+
+                let mut ids = Vec::with_capacity(asset.definitions.len());
+                for definition in &asset.definitions {
+                    let mut definition = definition.clone();
+                    if let Err(tag) = resolve_tags(&mut definition, &tags_manager) {
+                        warn!(
+                            "Ability '{}' uses unknown tag '{}', not registering it",
+                            definition.id,
+                            tag.get_tag_name()
+                        );
+                        // Don't leave the previous version registered untracked
+                        if previous.contains(&definition.id) {
+                            pending.0.insert(definition.id.clone(), None);
+                        }
+                        continue;
+                    }
+                    if let Some(effect_id) = missing_effect(&definition, &effect_registry) {
+                        warn!(
+                            "Ability '{}' references unregistered effect '{}', \
+                             waiting for it before registering",
+                            definition.id, effect_id
+                        );
+                    }
+                    ids.push(definition.id.clone());
+                    pending.0.insert(definition.id.clone(), Some(definition));
+                }
+                loaded.0.insert(id, ids);
+            }
+            AssetEvent::Removed { id } => {
+                for ability_id in loaded.0.remove(&id).unwrap_or_default() {
+                    pending.0.insert(ability_id, None);
+                }
+            }
+            AssetEvent::Unused { .. } | AssetEvent::LoadedWithDependencies { .. } => {}
+        }
+    }
+}
+
+/// System that applies pending definitions once it is safe to.
+pub fn apply_pending_abilities_system(
+    mut pending: ResMut<PendingAbilityDefinitions>,
+    mut registry: ResMut<AbilityRegistry>,
+    effect_registry: Res<GameplayEffectRegistry>,
+    specs: Query<(&AbilitySpec, &AbilityActiveState)>,
+) {
+    if pending.0.is_empty() {
+        return;
+    }
+
+    pending.0.retain(|ability_id, definition| {
+        let active = specs
+            .iter()
+            .any(|(spec, state)| spec.definition_id == *ability_id && state.is_active);
+        if active {
+            return true;
+        }
+        let Some(definition) = definition else {
+            debug!("Removing ability '{}' dropped from its asset", ability_id);
+            registry.definitions.remove(ability_id);
+            return false;
+        };
+        if missing_effect(definition, &effect_registry).is_some() {
+            return true;
+        }
+
+        let mut definition = definition.clone();
+        if definition.behavior.is_none()
+            && let Some(existing) = registry.get(ability_id.clone())
+        {
+            definition.behavior = existing.behavior.clone();
+        }
+        debug!("Registering ability '{}' from asset", ability_id);
+        registry.register(definition);
+        false
+    });
+}
+
+/// Returns the first cost, cooldown or cost-over-time effect of a definition
+/// that is not registered.
+fn missing_effect<'a>(
+    definition: &'a AbilityDefinition,
+    effect_registry: &GameplayEffectRegistry,
+) -> Option<&'a Atom> {
+    [
+        &definition.cost_effect,
+        &definition.cooldown_effect,
+        &definition.cost_over_time_effect,
+        &definition.immunity_window_effect,
+    ]
+    .into_iter()
+    .flatten()
+    .find(|effect_id| effect_registry.get((*effect_id).clone()).is_none())
+}
+
+/// Resolves every tag of a definition through the tags manager, filling in
+/// parent tags. Returns the first tag the manager does not know.
+fn resolve_tags(
+    definition: &mut AbilityDefinition,
+    tags_manager: &GameplayTagsManager,
+) -> Result<(), GameplayTag> {
+    let mut containers = vec![
+        &mut definition.ability_tags,
+        &mut definition.activation_owned_tags,
+        &mut definition.activation_required_tags,
+        &mut definition.activation_blocked_tags,
+        &mut definition.source_required_tags,
+        &mut definition.source_blocked_tags,
+        &mut definition.target_required_tags,
+        &mut definition.target_blocked_tags,
+        &mut definition.block_abilities_with_tags,
+        &mut definition.cancel_abilities_with_tags,
+    ];
+    for rule in &mut definition.target_filter.rules {
+        if let TargetFilterRule::RequiredTags(tags) | TargetFilterRule::BlockedTags(tags) = rule {
+            containers.push(tags);
+        }
+    }
+    for container in containers {
+        if let Some(tag) = container
+            .gameplay_tags
+            .iter()
+            .find(|tag| tags_manager.get_single_tag_container(tag).is_none())
+        {
+            return Err(tag.clone());
+        }
+        container.fill_parent_tags(tags_manager);
+    }
+
+    match definition.triggers.iter().find(|trigger| {
+        tags_manager
+            .get_single_tag_container(&trigger.trigger_tag)
+            .is_none()
+    }) {
+        Some(trigger) => Err(trigger.trigger_tag.clone()),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_single_and_list() {
+        let single = AbilityDefinitionAssetLoader::parse(
+            br#"(id: "dash", default_blocks_other_abilities: false, default_is_cancelable: true)"#,
+        )
+        .unwrap();
+        assert_eq!(single.definitions.len(), 1);
+        assert_eq!(single.definitions[0].id, Atom::from("dash"));
+
+        let list = AbilityDefinitionAssetLoader::parse(
+            br#"[
+                (id: "dash", default_blocks_other_abilities: false, default_is_cancelable: true),
+                (id: "fireball", cooldown_effect: Some("fireball_cooldown"),
+                 default_blocks_other_abilities: false, default_is_cancelable: true),
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(list.definitions.len(), 2);
+        assert_eq!(
+            list.definitions[1].cooldown_effect,
+            Some(Atom::from("fireball_cooldown"))
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_ron() {
+        assert!(matches!(
+            AbilityDefinitionAssetLoader::parse(b"(id: "),
+            Err(AbilityDefinitionAssetError::Ron(_))
+        ));
+    }
+}
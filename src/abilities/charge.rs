@@ -0,0 +1,372 @@
+//! Charge-up abilities.
+//!
+//! An ability whose definition has an [`AbilityCharge`] starts charging when
+//! activated, usually by pressing its input, and fires when the input is
+//! released. While charging, a [`ChargeTask`] counts the charge time up to
+//! `max_charge_secs` and the owner carries the charging tag, then the fully
+//! charged tag once the maximum is reached, for animation.
+//!
+//! Releasing triggers [`AbilityChargeReleasedEvent`] with the charge turned
+//! into a magnitude between `min_magnitude` and `max_magnitude`.
+//! [`AbilityChargeReleasedEvent::effect`] builds an effect application
+//! carrying it as the SetByCaller value under `magnitude_tag`. The ability
+//! stays active so the release can play out; end it from the handler.
+//!
+//! The charge is cancelled if the ability stops being active first, for
+//! example when a stun cancels it. AI can release by triggering
+//! [`AbilityInputReleasedEvent`] itself.
+//!
+//! # Example
+//! ```ignore
+//! abilities.register(
+//!     AbilityDefinition::new("charged_shot").with_charge(
+//!         AbilityCharge::new(1.5)
+//!             .with_magnitude_range(20.0, 80.0)
+//!             .with_charging_tag(GameplayTag::new("State.Charging")),
+//!     ),
+//! );
+//! effects.register(GameplayEffectDefinition::new("arrow_damage").add_modifier(
+//!     ModifierInfo::new(
+//!         "Health",
+//!         ModifierOperation::AddBase,
+//!         MagnitudeCalculation::set_by_caller(GameplayTag::new(CHARGE_MAGNITUDE_TAG)),
+//!     ),
+//! ));
+//!
+//! fn fire(ev: On<AbilityChargeReleasedEvent>, mut commands: Commands, target: Res<Target>) {
+//!     commands.trigger(ev.event().effect("arrow_damage", target.0));
+//!     commands.trigger(EndAbilityEvent {
+//!         instance: ev.event().instance,
+//!         ability_spec: ev.event().ability_spec,
+//!         owner: ev.event().owner,
+//!     });
+//! }
+//! ```
+
+use super::components::{AbilityActiveState, AbilitySpec};
+use super::definition::AbilityRegistry;
+use super::input::AbilityInputReleasedEvent;
+use super::systems::AbilityActivatedEvent;
+use super::tasks::{AbilityTask, TaskCancelledEvent, TaskCompletedEvent, TaskState};
+use crate::core::OwnedTags;
+use crate::core::timestep::GasTime;
+use crate::effects::systems::ApplyGameplayEffectEvent;
+use bevy::prelude::*;
+use bevy_gameplay_tag::GameplayTagsManager;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use string_cache::DefaultAtom as Atom;
+
+/// Default SetByCaller tag the charge magnitude is passed under.
+pub const CHARGE_MAGNITUDE_TAG: &str = "Data.Charge";
+
+/// How an ability charges up.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AbilityCharge {
+    /// Charge time giving the maximum magnitude, in seconds.
+    pub max_charge_secs: f32,
+    /// Magnitude of a release without charge.
+    pub min_magnitude: f32,
+    /// Magnitude of a fully charged release.
+    pub max_magnitude: f32,
+    /// SetByCaller tag the magnitude is passed under.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::gameplay_tag_serde")
+    )]
+    pub magnitude_tag: GameplayTag,
+    /// Tag granted to the owner while charging.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::option_gameplay_tag_serde")
+    )]
+    pub charging_tag: Option<GameplayTag>,
+    /// Tag granted to the owner once fully charged.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::option_gameplay_tag_serde")
+    )]
+    pub fully_charged_tag: Option<GameplayTag>,
+}
+
+impl AbilityCharge {
+    /// Creates a charge reaching its maximum after `max_charge_secs`, with
+    /// magnitudes from 0 to 1 under [`CHARGE_MAGNITUDE_TAG`].
+    pub fn new(max_charge_secs: f32) -> Self {
+        Self {
+            max_charge_secs,
+            min_magnitude: 0.0,
+            max_magnitude: 1.0,
+            magnitude_tag: GameplayTag::new(CHARGE_MAGNITUDE_TAG),
+            charging_tag: None,
+            fully_charged_tag: None,
+        }
+    }
+
+    /// Sets the magnitudes of uncharged and fully charged releases.
+    pub fn with_magnitude_range(mut self, min_magnitude: f32, max_magnitude: f32) -> Self {
+        self.min_magnitude = min_magnitude;
+        self.max_magnitude = max_magnitude;
+        self
+    }
+
+    /// Sets the SetByCaller tag the magnitude is passed under.
+    pub fn with_magnitude_tag(mut self, tag: GameplayTag) -> Self {
+        self.magnitude_tag = tag;
+        self
+    }
+
+    /// Sets the tag granted to the owner while charging.
+    pub fn with_charging_tag(mut self, tag: GameplayTag) -> Self {
+        self.charging_tag = Some(tag);
+        self
+    }
+
+    /// Sets the tag granted to the owner once fully charged.
+    pub fn with_fully_charged_tag(mut self, tag: GameplayTag) -> Self {
+        self.fully_charged_tag = Some(tag);
+        self
+    }
+
+    /// Returns the charge fraction, from 0 to 1, after `charged_secs`.
+    pub fn fraction(&self, charged_secs: f32) -> f32 {
+        if self.max_charge_secs <= 0.0 {
+            return 1.0;
+        }
+        (charged_secs / self.max_charge_secs).clamp(0.0, 1.0)
+    }
+
+    /// Returns the magnitude of a release after `charged_secs`.
+    pub fn magnitude(&self, charged_secs: f32) -> f32 {
+        self.min_magnitude + (self.max_magnitude - self.min_magnitude) * self.fraction(charged_secs)
+    }
+}
+
+/// Charge task - counts the charge time of a charge-up ability until its
+/// input is released.
+///
+/// Completes on release and is cancelled if the ability stops being active.
+#[derive(Component, Debug, Clone)]
+pub struct ChargeTask {
+    /// How the ability charges.
+    pub charge: AbilityCharge,
+    /// Charge time so far, capped at `max_charge_secs`.
+    pub charged_secs: f32,
+    /// Whether the maximum charge was reached.
+    pub fully_charged: bool,
+}
+
+impl ChargeTask {
+    /// Create a new charge task.
+    pub fn new(charge: AbilityCharge) -> Self {
+        Self {
+            charge,
+            charged_secs: 0.0,
+            fully_charged: false,
+        }
+    }
+}
+
+/// Event triggered when a charge-up ability is released.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct AbilityChargeReleasedEvent {
+    /// The ability spec entity.
+    pub ability_spec: Entity,
+    /// The owner entity.
+    pub owner: Entity,
+    /// The ability instance, if instanced.
+    pub instance: Option<Entity>,
+    /// The ability level.
+    pub level: i32,
+    /// Charge time, capped at `max_charge_secs`.
+    pub charged_secs: f32,
+    /// Charge fraction, from 0 to 1.
+    pub charge: f32,
+    /// Magnitude of the release.
+    pub magnitude: f32,
+    /// SetByCaller tag the magnitude is passed under.
+    pub magnitude_tag: GameplayTag,
+}
+
+impl AbilityChargeReleasedEvent {
+    /// Builds an application of `effect_id` to `target` from the owner,
+    /// carrying the magnitude as a SetByCaller value.
+    pub fn effect(&self, effect_id: impl Into<Atom>, target: Entity) -> ApplyGameplayEffectEvent {
+        ApplyGameplayEffectEvent::new(effect_id, target)
+            .with_source(self.owner)
+            .with_instigator(self.ability_spec)
+            .with_level(self.level)
+            .with_set_by_caller_magnitude(self.magnitude_tag.clone(), self.magnitude)
+    }
+}
+
+fn update_owner_tag(
+    commands: &mut Commands,
+    owner_tags: &mut Query<&mut OwnedTags>,
+    tags_manager: &GameplayTagsManager,
+    owner: Entity,
+    tag: Option<&GameplayTag>,
+    delta: i32,
+) {
+    if let Some(tag) = tag
+        && let Ok(mut tags) = owner_tags.get_mut(owner)
+    {
+        tags.0
+            .update_tag_count(tag, delta, tags_manager, commands, owner);
+    }
+}
+
+/// Observer that starts charging when a charge-up ability activates.
+pub fn on_ability_activated_start_charge(
+    trigger: On<AbilityActivatedEvent>,
+    mut commands: Commands,
+    registry: Res<AbilityRegistry>,
+    tags_manager: Res<GameplayTagsManager>,
+    specs: Query<&AbilitySpec>,
+    mut owner_tags: Query<&mut OwnedTags>,
+) {
+    let event = trigger.event();
+    let Some(charge) = specs
+        .get(event.ability_spec)
+        .ok()
+        .and_then(|spec| registry.get(&spec.definition_id))
+        .and_then(|definition| definition.charge.clone())
+    else {
+        return;
+    };
+    update_owner_tag(
+        &mut commands,
+        &mut owner_tags,
+        &tags_manager,
+        event.owner,
+        charge.charging_tag.as_ref(),
+        1,
+    );
+    commands.spawn((
+        AbilityTask {
+            ability_instance: event.instance,
+            ability_spec: event.ability_spec,
+            owner: event.owner,
+        },
+        ChargeTask::new(charge),
+        TaskState::Running,
+    ));
+}
+
+/// System that advances charge tasks and cancels those whose ability ended.
+pub fn tick_charge_tasks_system(
+    mut commands: Commands,
+    time: GasTime,
+    tags_manager: Res<GameplayTagsManager>,
+    active_states: Query<&AbilityActiveState>,
+    mut owner_tags: Query<&mut OwnedTags>,
+    mut tasks: Query<(Entity, &AbilityTask, &mut ChargeTask, &mut TaskState)>,
+) {
+    for (task_entity, ability_task, mut charge_task, mut state) in tasks.iter_mut() {
+        if *state != TaskState::Running {
+            continue;
+        }
+
+        if !active_states
+            .get(ability_task.ability_spec)
+            .is_ok_and(|active_state| active_state.is_active)
+        {
+            *state = TaskState::Cancelled;
+            commands.trigger(TaskCancelledEvent {
+                task: task_entity,
+                ability_instance: ability_task.ability_instance,
+                ability_spec: ability_task.ability_spec,
+                owner: ability_task.owner,
+            });
+            continue;
+        }
+
+        let max_charge_secs = charge_task.charge.max_charge_secs;
+        charge_task.charged_secs =
+            (charge_task.charged_secs + time.delta_secs(ability_task.owner)).min(max_charge_secs);
+
+        if !charge_task.fully_charged && charge_task.charged_secs >= max_charge_secs {
+            charge_task.fully_charged = true;
+            update_owner_tag(
+                &mut commands,
+                &mut owner_tags,
+                &tags_manager,
+                ability_task.owner,
+                charge_task.charge.fully_charged_tag.as_ref(),
+                1,
+            );
+        }
+    }
+}
+
+/// Observer that fires charge-up abilities when their input is released.
+pub fn on_ability_input_released_release_charge(
+    trigger: On<AbilityInputReleasedEvent>,
+    mut commands: Commands,
+    specs: Query<&AbilitySpec>,
+    mut tasks: Query<(Entity, &AbilityTask, &ChargeTask, &mut TaskState)>,
+) {
+    let event = trigger.event();
+
+    for (task_entity, ability_task, charge_task, mut state) in tasks.iter_mut() {
+        if *state != TaskState::Running || ability_task.ability_spec != event.ability_spec {
+            continue;
+        }
+
+        *state = TaskState::Completed;
+        commands.trigger(TaskCompletedEvent {
+            task: task_entity,
+            ability_instance: ability_task.ability_instance,
+            ability_spec: ability_task.ability_spec,
+            owner: ability_task.owner,
+        });
+
+        let charge = &charge_task.charge;
+        let charged_secs = charge_task.charged_secs;
+        commands.trigger(AbilityChargeReleasedEvent {
+            ability_spec: ability_task.ability_spec,
+            owner: ability_task.owner,
+            instance: ability_task.ability_instance,
+            level: specs
+                .get(ability_task.ability_spec)
+                .map_or(1, |spec| spec.level),
+            charged_secs,
+            charge: charge.fraction(charged_secs),
+            magnitude: charge.magnitude(charged_secs),
+            magnitude_tag: charge.magnitude_tag.clone(),
+        });
+    }
+}
+
+/// Observer that takes the charge tags off the owner when a charge task goes
+/// away.
+pub fn on_charge_task_removed(
+    ev: On<Remove, ChargeTask>,
+    mut commands: Commands,
+    tags_manager: Res<GameplayTagsManager>,
+    tasks: Query<(&AbilityTask, &ChargeTask)>,
+    mut owner_tags: Query<&mut OwnedTags>,
+) {
+    let Ok((ability_task, charge_task)) = tasks.get(ev.event_target()) else {
+        return;
+    };
+    let charge = &charge_task.charge;
+    update_owner_tag(
+        &mut commands,
+        &mut owner_tags,
+        &tags_manager,
+        ability_task.owner,
+        charge.charging_tag.as_ref(),
+        -1,
+    );
+    if charge_task.fully_charged {
+        update_owner_tag(
+            &mut commands,
+            &mut owner_tags,
+            &tags_manager,
+            ability_task.owner,
+            charge.fully_charged_tag.as_ref(),
+            -1,
+        );
+    }
+}
@@ -17,7 +17,12 @@ use super::traits::AbilityBehavior;
 ///
 /// Represents a granted ability on a character. Contains only the reference
 /// to the definition and per-grant configuration (level, input binding).
-#[derive(Component, Clone)]
+#[derive(Component, Clone, Reflect)]
+#[reflect(opaque)]
+#[reflect(Component, Debug, Clone)]
+#[require(AbilityActiveState)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct AbilitySpec {
     /// The ID of the ability definition in the AbilityRegistry.
     pub definition_id: Atom,
@@ -59,6 +64,7 @@ impl AbilitySpec {
 /// Separated from AbilitySpec so Bevy change detection can track
 /// activation state independently from the grant configuration.
 #[derive(Component, Debug, Clone, Default)]
+#[cfg_attr(feature = "replicon", derive(serde::Serialize, serde::Deserialize))]
 pub struct AbilityActiveState {
     /// Whether at least one instance is currently active.
     pub is_active: bool,
@@ -81,8 +87,22 @@ impl AbilityActiveState {
 }
 
 /// Component that links an ability to its owner entity.
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
-pub struct AbilityOwner(pub Entity);
+///
+/// A relationship, so the owner lists its abilities in [`OwnedAbilities`] and
+/// scenes remap it on load.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "replicon", derive(serde::Serialize, serde::Deserialize))]
+#[relationship(relationship_target = OwnedAbilities)]
+pub struct AbilityOwner(#[entities] pub Entity);
+
+/// The ability specs granted to an entity.
+///
+/// Despawning the entity despawns its ability specs.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component, FromWorld, Default)]
+#[relationship_target(relationship = AbilityOwner, linked_spawn)]
+pub struct OwnedAbilities(Vec<Entity>);
 
 /// Tracks activation history for an ability.
 ///
@@ -0,0 +1,315 @@
+//! Cooldown events for UI.
+//!
+//! An ability spec is on cooldown while its owner has an effect granting the
+//! tags of the spec's cooldown effect, the same tags activation checks. Such
+//! an effect is the spec's own cooldown effect or any other effect granting
+//! those tags, e.g. a shared `Cooldown.Potion`. While one runs, the spec
+//! carries an [`AbilityCooldown`] following the effect with the most time
+//! left, and the plugin triggers:
+//!
+//! - [`CooldownStartedEvent`] when such an effect is applied or refreshed,
+//!   or takes over from a removed one,
+//! - [`CooldownUpdatedEvent`] every
+//!   [`CooldownEventSettings::update_interval`] seconds with the time left,
+//! - [`CooldownEndedEvent`] when the last such effect is removed.
+//!
+//! HUD cooldown spinners can follow these events instead of matching effect
+//! tags against abilities every frame. Only cooldown effects with a duration
+//! are tracked.
+//!
+//! # Example
+//! ```ignore
+//! app.insert_resource(CooldownEventSettings::default().with_update_interval(0.05))
+//!     .add_observer(|ev: On<CooldownUpdatedEvent>, mut spinners: Query<(&mut Spinner, &SpinnerAbility)>| {
+//!         for (mut spinner, ability) in &mut spinners {
+//!             if ability.0 == ev.ability_spec {
+//!                 spinner.progress = ev.progress();
+//!             }
+//!         }
+//!     });
+//! ```
+
+use super::components::{AbilityOwner, AbilitySpec, OwnedAbilities};
+use super::definition::{AbilityDefinition, AbilityRegistry};
+use crate::core::timestep::GasTime;
+use crate::effects::components::{ActiveEffects, ActiveGameplayEffect, EffectDuration};
+use crate::effects::definition::GameplayEffectRegistry;
+use crate::effects::systems::{GameplayEffectAppliedEvent, GameplayEffectRemovedEvent};
+use bevy::prelude::*;
+use string_cache::DefaultAtom as Atom;
+
+/// How often [`CooldownUpdatedEvent`]s are triggered.
+#[derive(Resource, Debug, Clone)]
+pub struct CooldownEventSettings {
+    /// Seconds between updates of a running cooldown.
+    pub update_interval: f32,
+}
+
+impl Default for CooldownEventSettings {
+    fn default() -> Self {
+        Self {
+            update_interval: 0.1,
+        }
+    }
+}
+
+impl CooldownEventSettings {
+    /// Sets the seconds between updates.
+    pub fn with_update_interval(mut self, update_interval: f32) -> Self {
+        self.update_interval = update_interval;
+        self
+    }
+}
+
+/// The running cooldown of an ability spec.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct AbilityCooldown {
+    /// The cooldown effect entity.
+    pub effect: Entity,
+    /// Seconds of cooldown left as of the last update.
+    pub remaining: f32,
+    /// Total cooldown duration.
+    pub duration: f32,
+    /// Seconds since the last [`CooldownUpdatedEvent`].
+    since_update: f32,
+}
+
+impl AbilityCooldown {
+    /// Returns cooldown progress in `0..=1`: `0` when the cooldown starts and
+    /// `1` when it ends.
+    pub fn progress(&self) -> f32 {
+        cooldown_progress(self.remaining, self.duration)
+    }
+}
+
+fn cooldown_progress(remaining: f32, duration: f32) -> f32 {
+    if duration <= 0.0 {
+        return 1.0;
+    }
+    (1.0 - remaining / duration).clamp(0.0, 1.0)
+}
+
+/// Event triggered when an ability spec's cooldown starts or is refreshed.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct CooldownStartedEvent {
+    /// The ability spec entity.
+    pub ability_spec: Entity,
+    /// The owner of the ability.
+    pub owner: Entity,
+    /// The cooldown effect entity.
+    pub effect: Entity,
+    /// Total cooldown duration.
+    pub duration: f32,
+}
+
+/// Event triggered periodically while an ability spec is on cooldown.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct CooldownUpdatedEvent {
+    /// The ability spec entity.
+    pub ability_spec: Entity,
+    /// The owner of the ability.
+    pub owner: Entity,
+    /// Seconds of cooldown left.
+    pub remaining: f32,
+    /// Total cooldown duration.
+    pub duration: f32,
+}
+
+impl CooldownUpdatedEvent {
+    /// Returns cooldown progress in `0..=1`.
+    pub fn progress(&self) -> f32 {
+        cooldown_progress(self.remaining, self.duration)
+    }
+}
+
+/// Event triggered when an ability spec's cooldown ends.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct CooldownEndedEvent {
+    /// The ability spec entity.
+    pub ability_spec: Entity,
+    /// The owner of the ability.
+    pub owner: Entity,
+    /// The cooldown effect entity.
+    pub effect: Entity,
+}
+
+/// Returns `true` if an effect of `effect_id` puts specs of `definition` on
+/// cooldown: it is their cooldown effect or grants one of its tags.
+pub fn puts_on_cooldown(
+    definition: &AbilityDefinition,
+    effect_id: &Atom,
+    effects: &GameplayEffectRegistry,
+) -> bool {
+    let Some(cooldown_effect) = definition.cooldown_effect.as_ref() else {
+        return false;
+    };
+    if cooldown_effect == effect_id {
+        return true;
+    }
+    let (Some(cooldown), Some(effect)) = (effects.get(cooldown_effect), effects.get(effect_id))
+    else {
+        return false;
+    };
+    !cooldown.granted_tags.is_empty() && effect.granted_tags.has_any(&cooldown.granted_tags)
+}
+
+/// Observer that starts the cooldowns of the specs the effect applied to
+/// their owner puts on cooldown.
+///
+/// A spec already cooling down on another effect with more time left keeps
+/// following that one.
+pub fn on_effect_applied_start_cooldowns(
+    trigger: On<GameplayEffectAppliedEvent>,
+    mut commands: Commands,
+    registry: Res<AbilityRegistry>,
+    effects: Res<GameplayEffectRegistry>,
+    owners: Query<&OwnedAbilities>,
+    specs: Query<(&AbilitySpec, Option<&AbilityCooldown>)>,
+    durations: Query<&EffectDuration>,
+) {
+    let event = trigger.event();
+    let Ok(duration) = durations.get(event.effect) else {
+        return;
+    };
+    let Ok(owned) = owners.get(event.target) else {
+        return;
+    };
+    for ability_spec in owned.iter() {
+        let Ok((spec, cooldown)) = specs.get(ability_spec) else {
+            continue;
+        };
+        if !registry
+            .get(&spec.definition_id)
+            .is_some_and(|definition| puts_on_cooldown(definition, &event.effect_id, &effects))
+        {
+            continue;
+        }
+        if let Some(cooldown) = cooldown
+            && cooldown.effect != event.effect
+            && durations
+                .get(cooldown.effect)
+                .is_ok_and(|running| running.remaining > duration.remaining)
+        {
+            continue;
+        }
+        commands.entity(ability_spec).insert(AbilityCooldown {
+            effect: event.effect,
+            remaining: duration.remaining,
+            duration: duration.total,
+            since_update: 0.0,
+        });
+        commands.trigger(CooldownStartedEvent {
+            ability_spec,
+            owner: event.target,
+            effect: event.effect,
+            duration: duration.total,
+        });
+    }
+}
+
+/// Observer that ends the cooldowns whose effect was removed, or hands them
+/// to another effect of the owner that still puts the spec on cooldown.
+pub fn on_effect_removed_end_cooldowns(
+    trigger: On<GameplayEffectRemovedEvent>,
+    mut commands: Commands,
+    registry: Res<AbilityRegistry>,
+    effects: Res<GameplayEffectRegistry>,
+    owners: Query<(&OwnedAbilities, Option<&ActiveEffects>)>,
+    cooldowns: Query<(&AbilitySpec, &AbilityCooldown)>,
+    active_effects: Query<(&ActiveGameplayEffect, &EffectDuration)>,
+) {
+    let event = trigger.event();
+    let Ok((owned, owner_effects)) = owners.get(event.target) else {
+        return;
+    };
+    for ability_spec in owned.iter() {
+        let Ok((spec, cooldown)) = cooldowns.get(ability_spec) else {
+            continue;
+        };
+        if cooldown.effect != event.effect {
+            continue;
+        }
+        // The remaining effect with the most time left takes over
+        let successor = registry.get(&spec.definition_id).and_then(|definition| {
+            owner_effects
+                .into_iter()
+                .flat_map(|list| list.iter())
+                .filter(|&effect| effect != event.effect)
+                .filter_map(|effect| {
+                    active_effects
+                        .get(effect)
+                        .ok()
+                        .map(|(active, duration)| (effect, active, duration))
+                })
+                .filter(|(_, active, _)| {
+                    puts_on_cooldown(definition, &active.definition_id, &effects)
+                })
+                .max_by(|(_, _, a), (_, _, b)| a.remaining.total_cmp(&b.remaining))
+        });
+        match successor {
+            Some((effect, _, duration)) => {
+                commands.entity(ability_spec).insert(AbilityCooldown {
+                    effect,
+                    remaining: duration.remaining,
+                    duration: duration.total,
+                    since_update: 0.0,
+                });
+                commands.trigger(CooldownStartedEvent {
+                    ability_spec,
+                    owner: event.target,
+                    effect,
+                    duration: duration.total,
+                });
+            }
+            None => end_cooldown(&mut commands, ability_spec, event.target, event.effect),
+        }
+    }
+}
+
+fn end_cooldown(commands: &mut Commands, ability_spec: Entity, owner: Entity, effect: Entity) {
+    commands.entity(ability_spec).remove::<AbilityCooldown>();
+    commands.trigger(CooldownEndedEvent {
+        ability_spec,
+        owner,
+        effect,
+    });
+}
+
+/// System that refreshes running cooldowns and triggers throttled
+/// [`CooldownUpdatedEvent`]s.
+///
+/// Cooldowns whose effect disappeared without a removal event end here.
+pub fn update_ability_cooldowns_system(
+    mut commands: Commands,
+    settings: Res<CooldownEventSettings>,
+    time: GasTime,
+    mut cooldowns: Query<(Entity, &mut AbilityCooldown, &AbilityOwner)>,
+    durations: Query<&EffectDuration>,
+) {
+    let delta = time.global_delta_secs();
+    for (ability_spec, mut cooldown, owner) in &mut cooldowns {
+        let owner = owner.0;
+        let Ok(duration) = durations.get(cooldown.effect) else {
+            end_cooldown(&mut commands, ability_spec, owner, cooldown.effect);
+            continue;
+        };
+        // Only throttled updates mark the cooldown as changed.
+        let since_update = &mut cooldown.bypass_change_detection().since_update;
+        *since_update += delta;
+        if *since_update < settings.update_interval {
+            continue;
+        }
+        *cooldown = AbilityCooldown {
+            remaining: duration.remaining,
+            duration: duration.total,
+            since_update: 0.0,
+            ..*cooldown
+        };
+        commands.trigger(CooldownUpdatedEvent {
+            ability_spec,
+            owner,
+            remaining: duration.remaining,
+            duration: duration.total,
+        });
+    }
+}
@@ -0,0 +1,191 @@
+//! Costs paid over time.
+//!
+//! An ability whose definition names a
+//! [`cost_over_time_effect`](super::definition::AbilityDefinition::cost_over_time_effect)
+//! applies that periodic effect to its owner when activated and removes it
+//! when the last instance ends, for mana-draining channels or a
+//! stamina-draining sprint. The spec is the instigator of the drain.
+//!
+//! After each period the drain's attributes are checked on the owner: once
+//! one can no longer pay a full period, the plugin triggers
+//! [`AbilityCostDepletedEvent`] and ends the ability.
+//!
+//! # Example
+//! ```ignore
+//! effects.register(
+//!     GameplayEffectDefinition::new("sprint_drain")
+//!         .with_duration_policy(DurationPolicy::Infinite)
+//!         .with_period(0.5)
+//!         .add_modifier(ModifierInfo::new(
+//!             "Stamina",
+//!             ModifierOperation::AddBase,
+//!             MagnitudeCalculation::scalar(-5.0),
+//!         )),
+//! );
+//! abilities.register(AbilityDefinition::new("sprint").with_cost_over_time_effect("sprint_drain"));
+//! ```
+
+use super::components::{AbilityActiveState, AbilityOwner, AbilitySpec};
+use super::definition::AbilityRegistry;
+use super::systems::{AbilityActivatedEvent, EndAbilityEvent};
+use crate::attributes::AttributeData;
+use crate::effects::batch_aggregation::{AttributeKey, AttributeLookup};
+use crate::effects::components::{ActiveEffects, ActiveGameplayEffect, EffectInstigator};
+use crate::effects::systems::{
+    ApplyGameplayEffectEvent, GameplayEffectExecutedEvent, RemoveGameplayEffectEvent,
+};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use string_cache::DefaultAtom as Atom;
+
+/// Event triggered when an ability's owner can no longer pay its cost over
+/// time. The ability is ended right after.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct AbilityCostDepletedEvent {
+    /// The ability spec entity.
+    pub ability_spec: Entity,
+    /// The owner of the ability.
+    pub owner: Entity,
+    /// The attribute that ran out.
+    pub attribute: Atom,
+}
+
+/// System parameter finding the cost-over-time effects of ability specs.
+#[derive(SystemParam)]
+pub struct CostOverTimeEffects<'w, 's> {
+    pub lists: Query<'w, 's, &'static ActiveEffects>,
+    pub effects: Query<'w, 's, (&'static ActiveGameplayEffect, &'static EffectInstigator)>,
+}
+
+impl CostOverTimeEffects<'_, '_> {
+    /// Returns the active `effect_id` effects on `owner` instigated by
+    /// `ability_spec`.
+    fn of(&self, owner: Entity, ability_spec: Entity, effect_id: &Atom) -> Vec<Entity> {
+        let Ok(list) = self.lists.get(owner) else {
+            return Vec::new();
+        };
+        list.iter()
+            .filter(|&effect| {
+                self.effects.get(effect).is_ok_and(|(active, instigator)| {
+                    &active.definition_id == effect_id && instigator.0 == Some(ability_spec)
+                })
+            })
+            .collect()
+    }
+}
+
+/// Observer that starts the cost over time of an activated ability.
+pub fn on_ability_activated_apply_cost_over_time(
+    trigger: On<AbilityActivatedEvent>,
+    mut commands: Commands,
+    registry: Res<AbilityRegistry>,
+    specs: Query<&AbilitySpec>,
+    drains: CostOverTimeEffects,
+) {
+    let event = trigger.event();
+    let Ok(spec) = specs.get(event.ability_spec) else {
+        return;
+    };
+    let Some(effect_id) = registry
+        .get(&spec.definition_id)
+        .and_then(|definition| definition.cost_over_time_effect.as_ref())
+    else {
+        return;
+    };
+    // Further instances share the running drain.
+    if !drains
+        .of(event.owner, event.ability_spec, effect_id)
+        .is_empty()
+    {
+        return;
+    }
+    commands.trigger(
+        ApplyGameplayEffectEvent::new(effect_id.clone(), event.owner)
+            .with_source(event.owner)
+            .with_instigator(event.ability_spec)
+            .with_level(spec.level),
+    );
+}
+
+/// System that removes the cost over time of abilities that stopped being
+/// active.
+pub fn remove_ended_cost_over_time_system(
+    mut commands: Commands,
+    registry: Res<AbilityRegistry>,
+    specs: Query<
+        (Entity, &AbilitySpec, &AbilityActiveState, &AbilityOwner),
+        Changed<AbilityActiveState>,
+    >,
+    drains: CostOverTimeEffects,
+) {
+    for (ability_spec, spec, active_state, owner) in &specs {
+        if active_state.is_active {
+            continue;
+        }
+        let Some(effect_id) = registry
+            .get(&spec.definition_id)
+            .and_then(|definition| definition.cost_over_time_effect.as_ref())
+        else {
+            continue;
+        };
+        for effect in drains.of(owner.0, ability_spec, effect_id) {
+            commands.trigger(RemoveGameplayEffectEvent { effect });
+        }
+    }
+}
+
+/// Observer that ends abilities whose owner can't pay another period of
+/// their cost over time.
+///
+/// Drains outliving their ability, such as one applied after the ability
+/// already ended, are removed here.
+pub fn on_cost_over_time_executed(
+    trigger: On<GameplayEffectExecutedEvent>,
+    mut commands: Commands,
+    registry: Res<AbilityRegistry>,
+    specs: Query<(&AbilitySpec, Option<&AbilityActiveState>)>,
+    lookup: AttributeLookup,
+    attributes: Query<&AttributeData>,
+) {
+    let event = trigger.event();
+    let Some(ability_spec) = event.context.instigator else {
+        return;
+    };
+    let Ok((spec, active_state)) = specs.get(ability_spec) else {
+        return;
+    };
+    if registry
+        .get(&spec.definition_id)
+        .and_then(|definition| definition.cost_over_time_effect.as_ref())
+        != Some(&event.effect_id)
+    {
+        return;
+    }
+    if !active_state.is_some_and(|state| state.is_active) {
+        if let Some(effect) = event.effect {
+            commands.trigger(RemoveGameplayEffectEvent { effect });
+        }
+        return;
+    }
+
+    let depleted = event.changes.iter().find(|(attribute_name, change)| {
+        *change < 0.0
+            && lookup
+                .find(&AttributeKey::new(event.target, attribute_name.clone()))
+                .and_then(|attribute| attributes.get(attribute).ok())
+                .is_some_and(|data| data.base_value <= 0.0 || data.base_value < -change)
+    });
+    let Some((attribute, _)) = depleted else {
+        return;
+    };
+    commands.trigger(AbilityCostDepletedEvent {
+        ability_spec,
+        owner: event.target,
+        attribute: attribute.clone(),
+    });
+    commands.trigger(EndAbilityEvent {
+        instance: None,
+        ability_spec,
+        owner: event.target,
+    });
+}
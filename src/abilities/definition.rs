@@ -7,14 +7,24 @@ use bevy_gameplay_tag::{GameplayTagContainer, GameplayTagsManager, gameplay_tag:
 use std::sync::Arc;
 use string_cache::DefaultAtom as Atom;
 
+use super::charge::AbilityCharge;
+use super::components::AbilitySpec;
+use super::input::AbilityInputPolicy;
+use super::spender::AbilitySpend;
+use super::target_filter::TargetFilter;
 use super::traits::AbilityBehavior;
 use super::triggers::AbilityTriggerData;
+use crate::core::{AbilityId, DisplayInfo, IdTable, format_number, format_template};
+use crate::effects::components::GameplayEffectSpec;
+use crate::effects::definition::{GameplayEffectRegistry, modifier_display_magnitude};
+use crate::error::{GasError, GasResult};
 
 /// Instancing policy for abilities.
 ///
 /// Determines how ability instances are created and managed.
 /// Follows UE GAS's instancing model.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InstancingPolicy {
     /// No instance is created. Logic executes directly from the definition.
     /// - No per-activation state storage
@@ -42,6 +52,25 @@ impl Default for InstancingPolicy {
     }
 }
 
+/// Where an ability runs when the game is networked.
+///
+/// Only consulted while [`NetRole`](super::prediction::NetRole) is `Client`;
+/// standalone games and servers activate every ability normally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NetExecutionPolicy {
+    /// Runs on the client only; the server is never asked.
+    LocalOnly,
+    /// Runs on the client immediately under a prediction key and on the
+    /// server, which confirms or rejects the prediction (default).
+    #[default]
+    LocalPredicted,
+    /// Requested by the client, started by the server.
+    ServerInitiated,
+    /// Runs on the server only; clients see the replicated results.
+    ServerOnly,
+}
+
 /// Ability definition — pure configuration data stored in the AbilityRegistry.
 ///
 /// Each ability type is described by one definition. When granted to a character,
@@ -51,43 +80,145 @@ impl Default for InstancingPolicy {
 /// - InstancedPerActor: Reuses existing spec entity
 /// - InstancedPerExecution: Spawns new spec instance entity
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AbilityDefinition {
     /// Unique identifier for this ability.
     pub id: Atom,
     /// Instancing policy for this ability.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub instancing_policy: InstancingPolicy,
+    /// Where this ability runs when networked.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub net_execution_policy: NetExecutionPolicy,
+    /// How this ability reacts to its bound input.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub input_policy: AbilityInputPolicy,
     /// Effect ID to apply as costs when the ability is committed.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub cost_effect: Option<Atom>,
     /// Effect ID to apply as cooldown when the ability is committed.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub cooldown_effect: Option<Atom>,
+    /// Named cooldown shared with the other abilities of the category, e.g.
+    /// all potions. When the category is registered with
+    /// [`AbilityRegistry::register_cooldown_category`], its shared effect
+    /// replaces [`cooldown_effect`](Self::cooldown_effect).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cooldown_category: Option<Atom>,
+    /// Periodic effect ID applied to the owner while the ability is active,
+    /// draining a resource each period. The ability ends when the owner can't
+    /// pay another period.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cost_over_time_effect: Option<Atom>,
+    /// Effect ID applied to the owner on activation for an immunity window,
+    /// such as the i-frames of a dodge. See
+    /// [`immunity_window`](crate::effects::immunity::immunity_window).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub immunity_window_effect: Option<Atom>,
+    /// Animation the ability plays, passed on by [`AbilityActivatedEvent`]
+    /// for the game's animation code to map to a clip or graph node.
+    ///
+    /// [`AbilityActivatedEvent`]: super::systems::AbilityActivatedEvent
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::option_gameplay_tag_serde")
+    )]
+    pub animation_tag: Option<GameplayTag>,
+    /// Makes the ability charge up while its input is held and fire on
+    /// release. See [`AbilityCharge`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub charge: Option<AbilityCharge>,
+    /// Consumes a counted resource of the owner on activation and passes
+    /// the count on. See [`AbilitySpend`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub spend: Option<AbilitySpend>,
+    /// Name, icon and description shown in ability bars and tooltips.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub display: Option<DisplayInfo>,
     /// Tags describing this ability (used for cancel matching).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::tag_container_serde")
+    )]
     pub ability_tags: GameplayTagContainer,
     /// Tags granted to the owner while this ability is active.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::tag_container_serde")
+    )]
     pub activation_owned_tags: GameplayTagContainer,
     /// Tags required on the owner to activate this ability.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::tag_container_serde")
+    )]
     pub activation_required_tags: GameplayTagContainer,
     /// Tags that block activation if present on the owner.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::tag_container_serde")
+    )]
     pub activation_blocked_tags: GameplayTagContainer,
     /// Tags required on the source to activate this ability.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::tag_container_serde")
+    )]
     pub source_required_tags: GameplayTagContainer,
     /// Tags that block activation if present on the source.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::tag_container_serde")
+    )]
     pub source_blocked_tags: GameplayTagContainer,
     /// Tags required on the target to activate this ability.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::tag_container_serde")
+    )]
     pub target_required_tags: GameplayTagContainer,
     /// Tags that block activation if present on the target.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::tag_container_serde")
+    )]
     pub target_blocked_tags: GameplayTagContainer,
     /// Tags added to owner to block other abilities while active.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::tag_container_serde")
+    )]
     pub block_abilities_with_tags: GameplayTagContainer,
     /// Tags to cancel when this ability activates.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::tag_container_serde")
+    )]
     pub cancel_abilities_with_tags: GameplayTagContainer,
+    /// Filter applied to target data before this ability's
+    /// `ApplyEffectToTargetDataTask`s apply effects.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub target_filter: TargetFilter,
     /// Triggers that can automatically activate this ability.
     ///
     /// Matches UE GAS's `FAbilityTriggerData` array.
     /// When an ability is granted with triggers, the system will automatically
     /// activate it when the trigger conditions are met.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub triggers: Vec<AbilityTriggerData>,
     /// Custom behavior implementation.
+    ///
+    /// Not serialized; attach it in code after loading a definition.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub behavior: Option<Arc<dyn AbilityBehavior>>,
+    /// Script providing this ability's logic.
+    ///
+    /// When set, registering the definition replaces `behavior` with a
+    /// [`ScriptedAbilityBehavior`](crate::scripting::ScriptedAbilityBehavior)
+    /// running it.
+    #[cfg(feature = "scripting")]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub script: Option<Atom>,
     /// Whether instances of this ability block other abilities by default.
     pub default_blocks_other_abilities: bool,
     /// Whether instances of this ability are cancelable by default.
@@ -96,11 +227,21 @@ pub struct AbilityDefinition {
 
 impl std::fmt::Debug for AbilityDefinition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("AbilityDefinition")
+        let mut debug = f.debug_struct("AbilityDefinition");
+        debug
             .field("id", &self.id)
             .field("instancing_policy", &self.instancing_policy)
+            .field("net_execution_policy", &self.net_execution_policy)
+            .field("input_policy", &self.input_policy)
             .field("cost_effect", &self.cost_effect)
             .field("cooldown_effect", &self.cooldown_effect)
+            .field("cooldown_category", &self.cooldown_category)
+            .field("cost_over_time_effect", &self.cost_over_time_effect)
+            .field("immunity_window_effect", &self.immunity_window_effect)
+            .field("animation_tag", &self.animation_tag)
+            .field("charge", &self.charge)
+            .field("spend", &self.spend)
+            .field("display", &self.display)
             .field("ability_tags", &self.ability_tags)
             .field("activation_owned_tags", &self.activation_owned_tags)
             .field("activation_required_tags", &self.activation_required_tags)
@@ -114,9 +255,12 @@ impl std::fmt::Debug for AbilityDefinition {
                 "cancel_abilities_with_tags",
                 &self.cancel_abilities_with_tags,
             )
+            .field("target_filter", &self.target_filter)
             .field("triggers", &self.triggers)
-            .field("behavior", &self.behavior.as_ref().map(|_| "<behavior>"))
-            .finish()
+            .field("behavior", &self.behavior.as_ref().map(|_| "<behavior>"));
+        #[cfg(feature = "scripting")]
+        debug.field("script", &self.script);
+        debug.finish()
     }
 }
 
@@ -134,8 +278,17 @@ impl AbilityDefinition {
         Self {
             id: id.into(),
             instancing_policy: InstancingPolicy::default(),
+            net_execution_policy: NetExecutionPolicy::default(),
+            input_policy: AbilityInputPolicy::default(),
             cost_effect: None,
             cooldown_effect: None,
+            cooldown_category: None,
+            cost_over_time_effect: None,
+            immunity_window_effect: None,
+            animation_tag: None,
+            charge: None,
+            spend: None,
+            display: None,
             ability_tags: GameplayTagContainer::default(),
             activation_owned_tags: GameplayTagContainer::default(),
             activation_required_tags: GameplayTagContainer::default(),
@@ -146,8 +299,11 @@ impl AbilityDefinition {
             target_blocked_tags: GameplayTagContainer::default(),
             block_abilities_with_tags: GameplayTagContainer::default(),
             cancel_abilities_with_tags: GameplayTagContainer::default(),
+            target_filter: TargetFilter::default(),
             triggers: Vec::new(),
             behavior: None,
+            #[cfg(feature = "scripting")]
+            script: None,
             default_blocks_other_abilities: true,
             default_is_cancelable: true,
         }
@@ -159,12 +315,31 @@ impl AbilityDefinition {
         self
     }
 
+    /// Sets the script providing this ability's logic.
+    #[cfg(feature = "scripting")]
+    pub fn with_script(mut self, script: impl Into<Atom>) -> Self {
+        self.script = Some(script.into());
+        self
+    }
+
     /// Sets the instancing policy.
     pub fn with_instancing_policy(mut self, policy: InstancingPolicy) -> Self {
         self.instancing_policy = policy;
         self
     }
 
+    /// Sets the net execution policy.
+    pub fn with_net_execution_policy(mut self, policy: NetExecutionPolicy) -> Self {
+        self.net_execution_policy = policy;
+        self
+    }
+
+    /// Sets how the ability reacts to its bound input.
+    pub fn with_input_policy(mut self, policy: AbilityInputPolicy) -> Self {
+        self.input_policy = policy;
+        self
+    }
+
     /// Adds a cost effect.
     pub fn with_cost_effect(mut self, effect_id: impl Into<Atom>) -> Self {
         self.cost_effect = Some(effect_id.into());
@@ -177,6 +352,92 @@ impl AbilityDefinition {
         self
     }
 
+    /// Puts the ability in a shared cooldown category.
+    pub fn with_cooldown_category(mut self, category: impl Into<Atom>) -> Self {
+        self.cooldown_category = Some(category.into());
+        self
+    }
+
+    /// Sets the periodic effect draining a resource while the ability is
+    /// active, for channels and toggles.
+    pub fn with_cost_over_time_effect(mut self, effect_id: impl Into<Atom>) -> Self {
+        self.cost_over_time_effect = Some(effect_id.into());
+        self
+    }
+
+    /// Sets the effect granting the owner an immunity window when the
+    /// ability activates, for dodges and rolls.
+    pub fn with_immunity_window(mut self, effect_id: impl Into<Atom>) -> Self {
+        self.immunity_window_effect = Some(effect_id.into());
+        self
+    }
+
+    /// Sets the animation the ability plays.
+    pub fn with_animation_tag(mut self, tag: GameplayTag) -> Self {
+        self.animation_tag = Some(tag);
+        self
+    }
+
+    /// Makes the ability a charge-up ability.
+    pub fn with_charge(mut self, charge: AbilityCharge) -> Self {
+        self.charge = Some(charge);
+        self
+    }
+
+    /// Makes the ability a spender of a counted resource.
+    pub fn with_spend(mut self, spend: AbilitySpend) -> Self {
+        self.spend = Some(spend);
+        self
+    }
+
+    /// Sets the name, icon and description shown in UI.
+    pub fn with_display(mut self, display: DisplayInfo) -> Self {
+        self.display = Some(display);
+        self
+    }
+
+    /// Resolves the description template of [`Self::display`] for `spec`.
+    ///
+    /// Placeholders:
+    /// - `{level}`
+    /// - `{cooldown}`: duration of the cooldown effect
+    /// - `{cost.Attribute}`: amount of `Attribute` the cost effect takes
+    ///
+    /// Effect magnitudes are evaluated at the spec's level.
+    pub fn format_description(
+        &self,
+        spec: &AbilitySpec,
+        effects: &GameplayEffectRegistry,
+    ) -> Option<String> {
+        let template = self.display.as_ref()?.description.as_ref()?;
+        Some(format_template(template, |key| {
+            let value = match key {
+                "level" => spec.level as f32,
+                "cooldown" => {
+                    effects
+                        .get(self.cooldown_effect.as_ref()?)?
+                        .duration_magnitude
+                }
+                _ => {
+                    let attribute = key.strip_prefix("cost.")?;
+                    let cost = effects.get(self.cost_effect.as_ref()?)?;
+                    let cost_spec = GameplayEffectSpec::new(cost.id.clone(), Entity::PLACEHOLDER)
+                        .with_level(spec.level);
+                    let mut total = None;
+                    for modifier in &cost.modifiers {
+                        if modifier.attribute_name.as_ref() == attribute {
+                            let magnitude =
+                                modifier_display_magnitude(&modifier.magnitude, &cost_spec)?;
+                            *total.get_or_insert(0.0) += magnitude;
+                        }
+                    }
+                    total?.abs()
+                }
+            };
+            Some(format_number(value))
+        }))
+    }
+
     /// Sets whether instances block other abilities by default.
     pub fn with_blocks_other_abilities(mut self, blocks: bool) -> Self {
         self.default_blocks_other_abilities = blocks;
@@ -189,6 +450,12 @@ impl AbilityDefinition {
         self
     }
 
+    /// Sets the filter applied to this ability's target data.
+    pub fn with_target_filter(mut self, filter: TargetFilter) -> Self {
+        self.target_filter = filter;
+        self
+    }
+
     /// Adds a trigger to this ability.
     ///
     /// When the ability is granted, it will automatically activate when the
@@ -314,9 +581,26 @@ impl AbilityDefinition {
 }
 
 /// Resource that stores all ability definitions.
+///
+/// Definitions are stored behind an [`Arc`], so [`get_shared`](Self::get_shared)
+/// hands out a cheap handle that outlives the borrow of the registry.
+///
+/// The registry also maps cooldown categories to their shared cooldown
+/// effect. Definitions in a registered category get that effect as their
+/// `cooldown_effect`, whether they are registered before or after the
+/// category, so committing any of them puts them all on cooldown.
+///
+/// # Example
+/// ```ignore
+/// abilities.register_cooldown_category("Potion", "potion_cooldown");
+/// abilities.register(AbilityDefinition::new("health_potion").with_cooldown_category("Potion"));
+/// abilities.register(AbilityDefinition::new("mana_potion").with_cooldown_category("Potion"));
+/// ```
 #[derive(Resource, Default)]
 pub struct AbilityRegistry {
-    pub definitions: std::collections::HashMap<Atom, AbilityDefinition>,
+    pub definitions: std::collections::HashMap<Atom, Arc<AbilityDefinition>>,
+    ids: IdTable<AbilityId>,
+    cooldown_categories: std::collections::HashMap<Atom, Atom>,
 }
 
 impl AbilityRegistry {
@@ -324,12 +608,95 @@ impl AbilityRegistry {
         Self::default()
     }
 
-    pub fn register(&mut self, definition: AbilityDefinition) {
+    /// Registers an ability definition, taking either an owned definition or
+    /// one already shared through an [`Arc`].
+    pub fn register(&mut self, definition: impl Into<Arc<AbilityDefinition>>) {
+        let mut definition = definition.into();
+        #[cfg(feature = "scripting")]
+        if let Some(script) = definition.script.clone() {
+            Arc::make_mut(&mut definition).behavior = Some(Arc::new(
+                crate::scripting::ScriptedAbilityBehavior::new(script),
+            ));
+        }
+        if let Some(effect_id) = definition
+            .cooldown_category
+            .as_ref()
+            .and_then(|category| self.cooldown_categories.get(category))
+            && definition.cooldown_effect.as_ref() != Some(effect_id)
+        {
+            Arc::make_mut(&mut definition).cooldown_effect = Some(effect_id.clone());
+        }
+        self.ids.intern(definition.id.clone());
         self.definitions.insert(definition.id.clone(), definition);
     }
 
+    /// Registers the cooldown effect shared by the abilities of `category`,
+    /// and gives it to the ones already registered.
+    pub fn register_cooldown_category(
+        &mut self,
+        category: impl Into<Atom>,
+        effect_id: impl Into<Atom>,
+    ) {
+        let category = category.into();
+        let effect_id = effect_id.into();
+        for definition in self.definitions.values_mut() {
+            if definition.cooldown_category.as_ref() == Some(&category)
+                && definition.cooldown_effect.as_ref() != Some(&effect_id)
+            {
+                Arc::make_mut(definition).cooldown_effect = Some(effect_id.clone());
+            }
+        }
+        self.cooldown_categories.insert(category, effect_id);
+    }
+
+    /// Returns the shared cooldown effect of `category`.
+    pub fn cooldown_category(&self, category: &Atom) -> Option<&Atom> {
+        self.cooldown_categories.get(category)
+    }
+
+    /// Returns the abilities in `category`.
+    pub fn abilities_in_cooldown_category<'a>(
+        &'a self,
+        category: &'a Atom,
+    ) -> impl Iterator<Item = &'a AbilityDefinition> {
+        self.definitions
+            .values()
+            .map(Arc::as_ref)
+            .filter(move |definition| definition.cooldown_category.as_ref() == Some(category))
+    }
+
     pub fn get(&self, id: impl Into<Atom>) -> Option<&AbilityDefinition> {
-        self.definitions.get(&id.into())
+        self.definitions.get(&id.into()).map(Arc::as_ref)
+    }
+
+    /// Gets a shared handle to an ability definition.
+    pub fn get_shared(&self, id: impl Into<Atom>) -> Option<Arc<AbilityDefinition>> {
+        self.definitions.get(&id.into()).cloned()
+    }
+
+    /// Gets an ability definition by ID, or an error naming the missing ID.
+    pub fn try_get(&self, id: impl Into<Atom>) -> GasResult<&AbilityDefinition> {
+        let ability_id = id.into();
+        self.definitions
+            .get(&ability_id)
+            .map(Arc::as_ref)
+            .ok_or(GasError::AbilityDefinitionNotFound { ability_id })
+    }
+
+    /// Returns the interned ID of a registered ability, or `None` if the
+    /// name was never registered.
+    pub fn id(&self, name: impl Into<Atom>) -> Option<AbilityId> {
+        self.ids.get(name)
+    }
+
+    /// Returns the name of an interned ability ID.
+    pub fn name(&self, id: AbilityId) -> Option<&Atom> {
+        self.ids.name(id)
+    }
+
+    /// Gets an ability definition by interned ID.
+    pub fn get_by_id(&self, id: AbilityId) -> Option<&AbilityDefinition> {
+        self.definitions.get(self.ids.name(id)?).map(Arc::as_ref)
     }
 }
 
@@ -379,4 +746,25 @@ mod tests {
         assert!(registry.get("test").is_some());
         assert!(registry.get("nonexistent").is_none());
     }
+
+    #[test]
+    fn test_cooldown_category_resolves_in_any_order() {
+        let mut registry = AbilityRegistry::new();
+        registry.register(AbilityDefinition::new("health_potion").with_cooldown_category("Potion"));
+        registry.register_cooldown_category("Potion", "potion_cooldown");
+        registry.register(AbilityDefinition::new("mana_potion").with_cooldown_category("Potion"));
+
+        for ability_id in ["health_potion", "mana_potion"] {
+            assert_eq!(
+                registry.get(ability_id).unwrap().cooldown_effect,
+                Some(Atom::from("potion_cooldown"))
+            );
+        }
+        assert_eq!(
+            registry
+                .abilities_in_cooldown_category(&"Potion".into())
+                .count(),
+            2
+        );
+    }
 }
@@ -0,0 +1,114 @@
+//! `bevy_enhanced_input` integration.
+//!
+//! [`EnhancedInputAdapter`] reads the actions of an input context and reports
+//! them as [`AbilityInputEvent`]s of the context entity. An input is pressed
+//! when its action starts firing, held while it keeps firing and released
+//! when it stops, so the action's conditions decide when it counts as pressed
+//! (e.g. only after a `Hold`), `WhileHeld` channels end when the action
+//! completes or is cancelled, and `Toggle` abilities flip on each new fire.
+//!
+//! # Example
+//!
+//! ```ignore
+//! #[derive(Component)]
+//! struct Player;
+//!
+//! #[derive(InputAction)]
+//! #[action_output(bool)]
+//! struct CastFireball;
+//!
+//! app.add_input_context::<Player>().add_ability_input_adapter(
+//!     EnhancedInputAdapter::<Player>::new().bind::<CastFireball>(FIREBALL_INPUT),
+//! );
+//!
+//! commands.spawn((
+//!     Player,
+//!     actions!(Player[(Action::<CastFireball>::new(), bindings![KeyCode::KeyQ])]),
+//! ));
+//! ```
+
+use super::input::{AbilityInputAdapter, AbilityInputEvent};
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::{Action, ActionOf, InputAction, TriggerState};
+use std::marker::PhantomData;
+
+/// Collects the firing actions of one action type as `(action, context)`.
+type FiringActions = fn(&World, &mut Vec<(Entity, Entity)>);
+
+/// Maps `bevy_enhanced_input` actions of context `C` to ability input ids of
+/// the context entity.
+pub struct EnhancedInputAdapter<C: Component> {
+    /// Action readers and the input ids their actions press.
+    bindings: Vec<(FiringActions, i32)>,
+    /// Actions firing at the last poll, with their owner and input id.
+    firing: Vec<(Entity, Entity, i32)>,
+    marker: PhantomData<fn() -> C>,
+}
+
+impl<C: Component> Default for EnhancedInputAdapter<C> {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+            firing: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C: Component> EnhancedInputAdapter<C> {
+    /// Creates an adapter without bindings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds action `A` to `input_id`.
+    pub fn bind<A: InputAction>(mut self, input_id: i32) -> Self {
+        self.bindings.push((firing_actions::<C, A>, input_id));
+        self
+    }
+}
+
+fn firing_actions<C: Component, A: InputAction>(
+    world: &World,
+    actions: &mut Vec<(Entity, Entity)>,
+) {
+    let Some(mut query) =
+        world.try_query_filtered::<(Entity, &ActionOf<C>, &TriggerState), With<Action<A>>>()
+    else {
+        return;
+    };
+    actions.extend(
+        query
+            .iter(world)
+            .filter(|(_, _, state)| **state == TriggerState::Fired)
+            .map(|(action, context, _)| (action, **context)),
+    );
+}
+
+impl<C: Component> AbilityInputAdapter for EnhancedInputAdapter<C> {
+    fn poll(&mut self, world: &World, inputs: &mut Vec<AbilityInputEvent>) {
+        let mut firing = Vec::new();
+        let mut actions = Vec::new();
+        for &(firing_actions, input_id) in &self.bindings {
+            actions.clear();
+            firing_actions(world, &mut actions);
+            firing.extend(
+                actions
+                    .iter()
+                    .map(|&(action, owner)| (action, owner, input_id)),
+            );
+        }
+
+        for &(action, owner, input_id) in &self.firing {
+            if !firing.iter().any(|&(fired, ..)| fired == action) {
+                inputs.push(AbilityInputEvent::released(owner, input_id));
+            }
+        }
+        for &(action, owner, input_id) in &firing {
+            if !self.firing.iter().any(|&(fired, ..)| fired == action) {
+                inputs.push(AbilityInputEvent::pressed(owner, input_id));
+            }
+        }
+        self.firing = firing;
+    }
+}
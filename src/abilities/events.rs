@@ -1,10 +1,14 @@
 //! Gameplay event system for ability triggers.
 //!
 //! Provides a generic event system that can trigger abilities.
+//! [`GameplayEvent`] is broadcast to every listener, while
+//! [`SendGameplayEventEvent`] is delivered to one entity: its event-triggered
+//! abilities, its `WaitGameplayEventTask`s and any observers on it.
 
 use bevy::prelude::*;
 use bevy_gameplay_tag::gameplay_tag::GameplayTag;
 
+use super::activation_info::GameplayEventData;
 use super::target_data::GameplayAbilityTargetData;
 
 /// A gameplay event that can trigger abilities.
@@ -61,3 +65,57 @@ impl GameplayEvent {
         self
     }
 }
+
+/// Sends a gameplay event with a payload to one entity.
+///
+/// Abilities owned by `target` with a matching `GameplayEvent` trigger are
+/// activated with the payload in their `AbilityActivationInfo`, and running
+/// `WaitGameplayEventTask`s of its abilities complete with it. Observers on
+/// `target` receive the event too. This is UE GAS's `HandleGameplayEvent`.
+///
+/// # Example
+///
+/// ```ignore
+/// commands.trigger(
+///     SendGameplayEventEvent::new(target, GameplayTag::new("Event.Hit")).with_payload(
+///         GameplayEventData::new(attacker)
+///             .with_magnitude(25.0)
+///             .with_data(HitInfo { critical: true }),
+///     ),
+/// );
+/// ```
+#[derive(EntityEvent, Debug, Clone)]
+pub struct SendGameplayEventEvent {
+    /// The entity receiving the event.
+    #[event_target]
+    pub target: Entity,
+    /// The tag identifying this event type.
+    pub tag: GameplayTag,
+    /// Data passed to the receivers.
+    pub payload: GameplayEventData,
+}
+
+impl SendGameplayEventEvent {
+    /// Creates an event whose payload names `target` as its instigator.
+    pub fn new(target: Entity, tag: GameplayTag) -> Self {
+        Self {
+            target,
+            payload: GameplayEventData::new(target).with_target(target),
+            tag,
+        }
+    }
+
+    /// Sets the payload.
+    pub fn with_payload(mut self, payload: GameplayEventData) -> Self {
+        self.payload = payload;
+        self
+    }
+
+    /// Returns the payload with its tag and target filled in.
+    pub fn routed_payload(&self) -> GameplayEventData {
+        let mut payload = self.payload.clone();
+        payload.event_tag = Some(self.tag.get_tag_name().to_string());
+        payload.target.get_or_insert(self.target);
+        payload
+    }
+}
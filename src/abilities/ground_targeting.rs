@@ -0,0 +1,353 @@
+//! Ground-targeted ability targeting.
+//!
+//! [`WaitGroundTargetTask`] shows a reticle that follows the owner's
+//! [`GroundAim`] on the ground, clamped to a maximum range from the owner.
+//! `InputAction::Confirm` completes the task with the reticle point as target
+//! data; `InputAction::Cancel` cancels it. Both arrive as [`InputPressedEvent`]s.
+//!
+//! The reticle entity only carries a [`GroundTargetReticle`] and a `Transform`;
+//! add visuals with an `On<Add, GroundTargetReticle>` observer.
+
+use super::target_data::GameplayAbilityTargetData;
+use super::tasks::{
+    AbilityTask, InputAction, InputPressedEvent, TaskCancelledEvent, TaskCompletedEvent, TaskState,
+};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+/// Where an entity is aiming on the ground.
+///
+/// Game input writes this on the ability owner. Mouse games can add
+/// [`CursorGroundAim`] to have it filled from the cursor; physics games can
+/// trace against their colliders and write the hit as a `SurfacePoint`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default)]
+pub enum GroundAim {
+    /// No aim input.
+    #[default]
+    None,
+    /// Aim ray, intersected with the task's ground plane.
+    Ray(Ray3d),
+    /// Aim point already on a surface.
+    SurfacePoint(Vec3),
+}
+
+/// Fills the entity's [`GroundAim`] from the primary window cursor through
+/// the first active camera.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct CursorGroundAim;
+
+/// Marks a reticle spawned by a [`WaitGroundTargetTask`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct GroundTargetReticle {
+    /// The task showing this reticle.
+    pub task: Entity,
+}
+
+/// WaitGroundTarget task - waits for the player to pick a point on the ground.
+///
+/// Completes on confirm with `target_data` holding the point as end point and
+/// the owner position as origin. Confirm is ignored until the owner has aimed.
+#[derive(Component, Debug, Clone)]
+pub struct WaitGroundTargetTask {
+    /// Maximum horizontal distance from the owner.
+    pub max_range: f32,
+    /// Height of the ground plane used for `GroundAim::Ray`.
+    pub ground_height: f32,
+    /// Current reticle point, once the owner has aimed.
+    pub point: Option<Vec3>,
+    /// The reticle entity, once spawned.
+    pub reticle: Option<Entity>,
+    /// The chosen target, set on confirm.
+    pub target_data: Option<GameplayAbilityTargetData>,
+}
+
+impl WaitGroundTargetTask {
+    /// Create a task clamping the point to `max_range` on the y = 0 plane.
+    pub fn new(max_range: f32) -> Self {
+        Self {
+            max_range,
+            ground_height: 0.0,
+            point: None,
+            reticle: None,
+            target_data: None,
+        }
+    }
+
+    /// Set the height of the ground plane.
+    pub fn with_ground_height(mut self, ground_height: f32) -> Self {
+        self.ground_height = ground_height;
+        self
+    }
+
+    /// Resolve an aim to a ground point clamped to range around `owner`.
+    pub fn resolve_point(&self, aim: &GroundAim, owner: Vec3) -> Option<Vec3> {
+        let point = match *aim {
+            GroundAim::None => return None,
+            GroundAim::SurfacePoint(point) => point,
+            GroundAim::Ray(ray) => {
+                let plane_origin = Vec3::new(0.0, self.ground_height, 0.0);
+                let distance = ray.intersect_plane(plane_origin, InfinitePlane3d::new(Vec3::Y))?;
+                ray.get_point(distance)
+            }
+        };
+
+        let offset = Vec2::new(point.x - owner.x, point.z - owner.z);
+        if offset.length() <= self.max_range {
+            return Some(point);
+        }
+        let clamped = owner.xz() + offset.normalize() * self.max_range;
+        Some(Vec3::new(clamped.x, point.y, clamped.y))
+    }
+}
+
+/// System that writes cursor aim rays for [`CursorGroundAim`] entities.
+pub fn update_cursor_ground_aim_system(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut aims: Query<&mut GroundAim, With<CursorGroundAim>>,
+) {
+    if aims.is_empty() {
+        return;
+    }
+
+    let ray = windows
+        .single()
+        .ok()
+        .and_then(Window::cursor_position)
+        .and_then(|cursor| {
+            let (camera, transform) = cameras.iter().find(|(camera, _)| camera.is_active)?;
+            camera.viewport_to_world(transform, cursor).ok()
+        });
+    let aim = ray.map_or(GroundAim::None, GroundAim::Ray);
+
+    for mut ground_aim in aims.iter_mut() {
+        ground_aim.set_if_neq(aim);
+    }
+}
+
+/// System that moves ground target reticles to the owners' aim.
+pub fn update_ground_target_reticles_system(
+    mut commands: Commands,
+    mut tasks: Query<(Entity, &AbilityTask, &mut WaitGroundTargetTask, &TaskState)>,
+    owners: Query<(&GlobalTransform, &GroundAim)>,
+    mut reticles: Query<&mut Transform, With<GroundTargetReticle>>,
+) {
+    for (task_entity, ability_task, mut task, state) in tasks.iter_mut() {
+        if *state != TaskState::Running {
+            continue;
+        }
+        let Ok((owner_transform, aim)) = owners.get(ability_task.owner) else {
+            continue;
+        };
+        let Some(point) = task.resolve_point(aim, owner_transform.translation()) else {
+            continue;
+        };
+        task.point = Some(point);
+
+        match task.reticle {
+            Some(reticle) => {
+                if let Ok(mut transform) = reticles.get_mut(reticle) {
+                    transform.translation = point;
+                }
+            }
+            None => {
+                let reticle = commands
+                    .spawn((
+                        GroundTargetReticle { task: task_entity },
+                        Transform::from_translation(point),
+                    ))
+                    .id();
+                task.reticle = Some(reticle);
+            }
+        }
+    }
+}
+
+/// Observer that confirms or cancels ground target tasks on input.
+pub fn handle_input_for_ground_target_tasks(
+    trigger: On<InputPressedEvent>,
+    mut commands: Commands,
+    mut tasks: Query<(
+        Entity,
+        &AbilityTask,
+        &mut WaitGroundTargetTask,
+        &mut TaskState,
+    )>,
+    owners: Query<&GlobalTransform>,
+) {
+    let event = trigger.event();
+
+    for (task_entity, ability_task, mut task, mut state) in tasks.iter_mut() {
+        if *state != TaskState::Running || ability_task.owner != event.entity {
+            continue;
+        }
+
+        match event.action {
+            InputAction::Confirm => {
+                let Some(point) = task.point else {
+                    continue;
+                };
+                let mut target_data = GameplayAbilityTargetData::from_location(point);
+                if let Ok(owner_transform) = owners.get(ability_task.owner) {
+                    target_data = target_data
+                        .with_origin(Transform::from_translation(owner_transform.translation()));
+                }
+                task.target_data = Some(target_data);
+                *state = TaskState::Completed;
+                commands.trigger(TaskCompletedEvent {
+                    task: task_entity,
+                    ability_instance: ability_task.ability_instance,
+                    ability_spec: ability_task.ability_spec,
+                    owner: ability_task.owner,
+                });
+            }
+            InputAction::Cancel => {
+                *state = TaskState::Cancelled;
+                commands.trigger(TaskCancelledEvent {
+                    task: task_entity,
+                    ability_instance: ability_task.ability_instance,
+                    ability_spec: ability_task.ability_spec,
+                    owner: ability_task.owner,
+                });
+            }
+            InputAction::Custom(_) => {}
+        }
+    }
+}
+
+/// Observer that despawns the reticle when its task goes away.
+pub fn on_ground_target_task_removed(
+    trigger: On<Remove, WaitGroundTargetTask>,
+    mut commands: Commands,
+    tasks: Query<&WaitGroundTargetTask>,
+) {
+    if let Ok(task) = tasks.get(trigger.entity)
+        && let Some(reticle) = task.reticle
+    {
+        commands.entity(reticle).try_despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abilities::tasks::cleanup_finished_tasks_system;
+
+    fn setup(aim: GroundAim) -> (App, Entity, Entity) {
+        let mut app = App::new();
+        app.add_systems(
+            Update,
+            (
+                update_ground_target_reticles_system,
+                cleanup_finished_tasks_system,
+            )
+                .chain(),
+        )
+        .add_observer(handle_input_for_ground_target_tasks)
+        .add_observer(on_ground_target_task_removed);
+
+        let owner = app
+            .world_mut()
+            .spawn((
+                GlobalTransform::from_translation(Vec3::new(1.0, 0.0, 1.0)),
+                aim,
+            ))
+            .id();
+        let spec = app.world_mut().spawn_empty().id();
+        let task = app
+            .world_mut()
+            .spawn((
+                AbilityTask {
+                    ability_instance: None,
+                    ability_spec: spec,
+                    owner,
+                },
+                WaitGroundTargetTask::new(5.0),
+                TaskState::Running,
+            ))
+            .id();
+        (app, owner, task)
+    }
+
+    fn reticles(app: &mut App) -> Vec<Vec3> {
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&Transform, With<GroundTargetReticle>>();
+        query.iter(app.world()).map(|t| t.translation).collect()
+    }
+
+    #[test]
+    fn test_ray_aim_is_projected_and_clamped() {
+        let task = WaitGroundTargetTask::new(5.0).with_ground_height(2.0);
+        let ray = Ray3d::new(Vec3::new(3.0, 10.0, 0.0), Dir3::NEG_Y);
+        assert_eq!(
+            task.resolve_point(&GroundAim::Ray(ray), Vec3::ZERO),
+            Some(Vec3::new(3.0, 2.0, 0.0))
+        );
+        assert_eq!(
+            task.resolve_point(
+                &GroundAim::SurfacePoint(Vec3::new(0.0, 1.0, 20.0)),
+                Vec3::ZERO
+            ),
+            Some(Vec3::new(0.0, 1.0, 5.0))
+        );
+        assert_eq!(task.resolve_point(&GroundAim::None, Vec3::ZERO), None);
+    }
+
+    #[test]
+    fn test_confirm_delivers_point_and_despawns_reticle() {
+        let (mut app, owner, task) = setup(GroundAim::SurfacePoint(Vec3::new(1.0, 0.0, 3.0)));
+        app.update();
+        assert_eq!(reticles(&mut app), vec![Vec3::new(1.0, 0.0, 3.0)]);
+
+        app.world_mut()
+            .entity_mut(owner)
+            .insert(GroundAim::SurfacePoint(Vec3::new(2.0, 0.0, 1.0)));
+        app.update();
+        assert_eq!(reticles(&mut app), vec![Vec3::new(2.0, 0.0, 1.0)]);
+
+        app.world_mut().trigger(InputPressedEvent {
+            entity: owner,
+            action: InputAction::Confirm,
+        });
+        let ground_task = app.world().get::<WaitGroundTargetTask>(task).unwrap();
+        let target_data = ground_task.target_data.clone().unwrap();
+        assert_eq!(
+            target_data.end_point_location(),
+            Some(Vec3::new(2.0, 0.0, 1.0))
+        );
+        assert_eq!(
+            target_data.origin.unwrap().translation,
+            Vec3::new(1.0, 0.0, 1.0)
+        );
+
+        app.update();
+        assert!(app.world().get_entity(task).is_err());
+        assert!(reticles(&mut app).is_empty());
+    }
+
+    #[test]
+    fn test_cancel_and_unaimed_confirm() {
+        let (mut app, owner, task) = setup(GroundAim::None);
+        app.update();
+        assert!(reticles(&mut app).is_empty());
+
+        app.world_mut().trigger(InputPressedEvent {
+            entity: owner,
+            action: InputAction::Confirm,
+        });
+        assert_eq!(
+            *app.world().get::<TaskState>(task).unwrap(),
+            TaskState::Running
+        );
+
+        app.world_mut().trigger(InputPressedEvent {
+            entity: owner,
+            action: InputAction::Cancel,
+        });
+        assert_eq!(
+            *app.world().get::<TaskState>(task).unwrap(),
+            TaskState::Cancelled
+        );
+    }
+}
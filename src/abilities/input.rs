@@ -0,0 +1,292 @@
+//! Routing of player input to granted abilities.
+//!
+//! Input arrives as [`AbilityInputEvent`]s naming an owner and an input id.
+//! Every ability spec of the owner bound to that id (see
+//! [`AbilitySpec::with_input_id`]) reacts according to its definition's
+//! [`AbilityInputPolicy`]:
+//!
+//! | Policy | Press | Release |
+//! |---|---|---|
+//! | `Pressed` | Activates | — |
+//! | `WhileHeld` | Activates | Ends the ability (channels) |
+//! | `Toggle` | Activates, or ends it if active | — |
+//!
+//! Presses and releases reaching an already active ability are also
+//! triggered as [`AbilityInputPressedEvent`] and [`AbilityInputReleasedEvent`],
+//! the latter with how long the input was held, and presses complete
+//! [`WaitInputPressTask`](super::tasks::WaitInputPressTask)s waiting for
+//! `InputAction::Custom(input_id)`.
+//!
+//! Input crates plug in through an [`AbilityInputAdapter`] added with
+//! [`AbilityInputAppExt::add_ability_input_adapter`]; adapters are polled in
+//! [`GasSystemSet::Input`](crate::core::GasSystemSet::Input). [`ButtonInputAdapter`] maps Bevy's
+//! `ButtonInput<KeyCode>`/`ButtonInput<MouseButton>` directly, and with the
+//! `enhanced_input` feature `EnhancedInputAdapter` maps `bevy_enhanced_input`
+//! actions. Event-driven crates can skip the adapter and trigger
+//! [`AbilityInputEvent`]s from their own observers.
+//!
+//! # Example
+//!
+//! Forwarding `bevy_enhanced_input` actions from observers instead of the
+//! adapter:
+//!
+//! ```ignore
+//! fn fireball_pressed(ev: On<Start<CastFireball>>, mut commands: Commands) {
+//!     commands.trigger(AbilityInputEvent::pressed(ev.context, FIREBALL_INPUT));
+//! }
+//!
+//! fn fireball_released(ev: On<Complete<CastFireball>>, mut commands: Commands) {
+//!     commands.trigger(AbilityInputEvent::released(ev.context, FIREBALL_INPUT));
+//! }
+//! ```
+
+use super::components::{AbilityActiveState, AbilitySpec, OwnedAbilities};
+use super::definition::AbilityRegistry;
+use super::systems::{EndAbilityEvent, TryActivateAbilityEvent};
+use super::tasks::{InputAction, InputPressedEvent};
+use bevy::prelude::*;
+use std::hash::Hash;
+
+/// How an ability reacts to its bound input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AbilityInputPolicy {
+    /// Pressing activates the ability (default).
+    #[default]
+    Pressed,
+    /// Pressing activates the ability and releasing ends it.
+    WhileHeld,
+    /// Pressing activates the ability, or ends it while it is active.
+    Toggle,
+}
+
+/// Whether an input went down or up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AbilityInputPhase {
+    /// The input was pressed.
+    Pressed,
+    /// The input was released.
+    Released,
+}
+
+/// Event reporting that an ability input of an owner was pressed or released.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbilityInputEvent {
+    /// The entity whose abilities the input drives.
+    pub owner: Entity,
+    /// The input id abilities are bound to.
+    pub input_id: i32,
+    /// Whether the input was pressed or released.
+    pub phase: AbilityInputPhase,
+}
+
+impl AbilityInputEvent {
+    /// Reports that `input_id` was pressed.
+    pub fn pressed(owner: Entity, input_id: i32) -> Self {
+        Self {
+            owner,
+            input_id,
+            phase: AbilityInputPhase::Pressed,
+        }
+    }
+
+    /// Reports that `input_id` was released.
+    pub fn released(owner: Entity, input_id: i32) -> Self {
+        Self {
+            owner,
+            input_id,
+            phase: AbilityInputPhase::Released,
+        }
+    }
+}
+
+/// Event triggered when the input of an already active ability is pressed.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct AbilityInputPressedEvent {
+    /// The ability spec entity.
+    pub ability_spec: Entity,
+    /// The owner entity.
+    pub owner: Entity,
+}
+
+/// Event triggered when the input of an active ability is released.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct AbilityInputReleasedEvent {
+    /// The ability spec entity.
+    pub ability_spec: Entity,
+    /// The owner entity.
+    pub owner: Entity,
+    /// How long the input was held, in seconds.
+    pub held_secs: f32,
+}
+
+/// Marks an ability spec whose input is held down.
+#[derive(Component, Debug, Clone, Copy)]
+#[component(storage = "SparseSet")]
+pub struct AbilityInputHeld {
+    /// Elapsed time when the input was pressed, in seconds.
+    pub pressed_at: f64,
+}
+
+impl AbilityInputHeld {
+    /// Returns how long the input has been held at `now`.
+    pub fn held_secs(&self, now: f64) -> f32 {
+        (now - self.pressed_at) as f32
+    }
+}
+
+/// Reads an input source and reports ability input changes.
+pub trait AbilityInputAdapter: Send + Sync + 'static {
+    /// Appends the inputs pressed or released since the last poll.
+    fn poll(&mut self, world: &World, inputs: &mut Vec<AbilityInputEvent>);
+}
+
+/// The adapters polled for ability input each frame.
+#[derive(Resource, Default)]
+pub struct AbilityInputAdapters(pub Vec<Box<dyn AbilityInputAdapter>>);
+
+/// Extension trait for registering ability input adapters.
+pub trait AbilityInputAppExt {
+    /// Polls `adapter` for ability input each frame.
+    fn add_ability_input_adapter(&mut self, adapter: impl AbilityInputAdapter) -> &mut Self;
+}
+
+impl AbilityInputAppExt for App {
+    fn add_ability_input_adapter(&mut self, adapter: impl AbilityInputAdapter) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<AbilityInputAdapters>()
+            .0
+            .push(Box::new(adapter));
+        self
+    }
+}
+
+/// Maps buttons of a Bevy `ButtonInput<T>` resource to ability input ids of
+/// one owner.
+pub struct ButtonInputAdapter<T: Copy + Eq + Hash + Send + Sync + 'static> {
+    /// The entity whose abilities the buttons drive.
+    pub owner: Entity,
+    /// Buttons and the input ids they press.
+    pub bindings: Vec<(T, i32)>,
+}
+
+impl<T: Copy + Eq + Hash + Send + Sync + 'static> ButtonInputAdapter<T> {
+    /// Creates an adapter without bindings for `owner`.
+    pub fn new(owner: Entity) -> Self {
+        Self {
+            owner,
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Binds `button` to `input_id`.
+    pub fn bind(mut self, button: T, input_id: i32) -> Self {
+        self.bindings.push((button, input_id));
+        self
+    }
+}
+
+impl<T: Copy + Eq + Hash + Send + Sync + 'static> AbilityInputAdapter for ButtonInputAdapter<T> {
+    fn poll(&mut self, world: &World, inputs: &mut Vec<AbilityInputEvent>) {
+        let Some(buttons) = world.get_resource::<ButtonInput<T>>() else {
+            return;
+        };
+        for &(button, input_id) in &self.bindings {
+            if buttons.just_pressed(button) {
+                inputs.push(AbilityInputEvent::pressed(self.owner, input_id));
+            }
+            if buttons.just_released(button) {
+                inputs.push(AbilityInputEvent::released(self.owner, input_id));
+            }
+        }
+    }
+}
+
+/// Polls every [`AbilityInputAdapter`] and triggers the inputs they report.
+pub fn poll_ability_input_adapters_system(world: &mut World) {
+    let mut inputs = Vec::new();
+    world.resource_scope(|world, mut adapters: Mut<AbilityInputAdapters>| {
+        for adapter in &mut adapters.0 {
+            adapter.poll(world, &mut inputs);
+        }
+    });
+    for input in inputs {
+        world.trigger(input);
+    }
+}
+
+/// Observer routing an [`AbilityInputEvent`] to the owner's bound abilities.
+pub fn on_ability_input(
+    ev: On<AbilityInputEvent>,
+    mut commands: Commands,
+    registry: Res<AbilityRegistry>,
+    time: Res<Time>,
+    owned_abilities: Query<&OwnedAbilities>,
+    specs: Query<(
+        &AbilitySpec,
+        Option<&AbilityActiveState>,
+        Option<&AbilityInputHeld>,
+    )>,
+) {
+    let event = ev.event();
+    let owner = event.owner;
+    let now = time.elapsed_secs_f64();
+    let bound = owned_abilities
+        .get(owner)
+        .into_iter()
+        .flat_map(|abilities| abilities.iter())
+        .filter_map(|spec_entity| Some((spec_entity, specs.get(spec_entity).ok()?)))
+        .filter(|(_, (spec, _, _))| spec.input_id == Some(event.input_id));
+
+    for (spec_entity, (spec, active_state, held)) in bound {
+        let policy = registry
+            .get(&spec.definition_id)
+            .map(|definition| definition.input_policy)
+            .unwrap_or_default();
+        let active = active_state.is_some_and(|state| state.is_active);
+        let end = EndAbilityEvent {
+            instance: None,
+            ability_spec: spec_entity,
+            owner,
+        };
+
+        match event.phase {
+            AbilityInputPhase::Pressed => {
+                commands
+                    .entity(spec_entity)
+                    .insert(AbilityInputHeld { pressed_at: now });
+                if !active {
+                    commands.trigger(TryActivateAbilityEvent::new(spec_entity, owner));
+                    continue;
+                }
+                commands.trigger(AbilityInputPressedEvent {
+                    ability_spec: spec_entity,
+                    owner,
+                });
+                if let Ok(action) = u32::try_from(event.input_id) {
+                    commands.trigger(InputPressedEvent {
+                        entity: owner,
+                        action: InputAction::Custom(action),
+                    });
+                }
+                if policy == AbilityInputPolicy::Toggle {
+                    commands.trigger(end);
+                }
+            }
+            AbilityInputPhase::Released => {
+                commands.entity(spec_entity).remove::<AbilityInputHeld>();
+                if !active {
+                    continue;
+                }
+                commands.trigger(AbilityInputReleasedEvent {
+                    ability_spec: spec_entity,
+                    owner,
+                    held_secs: held.map_or(0.0, |held| held.held_secs(now)),
+                });
+                if policy == AbilityInputPolicy::WhileHeld {
+                    commands.trigger(end);
+                }
+            }
+        }
+    }
+}
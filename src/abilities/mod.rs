@@ -5,26 +5,74 @@
 
 pub mod activation_context;
 pub mod activation_info;
+#[cfg(feature = "ai")]
+pub mod ai;
+#[cfg(feature = "ability_assets")]
+pub mod asset;
+pub mod charge;
 pub mod components;
+pub mod cooldown;
+pub mod cost_over_time;
 pub mod definition;
+#[cfg(feature = "enhanced_input")]
+pub mod enhanced_input;
 pub mod events;
+pub mod ground_targeting;
+pub mod input;
 pub mod plugin;
+pub mod prediction;
+pub mod projectile;
+pub mod spender;
 pub mod systems;
+pub mod tag_relationships;
 pub mod target_data;
+pub mod target_filter;
+pub mod target_selection;
+pub mod targeting;
 pub mod tasks;
+pub mod trace;
+#[cfg(feature = "avian3d")]
+pub mod trace_avian;
+#[cfg(feature = "rapier3d")]
+pub mod trace_rapier;
 pub mod traits;
+pub mod transport;
 pub mod trigger_systems;
 pub mod triggers;
+pub mod usage;
+pub mod volume;
 
 pub use activation_context::*;
 pub use activation_info::*;
+#[cfg(feature = "ai")]
+pub use ai::*;
+#[cfg(feature = "ability_assets")]
+pub use asset::*;
+pub use charge::*;
 pub use components::*;
+pub use cooldown::*;
+pub use cost_over_time::*;
 pub use definition::*;
+#[cfg(feature = "enhanced_input")]
+pub use enhanced_input::*;
 pub use events::*;
+pub use ground_targeting::*;
+pub use input::*;
 pub use plugin::AbilityPlugin;
+pub use prediction::*;
+pub use projectile::*;
+pub use spender::*;
 pub use systems::*;
+pub use tag_relationships::*;
 pub use target_data::*;
+pub use target_filter::*;
+pub use target_selection::*;
+pub use targeting::*;
 pub use tasks::*;
+pub use trace::*;
 pub use traits::*;
+pub use transport::*;
 pub use trigger_systems::*;
 pub use triggers::*;
+pub use usage::*;
+pub use volume::*;
@@ -2,11 +2,29 @@
 //!
 //! This plugin registers all ability-related systems and events.
 
+use super::charge;
+use super::components::{AbilityOwner, AbilitySpec, OwnedAbilities};
+use super::cooldown::{self, CooldownEventSettings};
+use super::cost_over_time;
 use super::definition::AbilityRegistry;
+use super::ground_targeting;
+use super::input::{self, AbilityInputAdapters};
+use super::prediction::{
+    self, AbilityPredictions, NetRole, PredictionKeyGenerator, ScopedPredictionKey,
+};
+use super::projectile;
+use super::spender;
 use super::systems::*;
+use super::tag_relationships::{self, AbilityTagRelationshipMapping};
 use super::tasks;
+use super::transport;
 use super::trigger_systems::*;
-use crate::core::system_sets::GasSystemSet;
+use super::usage;
+use super::volume;
+use crate::core::handles::track_handle_generations;
+use crate::core::system_sets::{EffectSystemSet, GasSystemSet, configure_gas_system_sets};
+use crate::core::tag_changes::emit_owner_tags_changed_system;
+use crate::core::timestep::{GasTickMode, GlobalGasTimeScale};
 use crate::effects::definition::GameplayEffectRegistry;
 use bevy::prelude::*;
 
@@ -15,10 +33,24 @@ pub struct AbilityPlugin;
 
 impl Plugin for AbilityPlugin {
     fn build(&self, app: &mut App) {
+        let simulation_schedule = GasTickMode::of(app).simulation_schedule();
+        configure_gas_system_sets(app);
+        track_handle_generations::<AbilitySpec>(app);
         app
             // Register resources
             .init_resource::<AbilityRegistry>()
+            .init_resource::<GlobalGasTimeScale>()
             .init_resource::<GameplayEffectRegistry>()
+            .init_resource::<NetRole>()
+            .init_resource::<PredictionKeyGenerator>()
+            .init_resource::<ScopedPredictionKey>()
+            .init_resource::<AbilityPredictions>()
+            .init_resource::<AbilityInputAdapters>()
+            .init_resource::<AbilityTagRelationshipMapping>()
+            // Register reflected components so granted abilities can be saved in scenes
+            .register_type::<AbilitySpec>()
+            .register_type::<AbilityOwner>()
+            .register_type::<OwnedAbilities>()
             // Register observers
             .add_observer(on_try_activate_ability)
             .add_observer(on_commit_ability)
@@ -27,9 +59,29 @@ impl Plugin for AbilityPlugin {
             .add_observer(on_instance_removed)
             .add_observer(on_instance_removed)
             .add_observer(handle_gameplay_event_triggers_system)
+            .add_observer(on_send_gameplay_event)
+            .add_observer(tag_relationships::on_ability_activated_cancel_abilities)
+            // Prediction observers
+            .add_observer(prediction::record_ability_prediction)
+            .add_observer(prediction::record_predicted_changes)
+            .add_observer(prediction::on_ability_prediction_resolved)
+            // Request routing observers
+            .add_observer(transport::send_activation_request)
+            .add_observer(transport::on_client_activation_request)
+            .add_observer(transport::on_activation_response)
+            // Input routing
+            .add_observer(input::on_ability_input)
+            .add_systems(
+                simulation_schedule,
+                input::poll_ability_input_adapters_system.in_set(GasSystemSet::Input),
+            )
+            .add_systems(
+                simulation_schedule,
+                prediction::expire_stale_predictions_system.in_set(GasSystemSet::Abilities),
+            )
             // Activation systems: spawn instances, then call activate.
             .add_systems(
-                Update,
+                simulation_schedule,
                 (
                     spawn_pending_ability_instances_system,
                     call_activate_ability_system,
@@ -39,10 +91,12 @@ impl Plugin for AbilityPlugin {
             )
             // Task systems
             .add_systems(
-                Update,
+                simulation_schedule,
                 (
                     tasks::tick_wait_delay_tasks_system,
                     tasks::check_wait_target_data_tasks_system,
+                    ground_targeting::update_cursor_ground_aim_system,
+                    ground_targeting::update_ground_target_reticles_system,
                     tasks::cleanup_finished_tasks_system,
                 )
                     .chain()
@@ -50,14 +104,32 @@ impl Plugin for AbilityPlugin {
             )
             // Task observers
             .add_observer(tasks::handle_gameplay_event_for_tasks_system)
+            .add_observer(tasks::on_send_gameplay_event_for_tasks)
             .add_observer(tasks::handle_input_pressed_for_tasks_system)
             .add_observer(tasks::handle_overlap_for_tasks_system)
+            .add_observer(tasks::handle_animation_notify_for_tasks_system)
+            .add_observer(ground_targeting::handle_input_for_ground_target_tasks)
+            .add_observer(ground_targeting::on_ground_target_task_removed)
+            // Projectiles
+            .add_systems(
+                simulation_schedule,
+                projectile::update_projectiles_system.in_set(GasSystemSet::Abilities),
+            )
+            // Effect volumes, queued for the same frame's batched application
+            .add_systems(
+                simulation_schedule,
+                volume::update_effect_volumes_system
+                    .in_set(GasSystemSet::Effects)
+                    .before(EffectSystemSet::Apply),
+            )
+            .add_observer(volume::on_effect_volume_removed)
             // Trigger systems
+            .add_observer(handle_owned_tag_added_triggers_system)
+            .add_observer(handle_owned_tag_present_triggers_system)
             .add_systems(
-                Update,
+                simulation_schedule,
                 (
-                    handle_owned_tag_added_triggers_system,
-                    handle_owned_tag_present_triggers_system,
+                    emit_owner_tags_changed_system,
                     tasks::check_wait_attribute_change_tasks_system,
                     tasks::execute_apply_effect_to_target_data_tasks_system,
                 )
@@ -65,6 +137,41 @@ impl Plugin for AbilityPlugin {
             )
             .add_observer(tasks::on_effect_applied_for_tasks)
             .add_observer(tasks::on_effect_removed_for_tasks)
-            .add_observer(tasks::on_ability_instance_removed);
+            .add_observer(tasks::on_ability_instance_removed)
+            // Cooldown events
+            .init_resource::<CooldownEventSettings>()
+            .add_observer(cooldown::on_effect_applied_start_cooldowns)
+            .add_observer(cooldown::on_effect_removed_end_cooldowns)
+            .add_systems(
+                simulation_schedule,
+                cooldown::update_ability_cooldowns_system.in_set(GasSystemSet::Abilities),
+            )
+            // Usage statistics
+            .add_observer(usage::on_ability_activated_count_usage)
+            .add_observer(usage::on_damage_dealt_record_usage)
+            .add_observer(usage::on_healing_done_record_usage)
+            .add_systems(
+                simulation_schedule,
+                usage::accumulate_ability_active_time_system.in_set(GasSystemSet::Abilities),
+            )
+            // Costs over time
+            .add_observer(cost_over_time::on_ability_activated_apply_cost_over_time)
+            .add_observer(cost_over_time::on_cost_over_time_executed)
+            .add_systems(
+                simulation_schedule,
+                cost_over_time::remove_ended_cost_over_time_system.in_set(GasSystemSet::Abilities),
+            )
+            // Charge-up abilities
+            .add_observer(charge::on_ability_activated_start_charge)
+            .add_observer(charge::on_ability_input_released_release_charge)
+            .add_observer(charge::on_charge_task_removed)
+            .add_systems(
+                simulation_schedule,
+                charge::tick_charge_tasks_system
+                    .before(tasks::cleanup_finished_tasks_system)
+                    .in_set(GasSystemSet::Abilities),
+            )
+            // Spender abilities
+            .add_observer(spender::on_ability_activated_spend);
     }
 }
@@ -0,0 +1,375 @@
+//! Client-side prediction of ability activation.
+//!
+//! Each ability has a [`NetExecutionPolicy`]. While the [`NetRole`] resource is
+//! `Client`:
+//! - `LocalOnly` abilities activate locally and the server is never asked.
+//! - `LocalPredicted` abilities activate locally under a fresh
+//!   [`PredictionKey`] and ask the server to activate them too.
+//! - `ServerInitiated` and `ServerOnly` abilities only ask the server.
+//!
+//! Requests are [`AbilityActivationRequestEvent`]s, carried to the server by
+//! the installed transport (see [`super::transport`]). The server activates
+//! the ability under the same key and answers; on the client the answer
+//! triggers an [`AbilityPredictionResolvedEvent`].
+//!
+//! Effects applied while a predicted activation commits (costs, cooldowns)
+//! carry its key, on the client and on the server. When the server accepts,
+//! the predicted effects are replaced by the server's as they replicate. When
+//! it rejects, the client ends the ability (removing its activation tags),
+//! removes the predicted effects and the tags they granted, and undoes the
+//! base value changes its instant effects made. Changes made by anything else
+//! since the prediction, such as damage landing while the client waited,
+//! stand.
+//!
+//! A prediction the server has not answered within
+//! [`AbilityPredictions::expiry`] seconds is rolled back as if rejected, so a
+//! lost answer cannot leave a prediction pending forever; a late answer for it
+//! is ignored.
+//!
+//! Standalone games and servers activate every ability normally.
+
+use super::systems::EndAbilityEvent;
+use crate::attributes::AttributeData;
+use crate::core::OwnedTags;
+use crate::effects::{
+    ActiveGameplayEffect, AttributeKey, AttributeLookup, EffectGrantedTags,
+    GameplayEffectExecutedEvent, GameplayEffectRemovedEvent,
+};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_gameplay_tag::GameplayTagsManager;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use string_cache::DefaultAtom as Atom;
+
+pub use super::definition::NetExecutionPolicy;
+pub use crate::core::PredictionKey;
+
+/// This app's part in a networked game.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NetRole {
+    /// Not networked; everything runs locally.
+    #[default]
+    Standalone,
+    /// Connected to a server that owns gameplay state.
+    Client,
+    /// Owns gameplay state and answers client requests.
+    Server,
+}
+
+/// Hands out increasing [`PredictionKey`]s.
+#[derive(Resource, Debug, Default)]
+pub struct PredictionKeyGenerator(AtomicU32);
+
+impl PredictionKeyGenerator {
+    /// Returns a key not returned before.
+    pub fn next_key(&self) -> PredictionKey {
+        PredictionKey(self.0.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+}
+
+/// Key of the activation currently committing, if it was predicted.
+///
+/// Set around `AbilityBehavior::commit`, so effects applied by it can be
+/// stamped with the key.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct ScopedPredictionKey(pub Option<PredictionKey>);
+
+/// A predicted activation waiting for the server's answer.
+#[derive(Debug, Clone)]
+pub struct PredictedActivation {
+    /// The ability spec entity.
+    pub ability_spec: Entity,
+    /// The owner entity.
+    pub owner: Entity,
+    /// Base value changes made by instant effects applied under the key, as
+    /// (target, attribute, change).
+    pub changes: Vec<(Entity, Atom, f32)>,
+    /// Elapsed time in seconds when the prediction was made.
+    pub predicted_at: f32,
+}
+
+/// Client-side record of predicted activations, by key.
+#[derive(Resource, Debug)]
+pub struct AbilityPredictions {
+    pub pending: HashMap<PredictionKey, PredictedActivation>,
+    /// Seconds to wait for the server before rolling a prediction back.
+    pub expiry: f32,
+}
+
+impl Default for AbilityPredictions {
+    fn default() -> Self {
+        Self {
+            pending: HashMap::new(),
+            expiry: 2.0,
+        }
+    }
+}
+
+impl AbilityPredictions {
+    /// Sets how long predictions wait for the server.
+    pub fn with_expiry(mut self, expiry: f32) -> Self {
+        self.expiry = expiry;
+        self
+    }
+
+    /// Returns the pending prediction for a key.
+    pub fn get(&self, key: PredictionKey) -> Option<&PredictedActivation> {
+        self.pending.get(&key)
+    }
+
+    /// Returns true while a prediction waits for the server.
+    pub fn is_pending(&self, key: PredictionKey) -> bool {
+        self.pending.contains_key(&key)
+    }
+}
+
+/// Event asking the server to activate an ability.
+///
+/// Triggered on clients; `prediction_key` is set when the client already
+/// activated the ability locally.
+#[derive(Event, Debug, Clone)]
+#[cfg_attr(
+    feature = "replicon",
+    derive(serde::Serialize, serde::Deserialize, bevy::ecs::entity::MapEntities)
+)]
+pub struct AbilityActivationRequestEvent {
+    /// The ability spec entity.
+    #[cfg_attr(feature = "replicon", entities)]
+    pub ability_spec: Entity,
+    /// The owner entity.
+    #[cfg_attr(feature = "replicon", entities)]
+    pub owner: Entity,
+    /// Key of the local prediction, if any.
+    pub prediction_key: Option<PredictionKey>,
+}
+
+/// Event carrying the server's verdict on a predicted activation.
+///
+/// Triggered on the server when a predicted activation commits or fails, and
+/// on the predicting client once it arrives.
+#[derive(Event, Debug, Clone)]
+#[cfg_attr(
+    feature = "replicon",
+    derive(serde::Serialize, serde::Deserialize, bevy::ecs::entity::MapEntities)
+)]
+pub struct AbilityPredictionResolvedEvent {
+    /// The ability spec entity.
+    #[cfg_attr(feature = "replicon", entities)]
+    pub ability_spec: Entity,
+    /// Key of the resolved prediction.
+    pub prediction_key: PredictionKey,
+    /// Whether the server activated and committed the ability.
+    pub accepted: bool,
+}
+
+/// Queues setting the [`ScopedPredictionKey`].
+pub(crate) fn set_scoped_prediction_key(commands: &mut Commands, key: Option<PredictionKey>) {
+    commands.queue(move |world: &mut World| {
+        world.resource_mut::<ScopedPredictionKey>().0 = key;
+    });
+}
+
+/// Observer that records predicted activations on the client.
+pub fn record_ability_prediction(
+    ev: On<AbilityActivationRequestEvent>,
+    mut predictions: ResMut<AbilityPredictions>,
+    time: Res<Time>,
+) {
+    let event = ev.event();
+    let Some(key) = event.prediction_key else {
+        return;
+    };
+
+    predictions.pending.insert(
+        key,
+        PredictedActivation {
+            ability_spec: event.ability_spec,
+            owner: event.owner,
+            changes: Vec::new(),
+            predicted_at: time.elapsed_secs(),
+        },
+    );
+}
+
+/// Observer that records the attribute changes of predicted instant effects,
+/// so a rollback can undo them.
+///
+/// Only instant executions are recorded; a predicted periodic effect stops
+/// executing when the rollback discards it.
+pub fn record_predicted_changes(
+    ev: On<GameplayEffectExecutedEvent>,
+    mut predictions: ResMut<AbilityPredictions>,
+) {
+    let event = ev.event();
+    if event.effect.is_some() {
+        return;
+    }
+    let Some(prediction) = event
+        .prediction_key
+        .and_then(|key| predictions.pending.get_mut(&key))
+    else {
+        return;
+    };
+
+    prediction.changes.extend(
+        event
+            .changes
+            .iter()
+            .map(|(attribute_name, change)| (event.target, attribute_name.clone(), *change)),
+    );
+}
+
+/// Observer that confirms or rolls back a prediction on the client.
+pub fn on_ability_prediction_resolved(
+    ev: On<AbilityPredictionResolvedEvent>,
+    mut commands: Commands,
+    mut predictions: ResMut<AbilityPredictions>,
+    mut rollback: PredictionRollbackParams,
+) {
+    let event = ev.event();
+    let Some(prediction) = predictions.pending.remove(&event.prediction_key) else {
+        return;
+    };
+    if event.accepted {
+        debug!("Prediction {:?} accepted", event.prediction_key);
+        return;
+    }
+
+    debug!(
+        "Prediction {:?} rejected, rolling back spec {:?}",
+        event.prediction_key, prediction.ability_spec
+    );
+    rollback.rollback(&mut commands, event.prediction_key, prediction);
+}
+
+/// System that rolls back predictions the server has not answered in time.
+pub fn expire_stale_predictions_system(
+    mut commands: Commands,
+    mut predictions: ResMut<AbilityPredictions>,
+    mut rollback: PredictionRollbackParams,
+    time: Res<Time>,
+) {
+    let now = time.elapsed_secs();
+    let expiry = predictions.expiry;
+    let mut stale: Vec<_> = predictions
+        .pending
+        .iter()
+        .filter(|(_, prediction)| now - prediction.predicted_at > expiry)
+        .map(|(key, _)| *key)
+        .collect();
+    stale.sort();
+
+    for key in stale {
+        let Some(prediction) = predictions.pending.remove(&key) else {
+            continue;
+        };
+        warn!(
+            "Prediction {:?} for spec {:?} expired without an answer, rolling back",
+            key, prediction.ability_spec
+        );
+        rollback.rollback(&mut commands, key, prediction);
+    }
+}
+
+/// Bundled parameters for rolling back a predicted activation.
+#[derive(SystemParam)]
+pub struct PredictionRollbackParams<'w, 's> {
+    pub effects: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static PredictionKey,
+            &'static ActiveGameplayEffect,
+            Option<&'static EffectGrantedTags>,
+        ),
+    >,
+    pub discard: PredictedEffectParams<'w, 's>,
+    pub lookup: AttributeLookup<'w, 's>,
+    pub attributes: Query<'w, 's, &'static mut AttributeData>,
+}
+
+impl PredictionRollbackParams<'_, '_> {
+    /// Ends the predicted ability, discards its effects and undoes the
+    /// changes its instant effects made.
+    pub fn rollback(
+        &mut self,
+        commands: &mut Commands,
+        key: PredictionKey,
+        prediction: PredictedActivation,
+    ) {
+        commands.trigger(EndAbilityEvent {
+            instance: None,
+            ability_spec: prediction.ability_spec,
+            owner: prediction.owner,
+        });
+
+        for (effect_entity, effect_key, effect, granted) in self.effects.iter() {
+            if *effect_key == key {
+                self.discard
+                    .discard(commands, effect_entity, effect, granted);
+            }
+        }
+
+        for (target, attribute_name, change) in prediction.changes {
+            if let Some(attribute) = self.lookup.find(&AttributeKey::new(target, attribute_name))
+                && let Ok(mut data) = self.attributes.get_mut(attribute)
+            {
+                data.base_value -= change;
+            }
+        }
+    }
+}
+
+/// Bundled parameters for discarding predicted effects.
+#[derive(SystemParam)]
+pub struct PredictedEffectParams<'w, 's> {
+    pub tags_manager: Res<'w, GameplayTagsManager>,
+    pub tag_containers: Query<'w, 's, &'static mut OwnedTags>,
+}
+
+impl PredictedEffectParams<'_, '_> {
+    /// Removes a predicted effect along with its granted tags. Its modifiers
+    /// are despawned with it.
+    pub fn discard(
+        &mut self,
+        commands: &mut Commands,
+        effect_entity: Entity,
+        effect: &ActiveGameplayEffect,
+        granted: Option<&EffectGrantedTags>,
+    ) {
+        if let Some(granted) = granted
+            && let Ok(mut target_tags) = self.tag_containers.get_mut(effect.target)
+        {
+            target_tags.0.update_tag_container_count(
+                &granted.tags,
+                -1,
+                &self.tags_manager,
+                commands,
+                effect.target,
+            );
+        }
+
+        commands.trigger(GameplayEffectRemovedEvent {
+            effect: effect_entity,
+            target: effect.target,
+            effect_id: effect.definition_id.clone(),
+        });
+        commands.entity(effect_entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prediction_keys_are_unique() {
+        let generator = PredictionKeyGenerator::default();
+        let first = generator.next_key();
+        let second = generator.next_key();
+        assert_eq!(first, PredictionKey(1));
+        assert_ne!(first, second);
+    }
+}
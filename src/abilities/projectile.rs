@@ -0,0 +1,423 @@
+//! Projectiles.
+//!
+//! A [`Projectile`] carries a pre-built [`GameplayEffectSpec`] and moves every
+//! frame by its velocity. When it hits an actor the spec is applied to that
+//! actor with the hit location and normal in its context (so cues spawn at the
+//! impact), a [`ProjectileHitEvent`] is triggered and the projectile despawns.
+//!
+//! Hits are found by sweeping the projectile's movement each frame:
+//! - [`ProjectileCollision::Overlap`] tests actors (entities with `OwnedTags`)
+//!   as spheres of their [`ProjectileHitRadius`]; no physics needed.
+//! - [`ProjectileCollision::PhysicsTrace`] line-traces the physics backend
+//!   (`avian3d` or `rapier3d` feature). Hitting a collider that is not an actor
+//!   despawns the projectile without applying the spec.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let spec = GameplayEffectSpec::new("fireball_damage", Entity::PLACEHOLDER)
+//!     .with_context(GameplayEffectContext::new().with_source(caster));
+//! commands.spawn((
+//!     Projectile::new(spec, forward * 30.0)
+//!         .with_radius(0.25)
+//!         .with_lifetime(3.0)
+//!         .with_filter(TargetFilter::new().hostile()),
+//!     Transform::from_translation(muzzle),
+//! ));
+//! ```
+
+use super::target_filter::{TargetFilter, TargetFilterQuery};
+use crate::core::OwnedTags;
+use crate::core::timestep::GasTime;
+use crate::effects::{ApplyGameplayEffectEvent, GameplayEffectSpec};
+use bevy::prelude::*;
+
+#[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+use super::trace::TraceRequest;
+
+/// How a projectile finds what it hits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProjectileCollision {
+    /// Sweep against actor spheres.
+    #[default]
+    Overlap,
+    /// Line-trace the physics backend along the movement.
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    PhysicsTrace,
+}
+
+/// A moving effect carrier.
+///
+/// `spec.target` is replaced by the hit actor; the owner is the spec's source
+/// entity and is never hit.
+#[derive(Component, Debug, Clone)]
+#[require(Transform)]
+pub struct Projectile {
+    /// Effect applied to the hit actor.
+    pub spec: GameplayEffectSpec,
+    /// Velocity in world units per second.
+    pub velocity: Vec3,
+    /// Acceleration applied to the velocity, e.g. gravity for arcs.
+    pub acceleration: Vec3,
+    /// Radius of the projectile for overlap sweeps.
+    pub radius: f32,
+    /// Seconds left before the projectile despawns without hitting.
+    pub lifetime: Option<f32>,
+    /// Rules hit actors must pass; failing actors are passed through.
+    pub filter: TargetFilter,
+    /// How hits are found.
+    pub collision: ProjectileCollision,
+}
+
+impl Projectile {
+    /// Creates a point projectile with no lifetime limit.
+    pub fn new(spec: GameplayEffectSpec, velocity: Vec3) -> Self {
+        Self {
+            spec,
+            velocity,
+            acceleration: Vec3::ZERO,
+            radius: 0.0,
+            lifetime: None,
+            filter: TargetFilter::new(),
+            collision: ProjectileCollision::default(),
+        }
+    }
+
+    /// Sets the acceleration.
+    pub fn with_acceleration(mut self, acceleration: Vec3) -> Self {
+        self.acceleration = acceleration;
+        self
+    }
+
+    /// Sets the projectile radius.
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Despawns the projectile after `seconds`.
+    pub fn with_lifetime(mut self, seconds: f32) -> Self {
+        self.lifetime = Some(seconds);
+        self
+    }
+
+    /// Sets the filter hit actors must pass.
+    pub fn with_filter(mut self, filter: TargetFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Sets how hits are found.
+    pub fn with_collision(mut self, collision: ProjectileCollision) -> Self {
+        self.collision = collision;
+        self
+    }
+
+    /// The entity that launched the projectile.
+    pub fn owner(&self) -> Option<Entity> {
+        self.spec.source_entity()
+    }
+
+    /// Finds the first of `candidates` touched while moving from `start` to
+    /// `end`, returning the hit location, normal and fraction of the movement.
+    ///
+    /// Candidates are `(entity, center, radius)` spheres.
+    pub fn sweep(
+        &self,
+        start: Vec3,
+        end: Vec3,
+        candidates: impl IntoIterator<Item = (Entity, Vec3, f32)>,
+    ) -> Option<ProjectileSweepHit> {
+        let movement = end - start;
+        let fallback_normal = -movement.normalize_or(Vec3::NEG_Z);
+        let length_squared = movement.length_squared();
+
+        candidates
+            .into_iter()
+            .filter_map(|(entity, center, hit_radius)| {
+                let fraction = if length_squared > 0.0 {
+                    ((center - start).dot(movement) / length_squared).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                let closest = start + movement * fraction;
+                if closest.distance(center) > self.radius + hit_radius {
+                    return None;
+                }
+                let normal = (closest - center).normalize_or(fallback_normal);
+                Some(ProjectileSweepHit {
+                    entity,
+                    location: center + normal * hit_radius,
+                    normal,
+                    fraction,
+                })
+            })
+            .min_by(|a, b| a.fraction.total_cmp(&b.fraction))
+    }
+}
+
+/// Result of [`Projectile::sweep`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProjectileSweepHit {
+    /// The actor that was hit.
+    pub entity: Entity,
+    /// World-space hit location on the actor's sphere.
+    pub location: Vec3,
+    /// Normal of the actor's sphere at the hit.
+    pub normal: Vec3,
+    /// Fraction of the movement travelled before the hit.
+    pub fraction: f32,
+}
+
+/// Radius of an actor for [`ProjectileCollision::Overlap`] sweeps.
+///
+/// Actors without it are hit as points.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ProjectileHitRadius(pub f32);
+
+/// Event triggered when a projectile hits something and despawns.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ProjectileHitEvent {
+    /// The projectile entity, despawned with this event's commands.
+    pub projectile: Entity,
+    /// The actor the spec was applied to, or `None` for world geometry.
+    pub target: Option<Entity>,
+    /// World-space hit location.
+    pub location: Vec3,
+    /// World-space hit normal.
+    pub normal: Vec3,
+}
+
+/// System that moves projectiles and applies their spec on hit.
+///
+/// Projectiles advance by their owner's [`GasTime`] delta, so they freeze with
+/// a paused or disabled owner and slow down with its time scale. Movement is
+/// swept in world space; a projectile parented to a moving platform starts
+/// from where its parent's [`GlobalTransform`] puts it, and its local
+/// translation is updated to the swept end.
+pub fn update_projectiles_system(
+    mut commands: Commands,
+    time: GasTime,
+    mut projectiles: Query<(Entity, &mut Projectile, &mut Transform, Option<&ChildOf>)>,
+    candidates: Query<(Entity, &GlobalTransform, Option<&ProjectileHitRadius>), With<OwnedTags>>,
+    parents: Query<&GlobalTransform>,
+    filters: TargetFilterQuery,
+) {
+    for (entity, mut projectile, mut transform, child_of) in projectiles.iter_mut() {
+        let owner = projectile.owner();
+        let delta = owner.map_or_else(|| time.global_delta_secs(), |owner| time.delta_secs(owner));
+        let parent = child_of
+            .and_then(|child_of| parents.get(child_of.parent()).ok())
+            .copied()
+            .unwrap_or(GlobalTransform::IDENTITY);
+        let start = parent.transform_point(transform.translation);
+        let acceleration = projectile.acceleration;
+        projectile.velocity += acceleration * delta;
+        let end = start + projectile.velocity * delta;
+        let passes = |target: Entity| {
+            Some(target) != owner
+                && target != entity
+                && owner.is_none_or(|owner| filters.passes(&projectile.filter, owner, target))
+        };
+
+        let hit = match projectile.collision {
+            ProjectileCollision::Overlap => projectile
+                .sweep(
+                    start,
+                    end,
+                    candidates
+                        .iter()
+                        .filter(|(target, ..)| passes(*target))
+                        .map(|(target, transform, radius)| {
+                            (target, transform.translation(), radius.map_or(0.0, |r| r.0))
+                        }),
+                )
+                .map(|hit| (Some(hit.entity), hit.location, hit.normal)),
+            #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+            ProjectileCollision::PhysicsTrace => {
+                physics_trace(&filters, entity, owner, start, end, passes)
+            }
+        };
+
+        if let Some((target, location, normal)) = hit {
+            if let Some(target) = target {
+                let mut spec = projectile.spec.clone();
+                spec.target = target;
+                spec.context = spec
+                    .context
+                    .with_hit_location(location)
+                    .with_hit_normal(normal);
+                commands.trigger(ApplyGameplayEffectEvent::from_spec(spec));
+            }
+            commands.trigger(ProjectileHitEvent {
+                projectile: entity,
+                target,
+                location,
+                normal,
+            });
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        transform.translation = parent.affine().inverse().transform_point3(end);
+        if let Some(lifetime) = projectile.lifetime.as_mut() {
+            *lifetime -= delta;
+            if *lifetime <= 0.0 {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+/// Traces the movement through the physics backend, passing through actors
+/// rejected by `passes`.
+#[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+fn physics_trace(
+    filters: &TargetFilterQuery,
+    projectile: Entity,
+    owner: Option<Entity>,
+    start: Vec3,
+    end: Vec3,
+    passes: impl Fn(Entity) -> bool,
+) -> Option<(Option<Entity>, Vec3, Vec3)> {
+    /// Rejected actors traced through before giving up.
+    const MAX_PASS_THROUGH: usize = 8;
+
+    let offset = end - start;
+    let direction = Dir3::new(offset).ok()?;
+    let mut request = TraceRequest::new(start, direction, offset.length()).ignoring(projectile);
+    if let Some(owner) = owner {
+        request = request.ignoring(owner);
+    }
+
+    for _ in 0..=MAX_PASS_THROUGH {
+        let hit = filters.trace(&request)?;
+        match filters.actor_of(hit.entity) {
+            Some(actor) if !passes(actor) => request = request.ignoring(hit.entity),
+            actor => return Some((actor, hit.location, hit.normal)),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::effects::GameplayEffectContext;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[test]
+    fn test_sweep_finds_first_touched_sphere() {
+        let mut world = World::new();
+        let near = world.spawn_empty().id();
+        let far = world.spawn_empty().id();
+        let missed = world.spawn_empty().id();
+        let projectile =
+            Projectile::new(GameplayEffectSpec::new("hit", Entity::PLACEHOLDER), Vec3::X)
+                .with_radius(0.5);
+
+        let hit = projectile
+            .sweep(
+                Vec3::ZERO,
+                Vec3::new(10.0, 0.0, 0.0),
+                [
+                    (far, Vec3::new(8.0, 0.0, 0.0), 1.0),
+                    (near, Vec3::new(4.0, 1.0, 0.0), 1.0),
+                    (missed, Vec3::new(6.0, 3.0, 0.0), 1.0),
+                ],
+            )
+            .unwrap();
+        assert_eq!(hit.entity, near);
+        assert_eq!(hit.normal, Vec3::NEG_Y);
+        assert_eq!(hit.location, Vec3::new(4.0, 0.0, 0.0));
+        assert!((hit.fraction - 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_projectile_expires_and_skips_owner() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .add_systems(Update, update_projectiles_system);
+        // The first update only starts the clock.
+        app.update();
+
+        let owner = app
+            .world_mut()
+            .spawn((OwnedTags::default(), GlobalTransform::IDENTITY))
+            .id();
+        let spec = GameplayEffectSpec::new("hit", Entity::PLACEHOLDER)
+            .with_context(GameplayEffectContext::new().with_source(owner));
+        let projectile = app
+            .world_mut()
+            .spawn(Projectile::new(spec, Vec3::X).with_lifetime(0.25))
+            .id();
+
+        app.update();
+        app.update();
+        let position = app
+            .world()
+            .get::<Transform>(projectile)
+            .unwrap()
+            .translation;
+        assert!((position.x - 0.2).abs() < 1e-5);
+
+        app.update();
+        assert!(app.world().get_entity(projectile).is_err());
+    }
+
+    #[test]
+    fn test_parented_projectile_sweeps_in_world_space() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .add_systems(Update, update_projectiles_system);
+        app.update();
+
+        let platform = app
+            .world_mut()
+            .spawn(GlobalTransform::from_translation(Vec3::new(10.0, 0.0, 0.0)))
+            .id();
+        // Where the local translation would put it, and where it really is
+        let decoy = app
+            .world_mut()
+            .spawn((
+                OwnedTags::default(),
+                GlobalTransform::from_translation(Vec3::X),
+            ))
+            .id();
+        let target = app
+            .world_mut()
+            .spawn((
+                OwnedTags::default(),
+                GlobalTransform::from_translation(Vec3::new(11.0, 0.0, 0.0)),
+            ))
+            .id();
+        app.world_mut().spawn((
+            Projectile::new(
+                GameplayEffectSpec::new("hit", Entity::PLACEHOLDER),
+                Vec3::X * 5.0,
+            )
+            .with_radius(0.5),
+            ChildOf(platform),
+        ));
+        app.add_observer(|ev: On<ProjectileHitEvent>, mut commands: Commands| {
+            commands.insert_resource(LastHit(ev.target));
+        });
+
+        for _ in 0..3 {
+            app.update();
+        }
+        let hit = app.world().resource::<LastHit>().0;
+        assert_eq!(hit, Some(target));
+        assert_ne!(hit, Some(decoy));
+    }
+
+    #[derive(Resource)]
+    struct LastHit(Option<Entity>);
+}
@@ -0,0 +1,230 @@
+//! Spender abilities.
+//!
+//! An ability whose definition has an [`AbilitySpend`] consumes a counted
+//! resource of its owner when activated: the integer part of an attribute
+//! such as `ComboPoints`, or the stacks of an effect such as a sunder debuff.
+//! The plugin then triggers [`AbilitySpentEvent`] with the consumed count,
+//! and [`AbilitySpentEvent::effect`] builds an effect application carrying it
+//! as the SetByCaller value under `count_tag`, for effects scaling with the
+//! points spent. The event is triggered with a count of 0 when there was
+//! nothing to spend; require the resource with an activation requirement if
+//! the ability shouldn't activate then.
+//!
+//! # Example
+//! ```ignore
+//! abilities.register(
+//!     AbilityDefinition::new("eviscerate").with_spend(AbilitySpend::attribute("ComboPoints")),
+//! );
+//! effects.register(GameplayEffectDefinition::new("eviscerate_damage").add_modifier(
+//!     ModifierInfo::new(
+//!         "Health",
+//!         ModifierOperation::AddBase,
+//!         MagnitudeCalculation::set_by_caller(GameplayTag::new("Data.Damage")),
+//!     ),
+//! ));
+//!
+//! // 30 damage per combo point spent
+//! fn finish(ev: On<AbilitySpentEvent>, mut commands: Commands, target: Res<Target>) {
+//!     let ev = ev.event();
+//!     commands.trigger(
+//!         ev.effect("eviscerate_damage", target.0)
+//!             .with_set_by_caller_magnitude(GameplayTag::new("Data.Damage"), -30.0 * ev.count as f32),
+//!     );
+//! }
+//! ```
+
+use super::components::AbilitySpec;
+use super::definition::AbilityRegistry;
+use super::systems::AbilityActivatedEvent;
+use crate::attributes::AttributeData;
+use crate::effects::batch_aggregation::{AttributeKey, AttributeLookup};
+use crate::effects::components::{ActiveEffects, ActiveGameplayEffect};
+use crate::effects::systems::{ApplyGameplayEffectEvent, RemoveGameplayEffectEvent};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use string_cache::DefaultAtom as Atom;
+
+/// Default SetByCaller tag the spent count is passed under.
+pub const SPENT_COUNT_TAG: &str = "Data.Spent";
+
+/// The counted resource a spender ability consumes.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpendResource {
+    /// Whole points of an attribute of the owner.
+    Attribute(Atom),
+    /// Stacks of the effects with this ID active on the owner.
+    EffectStacks(Atom),
+}
+
+/// How a spender ability consumes a counted resource.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AbilitySpend {
+    /// The resource consumed.
+    pub resource: SpendResource,
+    /// Most that is consumed per activation, everything when `None`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_count: Option<u32>,
+    /// SetByCaller tag the spent count is passed under.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::gameplay_tag_serde")
+    )]
+    pub count_tag: GameplayTag,
+}
+
+impl AbilitySpend {
+    /// Creates a spend of the whole points of `attribute`, passed under
+    /// [`SPENT_COUNT_TAG`].
+    pub fn attribute(attribute: impl Into<Atom>) -> Self {
+        Self::new(SpendResource::Attribute(attribute.into()))
+    }
+
+    /// Creates a spend of the stacks of `effect_id`, passed under
+    /// [`SPENT_COUNT_TAG`].
+    pub fn effect_stacks(effect_id: impl Into<Atom>) -> Self {
+        Self::new(SpendResource::EffectStacks(effect_id.into()))
+    }
+
+    fn new(resource: SpendResource) -> Self {
+        Self {
+            resource,
+            max_count: None,
+            count_tag: GameplayTag::new(SPENT_COUNT_TAG),
+        }
+    }
+
+    /// Caps how much is consumed per activation.
+    pub fn with_max_count(mut self, max_count: u32) -> Self {
+        self.max_count = Some(max_count);
+        self
+    }
+
+    /// Sets the SetByCaller tag the spent count is passed under.
+    pub fn with_count_tag(mut self, tag: GameplayTag) -> Self {
+        self.count_tag = tag;
+        self
+    }
+
+    /// Returns how much of `available` is consumed.
+    pub fn count(&self, available: u32) -> u32 {
+        self.max_count
+            .map_or(available, |max_count| available.min(max_count))
+    }
+}
+
+/// Event triggered when a spender ability consumed its resource.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct AbilitySpentEvent {
+    /// The ability spec entity.
+    pub ability_spec: Entity,
+    /// The owner entity.
+    pub owner: Entity,
+    /// The ability instance, if instanced.
+    pub instance: Option<Entity>,
+    /// The ability level.
+    pub level: i32,
+    /// How much was consumed.
+    pub count: u32,
+    /// SetByCaller tag the count is passed under.
+    pub count_tag: GameplayTag,
+}
+
+impl AbilitySpentEvent {
+    /// Builds an application of `effect_id` to `target` from the owner,
+    /// carrying the spent count as a SetByCaller value.
+    pub fn effect(&self, effect_id: impl Into<Atom>, target: Entity) -> ApplyGameplayEffectEvent {
+        ApplyGameplayEffectEvent::new(effect_id, target)
+            .with_source(self.owner)
+            .with_instigator(self.ability_spec)
+            .with_level(self.level)
+            .with_set_by_caller_magnitude(self.count_tag.clone(), self.count as f32)
+    }
+}
+
+/// System parameter consuming the counted resources of an owner.
+#[derive(SystemParam)]
+pub struct SpendableResources<'w, 's> {
+    pub lookup: AttributeLookup<'w, 's>,
+    pub attributes: Query<'w, 's, &'static mut AttributeData>,
+    pub effect_lists: Query<'w, 's, &'static ActiveEffects>,
+    pub effects: Query<'w, 's, (Entity, &'static mut ActiveGameplayEffect)>,
+}
+
+impl SpendableResources<'_, '_> {
+    /// Consumes `spend` from `owner`, returning how much was consumed.
+    pub fn spend(&mut self, commands: &mut Commands, owner: Entity, spend: &AbilitySpend) -> u32 {
+        match &spend.resource {
+            SpendResource::Attribute(attribute) => {
+                let Some(mut data) = self
+                    .lookup
+                    .find(&AttributeKey::new(owner, attribute.clone()))
+                    .and_then(|attribute| self.attributes.get_mut(attribute).ok())
+                else {
+                    return 0;
+                };
+                let count = spend.count(data.base_value.max(0.0) as u32);
+                let base_value = data.base_value - count as f32;
+                data.set_base_value(base_value);
+                count
+            }
+            SpendResource::EffectStacks(effect_id) => {
+                let list = self.effect_lists.get(owner).ok();
+                let stacks: Vec<_> = self
+                    .effects
+                    .iter_many(list.into_iter().flat_map(|list| list.iter()))
+                    .filter(|(_, effect)| effect.definition_id == *effect_id)
+                    .map(|(entity, effect)| (entity, effect.stack_count.max(0) as u32))
+                    .collect();
+                let count = spend.count(stacks.iter().map(|(_, stack_count)| stack_count).sum());
+                let mut remaining = count;
+                for (effect, stack_count) in stacks {
+                    if remaining == 0 {
+                        break;
+                    }
+                    let taken = remaining.min(stack_count);
+                    remaining -= taken;
+                    if taken == stack_count {
+                        commands.trigger(RemoveGameplayEffectEvent { effect });
+                    } else if let Ok((_, mut active_effect)) = self.effects.get_mut(effect) {
+                        active_effect.stack_count -= taken as i32;
+                    }
+                }
+                count
+            }
+        }
+    }
+}
+
+/// Observer that consumes the resource of a spender ability when it
+/// activates.
+pub fn on_ability_activated_spend(
+    trigger: On<AbilityActivatedEvent>,
+    mut commands: Commands,
+    registry: Res<AbilityRegistry>,
+    specs: Query<&AbilitySpec>,
+    mut resources: SpendableResources,
+) {
+    let event = trigger.event();
+    let Ok(spec) = specs.get(event.ability_spec) else {
+        return;
+    };
+    let Some(spend) = registry
+        .get(&spec.definition_id)
+        .and_then(|definition| definition.spend.as_ref())
+    else {
+        return;
+    };
+
+    let count = resources.spend(&mut commands, event.owner, spend);
+    commands.trigger(AbilitySpentEvent {
+        ability_spec: event.ability_spec,
+        owner: event.owner,
+        instance: event.instance,
+        level: spec.level,
+        count,
+        count_tag: spend.count_tag.clone(),
+    });
+}
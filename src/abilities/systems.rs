@@ -8,6 +8,9 @@
 //!   → call_activate_ability_system: pre_activate → activate → CommitAbilityEvent
 //!   → on_commit_ability observer: apply costs/cooldowns
 //!
+//! On networked clients the net execution policy can predict the activation
+//! or hand it to the server instead; see [`super::prediction`].
+//!
 //! End flow:
 //!   EndAbilityEvent / CancelAbilityEvent → end_ability_internal:
 //!       behavior.end → despawn instance entity → decrement AbilityActiveState
@@ -18,14 +21,19 @@
 
 use super::components::*;
 use super::definition::*;
+use super::prediction::*;
+use super::tag_relationships::AbilityTagRelationshipMapping;
+use super::transport::respond_to_remote_request;
 use crate::attributes::{AttributeData, AttributeName};
 use crate::core::BlockedAbilityTags;
 use crate::core::OwnedTags;
 use crate::effects::definition::GameplayEffectRegistry;
+use crate::error::GasReportExt;
 use bevy::ecs::relationship::Relationship;
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_gameplay_tag::GameplayTagsManager;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
 
 // --- SystemParam bundles ---
 
@@ -72,6 +80,14 @@ pub struct EndAbilityParams<'w, 's> {
     >,
     pub tag_containers: Query<'w, 's, &'static mut OwnedTags>,
     pub blocked_ability_tags: Query<'w, 's, &'static mut BlockedAbilityTags>,
+    pub tag_relationships: Res<'w, AbilityTagRelationshipMapping>,
+}
+
+/// The tags manager with the global ability tag relationships.
+#[derive(SystemParam)]
+pub struct AbilityTagParams<'w> {
+    pub tags_manager: Res<'w, GameplayTagsManager>,
+    pub tag_relationships: Res<'w, AbilityTagRelationshipMapping>,
 }
 
 // --- Events ---
@@ -85,6 +101,10 @@ pub struct TryActivateAbilityEvent {
     pub owner: Entity,
     /// Optional activation context (target data, instigator, etc.).
     pub context: Option<super::activation_context::AbilityActivationContext>,
+    /// Key of the client prediction this activation confirms, if any.
+    pub prediction_key: Option<PredictionKey>,
+    /// Payload of the gameplay event that triggered this activation, if any.
+    pub event_payload: Option<super::activation_info::GameplayEventData>,
 }
 
 impl TryActivateAbilityEvent {
@@ -94,6 +114,8 @@ impl TryActivateAbilityEvent {
             ability_spec,
             owner,
             context: None,
+            prediction_key: None,
+            event_payload: None,
         }
     }
 
@@ -107,8 +129,25 @@ impl TryActivateAbilityEvent {
             ability_spec,
             owner,
             context: Some(context),
+            prediction_key: None,
+            event_payload: None,
         }
     }
+
+    /// Activates under a client's prediction key (server side).
+    pub fn with_prediction_key(mut self, key: PredictionKey) -> Self {
+        self.prediction_key = Some(key);
+        self
+    }
+
+    /// Activates with the payload of a triggering gameplay event.
+    pub fn with_event_payload(
+        mut self,
+        payload: super::activation_info::GameplayEventData,
+    ) -> Self {
+        self.event_payload = Some(payload);
+        self
+    }
 }
 
 /// Event triggered when an ability is successfully activated.
@@ -120,6 +159,10 @@ pub struct AbilityActivatedEvent {
     pub owner: Entity,
     /// The spawned instance entity (None for NonInstanced abilities).
     pub instance: Option<Entity>,
+    /// Key of the prediction the activation runs under, if any.
+    pub prediction_key: Option<PredictionKey>,
+    /// The definition's animation tag, if any.
+    pub animation_tag: Option<GameplayTag>,
 }
 
 /// Event for requesting ability end.
@@ -142,6 +185,8 @@ pub struct CommitAbilityEvent {
     pub instance: Option<Entity>,
     /// The owner entity.
     pub owner: Entity,
+    /// Key of the prediction the activation runs under, if any.
+    pub prediction_key: Option<PredictionKey>,
 }
 
 /// Event for canceling an ability.
@@ -191,6 +236,7 @@ pub struct OnGameplayAbilityEnded {
 
 /// Reason why ability activation failed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ActivationFailureReason {
     /// Ability is on cooldown.
     OnCooldown,
@@ -200,6 +246,28 @@ pub enum ActivationFailureReason {
     MissingRequiredTags,
     /// Owner has tags that block activation.
     BlockedByTags,
+    /// The spec, its owner or its definition could not be used (e.g. a
+    /// client request the server refused).
+    InvalidRequest,
+    /// The ability's behavior refused activation.
+    RejectedByBehavior,
+    /// The owner has [`GasDisabled`](crate::core::GasDisabled).
+    OwnerDisabled,
+}
+
+impl From<&super::traits::ActivationCheckFailure> for ActivationFailureReason {
+    fn from(failure: &super::traits::ActivationCheckFailure) -> Self {
+        use super::traits::ActivationCheckFailure;
+        match failure {
+            ActivationCheckFailure::OnCooldown(_) => Self::OnCooldown,
+            ActivationCheckFailure::SourceMissingRequiredTags(_)
+            | ActivationCheckFailure::TargetMissingRequiredTags(_) => Self::MissingRequiredTags,
+            ActivationCheckFailure::SourceHasBlockedTags(_)
+            | ActivationCheckFailure::TargetHasBlockedTags(_) => Self::BlockedByTags,
+            ActivationCheckFailure::MissingComponents => Self::InvalidRequest,
+            ActivationCheckFailure::RejectedByBehavior => Self::RejectedByBehavior,
+        }
+    }
 }
 
 // --- Pending activation ---
@@ -237,6 +305,7 @@ pub fn spawn_pending_ability_instances_system(
     pending_query: Query<(Entity, &PendingActivation, &AbilitySpec), With<PendingActivation>>,
     existing_instances: Query<(Entity, &AbilitySpecInstance, &ChildOf)>,
 ) {
+    let _span = info_span!("gas::spawn_pending_ability_instances").entered();
     for (spec_entity, pending, spec) in pending_query.iter() {
         let Some(def) = registry.get(&spec.definition_id) else {
             // Invalid definition, remove marker.
@@ -339,11 +408,12 @@ pub fn spawn_pending_ability_instances_system(
 /// 2. Adds activation_owned_tags to owner's OwnedTags
 /// 3. Adds block_abilities_with_tags to owner's BlockedAbilityTags
 /// 4. Calls pre_activate → activate on the behavior
-/// 5. Triggers CommitAbilityEvent and AbilityActivatedEvent
+/// 5. Adds the tag relationships' blocked tags to owner's BlockedAbilityTags
+/// 6. Triggers CommitAbilityEvent and AbilityActivatedEvent
 pub fn call_activate_ability_system(
     mut commands: Commands,
     ability_registry: Res<AbilityRegistry>,
-    tags_manager: Res<GameplayTagsManager>,
+    tags: AbilityTagParams,
     mut ready_query: Query<
         (
             Entity,
@@ -357,6 +427,7 @@ pub fn call_activate_ability_system(
     mut tag_containers: Query<&mut OwnedTags>,
     mut blocked_ability_tags: Query<&mut BlockedAbilityTags>,
 ) {
+    let _span = info_span!("gas::call_activate_ability").entered();
     for (spec_entity, ready, spec, mut active_state) in ready_query.iter_mut() {
         let Some(definition) = ability_registry.get(&spec.definition_id) else {
             commands.entity(spec_entity).remove::<ReadyToActivate>();
@@ -395,7 +466,7 @@ pub fn call_activate_ability_system(
             spec_entity,
             ready.owner,
             definition,
-            &tags_manager,
+            &tags.tags_manager,
             &mut tag_containers,
             &mut blocked_ability_tags,
         );
@@ -406,17 +477,33 @@ pub fn call_activate_ability_system(
             &ready.activation_info,
         );
 
+        // Block the abilities the global tag relationships block.
+        if let Ok(mut blocked_tags) = blocked_ability_tags.get_mut(ready.owner) {
+            blocked_tags.0.update_tag_container_count(
+                &tags
+                    .tag_relationships
+                    .block_abilities_with_tags(&definition.ability_tags, &tags.tags_manager),
+                1,
+                &tags.tags_manager,
+                &mut commands,
+                ready.owner,
+            );
+        }
+
         // Trigger events.
         commands.trigger(CommitAbilityEvent {
             ability_spec: spec_entity,
             instance: instance_entity,
             owner: ready.owner,
+            prediction_key: ready.activation_info.prediction_key,
         });
 
         commands.trigger(AbilityActivatedEvent {
             ability_spec: spec_entity,
             owner: ready.owner,
             instance: instance_entity,
+            prediction_key: ready.activation_info.prediction_key,
+            animation_tag: definition.animation_tag.clone(),
         });
 
         info!(
@@ -438,20 +525,62 @@ pub fn on_try_activate_ability(
     tags_manager: Res<bevy_gameplay_tag::GameplayTagsManager>,
     world: &World,
 ) {
+    let _span = info_span!("gas::try_activate_ability").entered();
     let event = ev.event();
     let spec_entity = event.ability_spec;
     let owner = event.owner;
+    let net_role = *world.resource::<NetRole>();
+    let reject = |commands: &mut Commands, reason: ActivationFailureReason| {
+        resolve_activation(
+            commands,
+            net_role,
+            spec_entity,
+            event.prediction_key,
+            Some(reason),
+        );
+    };
 
     let Ok(spec) = ability_specs.get(spec_entity) else {
+        reject(&mut commands, ActivationFailureReason::InvalidRequest);
         return;
     };
 
-    let Some(definition) = ability_registry.get(&spec.definition_id) else {
+    let Some(definition) = ability_registry
+        .try_get(spec.definition_id.clone())
+        .or_report(&mut commands)
+    else {
+        reject(&mut commands, ActivationFailureReason::InvalidRequest);
         return;
     };
 
+    // Clients leave server-run abilities to the server.
+    if net_role == NetRole::Client
+        && matches!(
+            definition.net_execution_policy,
+            NetExecutionPolicy::ServerInitiated | NetExecutionPolicy::ServerOnly
+        )
+    {
+        commands.trigger(AbilityActivationRequestEvent {
+            ability_spec: spec_entity,
+            owner,
+            prediction_key: None,
+        });
+        return;
+    }
+
     // Check if already pending activation (prevent duplicate activation in same frame).
     if world.get::<PendingActivation>(spec_entity).is_some() {
+        reject(&mut commands, ActivationFailureReason::InvalidRequest);
+        return;
+    }
+
+    if world.get::<crate::core::GasDisabled>(owner).is_some() {
+        commands.trigger(AbilityActivationFailedEvent {
+            ability_spec: spec_entity,
+            owner,
+            reason: ActivationFailureReason::OwnerDisabled,
+        });
+        reject(&mut commands, ActivationFailureReason::OwnerDisabled);
         return;
     }
 
@@ -463,26 +592,35 @@ pub fn on_try_activate_ability(
 
     // Check if can activate
     if let Err(failure) = behavior.can_activate(world, spec_entity, owner, &tags_manager) {
-        use super::traits::ActivationCheckFailure;
-        let reason = match failure {
-            ActivationCheckFailure::OnCooldown(_) => ActivationFailureReason::OnCooldown,
-            ActivationCheckFailure::SourceMissingRequiredTags(_)
-            | ActivationCheckFailure::TargetMissingRequiredTags(_) => {
-                ActivationFailureReason::MissingRequiredTags
-            }
-            ActivationCheckFailure::SourceHasBlockedTags(_)
-            | ActivationCheckFailure::TargetHasBlockedTags(_) => {
-                ActivationFailureReason::BlockedByTags
-            }
-            ActivationCheckFailure::MissingComponents => return,
-        };
-        commands.trigger(AbilityActivationFailedEvent {
+        let reason = ActivationFailureReason::from(&failure);
+        if !matches!(
+            failure,
+            super::traits::ActivationCheckFailure::MissingComponents
+        ) {
+            commands.trigger(AbilityActivationFailedEvent {
+                ability_spec: spec_entity,
+                owner,
+                reason,
+            });
+        }
+        reject(&mut commands, reason);
+        return;
+    }
+
+    // Predicted client activations run now and ask the server to follow.
+    let prediction_key = if net_role == NetRole::Client
+        && definition.net_execution_policy == NetExecutionPolicy::LocalPredicted
+    {
+        let key = world.resource::<PredictionKeyGenerator>().next_key();
+        commands.trigger(AbilityActivationRequestEvent {
             ability_spec: spec_entity,
             owner,
-            reason,
+            prediction_key: Some(key),
         });
-        return;
-    }
+        Some(key)
+    } else {
+        event.prediction_key
+    };
 
     // Mark for deferred activation.
     let activation_info = if let Some(ctx) = &event.context {
@@ -495,14 +633,19 @@ pub fn on_try_activate_ability(
                 .clone()
                 .unwrap_or_else(super::target_data::GameplayAbilityTargetData::empty),
             level: ctx.level,
-            event_payload: None,
+            event_payload: event.event_payload.clone(),
+            prediction_key,
         }
     } else {
         // No context provided, create minimal activation info
-        super::activation_info::AbilityActivationInfo::new(
-            owner,
-            super::target_data::GameplayAbilityTargetData::empty(),
-        )
+        super::activation_info::AbilityActivationInfo {
+            prediction_key,
+            event_payload: event.event_payload.clone(),
+            ..super::activation_info::AbilityActivationInfo::new(
+                owner,
+                super::target_data::GameplayAbilityTargetData::empty(),
+            )
+        }
     };
 
     commands.entity(spec_entity).insert(PendingActivation {
@@ -518,18 +661,35 @@ pub fn on_commit_ability(
     ability_registry: Res<AbilityRegistry>,
     ability_specs: Query<&AbilitySpec>,
     tags_manager: Res<bevy_gameplay_tag::GameplayTagsManager>,
+    net_role: Res<NetRole>,
     world: &World,
 ) {
     let event = ev.event();
     let spec_entity = event.ability_spec;
     let instance_entity = event.instance;
     let owner = event.owner;
+    let prediction_key = event.prediction_key;
 
+    let invalid = Some(ActivationFailureReason::InvalidRequest);
     let Ok(spec) = ability_specs.get(spec_entity) else {
+        resolve_activation(
+            &mut commands,
+            *net_role,
+            spec_entity,
+            prediction_key,
+            invalid,
+        );
         return;
     };
 
     let Some(definition) = ability_registry.get(&spec.definition_id) else {
+        resolve_activation(
+            &mut commands,
+            *net_role,
+            spec_entity,
+            prediction_key,
+            invalid,
+        );
         return;
     };
 
@@ -539,24 +699,28 @@ pub fn on_commit_ability(
         .map(|b| b.as_ref() as &dyn super::traits::AbilityBehavior)
         .unwrap_or(&super::traits::DefaultAbilityBehavior);
 
-    if behavior
-        .commit(world, &mut commands, definition, spec, owner, &tags_manager)
-        .is_err()
-    {
-        commands.trigger(CommitAbilityResultEvent {
-            ability_spec: spec_entity,
-            instance: instance_entity,
-            owner,
-            success: false,
-        });
-        return;
+    // Effects applied by the commit carry the activation's prediction key.
+    if prediction_key.is_some() {
+        set_scoped_prediction_key(&mut commands, prediction_key);
+    }
+    let result = behavior.commit(world, &mut commands, definition, spec, owner, &tags_manager);
+    if prediction_key.is_some() {
+        set_scoped_prediction_key(&mut commands, None);
     }
+    let success = result.is_ok();
+    resolve_activation(
+        &mut commands,
+        *net_role,
+        spec_entity,
+        prediction_key,
+        result.err().as_ref().map(ActivationFailureReason::from),
+    );
 
     commands.trigger(CommitAbilityResultEvent {
         ability_spec: spec_entity,
         instance: instance_entity,
         owner,
-        success: true,
+        success,
     });
 }
 
@@ -610,13 +774,11 @@ fn end_ability_internal(
         return;
     };
 
-    let Some(definition) = params.ability_registry.get(&spec.definition_id) else {
+    // A shared handle, since `params` is borrowed mutably below.
+    let Some(definition) = params.ability_registry.get_shared(&spec.definition_id) else {
         return;
     };
 
-    let owned_tags = definition.activation_owned_tags.clone();
-    let block_tags = definition.block_abilities_with_tags.clone();
-
     // Collect (entity, behavior) pairs for instances to end.
     let instances_to_end: Vec<(
         Entity,
@@ -658,7 +820,7 @@ fn end_ability_internal(
         // Remove activation_owned_tags from owner.
         if let Ok(mut owner_tags) = params.tag_containers.get_mut(owner) {
             owner_tags.0.update_tag_container_count(
-                &owned_tags,
+                &definition.activation_owned_tags,
                 -1,
                 &params.tags_manager,
                 commands,
@@ -669,7 +831,16 @@ fn end_ability_internal(
         // Remove block_abilities_with_tags from owner's BlockedAbilityTags.
         if let Ok(mut blocked_tags) = params.blocked_ability_tags.get_mut(owner) {
             blocked_tags.0.update_tag_container_count(
-                &block_tags,
+                &definition.block_abilities_with_tags,
+                -1,
+                &params.tags_manager,
+                commands,
+                owner,
+            );
+            blocked_tags.0.update_tag_container_count(
+                &params
+                    .tag_relationships
+                    .block_abilities_with_tags(&definition.ability_tags, &params.tags_manager),
                 -1,
                 &params.tags_manager,
                 commands,
@@ -724,6 +895,29 @@ pub fn on_instance_removed(
 
 // --- Helper functions ---
 
+/// Tells the requesting client whether its activation went through.
+///
+/// Only the authority resolves activations; clients wait for its answer.
+fn resolve_activation(
+    commands: &mut Commands,
+    net_role: NetRole,
+    ability_spec: Entity,
+    prediction_key: Option<PredictionKey>,
+    rejection: Option<ActivationFailureReason>,
+) {
+    if net_role == NetRole::Client {
+        return;
+    }
+    if let Some(prediction_key) = prediction_key {
+        commands.trigger(AbilityPredictionResolvedEvent {
+            ability_spec,
+            prediction_key,
+            accepted: rejection.is_none(),
+        });
+    }
+    respond_to_remote_request(commands, ability_spec, rejection);
+}
+
 /// Check if abilities can be activated based on tag requirements.
 pub fn check_ability_activation_requirements(
     ability_def: &AbilityDefinition,
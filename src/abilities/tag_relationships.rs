@@ -0,0 +1,242 @@
+//! Global ability tag relationships.
+//!
+//! [`AbilityTagRelationshipMapping`] keeps rules that apply to every ability
+//! with a given ability tag in one data table, instead of repeating them on
+//! each definition, as Lyra's `ULyraAbilityTagRelationshipMapping` does. The
+//! tags of the matching rules are merged with the definition's own:
+//!
+//! - activation required/blocked tags into the owner tag check of the
+//!   default `AbilityBehavior::can_activate`,
+//! - tags to block into the owner's `BlockedAbilityTags` while the ability
+//!   is active,
+//! - tags to cancel into the abilities cancelled when it activates.
+//!
+//! # Example
+//! ```ignore
+//! app.world_mut()
+//!     .resource_mut::<AbilityTagRelationshipMapping>()
+//!     .add_relationship(
+//!         AbilityTagRelationship::new(GameplayTag::new("Ability.Type.Melee"))
+//!             .add_activation_blocked_tag(GameplayTag::new("State.Disarmed"), &tags_manager),
+//!     );
+//! ```
+
+use super::components::{AbilityActiveState, AbilitySpec, OwnedAbilities};
+use super::definition::AbilityRegistry;
+use super::systems::{AbilityActivatedEvent, CancelAbilityEvent};
+use bevy::prelude::*;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use bevy_gameplay_tag::{GameplayTagContainer, GameplayTagsManager};
+
+/// Tags applied to every ability with `ability_tag` or one of its children.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbilityTagRelationship {
+    /// The ability tag the rule applies to.
+    pub ability_tag: GameplayTag,
+    /// Abilities with these tags are blocked while the ability is active.
+    pub block_abilities_with_tags: GameplayTagContainer,
+    /// Abilities with these tags are cancelled when the ability activates.
+    pub cancel_abilities_with_tags: GameplayTagContainer,
+    /// The owner must have these tags to activate the ability.
+    pub activation_required_tags: GameplayTagContainer,
+    /// The owner must have none of these tags to activate the ability.
+    pub activation_blocked_tags: GameplayTagContainer,
+}
+
+impl AbilityTagRelationship {
+    /// Creates an empty rule for `ability_tag`.
+    pub fn new(ability_tag: GameplayTag) -> Self {
+        Self {
+            ability_tag,
+            block_abilities_with_tags: GameplayTagContainer::default(),
+            cancel_abilities_with_tags: GameplayTagContainer::default(),
+            activation_required_tags: GameplayTagContainer::default(),
+            activation_blocked_tags: GameplayTagContainer::default(),
+        }
+    }
+
+    /// Adds a tag of abilities blocked while the ability is active.
+    pub fn add_block_abilities_with_tag(
+        mut self,
+        tag: GameplayTag,
+        tags_manager: &Res<GameplayTagsManager>,
+    ) -> Self {
+        self.block_abilities_with_tags.add_tag(tag, tags_manager);
+        self
+    }
+
+    /// Adds a tag of abilities cancelled when the ability activates.
+    pub fn add_cancel_abilities_with_tag(
+        mut self,
+        tag: GameplayTag,
+        tags_manager: &Res<GameplayTagsManager>,
+    ) -> Self {
+        self.cancel_abilities_with_tags.add_tag(tag, tags_manager);
+        self
+    }
+
+    /// Adds an activation required tag.
+    pub fn add_activation_required_tag(
+        mut self,
+        tag: GameplayTag,
+        tags_manager: &Res<GameplayTagsManager>,
+    ) -> Self {
+        self.activation_required_tags.add_tag(tag, tags_manager);
+        self
+    }
+
+    /// Adds an activation blocked tag.
+    pub fn add_activation_blocked_tag(
+        mut self,
+        tag: GameplayTag,
+        tags_manager: &Res<GameplayTagsManager>,
+    ) -> Self {
+        self.activation_blocked_tags.add_tag(tag, tags_manager);
+        self
+    }
+}
+
+/// Resource mapping ability tags to the tags they additionally require,
+/// are blocked by, block and cancel.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct AbilityTagRelationshipMapping {
+    /// The rules, in no particular order.
+    pub relationships: Vec<AbilityTagRelationship>,
+}
+
+impl AbilityTagRelationshipMapping {
+    /// Adds a rule.
+    pub fn add_relationship(&mut self, relationship: AbilityTagRelationship) {
+        self.relationships.push(relationship);
+    }
+
+    /// Adds a rule, for building the mapping.
+    pub fn with_relationship(mut self, relationship: AbilityTagRelationship) -> Self {
+        self.add_relationship(relationship);
+        self
+    }
+
+    /// Returns the rules applying to an ability with `ability_tags`.
+    pub fn relationships_for<'a>(
+        &'a self,
+        ability_tags: &'a GameplayTagContainer,
+    ) -> impl Iterator<Item = &'a AbilityTagRelationship> {
+        self.relationships
+            .iter()
+            .filter(|relationship| ability_tags.has_tag(&relationship.ability_tag))
+    }
+
+    /// Returns the tags of abilities blocked while an ability with
+    /// `ability_tags` is active.
+    pub fn block_abilities_with_tags(
+        &self,
+        ability_tags: &GameplayTagContainer,
+        tags_manager: &GameplayTagsManager,
+    ) -> GameplayTagContainer {
+        self.merged(ability_tags, tags_manager, |relationship| {
+            &relationship.block_abilities_with_tags
+        })
+    }
+
+    /// Returns the tags of abilities cancelled when an ability with
+    /// `ability_tags` activates.
+    pub fn cancel_abilities_with_tags(
+        &self,
+        ability_tags: &GameplayTagContainer,
+        tags_manager: &GameplayTagsManager,
+    ) -> GameplayTagContainer {
+        self.merged(ability_tags, tags_manager, |relationship| {
+            &relationship.cancel_abilities_with_tags
+        })
+    }
+
+    /// Returns the tags the owner must have to activate an ability with
+    /// `ability_tags`.
+    pub fn activation_required_tags(
+        &self,
+        ability_tags: &GameplayTagContainer,
+        tags_manager: &GameplayTagsManager,
+    ) -> GameplayTagContainer {
+        self.merged(ability_tags, tags_manager, |relationship| {
+            &relationship.activation_required_tags
+        })
+    }
+
+    /// Returns the tags that keep the owner from activating an ability with
+    /// `ability_tags`.
+    pub fn activation_blocked_tags(
+        &self,
+        ability_tags: &GameplayTagContainer,
+        tags_manager: &GameplayTagsManager,
+    ) -> GameplayTagContainer {
+        self.merged(ability_tags, tags_manager, |relationship| {
+            &relationship.activation_blocked_tags
+        })
+    }
+
+    fn merged(
+        &self,
+        ability_tags: &GameplayTagContainer,
+        tags_manager: &GameplayTagsManager,
+        tags: impl Fn(&AbilityTagRelationship) -> &GameplayTagContainer,
+    ) -> GameplayTagContainer {
+        let mut merged = GameplayTagContainer::default();
+        for relationship in self.relationships_for(ability_tags) {
+            merged.append_tags(tags(relationship), tags_manager);
+        }
+        merged
+    }
+}
+
+/// Observer that cancels the owner's other active abilities matching the
+/// definition's `cancel_abilities_with_tags` and the tags to cancel of the
+/// [`AbilityTagRelationshipMapping`] when an ability activates.
+pub fn on_ability_activated_cancel_abilities(
+    ev: On<AbilityActivatedEvent>,
+    mut commands: Commands,
+    registry: Res<AbilityRegistry>,
+    relationships: Res<AbilityTagRelationshipMapping>,
+    tags_manager: Res<GameplayTagsManager>,
+    owners: Query<&OwnedAbilities>,
+    abilities: Query<(&AbilitySpec, &AbilityActiveState)>,
+) {
+    let event = ev.event();
+    let Some(definition) = abilities
+        .get(event.ability_spec)
+        .ok()
+        .and_then(|(spec, _)| registry.get(&spec.definition_id))
+    else {
+        return;
+    };
+    let mut cancel_tags = definition.cancel_abilities_with_tags.clone();
+    cancel_tags.append_tags(
+        &relationships.cancel_abilities_with_tags(&definition.ability_tags, &tags_manager),
+        &tags_manager,
+    );
+    if cancel_tags.is_empty() {
+        return;
+    }
+
+    let Ok(owned) = owners.get(event.owner) else {
+        return;
+    };
+    for ability_spec in owned.iter() {
+        if ability_spec == event.ability_spec {
+            continue;
+        }
+        let Ok((spec, active_state)) = abilities.get(ability_spec) else {
+            continue;
+        };
+        if active_state.is_active
+            && registry
+                .get(&spec.definition_id)
+                .is_some_and(|other| other.ability_tags.has_any(&cancel_tags))
+        {
+            commands.trigger(CancelAbilityEvent {
+                instance: None,
+                ability_spec,
+                owner: event.owner,
+            });
+        }
+    }
+}
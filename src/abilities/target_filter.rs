@@ -0,0 +1,377 @@
+//! Target filters.
+//!
+//! A [`TargetFilter`] is a list of rules every target must pass: self
+//! exclusion, team relation or effect team policy, required/blocked gameplay
+//! tags, alive-only and line of sight. Filters are declared on an [`AbilityDefinition`] (applied
+//! when its `ApplyEffectToTargetDataTask`s run) or on a targeting request such
+//! as [`ShapeTargeting`], and can be applied to any target data with
+//! [`TargetFilterQuery::apply`].
+//!
+//! Line of sight needs the `avian3d` or `rapier3d` feature and the backend's
+//! physics plugin; without them the rule lets every target through.
+//!
+//! [`AbilityDefinition`]: super::definition::AbilityDefinition
+//! [`ShapeTargeting`]: super::targeting::ShapeTargeting
+
+use super::target_data::GameplayAbilityTargetData;
+use crate::core::{OwnedTags, Team};
+use crate::effects::EffectTeamPolicy;
+use bevy::ecs::system::{SystemParam, SystemState};
+use bevy::prelude::*;
+use bevy_gameplay_tag::GameplayTagContainer;
+
+#[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+use super::trace::{TraceHit, TraceRequest};
+#[cfg(feature = "avian3d")]
+use super::trace_avian::AvianTargetingQuery;
+#[cfg(feature = "rapier3d")]
+use super::trace_rapier::RapierTargetingQuery;
+
+/// Marks an actor as dead for [`TargetFilterRule::AliveOnly`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Dead;
+
+/// Required team relation between caster and target.
+///
+/// Actors without a [`Team`] are neither friendly nor hostile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TeamRelation {
+    /// Same team as the caster.
+    Friendly,
+    /// Different team from the caster.
+    Hostile,
+}
+
+/// A single target filter rule.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TargetFilterRule {
+    /// Rejects the caster.
+    ExcludeSelf,
+    /// Requires a team relation to the caster.
+    Team(TeamRelation),
+    /// Target must have all of these tags.
+    RequiredTags(
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::tag_container_serde")
+        )]
+        GameplayTagContainer,
+    ),
+    /// Target must have none of these tags.
+    BlockedTags(
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::tag_container_serde")
+        )]
+        GameplayTagContainer,
+    ),
+    /// Rejects targets with [`Dead`].
+    AliveOnly,
+    /// Requires an unobstructed line from the caster to the target.
+    LineOfSight,
+    /// Applies an effect's team policy with the caster as source.
+    TeamPolicy(EffectTeamPolicy),
+}
+
+/// Composable set of rules targets must pass.
+///
+/// # Example
+///
+/// ```ignore
+/// let filter = TargetFilter::new()
+///     .exclude_self()
+///     .hostile()
+///     .alive_only()
+///     .line_of_sight();
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TargetFilter {
+    /// Rules, all of which must pass.
+    pub rules: Vec<TargetFilterRule>,
+}
+
+impl TargetFilter {
+    /// Creates a filter accepting every target.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule.
+    pub fn with_rule(mut self, rule: TargetFilterRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Adds all rules of another filter.
+    pub fn and(mut self, other: TargetFilter) -> Self {
+        self.rules.extend(other.rules);
+        self
+    }
+
+    /// Rejects the caster.
+    pub fn exclude_self(self) -> Self {
+        self.with_rule(TargetFilterRule::ExcludeSelf)
+    }
+
+    /// Only accepts targets on the caster's team.
+    pub fn friendly(self) -> Self {
+        self.with_rule(TargetFilterRule::Team(TeamRelation::Friendly))
+    }
+
+    /// Only accepts targets on another team.
+    pub fn hostile(self) -> Self {
+        self.with_rule(TargetFilterRule::Team(TeamRelation::Hostile))
+    }
+
+    /// Requires targets to have all of `tags`.
+    pub fn require_tags(self, tags: GameplayTagContainer) -> Self {
+        self.with_rule(TargetFilterRule::RequiredTags(tags))
+    }
+
+    /// Rejects targets with any of `tags`.
+    pub fn block_tags(self, tags: GameplayTagContainer) -> Self {
+        self.with_rule(TargetFilterRule::BlockedTags(tags))
+    }
+
+    /// Rejects dead targets.
+    pub fn alive_only(self) -> Self {
+        self.with_rule(TargetFilterRule::AliveOnly)
+    }
+
+    /// Requires line of sight from the caster.
+    pub fn line_of_sight(self) -> Self {
+        self.with_rule(TargetFilterRule::LineOfSight)
+    }
+
+    /// Only accepts targets an effect with `policy` could be applied to.
+    pub fn team_policy(self, policy: EffectTeamPolicy) -> Self {
+        self.with_rule(TargetFilterRule::TeamPolicy(policy))
+    }
+
+    /// Returns true if the filter has no rules.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Filters target data with direct world access.
+    pub fn apply(
+        &self,
+        world: &mut World,
+        caster: Entity,
+        target_data: GameplayAbilityTargetData,
+    ) -> GameplayAbilityTargetData {
+        if self.is_empty() {
+            return target_data;
+        }
+        let mut state = SystemState::<TargetFilterQuery>::new(world);
+        state.get(world).apply(self, caster, target_data)
+    }
+}
+
+/// Per-actor data read by [`TargetFilterQuery`].
+pub type FilterActorData = (
+    Option<&'static Team>,
+    Option<&'static OwnedTags>,
+    Has<Dead>,
+    Option<&'static GlobalTransform>,
+);
+
+/// System parameter evaluating [`TargetFilter`]s.
+#[derive(SystemParam)]
+pub struct TargetFilterQuery<'w, 's> {
+    pub actors: Query<'w, 's, FilterActorData>,
+    pub parents: Query<'w, 's, &'static ChildOf>,
+    #[cfg(feature = "avian3d")]
+    pub avian: Option<AvianTargetingQuery<'w, 's>>,
+    #[cfg(feature = "rapier3d")]
+    pub rapier: Option<RapierTargetingQuery<'w, 's>>,
+}
+
+impl TargetFilterQuery<'_, '_> {
+    /// Returns true if `target` passes every rule of `filter`.
+    pub fn passes(&self, filter: &TargetFilter, caster: Entity, target: Entity) -> bool {
+        filter
+            .rules
+            .iter()
+            .all(|rule| self.passes_rule(rule, caster, target))
+    }
+
+    /// Keeps only the actors of `target_data` that pass `filter`.
+    pub fn apply(
+        &self,
+        filter: &TargetFilter,
+        caster: Entity,
+        mut target_data: GameplayAbilityTargetData,
+    ) -> GameplayAbilityTargetData {
+        target_data
+            .actors
+            .retain(|&target| self.passes(filter, caster, target));
+        target_data
+    }
+
+    fn passes_rule(&self, rule: &TargetFilterRule, caster: Entity, target: Entity) -> bool {
+        let target_tags = || {
+            self.actors
+                .get(target)
+                .ok()
+                .and_then(|(_, tags, _, _)| tags)
+        };
+        let team = |entity| {
+            self.actors
+                .get(entity)
+                .ok()
+                .and_then(|(team, ..)| team.copied())
+        };
+        match rule {
+            TargetFilterRule::ExcludeSelf => caster != target,
+            TargetFilterRule::Team(relation) => match (team(caster), team(target)) {
+                (Some(caster_team), Some(target_team)) => match relation {
+                    TeamRelation::Friendly => caster_team == target_team,
+                    TeamRelation::Hostile => caster_team != target_team,
+                },
+                _ => false,
+            },
+            TargetFilterRule::RequiredTags(tags) => {
+                tags.is_empty()
+                    || target_tags()
+                        .is_some_and(|owned| owned.0.has_all_matching_gameplay_tags(tags))
+            }
+            TargetFilterRule::BlockedTags(tags) => {
+                !target_tags().is_some_and(|owned| owned.0.has_any_matching_gameplay_tags(tags))
+            }
+            TargetFilterRule::AliveOnly => {
+                !self.actors.get(target).is_ok_and(|(_, _, dead, _)| dead)
+            }
+            TargetFilterRule::LineOfSight => self.has_line_of_sight(caster, target),
+            TargetFilterRule::TeamPolicy(policy) => policy.allows(Some(caster), target, team),
+        }
+    }
+
+    /// Returns true if nothing blocks the line from `caster` to `target`.
+    ///
+    /// True when there is no physics backend to trace with, or when either
+    /// entity has no `GlobalTransform`.
+    pub fn has_line_of_sight(&self, caster: Entity, target: Entity) -> bool {
+        #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+        {
+            if !self.has_trace_backend() {
+                return true;
+            }
+            let position = |entity| {
+                self.actors
+                    .get(entity)
+                    .ok()
+                    .and_then(|(.., transform)| transform)
+            };
+            let (Some(from), Some(to)) = (position(caster), position(target)) else {
+                return true;
+            };
+            let offset = to.translation() - from.translation();
+            let Ok(direction) = Dir3::new(offset) else {
+                return true;
+            };
+            let request =
+                TraceRequest::new(from.translation(), direction, offset.length()).ignoring(caster);
+
+            match self.trace(&request) {
+                Some(hit) => {
+                    hit.entity == target
+                        || self
+                            .parents
+                            .get(hit.entity)
+                            .is_ok_and(|child_of| child_of.parent() == target)
+                }
+                None => true,
+            }
+        }
+        #[cfg(not(any(feature = "avian3d", feature = "rapier3d")))]
+        {
+            let _ = (caster, target);
+            true
+        }
+    }
+
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    fn has_trace_backend(&self) -> bool {
+        #[cfg(feature = "avian3d")]
+        if self.avian.is_some() {
+            return true;
+        }
+        #[cfg(feature = "rapier3d")]
+        if self.rapier.is_some() {
+            return true;
+        }
+        false
+    }
+
+    /// Returns the actor owning `entity`: itself if it has `OwnedTags`,
+    /// otherwise its parent if that has them.
+    pub fn actor_of(&self, entity: Entity) -> Option<Entity> {
+        let is_actor = |entity| {
+            self.actors
+                .get(entity)
+                .is_ok_and(|(_, tags, ..)| tags.is_some())
+        };
+        if is_actor(entity) {
+            return Some(entity);
+        }
+        self.parents
+            .get(entity)
+            .ok()
+            .map(ChildOf::parent)
+            .filter(|&parent| is_actor(parent))
+    }
+
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    pub(crate) fn trace(&self, request: &TraceRequest) -> Option<TraceHit> {
+        #[cfg(feature = "avian3d")]
+        if let Some(avian) = &self.avian {
+            return avian.line_trace(request);
+        }
+        #[cfg(feature = "rapier3d")]
+        if let Some(rapier) = &self.rapier {
+            return rapier.line_trace(request);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_rules() {
+        let mut world = World::new();
+        let caster = world.spawn((Team(1), OwnedTags::default())).id();
+        let ally = world.spawn((Team(1), OwnedTags::default())).id();
+        let enemy = world.spawn((Team(2), OwnedTags::default())).id();
+        let dead_enemy = world.spawn((Team(2), OwnedTags::default(), Dead)).id();
+        let neutral = world.spawn(OwnedTags::default()).id();
+        let all =
+            GameplayAbilityTargetData::from_actors(vec![caster, ally, enemy, dead_enemy, neutral]);
+
+        let filtered =
+            TargetFilter::new()
+                .hostile()
+                .alive_only()
+                .apply(&mut world, caster, all.clone());
+        assert_eq!(filtered.actors, vec![enemy]);
+
+        let filtered =
+            TargetFilter::new()
+                .friendly()
+                .exclude_self()
+                .apply(&mut world, caster, all.clone());
+        assert_eq!(filtered.actors, vec![ally]);
+
+        let filtered = TargetFilter::new()
+            .exclude_self()
+            .line_of_sight()
+            .apply(&mut world, caster, all);
+        assert_eq!(filtered.actors, vec![ally, enemy, dead_enemy, neutral]);
+    }
+}
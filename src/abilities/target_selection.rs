@@ -0,0 +1,255 @@
+//! Target selection.
+//!
+//! A [`TargetSelection`] orders target actors with a [`TargetSort`] strategy
+//! and caps them at a maximum count, e.g. "the 3 nearest enemies" or "the
+//! lowest health ally". Apply it to any target data with
+//! [`TargetSelectionQuery::select`]; [`ShapeTargeting`] applies its own.
+//!
+//! [`ShapeTargeting`]: super::targeting::ShapeTargeting
+
+use super::target_data::GameplayAbilityTargetData;
+use crate::attributes::{AttributeData, AttributeName};
+use crate::core::GasRng;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use string_cache::DefaultAtom as Atom;
+
+/// Order in which targets are kept.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum TargetSort {
+    /// Keep the incoming order.
+    Unsorted,
+    /// Closest to the selection origin first.
+    #[default]
+    Nearest,
+    /// Farthest from the selection origin first.
+    Farthest,
+    /// Lowest current value of an attribute first, e.g. `"Health"`.
+    ///
+    /// Targets without the attribute come last.
+    LowestAttribute(Atom),
+    /// Highest current value of an attribute first.
+    ///
+    /// Targets without the attribute come last.
+    HighestAttribute(Atom),
+    /// Shuffled with the [`GasRng`] resource.
+    Random,
+}
+
+/// Sorting strategy plus an optional cap on the number of targets.
+///
+/// # Example
+///
+/// ```ignore
+/// // Chain lightning: up to 3 nearest enemies.
+/// let selection = TargetSelection::nearest().with_max_targets(3);
+/// // Heal the most wounded ally.
+/// let selection = TargetSelection::lowest_attribute("Health").with_max_targets(1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TargetSelection {
+    /// Order targets are kept in.
+    pub sort: TargetSort,
+    /// Maximum number of targets kept after sorting.
+    pub max_targets: Option<usize>,
+}
+
+impl TargetSelection {
+    /// Creates a selection with the given sort and no cap.
+    pub fn new(sort: TargetSort) -> Self {
+        Self {
+            sort,
+            max_targets: None,
+        }
+    }
+
+    /// Closest targets first.
+    pub fn nearest() -> Self {
+        Self::new(TargetSort::Nearest)
+    }
+
+    /// Farthest targets first.
+    pub fn farthest() -> Self {
+        Self::new(TargetSort::Farthest)
+    }
+
+    /// Lowest `attribute` first.
+    pub fn lowest_attribute(attribute: impl Into<Atom>) -> Self {
+        Self::new(TargetSort::LowestAttribute(attribute.into()))
+    }
+
+    /// Highest `attribute` first.
+    pub fn highest_attribute(attribute: impl Into<Atom>) -> Self {
+        Self::new(TargetSort::HighestAttribute(attribute.into()))
+    }
+
+    /// Random order.
+    pub fn random() -> Self {
+        Self::new(TargetSort::Random)
+    }
+
+    /// Keeps at most `max_targets` targets.
+    pub fn with_max_targets(mut self, max_targets: usize) -> Self {
+        self.max_targets = Some(max_targets);
+        self
+    }
+
+    /// Orders and caps `targets` using `key` to look up each target's sort
+    /// value. Entries with no key come last.
+    pub fn select_by(
+        &self,
+        targets: &mut Vec<Entity>,
+        rng: &mut GasRng,
+        key: impl Fn(Entity) -> Option<f32>,
+    ) {
+        match self.sort {
+            TargetSort::Unsorted => {}
+            TargetSort::Random => rng.shuffle(targets),
+            TargetSort::Nearest | TargetSort::LowestAttribute(_) => {
+                targets.sort_by(|a, b| compare_keys(key(*a), key(*b)));
+            }
+            TargetSort::Farthest | TargetSort::HighestAttribute(_) => {
+                targets.sort_by(|a, b| compare_keys(key(*a).map(|k| -k), key(*b).map(|k| -k)));
+            }
+        }
+        if let Some(max_targets) = self.max_targets {
+            targets.truncate(max_targets);
+        }
+    }
+}
+
+/// Ascending order with missing keys last.
+fn compare_keys(a: Option<f32>, b: Option<f32>) -> std::cmp::Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.total_cmp(&b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    }
+}
+
+/// System parameter applying [`TargetSelection`]s.
+///
+/// Random selection falls back to a default-seeded [`GasRng`] when the
+/// resource is missing.
+#[derive(SystemParam)]
+pub struct TargetSelectionQuery<'w, 's> {
+    pub transforms: Query<'w, 's, &'static GlobalTransform>,
+    pub children: Query<'w, 's, &'static Children>,
+    pub attributes: Query<'w, 's, (&'static AttributeName, &'static AttributeData)>,
+    pub rng: Option<ResMut<'w, GasRng>>,
+}
+
+impl TargetSelectionQuery<'_, '_> {
+    /// Orders and caps the actors of `target_data`.
+    ///
+    /// Distances are measured from `origin`.
+    pub fn select(
+        &mut self,
+        selection: &TargetSelection,
+        origin: Vec3,
+        mut target_data: GameplayAbilityTargetData,
+    ) -> GameplayAbilityTargetData {
+        let mut fallback_rng = GasRng::default();
+        let Self {
+            transforms,
+            children,
+            attributes,
+            rng,
+        } = self;
+        let rng = match rng.as_mut() {
+            Some(rng) => rng.as_mut(),
+            None => &mut fallback_rng,
+        };
+
+        match &selection.sort {
+            TargetSort::LowestAttribute(name) | TargetSort::HighestAttribute(name) => {
+                selection.select_by(&mut target_data.actors, rng, |entity| {
+                    attribute_value(children, attributes, entity, name)
+                });
+            }
+            _ => selection.select_by(&mut target_data.actors, rng, |entity| {
+                transforms
+                    .get(entity)
+                    .ok()
+                    .map(|transform| transform.translation().distance_squared(origin))
+            }),
+        }
+        target_data
+    }
+
+    /// Current value of the attribute named `name` owned by `owner`.
+    pub fn attribute_value(&self, owner: Entity, name: &Atom) -> Option<f32> {
+        attribute_value(&self.children, &self.attributes, owner, name)
+    }
+}
+
+fn attribute_value(
+    children: &Query<&Children>,
+    attributes: &Query<(&AttributeName, &AttributeData)>,
+    owner: Entity,
+    name: &Atom,
+) -> Option<f32> {
+    children
+        .get(owner)
+        .ok()?
+        .iter()
+        .filter_map(|child| attributes.get(child).ok())
+        .find(|(attribute_name, _)| attribute_name.0 == *name)
+        .map(|(_, data)| data.current_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::SystemState;
+
+    #[test]
+    fn test_sort_strategies_and_cap() {
+        let mut world = World::new();
+        let mut spawn = |x: f32, health: f32| {
+            let actor = world
+                .spawn(GlobalTransform::from_translation(Vec3::new(x, 0.0, 0.0)))
+                .id();
+            world.spawn((
+                AttributeName::new("Health"),
+                AttributeData::new(health),
+                ChildOf(actor),
+            ));
+            actor
+        };
+        let a = spawn(3.0, 50.0);
+        let b = spawn(1.0, 80.0);
+        let c = spawn(2.0, 20.0);
+        let no_health = world.spawn(GlobalTransform::IDENTITY).id();
+        world.insert_resource(GasRng::new(1));
+        let all = GameplayAbilityTargetData::from_actors(vec![a, b, c, no_health]);
+
+        let mut state = SystemState::<TargetSelectionQuery>::new(&mut world);
+        let mut query = state.get_mut(&mut world);
+        let mut select =
+            |selection: TargetSelection| query.select(&selection, Vec3::ZERO, all.clone()).actors;
+
+        assert_eq!(
+            select(TargetSelection::nearest().with_max_targets(3)),
+            vec![no_health, b, c]
+        );
+        assert_eq!(
+            select(TargetSelection::farthest()),
+            vec![a, c, b, no_health]
+        );
+        assert_eq!(
+            select(TargetSelection::lowest_attribute("Health")),
+            vec![c, a, b, no_health]
+        );
+        assert_eq!(
+            select(TargetSelection::highest_attribute("Health").with_max_targets(1)),
+            vec![b]
+        );
+
+        let mut random = select(TargetSelection::random().with_max_targets(2));
+        assert_eq!(random.len(), 2);
+        random.dedup();
+        assert_eq!(random.len(), 2);
+    }
+}
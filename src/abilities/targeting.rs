@@ -0,0 +1,325 @@
+//! Shape overlap targeting.
+//!
+//! Gathers ability system actors (entities with [`OwnedTags`]) inside a
+//! sphere, box or cone placed relative to the caster, and returns them as
+//! [`GameplayAbilityTargetData`] after applying the request's [`TargetFilter`]
+//! and [`TargetSelection`].
+//! Overlaps are tested against `GlobalTransform` positions, so no physics
+//! backend is required.
+//!
+//! Use [`TargetingQuery`] from systems, or [`ShapeTargeting::collect`] with
+//! world access (e.g. inside `commands.queue` from `AbilityBehavior::activate`).
+
+use super::target_data::GameplayAbilityTargetData;
+use super::target_filter::{TargetFilter, TargetFilterQuery};
+use super::target_selection::{TargetSelection, TargetSelectionQuery, TargetSort};
+use crate::core::OwnedTags;
+use bevy::ecs::system::{SystemParam, SystemState};
+use bevy::prelude::*;
+
+/// Overlap volume, in the caster's local space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TargetShape {
+    /// Sphere around the origin.
+    Sphere {
+        /// Sphere radius.
+        radius: f32,
+    },
+    /// Box centered on the origin, rotated with the caster.
+    Box {
+        /// Half size along each local axis.
+        half_extents: Vec3,
+    },
+    /// Cone starting at the origin.
+    Cone {
+        /// Maximum distance from the origin.
+        range: f32,
+        /// Angle in radians between the axis and the cone edge.
+        half_angle: f32,
+        /// Cone axis in the caster's local space.
+        direction: Vec3,
+    },
+}
+
+impl TargetShape {
+    /// Creates a sphere shape.
+    pub fn sphere(radius: f32) -> Self {
+        Self::Sphere { radius }
+    }
+
+    /// Creates a box shape.
+    pub fn cuboid(half_extents: Vec3) -> Self {
+        Self::Box { half_extents }
+    }
+
+    /// Creates a cone along the caster's forward (-Z) axis.
+    pub fn cone(range: f32, half_angle: f32) -> Self {
+        Self::Cone {
+            range,
+            half_angle,
+            direction: Vec3::NEG_Z,
+        }
+    }
+
+    /// Creates a cone along a local axis, e.g. `Vec3::X` for 2D side views.
+    pub fn cone_along(range: f32, half_angle: f32, direction: Vec3) -> Self {
+        Self::Cone {
+            range,
+            half_angle,
+            direction,
+        }
+    }
+
+    /// Returns true if a point in the shape's local space is inside it.
+    pub fn contains_local(&self, point: Vec3) -> bool {
+        match *self {
+            TargetShape::Sphere { radius } => point.length_squared() <= radius * radius,
+            TargetShape::Box { half_extents } => point.abs().cmple(half_extents).all(),
+            TargetShape::Cone {
+                range,
+                half_angle,
+                direction,
+            } => {
+                let distance = point.length();
+                if distance > range {
+                    return false;
+                }
+                if distance == 0.0 {
+                    return true;
+                }
+                let Some(axis) = direction.try_normalize() else {
+                    return false;
+                };
+                point.angle_between(axis) <= half_angle
+            }
+        }
+    }
+}
+
+/// Shape overlap targeting request.
+///
+/// # Example
+///
+/// ```ignore
+/// let targeting = ShapeTargeting::new(TargetShape::cone(300.0, 0.5))
+///     .with_filter(TargetFilter::new().exclude_self().hostile().alive_only())
+///     .with_max_targets(5);
+/// let target_data = targeting_query.overlap(caster, &targeting);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ShapeTargeting {
+    /// Overlap volume.
+    pub shape: TargetShape,
+    /// Shape origin offset in the caster's local space.
+    pub offset: Vec3,
+    /// Filter applied to every overlapping actor.
+    pub filter: TargetFilter,
+    /// Target order and cap, nearest first by default.
+    pub selection: TargetSelection,
+}
+
+impl ShapeTargeting {
+    /// Creates a request for `shape` centered on the caster, excluding it.
+    pub fn new(shape: TargetShape) -> Self {
+        Self {
+            shape,
+            offset: Vec3::ZERO,
+            filter: TargetFilter::new().exclude_self(),
+            selection: TargetSelection::nearest(),
+        }
+    }
+
+    /// Sets the origin offset in the caster's local space.
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Replaces the target filter, which excludes the caster by default.
+    pub fn with_filter(mut self, filter: TargetFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Keeps at most `max_targets` targets after sorting.
+    pub fn with_max_targets(mut self, max_targets: usize) -> Self {
+        self.selection.max_targets = Some(max_targets);
+        self
+    }
+
+    /// Sets the order targets are kept in.
+    pub fn with_sort(mut self, sort: TargetSort) -> Self {
+        self.selection.sort = sort;
+        self
+    }
+
+    /// Replaces the target selection.
+    pub fn with_selection(mut self, selection: TargetSelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Shape origin in world space for a caster transform.
+    pub fn origin(&self, caster: &GlobalTransform) -> Transform {
+        let caster = caster.compute_transform();
+        Transform {
+            translation: caster.transform_point(self.offset),
+            rotation: caster.rotation,
+            scale: Vec3::ONE,
+        }
+    }
+
+    /// Returns true if a world position is inside the shape placed at `origin`.
+    pub fn overlaps(&self, origin: &Transform, position: Vec3) -> bool {
+        let local = origin.rotation.inverse() * (position - origin.translation);
+        self.shape.contains_local(local)
+    }
+
+    /// Keeps the overlapping candidates accepted by `passes` and builds the
+    /// target data, closest first. The selection is not applied.
+    pub fn gather(
+        &self,
+        origin: Transform,
+        candidates: impl IntoIterator<Item = (Entity, Vec3)>,
+        passes: impl Fn(Entity) -> bool,
+    ) -> GameplayAbilityTargetData {
+        let mut hits: Vec<(Entity, f32)> = candidates
+            .into_iter()
+            .filter(|(_, position)| self.overlaps(&origin, *position))
+            .filter(|(entity, _)| passes(*entity))
+            .map(|(entity, position)| (entity, position.distance_squared(origin.translation)))
+            .collect();
+        hits.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        GameplayAbilityTargetData::from_actors(hits.into_iter().map(|(entity, _)| entity).collect())
+            .with_origin(origin)
+    }
+
+    /// Runs the overlap with direct world access.
+    ///
+    /// Returns empty target data if the caster has no `GlobalTransform`.
+    pub fn collect(&self, world: &mut World, caster: Entity) -> GameplayAbilityTargetData {
+        let mut state = SystemState::<TargetingQuery>::new(world);
+        state.get_mut(world).overlap(caster, self)
+    }
+}
+
+/// System parameter for running shape overlap targeting from systems.
+#[derive(SystemParam)]
+pub struct TargetingQuery<'w, 's> {
+    pub candidates: Query<'w, 's, (Entity, &'static GlobalTransform), With<OwnedTags>>,
+    pub filters: TargetFilterQuery<'w, 's>,
+    pub selection: TargetSelectionQuery<'w, 's>,
+}
+
+impl TargetingQuery<'_, '_> {
+    /// Gathers targets around `caster`.
+    ///
+    /// Returns empty target data if the caster has no `GlobalTransform`.
+    pub fn overlap(
+        &mut self,
+        caster: Entity,
+        targeting: &ShapeTargeting,
+    ) -> GameplayAbilityTargetData {
+        let Ok((_, caster_transform)) = self.candidates.get(caster) else {
+            warn!(
+                "Shape targeting caster {caster} is not an ability system actor with a transform"
+            );
+            return GameplayAbilityTargetData::empty();
+        };
+        let origin = targeting.origin(caster_transform);
+
+        let target_data = targeting.gather(
+            origin,
+            self.candidates
+                .iter()
+                .map(|(entity, transform)| (entity, transform.translation())),
+            |target| self.filters.passes(&targeting.filter, caster, target),
+        );
+        self.selection
+            .select(&targeting.selection, origin.translation, target_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_4;
+
+    fn spawn_actor(world: &mut World, position: Vec3) -> Entity {
+        world
+            .spawn((
+                OwnedTags::default(),
+                GlobalTransform::from_translation(position),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn test_shapes_contain_local_points() {
+        let sphere = TargetShape::sphere(2.0);
+        assert!(sphere.contains_local(Vec3::new(1.0, 1.0, 1.0)));
+        assert!(!sphere.contains_local(Vec3::new(2.0, 1.0, 0.0)));
+
+        let cuboid = TargetShape::cuboid(Vec3::new(1.0, 2.0, 3.0));
+        assert!(cuboid.contains_local(Vec3::new(-1.0, 2.0, 0.0)));
+        assert!(!cuboid.contains_local(Vec3::new(0.0, 2.5, 0.0)));
+
+        let cone = TargetShape::cone(10.0, FRAC_PI_4);
+        assert!(cone.contains_local(Vec3::new(0.0, 0.0, -5.0)));
+        assert!(cone.contains_local(Vec3::new(3.0, 0.0, -5.0)));
+        assert!(!cone.contains_local(Vec3::new(6.0, 0.0, -5.0)));
+        assert!(!cone.contains_local(Vec3::new(0.0, 0.0, 5.0)));
+        assert!(!cone.contains_local(Vec3::new(0.0, 0.0, -11.0)));
+    }
+
+    #[test]
+    fn test_collect_sorts_by_distance_and_excludes_caster() {
+        let mut world = World::new();
+        let caster = spawn_actor(&mut world, Vec3::ZERO);
+        let far = spawn_actor(&mut world, Vec3::new(4.0, 0.0, 0.0));
+        let near = spawn_actor(&mut world, Vec3::new(0.0, 1.0, 0.0));
+        spawn_actor(&mut world, Vec3::new(10.0, 0.0, 0.0));
+        // Not an ability system actor.
+        world.spawn(GlobalTransform::from_translation(Vec3::X));
+
+        let targeting = ShapeTargeting::new(TargetShape::sphere(5.0));
+        let target_data = targeting.collect(&mut world, caster);
+        assert_eq!(target_data.actors, vec![near, far]);
+        assert_eq!(target_data.origin.unwrap().translation, Vec3::ZERO);
+
+        let target_data = targeting
+            .clone()
+            .with_filter(TargetFilter::new())
+            .with_max_targets(2)
+            .collect(&mut world, caster);
+        assert_eq!(target_data.actors, vec![caster, near]);
+
+        let target_data = targeting
+            .clone()
+            .with_sort(TargetSort::Farthest)
+            .with_max_targets(1)
+            .collect(&mut world, caster);
+        assert_eq!(target_data.actors, vec![far]);
+    }
+
+    #[test]
+    fn test_cone_follows_caster_rotation() {
+        let mut world = World::new();
+        let caster = world
+            .spawn((
+                OwnedTags::default(),
+                GlobalTransform::from(Transform::from_rotation(Quat::from_rotation_y(
+                    std::f32::consts::FRAC_PI_2,
+                ))),
+            ))
+            .id();
+        // Rotated 90 degrees around Y, local -Z faces world -X.
+        let in_front = spawn_actor(&mut world, Vec3::new(-5.0, 0.0, 0.0));
+        spawn_actor(&mut world, Vec3::new(0.0, 0.0, -5.0));
+
+        let target_data =
+            ShapeTargeting::new(TargetShape::cone(10.0, FRAC_PI_4)).collect(&mut world, caster);
+        assert_eq!(target_data.actors, vec![in_front]);
+    }
+}
@@ -11,7 +11,9 @@ use bevy::prelude::*;
 use bevy_gameplay_tag::gameplay_tag::GameplayTag;
 use string_cache::DefaultAtom as Atom;
 
-use super::events::GameplayEvent;
+use super::activation_info::GameplayEventData;
+use super::events::{GameplayEvent, SendGameplayEventEvent};
+use crate::core::timestep::GasTime;
 use crate::effects::systems::{
     ApplyGameplayEffectEvent, GameplayEffectAppliedEvent, GameplayEffectRemovedEvent,
 };
@@ -75,6 +77,8 @@ pub struct WaitGameplayEventTask {
     pub only_trigger_once: bool,
     /// Whether the event has been received.
     pub triggered: bool,
+    /// Payload of the last [`SendGameplayEventEvent`] received.
+    pub payload: Option<GameplayEventData>,
 }
 
 impl WaitGameplayEventTask {
@@ -84,6 +88,7 @@ impl WaitGameplayEventTask {
             event_tag,
             only_trigger_once: true,
             triggered: false,
+            payload: None,
         }
     }
 
@@ -431,6 +436,66 @@ pub struct OverlapEvent {
     pub component_type: Option<&'static str>,
 }
 
+/// WaitAnimationNotify task - waits for an animation notify on the owner.
+///
+/// Completes when the owner's animation reaches a notify with the tag, so
+/// damage windows and spawns line up with the animation. Notifies arrive as
+/// [`AnimationNotifyEvent`]s forwarded by the game's animation code.
+#[derive(Component, Debug, Clone)]
+pub struct WaitAnimationNotifyTask {
+    /// The notify tag to wait for.
+    pub notify_tag: GameplayTag,
+    /// Whether to complete on the first notify.
+    pub only_trigger_once: bool,
+    /// How many notifies have been received.
+    pub received: u32,
+}
+
+impl WaitAnimationNotifyTask {
+    /// Create a new wait animation notify task.
+    pub fn new(notify_tag: GameplayTag) -> Self {
+        Self {
+            notify_tag,
+            only_trigger_once: true,
+            received: 0,
+        }
+    }
+
+    /// Set whether to complete on the first notify.
+    pub fn with_only_trigger_once(mut self, only_once: bool) -> Self {
+        self.only_trigger_once = only_once;
+        self
+    }
+}
+
+/// Event sent when an animation reaches a notify.
+///
+/// User code should send this event from its animation system, for example
+/// from an `AnimationEvent` observer.
+#[derive(Event, Debug, Clone)]
+pub struct AnimationNotifyEvent {
+    /// The entity whose animation reached the notify (usually the character).
+    pub entity: Entity,
+    /// The notify tag.
+    pub notify_tag: GameplayTag,
+}
+
+/// Event triggered for each notify a repeating `WaitAnimationNotify` task
+/// receives.
+#[derive(Event, Debug, Clone)]
+pub struct AnimationNotifyReceivedEvent {
+    /// The task entity.
+    pub task: Entity,
+    /// The ability instance that owns the task.
+    pub ability_instance: Option<Entity>,
+    /// The ability spec entity.
+    pub ability_spec: Entity,
+    /// The owner entity.
+    pub owner: Entity,
+    /// The notify tag.
+    pub notify_tag: GameplayTag,
+}
+
 /// Event triggered when a task completes.
 #[derive(Event, Debug, Clone)]
 pub struct TaskCompletedEvent {
@@ -462,7 +527,7 @@ pub struct TaskCancelledEvent {
 /// System that ticks WaitDelay tasks.
 pub fn tick_wait_delay_tasks_system(
     mut commands: Commands,
-    time: Res<Time>,
+    time: GasTime,
     mut tasks: Query<(Entity, &AbilityTask, &mut WaitDelayTask, &mut TaskState)>,
 ) {
     for (task_entity, ability_task, mut wait_delay, mut state) in tasks.iter_mut() {
@@ -470,7 +535,7 @@ pub fn tick_wait_delay_tasks_system(
             continue;
         }
 
-        wait_delay.remaining -= time.delta_secs();
+        wait_delay.remaining -= time.delta_secs(ability_task.owner);
 
         if wait_delay.remaining <= 0.0 {
             *state = TaskState::Completed;
@@ -532,6 +597,43 @@ pub fn handle_gameplay_event_for_tasks_system(
     }
 }
 
+/// Observer that delivers a [`SendGameplayEventEvent`] to the running
+/// `WaitGameplayEvent` tasks of the target's abilities.
+pub fn on_send_gameplay_event_for_tasks(
+    ev: On<SendGameplayEventEvent>,
+    mut commands: Commands,
+    mut tasks: Query<(
+        Entity,
+        &AbilityTask,
+        &mut WaitGameplayEventTask,
+        &mut TaskState,
+    )>,
+) {
+    let event = ev.event();
+
+    for (task_entity, ability_task, mut wait_event, mut state) in tasks.iter_mut() {
+        if *state != TaskState::Running
+            || ability_task.owner != event.target
+            || wait_event.event_tag != event.tag
+        {
+            continue;
+        }
+
+        wait_event.triggered = true;
+        wait_event.payload = Some(event.routed_payload());
+
+        if wait_event.only_trigger_once {
+            *state = TaskState::Completed;
+            commands.trigger(TaskCompletedEvent {
+                task: task_entity,
+                ability_instance: ability_task.ability_instance,
+                ability_spec: ability_task.ability_spec,
+                owner: ability_task.owner,
+            });
+        }
+    }
+}
+
 /// System that cleans up completed or cancelled tasks.
 pub fn cleanup_finished_tasks_system(
     mut commands: Commands,
@@ -711,6 +813,8 @@ pub fn on_effect_removed_for_tasks(
 }
 
 /// System that executes ApplyEffectToTargetData tasks.
+///
+/// Targets are filtered by the ability definition's `target_filter`.
 pub fn execute_apply_effect_to_target_data_tasks_system(
     mut commands: Commands,
     mut tasks: Query<(
@@ -719,14 +823,29 @@ pub fn execute_apply_effect_to_target_data_tasks_system(
         &mut ApplyEffectToTargetDataTask,
         &mut TaskState,
     )>,
+    specs: Query<&super::components::AbilitySpec>,
+    registry: Res<super::definition::AbilityRegistry>,
+    filters: super::target_filter::TargetFilterQuery,
 ) {
     for (task_entity, ability_task, mut apply_effect, mut state) in tasks.iter_mut() {
         if *state != TaskState::Running || apply_effect.executed {
             continue;
         }
 
-        // Apply effect to all actors in target data
+        let filter = specs
+            .get(ability_task.ability_spec)
+            .ok()
+            .and_then(|spec| registry.get(&spec.definition_id))
+            .map(|definition| &definition.target_filter);
+
+        // Apply effect to all actors in target data that pass the filter
         for &target in &apply_effect.target_data.actors {
+            if let Some(filter) = filter
+                && !filters.passes(filter, ability_task.owner, target)
+            {
+                continue;
+            }
+
             commands.trigger(
                 ApplyGameplayEffectEvent::new(apply_effect.effect_definition_id.clone(), target)
                     .with_instigator(ability_task.owner)
@@ -867,6 +986,49 @@ pub fn handle_overlap_for_tasks_system(
     }
 }
 
+/// Observer that handles animation notifies for WaitAnimationNotify tasks.
+pub fn handle_animation_notify_for_tasks_system(
+    trigger: On<AnimationNotifyEvent>,
+    mut commands: Commands,
+    mut tasks: Query<(
+        Entity,
+        &AbilityTask,
+        &mut WaitAnimationNotifyTask,
+        &mut TaskState,
+    )>,
+) {
+    let event = trigger.event();
+
+    for (task_entity, ability_task, mut wait_notify, mut state) in tasks.iter_mut() {
+        if *state != TaskState::Running
+            || ability_task.owner != event.entity
+            || wait_notify.notify_tag != event.notify_tag
+        {
+            continue;
+        }
+
+        wait_notify.received += 1;
+
+        if wait_notify.only_trigger_once {
+            *state = TaskState::Completed;
+            commands.trigger(TaskCompletedEvent {
+                task: task_entity,
+                ability_instance: ability_task.ability_instance,
+                ability_spec: ability_task.ability_spec,
+                owner: ability_task.owner,
+            });
+        } else {
+            commands.trigger(AnimationNotifyReceivedEvent {
+                task: task_entity,
+                ability_instance: ability_task.ability_instance,
+                ability_spec: ability_task.ability_spec,
+                owner: ability_task.owner,
+                notify_tag: event.notify_tag.clone(),
+            });
+        }
+    }
+}
+
 /// Observer that cancels all tasks when an ability instance is removed.
 pub fn on_ability_instance_removed(
     trigger: On<Remove, super::components::AbilitySpecInstance>,
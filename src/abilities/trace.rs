@@ -0,0 +1,133 @@
+//! Physics trace targeting.
+//!
+//! Backend-agnostic request and hit types for line traces and shape casts.
+//! The queries themselves come from a physics backend feature: `avian3d`
+//! provides [`AvianTargetingQuery`], `rapier3d` provides [`RapierTargetingQuery`].
+//! A [`TraceHit`] converts into target data or directly into an effect
+//! application carrying the hit location and normal in its context.
+
+use super::target_data::GameplayAbilityTargetData;
+use crate::effects::{ApplyGameplayEffectEvent, GameplayEffectContext, GameplayEffectSpec};
+use bevy::prelude::*;
+use string_cache::DefaultAtom as Atom;
+
+#[cfg(feature = "avian3d")]
+pub use super::trace_avian::*;
+#[cfg(feature = "rapier3d")]
+pub use super::trace_rapier::*;
+
+/// A ray or shape cast from a point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceRequest {
+    /// World-space start of the trace.
+    pub origin: Vec3,
+    /// Trace direction.
+    pub direction: Dir3,
+    /// Maximum trace length.
+    pub max_distance: f32,
+    /// Entities the trace passes through, typically the caster.
+    pub ignored: Vec<Entity>,
+}
+
+impl TraceRequest {
+    /// Creates a trace from `origin` along `direction`.
+    pub fn new(origin: Vec3, direction: Dir3, max_distance: f32) -> Self {
+        Self {
+            origin,
+            direction,
+            max_distance,
+            ignored: Vec::new(),
+        }
+    }
+
+    /// Creates a trace along the forward (-Z) axis of a transform.
+    pub fn forward_from(transform: &GlobalTransform, max_distance: f32) -> Self {
+        Self::new(transform.translation(), transform.forward(), max_distance)
+    }
+
+    /// Makes the trace pass through `entity`.
+    pub fn ignoring(mut self, entity: Entity) -> Self {
+        self.ignored.push(entity);
+        self
+    }
+
+    /// World position at the end of the trace.
+    pub fn end(&self) -> Vec3 {
+        self.origin + self.direction * self.max_distance
+    }
+}
+
+/// The closest hit of a trace.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TraceHit {
+    /// The collider entity that was hit.
+    pub entity: Entity,
+    /// World-space hit location.
+    pub location: Vec3,
+    /// World-space surface normal at the hit.
+    pub normal: Vec3,
+    /// Distance travelled from the trace origin.
+    pub distance: f32,
+}
+
+impl TraceHit {
+    /// Builds target data with the hit entity, the trace origin and the hit
+    /// location as end point.
+    pub fn to_target_data(&self, request: &TraceRequest) -> GameplayAbilityTargetData {
+        GameplayAbilityTargetData::from_actor(self.entity)
+            .with_origin(Transform::from_translation(request.origin))
+            .with_end_point(Transform::from_translation(self.location))
+    }
+
+    /// Adds the hit location and normal to an effect context.
+    pub fn apply_to_context(&self, context: GameplayEffectContext) -> GameplayEffectContext {
+        context
+            .with_hit_location(self.location)
+            .with_hit_normal(self.normal)
+    }
+
+    /// Builds an effect application on the hit entity.
+    ///
+    /// The context carries `source`, the hit location and the hit normal.
+    pub fn apply_effect(
+        &self,
+        effect_id: impl Into<Atom>,
+        source: Entity,
+    ) -> ApplyGameplayEffectEvent {
+        let context = self.apply_to_context(GameplayEffectContext::new().with_source(source));
+        ApplyGameplayEffectEvent::from_spec(
+            GameplayEffectSpec::new(effect_id, self.entity).with_context(context),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hit_fills_target_data_and_context() {
+        let mut world = World::new();
+        let caster = world.spawn_empty().id();
+        let target = world.spawn_empty().id();
+        let request = TraceRequest::new(Vec3::ZERO, Dir3::X, 100.0).ignoring(caster);
+        let hit = TraceHit {
+            entity: target,
+            location: Vec3::new(10.0, 0.0, 0.0),
+            normal: Vec3::NEG_X,
+            distance: 10.0,
+        };
+
+        let target_data = hit.to_target_data(&request);
+        assert_eq!(target_data.primary_target(), Some(target));
+        assert_eq!(target_data.end_point_location(), Some(hit.location));
+
+        let event = hit.apply_effect("hitscan", caster);
+        let spec = event.spec;
+        assert_eq!(spec.target, target);
+        assert_eq!(spec.context.source, Some(caster));
+        assert_eq!(spec.context.hit_location, Some(hit.location));
+        assert_eq!(spec.context.hit_normal, Some(Vec3::NEG_X));
+        assert_eq!(request.end(), Vec3::new(100.0, 0.0, 0.0));
+    }
+}
@@ -0,0 +1,76 @@
+//! Trace targeting backend built on `avian3d` spatial queries.
+//!
+//! Only compiled with the `avian3d` feature. Requires avian's `PhysicsPlugins`.
+
+use super::trace::{TraceHit, TraceRequest};
+use avian3d::prelude::{Collider, ShapeCastConfig, SpatialQuery, SpatialQueryFilter};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// System parameter running line traces, shape casts and overlaps through
+/// avian3d.
+#[derive(SystemParam)]
+pub struct AvianTargetingQuery<'w, 's> {
+    pub spatial_query: SpatialQuery<'w, 's>,
+}
+
+impl AvianTargetingQuery<'_, '_> {
+    /// Casts a ray and returns the closest hit.
+    pub fn line_trace(&self, request: &TraceRequest) -> Option<TraceHit> {
+        let filter = SpatialQueryFilter::from_excluded_entities(request.ignored.iter().copied());
+        let hit = self.spatial_query.cast_ray(
+            request.origin,
+            request.direction,
+            request.max_distance,
+            true,
+            &filter,
+        )?;
+
+        Some(TraceHit {
+            entity: hit.entity,
+            location: request.origin + request.direction * hit.distance,
+            normal: hit.normal,
+            distance: hit.distance,
+        })
+    }
+
+    /// Sweeps `shape` with `rotation` along the trace and returns the first hit.
+    pub fn shape_trace(
+        &self,
+        shape: &Collider,
+        rotation: Quat,
+        request: &TraceRequest,
+    ) -> Option<TraceHit> {
+        let filter = SpatialQueryFilter::from_excluded_entities(request.ignored.iter().copied());
+        let config = ShapeCastConfig::from_max_distance(request.max_distance);
+        let hit = self.spatial_query.cast_shape(
+            shape,
+            request.origin,
+            rotation,
+            request.direction,
+            &config,
+            &filter,
+        )?;
+
+        Some(TraceHit {
+            entity: hit.entity,
+            location: hit.point1,
+            normal: hit.normal1,
+            distance: hit.distance,
+        })
+    }
+
+    /// Returns the colliders intersecting `shape` placed at `position` with
+    /// `rotation`, except the `ignored` ones.
+    pub fn shape_overlap(
+        &self,
+        shape: &Collider,
+        position: Vec3,
+        rotation: Quat,
+        ignored: &[Entity],
+    ) -> Vec<Entity> {
+        let filter = SpatialQueryFilter::from_excluded_entities(ignored.iter().copied());
+        self.spatial_query
+            .shape_intersections(shape, position, rotation, &filter)
+    }
+}
@@ -0,0 +1,110 @@
+//! Trace targeting backend built on `bevy_rapier3d` scene queries.
+//!
+//! Only compiled with the `rapier3d` feature. Requires `RapierPhysicsPlugin`.
+
+use super::trace::{TraceHit, TraceRequest};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::{Collider, QueryFilter, ReadRapierContext, ShapeCastOptions};
+
+/// System parameter running line traces, shape casts and overlaps through
+/// rapier.
+#[derive(SystemParam)]
+pub struct RapierTargetingQuery<'w, 's> {
+    pub context: ReadRapierContext<'w, 's>,
+}
+
+impl RapierTargetingQuery<'_, '_> {
+    /// Casts a ray and returns the closest hit.
+    pub fn line_trace(&self, request: &TraceRequest) -> Option<TraceHit> {
+        let Ok(context) = self.context.single() else {
+            warn!("Line trace requested without a single rapier context");
+            return None;
+        };
+        let ignored = &request.ignored;
+        let predicate = |entity: Entity| !ignored.contains(&entity);
+        let (entity, hit) = context.cast_ray_and_get_normal(
+            request.origin,
+            request.direction.as_vec3(),
+            request.max_distance,
+            true,
+            QueryFilter::default().predicate(&predicate),
+        )?;
+
+        Some(TraceHit {
+            entity,
+            location: hit.point,
+            normal: hit.normal,
+            distance: hit.time_of_impact,
+        })
+    }
+
+    /// Sweeps `shape` with `rotation` along the trace and returns the first hit.
+    pub fn shape_trace(
+        &self,
+        shape: &Collider,
+        rotation: Quat,
+        request: &TraceRequest,
+    ) -> Option<TraceHit> {
+        let Ok(context) = self.context.single() else {
+            warn!("Shape trace requested without a single rapier context");
+            return None;
+        };
+        let ignored = &request.ignored;
+        let predicate = |entity: Entity| !ignored.contains(&entity);
+        let options = ShapeCastOptions::with_max_time_of_impact(request.max_distance);
+        let (entity, hit) = context.cast_shape(
+            request.origin,
+            rotation,
+            request.direction.as_vec3(),
+            &*shape.raw,
+            options,
+            QueryFilter::default().predicate(&predicate),
+        )?;
+
+        // The velocity is a unit vector, so time of impact is the distance.
+        let distance = hit.time_of_impact;
+        let (location, normal) = hit.details.map_or(
+            (
+                request.origin + request.direction * distance,
+                -request.direction.as_vec3(),
+            ),
+            |details| (details.witness1, details.normal1),
+        );
+
+        Some(TraceHit {
+            entity,
+            location,
+            normal,
+            distance,
+        })
+    }
+
+    /// Returns the colliders intersecting `shape` placed at `position` with
+    /// `rotation`, except the `ignored` ones.
+    pub fn shape_overlap(
+        &self,
+        shape: &Collider,
+        position: Vec3,
+        rotation: Quat,
+        ignored: &[Entity],
+    ) -> Vec<Entity> {
+        let Ok(context) = self.context.single() else {
+            warn!("Shape overlap requested without a single rapier context");
+            return Vec::new();
+        };
+        let predicate = |entity: Entity| !ignored.contains(&entity);
+        let mut hits = Vec::new();
+        context.intersect_shape(
+            position,
+            rotation,
+            &*shape.raw,
+            QueryFilter::default().predicate(&predicate),
+            |entity| {
+                hits.push(entity);
+                true
+            },
+        );
+        hits
+    }
+}
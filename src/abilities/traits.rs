@@ -3,7 +3,8 @@
 //! Defines the lifecycle hooks for custom ability implementations.
 
 use crate::abilities::OnGameplayAbilityEnded;
-use crate::core::{ApplyGameplayEffectEvent, OwnedTags};
+use crate::abilities::tag_relationships::AbilityTagRelationshipMapping;
+use crate::core::{ApplyGameplayEffectEvent, BlockedAbilityTags, OwnedTags};
 use bevy::prelude::*;
 use bevy_gameplay_tag::{GameplayTagContainer, GameplayTagsManager};
 
@@ -25,6 +26,8 @@ pub enum ActivationCheckFailure {
     TargetHasBlockedTags(GameplayTagContainer),
     /// Missing required components or resources.
     MissingComponents,
+    /// The behavior's own logic refused activation.
+    RejectedByBehavior,
 }
 
 /// Result type for activation checks.
@@ -107,6 +110,58 @@ pub trait AbilityBehavior: Send + Sync + 'static {
             return Err(ActivationCheckFailure::SourceHasBlockedTags(blocked_tags));
         }
 
+        // Check abilities blocked on the source by other abilities or statuses
+        if let Some(blocked_ability_tags) = world.get::<BlockedAbilityTags>(source) {
+            let mut blocked_tags = GameplayTagContainer::default();
+            blocked_tags.append_matches_tags(
+                &definition.ability_tags,
+                &blocked_ability_tags.0.explicit_tags,
+                tags_manager,
+            );
+            if !blocked_tags.is_empty() {
+                return Err(ActivationCheckFailure::SourceHasBlockedTags(blocked_tags));
+            }
+        }
+
+        // Check activation tags, merged with the global tag relationships
+        let mut required_tags = definition.activation_required_tags.clone();
+        let mut activation_blocked_tags = definition.activation_blocked_tags.clone();
+        if let Some(relationships) = world.get_resource::<AbilityTagRelationshipMapping>() {
+            required_tags.append_tags(
+                &relationships.activation_required_tags(&definition.ability_tags, tags_manager),
+                tags_manager,
+            );
+            activation_blocked_tags.append_tags(
+                &relationships.activation_blocked_tags(&definition.ability_tags, tags_manager),
+                tags_manager,
+            );
+        }
+        if !required_tags.is_empty()
+            && !source_tags.0.has_all_matching_gameplay_tags(&required_tags)
+        {
+            let mut missing_tags = GameplayTagContainer::default();
+            missing_tags.append_matches_tags(
+                &required_tags,
+                &source_tags.0.explicit_tags,
+                tags_manager,
+            );
+            return Err(ActivationCheckFailure::SourceMissingRequiredTags(
+                missing_tags,
+            ));
+        }
+        if source_tags
+            .0
+            .has_any_matching_gameplay_tags(&activation_blocked_tags)
+        {
+            let mut blocked_tags = GameplayTagContainer::default();
+            blocked_tags.append_matches_tags(
+                &source_tags.0.explicit_tags,
+                &activation_blocked_tags,
+                tags_manager,
+            );
+            return Err(ActivationCheckFailure::SourceHasBlockedTags(blocked_tags));
+        }
+
         Ok(())
     }
 
@@ -0,0 +1,226 @@
+//! Routing of ability activation requests between clients and the server.
+//!
+//! Clients do not run `ServerInitiated` and `ServerOnly` abilities (and only
+//! predict `LocalPredicted` ones); they trigger an
+//! [`AbilityActivationRequestEvent`] instead. An [`AbilityNetTransport`]
+//! installed as the [`AbilityTransport`] resource carries requests to the
+//! server and answers back, so any networking crate can plug in.
+//!
+//! On the server, the transport triggers a [`ClientActivationRequestEvent`]
+//! for each request it receives. The request is validated, then activated as
+//! a normal [`TryActivateAbilityEvent`] with a [`RemoteActivationRequest`] on
+//! the spec. If the activation fails, or once it commits, the server sends an
+//! [`AbilityActivationResponseEvent`] to the requesting client.
+//!
+//! On the client, the transport triggers the received response. It resolves
+//! the prediction, if any, and a rejection is surfaced as an
+//! [`AbilityActivationFailedEvent`] with the server's reason.
+
+use super::components::{AbilityOwner, AbilitySpec};
+use super::definition::{AbilityRegistry, NetExecutionPolicy};
+use super::prediction::{
+    AbilityActivationRequestEvent, AbilityPredictionResolvedEvent, NetRole, PredictionKey,
+};
+use super::systems::{
+    AbilityActivationFailedEvent, ActivationFailureReason, TryActivateAbilityEvent,
+};
+use bevy::prelude::*;
+
+/// Identifies a connected client to a transport.
+///
+/// The value is opaque to GAS; transports choose their own encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NetClientId(pub u64);
+
+/// Sends ability activation traffic over a network.
+pub trait AbilityNetTransport: Send + Sync + 'static {
+    /// Sends an activation request from this client to the server.
+    fn send_request(&self, commands: &mut Commands, request: AbilityActivationRequestEvent);
+
+    /// Sends the server's answer to the client that made a request.
+    fn send_response(
+        &self,
+        commands: &mut Commands,
+        client: NetClientId,
+        response: AbilityActivationResponseEvent,
+    );
+}
+
+/// The transport used for ability activation traffic.
+#[derive(Resource)]
+pub struct AbilityTransport(pub Box<dyn AbilityNetTransport>);
+
+impl AbilityTransport {
+    /// Wraps a transport.
+    pub fn new(transport: impl AbilityNetTransport) -> Self {
+        Self(Box::new(transport))
+    }
+}
+
+/// Event triggered on the server by a transport when a request arrives.
+#[derive(Event, Debug, Clone)]
+pub struct ClientActivationRequestEvent {
+    /// The client that sent the request.
+    pub client: NetClientId,
+    /// The request as sent by the client.
+    pub request: AbilityActivationRequestEvent,
+}
+
+/// The server's answer to an activation request.
+///
+/// Sent when a requested activation is rejected or commits.
+#[derive(Event, Debug, Clone)]
+#[cfg_attr(
+    feature = "replicon",
+    derive(serde::Serialize, serde::Deserialize, bevy::ecs::entity::MapEntities)
+)]
+pub struct AbilityActivationResponseEvent {
+    /// The ability spec entity.
+    #[cfg_attr(feature = "replicon", entities)]
+    pub ability_spec: Entity,
+    /// The owner entity.
+    #[cfg_attr(feature = "replicon", entities)]
+    pub owner: Entity,
+    /// Key of the client's prediction, if it predicted the activation.
+    pub prediction_key: Option<PredictionKey>,
+    /// Why the server refused the activation, if it did.
+    pub rejection: Option<ActivationFailureReason>,
+}
+
+/// Marks a spec whose activation was requested by a client (server).
+///
+/// Removed when the server answers the request.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RemoteActivationRequest {
+    /// The client to answer.
+    pub client: NetClientId,
+    /// Key of the client's prediction, if any.
+    pub prediction_key: Option<PredictionKey>,
+}
+
+/// Observer that hands a client's activation requests to the transport.
+pub fn send_activation_request(
+    ev: On<AbilityActivationRequestEvent>,
+    mut commands: Commands,
+    net_role: Res<NetRole>,
+    transport: Option<Res<AbilityTransport>>,
+) {
+    if *net_role != NetRole::Client {
+        return;
+    }
+    let Some(transport) = transport else {
+        debug!("No ability transport installed, dropping activation request");
+        return;
+    };
+    transport.0.send_request(&mut commands, ev.event().clone());
+}
+
+/// Observer that validates a client's request and activates the ability.
+///
+/// The spec must exist, belong to the requested owner and not be `LocalOnly`.
+/// Which owners a client may control is left to the game.
+pub fn on_client_activation_request(
+    ev: On<ClientActivationRequestEvent>,
+    mut commands: Commands,
+    ability_registry: Res<AbilityRegistry>,
+    specs: Query<(&AbilitySpec, &AbilityOwner)>,
+    transport: Option<Res<AbilityTransport>>,
+) {
+    let client = ev.client;
+    let request = &ev.request;
+    let spec_entity = request.ability_spec;
+
+    let valid = specs.get(spec_entity).is_ok_and(|(spec, owner)| {
+        owner.0 == request.owner
+            && ability_registry
+                .get(&spec.definition_id)
+                .is_some_and(|definition| {
+                    definition.net_execution_policy != NetExecutionPolicy::LocalOnly
+                })
+    });
+    if !valid {
+        warn!(
+            "Client {:?} sent an invalid activation request for spec {spec_entity}",
+            client
+        );
+        if let Some(transport) = transport {
+            transport.0.send_response(
+                &mut commands,
+                client,
+                AbilityActivationResponseEvent {
+                    ability_spec: spec_entity,
+                    owner: request.owner,
+                    prediction_key: request.prediction_key,
+                    rejection: Some(ActivationFailureReason::InvalidRequest),
+                },
+            );
+        }
+        return;
+    }
+
+    commands
+        .entity(spec_entity)
+        .insert(RemoteActivationRequest {
+            client,
+            prediction_key: request.prediction_key,
+        });
+    let mut activation = TryActivateAbilityEvent::new(spec_entity, request.owner);
+    if let Some(prediction_key) = request.prediction_key {
+        activation = activation.with_prediction_key(prediction_key);
+    }
+    commands.trigger(activation);
+}
+
+/// Observer that applies the server's answer on the client.
+pub fn on_activation_response(ev: On<AbilityActivationResponseEvent>, mut commands: Commands) {
+    let response = ev.event();
+    if let Some(prediction_key) = response.prediction_key {
+        commands.trigger(AbilityPredictionResolvedEvent {
+            ability_spec: response.ability_spec,
+            prediction_key,
+            accepted: response.rejection.is_none(),
+        });
+    }
+    if let Some(reason) = response.rejection {
+        debug!(
+            "Server rejected activation of spec {:?}: {:?}",
+            response.ability_spec, reason
+        );
+        commands.trigger(AbilityActivationFailedEvent {
+            ability_spec: response.ability_spec,
+            owner: response.owner,
+            reason,
+        });
+    }
+}
+
+/// Queues answering the client that requested a spec's activation, if any.
+pub(crate) fn respond_to_remote_request(
+    commands: &mut Commands,
+    ability_spec: Entity,
+    rejection: Option<ActivationFailureReason>,
+) {
+    commands.queue(move |world: &mut World| {
+        let Ok(mut spec_entity) = world.get_entity_mut(ability_spec) else {
+            return;
+        };
+        let Some(request) = spec_entity.take::<RemoteActivationRequest>() else {
+            return;
+        };
+        let owner = spec_entity
+            .get::<AbilityOwner>()
+            .map_or(Entity::PLACEHOLDER, |owner| owner.0);
+        let response = AbilityActivationResponseEvent {
+            ability_spec,
+            owner,
+            prediction_key: request.prediction_key,
+            rejection,
+        };
+        world.try_resource_scope(|world, transport: Mut<AbilityTransport>| {
+            transport
+                .0
+                .send_response(&mut world.commands(), request.client, response);
+        });
+        world.flush();
+    });
+}
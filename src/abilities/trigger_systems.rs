@@ -3,9 +3,9 @@
 //! Systems that handle automatic ability activation based on triggers.
 
 use super::components::*;
-use super::events::GameplayEvent;
+use super::events::{GameplayEvent, SendGameplayEventEvent};
 use super::triggers::*;
-use crate::core::OwnedTags;
+use crate::core::{OwnedTags, OwnerTagsChangedEvent};
 use bevy::prelude::*;
 use bevy_gameplay_tag::GameplayTagsManager;
 
@@ -39,90 +39,113 @@ pub fn handle_gameplay_event_triggers_system(
     }
 }
 
-/// System that handles OwnedTagAdded triggers.
+/// Observer that activates the target's abilities triggered by a
+/// [`SendGameplayEventEvent`], passing them the event's payload.
+pub fn on_send_gameplay_event(
+    ev: On<SendGameplayEventEvent>,
+    owners: Query<&OwnedAbilities>,
+    abilities: Query<&AbilityTriggers>,
+    mut commands: Commands,
+) {
+    let event = ev.event();
+    let Ok(owned) = owners.get(event.target) else {
+        return;
+    };
+
+    for ability_entity in owned.iter() {
+        let Ok(triggers) = abilities.get(ability_entity) else {
+            continue;
+        };
+        if triggers.has_trigger(&event.tag, AbilityTriggerSource::GameplayEvent) {
+            commands.trigger(
+                super::systems::TryActivateAbilityEvent::new(ability_entity, event.target)
+                    .with_event_payload(event.routed_payload()),
+            );
+        }
+    }
+}
+
+/// Observer that handles OwnedTagAdded triggers.
 ///
-/// When a tag is added to an entity, this system finds all abilities with
-/// matching OwnedTagAdded triggers and attempts to activate them.
+/// When an owner gains a tag, this observer activates its abilities with an
+/// OwnedTagAdded trigger on that tag or one of its parents.
 pub fn handle_owned_tag_added_triggers_system(
-    changed_tags: Query<(Entity, &OwnedTags), Changed<OwnedTags>>,
-    abilities: Query<(
-        Entity,
-        &AbilitySpec,
-        &AbilityOwner,
-        Option<&AbilityTriggers>,
-    )>,
-    _tags_manager: Res<GameplayTagsManager>,
+    trigger: On<OwnerTagsChangedEvent>,
+    owners: Query<&OwnedAbilities>,
+    abilities: Query<&AbilityTriggers>,
+    tags_manager: Res<GameplayTagsManager>,
     mut commands: Commands,
 ) {
-    for (owner_entity, owner_tags) in changed_tags.iter() {
-        // Check each ability owned by this entity
-        for (ability_entity, _spec, ability_owner, triggers) in abilities.iter() {
-            if ability_owner.0 != owner_entity {
-                continue;
-            }
+    let event = trigger.event();
+    if event.added.is_empty() {
+        return;
+    }
+    let Ok(owned) = owners.get(event.owner) else {
+        return;
+    };
 
-            if let Some(triggers) = triggers {
-                // Check if any OwnedTagAdded triggers match newly added tags
-                for trigger in &triggers.triggers {
-                    if trigger.trigger_source == AbilityTriggerSource::OwnedTagAdded {
-                        // Check if the trigger tag is present in owner's tags
-                        if owner_tags.0.explicit_tags.has_tag(&trigger.trigger_tag) {
-                            // Trigger ability activation
-                            commands.trigger(super::systems::TryActivateAbilityEvent::new(
-                                ability_entity,
-                                owner_entity,
-                            ));
-                        }
-                    }
-                }
-            }
+    for ability_entity in owned.iter() {
+        let Ok(triggers) = abilities.get(ability_entity) else {
+            continue;
+        };
+        let triggered = triggers.triggers.iter().any(|trigger| {
+            trigger.trigger_source == AbilityTriggerSource::OwnedTagAdded
+                && event
+                    .added
+                    .iter()
+                    .any(|tag| tag.matches_tag(&trigger.trigger_tag, &tags_manager))
+        });
+        if triggered {
+            commands.trigger(super::systems::TryActivateAbilityEvent::new(
+                ability_entity,
+                event.owner,
+            ));
         }
     }
 }
 
-/// System that handles OwnedTagPresent triggers.
+/// Observer that handles OwnedTagPresent triggers.
 ///
-/// Activates abilities when their trigger tag is present, and cancels them
-/// when the tag is removed.
+/// Activates abilities when their trigger tag is gained, and cancels them
+/// when the tag is lost.
 pub fn handle_owned_tag_present_triggers_system(
-    changed_tags: Query<(Entity, &OwnedTags), Changed<OwnedTags>>,
-    abilities: Query<(
-        Entity,
-        &AbilitySpec,
-        &AbilityOwner,
-        &AbilityActiveState,
-        Option<&AbilityTriggers>,
-    )>,
+    trigger: On<OwnerTagsChangedEvent>,
+    owners: Query<(&OwnedTags, &OwnedAbilities)>,
+    abilities: Query<(&AbilityTriggers, &AbilityActiveState)>,
+    tags_manager: Res<GameplayTagsManager>,
     mut commands: Commands,
 ) {
-    for (owner_entity, owner_tags) in changed_tags.iter() {
-        // Check each ability owned by this entity
-        for (ability_entity, _spec, ability_owner, active_state, triggers) in abilities.iter() {
-            if ability_owner.0 != owner_entity {
+    let event = trigger.event();
+    let Ok((owner_tags, owned)) = owners.get(event.owner) else {
+        return;
+    };
+
+    for ability_entity in owned.iter() {
+        let Ok((triggers, active_state)) = abilities.get(ability_entity) else {
+            continue;
+        };
+        for trigger in &triggers.triggers {
+            if trigger.trigger_source != AbilityTriggerSource::OwnedTagPresent
+                || !event
+                    .added
+                    .iter()
+                    .chain(&event.removed)
+                    .any(|tag| tag.matches_tag(&trigger.trigger_tag, &tags_manager))
+            {
                 continue;
             }
-
-            if let Some(triggers) = triggers {
-                for trigger in &triggers.triggers {
-                    if trigger.trigger_source == AbilityTriggerSource::OwnedTagPresent {
-                        let has_tag = owner_tags.0.explicit_tags.has_tag(&trigger.trigger_tag);
-
-                        if has_tag && !active_state.is_active {
-                            // Tag is present and ability is not active - activate it
-                            commands.trigger(super::systems::TryActivateAbilityEvent::new(
-                                ability_entity,
-                                owner_entity,
-                            ));
-                        } else if !has_tag && active_state.is_active {
-                            // Tag is removed and ability is active - cancel all instances
-                            // Note: We don't have instance entity here, so we'll need to query for it
-                            // For now, we'll just log a warning
-                            warn!(
-                                "OwnedTagPresent trigger: tag removed but cannot cancel ability without instance entity"
-                            );
-                        }
-                    }
-                }
+            let has_tag = owner_tags.0.explicit_tags.has_tag(&trigger.trigger_tag);
+            if has_tag && !active_state.is_active {
+                commands.trigger(super::systems::TryActivateAbilityEvent::new(
+                    ability_entity,
+                    event.owner,
+                ));
+            } else if !has_tag && active_state.is_active {
+                commands.trigger(super::systems::CancelAbilityEvent {
+                    instance: None,
+                    ability_spec: ability_entity,
+                    owner: event.owner,
+                });
             }
         }
     }
@@ -10,6 +10,7 @@ use bevy_gameplay_tag::gameplay_tag::GameplayTag;
 /// Defines what type of event will activate the ability.
 /// Matches UE GAS's `EGameplayAbilityTriggerSource`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AbilityTriggerSource {
     /// Triggered by an external gameplay event.
     ///
@@ -32,8 +33,13 @@ pub enum AbilityTriggerSource {
 /// Defines how an ability will be triggered by external events.
 /// Matches UE GAS's `FAbilityTriggerData`.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AbilityTriggerData {
     /// The tag to respond to.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::gameplay_tag_serde")
+    )]
     pub trigger_tag: GameplayTag,
     /// The type of trigger to respond to.
     pub trigger_source: AbilityTriggerSource,
@@ -0,0 +1,125 @@
+//! Ability usage statistics.
+//!
+//! Ability specs carrying an [`AbilityUsageStats`] count their activations,
+//! the seconds they spend active and the damage and healing of the effects
+//! they instigate, as reported by the combat log events. Specs without the
+//! component are not tracked. [`AbilityUsage`] reads the statistics of a spec
+//! or of an owner's abilities, e.g. for abilities that level up with use.
+//!
+//! # Example
+//! ```ignore
+//! commands.entity(spec).insert(AbilityUsageStats::default());
+//!
+//! fn level_up_abilities(usage: AbilityUsage, mut specs: Query<&mut AbilitySpec>) {
+//!     for (spec_entity, _, stats) in usage.of_owner(player) {
+//!         let mut spec = specs.get_mut(spec_entity).unwrap();
+//!         if stats.activation_count >= 50 * spec.level as u32 {
+//!             spec.level += 1;
+//!         }
+//!     }
+//! }
+//! ```
+
+use super::components::{AbilityActiveState, AbilityOwner, AbilitySpec, OwnedAbilities};
+use super::systems::AbilityActivatedEvent;
+use crate::core::timestep::GasTime;
+use crate::effects::combat_log::{DamageDealtEvent, HealingDoneEvent};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use string_cache::DefaultAtom as Atom;
+
+/// Usage statistics of an ability spec.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AbilityUsageStats {
+    /// Number of successful activations.
+    pub activation_count: u32,
+    /// Seconds the ability has been active, in GAS time.
+    pub active_time: f32,
+    /// Damage dealt by effects the spec instigated, after mitigation.
+    pub damage_dealt: f32,
+    /// Healing done by effects the spec instigated.
+    pub healing_done: f32,
+}
+
+/// Read access to the usage statistics of ability specs.
+#[derive(SystemParam)]
+pub struct AbilityUsage<'w, 's> {
+    pub owners: Query<'w, 's, &'static OwnedAbilities>,
+    pub specs: Query<'w, 's, (&'static AbilitySpec, &'static AbilityUsageStats)>,
+}
+
+impl AbilityUsage<'_, '_> {
+    /// Returns the statistics of `ability_spec`, if it is tracked.
+    pub fn of(&self, ability_spec: Entity) -> Option<&AbilityUsageStats> {
+        self.specs.get(ability_spec).ok().map(|(_, stats)| stats)
+    }
+
+    /// Returns the tracked abilities of `owner` with their statistics.
+    pub fn of_owner(
+        &self,
+        owner: Entity,
+    ) -> impl Iterator<Item = (Entity, &AbilitySpec, &AbilityUsageStats)> {
+        self.owners
+            .get(owner)
+            .into_iter()
+            .flat_map(|owned| owned.iter())
+            .filter_map(|ability_spec| {
+                let (spec, stats) = self.specs.get(ability_spec).ok()?;
+                Some((ability_spec, spec, stats))
+            })
+    }
+
+    /// Returns the statistics of the `ability_id` ability granted to `owner`,
+    /// if it is tracked.
+    pub fn of_ability(&self, owner: Entity, ability_id: &Atom) -> Option<&AbilityUsageStats> {
+        self.of_owner(owner)
+            .find(|(_, spec, _)| spec.definition_id == *ability_id)
+            .map(|(_, _, stats)| stats)
+    }
+}
+
+/// Observer counting the activations of tracked specs.
+pub fn on_ability_activated_count_usage(
+    ev: On<AbilityActivatedEvent>,
+    mut stats: Query<&mut AbilityUsageStats>,
+) {
+    if let Ok(mut stats) = stats.get_mut(ev.event().ability_spec) {
+        stats.activation_count += 1;
+    }
+}
+
+/// Observer adding damage dealt by tracked specs to their statistics.
+pub fn on_damage_dealt_record_usage(
+    ev: On<DamageDealtEvent>,
+    mut stats: Query<&mut AbilityUsageStats>,
+) {
+    let event = ev.event();
+    if let Some(mut stats) = event.ability.and_then(|spec| stats.get_mut(spec).ok()) {
+        stats.damage_dealt += event.post_mitigation;
+    }
+}
+
+/// Observer adding healing done by tracked specs to their statistics.
+pub fn on_healing_done_record_usage(
+    ev: On<HealingDoneEvent>,
+    mut stats: Query<&mut AbilityUsageStats>,
+) {
+    let event = ev.event();
+    if let Some(mut stats) = event.ability.and_then(|spec| stats.get_mut(spec).ok()) {
+        stats.healing_done += event.amount;
+    }
+}
+
+/// System adding the frame time of the owner to tracked specs that are
+/// active.
+pub fn accumulate_ability_active_time_system(
+    time: GasTime,
+    mut specs: Query<(&mut AbilityUsageStats, &AbilityActiveState, &AbilityOwner)>,
+) {
+    for (mut stats, active_state, owner) in &mut specs {
+        if active_state.is_active {
+            stats.active_time += time.delta_secs(owner.0);
+        }
+    }
+}
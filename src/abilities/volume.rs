@@ -0,0 +1,318 @@
+//! Effect volumes.
+//!
+//! A [`GameplayEffectVolume`] applies its spec to every actor (entity with
+//! `OwnedTags`) that enters it and passes its filter, for lava floors,
+//! healing fountains or capture-zone buffs. The volume is the instigator of
+//! the applications. When an actor leaves, or the volume is removed, the
+//! effects it applied are removed if their definition is infinite; effects
+//! with a duration run out on their own.
+//!
+//! Applications go through [`BatchedEvents`], so a volume filling with actors
+//! in one frame looks its definition up once rather than once per actor.
+//!
+//! Actors inside are found every frame:
+//! - [`VolumeOverlap::Shape`] tests actor `GlobalTransform` positions against
+//!   the volume's [`TargetShape`], as shape overlap targeting does; no physics
+//!   needed.
+//! - [`VolumeOverlap::Physics`] intersects the volume entity's own collider
+//!   through the physics backend (`avian3d` or `rapier3d` feature). Colliders
+//!   on children of an actor count for the actor.
+//!
+//! # Example
+//!
+//! ```ignore
+//! commands.spawn((
+//!     GameplayEffectVolume::new(
+//!         GameplayEffectSpec::new("lava_burn", Entity::PLACEHOLDER),
+//!         TargetShape::cuboid(Vec3::new(5.0, 1.0, 5.0)),
+//!     ),
+//!     Transform::from_translation(lava_pool),
+//! ));
+//! ```
+
+use super::target_filter::{TargetFilter, TargetFilterQuery};
+use super::targeting::{ShapeTargeting, TargetShape};
+use crate::core::OwnedTags;
+use crate::core::events::BatchedEvents;
+use crate::effects::components::{ActiveEffects, ActiveGameplayEffect, EffectInstigator};
+use crate::effects::definition::{DurationPolicy, GameplayEffectRegistry};
+use crate::effects::{ApplyGameplayEffectEvent, GameplayEffectSpec, RemoveGameplayEffectEvent};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+#[cfg(feature = "avian3d")]
+use super::trace_avian::AvianTargetingQuery;
+#[cfg(feature = "rapier3d")]
+use super::trace_rapier::RapierTargetingQuery;
+
+/// How a volume finds the actors inside it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VolumeOverlap {
+    /// Actor positions inside the volume's shape.
+    #[default]
+    Shape,
+    /// Actor colliders intersecting the volume's collider.
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+    Physics,
+}
+
+/// An area that applies an effect to the actors inside it.
+///
+/// `spec.target` is replaced by each entering actor and the instigator by the
+/// volume; the spec's source, if any, is the volume's owner.
+#[derive(Component, Debug, Clone)]
+#[require(Transform, VolumeOccupants)]
+pub struct GameplayEffectVolume {
+    /// Effect applied to entering actors.
+    pub spec: GameplayEffectSpec,
+    /// Volume shape for [`VolumeOverlap::Shape`], in the volume's local space.
+    pub shape: TargetShape,
+    /// Rules actors must pass to be affected.
+    pub filter: TargetFilter,
+    /// How actors inside are found.
+    pub overlap: VolumeOverlap,
+}
+
+impl GameplayEffectVolume {
+    /// Creates a volume of `shape` affecting every actor.
+    pub fn new(spec: GameplayEffectSpec, shape: TargetShape) -> Self {
+        Self {
+            spec,
+            shape,
+            filter: TargetFilter::new(),
+            overlap: VolumeOverlap::default(),
+        }
+    }
+
+    /// Sets the filter actors must pass.
+    pub fn with_filter(mut self, filter: TargetFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Sets how actors inside are found.
+    pub fn with_overlap(mut self, overlap: VolumeOverlap) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /// The entity that placed the volume.
+    pub fn owner(&self) -> Option<Entity> {
+        self.spec.context.source
+    }
+}
+
+/// The actors currently affected by a volume.
+#[derive(Component, Debug, Clone, Default)]
+pub struct VolumeOccupants(HashSet<Entity>);
+
+impl VolumeOccupants {
+    /// Returns true if `entity` is inside the volume.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.0.contains(&entity)
+    }
+
+    /// Iterates the actors inside the volume.
+    pub fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Number of actors inside the volume.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if no actor is inside the volume.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Event triggered when an actor enters a volume and receives its effect.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VolumeEnteredEvent {
+    /// The volume entity.
+    pub volume: Entity,
+    /// The actor that entered.
+    pub entity: Entity,
+}
+
+/// Event triggered when an actor leaves a volume.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VolumeExitedEvent {
+    /// The volume entity.
+    pub volume: Entity,
+    /// The actor that left.
+    pub entity: Entity,
+}
+
+/// System parameter removing the infinite effects a volume applied.
+#[derive(SystemParam)]
+pub struct VolumeEffects<'w, 's> {
+    pub registry: Res<'w, GameplayEffectRegistry>,
+    pub lists: Query<'w, 's, &'static ActiveEffects>,
+    pub effects: Query<'w, 's, (&'static ActiveGameplayEffect, &'static EffectInstigator)>,
+}
+
+impl VolumeEffects<'_, '_> {
+    /// Removes the effects of `volume` on `target` if its definition is
+    /// infinite.
+    fn remove(
+        &self,
+        commands: &mut Commands,
+        volume_entity: Entity,
+        volume: &GameplayEffectVolume,
+        target: Entity,
+    ) {
+        let infinite = self
+            .registry
+            .get(&volume.spec.effect_id)
+            .is_some_and(|definition| definition.duration_policy == DurationPolicy::Infinite);
+        if !infinite {
+            return;
+        }
+        let Ok(list) = self.lists.get(target) else {
+            return;
+        };
+        for effect in list.iter() {
+            if self.effects.get(effect).is_ok_and(|(active, instigator)| {
+                active.definition_id == volume.spec.effect_id && instigator.0 == Some(volume_entity)
+            }) {
+                commands.trigger(RemoveGameplayEffectEvent { effect });
+            }
+        }
+    }
+}
+
+/// System that applies volume effects to entering actors and removes them
+/// from leaving ones.
+///
+/// Runs before [`EffectSystemSet::Apply`](crate::core::EffectSystemSet::Apply),
+/// which applies the queued entries the same frame.
+pub fn update_effect_volumes_system(
+    mut commands: Commands,
+    mut applications: ResMut<BatchedEvents<ApplyGameplayEffectEvent>>,
+    mut volumes: Query<(
+        Entity,
+        &GameplayEffectVolume,
+        &GlobalTransform,
+        &mut VolumeOccupants,
+    )>,
+    candidates: Query<(Entity, &GlobalTransform), With<OwnedTags>>,
+    filters: TargetFilterQuery,
+    volume_effects: VolumeEffects,
+    #[cfg(any(feature = "avian3d", feature = "rapier3d"))] physics: VolumePhysicsQuery,
+) {
+    for (volume_entity, volume, transform, mut occupants) in volumes.iter_mut() {
+        let caster = volume.owner().unwrap_or(volume_entity);
+        let passes = |target: Entity| {
+            target != volume_entity && filters.passes(&volume.filter, caster, target)
+        };
+
+        let inside: HashSet<Entity> = match volume.overlap {
+            VolumeOverlap::Shape => {
+                let targeting = ShapeTargeting::new(volume.shape);
+                targeting
+                    .gather(
+                        targeting.origin(transform),
+                        candidates
+                            .iter()
+                            .map(|(entity, transform)| (entity, transform.translation())),
+                        passes,
+                    )
+                    .actors
+                    .into_iter()
+                    .collect()
+            }
+            #[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+            VolumeOverlap::Physics => physics
+                .overlaps(volume_entity, transform)
+                .into_iter()
+                .filter_map(|hit| filters.actor_of(hit))
+                .filter(|&entity| passes(entity))
+                .collect(),
+        };
+
+        if inside == occupants.0 {
+            continue;
+        }
+        for &entity in inside.difference(&occupants.0) {
+            let mut spec = volume.spec.clone();
+            spec.target = entity;
+            spec.context.instigator = Some(volume_entity);
+            applications.push(ApplyGameplayEffectEvent::from_spec(spec));
+            commands.trigger(VolumeEnteredEvent {
+                volume: volume_entity,
+                entity,
+            });
+        }
+        for &entity in occupants.0.difference(&inside) {
+            volume_effects.remove(&mut commands, volume_entity, volume, entity);
+            commands.trigger(VolumeExitedEvent {
+                volume: volume_entity,
+                entity,
+            });
+        }
+        occupants.0 = inside;
+    }
+}
+
+/// Observer that removes a volume's infinite effects from the actors inside
+/// when the volume is removed or despawned.
+pub fn on_effect_volume_removed(
+    trigger: On<Remove, GameplayEffectVolume>,
+    mut commands: Commands,
+    volumes: Query<(&GameplayEffectVolume, &VolumeOccupants)>,
+    volume_effects: VolumeEffects,
+) {
+    let volume_entity = trigger.event_target();
+    let Ok((volume, occupants)) = volumes.get(volume_entity) else {
+        return;
+    };
+    for entity in occupants.iter() {
+        volume_effects.remove(&mut commands, volume_entity, volume, entity);
+    }
+    // A volume inserted again applies its effect anew
+    commands
+        .entity(volume_entity)
+        .try_insert(VolumeOccupants::default());
+}
+
+/// System parameter intersecting volume colliders through the physics
+/// backend.
+#[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+#[derive(SystemParam)]
+pub struct VolumePhysicsQuery<'w, 's> {
+    #[cfg(feature = "avian3d")]
+    pub avian: Option<AvianTargetingQuery<'w, 's>>,
+    #[cfg(feature = "avian3d")]
+    pub avian_colliders: Query<'w, 's, &'static avian3d::prelude::Collider>,
+    #[cfg(feature = "rapier3d")]
+    pub rapier: Option<RapierTargetingQuery<'w, 's>>,
+    #[cfg(feature = "rapier3d")]
+    pub rapier_colliders: Query<'w, 's, &'static bevy_rapier3d::prelude::Collider>,
+}
+
+#[cfg(any(feature = "avian3d", feature = "rapier3d"))]
+impl VolumePhysicsQuery<'_, '_> {
+    /// Returns the colliders intersecting the collider of `volume`.
+    ///
+    /// Empty when the volume has no collider or there is no physics backend.
+    pub fn overlaps(&self, volume: Entity, transform: &GlobalTransform) -> Vec<Entity> {
+        let (_, rotation, position) = transform.to_scale_rotation_translation();
+        #[cfg(feature = "avian3d")]
+        if let Some(avian) = &self.avian
+            && let Ok(collider) = self.avian_colliders.get(volume)
+        {
+            return avian.shape_overlap(collider, position, rotation, &[volume]);
+        }
+        #[cfg(feature = "rapier3d")]
+        if let Some(rapier) = &self.rapier
+            && let Ok(collider) = self.rapier_colliders.get(volume)
+        {
+            return rapier.shape_overlap(collider, position, rotation, &[volume]);
+        }
+        Vec::new()
+    }
+}
@@ -0,0 +1,492 @@
+//! Attribute sets defined in asset files.
+//!
+//! With the `attribute_assets` feature, `.attributes.ron` files describe an
+//! attribute set as data, so stats can be added without implementing
+//! [`AttributeSetDefinition`](super::AttributeSetDefinition). Each attribute
+//! has a default value and optional bounds, and may:
+//! - regenerate: every second its base value grows by the current value of
+//!   another attribute (e.g. `Health` by `HealthRegen`);
+//! - be derived: its base value is recomputed every frame from the current
+//!   values of other attributes, so buffs to those flow through.
+//!
+//! Putting an [`AttributeSetHandle`] on an entity creates the set's
+//! attributes as its children once the file loads. When the file changes,
+//! existing attributes take the new rules and new attributes are added;
+//! attributes removed from the file are kept.
+//!
+//! See [`migration`](crate::serialization::migration) for file versions.
+//!
+//! # Example
+//!
+//! ```ignore
+//! // assets/attributes/character.attributes.ron
+//! (
+//!     attributes: [
+//!         (name: "Health", default: 100.0, min: Some(0.0), max: Some(100.0), regen: Some("HealthRegen")),
+//!         (name: "HealthRegen", default: 2.0),
+//!         (name: "Strength", default: 10.0),
+//!         (name: "AttackPower", derived: Some((base: 5.0, terms: [("Strength", 2.0)]))),
+//!     ],
+//! )
+//!
+//! app.add_plugins(AttributeAssetPlugin);
+//! commands.spawn(AttributeSetHandle(asset_server.load("attributes/character.attributes.ron")));
+//! ```
+
+use super::components::{
+    AttributeData, AttributeMetadata, AttributeMetadataComponent, AttributeName, AttributeSetId,
+};
+use super::traits::AttributeSetRegistry;
+use crate::core::system_sets::{EffectSystemSet, GasSystemSet};
+use crate::core::timestep::{GasTickMode, GasTime};
+use crate::core::validation::GasValidationAssets;
+use crate::serialization::migration::{
+    DefinitionFormat, DefinitionMigrationError, DefinitionMigrations,
+};
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::ecs::relationship::Relationship;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::any::TypeId;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use string_cache::DefaultAtom as Atom;
+
+/// One attribute of a data-defined set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttributeDefinition {
+    /// The attribute's name.
+    pub name: Atom,
+    /// Starting base value.
+    #[serde(default)]
+    pub default: f32,
+    /// Lower bound of the base and current values.
+    #[serde(default)]
+    pub min: Option<f32>,
+    /// Upper bound of the base and current values.
+    #[serde(default)]
+    pub max: Option<f32>,
+    /// Attribute whose current value is added to this one's base per second.
+    #[serde(default)]
+    pub regen: Option<Atom>,
+    /// Formula the base value is recomputed from.
+    #[serde(default)]
+    pub derived: Option<DerivedAttributeFormula>,
+}
+
+impl AttributeDefinition {
+    /// Returns the bounds as attribute metadata.
+    pub fn metadata(&self) -> AttributeMetadata {
+        AttributeMetadata {
+            name: self.name.clone(),
+            min_value: self.min,
+            max_value: self.max,
+        }
+    }
+}
+
+/// `base + sum(coefficient * current value of attribute)`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct DerivedAttributeFormula {
+    /// Constant part.
+    #[serde(default)]
+    pub base: f32,
+    /// Attribute names and their coefficients.
+    #[serde(default)]
+    pub terms: Vec<(Atom, f32)>,
+}
+
+impl DerivedAttributeFormula {
+    /// Evaluates the formula; missing attributes count as zero.
+    pub fn evaluate(&self, current_value: impl Fn(&Atom) -> Option<f32>) -> f32 {
+        self.terms
+            .iter()
+            .fold(self.base, |total, (attribute, coefficient)| {
+                total + coefficient * current_value(attribute).unwrap_or(0.0)
+            })
+    }
+}
+
+/// An attribute set loaded from one file.
+#[derive(Asset, TypePath, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttributeSetAsset {
+    /// The attributes, in order.
+    pub attributes: Vec<AttributeDefinition>,
+}
+
+/// Error loading an attribute set file.
+#[derive(Debug)]
+pub enum AttributeSetAssetError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// The file is not valid RON.
+    Ron(ron::error::SpannedError),
+    /// The file could not be migrated to the current format version.
+    Migration(DefinitionMigrationError),
+    /// An attribute is invalid.
+    InvalidAttribute {
+        /// The attribute's name.
+        name: Atom,
+        /// What is wrong with it.
+        message: &'static str,
+    },
+}
+
+impl fmt::Display for AttributeSetAssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "Could not read attribute set file: {error}"),
+            Self::Ron(error) => write!(f, "Invalid RON attribute set file: {error}"),
+            Self::Migration(error) => write!(f, "Outdated attribute set file: {error}"),
+            Self::InvalidAttribute { name, message } => {
+                write!(f, "Invalid attribute '{name}': {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AttributeSetAssetError {}
+
+/// Loads [`AttributeSetAsset`]s from `.attributes.ron` files.
+#[derive(TypePath, Default)]
+pub struct AttributeSetAssetLoader {
+    /// Migrations applied to outdated files.
+    pub migrations: DefinitionMigrations,
+}
+
+impl AttributeSetAssetLoader {
+    /// Migrates, parses and checks the RON contents of an attribute set file.
+    pub fn migrate_and_parse(
+        &self,
+        bytes: &[u8],
+    ) -> Result<AttributeSetAsset, AttributeSetAssetError> {
+        let bytes = self
+            .migrations
+            .migrate(DefinitionFormat::AttributeSet, bytes, false)
+            .map_err(AttributeSetAssetError::Migration)?;
+        Self::parse(&bytes)
+    }
+
+    /// Parses and checks the RON contents of an attribute set file in the
+    /// current format.
+    pub fn parse(bytes: &[u8]) -> Result<AttributeSetAsset, AttributeSetAssetError> {
+        let asset: AttributeSetAsset =
+            ron::de::from_bytes(bytes).map_err(AttributeSetAssetError::Ron)?;
+
+        let mut names = HashSet::new();
+        for attribute in &asset.attributes {
+            if !names.insert(&attribute.name) {
+                return Err(invalid(attribute, "defined twice"));
+            }
+        }
+        for attribute in &asset.attributes {
+            if attribute
+                .min
+                .zip(attribute.max)
+                .is_some_and(|(min, max)| min > max)
+            {
+                return Err(invalid(attribute, "min is greater than max"));
+            }
+            if attribute.regen.is_some() && attribute.derived.is_some() {
+                return Err(invalid(attribute, "derived attributes cannot regenerate"));
+            }
+            if attribute
+                .regen
+                .as_ref()
+                .is_some_and(|regen| !names.contains(regen))
+            {
+                return Err(invalid(attribute, "regenerates from an unknown attribute"));
+            }
+            if attribute.derived.as_ref().is_some_and(|formula| {
+                formula
+                    .terms
+                    .iter()
+                    .any(|(term, _)| !names.contains(term) || *term == attribute.name)
+            }) {
+                return Err(invalid(
+                    attribute,
+                    "derived from itself or an unknown attribute",
+                ));
+            }
+        }
+        Ok(asset)
+    }
+}
+
+fn invalid(attribute: &AttributeDefinition, message: &'static str) -> AttributeSetAssetError {
+    AttributeSetAssetError::InvalidAttribute {
+        name: attribute.name.clone(),
+        message,
+    }
+}
+
+impl AssetLoader for AttributeSetAssetLoader {
+    type Asset = AttributeSetAsset;
+    type Settings = ();
+    type Error = AttributeSetAssetError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(AttributeSetAssetError::Io)?;
+        self.migrate_and_parse(&bytes)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["attributes.ron"]
+    }
+}
+
+/// Gives an entity the attributes of a data-defined set.
+#[derive(Component, Debug, Clone)]
+pub struct AttributeSetHandle(pub Handle<AttributeSetAsset>);
+
+/// The definition an attribute was created from (data-defined sets only).
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct DataAttribute(pub AttributeDefinition);
+
+/// Plugin that creates attributes from [`AttributeSetHandle`]s.
+///
+/// Requires Bevy's `AssetPlugin` and GAS's `AttributePlugin`.
+pub struct AttributeAssetPlugin;
+
+impl Plugin for AttributeAssetPlugin {
+    fn build(&self, app: &mut App) {
+        let tick_mode = GasTickMode::of(app);
+        let tick_schedule = tick_mode.schedule();
+        let simulation_schedule = tick_mode.simulation_schedule();
+        let migrations = app
+            .world_mut()
+            .get_resource_or_init::<DefinitionMigrations>()
+            .clone();
+        app.init_asset::<AttributeSetAsset>()
+            .register_asset_loader(AttributeSetAssetLoader { migrations })
+            .init_resource::<AttributeSetRegistry>()
+            .init_resource::<GasValidationAssets>()
+            .add_observer(track_attribute_set_for_validation)
+            .add_systems(
+                Update,
+                instantiate_attribute_sets_system.in_set(GasSystemSet::Attributes),
+            )
+            .add_systems(
+                simulation_schedule,
+                update_derived_attributes_system
+                    .after(instantiate_attribute_sets_system)
+                    .in_set(GasSystemSet::Attributes),
+            )
+            .add_systems(
+                tick_schedule,
+                regenerate_attributes_system.in_set(GasSystemSet::Attributes),
+            )
+            .add_systems(
+                simulation_schedule,
+                clamp_data_attributes_system
+                    .after(EffectSystemSet::Aggregate)
+                    .in_set(GasSystemSet::Effects),
+            );
+    }
+}
+
+/// Observer making validation wait for the files of inserted
+/// [`AttributeSetHandle`]s.
+pub fn track_attribute_set_for_validation(
+    ev: On<Insert, AttributeSetHandle>,
+    handles: Query<&AttributeSetHandle>,
+    mut validation: ResMut<GasValidationAssets>,
+) {
+    if let Ok(handle) = handles.get(ev.event_target()) {
+        validation.0.push(handle.0.id().untyped());
+    }
+}
+
+/// System that creates and updates the attributes of data-defined sets.
+///
+/// Runs for entities whose handle changed and for every entity using a file
+/// that was loaded or modified.
+pub fn instantiate_attribute_sets_system(
+    mut commands: Commands,
+    mut events: MessageReader<AssetEvent<AttributeSetAsset>>,
+    assets: Res<Assets<AttributeSetAsset>>,
+    mut registry: ResMut<AttributeSetRegistry>,
+    owners: Query<(Entity, Ref<AttributeSetHandle>, Option<&Children>)>,
+    mut attributes: Query<(
+        &AttributeName,
+        &mut DataAttribute,
+        &mut AttributeMetadataComponent,
+    )>,
+) {
+    let mut changed = HashSet::new();
+    for event in events.read() {
+        if let AssetEvent::Added { id } | AssetEvent::Modified { id } = *event {
+            changed.insert(id);
+            if let Some(asset) = assets.get(id) {
+                registry.register_names(asset.attributes.iter().map(|a| a.name.clone()));
+            }
+        }
+    }
+
+    for (owner, handle, children) in owners.iter() {
+        if !handle.is_changed() && !changed.contains(&handle.0.id()) {
+            continue;
+        }
+        let Some(asset) = assets.get(&handle.0) else {
+            continue;
+        };
+
+        let mut existing = HashSet::new();
+        for child in children.into_iter().flatten() {
+            let Ok((name, mut data_attribute, mut metadata)) = attributes.get_mut(*child) else {
+                continue;
+            };
+            let Some(definition) = asset.attributes.iter().find(|a| a.name == name.0) else {
+                continue;
+            };
+            if data_attribute.0 != *definition {
+                data_attribute.0 = definition.clone();
+                metadata.0 = definition.metadata();
+            }
+            existing.insert(name.0.clone());
+        }
+
+        for definition in &asset.attributes {
+            if existing.contains(&definition.name) {
+                continue;
+            }
+            debug!("Creating attribute '{}' on {owner}", definition.name);
+            commands
+                .spawn((
+                    AttributeData::new(definition.metadata().clamp(definition.default)),
+                    AttributeName::new(definition.name.clone()),
+                    AttributeSetId(TypeId::of::<AttributeSetAsset>()),
+                    AttributeMetadataComponent(definition.metadata()),
+                    DataAttribute(definition.clone()),
+                ))
+                .set_parent_in_place(owner);
+        }
+    }
+}
+
+/// Current values of every attribute, by owner and name.
+fn current_values(
+    attributes: &Query<(Entity, &AttributeName, &ChildOf, &mut AttributeData)>,
+) -> HashMap<(Entity, Atom), f32> {
+    attributes
+        .iter()
+        .map(|(_, name, child_of, data)| ((child_of.get(), name.0.clone()), data.current_value))
+        .collect()
+}
+
+/// System that recomputes the base value of derived attributes.
+///
+/// Reads the other attributes' current values from the previous frame.
+pub fn update_derived_attributes_system(
+    derived: Query<(Entity, &DataAttribute)>,
+    mut attributes: Query<(Entity, &AttributeName, &ChildOf, &mut AttributeData)>,
+) {
+    let mut values = None;
+    for (entity, data_attribute) in derived.iter() {
+        let Some(formula) = &data_attribute.0.derived else {
+            continue;
+        };
+        let values = values.get_or_insert_with(|| current_values(&attributes));
+        let Ok((_, _, child_of, mut data)) = attributes.get_mut(entity) else {
+            continue;
+        };
+        let owner = child_of.get();
+        let base_value = data_attribute
+            .0
+            .metadata()
+            .clamp(formula.evaluate(|name| values.get(&(owner, name.clone())).copied()));
+        if (data.base_value - base_value).abs() > f32::EPSILON {
+            data.set_base_value(base_value);
+        }
+    }
+}
+
+/// System that regenerates attributes paired with a regen attribute.
+pub fn regenerate_attributes_system(
+    regenerating: Query<(Entity, &DataAttribute)>,
+    mut attributes: Query<(Entity, &AttributeName, &ChildOf, &mut AttributeData)>,
+    time: GasTime,
+) {
+    if time.global_delta_secs() <= 0.0 {
+        return;
+    }
+    let mut values = None;
+    for (entity, data_attribute) in regenerating.iter() {
+        let Some(regen) = &data_attribute.0.regen else {
+            continue;
+        };
+        let values = values.get_or_insert_with(|| current_values(&attributes));
+        let Ok((_, _, child_of, mut data)) = attributes.get_mut(entity) else {
+            continue;
+        };
+        let Some(rate) = values.get(&(child_of.get(), regen.clone())) else {
+            continue;
+        };
+        let delta = time.lod_delta_secs(child_of.get());
+        let base_value = data_attribute
+            .0
+            .metadata()
+            .clamp(data.base_value + rate * delta);
+        if (data.base_value - base_value).abs() > f32::EPSILON {
+            data.set_base_value(base_value);
+        }
+    }
+}
+
+/// System that keeps data-defined attributes within their bounds.
+pub fn clamp_data_attributes_system(
+    mut attributes: Query<(&mut AttributeData, &AttributeMetadataComponent), With<DataAttribute>>,
+) {
+    for (mut data, metadata) in attributes.iter_mut() {
+        let base_value = metadata.0.clamp(data.base_value);
+        let current_value = metadata.0.clamp(data.current_value);
+        if base_value != data.base_value || current_value != data.current_value {
+            data.base_value = base_value;
+            data.current_value = current_value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHARACTER: &[u8] = br#"(
+        attributes: [
+            (name: "Health", default: 100.0, min: Some(0.0), max: Some(100.0), regen: Some("HealthRegen")),
+            (name: "HealthRegen", default: 2.0),
+            (name: "Strength", default: 10.0),
+            (name: "AttackPower", derived: Some((base: 5.0, terms: [("Strength", 2.0)]))),
+        ],
+    )"#;
+
+    #[test]
+    fn test_parse_attribute_set() {
+        let asset = AttributeSetAssetLoader::parse(CHARACTER).unwrap();
+        assert_eq!(asset.attributes.len(), 4);
+        assert_eq!(asset.attributes[0].regen, Some(Atom::from("HealthRegen")));
+        let formula = asset.attributes[3].derived.as_ref().unwrap();
+        assert_eq!(formula.evaluate(|_| Some(10.0)), 25.0);
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_references() {
+        let result = AttributeSetAssetLoader::parse(
+            br#"(attributes: [(name: "Health", regen: Some("Missing"))])"#,
+        );
+        assert!(matches!(
+            result,
+            Err(AttributeSetAssetError::InvalidAttribute { .. })
+        ));
+    }
+}
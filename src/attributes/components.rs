@@ -20,7 +20,9 @@ use bevy::prelude::*;
 ///     current_value: 100.0,
 /// };
 /// ```
-#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "replicon", derive(serde::Serialize, serde::Deserialize))]
 pub struct AttributeData {
     /// The base value of the attribute (permanent).
     pub base_value: f32,
@@ -70,9 +72,10 @@ impl AttributeData {
 ///
 /// This defines the constraints and properties of an attribute type.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AttributeMetadata {
     /// The name of the attribute (e.g., "Health", "Mana").
-    pub name: &'static str,
+    pub name: string_cache::DefaultAtom,
     /// Minimum allowed value (if any).
     pub min_value: Option<f32>,
     /// Maximum allowed value (if any).
@@ -81,9 +84,9 @@ pub struct AttributeMetadata {
 
 impl AttributeMetadata {
     /// Creates new attribute metadata.
-    pub fn new(name: &'static str) -> Self {
+    pub fn new(name: impl Into<string_cache::DefaultAtom>) -> Self {
         Self {
-            name,
+            name: name.into(),
             min_value: None,
             max_value: None,
         }
@@ -117,13 +120,21 @@ impl AttributeMetadata {
 /// Component that stores the metadata for an attribute.
 ///
 /// This is attached to attribute entities to define their constraints.
-#[derive(Component, Debug, Clone, PartialEq)]
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(opaque)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct AttributeMetadataComponent(pub AttributeMetadata);
 
 /// Component that identifies which attribute this entity represents.
 ///
 /// Uses interned strings (Atom) for O(1) comparison performance.
-#[derive(Component, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[reflect(opaque)]
+#[reflect(Component, Debug, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct AttributeName(pub string_cache::DefaultAtom);
 
 impl AttributeName {
@@ -148,7 +159,19 @@ pub struct PreviousAttributeValue {
     pub previous_base: f32,
 }
 
+/// Marker for attributes whose current value must be re-aggregated.
+///
+/// Inserted when modifiers targeting the attribute are added or removed, and
+/// removed once aggregation has recomputed the current value. Attributes
+/// whose `AttributeData` changed are re-aggregated without it.
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[component(storage = "SparseSet")]
+pub struct AttributeDirty;
+
 /// Marker component identifying which AttributeSet this attribute belongs to.
+///
+/// Not saved in scenes; attributes loaded without it are linked back to their
+/// set through the [`AttributeSetRegistry`](super::AttributeSetRegistry).
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct AttributeSetId(pub std::any::TypeId);
 
@@ -55,12 +55,18 @@
 //! }
 //! ```
 
+#[cfg(feature = "attribute_assets")]
+pub mod asset;
 pub mod components;
 pub mod hooks;
 pub mod plugin;
+pub mod sync;
 pub mod traits;
 
+#[cfg(feature = "attribute_assets")]
+pub use asset::*;
 pub use components::*;
 pub use hooks::*;
 pub use plugin::*;
+pub use sync::*;
 pub use traits::*;
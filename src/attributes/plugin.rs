@@ -1,8 +1,14 @@
 //! Attribute system plugin.
 //!
-//! This plugin registers the attribute lifecycle hooks resource.
+//! This plugin registers the attribute lifecycle hooks and attribute set
+//! registry resources, and the reflected attribute components.
 
+use super::components::{AttributeData, AttributeMetadataComponent, AttributeName};
 use super::hooks::AttributeLifecycleHooks;
+use super::traits::{AttributeSetRegistry, link_attribute_sets_system};
+use crate::core::handles::track_handle_generations;
+use crate::core::system_sets::{GasSystemSet, configure_gas_system_sets};
+use crate::core::timestep::GasTickMode;
 use bevy::prelude::*;
 
 /// Plugin that adds attribute system functionality.
@@ -10,6 +16,17 @@ pub struct AttributePlugin;
 
 impl Plugin for AttributePlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<AttributeLifecycleHooks>();
+        let simulation_schedule = GasTickMode::of(app).simulation_schedule();
+        configure_gas_system_sets(app);
+        track_handle_generations::<AttributeData>(app);
+        app.init_resource::<AttributeLifecycleHooks>()
+            .init_resource::<AttributeSetRegistry>()
+            .register_type::<AttributeData>()
+            .register_type::<AttributeName>()
+            .register_type::<AttributeMetadataComponent>()
+            .add_systems(
+                simulation_schedule,
+                link_attribute_sets_system.in_set(GasSystemSet::Attributes),
+            );
     }
 }
@@ -0,0 +1,132 @@
+//! Attribute to component sync.
+//!
+//! Writes an attribute's current value into a component on the attribute's
+//! owner whenever the value changes, so movement, scale and similar gameplay
+//! code can read plain components instead of querying attributes.
+//!
+//! # Example
+//! ```ignore
+//! app.sync_attribute_to_component::<Movement>("MoveSpeed", |movement, value| {
+//!     movement.speed = value;
+//! })
+//! .sync_attribute_to_component::<Transform>("Scale", |transform, value| {
+//!     transform.scale = Vec3::splat(value);
+//! });
+//! ```
+
+use super::components::{AttributeData, AttributeName};
+use crate::core::system_sets::{GasSystemSet, configure_gas_system_sets};
+use crate::core::timestep::GasTickMode;
+use bevy::ecs::component::Mutable;
+use bevy::prelude::*;
+use string_cache::DefaultAtom as Atom;
+
+/// Writes an attribute value into a component.
+pub type AttributeSyncFn<C> = fn(&mut C, f32);
+
+/// The attributes synced into component `C`.
+#[derive(Resource)]
+pub struct AttributeComponentSyncs<C: Component> {
+    syncs: Vec<(Atom, AttributeSyncFn<C>)>,
+}
+
+impl<C: Component> Default for AttributeComponentSyncs<C> {
+    fn default() -> Self {
+        Self { syncs: Vec::new() }
+    }
+}
+
+impl<C: Component> AttributeComponentSyncs<C> {
+    /// Adds a sync of `attribute` into `C`.
+    pub fn add(&mut self, attribute: impl Into<Atom>, sync: AttributeSyncFn<C>) {
+        self.syncs.push((attribute.into(), sync));
+    }
+
+    /// Returns the syncs reading `attribute`.
+    fn for_attribute<'a>(
+        &'a self,
+        attribute: &'a Atom,
+    ) -> impl Iterator<Item = AttributeSyncFn<C>> + 'a {
+        self.syncs
+            .iter()
+            .filter(move |(name, _)| name == attribute)
+            .map(|(_, sync)| *sync)
+    }
+}
+
+/// Extension trait for registering attribute to component syncs.
+pub trait AttributeSyncAppExt {
+    /// Calls `sync` with the current value of `attribute` whenever it
+    /// changes, on the owner's `C`. Also runs when `C` is added to an owner.
+    fn sync_attribute_to_component<C: Component<Mutability = Mutable>>(
+        &mut self,
+        attribute: impl Into<Atom>,
+        sync: AttributeSyncFn<C>,
+    ) -> &mut Self;
+}
+
+impl AttributeSyncAppExt for App {
+    fn sync_attribute_to_component<C: Component<Mutability = Mutable>>(
+        &mut self,
+        attribute: impl Into<Atom>,
+        sync: AttributeSyncFn<C>,
+    ) -> &mut Self {
+        if !self
+            .world()
+            .contains_resource::<AttributeComponentSyncs<C>>()
+        {
+            let simulation_schedule = GasTickMode::of(self).simulation_schedule();
+            configure_gas_system_sets(self);
+            self.init_resource::<AttributeComponentSyncs<C>>()
+                .add_systems(
+                    simulation_schedule,
+                    sync_attributes_to_component_system::<C>.in_set(GasSystemSet::Cleanup),
+                );
+        }
+        self.world_mut()
+            .resource_mut::<AttributeComponentSyncs<C>>()
+            .add(attribute, sync);
+        self
+    }
+}
+
+/// Owners that just got `C`, with their attributes.
+type AddedOwners<'w, 's, C> = Query<'w, 's, (Entity, &'static Children), Added<C>>;
+
+/// System writing changed attributes into their owner's `C`.
+pub fn sync_attributes_to_component_system<C: Component<Mutability = Mutable>>(
+    syncs: Res<AttributeComponentSyncs<C>>,
+    changed: Query<(&AttributeData, &AttributeName, &ChildOf), Changed<AttributeData>>,
+    attributes: Query<(&AttributeData, &AttributeName)>,
+    mut components: ParamSet<(AddedOwners<C>, Query<&mut C>)>,
+) {
+    let added: Vec<_> = components
+        .p0()
+        .iter()
+        .flat_map(|(owner, children)| children.iter().map(move |child| (owner, child)))
+        .filter_map(|(owner, child)| {
+            let (data, name) = attributes.get(child).ok()?;
+            Some((owner, name.0.clone(), data.current_value))
+        })
+        .collect();
+    let mut components = components.p1();
+    for (owner, name, value) in added {
+        if let Ok(mut component) = components.get_mut(owner) {
+            for sync in syncs.for_attribute(&name) {
+                sync(&mut component, value);
+            }
+        }
+    }
+
+    for (data, name, child_of) in &changed {
+        let mut syncs = syncs.for_attribute(&name.0).peekable();
+        if syncs.peek().is_none() {
+            continue;
+        }
+        if let Ok(mut component) = components.get_mut(child_of.parent()) {
+            for sync in syncs {
+                sync(&mut component, data.current_value);
+            }
+        }
+    }
+}
@@ -8,6 +8,9 @@ use super::components::{
 use super::hooks::{AttributeLifecycleHooks, AttributeModifyContext, AttributeSetHooks};
 use bevy::ecs::relationship::Relationship;
 use bevy::prelude::*;
+use std::any::TypeId;
+use std::collections::{HashMap, HashSet};
+use string_cache::DefaultAtom as Atom;
 
 /// Trait for defining an attribute set.
 ///
@@ -85,12 +88,20 @@ pub trait AttributeSetDefinition: Send + Sync + 'static {
         if let Some(mut hooks_res) = world.get_resource_mut::<AttributeLifecycleHooks>() {
             hooks_res.register(type_id, hooks);
         }
+        if let Some(mut registry) = world.get_resource_mut::<AttributeSetRegistry>() {
+            registry.register::<Self>();
+        }
     }
 
     /// Creates all attributes for this set and attaches them to the owner entity.
     fn create_attributes(commands: &mut Commands, owner: Entity) -> Vec<Entity> {
         let mut attribute_entities = Vec::new();
         let set_id = AttributeSetId(std::any::TypeId::of::<Self>());
+        commands.queue(|world: &mut World| {
+            if let Some(mut registry) = world.get_resource_mut::<AttributeSetRegistry>() {
+                registry.register::<Self>();
+            }
+        });
 
         for &name in Self::attribute_names() {
             let default_value = Self::default_value(name);
@@ -114,6 +125,60 @@ pub trait AttributeSetDefinition: Send + Sync + 'static {
     }
 }
 
+/// Resource recording the attribute names of each attribute set in use.
+///
+/// A set is recorded when its hooks are registered or its attributes are
+/// first created. Sets defined in data record their names when loaded.
+#[derive(Resource, Debug, Default)]
+pub struct AttributeSetRegistry {
+    sets: HashMap<TypeId, &'static [&'static str]>,
+    data_names: HashSet<Atom>,
+}
+
+impl AttributeSetRegistry {
+    /// Records an attribute set.
+    pub fn register<T: AttributeSetDefinition + ?Sized>(&mut self) {
+        self.sets.insert(TypeId::of::<T>(), T::attribute_names());
+    }
+
+    /// Records attribute names of a set defined in data.
+    pub fn register_names(&mut self, names: impl IntoIterator<Item = Atom>) {
+        self.data_names.extend(names);
+    }
+
+    /// Returns true if some recorded set has an attribute with this name.
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.data_names.contains(&Atom::from(name))
+            || self.sets.values().any(|names| names.contains(&name))
+    }
+
+    /// Returns the set with an attribute of this name, if exactly one has it.
+    pub fn set_of(&self, name: &str) -> Option<TypeId> {
+        let mut sets = self
+            .sets
+            .iter()
+            .filter(|(_, names)| names.contains(&name))
+            .map(|(type_id, _)| *type_id);
+        let set = sets.next()?;
+        sets.next().is_none().then_some(set)
+    }
+}
+
+/// System that links attributes loaded from a scene back to their set.
+///
+/// Attributes whose name is in more than one recorded set are left unlinked.
+pub fn link_attribute_sets_system(
+    mut commands: Commands,
+    registry: Res<AttributeSetRegistry>,
+    attributes: Query<(Entity, &AttributeName), Without<AttributeSetId>>,
+) {
+    for (entity, name) in &attributes {
+        if let Some(set) = registry.set_of(name.as_str()) {
+            commands.entity(entity).insert(AttributeSetId(set));
+        }
+    }
+}
+
 /// Helper function to find an attribute entity by name for a given owner.
 ///
 /// This is a utility function for querying attributes.
@@ -171,6 +236,24 @@ mod tests {
         assert_eq!(attributes.len(), 2);
     }
 
+    #[test]
+    fn test_registry_records_created_sets() {
+        let mut app = App::new();
+        app.init_resource::<AttributeSetRegistry>();
+        let mut commands = app.world_mut().commands();
+        let owner = commands.spawn_empty().id();
+        TestAttributes::create_attributes(&mut commands, owner);
+        app.world_mut().flush();
+
+        let registry = app.world().resource::<AttributeSetRegistry>();
+        assert!(registry.has_attribute("Mana"));
+        assert!(!registry.has_attribute("Stamina"));
+        assert_eq!(
+            registry.set_of("Mana"),
+            Some(TypeId::of::<TestAttributes>())
+        );
+    }
+
     #[test]
     fn test_attribute_names() {
         assert_eq!(TestAttributes::attribute_names(), &["Health", "Mana"]);
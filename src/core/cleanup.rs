@@ -0,0 +1,75 @@
+//! Teardown of a GAS owner when it is despawned.
+//!
+//! An owner's attributes are its children, and its ability specs and active
+//! effects are linked through [`OwnedAbilities`] and [`ActiveEffects`], so
+//! Bevy despawns them together with the owner, and with them the ability
+//! instances and effect modifiers. Ability tasks only store the owner's
+//! entity, so [`on_gas_owner_despawned`] cancels them, and reports what was
+//! torn down in a [`GasOwnerDespawnedEvent`].
+
+use super::components::OwnedTags;
+use crate::abilities::OwnedAbilities;
+use crate::abilities::tasks::{AbilityTask, TaskCancelledEvent, TaskState};
+use crate::attributes::AttributeName;
+use crate::effects::ActiveEffects;
+use bevy::prelude::*;
+
+/// Event triggered when a GAS owner (an entity with [`OwnedTags`]) is
+/// despawned, listing the entities despawned with it.
+///
+/// Lets code keeping its own maps of attributes, specs or effects drop them.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct GasOwnerDespawnedEvent {
+    /// The despawned owner.
+    pub owner: Entity,
+    /// Its attribute entities.
+    pub attributes: Vec<Entity>,
+    /// Its ability spec entities.
+    pub abilities: Vec<Entity>,
+    /// The active effects that targeted it.
+    pub effects: Vec<Entity>,
+}
+
+/// Observer that cancels a despawned owner's ability tasks and triggers
+/// [`GasOwnerDespawnedEvent`].
+pub fn on_gas_owner_despawned(
+    ev: On<Despawn, OwnedTags>,
+    mut commands: Commands,
+    children: Query<&Children>,
+    attributes: Query<(), With<AttributeName>>,
+    owned_abilities: Query<&OwnedAbilities>,
+    active_effects: Query<&ActiveEffects>,
+    tasks: Query<(Entity, &AbilityTask, &TaskState)>,
+) {
+    let owner = ev.event_target();
+
+    for (task_entity, ability_task, state) in tasks.iter() {
+        if ability_task.owner == owner && *state == TaskState::Running {
+            commands.entity(task_entity).insert(TaskState::Cancelled);
+            commands.trigger(TaskCancelledEvent {
+                task: task_entity,
+                ability_instance: ability_task.ability_instance,
+                ability_spec: ability_task.ability_spec,
+                owner,
+            });
+        }
+    }
+
+    commands.trigger(GasOwnerDespawnedEvent {
+        owner,
+        attributes: children
+            .get(owner)
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .filter(|&child| attributes.contains(child))
+            .collect(),
+        abilities: owned_abilities
+            .get(owner)
+            .map(|abilities| abilities.iter().collect())
+            .unwrap_or_default(),
+        effects: active_effects
+            .get(owner)
+            .map(|effects| effects.iter().collect())
+            .unwrap_or_default(),
+    });
+}
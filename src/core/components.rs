@@ -4,8 +4,11 @@ use bevy::prelude::*;
 use bevy_gameplay_tag::gameplay_tag_count_container::GameplayTagCountContainer;
 
 /// 玩家拥有的标签(来自能力、效果等)
-#[derive(Component, Debug, Default)]
-pub struct OwnedTags(pub GameplayTagCountContainer);
+///
+/// Scenes store it empty; the tags granted by loaded effects are added back.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct OwnedTags(#[reflect(ignore)] pub GameplayTagCountContainer);
 
 /// Component that stores tags blocking other abilities from activating.
 ///
@@ -21,3 +24,29 @@ pub struct BlockedAbilityTags(pub GameplayTagCountContainer);
 /// This allows implementing damage immunity, status immunity, etc.
 #[derive(Component, Debug, Default)]
 pub struct ImmunityTags(pub GameplayTagCountContainer);
+
+/// Suspends GAS for an entity while keeping its state.
+///
+/// Effects targeting it stop ticking (durations, periods and cooldowns), its
+/// attributes stop regenerating, its ability tasks stop waiting and its
+/// abilities can't be activated. Remove it to resume where things left off.
+/// Useful for cutscenes, off-screen simulation LOD and possession changes.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GasDisabled;
+
+/// Team or faction an actor belongs to.
+///
+/// Read by effect team policies and target filters.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Team(pub u32);
+
+/// Identifies one predicted ability activation and everything it applied.
+///
+/// Keys are unique per client. They travel with activation requests, effect
+/// specs, applied events and cue parameters, and are stamped on the effects
+/// a predicted activation creates, so predicted and authoritative results
+/// can be matched.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PredictionKey(pub u32);
@@ -0,0 +1,131 @@
+//! Display metadata for UI.
+//!
+//! Effect and ability definitions carry an optional [`DisplayInfo`] with a
+//! display name, an icon path and a description template, so buff bars and
+//! tooltips can be built from the registries alone. Templates contain
+//! `{placeholder}`s resolved by
+//! `GameplayEffectDefinition::format_description` and
+//! `AbilityDefinition::format_description` from the live spec, or by
+//! [`format_template`] for custom ones. Unknown placeholders are left as
+//! written, so a typo shows up in the tooltip instead of disappearing.
+//!
+//! # Example
+//! ```ignore
+//! effects.register(
+//!     GameplayEffectDefinition::new("poison")
+//!         .with_duration(6.0)
+//!         .with_period(1.0)
+//!         .add_modifier(ModifierInfo::new("Health", ModifierOperation::AddCurrent, MagnitudeCalculation::scaled(-5.0, 1.5)))
+//!         .with_display(
+//!             DisplayInfo::new("Poison")
+//!                 .with_icon("icons/poison.png")
+//!                 .with_description("Deals {modifier.0} damage every {period}s for {duration}s."),
+//!         ),
+//! );
+//!
+//! // "Deals 7.5 damage every 1s for 6s." at level 2.
+//! let tooltip = definition.format_description(&GameplayEffectSpec::new("poison", target).with_level(2));
+//! ```
+
+/// Display name, icon and description of an effect or ability.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplayInfo {
+    /// The name shown to players.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
+    /// Asset path of the icon, for `AssetServer::load`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub icon: Option<String>,
+    /// Description template with `{placeholder}`s.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub description: Option<String>,
+}
+
+impl DisplayInfo {
+    /// Creates display metadata with a name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the icon asset path.
+    pub fn with_icon(mut self, path: impl Into<String>) -> Self {
+        self.icon = Some(path.into());
+        self
+    }
+
+    /// Sets the description template.
+    pub fn with_description(mut self, template: impl Into<String>) -> Self {
+        self.description = Some(template.into());
+        self
+    }
+}
+
+/// Replaces each `{key}` in `template` with `resolve(key)`.
+///
+/// Placeholders `resolve` returns `None` for are kept as written. `{{` and
+/// `}}` produce literal braces.
+pub fn format_template(template: &str, mut resolve: impl FnMut(&str) -> Option<String>) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find(['{', '}']) {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            output.push_str(&rest[..1]);
+            rest = &rest[2..];
+            continue;
+        }
+        let end = match rest.find('}') {
+            Some(end) if rest.starts_with('{') => end,
+            _ => {
+                output.push_str(&rest[..1]);
+                rest = &rest[1..];
+                continue;
+            }
+        };
+        let key = &rest[1..end];
+        match resolve(key) {
+            Some(value) => output.push_str(&value),
+            None => output.push_str(&rest[..=end]),
+        }
+        rest = &rest[end + 1..];
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Formats a number for display: whole numbers without decimals, others
+/// with up to two.
+pub fn format_number(value: f32) -> String {
+    let formatted = format!("{value:.2}");
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_template_resolves_known_placeholders() {
+        let formatted = format_template("Deals {damage} {{fire}} damage, {unknown}.", |key| {
+            (key == "damage").then(|| "40".to_string())
+        });
+        assert_eq!(formatted, "Deals 40 {fire} damage, {unknown}.");
+        assert_eq!(format_template("{unclosed", |_| None), "{unclosed");
+    }
+
+    #[test]
+    fn test_format_number_trims_decimals() {
+        assert_eq!(format_number(40.0), "40");
+        assert_eq!(format_number(7.5), "7.5");
+        assert_eq!(format_number(1.0 / 3.0), "0.33");
+        assert_eq!(format_number(-3.0), "-3");
+    }
+}
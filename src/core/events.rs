@@ -18,7 +18,9 @@ pub use crate::abilities::systems::{
 pub use crate::abilities::systems::ActivationFailureReason;
 
 // Re-export cue events
-pub use crate::cues::systems::TriggerGameplayCueEvent;
+pub use crate::cues::systems::{TriggerGameplayCueEvent, TriggerGameplayCueOnEntityEvent};
+
+use bevy::prelude::Resource;
 
 /// Trait for events that can be batched for performance.
 pub trait BatchableEvent: Send + Sync + 'static {
@@ -30,6 +32,58 @@ pub trait BatchableEvent: Send + Sync + 'static {
 impl BatchableEvent for ApplyGameplayEffectEvent {}
 impl BatchableEvent for GameplayEffectAppliedEvent {}
 impl BatchableEvent for GameplayEffectRemovedEvent {}
+impl BatchableEvent for TriggerGameplayCueEvent {}
+
+/// Resource queueing batchable events for processing in one pass.
+///
+/// [`ApplyGameplayEffectEvent`]s pushed here are applied together in
+/// [`EffectSystemSet::Apply`](super::EffectSystemSet::Apply), grouped by
+/// definition, level, source and instigator, instead of running the apply
+/// observer once per event. Prefer this over triggering when many effects
+/// land in one frame, e.g. an area attack hitting dozens of targets.
+///
+/// Events whose [`BatchableEvent::can_batch`] returns `false` are applied
+/// on their own when the batch is processed, in their priority's order.
+#[derive(Resource)]
+pub struct BatchedEvents<E: BatchableEvent> {
+    events: Vec<E>,
+}
+
+impl<E: BatchableEvent> Default for BatchedEvents<E> {
+    fn default() -> Self {
+        Self { events: Vec::new() }
+    }
+}
+
+impl<E: BatchableEvent> BatchedEvents<E> {
+    /// Queues an event for the next batch.
+    pub fn push(&mut self, event: E) {
+        self.events.push(event);
+    }
+
+    /// Number of queued events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if no events are queued.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Removes and returns all queued events in the order they were pushed.
+    pub fn drain(&mut self) -> std::vec::Drain<'_, E> {
+        self.events.drain(..)
+    }
+
+    /// Removes and returns the queued events matching `predicate`, in the
+    /// order they were pushed.
+    pub fn take_where(&mut self, mut predicate: impl FnMut(&E) -> bool) -> Vec<E> {
+        self.events
+            .extract_if(.., |event| predicate(event))
+            .collect()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -44,4 +98,19 @@ mod tests {
 
         assert!(event.can_batch());
     }
+
+    #[test]
+    fn test_batched_events_drain_in_order() {
+        let mut batch = BatchedEvents::<ApplyGameplayEffectEvent>::default();
+        batch.push(ApplyGameplayEffectEvent::new("first", Entity::PLACEHOLDER));
+        batch.push(ApplyGameplayEffectEvent::new("second", Entity::PLACEHOLDER));
+        assert_eq!(batch.len(), 2);
+
+        let drained: Vec<_> = batch
+            .drain()
+            .map(|event| event.spec.effect_id.to_string())
+            .collect();
+        assert_eq!(drained, vec!["first", "second"]);
+        assert!(batch.is_empty());
+    }
 }
@@ -1,5 +1,221 @@
-//! Handle types for safe entity references.
+//! Generation-checked handles to ability specs, active effects and attributes.
 //!
-//! Currently unused - Bevy's Entity type provides sufficient safety through
-//! EntityRef validation. This module is reserved for future use if custom
-//! handle types with generation counters become necessary.
+//! A bare [`Entity`] stays valid for as long as the entity lives, even if the
+//! component it was kept for is removed and added again (an effect entity
+//! reused for another application, a spec whose ability was regranted). A
+//! [`GasHandle`] also records the generation the component got when it was
+//! added, so it stops resolving once that component is gone, whatever
+//! happens to the entity afterwards.
+//!
+//! Generations are assigned by [`HandleGenerations`] when the component is
+//! added and dropped when it is removed or its entity despawned. The apply
+//! and grant flows hand handles out in `GameplayEffectAppliedEvent::handle`
+//! and `AbilityGrantedEvent::handle`; `Gas` takes them for other entities.
+//!
+//! # Example
+//!
+//! ```ignore
+//! fn remember_shield(ev: On<GameplayEffectAppliedEvent>, mut shields: ResMut<Shields>) {
+//!     shields.0.extend(ev.handle);
+//! }
+//!
+//! fn shield_expired(world: &World, shields: &Shields) -> bool {
+//!     shields.0.iter().all(|handle| handle.resolve(world).is_none())
+//! }
+//! ```
+
+use crate::abilities::AbilitySpec;
+use crate::attributes::AttributeData;
+use crate::effects::ActiveGameplayEffect;
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// Handle to an entity holding `T`, valid while that `T` is not removed.
+pub struct GasHandle<T> {
+    entity: Entity,
+    generation: u32,
+    marker: PhantomData<fn() -> T>,
+}
+
+/// Handle to an ability spec entity.
+pub type AbilityHandle = GasHandle<AbilitySpec>;
+
+/// Handle to an active gameplay effect entity.
+pub type EffectHandle = GasHandle<ActiveGameplayEffect>;
+
+/// Handle to an attribute entity.
+pub type AttributeHandle = GasHandle<AttributeData>;
+
+impl<T> GasHandle<T> {
+    /// The entity the handle points to. It may no longer hold `T`.
+    pub fn entity(self) -> Entity {
+        self.entity
+    }
+
+    /// The generation `T` had on the entity when the handle was taken.
+    pub fn generation(self) -> u32 {
+        self.generation
+    }
+}
+
+impl<T: Component> GasHandle<T> {
+    /// Returns the entity if it still holds the `T` the handle was taken for.
+    pub fn resolve(self, world: &World) -> Option<Entity> {
+        world.get_resource::<HandleGenerations<T>>()?.resolve(self)
+    }
+
+    /// Returns `true` if [`resolve`](Self::resolve) succeeds.
+    pub fn is_valid(self, world: &World) -> bool {
+        self.resolve(world).is_some()
+    }
+}
+
+impl<T> Clone for GasHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GasHandle<T> {}
+
+impl<T> PartialEq for GasHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entity == other.entity && self.generation == other.generation
+    }
+}
+
+impl<T> Eq for GasHandle<T> {}
+
+impl<T> Hash for GasHandle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.entity.hash(state);
+        self.generation.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for GasHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GasHandle")
+            .field("entity", &self.entity)
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
+
+/// Resource holding the current generation of every entity with `T`.
+///
+/// Kept up to date by the observers [`track_handle_generations`] adds.
+#[derive(Resource)]
+pub struct HandleGenerations<T> {
+    generations: HashMap<Entity, u32>,
+    next: u32,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for HandleGenerations<T> {
+    fn default() -> Self {
+        Self {
+            generations: HashMap::new(),
+            next: 0,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> HandleGenerations<T> {
+    /// Returns a handle to `entity`, if it holds `T`.
+    pub fn handle(&self, entity: Entity) -> Option<GasHandle<T>> {
+        self.generations.get(&entity).map(|&generation| GasHandle {
+            entity,
+            generation,
+            marker: PhantomData,
+        })
+    }
+
+    /// Returns the handle's entity if it still holds the same `T`.
+    pub fn resolve(&self, handle: GasHandle<T>) -> Option<Entity> {
+        (self.generations.get(&handle.entity) == Some(&handle.generation)).then_some(handle.entity)
+    }
+
+    /// Number of entities holding `T`.
+    pub fn len(&self) -> usize {
+        self.generations.len()
+    }
+
+    /// Returns `true` if no entity holds `T`.
+    pub fn is_empty(&self) -> bool {
+        self.generations.is_empty()
+    }
+
+    fn assign(&mut self, entity: Entity) {
+        self.generations.insert(entity, self.next);
+        self.next = self.next.wrapping_add(1);
+    }
+
+    fn invalidate(&mut self, entity: Entity) {
+        self.generations.remove(&entity);
+    }
+}
+
+/// Tracks handle generations for `T`. Calls after the first do nothing.
+///
+/// The attribute, effect and ability plugins call this for
+/// [`AttributeData`], [`ActiveGameplayEffect`] and [`AbilitySpec`].
+pub fn track_handle_generations<T: Component>(app: &mut App) {
+    if app.world().contains_resource::<HandleGenerations<T>>() {
+        return;
+    }
+    app.init_resource::<HandleGenerations<T>>()
+        .add_observer(
+            |ev: On<Add, T>, mut generations: ResMut<HandleGenerations<T>>| {
+                generations.assign(ev.event_target());
+            },
+        )
+        .add_observer(
+            |ev: On<Remove, T>, mut generations: ResMut<HandleGenerations<T>>| {
+                generations.invalidate(ev.event_target());
+            },
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn test_handles_stop_resolving_once_component_is_gone() {
+        let mut app = App::new();
+        track_handle_generations::<Marker>(&mut app);
+        track_handle_generations::<Marker>(&mut app);
+        let world = app.world_mut();
+
+        let entity = world.spawn(Marker).id();
+        let handle = world
+            .resource::<HandleGenerations<Marker>>()
+            .handle(entity)
+            .unwrap();
+        assert_eq!(handle.resolve(world), Some(entity));
+
+        // Readding the component gives it a new generation.
+        world.entity_mut(entity).remove::<Marker>();
+        assert!(!handle.is_valid(world));
+        world.entity_mut(entity).insert(Marker);
+        let readded = world
+            .resource::<HandleGenerations<Marker>>()
+            .handle(entity)
+            .unwrap();
+        assert_ne!(readded, handle);
+        assert!(!handle.is_valid(world));
+        assert!(readded.is_valid(world));
+
+        world.despawn(entity);
+        assert!(!readded.is_valid(world));
+        assert!(world.resource::<HandleGenerations<Marker>>().is_empty());
+    }
+}
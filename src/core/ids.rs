@@ -0,0 +1,127 @@
+//! Interned definition IDs.
+//!
+//! Effect and ability definitions are keyed by name. Registering a definition
+//! also assigns it a small integer ID, which is cheap to copy, compare and
+//! store, and can't be confused with an ID of the other kind. The string
+//! names keep working everywhere; the registries convert between the two.
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use string_cache::DefaultAtom as Atom;
+
+/// An ID handed out by an [`IdTable`].
+pub trait InternedId: Copy {
+    /// Wraps a table index.
+    fn from_index(index: u32) -> Self;
+
+    /// Returns the table index.
+    fn index(self) -> u32;
+}
+
+/// Interned ID of a gameplay effect definition.
+///
+/// Obtained from [`GameplayEffectRegistry::id`](crate::effects::GameplayEffectRegistry::id).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EffectId(u32);
+
+impl InternedId for EffectId {
+    fn from_index(index: u32) -> Self {
+        Self(index)
+    }
+
+    fn index(self) -> u32 {
+        self.0
+    }
+}
+
+/// Interned ID of an ability definition.
+///
+/// Obtained from [`AbilityRegistry::id`](crate::abilities::AbilityRegistry::id).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AbilityId(u32);
+
+impl InternedId for AbilityId {
+    fn from_index(index: u32) -> Self {
+        Self(index)
+    }
+
+    fn index(self) -> u32 {
+        self.0
+    }
+}
+
+/// Two-way mapping between names and interned IDs.
+///
+/// IDs are never reused, so an ID stays valid (and keeps naming the same
+/// definition) after its definition is removed and registered again.
+#[derive(Debug, Clone)]
+pub struct IdTable<I> {
+    ids: HashMap<Atom, u32>,
+    names: Vec<Atom>,
+    marker: PhantomData<I>,
+}
+
+impl<I> Default for IdTable<I> {
+    fn default() -> Self {
+        Self {
+            ids: HashMap::new(),
+            names: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<I: InternedId> IdTable<I> {
+    /// Returns the ID of `name`, assigning a new one if needed.
+    pub fn intern(&mut self, name: impl Into<Atom>) -> I {
+        let name = name.into();
+        if let Some(&index) = self.ids.get(&name) {
+            return I::from_index(index);
+        }
+        let index = self.names.len() as u32;
+        self.ids.insert(name.clone(), index);
+        self.names.push(name);
+        I::from_index(index)
+    }
+
+    /// Returns the ID of `name`, if it was interned.
+    pub fn get(&self, name: impl Into<Atom>) -> Option<I> {
+        self.ids
+            .get(&name.into())
+            .map(|&index| I::from_index(index))
+    }
+
+    /// Returns the name behind `id`.
+    pub fn name(&self, id: I) -> Option<&Atom> {
+        self.names.get(id.index() as usize)
+    }
+
+    /// Number of interned names.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns `true` if nothing was interned.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern_round_trips() {
+        let mut table = IdTable::<EffectId>::default();
+        let burn = table.intern("burn");
+        let heal = table.intern("heal");
+
+        assert_ne!(burn, heal);
+        assert_eq!(table.intern("burn"), burn);
+        assert_eq!(table.get("heal"), Some(heal));
+        assert_eq!(table.get("typo"), None);
+        assert_eq!(table.name(burn).map(|name| name.as_ref()), Some("burn"));
+        assert_eq!(table.len(), 2);
+    }
+}
@@ -2,11 +2,30 @@
 //!
 //! This module provides core types and utilities used across the GAS system.
 
+pub mod cleanup;
 pub mod components;
+pub mod display;
 pub mod events;
 pub mod handles;
+pub mod ids;
+pub mod rng;
+pub mod scene;
+pub mod settings;
 pub mod system_sets;
+pub mod tag_changes;
+pub mod timestep;
+pub mod validation;
 
+pub use cleanup::*;
 pub use components::*;
+pub use display::*;
 pub use events::*;
+pub use handles::*;
+pub use ids::*;
+pub use rng::*;
+pub use scene::*;
+pub use settings::*;
 pub use system_sets::*;
+pub use tag_changes::*;
+pub use timestep::*;
+pub use validation::*;
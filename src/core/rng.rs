@@ -0,0 +1,152 @@
+//! Deterministic random numbers.
+//!
+//! [`GasRng`] is a small seeded generator shared by GAS systems that need
+//! randomness, so a run can be reproduced by reseeding it.
+
+use bevy::prelude::*;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Seeded random number generator resource (SplitMix64).
+///
+/// # Example
+///
+/// ```
+/// # use bevy_gameplay_ability_system::core::GasRng;
+/// let mut a = GasRng::new(7);
+/// let mut b = GasRng::new(7);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// ```
+#[derive(Resource, Debug)]
+pub struct GasRng {
+    state: u64,
+    /// Rolls drawn by [`GasRng::next_roll`] since the last reseed.
+    rolls: AtomicU64,
+}
+
+impl GasRng {
+    /// Seed used by `GasRng::default()`.
+    pub const DEFAULT_SEED: u64 = 0x5EED_5EED;
+
+    /// Creates a generator from a seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+            rolls: AtomicU64::new(0),
+        }
+    }
+
+    /// Restarts the sequence from a seed.
+    pub fn reseed(&mut self, seed: u64) {
+        self.state = seed;
+        *self.rolls.get_mut() = 0;
+    }
+
+    /// Returns the next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a float in `[0, 1)`.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Returns a float in `[0, 1)` derived from the seed and `salt`.
+    ///
+    /// Does not advance the generator, so it can be used where only `&World`
+    /// is available. Equal salts give equal rolls until the generator is
+    /// reseeded or advanced.
+    pub fn roll(&self, salt: u64) -> f32 {
+        let mut mixed = Self::new(self.state ^ salt.wrapping_mul(0xD1B5_4A32_D192_ED03));
+        mixed.next_f32()
+    }
+
+    /// Returns a float in `[0, 1)` derived from the seed, `salt` and how many
+    /// rolls this method drew since the last reseed.
+    ///
+    /// Like [`GasRng::roll`] it only needs `&self`, but equal salts roll
+    /// independently. A run reseeded and rolling in the same order rolls the
+    /// same.
+    pub fn next_roll(&self, salt: u64) -> f32 {
+        let index = self.rolls.fetch_add(1, Ordering::Relaxed);
+        self.roll(salt ^ index.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+    }
+
+    /// Returns an index in `0..len`. `len` must not be zero.
+    pub fn index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len as u64) as usize
+    }
+
+    /// Shuffles a slice in place.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.index(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+impl Default for GasRng {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+impl Clone for GasRng {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state,
+            rolls: AtomicU64::new(self.rolls.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+impl PartialEq for GasRng {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+            && self.rolls.load(Ordering::Relaxed) == other.rolls.load(Ordering::Relaxed)
+    }
+}
+
+impl Eq for GasRng {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_is_seeded_and_in_range() {
+        let mut rng = GasRng::new(42);
+        let first: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+        rng.reseed(42);
+        let again: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+        assert_eq!(first, again);
+
+        for _ in 0..100 {
+            let value = rng.next_f32();
+            assert!((0.0..1.0).contains(&value));
+            assert!(rng.index(3) < 3);
+        }
+
+        let roll = rng.roll(9);
+        assert!((0.0..1.0).contains(&roll));
+        assert_eq!(roll, rng.roll(9));
+        assert_ne!(roll, rng.roll(10));
+
+        rng.reseed(42);
+        let next_rolls: Vec<f32> = (0..4).map(|_| rng.next_roll(9)).collect();
+        assert_ne!(next_rolls[0], next_rolls[1]);
+        rng.reseed(42);
+        let replayed: Vec<f32> = (0..4).map(|_| rng.next_roll(9)).collect();
+        assert_eq!(next_rolls, replayed);
+
+        let mut items = [1, 2, 3, 4, 5];
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+    }
+}
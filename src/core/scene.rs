@@ -0,0 +1,59 @@
+//! Scene export of a character's GAS entities.
+//!
+//! Attributes, active effects, modifiers and ability specs are reflected and
+//! linked to their owner through relationships, so a [`DynamicScene`] holding
+//! them restores the character's GAS state when written back to a world.
+//! Entity references are remapped on load.
+//!
+//! Not saved: `OwnedTags` counts (tags granted by loaded effects are added
+//! back, loose tags are lost), running ability instances, and which attribute
+//! set an attribute belongs to (relinked through the `AttributeSetRegistry`).
+//!
+//! # Example
+//!
+//! ```ignore
+//! let scene = extract_gas_scene(world, player);
+//! let ron = scene.serialize(&world.resource::<AppTypeRegistry>().read())?;
+//! ```
+
+use crate::abilities::OwnedAbilities;
+use crate::attributes::AttributeName;
+use crate::effects::{ActiveEffects, EffectModifiers};
+use bevy::prelude::*;
+
+/// Returns `entity` and the GAS entities that belong to it: its attributes,
+/// the active effects targeting it with their modifiers, and its ability
+/// specs.
+pub fn gas_scene_entities(world: &World, entity: Entity) -> Vec<Entity> {
+    let mut entities = vec![entity];
+    let Ok(owner) = world.get_entity(entity) else {
+        return entities;
+    };
+
+    if let Some(children) = owner.get::<Children>() {
+        entities.extend(
+            children
+                .iter()
+                .filter(|child| world.get::<AttributeName>(*child).is_some()),
+        );
+    }
+    if let Some(effects) = owner.get::<ActiveEffects>() {
+        for effect in effects.iter() {
+            entities.push(effect);
+            if let Some(modifiers) = world.get::<EffectModifiers>(effect) {
+                entities.extend(modifiers.iter());
+            }
+        }
+    }
+    if let Some(abilities) = owner.get::<OwnedAbilities>() {
+        entities.extend(abilities.iter());
+    }
+    entities
+}
+
+/// Builds a scene of `entity` and its GAS entities.
+pub fn extract_gas_scene(world: &World, entity: Entity) -> DynamicScene {
+    DynamicSceneBuilder::from_world(world)
+        .extract_entities(gas_scene_entities(world, entity).into_iter())
+        .build()
+}
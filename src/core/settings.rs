@@ -0,0 +1,140 @@
+//! Global GAS behavior settings.
+//!
+//! [`GasSettings`] collects the knobs that change how GAS behaves across
+//! modules: caps, cue rate limits, periodic tick alignment, stasis,
+//! validation and the schedule timers tick in. Configure it with
+//! [`GasPlugin::builder`] (or insert it before adding `GasPlugin`); the
+//! plugin keeps the [`GasTickMode`] and [`GasValidationMode`] resources in
+//! line with it.
+//!
+//! [`GasPlugin::builder`]: crate::GasPlugin::builder
+
+use super::timestep::GasTickMode;
+use super::validation::GasValidationMode;
+use crate::cues::{CueRateLimit, DEFAULT_EXECUTED_ACTOR_LIFETIME};
+use bevy::prelude::*;
+use bevy_gameplay_tag::GameplayTag;
+
+/// When the first execution of a periodic effect happens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PeriodicTickAlignment {
+    /// Execute on application, then every period (as in Unreal).
+    #[default]
+    Immediate,
+    /// Execute one period after application.
+    AfterPeriod,
+    /// Execute on multiples of the period of elapsed app time, so effects
+    /// with the same period tick together whenever they were applied.
+    Global,
+}
+
+impl PeriodicTickAlignment {
+    /// Seconds until the first execution of an effect with `period` applied
+    /// at `elapsed` seconds.
+    pub fn first_delay(self, period: f32, elapsed: f32) -> f32 {
+        match self {
+            Self::Immediate => 0.0,
+            Self::AfterPeriod => period,
+            Self::Global => (period - elapsed.rem_euclid(period)) % period,
+        }
+    }
+}
+
+/// Resource holding global GAS behavior settings.
+///
+/// `tick_mode` and `strict_validation` are read when `GasPlugin` is built;
+/// the other fields may be changed at runtime.
+///
+/// # Example
+///
+/// ```ignore
+/// app.add_plugins(
+///     GasPlugin::builder()
+///         .with_max_active_effects_per_entity(32)
+///         .with_periodic_tick_alignment(PeriodicTickAlignment::Global),
+/// );
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct GasSettings {
+    /// Maximum active effect entities on one target (`None` = unlimited).
+    /// Applications that would spawn another effect past it are dropped;
+    /// stacking onto an existing effect still works.
+    pub max_active_effects_per_entity: Option<usize>,
+    /// Maximum expired effects removed per frame (`None` = unlimited).
+    /// The rest wait in the [`ExpiredEffectQueue`] for later frames, keeping
+    /// their modifiers until then.
+    ///
+    /// [`ExpiredEffectQueue`]: crate::effects::ExpiredEffectQueue
+    pub max_effect_removals_per_frame: Option<usize>,
+    /// Rate limit for cue tags without one set on the `GameplayCueManager`.
+    pub default_cue_rate_limit: Option<CueRateLimit>,
+    /// Seconds an actor spawned by an `Executed` cue lives when its config
+    /// sets no lifetime.
+    pub executed_cue_actor_lifetime: f32,
+    /// When periodic effects first execute.
+    pub periodic_tick_alignment: PeriodicTickAlignment,
+    /// Panic on startup validation problems, as [`GasValidationMode::Strict`].
+    pub strict_validation: bool,
+    /// Where GAS timers tick.
+    pub tick_mode: GasTickMode,
+    /// Tag holding a target in stasis (`None` = no stasis rule), e.g.
+    /// `State.Stasis`. While the target has it, the duration and periodic
+    /// timers of its effects stop, except those of effects granting it.
+    pub stasis_tag: Option<GameplayTag>,
+}
+
+impl Default for GasSettings {
+    fn default() -> Self {
+        Self {
+            max_active_effects_per_entity: None,
+            max_effect_removals_per_frame: None,
+            default_cue_rate_limit: None,
+            executed_cue_actor_lifetime: DEFAULT_EXECUTED_ACTOR_LIFETIME,
+            periodic_tick_alignment: PeriodicTickAlignment::default(),
+            strict_validation: false,
+            tick_mode: GasTickMode::default(),
+            stasis_tag: None,
+        }
+    }
+}
+
+impl GasSettings {
+    /// Returns the settings inserted in `app`, or defaults taken from the
+    /// inserted [`GasTickMode`] and [`GasValidationMode`].
+    pub fn of(app: &App) -> Self {
+        app.world()
+            .get_resource::<Self>()
+            .cloned()
+            .unwrap_or_else(|| Self {
+                tick_mode: GasTickMode::of(app),
+                strict_validation: GasValidationMode::of(app) == GasValidationMode::Strict,
+                ..default()
+            })
+    }
+
+    /// Returns the validation mode these settings ask for, falling back to
+    /// the one inserted in `app`.
+    pub fn validation_mode(&self, app: &App) -> GasValidationMode {
+        if self.strict_validation {
+            GasValidationMode::Strict
+        } else {
+            GasValidationMode::of(app)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_periodic_first_delay() {
+        assert_eq!(PeriodicTickAlignment::Immediate.first_delay(2.0, 3.0), 0.0);
+        assert_eq!(
+            PeriodicTickAlignment::AfterPeriod.first_delay(2.0, 3.0),
+            2.0
+        );
+        assert_eq!(PeriodicTickAlignment::Global.first_delay(2.0, 3.0), 1.0);
+        assert_eq!(PeriodicTickAlignment::Global.first_delay(2.0, 4.0), 0.0);
+    }
+}
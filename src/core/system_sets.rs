@@ -3,6 +3,7 @@
 //! This module defines the system sets used to organize and order
 //! the various systems in the Gameplay Ability System.
 
+use bevy::ecs::schedule::ScheduleLabel;
 use bevy::prelude::*;
 
 /// System sets for the Gameplay Ability System.
@@ -88,12 +89,34 @@ pub enum CueSystemSet {
     UpdateWhileActive,
 }
 
+/// Marks an app whose GAS system sets are configured.
+#[derive(Resource)]
+struct GasSystemSetsConfigured;
+
 /// Helper function to configure GAS system ordering.
 ///
-/// This sets up the correct execution order for all GAS systems.
+/// This sets up the correct execution order for all GAS systems. The
+/// ordering is configured in both `Update` and `FixedUpdate`, where effects
+/// tick under [`GasTickMode::Fixed`](super::GasTickMode::Fixed) and the whole
+/// simulation runs under
+/// [`GasTickMode::FixedSimulation`](super::GasTickMode::FixedSimulation).
+///
+/// Every GAS plugin calls this, so the sub-plugins order their systems when
+/// added without `GasPlugin`. Calls after the first do nothing. User systems
+/// can be ordered against the sets, e.g.
+/// `app.add_systems(Update, read_health.after(GasSystemSet::Effects))`.
 pub fn configure_gas_system_sets(app: &mut App) {
+    if app.world().contains_resource::<GasSystemSetsConfigured>() {
+        return;
+    }
+    app.insert_resource(GasSystemSetsConfigured);
+    configure_schedule_sets(app, FixedUpdate);
+    configure_schedule_sets(app, Update);
+}
+
+fn configure_schedule_sets(app: &mut App, schedule: impl ScheduleLabel + Clone) {
     app.configure_sets(
-        Update,
+        schedule.clone(),
         (
             GasSystemSet::Input,
             GasSystemSet::Attributes,
@@ -107,7 +130,7 @@ pub fn configure_gas_system_sets(app: &mut App) {
 
     // Configure attribute system ordering
     app.configure_sets(
-        Update,
+        schedule.clone(),
         (AttributeSystemSet::Clamp, AttributeSystemSet::Events)
             .chain()
             .in_set(GasSystemSet::Attributes),
@@ -115,7 +138,7 @@ pub fn configure_gas_system_sets(app: &mut App) {
 
     // Configure effect system ordering
     app.configure_sets(
-        Update,
+        schedule.clone(),
         (
             EffectSystemSet::Apply,
             EffectSystemSet::CreateModifiers,
@@ -131,7 +154,7 @@ pub fn configure_gas_system_sets(app: &mut App) {
 
     // Configure cue system ordering
     app.configure_sets(
-        Update,
+        schedule,
         (
             CueSystemSet::Handle,
             CueSystemSet::Route,
@@ -166,4 +189,32 @@ mod tests {
         configure_gas_system_sets(&mut app);
         // If this doesn't panic, the configuration is valid
     }
+
+    #[test]
+    fn test_sets_order_systems_across_phases() {
+        #[derive(Resource, Default)]
+        struct Order(Vec<&'static str>);
+
+        let mut app = App::new();
+        configure_gas_system_sets(&mut app);
+        configure_gas_system_sets(&mut app);
+        app.init_resource::<Order>().add_systems(
+            Update,
+            (
+                (|mut order: ResMut<Order>| order.0.push("cleanup")).in_set(GasSystemSet::Cleanup),
+                (|mut order: ResMut<Order>| order.0.push("aggregate"))
+                    .in_set(EffectSystemSet::Aggregate),
+                (|mut order: ResMut<Order>| order.0.push("abilities"))
+                    .in_set(GasSystemSet::Abilities),
+                (|mut order: ResMut<Order>| order.0.push("clamp"))
+                    .in_set(AttributeSystemSet::Clamp),
+            ),
+        );
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Order>().0,
+            vec!["clamp", "aggregate", "abilities", "cleanup"]
+        );
+    }
 }
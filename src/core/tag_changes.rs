@@ -0,0 +1,77 @@
+//! Owner tag change events.
+//!
+//! Features reacting to the tags of an owner (tag triggers, cancel-on-tag,
+//! ongoing requirements) listen to [`OwnerTagsChangedEvent`] instead of
+//! scanning every owner with a changed [`OwnedTags`]. The event reports the
+//! explicit tags an owner gained and lost, whichever effect, ability or game
+//! system changed them; changes to a tag's count alone are not reported.
+//!
+//! # Example
+//! ```ignore
+//! app.add_observer(|ev: On<OwnerTagsChangedEvent>| {
+//!     if ev.added.contains(&GameplayTag::new("State.Stunned")) {
+//!         info!("{} is stunned", ev.owner);
+//!     }
+//! });
+//! ```
+
+use super::components::OwnedTags;
+use bevy::prelude::*;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+
+/// Event triggered when an owner gains or loses explicit tags.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct OwnerTagsChangedEvent {
+    /// The entity whose [`OwnedTags`] changed.
+    pub owner: Entity,
+    /// Tags the owner gained.
+    pub added: Vec<GameplayTag>,
+    /// Tags the owner lost.
+    pub removed: Vec<GameplayTag>,
+}
+
+/// The explicit tags of an owner as of its last [`OwnerTagsChangedEvent`].
+#[derive(Component, Debug, Default, Clone)]
+pub struct ReportedOwnedTags(Vec<GameplayTag>);
+
+/// System that compares each changed [`OwnedTags`] with the tags last
+/// reported for its owner and triggers an [`OwnerTagsChangedEvent`] with the
+/// difference.
+pub fn emit_owner_tags_changed_system(
+    mut commands: Commands,
+    mut owners: Query<(Entity, &OwnedTags, Option<&mut ReportedOwnedTags>), Changed<OwnedTags>>,
+) {
+    for (owner, tags, reported) in owners.iter_mut() {
+        let current = &tags.0.explicit_tags.gameplay_tags;
+        let previous = reported
+            .as_ref()
+            .map_or(&[][..], |reported| &reported.0[..]);
+        let added: Vec<_> = current
+            .iter()
+            .filter(|tag| !previous.contains(tag))
+            .cloned()
+            .collect();
+        let removed: Vec<_> = previous
+            .iter()
+            .filter(|tag| !current.contains(tag))
+            .cloned()
+            .collect();
+        if added.is_empty() && removed.is_empty() {
+            continue;
+        }
+
+        match reported {
+            Some(mut reported) => reported.0.clone_from(current),
+            None => {
+                commands
+                    .entity(owner)
+                    .insert(ReportedOwnedTags(current.clone()));
+            }
+        }
+        commands.trigger(OwnerTagsChangedEvent {
+            owner,
+            added,
+            removed,
+        });
+    }
+}
@@ -0,0 +1,322 @@
+//! Choice of schedule for time-driven GAS state.
+//!
+//! By default effect durations and periods (and so cooldowns and periodic
+//! regeneration) tick in `Update` by the frame's delta, which makes results
+//! depend on the frame rate. Lockstep and rollback games can build
+//! `GasPlugin` with [`GasTickMode::Fixed`] to tick them in `FixedUpdate`
+//! instead, where Bevy accumulates frame time into whole fixed steps.
+//! Combined with a seeded [`GasRng`](super::GasRng), runs with the same
+//! inputs then produce the same results. Physics-tied and networked games can
+//! go further with [`GasTickMode::FixedSimulation`]
+//! (`GasPlugin::builder().in_fixed_update()`), which moves all attribute,
+//! effect and ability systems to `FixedUpdate` while cues keep running every
+//! frame in `Update`.
+//!
+//! GAS timers (effect durations and periods, regeneration and ability wait
+//! tasks) can also run slower, faster or not at all without touching `Time`:
+//! [`GlobalGasTimeScale`] scales every timer and a [`GasTimeScale`] on an
+//! entity scales the timers of effects on it and of its abilities, so
+//! slow-motion zones, stasis effects and pause menus leave the rest of the app
+//! running.
+//!
+//! Entities far from any player can opt into [`GasSimulationLod`], which
+//! ticks their effect and regeneration timers in chunks instead of every
+//! frame.
+
+use super::components::GasDisabled;
+use super::settings::GasSettings;
+use crate::error::GasError;
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// Where GAS ticks effect durations and periods.
+///
+/// Set with `GasPlugin::builder().with_tick_mode(..)`, which inserts this
+/// resource. GAS systems are added to their schedules when the plugin is
+/// built, so replacing the resource afterwards has no effect; it is reset to
+/// the built mode and a [`GasError::TickModeChanged`] is reported.
+///
+/// # Example
+///
+/// ```ignore
+/// app.insert_resource(GasRng::new(match_seed))
+///     .add_plugins(GasPlugin::builder().with_tick_mode(GasTickMode::Fixed));
+/// ```
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GasTickMode {
+    /// Tick in `Update` by the frame's delta.
+    #[default]
+    Variable,
+    /// Tick in `FixedUpdate` by the fixed timestep.
+    Fixed,
+    /// Run the whole simulation (attributes, effects and abilities) in
+    /// `FixedUpdate`. Cues, asset loading and input observers stay in
+    /// `Update`.
+    FixedSimulation,
+}
+
+impl GasTickMode {
+    /// Returns the mode inserted in `app`, or the default.
+    pub fn of(app: &App) -> Self {
+        app.world()
+            .get_resource::<Self>()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns whether timers tick by the fixed timestep.
+    pub fn is_fixed(self) -> bool {
+        self != Self::Variable
+    }
+
+    /// Returns the schedule time-driven systems run in.
+    pub fn schedule(self) -> InternedScheduleLabel {
+        if self.is_fixed() {
+            FixedUpdate.intern()
+        } else {
+            Update.intern()
+        }
+    }
+
+    /// Returns the schedule the other attribute, effect and ability systems
+    /// run in.
+    pub fn simulation_schedule(self) -> InternedScheduleLabel {
+        match self {
+            Self::FixedSimulation => FixedUpdate.intern(),
+            Self::Variable | Self::Fixed => Update.intern(),
+        }
+    }
+}
+
+/// Speed of all GAS timers. `0.0` pauses them.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct GlobalGasTimeScale {
+    /// Multiplier applied to the frame's delta.
+    pub scale: f32,
+    /// Stops GAS timers while set, keeping `scale` for when it is cleared.
+    pub paused: bool,
+}
+
+impl Default for GlobalGasTimeScale {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            paused: false,
+        }
+    }
+}
+
+impl GlobalGasTimeScale {
+    /// Returns the scale in effect.
+    pub fn effective(&self) -> f32 {
+        if self.paused { 0.0 } else { self.scale }
+    }
+}
+
+/// Speed of the GAS timers of one entity: effects targeting it and its
+/// abilities' tasks. Multiplies the [`GlobalGasTimeScale`]; `0.0` freezes
+/// them.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct GasTimeScale(pub f32);
+
+impl Default for GasTimeScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Ticks the effect and regeneration timers of an entity in chunks.
+///
+/// An opt-in level of detail for entities far from any player. Frame time is
+/// accumulated and handed to the timers once `interval` seconds have built
+/// up: durations advance by the whole chunk, periodic effects run every
+/// execution they missed, and regeneration adds `rate * chunk`, so the
+/// results match ticking every frame while the per-frame work is skipped.
+/// Ability tasks keep ticking every frame.
+///
+/// Removing the component drops the time accumulated so far; set `interval`
+/// to `0.0` first to hand it over on the next tick.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct GasSimulationLod {
+    /// Seconds accumulated before the timers tick.
+    pub interval: f32,
+    accumulated: f32,
+    released: f32,
+}
+
+impl GasSimulationLod {
+    /// Ticks the timers every `interval` seconds.
+    pub fn new(interval: f32) -> Self {
+        Self {
+            interval,
+            accumulated: 0.0,
+            released: 0.0,
+        }
+    }
+
+    /// Adds `delta` and releases the accumulated time once it reaches
+    /// `interval`.
+    pub fn advance(&mut self, delta: f32) {
+        self.accumulated += delta;
+        if self.accumulated >= self.interval {
+            self.released = self.accumulated;
+            self.accumulated = 0.0;
+        } else {
+            self.released = 0.0;
+        }
+    }
+
+    /// Seconds released to the timers this tick, zero between chunks.
+    pub fn released(&self) -> f32 {
+        self.released
+    }
+
+    /// Seconds accumulated toward the next chunk.
+    pub fn accumulated(&self) -> f32 {
+        self.accumulated
+    }
+}
+
+/// System that advances every [`GasSimulationLod`] by the entity's scaled
+/// delta, so time scales and [`GasDisabled`] apply while time accumulates.
+pub fn advance_simulation_lod_system(
+    time: Res<Time>,
+    global_scale: Option<Res<GlobalGasTimeScale>>,
+    mut lods: Query<(
+        &mut GasSimulationLod,
+        Option<&GasTimeScale>,
+        Has<GasDisabled>,
+    )>,
+) {
+    let global_delta = time.delta_secs()
+        * global_scale
+            .as_deref()
+            .map_or(1.0, GlobalGasTimeScale::effective);
+    for (mut lod, scale, disabled) in lods.iter_mut() {
+        lod.advance(scaled_delta(global_delta, scale, disabled));
+    }
+}
+
+/// System resetting a [`GasTickMode`] replaced after `GasPlugin` was built to
+/// the mode its systems were scheduled with, and reporting the replacement
+/// as a [`GasError::TickModeChanged`].
+pub fn check_gas_tick_mode_system(
+    mut commands: Commands,
+    mut tick_mode: ResMut<GasTickMode>,
+    settings: Res<GasSettings>,
+) {
+    if *tick_mode != settings.tick_mode {
+        GasError::TickModeChanged {
+            inserted: *tick_mode,
+            built: settings.tick_mode,
+        }
+        .report(&mut commands);
+        *tick_mode = settings.tick_mode;
+    }
+}
+
+/// Scales `global_delta` for an entity with `scale`, zero while disabled.
+fn scaled_delta(global_delta: f32, scale: Option<&GasTimeScale>, disabled: bool) -> f32 {
+    if disabled {
+        return 0.0;
+    }
+    global_delta * scale.map_or(1.0, |scale| scale.0).max(0.0)
+}
+
+/// Frame delta for GAS timers, with time scales applied.
+#[derive(SystemParam)]
+pub struct GasTime<'w, 's> {
+    pub time: Res<'w, Time>,
+    pub global_scale: Option<Res<'w, GlobalGasTimeScale>>,
+    pub scales: Query<'w, 's, &'static GasTimeScale>,
+    pub disabled: Query<'w, 's, (), With<GasDisabled>>,
+    pub lods: Query<'w, 's, &'static GasSimulationLod>,
+}
+
+impl GasTime<'_, '_> {
+    /// Returns the delta scaled by the global time scale only.
+    pub fn global_delta_secs(&self) -> f32 {
+        let scale = self
+            .global_scale
+            .as_deref()
+            .map_or(1.0, GlobalGasTimeScale::effective);
+        self.time.delta_secs() * scale
+    }
+
+    /// Returns the delta for timers of `entity`, zero while it has
+    /// [`GasDisabled`].
+    pub fn delta_secs(&self, entity: Entity) -> f32 {
+        scaled_delta(
+            self.global_delta_secs(),
+            self.scales.get(entity).ok(),
+            self.disabled.contains(entity),
+        )
+    }
+
+    /// Returns the delta for the effect and regeneration timers of `entity`:
+    /// [`delta_secs`](Self::delta_secs), or the chunk its
+    /// [`GasSimulationLod`] released this tick.
+    pub fn lod_delta_secs(&self, entity: Entity) -> f32 {
+        match self.lods.get(entity) {
+            Ok(lod) => lod.released(),
+            Err(_) => self.delta_secs(entity),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    #[test]
+    fn test_delta_is_scaled_per_entity_and_globally() {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(100));
+        world.insert_resource(time);
+        world.insert_resource(GlobalGasTimeScale {
+            scale: 0.5,
+            paused: false,
+        });
+        let normal = world.spawn_empty().id();
+        let frozen = world.spawn(GasTimeScale(0.0)).id();
+        let hasted = world.spawn(GasTimeScale(2.0)).id();
+        let disabled = world.spawn((GasTimeScale(2.0), GasDisabled)).id();
+
+        world
+            .run_system_once(move |time: GasTime| {
+                assert!((time.delta_secs(normal) - 0.05).abs() < 1e-6);
+                assert_eq!(time.delta_secs(frozen), 0.0);
+                assert!((time.delta_secs(hasted) - 0.1).abs() < 1e-6);
+                assert_eq!(time.delta_secs(disabled), 0.0);
+            })
+            .unwrap();
+
+        world.resource_mut::<GlobalGasTimeScale>().paused = true;
+        world
+            .run_system_once(move |time: GasTime| assert_eq!(time.delta_secs(hasted), 0.0))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_simulation_lod_releases_time_in_chunks() {
+        let mut lod = GasSimulationLod::new(0.25);
+        let mut released = Vec::new();
+        for _ in 0..6 {
+            lod.advance(0.1);
+            released.push(lod.released());
+        }
+        assert_eq!(released[..2], [0.0, 0.0]);
+        assert!((released[2] - 0.3).abs() < 1e-6);
+        assert_eq!(released[3..5], [0.0, 0.0]);
+        assert!((released[5] - 0.3).abs() < 1e-6);
+
+        lod.interval = 0.0;
+        lod.advance(0.1);
+        assert!((lod.released() - 0.1).abs() < 1e-6);
+        assert_eq!(lod.accumulated(), 0.0);
+    }
+}
@@ -0,0 +1,382 @@
+//! Startup check that definitions only reference things that exist.
+//!
+//! After startup, once the definition files in [`GasValidationAssets`] have
+//! loaded and been registered, GAS checks that:
+//! - every ability's cost, cost over time, cooldown and immunity window effect
+//!   is in the [`GameplayEffectRegistry`], and its cooldown category is
+//!   registered;
+//! - every modifier's attribute, and the attribute an attribute-based
+//!   magnitude reads, belongs to a set in the [`AttributeSetRegistry`];
+//! - every effect cue tag has a handler in the [`GameplayCueManager`].
+//!
+//! Problems are logged and kept in the [`GasValidationReport`] resource. With
+//! [`GasValidationMode::Strict`] the app panics instead.
+
+use crate::abilities::AbilityRegistry;
+use crate::attributes::AttributeSetRegistry;
+use crate::cues::GameplayCueManager;
+use crate::effects::{GameplayEffectRegistry, MagnitudeCalculation};
+use bevy::asset::UntypedAssetId;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use std::fmt;
+use string_cache::DefaultAtom as Atom;
+
+/// How a failed startup validation is reported.
+///
+/// Read when `GasPlugin` is built, so it must be inserted before it.
+///
+/// # Example
+///
+/// ```ignore
+/// app.insert_resource(GasValidationMode::Strict)
+///     .add_plugins(GasPlugin);
+/// ```
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GasValidationMode {
+    /// Skip validation.
+    Off,
+    /// Log each problem as a warning.
+    #[default]
+    Warn,
+    /// Panic with the report if there is any problem.
+    Strict,
+}
+
+impl GasValidationMode {
+    /// Returns the mode inserted in `app`, or the default.
+    pub fn of(app: &App) -> Self {
+        app.world()
+            .get_resource::<Self>()
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// Definition files validation waits for.
+///
+/// The effect, ability and attribute set asset plugins add the files they
+/// load, so definitions from files still loading after startup are validated
+/// once they are registered instead of reported as missing.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct GasValidationAssets(pub Vec<UntypedAssetId>);
+
+/// A reference that does not resolve.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GasValidationIssue {
+    /// An ability's cost effect is not registered.
+    MissingCostEffect { ability_id: Atom, effect_id: Atom },
+    /// An ability's cost over time effect is not registered.
+    MissingCostOverTimeEffect { ability_id: Atom, effect_id: Atom },
+    /// An ability's cooldown effect is not registered.
+    MissingCooldownEffect { ability_id: Atom, effect_id: Atom },
+    /// An ability's immunity window effect is not registered.
+    MissingImmunityWindowEffect { ability_id: Atom, effect_id: Atom },
+    /// An ability's cooldown category has no registered cooldown effect.
+    UnknownCooldownCategory { ability_id: Atom, category: Atom },
+    /// An effect modifies or reads an attribute no registered set has.
+    UnknownAttribute {
+        effect_id: Atom,
+        attribute_name: Atom,
+    },
+    /// An effect triggers a cue no handler is registered for.
+    UnhandledCue {
+        effect_id: Atom,
+        cue_tag: GameplayTag,
+    },
+}
+
+impl fmt::Display for GasValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingCostEffect {
+                ability_id,
+                effect_id,
+            } => write!(
+                f,
+                "ability '{ability_id}': cost effect '{effect_id}' is not registered"
+            ),
+            Self::MissingCostOverTimeEffect {
+                ability_id,
+                effect_id,
+            } => write!(
+                f,
+                "ability '{ability_id}': cost over time effect '{effect_id}' is not registered"
+            ),
+            Self::MissingCooldownEffect {
+                ability_id,
+                effect_id,
+            } => write!(
+                f,
+                "ability '{ability_id}': cooldown effect '{effect_id}' is not registered"
+            ),
+            Self::MissingImmunityWindowEffect {
+                ability_id,
+                effect_id,
+            } => write!(
+                f,
+                "ability '{ability_id}': immunity window effect '{effect_id}' is not registered"
+            ),
+            Self::UnknownCooldownCategory {
+                ability_id,
+                category,
+            } => write!(
+                f,
+                "ability '{ability_id}': cooldown category '{category}' is not registered"
+            ),
+            Self::UnknownAttribute {
+                effect_id,
+                attribute_name,
+            } => write!(
+                f,
+                "effect '{effect_id}': attribute '{attribute_name}' is not in any attribute set"
+            ),
+            Self::UnhandledCue { effect_id, cue_tag } => write!(
+                f,
+                "effect '{effect_id}': cue '{}' has no handler",
+                cue_tag.get_tag_name()
+            ),
+        }
+    }
+}
+
+/// Result of the startup validation.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct GasValidationReport {
+    /// Problems found, sorted by their message.
+    pub issues: Vec<GasValidationIssue>,
+}
+
+impl GasValidationReport {
+    /// Checks the registries against each other.
+    pub fn build(
+        abilities: &AbilityRegistry,
+        effects: &GameplayEffectRegistry,
+        attribute_sets: &AttributeSetRegistry,
+        cues: &GameplayCueManager,
+    ) -> Self {
+        let mut issues = Vec::new();
+
+        for (ability_id, definition) in &abilities.definitions {
+            if let Some(effect_id) = &definition.cost_effect
+                && effects.get(effect_id.clone()).is_none()
+            {
+                issues.push(GasValidationIssue::MissingCostEffect {
+                    ability_id: ability_id.clone(),
+                    effect_id: effect_id.clone(),
+                });
+            }
+            if let Some(effect_id) = &definition.cost_over_time_effect
+                && effects.get(effect_id.clone()).is_none()
+            {
+                issues.push(GasValidationIssue::MissingCostOverTimeEffect {
+                    ability_id: ability_id.clone(),
+                    effect_id: effect_id.clone(),
+                });
+            }
+            if let Some(effect_id) = &definition.cooldown_effect
+                && effects.get(effect_id.clone()).is_none()
+            {
+                issues.push(GasValidationIssue::MissingCooldownEffect {
+                    ability_id: ability_id.clone(),
+                    effect_id: effect_id.clone(),
+                });
+            }
+            if let Some(effect_id) = &definition.immunity_window_effect
+                && effects.get(effect_id.clone()).is_none()
+            {
+                issues.push(GasValidationIssue::MissingImmunityWindowEffect {
+                    ability_id: ability_id.clone(),
+                    effect_id: effect_id.clone(),
+                });
+            }
+            if let Some(category) = &definition.cooldown_category
+                && abilities.cooldown_category(category).is_none()
+            {
+                issues.push(GasValidationIssue::UnknownCooldownCategory {
+                    ability_id: ability_id.clone(),
+                    category: category.clone(),
+                });
+            }
+        }
+
+        for (effect_id, definition) in &effects.definitions {
+            for modifier in &definition.modifiers {
+                let mut attribute_names = vec![&modifier.attribute_name];
+                if let MagnitudeCalculation::AttributeBased { attribute_name, .. } =
+                    &modifier.magnitude
+                {
+                    attribute_names.push(attribute_name);
+                }
+                for attribute_name in attribute_names {
+                    if !attribute_sets.has_attribute(attribute_name) {
+                        issues.push(GasValidationIssue::UnknownAttribute {
+                            effect_id: effect_id.clone(),
+                            attribute_name: attribute_name.clone(),
+                        });
+                    }
+                }
+            }
+            for cue in &definition.gameplay_cues {
+                if cues.find_handler_tag(&cue.cue_tag).is_none() {
+                    issues.push(GasValidationIssue::UnhandledCue {
+                        effect_id: effect_id.clone(),
+                        cue_tag: cue.cue_tag.clone(),
+                    });
+                }
+            }
+        }
+
+        issues.sort_by_cached_key(ToString::to_string);
+        issues.dedup();
+        Self { issues }
+    }
+
+    /// Returns true if nothing failed to resolve.
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl fmt::Display for GasValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "GAS validation found {} issue(s)", self.issues.len())?;
+        for issue in &self.issues {
+            write!(f, "\n  - {issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The registries checked by validation.
+#[derive(SystemParam)]
+pub struct GasRegistries<'w> {
+    pub abilities: Res<'w, AbilityRegistry>,
+    pub effects: Res<'w, GameplayEffectRegistry>,
+    pub attribute_sets: Res<'w, AttributeSetRegistry>,
+    pub cues: Res<'w, GameplayCueManager>,
+}
+
+/// System that validates the registries and reports the result.
+///
+/// Runs every frame until it has produced a [`GasValidationReport`]. While a
+/// file in [`GasValidationAssets`] is loading it waits, and once they have all
+/// loaded it gives the asset plugins one more frame to register them.
+pub fn validate_gas_registries_system(
+    mut commands: Commands,
+    mode: Res<GasValidationMode>,
+    assets: Res<GasValidationAssets>,
+    asset_server: Option<Res<AssetServer>>,
+    registries: GasRegistries,
+    mut settled: Local<bool>,
+) {
+    let loading = asset_server.is_some_and(|asset_server| {
+        assets
+            .0
+            .iter()
+            .any(|id| asset_server.load_state(*id).is_loading())
+    });
+    if loading {
+        *settled = false;
+        return;
+    }
+    // Loaded files reach the registries in the next frame
+    if !assets.0.is_empty() && !*settled {
+        *settled = true;
+        return;
+    }
+
+    let report = GasValidationReport::build(
+        &registries.abilities,
+        &registries.effects,
+        &registries.attribute_sets,
+        &registries.cues,
+    );
+    if report.is_ok() {
+        debug!(
+            "GAS validation passed ({} abilities, {} effects)",
+            registries.abilities.definitions.len(),
+            registries.effects.definitions.len()
+        );
+    } else if *mode == GasValidationMode::Strict {
+        panic!("{report}");
+    } else {
+        warn!("{report}");
+    }
+    commands.insert_resource(report);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abilities::AbilityDefinition;
+    use crate::attributes::{AttributeMetadata, AttributeSetDefinition};
+    use crate::effects::{GameplayEffectDefinition, ModifierInfo, ModifierOperation};
+
+    struct Vitals;
+
+    impl AttributeSetDefinition for Vitals {
+        fn attribute_names() -> &'static [&'static str] {
+            &["Health"]
+        }
+
+        fn attribute_metadata(_name: &str) -> Option<AttributeMetadata> {
+            None
+        }
+
+        fn default_value(_name: &str) -> f32 {
+            100.0
+        }
+    }
+
+    #[test]
+    fn test_report_lists_unresolved_references() {
+        let mut abilities = AbilityRegistry::default();
+        abilities.register(
+            AbilityDefinition::new("fireball")
+                .with_cost_effect("fireball_cost")
+                .with_cooldown_effect("fireball_cooldown")
+                .with_cost_over_time_effect("fireball_channel")
+                .with_immunity_window("fireball_immunity"),
+        );
+        let mut effects = GameplayEffectRegistry::default();
+        effects.register(GameplayEffectDefinition::new("fireball_cost").add_modifier(
+            ModifierInfo::new(
+                "Mana",
+                ModifierOperation::AddBase,
+                MagnitudeCalculation::scaled(-10.0, 0.0),
+            ),
+        ));
+        let mut attribute_sets = AttributeSetRegistry::default();
+        attribute_sets.register::<Vitals>();
+
+        let report = GasValidationReport::build(
+            &abilities,
+            &effects,
+            &attribute_sets,
+            &GameplayCueManager::default(),
+        );
+        assert_eq!(
+            report.issues,
+            vec![
+                GasValidationIssue::MissingCooldownEffect {
+                    ability_id: "fireball".into(),
+                    effect_id: "fireball_cooldown".into(),
+                },
+                GasValidationIssue::MissingCostOverTimeEffect {
+                    ability_id: "fireball".into(),
+                    effect_id: "fireball_channel".into(),
+                },
+                GasValidationIssue::MissingImmunityWindowEffect {
+                    ability_id: "fireball".into(),
+                    effect_id: "fireball_immunity".into(),
+                },
+                GasValidationIssue::UnknownAttribute {
+                    effect_id: "fireball_cost".into(),
+                    attribute_name: "Mana".into(),
+                },
+            ]
+        );
+    }
+}
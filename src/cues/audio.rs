@@ -0,0 +1,133 @@
+//! Audio cue backend built on `bevy_audio`.
+//!
+//! Provides [`AudioCue`], a ready-made static cue handler that plays a one-shot
+//! sound whenever its cue fires. With the `kira` feature, [`KiraAudioCue`] offers
+//! the same behaviour on top of `bevy_kira_audio`.
+
+use super::manager::GameplayCueParameters;
+use super::notify::GameplayCueNotifyStatic;
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+#[cfg(feature = "kira")]
+pub use super::kira_audio::*;
+
+/// Static cue handler that plays a sound.
+///
+/// Each `Executed` or `OnActive` event spawns a one-shot audio entity that
+/// despawns itself once playback finishes.
+///
+/// # Example
+///
+/// ```ignore
+/// let hit = asset_server.load("sounds/hit.ogg");
+/// manager.register_static_cue_handler(
+///     hit_tag,
+///     Arc::new(AudioCue::new(hit).scaled_by_magnitude().spatial()),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct AudioCue {
+    /// The sound to play.
+    pub sound: Handle<AudioSource>,
+    /// Linear volume before magnitude scaling.
+    pub volume: f32,
+    /// Multiply the volume by `GameplayCueParameters::normalized_magnitude`.
+    pub scale_by_magnitude: bool,
+    /// Play the sound spatially at the cue location.
+    ///
+    /// Requires a `SpatialListener` in the world to be audible as intended.
+    pub spatial: bool,
+}
+
+impl AudioCue {
+    /// Creates a handler that plays `sound` at full volume, non-spatially.
+    pub fn new(sound: Handle<AudioSource>) -> Self {
+        Self {
+            sound,
+            volume: 1.0,
+            scale_by_magnitude: false,
+            spatial: false,
+        }
+    }
+
+    /// Sets the linear base volume.
+    pub fn with_volume(mut self, volume: f32) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    /// Scales the volume by the cue's normalized magnitude.
+    pub fn scaled_by_magnitude(mut self) -> Self {
+        self.scale_by_magnitude = true;
+        self
+    }
+
+    /// Plays the sound spatially at the cue location.
+    pub fn spatial(mut self) -> Self {
+        self.spatial = true;
+        self
+    }
+}
+
+impl GameplayCueNotifyStatic for AudioCue {
+    fn on_execute(&self, _target: Entity, params: &GameplayCueParameters, commands: &mut Commands) {
+        let volume = cue_volume(self.volume, self.scale_by_magnitude, params);
+        commands.spawn((
+            AudioPlayer::new(self.sound.clone()),
+            PlaybackSettings::DESPAWN
+                .with_volume(Volume::Linear(volume))
+                .with_spatial(self.spatial),
+            Transform::from_translation(params.location),
+        ));
+    }
+}
+
+/// Computes the linear volume for an audio cue.
+///
+/// The normalized magnitude is clamped so out-of-range effect magnitudes cannot
+/// amplify a sound beyond its configured volume.
+pub(crate) fn cue_volume(
+    base: f32,
+    scale_by_magnitude: bool,
+    params: &GameplayCueParameters,
+) -> f32 {
+    if scale_by_magnitude {
+        base * params.normalized_magnitude.clamp(0.0, 1.0)
+    } else {
+        base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cue_volume_scaling() {
+        let params = GameplayCueParameters::new().with_magnitude(50.0, 0.5);
+        assert_eq!(cue_volume(0.8, false, &params), 0.8);
+        assert_eq!(cue_volume(0.8, true, &params), 0.4);
+
+        let params = GameplayCueParameters::new().with_magnitude(500.0, 5.0);
+        assert_eq!(cue_volume(0.8, true, &params), 0.8);
+    }
+
+    #[test]
+    fn test_audio_cue_spawns_player_at_location() {
+        let mut app = App::new();
+        let target = app.world_mut().spawn_empty().id();
+        let cue = AudioCue::new(Handle::default()).with_volume(0.5).spatial();
+        let params = GameplayCueParameters::new().with_location(Vec3::new(4.0, 0.0, 2.0));
+
+        let mut commands = app.world_mut().commands();
+        cue.on_execute(target, &params, &mut commands);
+        app.world_mut().flush();
+
+        let mut query = app.world_mut().query::<(&PlaybackSettings, &Transform)>();
+        let (settings, transform) = query.single(app.world()).unwrap();
+        assert!(settings.spatial);
+        assert_eq!(settings.volume, Volume::Linear(0.5));
+        assert_eq!(transform.translation, Vec3::new(4.0, 0.0, 2.0));
+    }
+}
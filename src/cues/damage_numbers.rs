@@ -0,0 +1,433 @@
+//! Floating damage number cue handler.
+//!
+//! [`DamageNumberCue`] shows the cue's raw magnitude at the cue target. Its
+//! style follows the executed cue tag: cues under the heal tag
+//! (`GameplayCue.Heal` by default) are drawn as heals, cues under the crit tag
+//! (`GameplayCue.Damage.Crit`) and critical hits as crits, and anything else as
+//! plain damage. Register the handler under the parent tags it should cover.
+//!
+//! Sizes and drift are relative to the font size, so the defaults read the
+//! same whatever a world unit is; only `offset` is in world units.
+
+use super::manager::{GameplayCueParameters, parent_tags};
+use super::notify::GameplayCueNotifyStatic;
+use bevy::prelude::*;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+
+/// Visual style of a floating damage number.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DamageNumberStyle {
+    /// Text color.
+    pub color: Color,
+    /// Font size in pixels.
+    pub font_size: f32,
+    /// Seconds the number stays visible.
+    pub lifetime: f32,
+    /// Upward drift in font sizes per second.
+    pub rise_speed: f32,
+}
+
+impl Default for DamageNumberStyle {
+    fn default() -> Self {
+        Self {
+            color: Color::WHITE,
+            font_size: 24.0,
+            lifetime: 1.0,
+            rise_speed: 1.5,
+        }
+    }
+}
+
+impl DamageNumberStyle {
+    /// Larger, orange style for critical hits.
+    pub fn crit() -> Self {
+        Self {
+            color: Color::srgb(1.0, 0.55, 0.0),
+            font_size: 36.0,
+            ..Self::default()
+        }
+    }
+
+    /// Green style for healing.
+    pub fn heal() -> Self {
+        Self {
+            color: Color::srgb(0.3, 1.0, 0.3),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the text color.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets the font size.
+    pub fn with_font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// Sets how long the number stays visible.
+    pub fn with_lifetime(mut self, lifetime: f32) -> Self {
+        self.lifetime = lifetime;
+        self
+    }
+
+    /// Sets the upward drift, in font sizes per second.
+    pub fn with_rise_speed(mut self, rise_speed: f32) -> Self {
+        self.rise_speed = rise_speed;
+        self
+    }
+}
+
+/// How a [`DamageNumberCue`] presents its number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DamageNumberDisplay {
+    /// Spawn world-space `Text2d` and trigger a [`DamageNumberEvent`].
+    #[default]
+    WorldText,
+    /// Only trigger a [`DamageNumberEvent`], for games that draw numbers in UI.
+    EventOnly,
+}
+
+/// Triggered for every damage number a [`DamageNumberCue`] shows.
+#[derive(Event, Debug, Clone)]
+pub struct DamageNumberEvent {
+    /// The cue target.
+    pub target: Entity,
+    /// Absolute magnitude shown.
+    pub amount: f32,
+    /// Whether the cue tag marked a heal.
+    pub is_heal: bool,
+    /// Whether the cue tag or the hit marked a crit.
+    pub is_crit: bool,
+    /// World position of the number.
+    pub position: Vec3,
+    /// Style resolved for this number.
+    pub style: DamageNumberStyle,
+}
+
+/// Static cue handler that shows the cue's raw magnitude as a floating number.
+///
+/// Cues under `heal_tag` use `heal_style`; cues under `crit_tag`, or raised by
+/// a critical hit, use `crit_style`; other cues use `style`. The number is
+/// shown unsigned, so the magnitude's sign plays no part. It spawns at the
+/// target's `GlobalTransform` plus `offset`, falling back to the cue location
+/// when the target has no transform.
+///
+/// # Example
+///
+/// ```ignore
+/// let numbers = DamageNumberCue::new().with_offset(Vec3::Y * 2.0);
+/// app.register_gameplay_cue_handler("GameplayCue.Damage", numbers.clone())
+///     .register_gameplay_cue_handler("GameplayCue.Heal", numbers);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DamageNumberCue {
+    /// Style for damage.
+    pub style: DamageNumberStyle,
+    /// Style for critical hits.
+    pub crit_style: DamageNumberStyle,
+    /// Style for healing.
+    pub heal_style: DamageNumberStyle,
+    /// Cue tag whose children are drawn as crits.
+    pub crit_tag: GameplayTag,
+    /// Cue tag whose children are drawn as heals.
+    pub heal_tag: GameplayTag,
+    /// Offset from the target position, in world units.
+    pub offset: Vec3,
+    /// How the number is presented.
+    pub display: DamageNumberDisplay,
+}
+
+impl Default for DamageNumberCue {
+    fn default() -> Self {
+        Self {
+            style: DamageNumberStyle::default(),
+            crit_style: DamageNumberStyle::crit(),
+            heal_style: DamageNumberStyle::heal(),
+            crit_tag: GameplayTag::new("GameplayCue.Damage.Crit"),
+            heal_tag: GameplayTag::new("GameplayCue.Heal"),
+            offset: Vec3::ZERO,
+            display: DamageNumberDisplay::WorldText,
+        }
+    }
+}
+
+impl DamageNumberCue {
+    /// Creates a handler with the default styles.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the damage style.
+    pub fn with_style(mut self, style: DamageNumberStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the crit style.
+    pub fn with_crit_style(mut self, style: DamageNumberStyle) -> Self {
+        self.crit_style = style;
+        self
+    }
+
+    /// Sets the heal style.
+    pub fn with_heal_style(mut self, style: DamageNumberStyle) -> Self {
+        self.heal_style = style;
+        self
+    }
+
+    /// Sets the cue tag whose children are drawn as crits.
+    pub fn with_crit_tag(mut self, tag: GameplayTag) -> Self {
+        self.crit_tag = tag;
+        self
+    }
+
+    /// Sets the cue tag whose children are drawn as heals.
+    pub fn with_heal_tag(mut self, tag: GameplayTag) -> Self {
+        self.heal_tag = tag;
+        self
+    }
+
+    /// Sets the offset from the target position.
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Only triggers [`DamageNumberEvent`] instead of spawning text.
+    pub fn event_only(mut self) -> Self {
+        self.display = DamageNumberDisplay::EventOnly;
+        self
+    }
+}
+
+impl GameplayCueNotifyStatic for DamageNumberCue {
+    fn on_execute(&self, target: Entity, params: &GameplayCueParameters, commands: &mut Commands) {
+        let amount = params.raw_magnitude;
+        if amount == 0.0 {
+            return;
+        }
+
+        let under = |parent: &GameplayTag| {
+            params.cue_tag.as_ref().is_some_and(|cue_tag| {
+                cue_tag == parent || parent_tags(cue_tag).any(|tag| tag == *parent)
+            })
+        };
+        let is_heal = under(&self.heal_tag);
+        let is_crit = !is_heal && (params.critical || under(&self.crit_tag));
+        let style = if is_heal {
+            self.heal_style
+        } else if is_crit {
+            self.crit_style
+        } else {
+            self.style
+        };
+        let display = self.display;
+        let offset = self.offset;
+        let fallback = params.location;
+
+        // The target position is only reachable through the world.
+        commands.queue(move |world: &mut World| {
+            let position = world
+                .get::<GlobalTransform>(target)
+                .map_or(fallback, GlobalTransform::translation)
+                + offset;
+
+            if display == DamageNumberDisplay::WorldText {
+                world.spawn((
+                    Text2d::new(format!("{}", amount.abs().round())),
+                    TextFont::from_font_size(style.font_size),
+                    TextColor(style.color),
+                    Transform::from_translation(position),
+                    FloatingDamageNumber {
+                        elapsed: 0.0,
+                        lifetime: style.lifetime,
+                        rise_speed: style.rise_speed * style.font_size,
+                        color: style.color,
+                    },
+                ));
+            }
+
+            world.trigger(DamageNumberEvent {
+                target,
+                amount: amount.abs(),
+                is_heal,
+                is_crit,
+                position,
+                style,
+            });
+        });
+    }
+}
+
+/// Animation state of a spawned damage number.
+#[derive(Component, Debug, Clone)]
+pub struct FloatingDamageNumber {
+    /// Seconds since the number spawned.
+    pub elapsed: f32,
+    /// Seconds until the number despawns.
+    pub lifetime: f32,
+    /// Upward drift in world units per second.
+    pub rise_speed: f32,
+    /// Base color, faded out over the lifetime.
+    pub color: Color,
+}
+
+/// Drifts damage numbers upward, fades them out and despawns them.
+pub fn animate_damage_numbers_system(
+    mut commands: Commands,
+    mut numbers: Query<(
+        Entity,
+        &mut FloatingDamageNumber,
+        &mut Transform,
+        &mut TextColor,
+    )>,
+    time: Res<Time>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut number, mut transform, mut color) in numbers.iter_mut() {
+        number.elapsed += delta;
+        if number.elapsed >= number.lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        transform.translation.y += number.rise_speed * delta;
+        let alpha = 1.0 - number.elapsed / number.lifetime;
+        color.0 = number.color.with_alpha(number.color.alpha() * alpha);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct ReceivedNumbers(Vec<DamageNumberEvent>);
+
+    fn execute(
+        app: &mut App,
+        cue: &DamageNumberCue,
+        target: Entity,
+        params: GameplayCueParameters,
+    ) {
+        let mut commands = app.world_mut().commands();
+        cue.on_execute(target, &params, &mut commands);
+        app.world_mut().flush();
+    }
+
+    fn tagged(cue_tag: &str, magnitude: f32) -> GameplayCueParameters {
+        GameplayCueParameters {
+            cue_tag: Some(GameplayTag::new(cue_tag)),
+            ..GameplayCueParameters::new().with_magnitude(magnitude, 0.0)
+        }
+    }
+
+    #[test]
+    fn test_damage_number_spawns_at_target() {
+        let mut app = App::new();
+        app.init_resource::<ReceivedNumbers>();
+        app.add_observer(
+            |ev: On<DamageNumberEvent>, mut received: ResMut<ReceivedNumbers>| {
+                received.0.push(ev.event().clone());
+            },
+        );
+        let target = app
+            .world_mut()
+            .spawn(GlobalTransform::from_translation(Vec3::new(10.0, 0.0, 0.0)))
+            .id();
+        let cue = DamageNumberCue::new();
+
+        execute(
+            &mut app,
+            &cue,
+            target,
+            tagged("GameplayCue.Damage.Fire", -42.4),
+        );
+        execute(
+            &mut app,
+            &cue,
+            target,
+            tagged("GameplayCue.Heal.Potion", 15.0),
+        );
+
+        let received = app.world().resource::<ReceivedNumbers>();
+        assert_eq!(received.0.len(), 2);
+        assert_eq!(received.0[0].amount, 42.4);
+        assert!(!received.0[0].is_heal);
+        assert_eq!(received.0[0].position, Vec3::new(10.0, 0.0, 0.0));
+        assert!(received.0[1].is_heal);
+        assert_eq!(received.0[1].style, DamageNumberStyle::heal());
+
+        let mut query = app.world_mut().query::<&Text2d>();
+        let mut texts: Vec<_> = query.iter(app.world()).map(|text| text.0.clone()).collect();
+        texts.sort();
+        assert_eq!(texts, vec!["15".to_string(), "42".to_string()]);
+    }
+
+    #[test]
+    fn test_damage_number_style_follows_cue_tag() {
+        let mut app = App::new();
+        app.init_resource::<ReceivedNumbers>();
+        app.add_observer(
+            |ev: On<DamageNumberEvent>, mut received: ResMut<ReceivedNumbers>| {
+                received.0.push(ev.event().clone());
+            },
+        );
+        let target = app.world_mut().spawn_empty().id();
+        let cue = DamageNumberCue::new().event_only();
+
+        // A positive magnitude under a damage tag is still damage, a negative
+        // one under the heal tag still a heal.
+        execute(&mut app, &cue, target, tagged("GameplayCue.Damage", 20.0));
+        execute(&mut app, &cue, target, tagged("GameplayCue.Heal", -20.0));
+        execute(
+            &mut app,
+            &cue,
+            target,
+            tagged("GameplayCue.Damage.Crit", -20.0),
+        );
+        execute(
+            &mut app,
+            &cue,
+            target,
+            tagged("GameplayCue.Damage", -20.0).with_critical(true),
+        );
+
+        let styles: Vec<_> = app
+            .world()
+            .resource::<ReceivedNumbers>()
+            .0
+            .iter()
+            .map(|number| (number.is_heal, number.is_crit, number.style))
+            .collect();
+        assert_eq!(
+            styles,
+            vec![
+                (false, false, DamageNumberStyle::default()),
+                (true, false, DamageNumberStyle::heal()),
+                (false, true, DamageNumberStyle::crit()),
+                (false, true, DamageNumberStyle::crit()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_event_only_damage_number_spawns_no_text() {
+        let mut app = App::new();
+        let target = app.world_mut().spawn_empty().id();
+
+        execute(
+            &mut app,
+            &DamageNumberCue::new().event_only(),
+            target,
+            tagged("GameplayCue.Damage", -5.0),
+        );
+
+        let mut query = app.world_mut().query::<&FloatingDamageNumber>();
+        assert_eq!(query.iter(app.world()).count(), 0);
+    }
+}
@@ -0,0 +1,108 @@
+//! Cue handling for dedicated servers.
+//!
+//! With the `headless` feature, the presentation handlers (audio, particles,
+//! damage numbers, camera shake and hit-stop) are not compiled and
+//! [`CuePlugin`](super::CuePlugin) never executes cue handlers. Cue triggers
+//! are still forwarded to clients through the [`CueChannel`](super::CueChannel)
+//! and are recorded in the [`HeadlessCueLog`], so servers run the same
+//! gameplay code as clients.
+
+use super::manager::{GameplayCueParameters, PendingCueExecution};
+use super::systems::{TriggerGameplayCueEvent, TriggerGameplayCueOnEntityEvent};
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+/// The most recent cue triggers, oldest first.
+///
+/// Holds at most `capacity` triggers; older ones are dropped.
+#[derive(Resource, Debug, Clone)]
+pub struct HeadlessCueLog {
+    pub recorded: VecDeque<PendingCueExecution>,
+    pub capacity: usize,
+}
+
+impl Default for HeadlessCueLog {
+    fn default() -> Self {
+        Self {
+            recorded: VecDeque::new(),
+            capacity: 256,
+        }
+    }
+}
+
+impl HeadlessCueLog {
+    /// Sets how many triggers are kept.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Records a trigger, dropping the oldest one when full.
+    pub fn record(&mut self, cue: PendingCueExecution) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.recorded.len() == self.capacity {
+            self.recorded.pop_front();
+        }
+        self.recorded.push_back(cue);
+    }
+
+    /// Removes and returns the recorded triggers.
+    pub fn drain(&mut self) -> Vec<PendingCueExecution> {
+        self.recorded.drain(..).collect()
+    }
+}
+
+/// Observer that records cue triggers instead of executing them.
+pub fn record_gameplay_cue(ev: On<TriggerGameplayCueEvent>, mut log: ResMut<HeadlessCueLog>) {
+    let event = ev.event();
+    log.record(PendingCueExecution {
+        cue_tag: event.cue_tag.clone(),
+        event_type: event.event_type,
+        parameters: event.parameters.clone(),
+    });
+}
+
+/// Observer that records entity cue triggers instead of executing them.
+pub fn record_gameplay_cue_on_entity(
+    ev: On<TriggerGameplayCueOnEntityEvent>,
+    mut log: ResMut<HeadlessCueLog>,
+) {
+    let event = ev.event();
+    log.record(PendingCueExecution {
+        cue_tag: event.cue_tag.clone(),
+        event_type: event.event_type,
+        parameters: GameplayCueParameters {
+            target: Some(event.target),
+            ..event.parameters.clone()
+        },
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cues::manager::GameplayCueEvent;
+    use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+
+    #[test]
+    fn test_log_keeps_most_recent_triggers() {
+        let mut log = HeadlessCueLog::default().with_capacity(2);
+        for level in 1..=3 {
+            log.record(PendingCueExecution {
+                cue_tag: GameplayTag::new("GameplayCue.Hit"),
+                event_type: GameplayCueEvent::Executed,
+                parameters: GameplayCueParameters::new().with_effect_level(level as f32),
+            });
+        }
+
+        let levels: Vec<_> = log
+            .drain()
+            .iter()
+            .map(|cue| cue.parameters.gameplay_effect_level)
+            .collect();
+        assert_eq!(levels, vec![2.0, 3.0]);
+        assert!(log.recorded.is_empty());
+    }
+}
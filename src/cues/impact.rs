@@ -0,0 +1,376 @@
+//! Impact feedback cue handlers: camera shake and hit-stop.
+//!
+//! Camera shake is trauma based: cues add trauma to [`CameraShake`] cameras,
+//! the offset grows with trauma squared and trauma decays over time. Hit-stop
+//! briefly slows `Time<Virtual>` and restores it using real time, so the
+//! countdown is not slowed by the hit-stop itself.
+
+use super::manager::GameplayCueParameters;
+use super::notify::GameplayCueNotifyStatic;
+use bevy::prelude::*;
+
+/// Makes a camera respond to [`CameraShakeCue`]s.
+#[derive(Component, Debug, Clone)]
+pub struct CameraShake {
+    /// Current trauma in `[0, 1]`.
+    pub trauma: f32,
+    /// Trauma removed per second.
+    pub decay: f32,
+    /// Translation offset at full trauma.
+    pub max_offset: Vec3,
+    /// Roll in radians at full trauma.
+    pub max_roll: f32,
+    /// Noise frequency of the shake.
+    pub frequency: f32,
+    /// Entity whose cues shake this camera under [`ShakeScope::TargetCamera`],
+    /// typically the player the camera follows.
+    pub owner: Option<Entity>,
+    applied_offset: Vec3,
+    applied_roll: f32,
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self {
+            trauma: 0.0,
+            decay: 1.5,
+            max_offset: Vec3::new(12.0, 12.0, 0.0),
+            max_roll: 0.05,
+            frequency: 25.0,
+            owner: None,
+            applied_offset: Vec3::ZERO,
+            applied_roll: 0.0,
+        }
+    }
+}
+
+impl CameraShake {
+    /// Creates a shake component with default tuning.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ties the camera to `owner` for target-scoped shakes.
+    pub fn with_owner(mut self, owner: Entity) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
+    /// Sets the translation offset at full trauma.
+    pub fn with_max_offset(mut self, max_offset: Vec3) -> Self {
+        self.max_offset = max_offset;
+        self
+    }
+
+    /// Sets the trauma decay per second.
+    pub fn with_decay(mut self, decay: f32) -> Self {
+        self.decay = decay;
+        self
+    }
+
+    /// Adds trauma, clamped to `[0, 1]`.
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+    }
+}
+
+/// Which cameras a [`CameraShakeCue`] shakes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ShakeScope {
+    /// Every camera with a [`CameraShake`] component.
+    #[default]
+    AllCameras,
+    /// Only cameras whose `CameraShake::owner` is the cue target.
+    TargetCamera,
+}
+
+/// Static cue handler that adds trauma to shaking cameras.
+#[derive(Debug, Clone, Copy)]
+pub struct CameraShakeCue {
+    /// Trauma added per execution.
+    pub trauma: f32,
+    /// Multiply the trauma by `GameplayCueParameters::normalized_magnitude`.
+    pub scale_by_magnitude: bool,
+    /// Which cameras are shaken.
+    pub scope: ShakeScope,
+}
+
+impl CameraShakeCue {
+    /// Creates a handler adding `trauma` to every shaking camera.
+    pub fn new(trauma: f32) -> Self {
+        Self {
+            trauma,
+            scale_by_magnitude: false,
+            scope: ShakeScope::AllCameras,
+        }
+    }
+
+    /// Scales the trauma by the cue's normalized magnitude.
+    pub fn scaled_by_magnitude(mut self) -> Self {
+        self.scale_by_magnitude = true;
+        self
+    }
+
+    /// Only shakes the camera owned by the cue target.
+    pub fn target_camera(mut self) -> Self {
+        self.scope = ShakeScope::TargetCamera;
+        self
+    }
+}
+
+impl GameplayCueNotifyStatic for CameraShakeCue {
+    fn on_execute(&self, target: Entity, params: &GameplayCueParameters, commands: &mut Commands) {
+        let trauma = if self.scale_by_magnitude {
+            self.trauma * params.normalized_magnitude.clamp(0.0, 1.0)
+        } else {
+            self.trauma
+        };
+        let scope = self.scope;
+
+        commands.queue(move |world: &mut World| {
+            let mut cameras = world.query::<&mut CameraShake>();
+            for mut shake in cameras.iter_mut(world) {
+                if scope == ShakeScope::TargetCamera && shake.owner != Some(target) {
+                    continue;
+                }
+                shake.add_trauma(trauma);
+            }
+        });
+    }
+}
+
+/// Applies and decays camera shake.
+///
+/// The previous frame's offset is removed before the new one is added, so
+/// camera controllers that move the transform keep working.
+pub fn apply_camera_shake_system(
+    mut cameras: Query<(&mut CameraShake, &mut Transform)>,
+    time: Res<Time>,
+) {
+    let t = time.elapsed_secs();
+    for (mut shake, mut transform) in cameras.iter_mut() {
+        transform.translation -= shake.applied_offset;
+        transform.rotate_z(-shake.applied_roll);
+
+        shake.trauma = (shake.trauma - shake.decay * time.delta_secs()).max(0.0);
+        let intensity = shake.trauma * shake.trauma;
+        if intensity == 0.0 {
+            shake.applied_offset = Vec3::ZERO;
+            shake.applied_roll = 0.0;
+            continue;
+        }
+
+        // Cheap smooth noise: incommensurate sine frequencies per axis.
+        let phase = t * shake.frequency;
+        let noise = Vec3::new(
+            (phase * 1.0).sin() * (phase * 0.37).cos(),
+            (phase * 1.31).sin() * (phase * 0.53).cos(),
+            (phase * 0.87).sin() * (phase * 0.29).cos(),
+        );
+        let offset = shake.max_offset * noise * intensity;
+        let roll = shake.max_roll * (phase * 1.13).sin() * intensity;
+
+        transform.translation += offset;
+        transform.rotate_z(roll);
+        shake.applied_offset = offset;
+        shake.applied_roll = roll;
+    }
+}
+
+/// Active hit-stop state.
+#[derive(Resource, Debug, Clone)]
+pub struct HitStop {
+    /// Real seconds left.
+    pub remaining: f32,
+    /// Virtual time speed while stopped.
+    pub time_scale: f32,
+    /// Virtual time speed to restore afterwards.
+    pub restore_speed: f32,
+}
+
+/// Static cue handler that briefly slows virtual time.
+///
+/// Overlapping hit-stops keep the longest remaining duration and the lowest
+/// time scale, and restore the speed from before the first one started.
+#[derive(Debug, Clone, Copy)]
+pub struct HitStopCue {
+    /// Real seconds the hit-stop lasts.
+    pub duration: f32,
+    /// Virtual time speed during the hit-stop (0.0 freezes).
+    pub time_scale: f32,
+}
+
+impl HitStopCue {
+    /// Creates a hit-stop of `duration` real seconds at `time_scale`.
+    pub fn new(duration: f32, time_scale: f32) -> Self {
+        Self {
+            duration,
+            time_scale,
+        }
+    }
+}
+
+impl GameplayCueNotifyStatic for HitStopCue {
+    fn on_execute(
+        &self,
+        _target: Entity,
+        _params: &GameplayCueParameters,
+        commands: &mut Commands,
+    ) {
+        let HitStopCue {
+            duration,
+            time_scale,
+        } = *self;
+
+        commands.queue(move |world: &mut World| {
+            let Some(current_speed) = world
+                .get_resource::<Time<Virtual>>()
+                .map(Time::<Virtual>::relative_speed)
+            else {
+                warn!("HitStopCue triggered without Time<Virtual>");
+                return;
+            };
+
+            let hit_stop = match world.get_resource::<HitStop>() {
+                Some(existing) => HitStop {
+                    remaining: existing.remaining.max(duration),
+                    time_scale: existing.time_scale.min(time_scale),
+                    restore_speed: existing.restore_speed,
+                },
+                None => HitStop {
+                    remaining: duration,
+                    time_scale,
+                    restore_speed: current_speed,
+                },
+            };
+            world
+                .resource_mut::<Time<Virtual>>()
+                .set_relative_speed(hit_stop.time_scale);
+            world.insert_resource(hit_stop);
+        });
+    }
+}
+
+/// Counts down the active hit-stop in real time and restores the time speed.
+pub fn update_hit_stop_system(
+    mut commands: Commands,
+    hit_stop: Option<ResMut<HitStop>>,
+    real_time: Res<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    let Some(mut hit_stop) = hit_stop else {
+        return;
+    };
+
+    hit_stop.remaining -= real_time.delta_secs();
+    if hit_stop.remaining <= 0.0 {
+        virtual_time.set_relative_speed(hit_stop.restore_speed);
+        commands.remove_resource::<HitStop>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    fn execute(app: &mut App, cue: &impl GameplayCueNotifyStatic, target: Entity, magnitude: f32) {
+        let params = GameplayCueParameters::new().with_magnitude(0.0, magnitude);
+        let mut commands = app.world_mut().commands();
+        cue.on_execute(target, &params, &mut commands);
+        app.world_mut().flush();
+    }
+
+    #[test]
+    fn test_camera_shake_scopes() {
+        let mut app = App::new();
+        let player = app.world_mut().spawn_empty().id();
+        let enemy = app.world_mut().spawn_empty().id();
+        let player_camera = app
+            .world_mut()
+            .spawn(CameraShake::new().with_owner(player))
+            .id();
+        let spectator_camera = app.world_mut().spawn(CameraShake::new()).id();
+
+        execute(
+            &mut app,
+            &CameraShakeCue::new(0.4).target_camera(),
+            player,
+            0.0,
+        );
+        execute(
+            &mut app,
+            &CameraShakeCue::new(0.4).target_camera(),
+            enemy,
+            0.0,
+        );
+        execute(
+            &mut app,
+            &CameraShakeCue::new(1.0).scaled_by_magnitude(),
+            enemy,
+            0.5,
+        );
+
+        let world = app.world();
+        assert_eq!(world.get::<CameraShake>(player_camera).unwrap().trauma, 0.9);
+        assert_eq!(
+            world.get::<CameraShake>(spectator_camera).unwrap().trauma,
+            0.5
+        );
+    }
+
+    #[test]
+    fn test_camera_shake_offset_is_removed_after_decay() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                50,
+            )));
+        app.add_systems(Update, apply_camera_shake_system);
+        let camera = app
+            .world_mut()
+            .spawn((CameraShake::new().with_decay(100.0), Transform::default()))
+            .id();
+        app.update();
+
+        app.world_mut()
+            .get_mut::<CameraShake>(camera)
+            .unwrap()
+            .add_trauma(1.0);
+        app.update();
+        app.update();
+
+        let transform = app.world().get::<Transform>(camera).unwrap();
+        assert!(transform.translation.length() < 1e-4);
+        assert_eq!(app.world().get::<CameraShake>(camera).unwrap().trauma, 0.0);
+    }
+
+    #[test]
+    fn test_hit_stop_slows_and_restores_virtual_time() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                30,
+            )));
+        app.add_systems(Update, update_hit_stop_system);
+        app.update();
+        let target = app.world_mut().spawn_empty().id();
+
+        execute(&mut app, &HitStopCue::new(0.02, 0.1), target, 0.0);
+        execute(&mut app, &HitStopCue::new(0.01, 0.0), target, 0.0);
+        assert_eq!(
+            app.world().resource::<Time<Virtual>>().relative_speed(),
+            0.0
+        );
+        assert_eq!(app.world().resource::<HitStop>().remaining, 0.02);
+
+        app.update();
+
+        assert!(app.world().get_resource::<HitStop>().is_none());
+        assert_eq!(
+            app.world().resource::<Time<Virtual>>().relative_speed(),
+            1.0
+        );
+    }
+}
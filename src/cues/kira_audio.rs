@@ -0,0 +1,112 @@
+//! Audio cue backend built on `bevy_kira_audio`.
+//!
+//! Only compiled with the `kira` feature. Requires `bevy_kira_audio::AudioPlugin`,
+//! and `SpatialAudioPlugin` when spatial cues are used.
+
+use super::audio::cue_volume;
+use super::manager::GameplayCueParameters;
+use super::notify::GameplayCueNotifyStatic;
+use bevy::prelude::*;
+use bevy_kira_audio::prelude::{Audio, AudioControl, AudioSource, Decibels, SpatialAudioEmitter};
+
+/// Static cue handler that plays a sound on the kira main track.
+///
+/// Spatial cues spawn a short-lived [`SpatialAudioEmitter`] at the cue location,
+/// which is despawned once its sound has stopped.
+#[derive(Clone, Debug)]
+pub struct KiraAudioCue {
+    /// The sound to play.
+    pub sound: Handle<AudioSource>,
+    /// Linear volume before magnitude scaling.
+    pub volume: f32,
+    /// Multiply the volume by `GameplayCueParameters::normalized_magnitude`.
+    pub scale_by_magnitude: bool,
+    /// Play the sound through a spatial emitter at the cue location.
+    pub spatial: bool,
+}
+
+impl KiraAudioCue {
+    /// Creates a handler that plays `sound` at full volume, non-spatially.
+    pub fn new(sound: Handle<AudioSource>) -> Self {
+        Self {
+            sound,
+            volume: 1.0,
+            scale_by_magnitude: false,
+            spatial: false,
+        }
+    }
+
+    /// Sets the linear base volume.
+    pub fn with_volume(mut self, volume: f32) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    /// Scales the volume by the cue's normalized magnitude.
+    pub fn scaled_by_magnitude(mut self) -> Self {
+        self.scale_by_magnitude = true;
+        self
+    }
+
+    /// Plays the sound through a spatial emitter at the cue location.
+    pub fn spatial(mut self) -> Self {
+        self.spatial = true;
+        self
+    }
+}
+
+impl GameplayCueNotifyStatic for KiraAudioCue {
+    fn on_execute(&self, _target: Entity, params: &GameplayCueParameters, commands: &mut Commands) {
+        let sound = self.sound.clone();
+        let volume = linear_to_decibels(cue_volume(self.volume, self.scale_by_magnitude, params));
+        let spatial = self.spatial;
+        let location = params.location;
+
+        // The kira channel is a resource, which static handlers cannot reach
+        // through `Commands` alone.
+        commands.queue(move |world: &mut World| {
+            let Some(audio) = world.get_resource::<Audio>() else {
+                warn!("KiraAudioCue triggered without bevy_kira_audio::AudioPlugin");
+                return;
+            };
+            let instance = audio.play(sound).with_volume(volume).handle();
+
+            if spatial {
+                world.spawn((
+                    SpatialAudioEmitter {
+                        instances: vec![instance],
+                    },
+                    Transform::from_translation(location),
+                    KiraCueEmitter,
+                ));
+            }
+        });
+    }
+}
+
+/// Marks a spatial emitter spawned by a [`KiraAudioCue`].
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct KiraCueEmitter;
+
+/// Despawns cue emitters once all of their sounds have stopped.
+///
+/// `bevy_kira_audio` drops stopped instances from emitters but leaves the
+/// emitter entity itself behind.
+pub fn despawn_finished_kira_cue_emitters_system(
+    mut commands: Commands,
+    emitters: Query<(Entity, &SpatialAudioEmitter), With<KiraCueEmitter>>,
+) {
+    for (entity, emitter) in emitters.iter() {
+        if emitter.instances.is_empty() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn linear_to_decibels(volume: f32) -> Decibels {
+    if volume <= 0.0 {
+        Decibels::SILENCE
+    } else {
+        Decibels((20.0 * volume.log10()).max(Decibels::SILENCE.0))
+    }
+}
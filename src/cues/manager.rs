@@ -2,14 +2,19 @@
 //!
 //! This module manages the registration and execution of gameplay cues.
 
+use super::notify::{GameplayCueActorConfig, GameplayCueNotifyStatic};
+use super::translation::GameplayCueTranslator;
+use crate::core::PredictionKey;
 use bevy::prelude::*;
 use bevy_gameplay_tag::gameplay_tag::GameplayTag;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// GameplayCue event type.
 ///
 /// Determines how the cue should be executed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameplayCueEvent {
     /// Cue is executed once.
     OnActive,
@@ -25,6 +30,8 @@ pub enum GameplayCueEvent {
 ///
 /// Matches UE GAS's `FGameplayCueParameters` with comprehensive context information.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct GameplayCueParameters {
     /// Normalized magnitude (0.0 to 1.0).
     pub normalized_magnitude: f32,
@@ -51,9 +58,34 @@ pub struct GameplayCueParameters {
     /// The level of the ability that triggered this cue.
     pub ability_level: f32,
     /// Source tags from the effect/ability that triggered this cue.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::option_tag_container_serde")
+    )]
     pub source_tags: Option<bevy_gameplay_tag::GameplayTagContainer>,
     /// Target tags at the time the cue was triggered.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::option_tag_container_serde")
+    )]
     pub target_tags: Option<bevy_gameplay_tag::GameplayTagContainer>,
+    /// The active effect entity that triggered this cue, if any.
+    pub source_effect: Option<Entity>,
+    /// Key of the predicted activation that triggered this cue, if any.
+    ///
+    /// Lets cue handlers skip a server cue the client already played.
+    pub prediction_key: Option<PredictionKey>,
+    /// Whether the damage that triggered this cue was a critical hit.
+    pub critical: bool,
+    /// The executed cue tag, set when the cue is routed to its handler.
+    ///
+    /// Handlers registered under a parent tag read it to tell the child
+    /// tags they handle apart.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::option_gameplay_tag_serde")
+    )]
+    pub cue_tag: Option<GameplayTag>,
 }
 
 impl Default for GameplayCueParameters {
@@ -72,6 +104,10 @@ impl Default for GameplayCueParameters {
             ability_level: 1.0,
             source_tags: None,
             target_tags: None,
+            source_effect: None,
+            prediction_key: None,
+            critical: false,
+            cue_tag: None,
         }
     }
 }
@@ -154,6 +190,24 @@ impl GameplayCueParameters {
         self.target_tags = Some(tags);
         self
     }
+
+    /// Sets the active effect that triggered this cue.
+    pub fn with_source_effect(mut self, effect: Entity) -> Self {
+        self.source_effect = Some(effect);
+        self
+    }
+
+    /// Sets the prediction key.
+    pub fn with_prediction_key(mut self, key: PredictionKey) -> Self {
+        self.prediction_key = Some(key);
+        self
+    }
+
+    /// Marks the cue as coming from a critical hit.
+    pub fn with_critical(mut self, critical: bool) -> Self {
+        self.critical = critical;
+        self
+    }
 }
 
 /// Information about a registered cue notify.
@@ -163,6 +217,8 @@ pub struct CueNotifyInfo {
     pub tag: GameplayTag,
     /// Whether this is a static cue (function-based) or actor-based.
     pub is_static: bool,
+    /// Spawn configuration (Some for actor-based cues only).
+    pub actor_config: Option<GameplayCueActorConfig>,
 }
 
 /// Pending cue execution.
@@ -176,6 +232,184 @@ pub struct PendingCueExecution {
     pub parameters: GameplayCueParameters,
 }
 
+/// A cue execution resolved to a registered actor cue, waiting to be spawned or removed.
+#[derive(Debug, Clone)]
+pub struct RoutedActorCue {
+    /// The executed cue tag.
+    pub cue_tag: GameplayTag,
+    /// The registered cue tag that handles this execution.
+    pub handler_tag: GameplayTag,
+    /// Spawn configuration of the handler.
+    pub config: GameplayCueActorConfig,
+    /// The event type.
+    pub event_type: GameplayCueEvent,
+    /// The parameters.
+    pub parameters: GameplayCueParameters,
+}
+
+/// A cue execution resolved to a registered static cue, waiting for its handler.
+#[derive(Debug, Clone)]
+pub struct RoutedStaticCue {
+    /// The executed cue tag.
+    pub cue_tag: GameplayTag,
+    /// The registered cue tag that handles this execution.
+    pub handler_tag: GameplayTag,
+    /// The event type.
+    pub event_type: GameplayCueEvent,
+    /// The parameters.
+    pub parameters: GameplayCueParameters,
+}
+
+/// A static cue that has been activated and receives `while_active` calls.
+#[derive(Debug, Clone)]
+pub struct ActiveStaticCue {
+    /// The executed cue tag, which suppression is checked against.
+    pub cue_tag: GameplayTag,
+    /// The registered cue tag that handles this cue.
+    pub handler_tag: GameplayTag,
+    /// Parameters passed to `while_active`, refreshed every update.
+    pub parameters: GameplayCueParameters,
+    /// Seconds accumulated since the last `while_active` call.
+    pub time_since_tick: f32,
+}
+
+impl ActiveStaticCue {
+    /// Returns true if this entry tracks the same cue instance as `parameters`.
+    fn is_same_instance(
+        &self,
+        handler_tag: &GameplayTag,
+        parameters: &GameplayCueParameters,
+    ) -> bool {
+        self.handler_tag == *handler_tag
+            && self.parameters.target == parameters.target
+            && (parameters.source_effect.is_none()
+                || self.parameters.source_effect == parameters.source_effect)
+    }
+}
+
+/// What happens to `Executed` cues that exceed a [`CueRateLimit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CueOverflowPolicy {
+    /// Discard the excess executions.
+    #[default]
+    Drop,
+    /// Merge the excess executions into one deferred execution.
+    ///
+    /// Raw magnitudes are summed and the largest normalized magnitude is kept;
+    /// the remaining parameters come from the first merged execution. The merged
+    /// cue fires as soon as the limit allows it again.
+    Coalesce,
+}
+
+/// Rate limit applied to `Executed` events of one cue tag.
+///
+/// Child tags without a limit of their own share the budget of their
+/// closest limited parent, like they fall back to its handler.
+///
+/// `OnActive`/`Removed` are never limited, since dropping either would leave
+/// an actor or `while_active` registration without its counterpart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CueRateLimit {
+    /// Minimum seconds between two executions.
+    pub min_interval: f32,
+    /// Maximum executions per frame (`None` = unlimited).
+    pub max_per_frame: Option<u32>,
+    /// How executions over the limit are handled.
+    pub overflow: CueOverflowPolicy,
+}
+
+impl Default for CueRateLimit {
+    fn default() -> Self {
+        Self {
+            min_interval: 0.0,
+            max_per_frame: None,
+            overflow: CueOverflowPolicy::Drop,
+        }
+    }
+}
+
+impl CueRateLimit {
+    /// Creates a limit that admits every execution.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the minimum seconds between executions.
+    pub fn with_min_interval(mut self, seconds: f32) -> Self {
+        self.min_interval = seconds;
+        self
+    }
+
+    /// Sets the maximum executions per frame.
+    pub fn with_max_per_frame(mut self, max: u32) -> Self {
+        self.max_per_frame = Some(max);
+        self
+    }
+
+    /// Sets the overflow policy.
+    pub fn with_overflow(mut self, overflow: CueOverflowPolicy) -> Self {
+        self.overflow = overflow;
+        self
+    }
+}
+
+/// Distance culling rule for a cue tag.
+///
+/// Beyond `max_distance` from the nearest listener, `Executed` and `OnActive`
+/// events are skipped, or redirected to `far_tag` when set so a cheaper
+/// handler can stand in. `WhileActive`/`Removed` are never culled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueLod {
+    /// Maximum distance at which the cue runs normally.
+    pub max_distance: f32,
+    /// Cue tag executed instead when the target is farther away.
+    pub far_tag: Option<GameplayTag>,
+}
+
+impl CueLod {
+    /// Skips the cue beyond `max_distance`.
+    pub fn new(max_distance: f32) -> Self {
+        Self {
+            max_distance,
+            far_tag: None,
+        }
+    }
+
+    /// Executes `far_tag` instead of skipping beyond the distance.
+    pub fn with_far_tag(mut self, far_tag: GameplayTag) -> Self {
+        self.far_tag = Some(far_tag);
+        self
+    }
+}
+
+/// Runtime bookkeeping for a rate-limited cue tag.
+#[derive(Debug, Clone, Default)]
+struct CueRateState {
+    last_execution: Option<f32>,
+    executions_this_frame: u32,
+    /// Coalesced execution, under the tag of the first merged one.
+    deferred: Option<(GameplayTag, GameplayCueParameters)>,
+}
+
+/// The tag an active cue was translated to when it became active.
+#[derive(Debug, Clone)]
+struct ActiveCueTranslation {
+    cue_tag: GameplayTag,
+    target: Option<Entity>,
+    source_effect: Option<Entity>,
+    translated: GameplayTag,
+}
+
+impl ActiveCueTranslation {
+    /// Returns true if this entry tracks the cue instance of `parameters`.
+    fn is_same_instance(&self, cue_tag: &GameplayTag, parameters: &GameplayCueParameters) -> bool {
+        self.cue_tag == *cue_tag
+            && self.target == parameters.target
+            && (parameters.source_effect.is_none()
+                || self.source_effect == parameters.source_effect)
+    }
+}
+
 /// GameplayCue manager resource.
 ///
 /// This manages all registered cues and handles cue execution.
@@ -189,6 +423,35 @@ pub struct GameplayCueManager {
     pub pending_cues: Vec<PendingCueExecution>,
     /// Whether batching is currently active.
     pub batching_active: bool,
+    /// Actor cue executions waiting for `manage_cue_actors_system`.
+    pub routed_actor_cues: Vec<RoutedActorCue>,
+    /// Handlers for static cues (tag -> handler).
+    pub static_handlers: HashMap<GameplayTag, Arc<dyn GameplayCueNotifyStatic>>,
+    /// Static cue executions waiting for `execute_static_cues_system`.
+    pub routed_static_cues: Vec<RoutedStaticCue>,
+    /// Static cues currently receiving `while_active` updates.
+    pub active_static_cues: Vec<ActiveStaticCue>,
+    /// Seconds between `while_active` calls (0.0 = every frame).
+    pub while_active_interval: f32,
+    /// Mutes every cue except `Removed` events, e.g. during cutscenes or on a
+    /// dedicated server. Per-entity muting uses the `CueSuppression` component.
+    pub muted: bool,
+    /// Disables parent-tag fallback in `find_handler_tag`.
+    pub exact_match_only: bool,
+    /// Distance culling rules (cue tag -> rule), inherited by child tags.
+    pub lods: HashMap<GameplayTag, CueLod>,
+    /// Rate limits for `Executed` events (cue tag -> limit), inherited by
+    /// child tags.
+    pub rate_limits: HashMap<GameplayTag, CueRateLimit>,
+    /// Rate limit for tags missing from `rate_limits`. Kept in sync with
+    /// `GasSettings::default_cue_rate_limit` by `handle_gameplay_cue_system`.
+    pub default_rate_limit: Option<CueRateLimit>,
+    /// Translators remapping triggered cue tags, in the order they run.
+    pub translators: Vec<Arc<dyn GameplayCueTranslator>>,
+    active_translations: Vec<ActiveCueTranslation>,
+    rate_states: HashMap<GameplayTag, CueRateState>,
+    /// Elapsed seconds as of the last `advance_rate_limits` call.
+    elapsed: f32,
 }
 
 impl GameplayCueManager {
@@ -204,21 +467,176 @@ impl GameplayCueManager {
             CueNotifyInfo {
                 tag,
                 is_static: true,
+                actor_config: None,
             },
         );
     }
 
-    /// Registers an actor-based cue notify.
+    /// Registers a static cue notify together with the handler that executes it.
+    pub fn register_static_cue_handler(
+        &mut self,
+        tag: GameplayTag,
+        handler: Arc<dyn GameplayCueNotifyStatic>,
+    ) {
+        self.register_static_cue(tag.clone());
+        self.static_handlers.insert(tag, handler);
+    }
+
+    /// Starts tracking a static cue for `while_active` updates.
+    ///
+    /// Re-activating a cue that is already tracked refreshes its parameters
+    /// instead of adding a second entry.
+    pub fn start_while_active(
+        &mut self,
+        cue_tag: GameplayTag,
+        handler_tag: GameplayTag,
+        parameters: GameplayCueParameters,
+    ) {
+        if let Some(existing) = self
+            .active_static_cues
+            .iter_mut()
+            .find(|active| active.is_same_instance(&handler_tag, &parameters))
+        {
+            existing.parameters = parameters;
+            return;
+        }
+        self.active_static_cues.push(ActiveStaticCue {
+            cue_tag,
+            handler_tag,
+            parameters,
+            time_since_tick: 0.0,
+        });
+    }
+
+    /// Stops tracking a static cue. Returns true if an entry was removed.
+    pub fn stop_while_active(
+        &mut self,
+        handler_tag: &GameplayTag,
+        parameters: &GameplayCueParameters,
+    ) -> bool {
+        let before = self.active_static_cues.len();
+        self.active_static_cues
+            .retain(|active| !active.is_same_instance(handler_tag, parameters));
+        self.active_static_cues.len() != before
+    }
+
+    /// Registers an actor-based cue notify with the default spawn configuration.
     pub fn register_actor_cue(&mut self, tag: GameplayTag) {
+        self.register_actor_cue_with_config(tag, GameplayCueActorConfig::default());
+    }
+
+    /// Registers an actor-based cue notify with a custom spawn configuration.
+    pub fn register_actor_cue_with_config(
+        &mut self,
+        tag: GameplayTag,
+        config: GameplayCueActorConfig,
+    ) {
         self.loaded_cues.insert(
             tag.clone(),
             CueNotifyInfo {
                 tag,
                 is_static: false,
+                actor_config: Some(config),
             },
         );
     }
 
+    /// Adds a translator remapping triggered cue tags.
+    ///
+    /// Translators run in the order they were added, before rate limits and
+    /// handler lookup. An active cue keeps the tag it was translated to when
+    /// it became active until its `Removed` event, whatever parameters that
+    /// event carries.
+    pub fn add_translator(&mut self, translator: Arc<dyn GameplayCueTranslator>) {
+        self.translators.push(translator);
+    }
+
+    /// Returns the tag `cue_tag` is executed as after translation.
+    pub fn translate_cue(
+        &self,
+        cue_tag: GameplayTag,
+        parameters: &GameplayCueParameters,
+    ) -> GameplayTag {
+        self.translators.iter().fold(cue_tag, |tag, translator| {
+            translator.translate(&tag, parameters).unwrap_or(tag)
+        })
+    }
+
+    /// Translates the `event_type` event of `cue_tag`.
+    ///
+    /// `Executed` events are translated on their own. The first `OnActive` or
+    /// `WhileActive` event of a cue instance remembers its translation, which
+    /// the instance's later events reuse until `Removed` forgets it, so the
+    /// looping cue or cue actor started under the translated tag is cleaned up.
+    fn translate_event(
+        &mut self,
+        cue_tag: GameplayTag,
+        event_type: GameplayCueEvent,
+        parameters: &GameplayCueParameters,
+    ) -> GameplayTag {
+        let active = self
+            .active_translations
+            .iter()
+            .position(|active| active.is_same_instance(&cue_tag, parameters));
+        match (event_type, active) {
+            (GameplayCueEvent::Executed, _) => self.translate_cue(cue_tag, parameters),
+            (GameplayCueEvent::OnActive | GameplayCueEvent::WhileActive, Some(index)) => {
+                self.active_translations[index].translated.clone()
+            }
+            (GameplayCueEvent::OnActive | GameplayCueEvent::WhileActive, None) => {
+                let translated = self.translate_cue(cue_tag.clone(), parameters);
+                if translated != cue_tag {
+                    self.active_translations.push(ActiveCueTranslation {
+                        cue_tag,
+                        target: parameters.target,
+                        source_effect: parameters.source_effect,
+                        translated: translated.clone(),
+                    });
+                }
+                translated
+            }
+            (GameplayCueEvent::Removed, Some(index)) => {
+                self.active_translations.swap_remove(index).translated
+            }
+            (GameplayCueEvent::Removed, None) => cue_tag,
+        }
+    }
+
+    /// Limits how often `Executed` events of `tag` and its child tags reach
+    /// the handlers.
+    pub fn set_rate_limit(&mut self, tag: GameplayTag, limit: CueRateLimit) {
+        self.rate_limits.insert(tag, limit);
+    }
+
+    /// Starts a new rate-limit frame at `elapsed` seconds.
+    ///
+    /// Resets the per-frame counters and routes coalesced executions whose
+    /// interval has passed. Called by `handle_gameplay_cue_system`.
+    pub fn advance_rate_limits(&mut self, elapsed: f32) {
+        self.elapsed = elapsed;
+
+        let mut ready = Vec::new();
+        for (tag, state) in self.rate_states.iter_mut() {
+            state.executions_this_frame = 0;
+            let Some(limit) = self
+                .rate_limits
+                .get(tag)
+                .or(self.default_rate_limit.as_ref())
+            else {
+                continue;
+            };
+            if state.deferred.is_some() && interval_elapsed(state, limit, elapsed) {
+                state.last_execution = Some(elapsed);
+                state.executions_this_frame = 1;
+                ready.extend(state.deferred.take());
+            }
+        }
+
+        for (tag, parameters) in ready {
+            self.route_cue(tag, GameplayCueEvent::Executed, parameters);
+        }
+    }
+
     /// Executes a gameplay cue.
     pub fn execute_cue(
         &mut self,
@@ -226,6 +644,11 @@ impl GameplayCueManager {
         event_type: GameplayCueEvent,
         parameters: GameplayCueParameters,
     ) {
+        if self.muted && event_type != GameplayCueEvent::Removed {
+            return;
+        }
+        let cue_tag = self.translate_event(cue_tag, event_type, &parameters);
+
         if self.batching_active {
             // Queue for later execution
             self.pending_cues.push(PendingCueExecution {
@@ -244,40 +667,144 @@ impl GameplayCueManager {
         &mut self,
         cue_tag: GameplayTag,
         event_type: GameplayCueEvent,
-        _parameters: GameplayCueParameters,
+        parameters: GameplayCueParameters,
     ) {
-        // Find matching cues (including parent tags)
-        let matching_cues: Vec<_> = self
-            .loaded_cues
-            .keys()
-            .filter(|tag| cue_tag.matches_tag_exact(tag))
-            .cloned()
-            .collect();
+        if event_type == GameplayCueEvent::Executed && !self.admit_execution(&cue_tag, &parameters)
+        {
+            return;
+        }
+        self.route_cue(cue_tag, event_type, parameters);
+    }
+
+    /// Applies the rate limit of `cue_tag`. Returns true if the execution may run now.
+    fn admit_execution(
+        &mut self,
+        cue_tag: &GameplayTag,
+        parameters: &GameplayCueParameters,
+    ) -> bool {
+        let Some((limit_tag, limit)) = self.find_rate_limit(cue_tag) else {
+            return true;
+        };
+        let state = self.rate_states.entry(limit_tag).or_default();
+
+        let within_frame_budget = limit
+            .max_per_frame
+            .is_none_or(|max| state.executions_this_frame < max);
+        if within_frame_budget && interval_elapsed(state, &limit, self.elapsed) {
+            state.last_execution = Some(self.elapsed);
+            state.executions_this_frame += 1;
+            return true;
+        }
 
-        for matched_tag in matching_cues {
-            if let Some(info) = self.loaded_cues.get(&matched_tag) {
-                if info.is_static {
-                    // Static cues are handled by systems
-                    // We'll emit an event for them
-                } else {
-                    // Actor-based cues need to be spawned/managed
-                    match event_type {
-                        GameplayCueEvent::OnActive => {
-                            // Spawn a cue actor
-                            // This will be handled by systems
-                        }
-                        GameplayCueEvent::Removed => {
-                            // Remove active cue actors
-                            if let Some(_actors) = self.active_cues.get(&matched_tag) {
-                                // Mark for removal
-                                // This will be handled by systems
-                            }
-                        }
-                        _ => {}
-                    }
+        if limit.overflow == CueOverflowPolicy::Coalesce {
+            match state.deferred.as_mut() {
+                Some((_, deferred)) => {
+                    deferred.raw_magnitude += parameters.raw_magnitude;
+                    deferred.normalized_magnitude = deferred
+                        .normalized_magnitude
+                        .max(parameters.normalized_magnitude);
                 }
+                None => state.deferred = Some((cue_tag.clone(), parameters.clone())),
             }
         }
+        false
+    }
+
+    /// Returns the rate limit of `cue_tag` with the tag whose budget it uses.
+    ///
+    /// The closest of the tag and its parents with a limit wins; the default
+    /// limit gives each tag its own budget.
+    fn find_rate_limit(&self, cue_tag: &GameplayTag) -> Option<(GameplayTag, CueRateLimit)> {
+        std::iter::once(cue_tag.clone())
+            .chain(parent_tags(cue_tag))
+            .find_map(|tag| self.rate_limits.get(&tag).map(|&limit| (tag, limit)))
+            .or_else(|| {
+                self.default_rate_limit
+                    .map(|limit| (cue_tag.clone(), limit))
+            })
+    }
+
+    /// Resolves a cue execution to its registered handlers.
+    ///
+    /// Resolved executions are queued in `routed_static_cues` and `routed_actor_cues`;
+    /// handlers need `Commands`, so the cue systems drain the queues.
+    fn route_cue(
+        &mut self,
+        cue_tag: GameplayTag,
+        event_type: GameplayCueEvent,
+        mut parameters: GameplayCueParameters,
+    ) {
+        let Some(matched_tag) = self.find_handler_tag(&cue_tag) else {
+            return;
+        };
+        parameters.cue_tag = Some(cue_tag.clone());
+        let Some(info) = self.loaded_cues.get(&matched_tag) else {
+            return;
+        };
+        let Some(config) = info.actor_config else {
+            self.routed_static_cues.push(RoutedStaticCue {
+                cue_tag,
+                handler_tag: matched_tag,
+                event_type,
+                parameters,
+            });
+            return;
+        };
+
+        match event_type {
+            GameplayCueEvent::OnActive | GameplayCueEvent::Executed | GameplayCueEvent::Removed => {
+                self.routed_actor_cues.push(RoutedActorCue {
+                    cue_tag,
+                    handler_tag: matched_tag,
+                    config,
+                    event_type,
+                    parameters,
+                });
+            }
+            // Actor cues update themselves; nothing to spawn.
+            GameplayCueEvent::WhileActive => {}
+        }
+    }
+
+    /// Returns the registered cue tag that handles `cue_tag`.
+    ///
+    /// An exact registration wins; otherwise the closest registered parent is
+    /// used, so `GameplayCue.Damage.Fire` falls back to `GameplayCue.Damage`.
+    /// Only one handler runs per execution, as in Unreal. Parents are derived
+    /// from the dotted tag name, so this needs no `GameplayTagsManager`.
+    pub fn find_handler_tag(&self, cue_tag: &GameplayTag) -> Option<GameplayTag> {
+        if self.loaded_cues.contains_key(cue_tag) {
+            return Some(cue_tag.clone());
+        }
+        if self.exact_match_only {
+            return None;
+        }
+
+        parent_tags(cue_tag).find(|parent| self.loaded_cues.contains_key(parent))
+    }
+
+    /// Sets the distance culling rule for `tag` and its children.
+    pub fn set_cue_lod(&mut self, tag: GameplayTag, lod: CueLod) {
+        self.lods.insert(tag, lod);
+    }
+
+    /// Returns the culling rule for `cue_tag`, falling back to its closest parent.
+    pub fn find_cue_lod(&self, cue_tag: &GameplayTag) -> Option<&CueLod> {
+        self.lods
+            .get(cue_tag)
+            .or_else(|| parent_tags(cue_tag).find_map(|parent| self.lods.get(&parent)))
+    }
+
+    /// Routes a cue execution to its handler, bypassing rate limits.
+    ///
+    /// Used by distance culling to redirect far cues to their cheaper variant.
+    pub fn reroute_cue(
+        &mut self,
+        cue_tag: GameplayTag,
+        event_type: GameplayCueEvent,
+        parameters: GameplayCueParameters,
+    ) {
+        self.route_cue(cue_tag, event_type, parameters);
     }
 
     /// Starts batching cue executions.
@@ -288,6 +815,14 @@ impl GameplayCueManager {
     /// Ends batching and executes all pending cues.
     pub fn end_batching(&mut self) {
         self.batching_active = false;
+        self.flush_pending();
+    }
+
+    /// Executes all pending cues without changing the batching state.
+    ///
+    /// Called by `handle_gameplay_cue_system` every frame, so a batching window
+    /// never delays cues past the current frame's `CueSystemSet::Handle` stage.
+    pub fn flush_pending(&mut self) {
         let pending = std::mem::take(&mut self.pending_cues);
         for cue in pending {
             self.execute_cue_internal(cue.cue_tag, cue.event_type, cue.parameters);
@@ -310,6 +845,19 @@ impl GameplayCueManager {
     }
 }
 
+/// Yields the parents of `tag`, closest first, based on its dotted name.
+pub(crate) fn parent_tags(tag: &GameplayTag) -> impl Iterator<Item = GameplayTag> + '_ {
+    let name = tag.get_tag_name();
+    name.rmatch_indices('.')
+        .map(move |(index, _)| GameplayTag::new(&name[..index]))
+}
+
+fn interval_elapsed(state: &CueRateState, limit: &CueRateLimit, now: f32) -> bool {
+    state
+        .last_execution
+        .is_none_or(|last| now - last >= limit.min_interval)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -324,6 +872,116 @@ mod tests {
         assert!(manager.loaded_cues.get(&tag).unwrap().is_static);
     }
 
+    #[test]
+    fn test_actor_cue_execution_is_routed() {
+        let mut manager = GameplayCueManager::new();
+        let tag = GameplayTag::new("GameplayCue.Test");
+        manager.register_actor_cue_with_config(
+            tag.clone(),
+            GameplayCueActorConfig::new().with_lifetime(1.0),
+        );
+
+        manager.execute_cue(
+            tag.clone(),
+            GameplayCueEvent::OnActive,
+            GameplayCueParameters::new(),
+        );
+
+        assert_eq!(manager.routed_actor_cues.len(), 1);
+        assert_eq!(manager.routed_actor_cues[0].handler_tag, tag);
+        assert_eq!(manager.routed_actor_cues[0].config.lifetime, Some(1.0));
+    }
+
+    #[test]
+    fn test_translators_remap_cue_tags_in_order() {
+        use super::super::translation::SurfaceCueTranslator;
+
+        let mut manager = GameplayCueManager::new();
+        let footstep = GameplayTag::new("GameplayCue.Footstep");
+        let snow = GameplayTag::new("GameplayCue.Footstep.Snow");
+        let deep_snow = GameplayTag::new("GameplayCue.Footstep.Snow.Deep");
+        manager.register_static_cue(footstep.clone());
+        manager.register_static_cue(snow.clone());
+        manager.register_static_cue(deep_snow.clone());
+        manager.add_translator(Arc::new(
+            SurfaceCueTranslator::new(footstep.clone()).with_surface("Snow", snow.clone()),
+        ));
+        let deep = deep_snow.clone();
+        manager.add_translator(Arc::new(
+            move |tag: &GameplayTag, params: &GameplayCueParameters| {
+                (*tag == snow && params.raw_magnitude > 1.0).then(|| deep.clone())
+            },
+        ));
+
+        for params in [
+            GameplayCueParameters::new(),
+            GameplayCueParameters::new().with_physical_material("Stone"),
+            GameplayCueParameters::new().with_physical_material("Snow"),
+            GameplayCueParameters::new()
+                .with_physical_material("Snow")
+                .with_magnitude(2.0, 1.0),
+        ] {
+            manager.execute_cue(footstep.clone(), GameplayCueEvent::Executed, params);
+        }
+
+        let handlers: Vec<_> = manager
+            .routed_static_cues
+            .iter()
+            .map(|cue| &cue.cue_tag)
+            .collect();
+        assert_eq!(
+            handlers,
+            [
+                &footstep,
+                &footstep,
+                &GameplayTag::new("GameplayCue.Footstep.Snow"),
+                &deep_snow
+            ]
+        );
+    }
+
+    #[test]
+    fn test_active_cues_are_removed_under_their_translated_tag() {
+        use super::super::translation::SurfaceCueTranslator;
+
+        let mut manager = GameplayCueManager::new();
+        let aura = GameplayTag::new("GameplayCue.Aura");
+        let snow = GameplayTag::new("GameplayCue.Aura.Snow");
+        manager.register_static_cue(aura.clone());
+        manager.register_static_cue(snow.clone());
+        manager.add_translator(Arc::new(
+            SurfaceCueTranslator::new(aura.clone()).with_surface("Snow", snow.clone()),
+        ));
+
+        let target = Entity::from_bits(1);
+        let mut params = GameplayCueParameters::new().with_physical_material("Snow");
+        params.target = Some(target);
+        params.source_effect = Some(Entity::from_bits(2));
+        manager.execute_cue(aura.clone(), GameplayCueEvent::OnActive, params.clone());
+
+        // The removal is rebuilt without the surface it started on
+        let mut removal = GameplayCueParameters::new();
+        removal.target = Some(target);
+        removal.source_effect = params.source_effect;
+        manager.execute_cue(aura.clone(), GameplayCueEvent::Removed, removal);
+        // A later removal on another surface has nothing left to reuse
+        manager.execute_cue(aura.clone(), GameplayCueEvent::Removed, params);
+
+        let routed: Vec<_> = manager
+            .routed_static_cues
+            .iter()
+            .map(|cue| (&cue.cue_tag, cue.event_type))
+            .collect();
+        assert_eq!(
+            routed,
+            [
+                (&snow, GameplayCueEvent::OnActive),
+                (&snow, GameplayCueEvent::Removed),
+                (&aura, GameplayCueEvent::Removed),
+            ]
+        );
+    }
+
     #[test]
     fn test_cue_parameters_builder() {
         let params = GameplayCueParameters::new()
@@ -335,6 +993,182 @@ mod tests {
         assert_eq!(params.location, Vec3::new(1.0, 2.0, 3.0));
     }
 
+    #[test]
+    fn test_cue_routes_to_closest_registered_parent() {
+        let mut manager = GameplayCueManager::new();
+        let damage = GameplayTag::new("GameplayCue.Damage");
+        let fire = GameplayTag::new("GameplayCue.Damage.Fire");
+        manager.register_static_cue(GameplayTag::new("GameplayCue"));
+        manager.register_static_cue(damage.clone());
+        manager.register_static_cue(fire.clone());
+
+        assert_eq!(manager.find_handler_tag(&fire), Some(fire.clone()));
+        assert_eq!(
+            manager.find_handler_tag(&GameplayTag::new("GameplayCue.Damage.Ice.Shard")),
+            Some(damage.clone())
+        );
+        assert_eq!(
+            manager.find_handler_tag(&GameplayTag::new("Other.Tag")),
+            None
+        );
+
+        manager.execute_cue(
+            GameplayTag::new("GameplayCue.Damage.Poison"),
+            GameplayCueEvent::Executed,
+            GameplayCueParameters::new(),
+        );
+        assert_eq!(manager.routed_static_cues.len(), 1);
+        assert_eq!(manager.routed_static_cues[0].handler_tag, damage);
+    }
+
+    #[test]
+    fn test_exact_match_only_disables_parent_fallback() {
+        let mut manager = GameplayCueManager::new();
+        manager.register_static_cue(GameplayTag::new("GameplayCue.Damage"));
+        manager.exact_match_only = true;
+
+        manager.execute_cue(
+            GameplayTag::new("GameplayCue.Damage.Fire"),
+            GameplayCueEvent::Executed,
+            GameplayCueParameters::new(),
+        );
+        assert!(manager.routed_static_cues.is_empty());
+    }
+
+    #[test]
+    fn test_cue_lod_is_inherited_by_child_tags() {
+        let mut manager = GameplayCueManager::new();
+        manager.set_cue_lod(GameplayTag::new("GameplayCue.Damage"), CueLod::new(50.0));
+
+        let lod = manager.find_cue_lod(&GameplayTag::new("GameplayCue.Damage.Fire"));
+        assert_eq!(lod.map(|lod| lod.max_distance), Some(50.0));
+        assert!(
+            manager
+                .find_cue_lod(&GameplayTag::new("GameplayCue.Heal"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_muted_manager_only_routes_removed() {
+        let mut manager = GameplayCueManager::new();
+        let tag = GameplayTag::new("GameplayCue.Test");
+        manager.register_static_cue(tag.clone());
+        manager.muted = true;
+
+        manager.execute_cue(
+            tag.clone(),
+            GameplayCueEvent::Executed,
+            GameplayCueParameters::new(),
+        );
+        assert!(manager.routed_static_cues.is_empty());
+
+        manager.execute_cue(tag, GameplayCueEvent::Removed, GameplayCueParameters::new());
+        assert_eq!(manager.routed_static_cues.len(), 1);
+    }
+
+    #[test]
+    fn test_rate_limit_drops_excess_executions() {
+        let mut manager = GameplayCueManager::new();
+        let tag = GameplayTag::new("GameplayCue.Test");
+        manager.register_static_cue(tag.clone());
+        manager.set_rate_limit(tag.clone(), CueRateLimit::new().with_max_per_frame(2));
+
+        manager.advance_rate_limits(0.0);
+        for _ in 0..5 {
+            manager.execute_cue(
+                tag.clone(),
+                GameplayCueEvent::Executed,
+                GameplayCueParameters::new(),
+            );
+        }
+        assert_eq!(manager.routed_static_cues.len(), 2);
+
+        // OnActive is never limited.
+        manager.execute_cue(
+            tag.clone(),
+            GameplayCueEvent::OnActive,
+            GameplayCueParameters::new(),
+        );
+        assert_eq!(manager.routed_static_cues.len(), 3);
+
+        manager.advance_rate_limits(0.016);
+        manager.execute_cue(
+            tag,
+            GameplayCueEvent::Executed,
+            GameplayCueParameters::new(),
+        );
+        assert_eq!(manager.routed_static_cues.len(), 4);
+    }
+
+    #[test]
+    fn test_child_tags_share_their_parents_rate_limit() {
+        let mut manager = GameplayCueManager::new();
+        let damage = GameplayTag::new("GameplayCue.Damage");
+        let fire = GameplayTag::new("GameplayCue.Damage.Fire");
+        let frost = GameplayTag::new("GameplayCue.Damage.Frost");
+        manager.register_static_cue(damage.clone());
+        manager.set_rate_limit(
+            damage.clone(),
+            CueRateLimit::new()
+                .with_max_per_frame(1)
+                .with_overflow(CueOverflowPolicy::Coalesce),
+        );
+
+        manager.advance_rate_limits(0.0);
+        for (tag, magnitude) in [(&fire, 10.0), (&frost, 20.0), (&damage, 30.0)] {
+            manager.execute_cue(
+                tag.clone(),
+                GameplayCueEvent::Executed,
+                GameplayCueParameters::new().with_magnitude(magnitude, 0.0),
+            );
+        }
+        assert_eq!(manager.routed_static_cues.len(), 1);
+        assert_eq!(manager.routed_static_cues[0].cue_tag, fire);
+
+        // The overflow fires as one execution, under its first tag
+        manager.advance_rate_limits(0.016);
+        assert_eq!(manager.routed_static_cues.len(), 2);
+        let coalesced = &manager.routed_static_cues[1];
+        assert_eq!(coalesced.cue_tag, frost);
+        assert_eq!(coalesced.handler_tag, damage);
+        assert_eq!(coalesced.parameters.raw_magnitude, 50.0);
+    }
+
+    #[test]
+    fn test_rate_limit_coalesces_into_deferred_execution() {
+        let mut manager = GameplayCueManager::new();
+        let tag = GameplayTag::new("GameplayCue.Test");
+        manager.register_static_cue(tag.clone());
+        manager.set_rate_limit(
+            tag.clone(),
+            CueRateLimit::new()
+                .with_min_interval(0.5)
+                .with_overflow(CueOverflowPolicy::Coalesce),
+        );
+
+        manager.advance_rate_limits(0.0);
+        for magnitude in [10.0, 20.0, 30.0] {
+            manager.execute_cue(
+                tag.clone(),
+                GameplayCueEvent::Executed,
+                GameplayCueParameters::new().with_magnitude(magnitude, magnitude / 100.0),
+            );
+        }
+        assert_eq!(manager.routed_static_cues.len(), 1);
+        assert_eq!(manager.routed_static_cues[0].parameters.raw_magnitude, 10.0);
+
+        // Still inside the interval: the coalesced execution waits.
+        manager.advance_rate_limits(0.2);
+        assert_eq!(manager.routed_static_cues.len(), 1);
+
+        manager.advance_rate_limits(0.6);
+        assert_eq!(manager.routed_static_cues.len(), 2);
+        let coalesced = &manager.routed_static_cues[1].parameters;
+        assert_eq!(coalesced.raw_magnitude, 50.0);
+        assert_eq!(coalesced.normalized_magnitude, 0.3);
+    }
+
     #[test]
     fn test_batching() {
         let mut manager = GameplayCueManager::new();
@@ -2,13 +2,49 @@
 //!
 //! This module provides the gameplay cue system, which handles visual and audio
 //! feedback for gameplay events.
+//!
+//! With the `headless` feature the presentation handlers are left out and cues
+//! are only recorded and forwarded; see [`headless`].
+
+#[cfg(all(feature = "headless", any(feature = "hanabi", feature = "kira")))]
+compile_error!("the `headless` feature cannot be combined with `hanabi` or `kira`");
 
+#[cfg(not(feature = "headless"))]
+pub mod audio;
+#[cfg(not(feature = "headless"))]
+pub mod damage_numbers;
+#[cfg(feature = "headless")]
+pub mod headless;
+#[cfg(not(feature = "headless"))]
+pub mod impact;
+#[cfg(feature = "kira")]
+pub mod kira_audio;
 pub mod manager;
+pub mod net;
 pub mod notify;
+#[cfg(not(feature = "headless"))]
+pub mod notify_presets;
+#[cfg(feature = "hanabi")]
+pub mod particles;
 pub mod plugin;
 pub mod systems;
+pub mod translation;
 
+#[cfg(not(feature = "headless"))]
+pub use audio::*;
+#[cfg(not(feature = "headless"))]
+pub use damage_numbers::*;
+#[cfg(feature = "headless")]
+pub use headless::*;
+#[cfg(not(feature = "headless"))]
+pub use impact::*;
 pub use manager::*;
+pub use net::*;
 pub use notify::*;
-pub use plugin::CuePlugin;
+#[cfg(not(feature = "headless"))]
+pub use notify_presets::*;
+#[cfg(feature = "hanabi")]
+pub use particles::*;
+pub use plugin::{CuePlugin, GameplayCueAppExt};
 pub use systems::*;
+pub use translation::*;
@@ -0,0 +1,261 @@
+//! Multicasting gameplay cues from the server to clients.
+//!
+//! Cues are cosmetic, so they are not replicated reliably. While the
+//! [`NetRole`] is `Server`, every cue triggered on the server is handed to the
+//! installed [`CueChannel`] as a [`GameplayCueMulticastEvent`], addressed to
+//! the clients with a [`CueViewer`] within [`CueMulticastSettings`]'s
+//! relevancy distance. Channels are expected to send unreliably; a cue that is
+//! lost or arrives for a client that joined later is never resent.
+//!
+//! Clients trigger received cues locally, so they run through the usual
+//! [`GameplayCueManager`](super::manager::GameplayCueManager) with its
+//! batching, LOD and rate limits.
+
+use super::manager::{GameplayCueEvent, GameplayCueParameters};
+use super::systems::{TriggerGameplayCueEvent, TriggerGameplayCueOnEntityEvent};
+use crate::abilities::{NetClientId, NetRole};
+use crate::core::PredictionKey;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+
+/// Sends cue multicasts over a network.
+pub trait GameplayCueChannel: Send + Sync + 'static {
+    /// Sends a cue to each of `clients`, unreliably.
+    fn multicast(
+        &self,
+        commands: &mut Commands,
+        clients: &[NetClientId],
+        cue: &GameplayCueMulticastEvent,
+    );
+}
+
+/// The channel used for cue multicasts.
+#[derive(Resource)]
+pub struct CueChannel(pub Box<dyn GameplayCueChannel>);
+
+impl CueChannel {
+    /// Wraps a channel.
+    pub fn new(channel: impl GameplayCueChannel) -> Self {
+        Self(Box::new(channel))
+    }
+}
+
+/// Settings for cue multicasts.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct CueMulticastSettings {
+    /// Clients whose viewer is farther than this from a cue do not receive it.
+    pub relevancy_distance: f32,
+}
+
+impl Default for CueMulticastSettings {
+    fn default() -> Self {
+        Self {
+            relevancy_distance: 50.0,
+        }
+    }
+}
+
+impl CueMulticastSettings {
+    /// Sets the relevancy distance.
+    pub fn with_relevancy_distance(mut self, distance: f32) -> Self {
+        self.relevancy_distance = distance;
+        self
+    }
+}
+
+/// Marks the entity whose position decides which cues a client receives.
+///
+/// Put it on the client's character or camera on the server.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CueViewer(pub NetClientId);
+
+/// The part of [`GameplayCueParameters`] sent with a multicast cue.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "replicon",
+    derive(serde::Serialize, serde::Deserialize, bevy::ecs::entity::MapEntities)
+)]
+pub struct NetCueParameters {
+    /// Normalized magnitude (0.0 to 1.0).
+    pub normalized_magnitude: f32,
+    /// Raw magnitude value.
+    pub raw_magnitude: f32,
+    /// Location where the cue should be spawned.
+    pub location: Vec3,
+    /// Normal vector (for surface effects).
+    pub normal: Vec3,
+    /// The entity that instigated this cue.
+    #[cfg_attr(feature = "replicon", entities)]
+    pub instigator: Option<Entity>,
+    /// The entity that caused the effect (e.g., projectile).
+    #[cfg_attr(feature = "replicon", entities)]
+    pub effect_causer: Option<Entity>,
+    /// The target entity.
+    #[cfg_attr(feature = "replicon", entities)]
+    pub target: Option<Entity>,
+    /// The level of the gameplay effect that triggered this cue.
+    pub gameplay_effect_level: f32,
+    /// Key of the predicted activation that triggered this cue, if any.
+    pub prediction_key: Option<PredictionKey>,
+    /// Whether the damage that triggered this cue was a critical hit.
+    pub critical: bool,
+}
+
+impl From<&GameplayCueParameters> for NetCueParameters {
+    fn from(parameters: &GameplayCueParameters) -> Self {
+        Self {
+            normalized_magnitude: parameters.normalized_magnitude,
+            raw_magnitude: parameters.raw_magnitude,
+            location: parameters.location,
+            normal: parameters.normal,
+            instigator: parameters.instigator,
+            effect_causer: parameters.effect_causer,
+            target: parameters.target,
+            gameplay_effect_level: parameters.gameplay_effect_level,
+            prediction_key: parameters.prediction_key,
+            critical: parameters.critical,
+        }
+    }
+}
+
+impl NetCueParameters {
+    /// Expands back into full cue parameters.
+    pub fn to_parameters(&self) -> GameplayCueParameters {
+        GameplayCueParameters {
+            normalized_magnitude: self.normalized_magnitude,
+            raw_magnitude: self.raw_magnitude,
+            location: self.location,
+            normal: self.normal,
+            instigator: self.instigator,
+            effect_causer: self.effect_causer,
+            target: self.target,
+            gameplay_effect_level: self.gameplay_effect_level,
+            prediction_key: self.prediction_key,
+            critical: self.critical,
+            ..GameplayCueParameters::default()
+        }
+    }
+}
+
+/// A cue sent from the server to clients.
+#[derive(Event, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "replicon",
+    derive(serde::Serialize, serde::Deserialize, bevy::ecs::entity::MapEntities)
+)]
+pub struct GameplayCueMulticastEvent {
+    /// The cue tag.
+    #[cfg_attr(
+        feature = "replicon",
+        serde(with = "crate::serialization::gameplay_tag_serde")
+    )]
+    pub cue_tag: GameplayTag,
+    /// The event type.
+    pub event_type: GameplayCueEvent,
+    /// The compact parameters.
+    #[cfg_attr(feature = "replicon", entities)]
+    pub parameters: NetCueParameters,
+}
+
+/// Observer that multicasts cues triggered on the server.
+pub fn multicast_gameplay_cue(ev: On<TriggerGameplayCueEvent>, mut multicast: CueMulticastParams) {
+    let event = ev.event();
+    multicast.send(&event.cue_tag, event.event_type, &event.parameters);
+}
+
+/// Observer that multicasts entity cues triggered on the server.
+pub fn multicast_gameplay_cue_on_entity(
+    ev: On<TriggerGameplayCueOnEntityEvent>,
+    mut multicast: CueMulticastParams,
+) {
+    let event = ev.event();
+    let mut parameters = event.parameters.clone();
+    parameters.target = Some(event.target);
+    multicast.send(&event.cue_tag, event.event_type, &parameters);
+}
+
+/// Observer that plays cues received from the server.
+pub fn on_gameplay_cue_multicast(
+    ev: On<GameplayCueMulticastEvent>,
+    mut commands: Commands,
+    net_role: Option<Res<NetRole>>,
+) {
+    if net_role.is_some_and(|role| *role == NetRole::Server) {
+        return;
+    }
+    let event = ev.event();
+    commands.trigger(TriggerGameplayCueEvent::new(
+        event.cue_tag.clone(),
+        event.event_type,
+        event.parameters.to_parameters(),
+    ));
+}
+
+/// Bundled parameters for multicasting cues.
+#[derive(SystemParam)]
+pub struct CueMulticastParams<'w, 's> {
+    pub commands: Commands<'w, 's>,
+    pub net_role: Option<Res<'w, NetRole>>,
+    pub channel: Option<Res<'w, CueChannel>>,
+    pub settings: Res<'w, CueMulticastSettings>,
+    pub viewers: Query<'w, 's, (&'static CueViewer, &'static GlobalTransform)>,
+    pub transforms: Query<'w, 's, &'static GlobalTransform>,
+}
+
+impl CueMulticastParams<'_, '_> {
+    /// Sends a cue to the clients it is relevant to.
+    pub fn send(
+        &mut self,
+        cue_tag: &GameplayTag,
+        event_type: GameplayCueEvent,
+        parameters: &GameplayCueParameters,
+    ) {
+        if self
+            .net_role
+            .as_ref()
+            .is_none_or(|role| **role != NetRole::Server)
+        {
+            return;
+        }
+        let Some(channel) = &self.channel else {
+            return;
+        };
+
+        let position = self.cue_position(parameters);
+        let max_distance_squared = self.settings.relevancy_distance.powi(2);
+        let clients: Vec<_> = self
+            .viewers
+            .iter()
+            .filter(|(_, transform)| {
+                position.is_none_or(|position| {
+                    transform.translation().distance_squared(position) <= max_distance_squared
+                })
+            })
+            .map(|(viewer, _)| viewer.0)
+            .collect();
+        if clients.is_empty() {
+            return;
+        }
+
+        let cue = GameplayCueMulticastEvent {
+            cue_tag: cue_tag.clone(),
+            event_type,
+            parameters: NetCueParameters::from(parameters),
+        };
+        channel.0.multicast(&mut self.commands, &clients, &cue);
+    }
+
+    /// Where a cue plays: its location if set, else its target's position.
+    ///
+    /// Cues with neither are relevant to every client.
+    fn cue_position(&self, parameters: &GameplayCueParameters) -> Option<Vec3> {
+        if parameters.location != Vec3::ZERO {
+            return Some(parameters.location);
+        }
+        parameters
+            .target
+            .and_then(|target| self.transforms.get(target).ok())
+            .map(GlobalTransform::translation)
+    }
+}
@@ -38,6 +38,73 @@ pub trait GameplayCueNotifyStatic: Send + Sync + 'static {
     }
 }
 
+/// Where a spawned cue actor is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CueActorAttachment {
+    /// Spawn at `GameplayCueParameters::location` in world space.
+    #[default]
+    AtLocation,
+    /// Spawn as a child of the cue target so the actor follows it.
+    ///
+    /// The actor is despawned together with the target through the hierarchy.
+    AttachToTarget,
+}
+
+/// Default for [`GasSettings::executed_cue_actor_lifetime`], the seconds an
+/// actor spawned by an `Executed` cue lives when its config sets no lifetime.
+/// Bursts never get a Removed event, so they need one.
+///
+/// [`GasSettings::executed_cue_actor_lifetime`]: crate::core::GasSettings::executed_cue_actor_lifetime
+pub const DEFAULT_EXECUTED_ACTOR_LIFETIME: f32 = 2.0;
+
+/// Spawn configuration for an actor-based cue.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GameplayCueActorConfig {
+    /// Where the actor is placed.
+    pub attachment: CueActorAttachment,
+    /// Seconds after which the actor is despawned automatically (None = no
+    /// limit, or `GasSettings::executed_cue_actor_lifetime` for `Executed`
+    /// cues).
+    pub lifetime: Option<f32>,
+    /// Whether to despawn the actor when the cue is removed or its source effect ends.
+    pub auto_destroy_on_remove: bool,
+}
+
+impl Default for GameplayCueActorConfig {
+    fn default() -> Self {
+        Self {
+            attachment: CueActorAttachment::AtLocation,
+            lifetime: None,
+            auto_destroy_on_remove: true,
+        }
+    }
+}
+
+impl GameplayCueActorConfig {
+    /// Creates a config that spawns at the cue location with no lifetime.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets where the actor is placed.
+    pub fn with_attachment(mut self, attachment: CueActorAttachment) -> Self {
+        self.attachment = attachment;
+        self
+    }
+
+    /// Sets the lifetime in seconds.
+    pub fn with_lifetime(mut self, lifetime: f32) -> Self {
+        self.lifetime = Some(lifetime);
+        self
+    }
+
+    /// Sets whether to auto-destroy on remove.
+    pub fn with_auto_destroy(mut self, auto_destroy: bool) -> Self {
+        self.auto_destroy_on_remove = auto_destroy;
+        self
+    }
+}
+
 /// Component for actor-based gameplay cue notifies.
 ///
 /// Actor cues spawn entities that persist for the duration of the cue.
@@ -52,6 +119,12 @@ pub struct GameplayCueNotifyActor {
     pub auto_destroy_on_remove: bool,
     /// The time when this cue was activated.
     pub activation_time: f32,
+    /// Seconds after activation at which this actor is despawned (None = no limit).
+    pub lifetime: Option<f32>,
+    /// The active effect that started this cue, if any.
+    ///
+    /// When the effect entity goes away the actor is removed as well.
+    pub source_effect: Option<Entity>,
 }
 
 impl GameplayCueNotifyActor {
@@ -62,6 +135,8 @@ impl GameplayCueNotifyActor {
             target,
             auto_destroy_on_remove: true,
             activation_time,
+            lifetime: None,
+            source_effect: None,
         }
     }
 
@@ -70,12 +145,73 @@ impl GameplayCueNotifyActor {
         self.auto_destroy_on_remove = auto_destroy;
         self
     }
+
+    /// Sets the lifetime in seconds.
+    pub fn with_lifetime(mut self, lifetime: f32) -> Self {
+        self.lifetime = Some(lifetime);
+        self
+    }
+
+    /// Sets the effect that started this cue.
+    pub fn with_source_effect(mut self, effect: Entity) -> Self {
+        self.source_effect = Some(effect);
+        self
+    }
+
+    /// Returns true once the actor has outlived its configured lifetime.
+    pub fn is_expired(&self, now: f32) -> bool {
+        self.lifetime
+            .is_some_and(|lifetime| now - self.activation_time >= lifetime)
+    }
 }
 
 /// Marker component for cue actors that should be removed.
 #[derive(Component, Debug)]
 pub struct CueActorPendingRemoval;
 
+/// Marks an entity whose distance to cue targets drives cue culling.
+///
+/// Without any listener, active cameras are used instead.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct CueListener;
+
+/// Suppresses gameplay cues whose target is this entity.
+///
+/// Suppression only blocks cues from starting: `Removed` events still run so
+/// actors and `while_active` registrations from before the suppression are
+/// cleaned up. Active static cues skip their `while_active` calls while
+/// suppressed.
+#[derive(Component, Debug, Clone, Default)]
+pub enum CueSuppression {
+    /// Suppress every cue.
+    #[default]
+    All,
+    /// Suppress cues matching any of these tags or their children.
+    Tags(Vec<GameplayTag>),
+}
+
+impl CueSuppression {
+    /// Suppresses cues matching `tags` or their children.
+    pub fn tags(tags: impl IntoIterator<Item = GameplayTag>) -> Self {
+        Self::Tags(tags.into_iter().collect())
+    }
+
+    /// Returns true if `cue_tag` is suppressed.
+    pub fn suppresses(&self, cue_tag: &GameplayTag) -> bool {
+        match self {
+            Self::All => true,
+            Self::Tags(tags) => {
+                let name = cue_tag.get_tag_name();
+                tags.iter().any(|tag| {
+                    let suppressed = tag.get_tag_name();
+                    name.strip_prefix(suppressed)
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+                })
+            }
+        }
+    }
+}
+
 /// Example static cue implementation.
 ///
 /// This is a simple example showing how to implement a static cue.
@@ -105,6 +241,17 @@ impl GameplayCueNotifyStatic for ExampleStaticCue {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_cue_suppression_matches_children() {
+        let suppression = CueSuppression::tags([GameplayTag::new("GameplayCue.Damage")]);
+
+        assert!(suppression.suppresses(&GameplayTag::new("GameplayCue.Damage")));
+        assert!(suppression.suppresses(&GameplayTag::new("GameplayCue.Damage.Fire")));
+        assert!(!suppression.suppresses(&GameplayTag::new("GameplayCue.DamageBoost")));
+        assert!(!suppression.suppresses(&GameplayTag::new("GameplayCue.Heal")));
+        assert!(CueSuppression::All.suppresses(&GameplayTag::new("GameplayCue.Heal")));
+    }
+
     #[test]
     fn test_cue_notify_actor_creation() {
         let tag = GameplayTag::new("GameplayCue.Test");
@@ -124,4 +271,13 @@ mod tests {
 
         assert!(!actor.auto_destroy_on_remove);
     }
+
+    #[test]
+    fn test_cue_notify_actor_lifetime() {
+        let tag = GameplayTag::new("GameplayCue.Test");
+        let actor = GameplayCueNotifyActor::new(tag, Entity::PLACEHOLDER, 1.0).with_lifetime(2.0);
+
+        assert!(!actor.is_expired(2.5));
+        assert!(actor.is_expired(3.0));
+    }
 }
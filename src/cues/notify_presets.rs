@@ -0,0 +1,310 @@
+//! Declarative burst and looping cue handlers.
+//!
+//! Counterparts of Unreal's `GameplayCueNotify_Burst` and
+//! `GameplayCueNotify_Looping`: plain config structs combining the built-in
+//! handlers, so common cues are described as data instead of implementing
+//! [`GameplayCueNotifyStatic`].
+
+use super::audio::AudioCue;
+use super::damage_numbers::DamageNumberCue;
+use super::impact::{CameraShakeCue, HitStopCue};
+use super::manager::GameplayCueParameters;
+use super::notify::GameplayCueNotifyStatic;
+#[cfg(feature = "hanabi")]
+use super::particles::ParticleCue;
+use bevy::prelude::*;
+
+/// One-shot feedback fired together.
+///
+/// Every configured element runs on `Executed` and `OnActive`.
+///
+/// # Example
+///
+/// ```ignore
+/// app.register_gameplay_cue_handler(
+///     "GameplayCue.Impact.Heavy",
+///     GameplayCueBurst::new()
+///         .with_sound(AudioCue::new(thud).spatial())
+///         .with_camera_shake(CameraShakeCue::new(0.6))
+///         .with_hit_stop(HitStopCue::new(0.05, 0.0)),
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GameplayCueBurst {
+    /// Particle effect to spawn.
+    #[cfg(feature = "hanabi")]
+    pub particles: Option<ParticleCue>,
+    /// Sound to play.
+    pub sound: Option<AudioCue>,
+    /// Camera shake to add.
+    pub camera_shake: Option<CameraShakeCue>,
+    /// Hit-stop to apply.
+    pub hit_stop: Option<HitStopCue>,
+    /// Damage number to show.
+    pub damage_number: Option<DamageNumberCue>,
+}
+
+impl GameplayCueBurst {
+    /// Creates an empty burst.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the particle effect.
+    #[cfg(feature = "hanabi")]
+    pub fn with_particles(mut self, particles: ParticleCue) -> Self {
+        self.particles = Some(particles);
+        self
+    }
+
+    /// Sets the sound.
+    pub fn with_sound(mut self, sound: AudioCue) -> Self {
+        self.sound = Some(sound);
+        self
+    }
+
+    /// Sets the camera shake.
+    pub fn with_camera_shake(mut self, camera_shake: CameraShakeCue) -> Self {
+        self.camera_shake = Some(camera_shake);
+        self
+    }
+
+    /// Sets the hit-stop.
+    pub fn with_hit_stop(mut self, hit_stop: HitStopCue) -> Self {
+        self.hit_stop = Some(hit_stop);
+        self
+    }
+
+    /// Sets the damage number.
+    pub fn with_damage_number(mut self, damage_number: DamageNumberCue) -> Self {
+        self.damage_number = Some(damage_number);
+        self
+    }
+
+    /// Returns true if nothing is configured.
+    pub fn is_empty(&self) -> bool {
+        #[cfg(feature = "hanabi")]
+        if self.particles.is_some() {
+            return false;
+        }
+        self.sound.is_none()
+            && self.camera_shake.is_none()
+            && self.hit_stop.is_none()
+            && self.damage_number.is_none()
+    }
+
+    fn fire(&self, target: Entity, params: &GameplayCueParameters, commands: &mut Commands) {
+        #[cfg(feature = "hanabi")]
+        if let Some(particles) = &self.particles {
+            particles.on_execute(target, params, commands);
+        }
+        if let Some(sound) = &self.sound {
+            sound.on_execute(target, params, commands);
+        }
+        if let Some(camera_shake) = &self.camera_shake {
+            camera_shake.on_execute(target, params, commands);
+        }
+        if let Some(hit_stop) = &self.hit_stop {
+            hit_stop.on_execute(target, params, commands);
+        }
+        if let Some(damage_number) = &self.damage_number {
+            damage_number.on_execute(target, params, commands);
+        }
+    }
+}
+
+impl GameplayCueNotifyStatic for GameplayCueBurst {
+    fn on_execute(&self, target: Entity, params: &GameplayCueParameters, commands: &mut Commands) {
+        self.fire(target, params, commands);
+    }
+}
+
+/// Persistent feedback kept alive while a looping cue is active.
+#[derive(Debug, Clone, Default)]
+pub struct LoopingStage {
+    /// Particle effect spawned on the target until removal.
+    #[cfg(feature = "hanabi")]
+    pub particles: Option<ParticleCue>,
+    /// Sound looped on the target until removal.
+    pub sound: Option<Handle<AudioSource>>,
+    /// Linear volume of the looping sound.
+    pub volume: f32,
+}
+
+/// Marks a looping sound spawned by a [`GameplayCueLooping`].
+#[derive(Component, Debug, Clone)]
+pub struct LoopingCueSound {
+    /// The cue target the sound belongs to.
+    pub target: Entity,
+    /// The looping sound asset.
+    pub sound: AssetId<AudioSource>,
+}
+
+/// Cue with start, loop and end stages, for duration effects.
+///
+/// - `OnActive` fires `start` and starts the `looping` stage.
+/// - `Executed` while active (e.g. periodic ticks) fires `recurring`.
+/// - `Removed` stops the `looping` stage and fires `end`.
+///
+/// # Example
+///
+/// ```ignore
+/// app.register_gameplay_cue_handler(
+///     "GameplayCue.Status.Burning",
+///     GameplayCueLooping::new()
+///         .with_start(GameplayCueBurst::new().with_sound(AudioCue::new(ignite)))
+///         .with_looping_sound(crackle, 0.6)
+///         .with_end(GameplayCueBurst::new().with_sound(AudioCue::new(fizzle))),
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GameplayCueLooping {
+    /// Burst fired when the cue becomes active.
+    pub start: GameplayCueBurst,
+    /// Feedback kept alive while active.
+    pub looping: LoopingStage,
+    /// Burst fired on each execution while active.
+    pub recurring: GameplayCueBurst,
+    /// Burst fired when the cue is removed.
+    pub end: GameplayCueBurst,
+}
+
+impl GameplayCueLooping {
+    /// Creates a looping cue with empty stages.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the start burst.
+    pub fn with_start(mut self, start: GameplayCueBurst) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Sets the sound looped while active.
+    pub fn with_looping_sound(mut self, sound: Handle<AudioSource>, volume: f32) -> Self {
+        self.looping.sound = Some(sound);
+        self.looping.volume = volume;
+        self
+    }
+
+    /// Sets the particle effect kept alive while active.
+    #[cfg(feature = "hanabi")]
+    pub fn with_looping_particles(mut self, particles: ParticleCue) -> Self {
+        self.looping.particles = Some(particles);
+        self
+    }
+
+    /// Sets the burst fired on each execution while active.
+    pub fn with_recurring(mut self, recurring: GameplayCueBurst) -> Self {
+        self.recurring = recurring;
+        self
+    }
+
+    /// Sets the end burst.
+    pub fn with_end(mut self, end: GameplayCueBurst) -> Self {
+        self.end = end;
+        self
+    }
+}
+
+impl GameplayCueNotifyStatic for GameplayCueLooping {
+    fn on_execute(&self, target: Entity, params: &GameplayCueParameters, commands: &mut Commands) {
+        self.recurring.fire(target, params, commands);
+    }
+
+    fn on_active(&self, target: Entity, params: &GameplayCueParameters, commands: &mut Commands) {
+        self.start.fire(target, params, commands);
+
+        #[cfg(feature = "hanabi")]
+        if let Some(particles) = &self.looping.particles {
+            particles.on_active(target, params, commands);
+        }
+        if let Some(sound) = &self.looping.sound {
+            commands.spawn((
+                AudioPlayer::new(sound.clone()),
+                PlaybackSettings::LOOP
+                    .with_volume(bevy::audio::Volume::Linear(self.looping.volume)),
+                LoopingCueSound {
+                    target,
+                    sound: sound.id(),
+                },
+                Transform::default(),
+                ChildOf(target),
+            ));
+        }
+    }
+
+    fn on_remove(&self, target: Entity, params: &GameplayCueParameters, commands: &mut Commands) {
+        #[cfg(feature = "hanabi")]
+        if let Some(particles) = &self.looping.particles {
+            particles.on_remove(target, params, commands);
+        }
+        if let Some(sound) = self.looping.sound.as_ref().map(Handle::id) {
+            commands.queue(move |world: &mut World| {
+                let mut query = world.query::<(Entity, &LoopingCueSound)>();
+                let sounds: Vec<Entity> = query
+                    .iter(world)
+                    .filter(|(_, looping)| looping.target == target && looping.sound == sound)
+                    .map(|(entity, _)| entity)
+                    .collect();
+                for entity in sounds {
+                    world.despawn(entity);
+                }
+            });
+        }
+
+        self.end.fire(target, params, commands);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cues::impact::CameraShake;
+
+    #[test]
+    fn test_burst_fires_every_element() {
+        let mut app = App::new();
+        let camera = app.world_mut().spawn(CameraShake::new()).id();
+        let target = app.world_mut().spawn_empty().id();
+        let burst = GameplayCueBurst::new()
+            .with_sound(AudioCue::new(Handle::default()))
+            .with_camera_shake(CameraShakeCue::new(0.5));
+        assert!(!burst.is_empty());
+
+        let mut commands = app.world_mut().commands();
+        burst.on_execute(target, &GameplayCueParameters::new(), &mut commands);
+        app.world_mut().flush();
+
+        assert_eq!(app.world().get::<CameraShake>(camera).unwrap().trauma, 0.5);
+        let mut sounds = app.world_mut().query::<&AudioPlayer>();
+        assert_eq!(sounds.iter(app.world()).count(), 1);
+    }
+
+    #[test]
+    fn test_looping_sound_lives_between_active_and_remove() {
+        let mut app = App::new();
+        let target = app.world_mut().spawn_empty().id();
+        let looping = GameplayCueLooping::new()
+            .with_looping_sound(Handle::default(), 0.5)
+            .with_end(GameplayCueBurst::new().with_sound(AudioCue::new(Handle::default())));
+        let params = GameplayCueParameters::new();
+
+        let mut commands = app.world_mut().commands();
+        looping.on_active(target, &params, &mut commands);
+        app.world_mut().flush();
+
+        let mut query = app.world_mut().query::<&LoopingCueSound>();
+        assert_eq!(query.iter(app.world()).count(), 1);
+
+        let mut commands = app.world_mut().commands();
+        looping.on_remove(target, &params, &mut commands);
+        app.world_mut().flush();
+
+        assert_eq!(query.iter(app.world()).count(), 0);
+        // Only the end burst's one-shot sound remains.
+        let mut sounds = app.world_mut().query::<&AudioPlayer>();
+        assert_eq!(sounds.iter(app.world()).count(), 1);
+    }
+}
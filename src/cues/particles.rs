@@ -0,0 +1,185 @@
+//! Particle cue backend built on `bevy_hanabi`.
+//!
+//! Only compiled with the `hanabi` feature. Provides [`ParticleCue`], a ready-made
+//! static cue handler that spawns a configured [`EffectAsset`] at the cue location
+//! or on the cue target.
+
+use super::manager::GameplayCueParameters;
+use super::notify::{CueActorAttachment, GameplayCueNotifyStatic};
+use bevy::prelude::*;
+use bevy_hanabi::prelude::{EffectAsset, ParticleEffect};
+
+/// Static cue handler that spawns a particle effect.
+///
+/// `Executed` and `OnActive` events spawn one instance each. `Removed` despawns
+/// every instance this handler spawned on the target, so looping effects tied to
+/// a duration effect stop together with it.
+///
+/// # Example
+///
+/// ```ignore
+/// let fire = asset_server.load("effects/fire.effect");
+/// manager.register_static_cue_handler(
+///     fire_tag,
+///     Arc::new(ParticleCue::new(fire).attached().with_lifetime(1.5)),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct ParticleCue {
+    /// The particle effect to spawn.
+    pub effect: Handle<EffectAsset>,
+    /// Where the particle effect is placed.
+    pub attachment: CueActorAttachment,
+    /// Seconds after which a spawned instance is despawned.
+    ///
+    /// `None` keeps the instance until the cue is removed, which suits looping
+    /// effects. Burst effects should set this so their entities do not pile up.
+    pub lifetime: Option<f32>,
+}
+
+impl ParticleCue {
+    /// Creates a handler that spawns `effect` at the cue location.
+    pub fn new(effect: Handle<EffectAsset>) -> Self {
+        Self {
+            effect,
+            attachment: CueActorAttachment::AtLocation,
+            lifetime: None,
+        }
+    }
+
+    /// Spawns the effect as a child of the cue target instead.
+    pub fn attached(mut self) -> Self {
+        self.attachment = CueActorAttachment::AttachToTarget;
+        self
+    }
+
+    /// Despawns each spawned instance after `seconds`.
+    pub fn with_lifetime(mut self, seconds: f32) -> Self {
+        self.lifetime = Some(seconds);
+        self
+    }
+
+    fn spawn(&self, target: Entity, params: &GameplayCueParameters, commands: &mut Commands) {
+        let instance = ParticleCueInstance {
+            target,
+            effect: self.effect.id(),
+            remaining: self.lifetime,
+        };
+        let effect = ParticleEffect::new(self.effect.clone());
+
+        match self.attachment {
+            CueActorAttachment::AtLocation => {
+                commands.spawn((
+                    effect,
+                    instance,
+                    Transform::from_translation(params.location),
+                ));
+            }
+            CueActorAttachment::AttachToTarget => {
+                commands.spawn((effect, instance, Transform::default(), ChildOf(target)));
+            }
+        }
+    }
+}
+
+impl GameplayCueNotifyStatic for ParticleCue {
+    fn on_execute(&self, target: Entity, params: &GameplayCueParameters, commands: &mut Commands) {
+        self.spawn(target, params, commands);
+    }
+
+    fn on_remove(&self, target: Entity, _params: &GameplayCueParameters, commands: &mut Commands) {
+        // Static handlers have no query access, so resolve the instances once the
+        // command is applied. Matching on the effect asset keeps two particle cues
+        // on the same target from removing each other.
+        let effect = self.effect.id();
+        commands.queue(move |world: &mut World| {
+            let mut query = world.query::<(Entity, &ParticleCueInstance)>();
+            let instances: Vec<Entity> = query
+                .iter(world)
+                .filter(|(_, instance)| instance.target == target && instance.effect == effect)
+                .map(|(entity, _)| entity)
+                .collect();
+            for entity in instances {
+                world.despawn(entity);
+            }
+        });
+    }
+}
+
+/// Marks a particle effect entity spawned by a [`ParticleCue`].
+#[derive(Component, Debug, Clone)]
+pub struct ParticleCueInstance {
+    /// The cue target the instance belongs to.
+    pub target: Entity,
+    /// The effect asset that was spawned.
+    pub effect: AssetId<EffectAsset>,
+    /// Seconds left before the instance is despawned, if it has a lifetime.
+    pub remaining: Option<f32>,
+}
+
+/// Despawns particle cue instances whose lifetime has run out.
+pub fn despawn_expired_particle_cues_system(
+    mut commands: Commands,
+    mut instances: Query<(Entity, &mut ParticleCueInstance)>,
+    time: Res<Time>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut instance) in instances.iter_mut() {
+        let Some(remaining) = instance.remaining.as_mut() else {
+            continue;
+        };
+        *remaining -= delta;
+        if *remaining <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn_instances(app: &mut App, cue: &ParticleCue, target: Entity) {
+        let params = GameplayCueParameters::new().with_location(Vec3::new(1.0, 2.0, 3.0));
+        let mut commands = app.world_mut().commands();
+        cue.on_execute(target, &params, &mut commands);
+        app.world_mut().flush();
+    }
+
+    #[test]
+    fn test_particle_cue_spawns_at_location_and_removes() {
+        let mut app = App::new();
+        let target = app.world_mut().spawn_empty().id();
+        let cue = ParticleCue::new(Handle::default());
+
+        spawn_instances(&mut app, &cue, target);
+
+        let mut query = app
+            .world_mut()
+            .query::<(&ParticleCueInstance, &Transform)>();
+        let (instance, transform) = query.single(app.world()).unwrap();
+        assert_eq!(instance.target, target);
+        assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+
+        let mut commands = app.world_mut().commands();
+        cue.on_remove(target, &GameplayCueParameters::new(), &mut commands);
+        app.world_mut().flush();
+
+        let mut query = app.world_mut().query::<&ParticleCueInstance>();
+        assert_eq!(query.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn test_attached_particle_cue_is_child_of_target() {
+        let mut app = App::new();
+        let target = app.world_mut().spawn_empty().id();
+        let cue = ParticleCue::new(Handle::default()).attached();
+
+        spawn_instances(&mut app, &cue, target);
+
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&ChildOf, With<ParticleCueInstance>>();
+        assert_eq!(query.single(app.world()).unwrap().parent(), target);
+    }
+}
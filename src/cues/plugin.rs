@@ -2,10 +2,25 @@
 //!
 //! This module provides the plugin for the gameplay cue system.
 
+#[cfg(not(feature = "headless"))]
+use super::damage_numbers::animate_damage_numbers_system;
+#[cfg(feature = "headless")]
+use super::headless::*;
+#[cfg(not(feature = "headless"))]
+use super::impact::{apply_camera_shake_system, update_hit_stop_system};
 use super::manager::GameplayCueManager;
+use super::net::*;
+use super::notify::{GameplayCueActorConfig, GameplayCueNotifyStatic};
+#[cfg(not(feature = "headless"))]
 use super::systems::*;
+use super::translation::GameplayCueTranslator;
+use crate::core::settings::GasSettings;
+#[cfg(not(feature = "headless"))]
 use crate::core::system_sets::CueSystemSet;
+use crate::core::system_sets::configure_gas_system_sets;
 use bevy::prelude::*;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use std::sync::Arc;
 
 /// Plugin for the gameplay cue system.
 ///
@@ -25,30 +40,149 @@ pub struct CuePlugin;
 
 impl Plugin for CuePlugin {
     fn build(&self, app: &mut App) {
+        configure_gas_system_sets(app);
         // Register resources
-        app.init_resource::<GameplayCueManager>();
-
-        // TODO: Register events with Bevy 0.18 observer pattern
-        // For now, systems will handle cue execution directly
-
-        // Register systems
-        app.add_systems(
-            Update,
-            (
-                handle_gameplay_cue_system.in_set(CueSystemSet::Handle),
-                route_gameplay_cue_system.in_set(CueSystemSet::Route),
-                execute_static_cues_system.in_set(CueSystemSet::ExecuteStatic),
-                manage_cue_actors_system.in_set(CueSystemSet::ManageActors),
-                cleanup_finished_cues_system.in_set(CueSystemSet::Cleanup),
-                update_while_active_cues_system.in_set(CueSystemSet::UpdateWhileActive),
-            ),
-        );
+        app.init_resource::<GameplayCueManager>()
+            .init_resource::<CueMulticastSettings>()
+            .init_resource::<GasSettings>();
+
+        // Register observers
+        app.add_observer(multicast_gameplay_cue)
+            .add_observer(multicast_gameplay_cue_on_entity)
+            .add_observer(on_gameplay_cue_multicast);
+
+        // Dedicated servers only record cues.
+        #[cfg(feature = "headless")]
+        app.init_resource::<HeadlessCueLog>()
+            .add_observer(record_gameplay_cue)
+            .add_observer(record_gameplay_cue_on_entity);
+
+        #[cfg(not(feature = "headless"))]
+        add_cue_execution(app);
+    }
+}
+
+/// Registers the observers and systems that execute cue handlers.
+#[cfg(not(feature = "headless"))]
+fn add_cue_execution(app: &mut App) {
+    app.add_observer(on_trigger_gameplay_cue)
+        .add_observer(on_trigger_gameplay_cue_on_entity)
+        .add_observer(on_cue_actor_removed);
+
+    app.add_systems(
+        Update,
+        (
+            handle_gameplay_cue_system.in_set(CueSystemSet::Handle),
+            route_gameplay_cue_system.in_set(CueSystemSet::Route),
+            execute_static_cues_system.in_set(CueSystemSet::ExecuteStatic),
+            manage_cue_actors_system.in_set(CueSystemSet::ManageActors),
+            cleanup_finished_cues_system.in_set(CueSystemSet::Cleanup),
+            update_while_active_cues_system.in_set(CueSystemSet::UpdateWhileActive),
+            animate_damage_numbers_system.in_set(CueSystemSet::Cleanup),
+            apply_camera_shake_system.in_set(CueSystemSet::Cleanup),
+            update_hit_stop_system.in_set(CueSystemSet::Cleanup),
+        ),
+    );
+
+    #[cfg(feature = "hanabi")]
+    app.add_systems(
+        Update,
+        super::particles::despawn_expired_particle_cues_system.in_set(CueSystemSet::Cleanup),
+    );
+
+    #[cfg(feature = "kira")]
+    app.add_systems(
+        Update,
+        super::kira_audio::despawn_finished_kira_cue_emitters_system.in_set(CueSystemSet::Cleanup),
+    );
+}
+
+/// Extension methods for registering gameplay cues while building an app.
+///
+/// Lets plugins register their cue handlers in `Plugin::build` instead of
+/// mutating [`GameplayCueManager`] from a startup system. The manager is
+/// created on first use, so the order relative to [`CuePlugin`] does not matter.
+///
+/// # Example
+///
+/// ``` ignore
+/// app.register_gameplay_cue_static::<FireImpactCue>("GameplayCue.Damage.Fire")
+///     .register_gameplay_cue_actor("GameplayCue.Buff.Shield", GameplayCueActorConfig::new());
+/// ```
+pub trait GameplayCueAppExt {
+    /// Registers a default-constructed static cue handler for `tag`.
+    fn register_gameplay_cue_static<T: GameplayCueNotifyStatic + Default>(
+        &mut self,
+        tag: &str,
+    ) -> &mut Self;
+
+    /// Registers a configured static cue handler instance for `tag`.
+    fn register_gameplay_cue_handler(
+        &mut self,
+        tag: &str,
+        handler: impl GameplayCueNotifyStatic,
+    ) -> &mut Self;
+
+    /// Registers an actor-based cue for `tag`.
+    fn register_gameplay_cue_actor(
+        &mut self,
+        tag: &str,
+        config: GameplayCueActorConfig,
+    ) -> &mut Self;
+
+    /// Adds a translator remapping triggered cue tags.
+    fn add_gameplay_cue_translator(&mut self, translator: impl GameplayCueTranslator) -> &mut Self;
+}
+
+impl GameplayCueAppExt for App {
+    fn register_gameplay_cue_static<T: GameplayCueNotifyStatic + Default>(
+        &mut self,
+        tag: &str,
+    ) -> &mut Self {
+        self.register_gameplay_cue_handler(tag, T::default())
+    }
+
+    fn register_gameplay_cue_handler(
+        &mut self,
+        tag: &str,
+        handler: impl GameplayCueNotifyStatic,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<GameplayCueManager>()
+            .register_static_cue_handler(GameplayTag::new(tag), Arc::new(handler));
+        self
+    }
+
+    fn register_gameplay_cue_actor(
+        &mut self,
+        tag: &str,
+        config: GameplayCueActorConfig,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<GameplayCueManager>()
+            .register_actor_cue_with_config(GameplayTag::new(tag), config);
+        self
+    }
+
+    fn add_gameplay_cue_translator(&mut self, translator: impl GameplayCueTranslator) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<GameplayCueManager>()
+            .add_translator(Arc::new(translator));
+        self
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cues::manager::GameplayCueParameters;
+
+    #[derive(Default)]
+    struct NoopCue;
+
+    impl GameplayCueNotifyStatic for NoopCue {
+        fn on_execute(&self, _: Entity, _: &GameplayCueParameters, _: &mut Commands) {}
+    }
 
     #[test]
     fn test_plugin_builds() {
@@ -58,4 +192,22 @@ mod tests {
         // Verify resource is registered
         assert!(app.world().get_resource::<GameplayCueManager>().is_some());
     }
+
+    #[test]
+    fn test_app_registration_before_plugin() {
+        let mut app = App::new();
+        app.register_gameplay_cue_static::<NoopCue>("GameplayCue.Static")
+            .register_gameplay_cue_actor(
+                "GameplayCue.Actor",
+                GameplayCueActorConfig::new().with_lifetime(2.0),
+            );
+        app.add_plugins(CuePlugin);
+
+        let manager = app.world().resource::<GameplayCueManager>();
+        let static_tag = GameplayTag::new("GameplayCue.Static");
+        assert!(manager.loaded_cues[&static_tag].is_static);
+        assert!(manager.static_handlers.contains_key(&static_tag));
+        let actor = &manager.loaded_cues[&GameplayTag::new("GameplayCue.Actor")];
+        assert_eq!(actor.actor_config.unwrap().lifetime, Some(2.0));
+    }
 }
@@ -3,7 +3,11 @@
 //! This module contains the systems that handle gameplay cue execution.
 
 use super::manager::{GameplayCueEvent, GameplayCueManager, GameplayCueParameters};
-use super::notify::{CueActorPendingRemoval, GameplayCueNotifyActor};
+use super::notify::{
+    CueActorAttachment, CueActorPendingRemoval, CueListener, CueSuppression, GameplayCueNotifyActor,
+};
+use crate::core::{GasSettings, OwnedTags};
+use crate::effects::components::ActiveGameplayEffect;
 use bevy::prelude::*;
 use bevy_gameplay_tag::gameplay_tag::GameplayTag;
 
@@ -18,89 +22,449 @@ pub struct TriggerGameplayCueEvent {
     pub parameters: GameplayCueParameters,
 }
 
+impl TriggerGameplayCueEvent {
+    /// Creates a cue trigger with the given parameters.
+    pub fn new(
+        cue_tag: GameplayTag,
+        event_type: GameplayCueEvent,
+        parameters: GameplayCueParameters,
+    ) -> Self {
+        Self {
+            cue_tag,
+            event_type,
+            parameters,
+        }
+    }
+}
+
+/// Entity event for triggering a gameplay cue on a specific target.
+///
+/// The event target overrides `parameters.target`, so cues can be fired with
+/// `commands.entity(target).trigger(...)` and observed per entity.
+#[derive(EntityEvent, Debug, Clone)]
+pub struct TriggerGameplayCueOnEntityEvent {
+    /// The entity the cue plays on.
+    #[event_target]
+    pub target: Entity,
+    /// The cue tag to trigger.
+    pub cue_tag: GameplayTag,
+    /// The event type.
+    pub event_type: GameplayCueEvent,
+    /// The parameters.
+    pub parameters: GameplayCueParameters,
+}
+
+impl TriggerGameplayCueOnEntityEvent {
+    /// Creates a cue trigger targeting an entity with default parameters.
+    pub fn new(target: Entity, cue_tag: GameplayTag, event_type: GameplayCueEvent) -> Self {
+        Self {
+            target,
+            cue_tag,
+            event_type,
+            parameters: GameplayCueParameters::new(),
+        }
+    }
+
+    /// Sets the parameters.
+    pub fn with_parameters(mut self, parameters: GameplayCueParameters) -> Self {
+        self.parameters = parameters;
+        self
+    }
+}
+
+/// Observer for TriggerGameplayCueEvent.
+///
+/// Feeds the manager, which queues the cue while batching is active.
+pub fn on_trigger_gameplay_cue(
+    ev: On<TriggerGameplayCueEvent>,
+    mut manager: ResMut<GameplayCueManager>,
+) {
+    let event = ev.event();
+    manager.execute_cue(
+        event.cue_tag.clone(),
+        event.event_type,
+        event.parameters.clone(),
+    );
+}
+
+/// Observer for TriggerGameplayCueOnEntityEvent.
+pub fn on_trigger_gameplay_cue_on_entity(
+    ev: On<TriggerGameplayCueOnEntityEvent>,
+    mut manager: ResMut<GameplayCueManager>,
+) {
+    let event = ev.event();
+    let mut parameters = event.parameters.clone();
+    parameters.target = Some(event.target);
+    manager.execute_cue(event.cue_tag.clone(), event.event_type, parameters);
+}
+
 /// System that handles gameplay cue triggers.
 ///
-/// This system processes TriggerGameplayCueEvent and routes them to the manager.
-pub fn handle_gameplay_cue_system(manager: ResMut<GameplayCueManager>, _commands: Commands) {
-    // TODO: Implement with Bevy 0.18 observer pattern
-    // This will read TriggerGameplayCueEvent and call manager.execute_cue()
-    let _ = manager;
+/// Triggers are consumed by observers as they happen; this system flushes the
+/// cues they queued while batching so they reach the handlers this frame. It
+/// also marks the frame boundary for cue rate limits and applies
+/// `GasSettings::default_cue_rate_limit`.
+pub fn handle_gameplay_cue_system(
+    mut manager: ResMut<GameplayCueManager>,
+    settings: Res<GasSettings>,
+    time: Res<Time>,
+) {
+    if settings.is_changed() {
+        manager.default_rate_limit = settings.default_cue_rate_limit;
+    }
+    manager.advance_rate_limits(time.elapsed_secs());
+    if !manager.pending_cues.is_empty() {
+        manager.flush_pending();
+    }
 }
 
-/// System that routes gameplay cues to appropriate handlers.
+/// System that culls routed cues by distance.
 ///
-/// This system finds matching cue handlers based on tag hierarchy and
-/// executes them with the provided parameters.
+/// Runs between routing and the handler stages. Cues with a `CueLod` whose
+/// target is farther than its `max_distance` from every `CueListener` (or
+/// active camera, when there are no listeners) are dropped or redirected to
+/// the LOD's `far_tag`. Without listeners or cameras nothing is culled.
 pub fn route_gameplay_cue_system(
-    manager: Res<GameplayCueManager>,
-    _commands: Commands,
-    _time: Res<Time>,
+    mut manager: ResMut<GameplayCueManager>,
+    listeners: Query<&GlobalTransform, With<CueListener>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    transforms: Query<&GlobalTransform>,
 ) {
-    // TODO: Implement cue routing logic
-    // This will:
-    // 1. Check for pending cues in the manager
-    // 2. Find matching cue handlers (including parent tags)
-    // 3. Execute static cues or spawn actor cues
-    let _ = manager;
+    let _span = info_span!("gas::route_gameplay_cues").entered();
+    if manager.lods.is_empty()
+        || (manager.routed_static_cues.is_empty() && manager.routed_actor_cues.is_empty())
+    {
+        return;
+    }
+
+    let mut listener_positions: Vec<Vec3> = listeners.iter().map(|t| t.translation()).collect();
+    if listener_positions.is_empty() {
+        listener_positions = cameras
+            .iter()
+            .filter(|(camera, _)| camera.is_active)
+            .map(|(_, transform)| transform.translation())
+            .collect();
+    }
+    if listener_positions.is_empty() {
+        return;
+    }
+
+    // Returns None to keep the cue, Some(far_tag) to cull it.
+    let cull = |manager: &GameplayCueManager,
+                cue_tag: &GameplayTag,
+                event_type: GameplayCueEvent,
+                parameters: &GameplayCueParameters|
+     -> Option<Option<GameplayTag>> {
+        if !matches!(
+            event_type,
+            GameplayCueEvent::Executed | GameplayCueEvent::OnActive
+        ) {
+            return None;
+        }
+        let lod = manager.find_cue_lod(cue_tag)?;
+        let position = parameters
+            .target
+            .and_then(|target| transforms.get(target).ok())
+            .map_or(parameters.location, GlobalTransform::translation);
+        let max_distance_squared = lod.max_distance * lod.max_distance;
+        let in_range = listener_positions
+            .iter()
+            .any(|listener| listener.distance_squared(position) <= max_distance_squared);
+        (!in_range).then(|| lod.far_tag.clone())
+    };
+
+    let mut redirected = Vec::new();
+
+    let static_cues = std::mem::take(&mut manager.routed_static_cues);
+    let mut kept_static = Vec::with_capacity(static_cues.len());
+    for routed in static_cues {
+        match cull(
+            &manager,
+            &routed.cue_tag,
+            routed.event_type,
+            &routed.parameters,
+        ) {
+            None => kept_static.push(routed),
+            Some(far_tag) => {
+                redirected.extend(far_tag.map(|tag| (tag, routed.event_type, routed.parameters)))
+            }
+        }
+    }
+
+    let actor_cues = std::mem::take(&mut manager.routed_actor_cues);
+    let mut kept_actors = Vec::with_capacity(actor_cues.len());
+    for routed in actor_cues {
+        match cull(
+            &manager,
+            &routed.cue_tag,
+            routed.event_type,
+            &routed.parameters,
+        ) {
+            None => kept_actors.push(routed),
+            Some(far_tag) => {
+                redirected.extend(far_tag.map(|tag| (tag, routed.event_type, routed.parameters)))
+            }
+        }
+    }
+
+    manager.routed_static_cues = kept_static;
+    manager.routed_actor_cues = kept_actors;
+    for (far_tag, event_type, parameters) in redirected {
+        manager.reroute_cue(far_tag, event_type, parameters);
+    }
 }
 
 /// System that executes static gameplay cues.
 ///
-/// Static cues are function-based and don't spawn entities.
-pub fn execute_static_cues_system(_manager: Res<GameplayCueManager>, _commands: Commands) {
-    // TODO: Implement static cue execution
-    // This will call the appropriate GameplayCueNotifyStatic trait methods
+/// Static cues are function-based and don't spawn entities. OnActive and
+/// WhileActive executions also start `while_active` tracking; Removed stops it.
+pub fn execute_static_cues_system(
+    mut manager: ResMut<GameplayCueManager>,
+    mut commands: Commands,
+    suppressions: Query<&CueSuppression>,
+) {
+    for routed in std::mem::take(&mut manager.routed_static_cues) {
+        let Some(handler) = manager.static_handlers.get(&routed.handler_tag).cloned() else {
+            continue;
+        };
+        let Some(target) = routed.parameters.target else {
+            warn!(
+                "Static cue '{:?}' executed without a target, skipping",
+                routed.handler_tag
+            );
+            continue;
+        };
+        if routed.event_type != GameplayCueEvent::Removed
+            && is_suppressed(&suppressions, target, &routed.cue_tag)
+        {
+            continue;
+        }
+
+        match routed.event_type {
+            GameplayCueEvent::Executed => {
+                handler.on_execute(target, &routed.parameters, &mut commands);
+            }
+            GameplayCueEvent::OnActive => {
+                handler.on_active(target, &routed.parameters, &mut commands);
+                manager.start_while_active(routed.cue_tag, routed.handler_tag, routed.parameters);
+            }
+            GameplayCueEvent::WhileActive => {
+                manager.start_while_active(routed.cue_tag, routed.handler_tag, routed.parameters);
+            }
+            GameplayCueEvent::Removed => {
+                manager.stop_while_active(&routed.handler_tag, &routed.parameters);
+                handler.on_remove(target, &routed.parameters, &mut commands);
+            }
+        }
+    }
+}
+
+/// Returns true if `target` has a `CueSuppression` covering `cue_tag`.
+fn is_suppressed(
+    suppressions: &Query<&CueSuppression>,
+    target: Entity,
+    cue_tag: &GameplayTag,
+) -> bool {
+    suppressions
+        .get(target)
+        .is_ok_and(|suppression| suppression.suppresses(cue_tag))
 }
 
 /// System that manages gameplay cue actors.
 ///
-/// This system handles the lifecycle of actor-based cues, including
-/// spawning, updating, and cleanup.
+/// This system handles the lifecycle of actor-based cues: it spawns actors for
+/// routed OnActive/Executed executions, marks actors of routed Removed executions
+/// for removal, and despawns actors marked with `CueActorPendingRemoval`.
+/// Actors of Executed executions always expire, after
+/// [`GasSettings::executed_cue_actor_lifetime`] unless their config sets a
+/// lifetime.
 pub fn manage_cue_actors_system(
     mut commands: Commands,
     mut manager: ResMut<GameplayCueManager>,
+    settings: Res<GasSettings>,
     cue_actors: Query<(Entity, &GameplayCueNotifyActor)>,
     pending_removal: Query<Entity, With<CueActorPendingRemoval>>,
+    suppressions: Query<&CueSuppression>,
+    time: Res<Time>,
 ) {
-    // Clean up actors marked for removal
+    let _span = info_span!("gas::manage_cue_actors").entered();
+    // Clean up actors marked for removal. Bookkeeping in `active_cues` is
+    // handled by `on_cue_actor_removed` so every despawn path is covered.
     for entity in pending_removal.iter() {
-        if let Ok((_, actor)) = cue_actors.get(entity) {
-            manager.remove_active_cue(&actor.cue_tag, entity);
-        }
         commands.entity(entity).despawn();
     }
+
+    for routed in std::mem::take(&mut manager.routed_actor_cues) {
+        let Some(target) = routed.parameters.target else {
+            warn!(
+                "Actor cue '{:?}' executed without a target, skipping",
+                routed.handler_tag
+            );
+            continue;
+        };
+
+        match routed.event_type {
+            GameplayCueEvent::OnActive | GameplayCueEvent::Executed
+                if is_suppressed(&suppressions, target, &routed.cue_tag) => {}
+            GameplayCueEvent::OnActive | GameplayCueEvent::Executed => {
+                let mut actor = GameplayCueNotifyActor::new(
+                    routed.handler_tag.clone(),
+                    target,
+                    time.elapsed_secs(),
+                )
+                .with_auto_destroy(routed.config.auto_destroy_on_remove);
+                let lifetime = match routed.event_type {
+                    GameplayCueEvent::Executed => routed
+                        .config
+                        .lifetime
+                        .or(Some(settings.executed_cue_actor_lifetime)),
+                    _ => routed.config.lifetime,
+                };
+                if let Some(lifetime) = lifetime {
+                    actor = actor.with_lifetime(lifetime);
+                }
+                if let Some(effect) = routed.parameters.source_effect {
+                    actor = actor.with_source_effect(effect);
+                }
+
+                let actor_entity = match routed.config.attachment {
+                    CueActorAttachment::AtLocation => commands
+                        .spawn((
+                            actor,
+                            Transform::from_translation(routed.parameters.location),
+                        ))
+                        .id(),
+                    CueActorAttachment::AttachToTarget => commands
+                        .spawn((actor, Transform::default(), ChildOf(target)))
+                        .id(),
+                };
+
+                manager.add_active_cue(routed.handler_tag, actor_entity);
+            }
+            GameplayCueEvent::Removed => {
+                let Some(actors) = manager.active_cues.get(&routed.handler_tag) else {
+                    continue;
+                };
+                for &entity in actors {
+                    // With a source effect, only that effect's actors go, so
+                    // overlapping effects with the same cue keep theirs.
+                    if let Ok((_, actor)) = cue_actors.get(entity)
+                        && actor.target == target
+                        && actor.auto_destroy_on_remove
+                        && (routed.parameters.source_effect.is_none()
+                            || actor.source_effect == routed.parameters.source_effect)
+                    {
+                        commands.entity(entity).insert(CueActorPendingRemoval);
+                    }
+                }
+            }
+            GameplayCueEvent::WhileActive => {}
+        }
+    }
 }
 
 /// System that cleans up finished gameplay cues.
 ///
-/// This system removes cue actors that have completed their execution.
+/// Cue actors are marked for removal when their lifetime has elapsed or when
+/// the effect that started them no longer exists.
 pub fn cleanup_finished_cues_system(
-    commands: Commands,
-    cue_actors: Query<(Entity, &GameplayCueNotifyActor)>,
-    _time: Res<Time>,
+    mut commands: Commands,
+    cue_actors: Query<(Entity, &GameplayCueNotifyActor), Without<CueActorPendingRemoval>>,
+    effects: Query<(), With<ActiveGameplayEffect>>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed_secs();
+    for (entity, actor) in cue_actors.iter() {
+        let effect_ended = actor.auto_destroy_on_remove
+            && actor
+                .source_effect
+                .is_some_and(|effect| effects.get(effect).is_err());
+
+        if actor.is_expired(now) || effect_ended {
+            commands.entity(entity).insert(CueActorPendingRemoval);
+        }
+    }
+}
+
+/// Observer that drops despawned cue actors from `GameplayCueManager::active_cues`.
+///
+/// Actors attached to a target are despawned with it through the hierarchy, so
+/// the manager cannot rely on its own systems seeing every removal.
+pub fn on_cue_actor_removed(
+    ev: On<Remove, GameplayCueNotifyActor>,
+    cue_actors: Query<&GameplayCueNotifyActor>,
+    mut manager: ResMut<GameplayCueManager>,
 ) {
-    // TODO: Implement cleanup logic
-    // This will check for cues that should be removed based on:
-    // - Duration expired
-    // - Effect removed
-    // - Manual cancellation
-    for (_entity, _actor) in cue_actors.iter() {
-        // Check if cue should be removed
-        // If so, add CueActorPendingRemoval component
-    }
-    let _ = commands;
+    let entity = ev.event_target();
+    if let Ok(actor) = cue_actors.get(entity) {
+        manager.remove_active_cue(&actor.cue_tag, entity);
+    }
 }
 
 /// System that updates WhileActive cues every frame.
 ///
 /// This system calls the while_active method on static cues that are
-/// currently active.
-pub fn update_while_active_cues_system(_manager: Res<GameplayCueManager>, _commands: Commands) {
-    // TODO: Implement WhileActive cue updates
-    // This will call GameplayCueNotifyStatic::while_active() for active cues
+/// currently active, at most once per `GameplayCueManager::while_active_interval`.
+/// Before each call the parameters are refreshed: the location follows the
+/// target's `GlobalTransform` and the target tags mirror its `OwnedTags`.
+///
+/// A cue started by an effect stops (and receives `on_remove`) as soon as that
+/// effect entity no longer exists, even if no Removed event was routed.
+pub fn update_while_active_cues_system(
+    mut manager: ResMut<GameplayCueManager>,
+    mut commands: Commands,
+    effects: Query<(), With<ActiveGameplayEffect>>,
+    transforms: Query<&GlobalTransform>,
+    owned_tags: Query<&OwnedTags>,
+    suppressions: Query<&CueSuppression>,
+    time: Res<Time>,
+) {
+    let interval = manager.while_active_interval;
+    let delta = time.delta_secs();
+    let mut active_cues = std::mem::take(&mut manager.active_static_cues);
+
+    active_cues.retain_mut(|active| {
+        let Some(handler) = manager.static_handlers.get(&active.handler_tag).cloned() else {
+            return false;
+        };
+        let Some(target) = active.parameters.target else {
+            return false;
+        };
+
+        let effect_ended = active
+            .parameters
+            .source_effect
+            .is_some_and(|effect| effects.get(effect).is_err());
+        if effect_ended {
+            handler.on_remove(target, &active.parameters, &mut commands);
+            return false;
+        }
+
+        active.time_since_tick += delta;
+        if active.time_since_tick < interval {
+            return true;
+        }
+        // Keep the leftover so ticks don't drift behind at uneven frame rates
+        active.time_since_tick -= interval;
+        if is_suppressed(&suppressions, target, &active.cue_tag) {
+            return true;
+        }
+
+        if let Ok(transform) = transforms.get(target) {
+            active.parameters.location = transform.translation();
+        }
+        if let Ok(tags) = owned_tags.get(target) {
+            active.parameters.target_tags = Some(tags.0.explicit_tags.clone());
+        }
+
+        handler.while_active(target, &active.parameters, &mut commands);
+        true
+    });
+
+    manager.active_static_cues = active_cues;
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "headless")))]
 mod tests {
     use super::*;
 
@@ -116,4 +480,352 @@ mod tests {
         assert_eq!(event.cue_tag, tag);
         assert_eq!(event.event_type, GameplayCueEvent::Executed);
     }
+
+    fn cue_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, super::super::CuePlugin));
+        crate::core::configure_gas_system_sets(&mut app);
+        app
+    }
+
+    #[test]
+    fn test_actor_cue_spawns_at_location_and_expires() {
+        use super::super::notify::GameplayCueActorConfig;
+
+        let mut app = cue_app();
+        let tag = GameplayTag::new("GameplayCue.Test");
+        let target = app.world_mut().spawn_empty().id();
+
+        {
+            let mut manager = app.world_mut().resource_mut::<GameplayCueManager>();
+            manager.register_actor_cue_with_config(
+                tag.clone(),
+                GameplayCueActorConfig::new().with_lifetime(0.0),
+            );
+            manager.execute_cue(
+                tag.clone(),
+                GameplayCueEvent::OnActive,
+                GameplayCueParameters::new()
+                    .with_target(target)
+                    .with_location(Vec3::new(1.0, 2.0, 3.0)),
+            );
+        }
+        app.update();
+
+        let (actor_entity, transform) = {
+            let mut query = app
+                .world_mut()
+                .query::<(Entity, &GameplayCueNotifyActor, &Transform)>();
+            let (entity, actor, transform) = query
+                .single(app.world())
+                .expect("OnActive should spawn one cue actor");
+            assert_eq!(actor.target, target);
+            (entity, *transform)
+        };
+        assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            app.world().resource::<GameplayCueManager>().active_cues[&tag],
+            vec![actor_entity]
+        );
+
+        // Lifetime elapsed: marked this frame, despawned the next.
+        app.update();
+        app.update();
+
+        assert!(app.world().get_entity(actor_entity).is_err());
+        assert!(
+            app.world()
+                .resource::<GameplayCueManager>()
+                .active_cues
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_suppressed_target_spawns_no_actor() {
+        let mut app = cue_app();
+        let fire = GameplayTag::new("GameplayCue.Damage.Fire");
+        let heal = GameplayTag::new("GameplayCue.Heal");
+        let target = app
+            .world_mut()
+            .spawn(CueSuppression::tags([GameplayTag::new(
+                "GameplayCue.Damage",
+            )]))
+            .id();
+
+        {
+            let mut manager = app.world_mut().resource_mut::<GameplayCueManager>();
+            manager.register_actor_cue(fire.clone());
+            manager.register_actor_cue(heal.clone());
+            for tag in [fire, heal.clone()] {
+                manager.execute_cue(
+                    tag,
+                    GameplayCueEvent::Executed,
+                    GameplayCueParameters::new().with_target(target),
+                );
+            }
+        }
+        app.update();
+
+        let mut query = app.world_mut().query::<&GameplayCueNotifyActor>();
+        let actors: Vec<_> = query.iter(app.world()).map(|a| a.cue_tag.clone()).collect();
+        assert_eq!(actors, vec![heal]);
+    }
+
+    #[test]
+    fn test_far_cue_is_culled_or_redirected() {
+        use super::super::manager::CueLod;
+
+        let mut app = cue_app();
+        let near_tag = GameplayTag::new("GameplayCue.Hit");
+        let far_tag = GameplayTag::new("GameplayCue.HitFar");
+        app.world_mut()
+            .spawn((CueListener, GlobalTransform::default()));
+        let near = app
+            .world_mut()
+            .spawn(GlobalTransform::from_translation(Vec3::X * 10.0))
+            .id();
+        let far = app
+            .world_mut()
+            .spawn(GlobalTransform::from_translation(Vec3::X * 500.0))
+            .id();
+
+        {
+            let mut manager = app.world_mut().resource_mut::<GameplayCueManager>();
+            manager.register_actor_cue(near_tag.clone());
+            manager.register_actor_cue(far_tag.clone());
+            manager.set_cue_lod(
+                near_tag.clone(),
+                CueLod::new(100.0).with_far_tag(far_tag.clone()),
+            );
+            for target in [near, far] {
+                manager.execute_cue(
+                    near_tag.clone(),
+                    GameplayCueEvent::Executed,
+                    GameplayCueParameters::new().with_target(target),
+                );
+            }
+        }
+        app.update();
+
+        let mut query = app.world_mut().query::<&GameplayCueNotifyActor>();
+        let actors: Vec<_> = query
+            .iter(app.world())
+            .map(|actor| (actor.target, actor.cue_tag.clone()))
+            .collect();
+        assert_eq!(actors.len(), 2);
+        assert!(actors.contains(&(near, near_tag)));
+        assert!(actors.contains(&(far, far_tag)));
+    }
+
+    #[test]
+    fn test_trigger_event_is_batched_until_handle_stage() {
+        let mut app = cue_app();
+        let tag = GameplayTag::new("GameplayCue.Test");
+        let target = app.world_mut().spawn_empty().id();
+
+        {
+            let mut manager = app.world_mut().resource_mut::<GameplayCueManager>();
+            manager.register_actor_cue(tag.clone());
+            manager.start_batching();
+        }
+
+        app.world_mut()
+            .trigger(TriggerGameplayCueOnEntityEvent::new(
+                target,
+                tag,
+                GameplayCueEvent::Executed,
+            ));
+
+        {
+            let manager = app.world().resource::<GameplayCueManager>();
+            assert_eq!(manager.pending_cues.len(), 1);
+            assert!(manager.routed_actor_cues.is_empty());
+        }
+
+        app.update();
+
+        let mut query = app.world_mut().query::<&GameplayCueNotifyActor>();
+        let actor = query
+            .single(app.world())
+            .expect("batched cue should be flushed and spawned this frame");
+        assert_eq!(actor.target, target);
+        assert!(
+            app.world()
+                .resource::<GameplayCueManager>()
+                .pending_cues
+                .is_empty()
+        );
+    }
+
+    #[derive(Default)]
+    struct CountingCue {
+        while_active_calls: std::sync::atomic::AtomicUsize,
+        remove_calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl super::super::notify::GameplayCueNotifyStatic for CountingCue {
+        fn on_execute(&self, _: Entity, _: &GameplayCueParameters, _: &mut Commands) {}
+
+        fn while_active(&self, _: Entity, _: &GameplayCueParameters, _: &mut Commands) {
+            self.while_active_calls
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+
+        fn on_remove(&self, _: Entity, _: &GameplayCueParameters, _: &mut Commands) {
+            self.remove_calls
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_while_active_stops_when_backing_effect_is_removed() {
+        use std::sync::atomic::Ordering;
+
+        let mut app = cue_app();
+        let tag = GameplayTag::new("GameplayCue.Test");
+        let handler = std::sync::Arc::new(CountingCue::default());
+        let target = app.world_mut().spawn_empty().id();
+        let effect = app
+            .world_mut()
+            .spawn(ActiveGameplayEffect::new("test", target, target, 1, 0.0))
+            .id();
+
+        {
+            let mut manager = app.world_mut().resource_mut::<GameplayCueManager>();
+            manager.register_static_cue_handler(tag.clone(), handler.clone());
+            manager.execute_cue(
+                tag,
+                GameplayCueEvent::OnActive,
+                GameplayCueParameters::new()
+                    .with_target(target)
+                    .with_source_effect(effect),
+            );
+        }
+        app.update();
+        app.update();
+
+        assert_eq!(handler.while_active_calls.load(Ordering::SeqCst), 2);
+
+        app.world_mut().entity_mut(effect).despawn();
+        app.update();
+        app.update();
+
+        assert_eq!(handler.while_active_calls.load(Ordering::SeqCst), 2);
+        assert_eq!(handler.remove_calls.load(Ordering::SeqCst), 1);
+        assert!(
+            app.world()
+                .resource::<GameplayCueManager>()
+                .active_static_cues
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_while_active_is_suppressed_by_the_executed_tag() {
+        use std::sync::atomic::Ordering;
+
+        let mut app = cue_app();
+        let handler = std::sync::Arc::new(CountingCue::default());
+        let target = app.world_mut().spawn_empty().id();
+
+        {
+            let mut manager = app.world_mut().resource_mut::<GameplayCueManager>();
+            manager
+                .register_static_cue_handler(GameplayTag::new("GameplayCue.Burn"), handler.clone());
+            manager.execute_cue(
+                GameplayTag::new("GameplayCue.Burn.Fire"),
+                GameplayCueEvent::OnActive,
+                GameplayCueParameters::new().with_target(target),
+            );
+        }
+        app.update();
+        assert_eq!(handler.while_active_calls.load(Ordering::SeqCst), 1);
+
+        app.world_mut()
+            .entity_mut(target)
+            .insert(CueSuppression::tags([GameplayTag::new(
+                "GameplayCue.Burn.Fire",
+            )]));
+        app.update();
+        app.update();
+        assert_eq!(handler.while_active_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_while_active_interval_keeps_leftover_time() {
+        use bevy::time::TimeUpdateStrategy;
+        use std::sync::atomic::Ordering;
+        use std::time::Duration;
+
+        let mut app = cue_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            60,
+        )));
+        app.update();
+        let handler = std::sync::Arc::new(CountingCue::default());
+        let target = app.world_mut().spawn_empty().id();
+
+        {
+            let mut manager = app.world_mut().resource_mut::<GameplayCueManager>();
+            manager.while_active_interval = 0.1;
+            manager
+                .register_static_cue_handler(GameplayTag::new("GameplayCue.Burn"), handler.clone());
+            manager.execute_cue(
+                GameplayTag::new("GameplayCue.Burn"),
+                GameplayCueEvent::OnActive,
+                GameplayCueParameters::new().with_target(target),
+            );
+        }
+        // 3 seconds of 60ms frames tick about 30 times, not once every
+        // second frame
+        for _ in 0..50 {
+            app.update();
+        }
+        assert!(handler.while_active_calls.load(Ordering::SeqCst) >= 29);
+    }
+
+    #[test]
+    fn test_actor_cue_attached_to_target_is_removed_on_removed_event() {
+        use super::super::notify::{CueActorAttachment, GameplayCueActorConfig};
+
+        let mut app = cue_app();
+        let tag = GameplayTag::new("GameplayCue.Test");
+        let target = app.world_mut().spawn(Transform::default()).id();
+
+        {
+            let mut manager = app.world_mut().resource_mut::<GameplayCueManager>();
+            manager.register_actor_cue_with_config(
+                tag.clone(),
+                GameplayCueActorConfig::new().with_attachment(CueActorAttachment::AttachToTarget),
+            );
+            manager.execute_cue(
+                tag.clone(),
+                GameplayCueEvent::OnActive,
+                GameplayCueParameters::new().with_target(target),
+            );
+        }
+        app.update();
+
+        let actor_entity = {
+            let mut query = app
+                .world_mut()
+                .query_filtered::<(Entity, &ChildOf), With<GameplayCueNotifyActor>>();
+            let (entity, child_of) = query.single(app.world()).expect("actor should spawn");
+            assert_eq!(child_of.parent(), target);
+            entity
+        };
+
+        app.world_mut()
+            .resource_mut::<GameplayCueManager>()
+            .execute_cue(
+                tag,
+                GameplayCueEvent::Removed,
+                GameplayCueParameters::new().with_target(target),
+            );
+        app.update();
+        app.update();
+
+        assert!(app.world().get_entity(actor_entity).is_err());
+    }
 }
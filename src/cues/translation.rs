@@ -0,0 +1,103 @@
+//! GameplayCue translation.
+//!
+//! Translators remap a triggered cue tag to another one based on its
+//! parameters, before rate limiting and handler lookup, so effects and
+//! abilities can trigger generic cues like `GameplayCue.Footstep` while the
+//! game picks `GameplayCue.Footstep.Snow` from the surface it supplies.
+//! Matches UE GAS's `UGameplayCueTranslator`.
+//!
+//! Translators run in registration order on the
+//! [`GameplayCueManager`](super::manager::GameplayCueManager), each seeing the
+//! tag the previous ones produced. An `OnActive` cue keeps the tag it was
+//! translated to until its `Removed` event, even if the removal carries
+//! different parameters.
+//!
+//! # Example
+//! ```ignore
+//! app.add_gameplay_cue_translator(
+//!     SurfaceCueTranslator::new(GameplayTag::new("GameplayCue.Footstep"))
+//!         .with_surface("Snow", GameplayTag::new("GameplayCue.Footstep.Snow"))
+//!         .with_surface("Metal", GameplayTag::new("GameplayCue.Footstep.Metal")),
+//! );
+//!
+//! // Executes GameplayCue.Footstep.Snow
+//! manager.execute_cue(
+//!     GameplayTag::new("GameplayCue.Footstep"),
+//!     GameplayCueEvent::Executed,
+//!     GameplayCueParameters::new().with_physical_material("Snow"),
+//! );
+//! ```
+
+use super::manager::GameplayCueParameters;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use std::collections::HashMap;
+
+/// Trait for remapping triggered cue tags.
+pub trait GameplayCueTranslator: Send + Sync + 'static {
+    /// Returns the tag to execute instead of `cue_tag`, or `None` to keep it.
+    fn translate(
+        &self,
+        cue_tag: &GameplayTag,
+        parameters: &GameplayCueParameters,
+    ) -> Option<GameplayTag>;
+}
+
+impl<F> GameplayCueTranslator for F
+where
+    F: Fn(&GameplayTag, &GameplayCueParameters) -> Option<GameplayTag> + Send + Sync + 'static,
+{
+    fn translate(
+        &self,
+        cue_tag: &GameplayTag,
+        parameters: &GameplayCueParameters,
+    ) -> Option<GameplayTag> {
+        self(cue_tag, parameters)
+    }
+}
+
+/// Translates a cue tag by the surface in
+/// [`GameplayCueParameters::physical_material`].
+///
+/// Surfaces without a mapping keep the original tag.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SurfaceCueTranslator {
+    /// The cue tag to translate.
+    pub cue_tag: GameplayTag,
+    /// Tag executed instead for each physical material.
+    pub surfaces: HashMap<String, GameplayTag>,
+}
+
+impl SurfaceCueTranslator {
+    /// Creates a translator for `cue_tag` with no surfaces.
+    pub fn new(cue_tag: GameplayTag) -> Self {
+        Self {
+            cue_tag,
+            surfaces: HashMap::new(),
+        }
+    }
+
+    /// Executes `translated` instead on `physical_material`.
+    pub fn with_surface(
+        mut self,
+        physical_material: impl Into<String>,
+        translated: GameplayTag,
+    ) -> Self {
+        self.surfaces.insert(physical_material.into(), translated);
+        self
+    }
+}
+
+impl GameplayCueTranslator for SurfaceCueTranslator {
+    fn translate(
+        &self,
+        cue_tag: &GameplayTag,
+        parameters: &GameplayCueParameters,
+    ) -> Option<GameplayTag> {
+        if *cue_tag != self.cue_tag {
+            return None;
+        }
+        self.surfaces
+            .get(parameters.physical_material.as_deref()?)
+            .cloned()
+    }
+}
@@ -0,0 +1,437 @@
+//! Developer console commands for changing GAS state at runtime.
+//!
+//! Only compiled with the `console` feature. When `bevy_console`'s
+//! `ConsolePlugin` is added, [`GasConsolePlugin`] registers the commands with
+//! it and prints their output in its console. The commands are plain text, so
+//! any other frontend (an egui text field, a socket) can drive them too:
+//! forward each line as a [`GasConsoleCommandEvent`] and print the
+//! [`GasConsoleOutputEvent`] that answers it.
+//!
+//! | Command | Effect |
+//! |---|---|
+//! | `gas.apply <entity> <effect_id> [level]` | Applies an effect to the entity |
+//! | `gas.give <entity> <ability_id> [level]` | Grants an ability to the entity |
+//! | `gas.set <entity> <attribute> <value>` | Sets an attribute's base and current value |
+//! | `gas.dump <entity>` | Prints the entity's attributes, effects, abilities and tags |
+//!
+//! `<entity>` is an owner's `Name`, its index (`12`) or its index and
+//! generation as printed by `Entity`'s `Display` (`12v1`).
+//!
+//! # Example
+//!
+//! ```ignore
+//! app.add_plugins((DefaultPlugins, ConsolePlugin, GasPlugin, GasConsolePlugin));
+//! ```
+
+use super::info::GasDebug;
+use crate::abilities::components::{AbilityOwner, AbilitySpec};
+use crate::abilities::definition::AbilityRegistry;
+use crate::attributes::components::{AttributeData, AttributeName};
+use crate::core::OwnedTags;
+use crate::effects::definition::GameplayEffectRegistry;
+use crate::effects::systems::ApplyGameplayEffectEvent;
+use crate::error::GasError;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_console::{AddConsoleCommand, ConsoleCommand, ConsoleConfiguration, PrintConsoleLine};
+use clap::Parser;
+use std::fmt;
+use std::str::FromStr;
+use string_cache::DefaultAtom as Atom;
+
+/// Plugin running [`GasConsoleCommandEvent`]s.
+pub struct GasConsolePlugin;
+
+impl Plugin for GasConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_gas_console_command);
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Only bridge to bevy_console when its plugin was added, in any order.
+        if !app.world().contains_resource::<ConsoleConfiguration>() {
+            return;
+        }
+        app.add_console_command::<GasApplyConsoleCommand, _>(gas_apply_console_command)
+            .add_console_command::<GasGiveConsoleCommand, _>(gas_give_console_command)
+            .add_console_command::<GasSetConsoleCommand, _>(gas_set_console_command)
+            .add_console_command::<GasDumpConsoleCommand, _>(gas_dump_console_command)
+            .add_observer(print_gas_console_output);
+    }
+}
+
+/// A parsed `gas.*` console command.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GasConsoleCommand {
+    /// `gas.apply <entity> <effect_id> [level]`
+    Apply {
+        entity: String,
+        effect_id: Atom,
+        level: i32,
+    },
+    /// `gas.give <entity> <ability_id> [level]`
+    Give {
+        entity: String,
+        ability_id: Atom,
+        level: i32,
+    },
+    /// `gas.set <entity> <attribute> <value>`
+    Set {
+        entity: String,
+        attribute: Atom,
+        value: f32,
+    },
+    /// `gas.dump <entity>`
+    Dump { entity: String },
+}
+
+/// Why a console command failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GasConsoleError {
+    /// The command name is not a `gas.*` command.
+    UnknownCommand(String),
+    /// The arguments don't match the command; holds its usage line.
+    Usage(&'static str),
+    /// No GAS owner matches the entity argument.
+    EntityNotFound(String),
+    /// The command referenced a missing definition or attribute.
+    Gas(GasError),
+}
+
+impl fmt::Display for GasConsoleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GasConsoleError::UnknownCommand(name) => write!(f, "Unknown command '{}'", name),
+            GasConsoleError::Usage(usage) => write!(f, "Usage: {}", usage),
+            GasConsoleError::EntityNotFound(entity) => {
+                write!(f, "No GAS owner matches '{}'", entity)
+            }
+            GasConsoleError::Gas(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for GasConsoleError {}
+
+impl From<GasError> for GasConsoleError {
+    fn from(error: GasError) -> Self {
+        GasConsoleError::Gas(error)
+    }
+}
+
+const APPLY_USAGE: &str = "gas.apply <entity> <effect_id> [level]";
+const GIVE_USAGE: &str = "gas.give <entity> <ability_id> [level]";
+const SET_USAGE: &str = "gas.set <entity> <attribute> <value>";
+const DUMP_USAGE: &str = "gas.dump <entity>";
+
+impl FromStr for GasConsoleCommand {
+    type Err = GasConsoleError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut words = line.split_whitespace();
+        let name = words.next().unwrap_or_default();
+        let args: Vec<&str> = words.collect();
+        let level = |arg: Option<&&str>, usage| match arg {
+            Some(level) => level.parse().map_err(|_| GasConsoleError::Usage(usage)),
+            None => Ok(1),
+        };
+        match (name, args.as_slice()) {
+            ("gas.apply", [entity, effect_id, rest @ ..]) if rest.len() <= 1 => {
+                Ok(GasConsoleCommand::Apply {
+                    entity: entity.to_string(),
+                    effect_id: Atom::from(*effect_id),
+                    level: level(rest.first(), APPLY_USAGE)?,
+                })
+            }
+            ("gas.give", [entity, ability_id, rest @ ..]) if rest.len() <= 1 => {
+                Ok(GasConsoleCommand::Give {
+                    entity: entity.to_string(),
+                    ability_id: Atom::from(*ability_id),
+                    level: level(rest.first(), GIVE_USAGE)?,
+                })
+            }
+            ("gas.set", [entity, attribute, value]) => Ok(GasConsoleCommand::Set {
+                entity: entity.to_string(),
+                attribute: Atom::from(*attribute),
+                value: value
+                    .parse()
+                    .map_err(|_| GasConsoleError::Usage(SET_USAGE))?,
+            }),
+            ("gas.dump", [entity]) => Ok(GasConsoleCommand::Dump {
+                entity: entity.to_string(),
+            }),
+            ("gas.apply", _) => Err(GasConsoleError::Usage(APPLY_USAGE)),
+            ("gas.give", _) => Err(GasConsoleError::Usage(GIVE_USAGE)),
+            ("gas.set", _) => Err(GasConsoleError::Usage(SET_USAGE)),
+            ("gas.dump", _) => Err(GasConsoleError::Usage(DUMP_USAGE)),
+            _ => Err(GasConsoleError::UnknownCommand(name.to_string())),
+        }
+    }
+}
+
+impl GasConsoleCommand {
+    /// Runs the command and returns its output.
+    ///
+    /// Effect applications are triggered immediately; their results show up
+    /// after the next update.
+    pub fn run(&self, world: &mut World) -> Result<String, GasConsoleError> {
+        match self {
+            GasConsoleCommand::Apply {
+                entity,
+                effect_id,
+                level,
+            } => {
+                let target = resolve_owner(world, entity)?;
+                if world
+                    .get_resource::<GameplayEffectRegistry>()
+                    .and_then(|registry| registry.get(effect_id.clone()))
+                    .is_none()
+                {
+                    return Err(GasError::EffectDefinitionNotFound {
+                        effect_id: effect_id.clone(),
+                    }
+                    .into());
+                }
+                world.trigger(
+                    ApplyGameplayEffectEvent::new(effect_id.clone(), target).with_level(*level),
+                );
+                Ok(format!("Applied '{}' to {}", effect_id, target))
+            }
+            GasConsoleCommand::Give {
+                entity,
+                ability_id,
+                level,
+            } => {
+                let owner = resolve_owner(world, entity)?;
+                if world
+                    .get_resource::<AbilityRegistry>()
+                    .and_then(|registry| registry.get(ability_id.clone()))
+                    .is_none()
+                {
+                    return Err(GasError::AbilityDefinitionNotFound {
+                        ability_id: ability_id.clone(),
+                    }
+                    .into());
+                }
+                let spec = world
+                    .spawn((
+                        AbilitySpec::new(ability_id.clone(), *level),
+                        AbilityOwner(owner),
+                    ))
+                    .id();
+                Ok(format!("Granted '{}' to {} as {}", ability_id, owner, spec))
+            }
+            GasConsoleCommand::Set {
+                entity,
+                attribute,
+                value,
+            } => {
+                let owner = resolve_owner(world, entity)?;
+                let mut attributes =
+                    world.query::<(&AttributeName, &ChildOf, &mut AttributeData)>();
+                let Some((_, _, mut data)) = attributes
+                    .iter_mut(world)
+                    .find(|(name, child_of, _)| child_of.parent() == owner && name.0 == *attribute)
+                else {
+                    return Err(GasError::AttributeNotFound {
+                        attribute_name: attribute.clone(),
+                        owner,
+                    }
+                    .into());
+                };
+                // Aggregation recomputes the current value of modified
+                // attributes from the new base.
+                data.set_base_value(*value);
+                data.set_current_value(*value);
+                Ok(format!("Set {} of {} to {}", attribute, owner, value))
+            }
+            GasConsoleCommand::Dump { entity } => {
+                let owner = resolve_owner(world, entity)?;
+                world
+                    .run_system_once(move |debug: GasDebug| debug.info(owner).to_string())
+                    .map_err(|error| {
+                        GasError::InvalidState {
+                            message: error.to_string(),
+                        }
+                        .into()
+                    })
+            }
+        }
+    }
+}
+
+/// Finds the GAS owner (an entity with `OwnedTags`) named by `argument`.
+fn resolve_owner(world: &mut World, argument: &str) -> Result<Entity, GasConsoleError> {
+    let (index, generation) = match argument.split_once('v') {
+        Some((index, generation)) => (index.parse::<u32>().ok(), generation.parse::<u32>().ok()),
+        None => (argument.parse::<u32>().ok(), None),
+    };
+    let mut owners = world.query_filtered::<(Entity, Option<&Name>), With<OwnedTags>>();
+    owners
+        .iter(world)
+        .find(|(entity, name)| {
+            name.is_some_and(|name| name.as_str() == argument)
+                || (index == Some(entity.index_u32())
+                    && generation
+                        .is_none_or(|generation| generation == entity.generation().to_bits()))
+        })
+        .map(|(entity, _)| entity)
+        .ok_or_else(|| GasConsoleError::EntityNotFound(argument.to_string()))
+}
+
+/// Event carrying one console line to run.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct GasConsoleCommandEvent {
+    /// The line as typed, e.g. `gas.apply Player burn 2`.
+    pub line: String,
+}
+
+impl GasConsoleCommandEvent {
+    /// Creates an event for `line`.
+    pub fn new(line: impl Into<String>) -> Self {
+        Self { line: line.into() }
+    }
+}
+
+/// Event triggered with the result of each [`GasConsoleCommandEvent`].
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct GasConsoleOutputEvent {
+    /// The line that was run.
+    pub line: String,
+    /// The command output, or why it failed.
+    pub result: Result<String, GasConsoleError>,
+}
+
+/// Observer running [`GasConsoleCommandEvent`]s.
+pub fn on_gas_console_command(ev: On<GasConsoleCommandEvent>, mut commands: Commands) {
+    let line = ev.line.clone();
+    commands.queue(move |world: &mut World| {
+        let result = line
+            .parse::<GasConsoleCommand>()
+            .and_then(|command| command.run(world));
+        match &result {
+            Ok(output) => info!("{}", output),
+            Err(error) => warn!("{}: {}", line, error),
+        }
+        world.trigger(GasConsoleOutputEvent { line, result });
+    });
+}
+
+/// Applies an effect to a GAS owner.
+#[derive(Parser, bevy_console::ConsoleCommand)]
+#[command(name = "gas.apply")]
+struct GasApplyConsoleCommand {
+    /// Owner name, index or index and generation
+    entity: String,
+    /// Effect to apply
+    effect_id: String,
+    /// Effect level
+    level: Option<String>,
+}
+
+/// Grants an ability to a GAS owner.
+#[derive(Parser, bevy_console::ConsoleCommand)]
+#[command(name = "gas.give")]
+struct GasGiveConsoleCommand {
+    /// Owner name, index or index and generation
+    entity: String,
+    /// Ability to grant
+    ability_id: String,
+    /// Ability level
+    level: Option<String>,
+}
+
+/// Sets an attribute's base and current value.
+#[derive(Parser, bevy_console::ConsoleCommand)]
+#[command(name = "gas.set")]
+struct GasSetConsoleCommand {
+    /// Owner name, index or index and generation
+    entity: String,
+    /// Attribute to set
+    attribute: String,
+    /// New value
+    value: String,
+}
+
+/// Prints a GAS owner's attributes, effects, abilities and tags.
+#[derive(Parser, bevy_console::ConsoleCommand)]
+#[command(name = "gas.dump")]
+struct GasDumpConsoleCommand {
+    /// Owner name, index or index and generation
+    entity: String,
+}
+
+/// Joins a command name and its arguments back into a console line.
+fn console_line<'a>(name: &str, args: impl IntoIterator<Item = &'a String>) -> String {
+    args.into_iter()
+        .fold(name.to_string(), |line, arg| line + " " + arg)
+}
+
+fn gas_apply_console_command(
+    mut command: ConsoleCommand<GasApplyConsoleCommand>,
+    mut commands: Commands,
+) {
+    if let Some(Ok(GasApplyConsoleCommand {
+        entity,
+        effect_id,
+        level,
+    })) = command.take()
+    {
+        let args = [&entity, &effect_id].into_iter().chain(&level);
+        commands.trigger(GasConsoleCommandEvent::new(console_line("gas.apply", args)));
+    }
+}
+
+fn gas_give_console_command(
+    mut command: ConsoleCommand<GasGiveConsoleCommand>,
+    mut commands: Commands,
+) {
+    if let Some(Ok(GasGiveConsoleCommand {
+        entity,
+        ability_id,
+        level,
+    })) = command.take()
+    {
+        let args = [&entity, &ability_id].into_iter().chain(&level);
+        commands.trigger(GasConsoleCommandEvent::new(console_line("gas.give", args)));
+    }
+}
+
+fn gas_set_console_command(
+    mut command: ConsoleCommand<GasSetConsoleCommand>,
+    mut commands: Commands,
+) {
+    if let Some(Ok(GasSetConsoleCommand {
+        entity,
+        attribute,
+        value,
+    })) = command.take()
+    {
+        let args = [&entity, &attribute, &value];
+        commands.trigger(GasConsoleCommandEvent::new(console_line("gas.set", args)));
+    }
+}
+
+fn gas_dump_console_command(
+    mut command: ConsoleCommand<GasDumpConsoleCommand>,
+    mut commands: Commands,
+) {
+    if let Some(Ok(GasDumpConsoleCommand { entity })) = command.take() {
+        commands.trigger(GasConsoleCommandEvent::new(console_line(
+            "gas.dump",
+            [&entity],
+        )));
+    }
+}
+
+/// Observer printing console command outputs in `bevy_console`.
+fn print_gas_console_output(
+    ev: On<GasConsoleOutputEvent>,
+    mut lines: MessageWriter<PrintConsoleLine>,
+) {
+    let (output, status) = match &ev.result {
+        Ok(output) => (output.clone(), "[ok]"),
+        Err(error) => (error.to_string(), "[failed]"),
+    };
+    lines.write_batch([output, status.to_string()].map(PrintConsoleLine::new));
+}
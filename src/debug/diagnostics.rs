@@ -0,0 +1,116 @@
+//! Performance diagnostics.
+//!
+//! [`GasDiagnosticsPlugin`] registers GAS counters with Bevy's
+//! [`DiagnosticsStore`], so they show up in `LogDiagnosticsPlugin` output
+//! next to the frame time. The major GAS systems also open `gas::*` tracing
+//! spans, which Tracy captures (`bevy/trace_tracy`) without this plugin.
+
+use crate::abilities::systems::AbilityActivatedEvent;
+use crate::core::system_sets::{EffectSystemSet, configure_gas_system_sets};
+use crate::core::timestep::GasTickMode;
+use crate::effects::components::{ActiveGameplayEffect, AttributeModifier};
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+
+/// Plugin registering the GAS [`Diagnostic`]s.
+///
+/// # Example
+///
+/// ```ignore
+/// app.add_plugins(GasPlugin)
+///     .add_plugins((GasDiagnosticsPlugin, LogDiagnosticsPlugin::default()));
+/// ```
+pub struct GasDiagnosticsPlugin;
+
+impl GasDiagnosticsPlugin {
+    /// Number of active gameplay effects.
+    pub const ACTIVE_EFFECTS: DiagnosticPath = DiagnosticPath::const_new("gas/active_effects");
+    /// Number of attribute modifiers.
+    pub const MODIFIERS: DiagnosticPath = DiagnosticPath::const_new("gas/modifiers");
+    /// Ability activations per second.
+    pub const ACTIVATIONS_PER_SECOND: DiagnosticPath =
+        DiagnosticPath::const_new("gas/activations_per_second");
+    /// Time spent aggregating modifiers, in milliseconds.
+    pub const AGGREGATION_TIME: DiagnosticPath = DiagnosticPath::const_new("gas/aggregation_time");
+}
+
+impl Plugin for GasDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        let simulation_schedule = GasTickMode::of(app).simulation_schedule();
+        configure_gas_system_sets(app);
+        app.register_diagnostic(Diagnostic::new(Self::ACTIVE_EFFECTS))
+            .register_diagnostic(Diagnostic::new(Self::MODIFIERS))
+            .register_diagnostic(Diagnostic::new(Self::ACTIVATIONS_PER_SECOND))
+            .register_diagnostic(Diagnostic::new(Self::AGGREGATION_TIME).with_suffix("ms"))
+            .init_resource::<GasDiagnosticsState>()
+            .add_observer(count_ability_activations)
+            .add_systems(
+                simulation_schedule,
+                (
+                    start_aggregation_timer_system
+                        .after(EffectSystemSet::CreateModifiers)
+                        .before(EffectSystemSet::Aggregate),
+                    stop_aggregation_timer_system
+                        .after(EffectSystemSet::Aggregate)
+                        .before(EffectSystemSet::UpdateDurations),
+                ),
+            )
+            .add_systems(Last, update_gas_diagnostics_system);
+    }
+}
+
+/// Measurements collected between diagnostic updates.
+#[derive(Resource, Debug, Default)]
+pub struct GasDiagnosticsState {
+    activations: u32,
+    aggregation_start: Option<Instant>,
+}
+
+/// Observer counting ability activations.
+pub fn count_ability_activations(
+    _ev: On<AbilityActivatedEvent>,
+    mut state: ResMut<GasDiagnosticsState>,
+) {
+    state.activations += 1;
+}
+
+/// System marking the start of modifier aggregation.
+pub fn start_aggregation_timer_system(mut state: ResMut<GasDiagnosticsState>) {
+    state.aggregation_start = Some(Instant::now());
+}
+
+/// System measuring how long modifier aggregation took.
+pub fn stop_aggregation_timer_system(
+    mut state: ResMut<GasDiagnosticsState>,
+    mut diagnostics: Diagnostics,
+) {
+    if let Some(start) = state.aggregation_start.take() {
+        diagnostics.add_measurement(&GasDiagnosticsPlugin::AGGREGATION_TIME, || {
+            start.elapsed().as_secs_f64() * 1000.0
+        });
+    }
+}
+
+/// System recording the effect and modifier counts and the activation rate.
+pub fn update_gas_diagnostics_system(
+    mut state: ResMut<GasDiagnosticsState>,
+    mut diagnostics: Diagnostics,
+    effects: Query<(), With<ActiveGameplayEffect>>,
+    modifiers: Query<(), With<AttributeModifier>>,
+    time: Res<Time<Real>>,
+) {
+    diagnostics.add_measurement(&GasDiagnosticsPlugin::ACTIVE_EFFECTS, || {
+        effects.iter().count() as f64
+    });
+    diagnostics.add_measurement(&GasDiagnosticsPlugin::MODIFIERS, || {
+        modifiers.iter().count() as f64
+    });
+    let delta = time.delta_secs_f64();
+    if delta > 0.0 {
+        let activations = std::mem::take(&mut state.activations);
+        diagnostics.add_measurement(&GasDiagnosticsPlugin::ACTIVATIONS_PER_SECOND, || {
+            f64::from(activations) / delta
+        });
+    }
+}
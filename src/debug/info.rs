@@ -0,0 +1,364 @@
+//! Read-only snapshots of an entity's GAS state for debug tools.
+//!
+//! [`GasDebug`] collects a [`GasEntityDebugInfo`] for an owner: its
+//! attributes with the modifiers applied to them, its active effects, its
+//! granted abilities and its tags. Debug UIs render the snapshot instead of
+//! querying GAS components themselves.
+
+use crate::abilities::components::AbilityActiveState;
+use crate::abilities::definition::AbilityRegistry;
+use crate::core::OwnedTags;
+use crate::effects::components::{
+    AttributeModifier, EffectDuration, EffectModifiers, EvaluationChannel, ModifierOperation,
+    PeriodicEffect,
+};
+use crate::utils::Gas;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use std::fmt;
+use string_cache::DefaultAtom as Atom;
+
+/// A modifier applied to an attribute, with the effect that created it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModifierDebugInfo {
+    /// The active effect entity that owns the modifier.
+    pub effect: Entity,
+    /// The definition ID of that effect.
+    pub effect_id: Atom,
+    /// The operation the modifier performs.
+    pub operation: ModifierOperation,
+    /// The magnitude of the modifier.
+    pub magnitude: f32,
+    /// The evaluation channel of the modifier.
+    pub channel: EvaluationChannel,
+}
+
+/// An attribute of an owner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeDebugInfo {
+    /// The attribute entity.
+    pub entity: Entity,
+    /// The attribute name.
+    pub name: Atom,
+    /// The base value.
+    pub base_value: f32,
+    /// The current value.
+    pub current_value: f32,
+    /// The modifiers applied to the attribute, in channel order.
+    pub modifiers: Vec<ModifierDebugInfo>,
+}
+
+/// An active effect on an owner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectDebugInfo {
+    /// The active effect entity.
+    pub entity: Entity,
+    /// The effect definition ID.
+    pub definition_id: Atom,
+    /// The entity that applied the effect.
+    pub source: Entity,
+    /// The effect level.
+    pub level: i32,
+    /// The stack count.
+    pub stack_count: i32,
+    /// Seconds left, or `None` for infinite effects.
+    pub remaining: Option<f32>,
+    /// Total duration, or `None` for infinite effects.
+    pub duration: Option<f32>,
+    /// Period of periodic effects.
+    pub period: Option<f32>,
+}
+
+/// A granted ability of an owner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbilityDebugInfo {
+    /// The ability spec entity.
+    pub entity: Entity,
+    /// The ability definition ID.
+    pub definition_id: Atom,
+    /// The ability level.
+    pub level: i32,
+    /// Whether an instance of the ability is running.
+    pub is_active: bool,
+    /// Number of running instances.
+    pub active_count: u8,
+    /// Seconds left on the ability's cooldown effect, if it is cooling down.
+    pub cooldown_remaining: Option<f32>,
+}
+
+/// Snapshot of the GAS state of one owner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GasEntityDebugInfo {
+    /// The owner entity.
+    pub entity: Entity,
+    /// The owner's `Name`, if it has one.
+    pub name: Option<String>,
+    /// Attributes, sorted by name.
+    pub attributes: Vec<AttributeDebugInfo>,
+    /// Active effects.
+    pub effects: Vec<EffectDebugInfo>,
+    /// Granted abilities.
+    pub abilities: Vec<AbilityDebugInfo>,
+    /// Explicit tags the owner has, sorted by name.
+    pub tags: Vec<GameplayTag>,
+}
+
+impl fmt::Display for GasEntityDebugInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => writeln!(f, "{} ({})", name, self.entity)?,
+            None => writeln!(f, "{}", self.entity)?,
+        }
+        writeln!(f, "  attributes:")?;
+        for attribute in &self.attributes {
+            writeln!(
+                f,
+                "    {} = {} (base {})",
+                attribute.name, attribute.current_value, attribute.base_value
+            )?;
+            for modifier in &attribute.modifiers {
+                writeln!(
+                    f,
+                    "      {:?} {:?} {} from {}",
+                    modifier.channel, modifier.operation, modifier.magnitude, modifier.effect_id
+                )?;
+            }
+        }
+        writeln!(f, "  effects:")?;
+        for effect in &self.effects {
+            write!(
+                f,
+                "    {} level {} x{}",
+                effect.definition_id, effect.level, effect.stack_count
+            )?;
+            match effect.remaining {
+                Some(remaining) => writeln!(f, " {remaining:.2}s left")?,
+                None => writeln!(f, " infinite")?,
+            }
+        }
+        writeln!(f, "  abilities:")?;
+        for ability in &self.abilities {
+            write!(f, "    {} level {}", ability.definition_id, ability.level)?;
+            if ability.is_active {
+                write!(f, " active x{}", ability.active_count)?;
+            }
+            match ability.cooldown_remaining {
+                Some(remaining) => writeln!(f, " cooldown {remaining:.2}s")?,
+                None => writeln!(f)?,
+            }
+        }
+        write!(f, "  tags:")?;
+        for tag in &self.tags {
+            write!(f, " {}", tag.get_tag_name())?;
+        }
+        Ok(())
+    }
+}
+
+/// System parameter collecting [`GasEntityDebugInfo`] snapshots.
+#[derive(SystemParam)]
+pub struct GasDebug<'w, 's> {
+    pub gas: Gas<'w, 's>,
+    pub durations: Query<'w, 's, &'static EffectDuration>,
+    pub periodic: Query<'w, 's, &'static PeriodicEffect>,
+    pub effect_modifiers: Query<'w, 's, &'static EffectModifiers>,
+    pub modifiers: Query<'w, 's, &'static AttributeModifier>,
+    pub active_states: Query<'w, 's, &'static AbilityActiveState>,
+    pub owners: Query<'w, 's, (Entity, Option<&'static Name>), With<OwnedTags>>,
+    pub ability_registry: Option<Res<'w, AbilityRegistry>>,
+}
+
+impl GasDebug<'_, '_> {
+    /// Returns the GAS owners (entities with `OwnedTags`) and their names.
+    pub fn owners(&self) -> impl Iterator<Item = (Entity, Option<&Name>)> + '_ {
+        self.owners.iter()
+    }
+
+    /// Returns a snapshot of the GAS state of `owner`.
+    pub fn info(&self, owner: Entity) -> GasEntityDebugInfo {
+        let effects: Vec<EffectDebugInfo> = self
+            .gas
+            .active_effects(owner)
+            .map(|(entity, effect)| {
+                let duration = self.durations.get(entity).ok();
+                EffectDebugInfo {
+                    entity,
+                    definition_id: effect.definition_id.clone(),
+                    source: effect.source,
+                    level: effect.level,
+                    stack_count: effect.stack_count,
+                    remaining: duration.map(|duration| duration.remaining),
+                    duration: duration.map(|duration| duration.total),
+                    period: self
+                        .periodic
+                        .get(entity)
+                        .ok()
+                        .map(|periodic| periodic.period),
+                }
+            })
+            .collect();
+
+        let mut attributes: Vec<AttributeDebugInfo> = self
+            .gas
+            .attributes(owner)
+            .map(|(entity, name, data)| AttributeDebugInfo {
+                entity,
+                name: name.0.clone(),
+                base_value: data.base_value,
+                current_value: data.current_value,
+                modifiers: Vec::new(),
+            })
+            .collect();
+        attributes.sort_by(|a, b| a.name.cmp(&b.name));
+        for effect in &effects {
+            let Ok(modifiers) = self.effect_modifiers.get(effect.entity) else {
+                continue;
+            };
+            for modifier in modifiers
+                .iter()
+                .filter_map(|modifier| self.modifiers.get(modifier).ok())
+            {
+                if let Some(attribute) = attributes
+                    .iter_mut()
+                    .find(|attribute| attribute.name == modifier.target_attribute)
+                {
+                    attribute.modifiers.push(ModifierDebugInfo {
+                        effect: effect.entity,
+                        effect_id: effect.definition_id.clone(),
+                        operation: modifier.operation,
+                        magnitude: modifier.magnitude,
+                        channel: modifier.channel,
+                    });
+                }
+            }
+        }
+        for attribute in &mut attributes {
+            attribute
+                .modifiers
+                .sort_by_key(|modifier| (modifier.channel, modifier.operation.priority()));
+        }
+
+        let abilities = self
+            .gas
+            .abilities(owner)
+            .map(|(entity, spec)| {
+                let state = self.active_states.get(entity).ok();
+                let cooldown_effect = self
+                    .ability_registry
+                    .as_ref()
+                    .and_then(|registry| registry.get(spec.definition_id.clone()))
+                    .and_then(|definition| definition.cooldown_effect.as_ref());
+                AbilityDebugInfo {
+                    entity,
+                    definition_id: spec.definition_id.clone(),
+                    level: spec.level,
+                    is_active: state.is_some_and(|state| state.is_active),
+                    active_count: state.map_or(0, |state| state.active_count),
+                    cooldown_remaining: cooldown_effect.and_then(|cooldown_effect| {
+                        effects
+                            .iter()
+                            .filter(|effect| &effect.definition_id == cooldown_effect)
+                            .filter_map(|effect| effect.remaining)
+                            .reduce(f32::max)
+                    }),
+                }
+            })
+            .collect();
+
+        let mut tags: Vec<GameplayTag> = self
+            .gas
+            .tags
+            .get(owner)
+            .map(|tags| tags.0.explicit_tags.gameplay_tags.clone())
+            .unwrap_or_default();
+        tags.sort_by(|a, b| a.get_tag_name().cmp(b.get_tag_name()));
+
+        GasEntityDebugInfo {
+            entity: owner,
+            name: self
+                .owners
+                .get(owner)
+                .ok()
+                .and_then(|(_, name)| name)
+                .map(|name| name.to_string()),
+            attributes,
+            effects,
+            abilities,
+            tags,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abilities::components::{AbilityOwner, AbilitySpec};
+    use crate::abilities::definition::AbilityDefinition;
+    use crate::attributes::components::{AttributeData, AttributeName};
+    use crate::effects::components::{ActiveGameplayEffect, EffectTarget, ModifierSource};
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_info_collects_modifiers_effects_and_cooldowns() {
+        let mut world = World::new();
+        let mut registry = AbilityRegistry::default();
+        registry.register(AbilityDefinition::new("fireball").with_cooldown_effect("fireball_cd"));
+        world.insert_resource(registry);
+
+        let owner = world.spawn(OwnedTags::default()).id();
+        let health = world
+            .spawn((
+                AttributeName::new("Health"),
+                AttributeData::new(100.0),
+                ChildOf(owner),
+            ))
+            .id();
+        let buff = world
+            .spawn((
+                ActiveGameplayEffect::new("fortify", owner, owner, 2, 0.0),
+                EffectTarget(owner),
+            ))
+            .id();
+        world.spawn((
+            AttributeModifier {
+                target_entity: owner,
+                target_attribute: "Health".into(),
+                operation: ModifierOperation::AddBase,
+                magnitude: 25.0,
+                channel: EvaluationChannel::Channel0,
+            },
+            ModifierSource(buff),
+        ));
+        world.spawn((
+            ActiveGameplayEffect::new("fireball_cd", owner, owner, 1, 0.0),
+            EffectTarget(owner),
+            EffectDuration {
+                remaining: 1.5,
+                total: 3.0,
+            },
+        ));
+        world.spawn((AbilitySpec::new("fireball", 1), AbilityOwner(owner)));
+
+        let info = world
+            .run_system_once(move |debug: GasDebug| debug.info(owner))
+            .unwrap();
+
+        assert_eq!(info.attributes.len(), 1);
+        assert_eq!(info.attributes[0].entity, health);
+        assert_eq!(info.attributes[0].modifiers.len(), 1);
+        assert_eq!(info.attributes[0].modifiers[0].effect, buff);
+        assert_eq!(info.attributes[0].modifiers[0].magnitude, 25.0);
+        assert_eq!(info.effects.len(), 2);
+        let cooldown = info
+            .effects
+            .iter()
+            .find(|effect| &*effect.definition_id == "fireball_cd")
+            .unwrap();
+        assert_eq!(cooldown.remaining, Some(1.5));
+        assert_eq!(info.abilities.len(), 1);
+        assert_eq!(info.abilities[0].cooldown_remaining, Some(1.5));
+        assert!(!info.abilities[0].is_active);
+        assert!(info.tags.is_empty());
+    }
+}
@@ -0,0 +1,168 @@
+//! egui debug window for GAS state, similar to Unreal's
+//! `showdebug abilitysystem`.
+//!
+//! Only compiled with the `egui` feature. Add [`GasInspectorPlugin`] after
+//! `EguiPlugin` and `GasPlugin`, then pick an owner in the window to see its
+//! attributes (with modifier breakdown), active effects, granted abilities
+//! and tags.
+
+use super::info::{GasDebug, GasEntityDebugInfo};
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+/// Plugin adding the GAS inspector window.
+///
+/// # Example
+///
+/// ```ignore
+/// app.add_plugins(EguiPlugin::default())
+///     .add_plugins(GasPlugin)
+///     .add_plugins(GasInspectorPlugin);
+/// ```
+pub struct GasInspectorPlugin;
+
+impl Plugin for GasInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GasInspector>()
+            .add_systems(EguiPrimaryContextPass, gas_inspector_ui_system);
+    }
+}
+
+/// Resource controlling the GAS inspector window.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct GasInspector {
+    /// Whether the window is shown.
+    pub open: bool,
+    /// The owner being inspected.
+    pub selected: Option<Entity>,
+}
+
+impl Default for GasInspector {
+    fn default() -> Self {
+        Self {
+            open: true,
+            selected: None,
+        }
+    }
+}
+
+/// Draws the GAS inspector window.
+pub fn gas_inspector_ui_system(
+    mut contexts: EguiContexts,
+    mut inspector: ResMut<GasInspector>,
+    debug: GasDebug,
+) -> Result {
+    if !inspector.open {
+        return Ok(());
+    }
+    let mut open = inspector.open;
+    egui::Window::new("Gameplay Ability System")
+        .open(&mut open)
+        .default_width(420.0)
+        .show(contexts.ctx_mut()?, |ui| {
+            let selected_text = inspector
+                .selected
+                .map_or_else(|| "None".to_string(), |entity| owner_label(&debug, entity));
+            egui::ComboBox::from_label("Owner")
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    for (entity, name) in debug.owners() {
+                        let label =
+                            name.map_or_else(|| entity.to_string(), |name| name.to_string());
+                        ui.selectable_value(&mut inspector.selected, Some(entity), label);
+                    }
+                });
+
+            let Some(selected) = inspector.selected else {
+                return;
+            };
+            if debug.owners.get(selected).is_err() {
+                inspector.selected = None;
+                return;
+            }
+            ui.separator();
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                show_info(ui, &debug.info(selected));
+            });
+        });
+    inspector.open = open;
+    Ok(())
+}
+
+fn owner_label(debug: &GasDebug, entity: Entity) -> String {
+    match debug.owners.get(entity) {
+        Ok((_, Some(name))) => name.to_string(),
+        _ => entity.to_string(),
+    }
+}
+
+fn show_info(ui: &mut egui::Ui, info: &GasEntityDebugInfo) {
+    egui::CollapsingHeader::new(format!("Attributes ({})", info.attributes.len()))
+        .default_open(true)
+        .show(ui, |ui| {
+            for attribute in &info.attributes {
+                ui.label(format!(
+                    "{}: {:.2} (base {:.2})",
+                    attribute.name, attribute.current_value, attribute.base_value
+                ));
+                ui.indent(attribute.entity, |ui| {
+                    for modifier in &attribute.modifiers {
+                        ui.weak(format!(
+                            "{:?} {:?} {:.2} from {}",
+                            modifier.channel,
+                            modifier.operation,
+                            modifier.magnitude,
+                            modifier.effect_id
+                        ));
+                    }
+                });
+            }
+        });
+
+    egui::CollapsingHeader::new(format!("Active effects ({})", info.effects.len()))
+        .default_open(true)
+        .show(ui, |ui| {
+            for effect in &info.effects {
+                let duration = match (effect.remaining, effect.duration) {
+                    (Some(remaining), Some(total)) => format!("{remaining:.1}s / {total:.1}s"),
+                    _ => "infinite".to_string(),
+                };
+                let period = effect
+                    .period
+                    .map_or_else(String::new, |period| format!(", every {period:.1}s"));
+                ui.label(format!(
+                    "{} (level {}, x{}) {duration}{period}",
+                    effect.definition_id, effect.level, effect.stack_count
+                ));
+            }
+        });
+
+    egui::CollapsingHeader::new(format!("Abilities ({})", info.abilities.len()))
+        .default_open(true)
+        .show(ui, |ui| {
+            for ability in &info.abilities {
+                let state = if ability.is_active {
+                    format!("active x{}", ability.active_count)
+                } else {
+                    "inactive".to_string()
+                };
+                let cooldown = ability
+                    .cooldown_remaining
+                    .map_or_else(String::new, |remaining| {
+                        format!(", cooldown {remaining:.1}s")
+                    });
+                ui.label(format!(
+                    "{} (level {}) {state}{cooldown}",
+                    ability.definition_id, ability.level
+                ));
+            }
+        });
+
+    egui::CollapsingHeader::new(format!("Tags ({})", info.tags.len()))
+        .default_open(true)
+        .show(ui, |ui| {
+            for tag in &info.tags {
+                ui.label(tag.get_tag_name());
+            }
+        });
+}
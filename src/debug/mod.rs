@@ -0,0 +1,29 @@
+//! Debug module.
+//!
+//! This module provides tools for inspecting GAS state at runtime.
+
+#[cfg(feature = "console")]
+pub mod console;
+pub mod diagnostics;
+pub mod info;
+#[cfg(feature = "egui")]
+pub mod inspector;
+#[cfg(feature = "debug_overlay")]
+pub mod overlay;
+#[cfg(feature = "serde")]
+pub mod state_dump;
+pub mod stats;
+pub mod timeline;
+
+#[cfg(feature = "console")]
+pub use console::*;
+pub use diagnostics::*;
+pub use info::*;
+#[cfg(feature = "egui")]
+pub use inspector::*;
+#[cfg(feature = "debug_overlay")]
+pub use overlay::*;
+#[cfg(feature = "serde")]
+pub use state_dump::*;
+pub use stats::*;
+pub use timeline::*;
@@ -0,0 +1,211 @@
+//! World-space debug overlay drawn with gizmos.
+//!
+//! Only compiled with the `debug_overlay` feature. [`GasDebugOverlayPlugin`]
+//! draws attribute bars and one colored pip per active effect above every GAS
+//! owner with a `GlobalTransform`. Each effect ID always gets the same pip
+//! color. Toggle and configure it through the [`GasDebugOverlay`] resource.
+
+use crate::core::OwnedTags;
+use crate::utils::Gas;
+use bevy::prelude::*;
+use std::hash::{Hash, Hasher};
+use string_cache::DefaultAtom as Atom;
+
+/// Plugin drawing the GAS debug overlay.
+///
+/// # Example
+///
+/// ```ignore
+/// app.add_plugins(GasPlugin)
+///     .add_plugins(GasDebugOverlayPlugin)
+///     .insert_resource(GasDebugOverlay::default().with_bar(
+///         OverlayBar::new("Stamina", Color::srgb(0.9, 0.8, 0.1)).with_max(100.0),
+///     ));
+/// ```
+pub struct GasDebugOverlayPlugin;
+
+impl Plugin for GasDebugOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GasDebugOverlay>()
+            .add_systems(Last, draw_gas_debug_overlay_system);
+    }
+}
+
+/// An attribute drawn as a bar by the overlay.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlayBar {
+    /// Attribute whose current value fills the bar.
+    pub attribute: Atom,
+    /// Attribute holding the bar's maximum.
+    pub max_attribute: Option<Atom>,
+    /// Maximum used when `max_attribute` is unset or missing.
+    pub max: f32,
+    /// Fill color.
+    pub color: Color,
+}
+
+impl OverlayBar {
+    /// Creates a bar for `attribute` with a maximum of 100.
+    pub fn new(attribute: impl Into<Atom>, color: Color) -> Self {
+        Self {
+            attribute: attribute.into(),
+            max_attribute: None,
+            max: 100.0,
+            color,
+        }
+    }
+
+    /// Reads the maximum from another attribute.
+    pub fn with_max_attribute(mut self, max_attribute: impl Into<Atom>) -> Self {
+        self.max_attribute = Some(max_attribute.into());
+        self
+    }
+
+    /// Sets a fixed maximum.
+    pub fn with_max(mut self, max: f32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Returns the filled fraction for `current` out of `max`, in `0..=1`.
+    pub fn fill(current: f32, max: f32) -> f32 {
+        if max <= 0.0 {
+            0.0
+        } else {
+            (current / max).clamp(0.0, 1.0)
+        }
+    }
+}
+
+/// Resource controlling the GAS debug overlay.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct GasDebugOverlay {
+    /// Whether the overlay is drawn.
+    pub enabled: bool,
+    /// Bars drawn per owner, top to bottom. Owners without the attribute skip
+    /// the bar.
+    pub bars: Vec<OverlayBar>,
+    /// Whether a pip is drawn per active effect.
+    pub show_effects: bool,
+    /// Offset of the top bar from the owner's position.
+    pub offset: Vec3,
+    /// Width and height of a bar, in world units.
+    pub bar_size: Vec2,
+}
+
+impl Default for GasDebugOverlay {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            bars: vec![
+                OverlayBar::new("Health", Color::srgb(0.85, 0.15, 0.15))
+                    .with_max_attribute("MaxHealth"),
+                OverlayBar::new("Mana", Color::srgb(0.2, 0.4, 0.95)).with_max_attribute("MaxMana"),
+            ],
+            show_effects: true,
+            offset: Vec3::new(0.0, 2.2, 0.0),
+            bar_size: Vec2::new(1.2, 0.12),
+        }
+    }
+}
+
+impl GasDebugOverlay {
+    /// Adds a bar below the existing ones.
+    pub fn with_bar(mut self, bar: OverlayBar) -> Self {
+        self.bars.push(bar);
+        self
+    }
+
+    /// Sets the offset from the owner's position.
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the bar size.
+    pub fn with_bar_size(mut self, bar_size: Vec2) -> Self {
+        self.bar_size = bar_size;
+        self
+    }
+}
+
+/// Returns the pip color of an effect ID.
+pub fn effect_debug_color(effect_id: &str) -> Color {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    effect_id.hash(&mut hasher);
+    Color::hsl((hasher.finish() % 360) as f32, 0.75, 0.55)
+}
+
+/// Draws the overlay, facing the first active camera.
+pub fn draw_gas_debug_overlay_system(
+    mut gizmos: Gizmos,
+    overlay: Res<GasDebugOverlay>,
+    gas: Gas,
+    owners: Query<(Entity, &GlobalTransform), With<OwnedTags>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+) {
+    if !overlay.enabled {
+        return;
+    }
+    let (right, up, rotation) = cameras.iter().find(|(camera, _)| camera.is_active).map_or(
+        (Vec3::X, Vec3::Y, Quat::IDENTITY),
+        |(_, transform)| {
+            (
+                transform.right().into(),
+                transform.up().into(),
+                transform.rotation(),
+            )
+        },
+    );
+    let half_width = overlay.bar_size.x * 0.5;
+    let row_height = overlay.bar_size.y * 1.5;
+    let background = Color::srgba(0.0, 0.0, 0.0, 0.6);
+
+    for (owner, transform) in &owners {
+        let mut row = transform.translation() + overlay.offset;
+        for bar in &overlay.bars {
+            let Some(current) = gas.attribute_value(owner, &bar.attribute) else {
+                continue;
+            };
+            let max = bar
+                .max_attribute
+                .as_ref()
+                .and_then(|max_attribute| gas.attribute_value(owner, max_attribute))
+                .unwrap_or(bar.max);
+            let left = row - right * half_width;
+            gizmos.rect(Isometry3d::new(row, rotation), overlay.bar_size, background);
+            let fill = OverlayBar::fill(current, max) * overlay.bar_size.x;
+            if fill > 0.0 {
+                gizmos.line(left, left + right * fill, bar.color);
+            }
+            row -= up * row_height;
+        }
+
+        if overlay.show_effects {
+            let pip = overlay.bar_size.y;
+            let mut position = row - right * (half_width - pip * 0.5);
+            for (_, effect) in gas.active_effects(owner) {
+                gizmos.rect(
+                    Isometry3d::new(position, rotation),
+                    Vec2::splat(pip),
+                    effect_debug_color(&effect.definition_id),
+                );
+                position += right * pip * 1.5;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_fill_and_effect_colors() {
+        assert_eq!(OverlayBar::fill(50.0, 200.0), 0.25);
+        assert_eq!(OverlayBar::fill(250.0, 200.0), 1.0);
+        assert_eq!(OverlayBar::fill(-5.0, 200.0), 0.0);
+        assert_eq!(OverlayBar::fill(5.0, 0.0), 0.0);
+        assert_eq!(effect_debug_color("burn"), effect_debug_color("burn"));
+    }
+}
@@ -0,0 +1,360 @@
+//! JSON dumps of the whole GAS state and diffs between them.
+//!
+//! Only compiled with the `serde` feature. [`dump_gas_state`] turns every GAS
+//! owner's attributes, active effects, abilities and tags into a
+//! [`serde_json::Value`] that can be pasted into a bug report or compared
+//! against a golden file. To see what changed between two frames, capture a
+//! [`GasWorldState`] in each and [`diff`](GasWorldState::diff) them.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let before = GasWorldState::capture(app.world_mut());
+//! app.update();
+//! let after = GasWorldState::capture(app.world_mut());
+//! for change in before.diff(&after) {
+//!     println!("{change}");
+//! }
+//! ```
+
+use super::info::{GasDebug, GasEntityDebugInfo};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+use std::fmt;
+use string_cache::DefaultAtom as Atom;
+
+/// Returns the GAS state of every owner as JSON.
+///
+/// The result is an object keyed by owner entity (`"12v1"`), holding the
+/// owner's `name`, its `attributes` keyed by name, its `effects` and
+/// `abilities` keyed by entity, and its `tags`. Keys are sorted, so equal
+/// states produce equal dumps.
+pub fn dump_gas_state(world: &mut World) -> Value {
+    GasWorldState::capture(world).to_json()
+}
+
+/// The GAS state of every owner at one point in time.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GasWorldState {
+    /// Owners, sorted by entity.
+    pub owners: Vec<GasEntityDebugInfo>,
+}
+
+impl GasWorldState {
+    /// Captures the state of every GAS owner (entity with `OwnedTags`).
+    pub fn capture(world: &mut World) -> Self {
+        let owners = world
+            .run_system_once(|debug: GasDebug| {
+                let mut owners: Vec<Entity> = debug.owners().map(|(owner, _)| owner).collect();
+                owners.sort();
+                owners
+                    .into_iter()
+                    .map(|owner| debug.info(owner))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        Self { owners }
+    }
+
+    /// Returns the snapshot as JSON, in the format of [`dump_gas_state`].
+    pub fn to_json(&self) -> Value {
+        let owners = self
+            .owners
+            .iter()
+            .map(|owner| {
+                let attributes: Map<String, Value> = owner
+                    .attributes
+                    .iter()
+                    .map(|attribute| {
+                        (
+                            attribute.name.to_string(),
+                            json!({
+                                "base": attribute.base_value,
+                                "current": attribute.current_value,
+                            }),
+                        )
+                    })
+                    .collect();
+                let effects: Map<String, Value> = owner
+                    .effects
+                    .iter()
+                    .map(|effect| {
+                        (
+                            effect.entity.to_string(),
+                            json!({
+                                "id": &*effect.definition_id,
+                                "source": effect.source.to_string(),
+                                "level": effect.level,
+                                "stack_count": effect.stack_count,
+                                "remaining": effect.remaining,
+                            }),
+                        )
+                    })
+                    .collect();
+                let abilities: Map<String, Value> = owner
+                    .abilities
+                    .iter()
+                    .map(|ability| {
+                        (
+                            ability.entity.to_string(),
+                            json!({
+                                "id": &*ability.definition_id,
+                                "level": ability.level,
+                                "active": ability.is_active,
+                                "active_count": ability.active_count,
+                                "cooldown_remaining": ability.cooldown_remaining,
+                            }),
+                        )
+                    })
+                    .collect();
+                let tags: Vec<&str> = owner.tags.iter().map(|tag| tag.get_tag_name()).collect();
+                (
+                    owner.entity.to_string(),
+                    json!({
+                        "name": owner.name,
+                        "attributes": attributes,
+                        "effects": effects,
+                        "abilities": abilities,
+                        "tags": tags,
+                    }),
+                )
+            })
+            .collect::<Map<String, Value>>();
+        Value::Object(owners)
+    }
+
+    /// Returns what changed from this snapshot to `next`.
+    ///
+    /// Effect `remaining` times and cooldowns are not reported; they change
+    /// every frame.
+    pub fn diff(&self, next: &GasWorldState) -> Vec<GasStateChange> {
+        let mut changes = Vec::new();
+        for old in &self.owners {
+            if !next.owners.iter().any(|new| new.entity == old.entity) {
+                changes.push(GasStateChange::OwnerRemoved { owner: old.entity });
+            }
+        }
+        for new in &next.owners {
+            match self.owners.iter().find(|old| old.entity == new.entity) {
+                Some(old) => diff_owner(old, new, &mut changes),
+                None => {
+                    changes.push(GasStateChange::OwnerAdded { owner: new.entity });
+                    diff_owner(
+                        &GasEntityDebugInfo {
+                            entity: new.entity,
+                            name: new.name.clone(),
+                            attributes: Vec::new(),
+                            effects: Vec::new(),
+                            abilities: Vec::new(),
+                            tags: Vec::new(),
+                        },
+                        new,
+                        &mut changes,
+                    );
+                }
+            }
+        }
+        changes
+    }
+}
+
+fn diff_owner(
+    old: &GasEntityDebugInfo,
+    new: &GasEntityDebugInfo,
+    changes: &mut Vec<GasStateChange>,
+) {
+    let owner = new.entity;
+    for attribute in &new.attributes {
+        let (old_base, old_current) = old
+            .attributes
+            .iter()
+            .find(|old| old.name == attribute.name)
+            .map_or((0.0, 0.0), |old| (old.base_value, old.current_value));
+        if old_base != attribute.base_value || old_current != attribute.current_value {
+            changes.push(GasStateChange::AttributeChanged {
+                owner,
+                attribute: attribute.name.clone(),
+                old_base,
+                new_base: attribute.base_value,
+                old_current,
+                new_current: attribute.current_value,
+            });
+        }
+    }
+
+    for effect in &old.effects {
+        if !new.effects.iter().any(|new| new.entity == effect.entity) {
+            changes.push(GasStateChange::EffectRemoved {
+                owner,
+                effect: effect.entity,
+                effect_id: effect.definition_id.clone(),
+            });
+        }
+    }
+    for effect in &new.effects {
+        match old.effects.iter().find(|old| old.entity == effect.entity) {
+            Some(old) if old.stack_count != effect.stack_count => {
+                changes.push(GasStateChange::EffectStackChanged {
+                    owner,
+                    effect: effect.entity,
+                    effect_id: effect.definition_id.clone(),
+                    old_stack_count: old.stack_count,
+                    new_stack_count: effect.stack_count,
+                });
+            }
+            Some(_) => {}
+            None => changes.push(GasStateChange::EffectAdded {
+                owner,
+                effect: effect.entity,
+                effect_id: effect.definition_id.clone(),
+            }),
+        }
+    }
+
+    for ability in &new.abilities {
+        let was_active = old
+            .abilities
+            .iter()
+            .find(|old| old.entity == ability.entity)
+            .is_some_and(|old| old.is_active);
+        if was_active != ability.is_active {
+            changes.push(GasStateChange::AbilityStateChanged {
+                owner,
+                ability: ability.entity,
+                ability_id: ability.definition_id.clone(),
+                is_active: ability.is_active,
+            });
+        }
+    }
+
+    for tag in &old.tags {
+        if !new.tags.contains(tag) {
+            changes.push(GasStateChange::TagRemoved {
+                owner,
+                tag: tag.get_tag_name().to_string(),
+            });
+        }
+    }
+    for tag in &new.tags {
+        if !old.tags.contains(tag) {
+            changes.push(GasStateChange::TagAdded {
+                owner,
+                tag: tag.get_tag_name().to_string(),
+            });
+        }
+    }
+}
+
+/// One difference between two [`GasWorldState`]s.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum GasStateChange {
+    /// An entity became a GAS owner.
+    OwnerAdded { owner: Entity },
+    /// A GAS owner was despawned or lost its `OwnedTags`.
+    OwnerRemoved { owner: Entity },
+    /// An attribute's base or current value changed. Attributes that appeared
+    /// are reported as changing from zero.
+    AttributeChanged {
+        owner: Entity,
+        attribute: Atom,
+        old_base: f32,
+        new_base: f32,
+        old_current: f32,
+        new_current: f32,
+    },
+    /// An effect started targeting the owner.
+    EffectAdded {
+        owner: Entity,
+        effect: Entity,
+        effect_id: Atom,
+    },
+    /// An effect stopped targeting the owner.
+    EffectRemoved {
+        owner: Entity,
+        effect: Entity,
+        effect_id: Atom,
+    },
+    /// An effect's stack count changed.
+    EffectStackChanged {
+        owner: Entity,
+        effect: Entity,
+        effect_id: Atom,
+        old_stack_count: i32,
+        new_stack_count: i32,
+    },
+    /// An ability became active or inactive.
+    AbilityStateChanged {
+        owner: Entity,
+        ability: Entity,
+        ability_id: Atom,
+        is_active: bool,
+    },
+    /// The owner gained a tag.
+    TagAdded { owner: Entity, tag: String },
+    /// The owner lost a tag.
+    TagRemoved { owner: Entity, tag: String },
+}
+
+impl fmt::Display for GasStateChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GasStateChange::OwnerAdded { owner } => write!(f, "{}: added", owner),
+            GasStateChange::OwnerRemoved { owner } => write!(f, "{}: removed", owner),
+            GasStateChange::AttributeChanged {
+                owner,
+                attribute,
+                old_base,
+                new_base,
+                old_current,
+                new_current,
+            } => write!(
+                f,
+                "{}: {} {} -> {} (base {} -> {}, delta {:+})",
+                owner,
+                attribute,
+                old_current,
+                new_current,
+                old_base,
+                new_base,
+                new_current - old_current
+            ),
+            GasStateChange::EffectAdded {
+                owner,
+                effect,
+                effect_id,
+            } => write!(f, "{}: + effect {} ({})", owner, effect_id, effect),
+            GasStateChange::EffectRemoved {
+                owner,
+                effect,
+                effect_id,
+            } => write!(f, "{}: - effect {} ({})", owner, effect_id, effect),
+            GasStateChange::EffectStackChanged {
+                owner,
+                effect_id,
+                old_stack_count,
+                new_stack_count,
+                ..
+            } => write!(
+                f,
+                "{}: effect {} stacks {} -> {}",
+                owner, effect_id, old_stack_count, new_stack_count
+            ),
+            GasStateChange::AbilityStateChanged {
+                owner,
+                ability_id,
+                is_active,
+                ..
+            } => write!(
+                f,
+                "{}: ability {} {}",
+                owner,
+                ability_id,
+                if *is_active { "activated" } else { "ended" }
+            ),
+            GasStateChange::TagAdded { owner, tag } => write!(f, "{}: + tag {}", owner, tag),
+            GasStateChange::TagRemoved { owner, tag } => write!(f, "{}: - tag {}", owner, tag),
+        }
+    }
+}
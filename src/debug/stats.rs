@@ -0,0 +1,151 @@
+//! Running GAS statistics.
+//!
+//! [`GasStatsPlugin`] keeps [`GasStats`] up to date with observers: active
+//! effects per definition, ability activations per ability and rejected
+//! effect applications per reason. Reading it costs nothing per frame, so it
+//! can feed a balancing dashboard, and an active count that only ever grows
+//! points at an effect that never expires.
+
+use crate::abilities::components::AbilitySpec;
+use crate::abilities::systems::AbilityActivatedEvent;
+use crate::effects::components::ActiveGameplayEffect;
+use crate::effects::systems::{EffectRejectionReason, GameplayEffectRejectedEvent};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use string_cache::DefaultAtom as Atom;
+
+/// Plugin maintaining [`GasStats`].
+pub struct GasStatsPlugin;
+
+impl Plugin for GasStatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GasStats>()
+            .add_observer(count_effect_added)
+            .add_observer(count_effect_removed)
+            .add_observer(count_ability_activated)
+            .add_observer(count_effect_rejected);
+    }
+}
+
+/// Counts of GAS activity since startup or the last [`GasStats::reset`].
+#[derive(Resource, Debug, Clone, Default)]
+pub struct GasStats {
+    active_effects: HashMap<Atom, usize>,
+    effects_applied: HashMap<Atom, u64>,
+    activations: HashMap<Atom, u64>,
+    rejections: HashMap<EffectRejectionReason, u64>,
+}
+
+impl GasStats {
+    /// Returns how many effects of `effect_id` are active.
+    pub fn active_effects(&self, effect_id: &str) -> usize {
+        self.active_effects
+            .get(&Atom::from(effect_id))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns how many effect entities of `effect_id` were spawned.
+    /// Reapplications that refresh or stack an existing effect don't count.
+    pub fn effects_applied(&self, effect_id: &str) -> u64 {
+        self.effects_applied
+            .get(&Atom::from(effect_id))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns how many times `ability_id` was activated.
+    pub fn activations(&self, ability_id: &str) -> u64 {
+        self.activations
+            .get(&Atom::from(ability_id))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns how many effect applications were rejected for `reason`.
+    pub fn rejections(&self, reason: EffectRejectionReason) -> u64 {
+        self.rejections.get(&reason).copied().unwrap_or(0)
+    }
+
+    /// Returns the active effect count of every definition with active
+    /// effects.
+    pub fn iter_active_effects(&self) -> impl Iterator<Item = (&Atom, usize)> {
+        self.active_effects
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(id, count)| (id, *count))
+    }
+
+    /// Returns the activation count of every activated ability.
+    pub fn iter_activations(&self) -> impl Iterator<Item = (&Atom, u64)> {
+        self.activations.iter().map(|(id, count)| (id, *count))
+    }
+
+    /// Returns the rejection count of every reason that occurred.
+    pub fn iter_rejections(&self) -> impl Iterator<Item = (EffectRejectionReason, u64)> + '_ {
+        self.rejections
+            .iter()
+            .map(|(reason, count)| (*reason, *count))
+    }
+
+    /// Clears the applied, activation and rejection counts. Active effect
+    /// counts are kept, since those effects are still active.
+    pub fn reset(&mut self) {
+        self.effects_applied.clear();
+        self.activations.clear();
+        self.rejections.clear();
+    }
+}
+
+/// Observer counting spawned active effects.
+pub fn count_effect_added(
+    ev: On<Add, ActiveGameplayEffect>,
+    effects: Query<&ActiveGameplayEffect>,
+    mut stats: ResMut<GasStats>,
+) {
+    let Ok(effect) = effects.get(ev.event_target()) else {
+        return;
+    };
+    *stats
+        .active_effects
+        .entry(effect.definition_id.clone())
+        .or_default() += 1;
+    *stats
+        .effects_applied
+        .entry(effect.definition_id.clone())
+        .or_default() += 1;
+}
+
+/// Observer counting removed active effects.
+pub fn count_effect_removed(
+    ev: On<Remove, ActiveGameplayEffect>,
+    effects: Query<&ActiveGameplayEffect>,
+    mut stats: ResMut<GasStats>,
+) {
+    let Ok(effect) = effects.get(ev.event_target()) else {
+        return;
+    };
+    if let Some(count) = stats.active_effects.get_mut(&effect.definition_id) {
+        *count = count.saturating_sub(1);
+    }
+}
+
+/// Observer counting ability activations.
+pub fn count_ability_activated(
+    ev: On<AbilityActivatedEvent>,
+    specs: Query<&AbilitySpec>,
+    mut stats: ResMut<GasStats>,
+) {
+    let Ok(spec) = specs.get(ev.ability_spec) else {
+        return;
+    };
+    *stats
+        .activations
+        .entry(spec.definition_id.clone())
+        .or_default() += 1;
+}
+
+/// Observer counting rejected effect applications.
+pub fn count_effect_rejected(ev: On<GameplayEffectRejectedEvent>, mut stats: ResMut<GasStats>) {
+    *stats.rejections.entry(ev.reason).or_default() += 1;
+}
@@ -0,0 +1,352 @@
+//! Event timeline recorder for debugging.
+//!
+//! [`GasTimelinePlugin`] records every effect application and removal,
+//! ability activation, failed activation and end, attribute change and cue
+//! trigger into the [`GasTimeline`] ring buffer, stamped with the app's
+//! elapsed time and the instigator when there is one. Read it per entity with
+//! [`GasTimeline::for_entity`] to answer questions like "why did my buff fall
+//! off", or dump it with `GasTimeline::to_json` (`serde` feature).
+
+use crate::abilities::components::AbilitySpecInstance;
+use crate::abilities::systems::{
+    AbilityActivatedEvent, AbilityActivationFailedEvent, ActivationFailureReason,
+    OnGameplayAbilityEnded,
+};
+use crate::attributes::components::{AttributeData, AttributeName};
+use crate::core::system_sets::{GasSystemSet, configure_gas_system_sets};
+use crate::core::timestep::GasTickMode;
+use crate::cues::manager::GameplayCueEvent;
+use crate::cues::systems::{TriggerGameplayCueEvent, TriggerGameplayCueOnEntityEvent};
+use crate::effects::components::ActiveGameplayEffect;
+use crate::effects::systems::{GameplayEffectAppliedEvent, GameplayEffectRemovedEvent};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use string_cache::DefaultAtom as Atom;
+
+/// Plugin recording GAS events into [`GasTimeline`].
+///
+/// # Example
+///
+/// ```ignore
+/// app.add_plugins(GasPlugin)
+///     .add_plugins(GasTimelinePlugin)
+///     .insert_resource(GasTimeline::with_capacity(4096));
+/// ```
+pub struct GasTimelinePlugin;
+
+impl Plugin for GasTimelinePlugin {
+    fn build(&self, app: &mut App) {
+        let simulation_schedule = GasTickMode::of(app).simulation_schedule();
+        configure_gas_system_sets(app);
+        app.init_resource::<GasTimeline>()
+            .add_observer(record_effect_applied)
+            .add_observer(record_effect_removed)
+            .add_observer(record_ability_activated)
+            .add_observer(record_ability_activation_failed)
+            .add_observer(record_ability_ended)
+            .add_observer(record_cue_triggered)
+            .add_observer(record_cue_triggered_on_entity)
+            .add_systems(
+                simulation_schedule,
+                record_attribute_changes_system.in_set(GasSystemSet::Cleanup),
+            );
+    }
+}
+
+/// What happened in a [`TimelineRecord`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TimelineEventKind {
+    /// An effect was applied.
+    EffectApplied { effect: Entity, effect_id: Atom },
+    /// An active effect was removed.
+    EffectRemoved { effect: Entity, effect_id: Atom },
+    /// An ability was activated.
+    AbilityActivated {
+        ability_spec: Entity,
+        instance: Option<Entity>,
+    },
+    /// An ability failed to activate.
+    AbilityActivationFailed {
+        ability_spec: Entity,
+        reason: ActivationFailureReason,
+    },
+    /// An ability instance ended.
+    AbilityEnded {
+        ability_spec: Entity,
+        instance: Entity,
+        was_cancelled: bool,
+    },
+    /// An attribute's base or current value changed.
+    AttributeChanged {
+        attribute: Atom,
+        old_base: f32,
+        new_base: f32,
+        old_current: f32,
+        new_current: f32,
+    },
+    /// A gameplay cue was triggered.
+    CueTriggered {
+        cue_tag: String,
+        event_type: GameplayCueEvent,
+    },
+}
+
+/// One recorded GAS event.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TimelineRecord {
+    /// Elapsed app time in seconds when the event happened.
+    pub time: f32,
+    /// The entity the event happened to (effect target, ability owner,
+    /// attribute owner or cue target).
+    pub entity: Option<Entity>,
+    /// The entity that caused the event, if known.
+    pub instigator: Option<Entity>,
+    /// What happened.
+    pub kind: TimelineEventKind,
+}
+
+/// Ring buffer of recorded GAS events, oldest first.
+#[derive(Resource, Debug, Clone)]
+pub struct GasTimeline {
+    /// Whether events are recorded.
+    pub enabled: bool,
+    /// Maximum records kept; the oldest are dropped first.
+    pub capacity: usize,
+    records: VecDeque<TimelineRecord>,
+    attribute_values: HashMap<Entity, (f32, f32)>,
+}
+
+impl Default for GasTimeline {
+    fn default() -> Self {
+        Self::with_capacity(1024)
+    }
+}
+
+impl GasTimeline {
+    /// Creates an enabled timeline keeping up to `capacity` records.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            enabled: true,
+            capacity,
+            records: VecDeque::new(),
+            attribute_values: HashMap::default(),
+        }
+    }
+
+    /// Records an event, dropping the oldest one when full.
+    pub fn record(&mut self, record: TimelineRecord) {
+        if !self.enabled || self.capacity == 0 {
+            return;
+        }
+        if self.records.len() >= self.capacity {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+
+    /// Returns all records, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &TimelineRecord> {
+        self.records.iter()
+    }
+
+    /// Returns the records that happened to or were caused by `entity`.
+    pub fn for_entity(&self, entity: Entity) -> impl Iterator<Item = &TimelineRecord> {
+        self.records.iter().filter(move |record| {
+            record.entity == Some(entity) || record.instigator == Some(entity)
+        })
+    }
+
+    /// Returns the number of records.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns whether there are no records.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Removes all records.
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+
+    /// Serializes the records, oldest first, as a JSON array.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.records).unwrap_or_default()
+    }
+}
+
+fn elapsed(time: &Option<Res<Time>>) -> f32 {
+    time.as_ref().map_or(0.0, |time| time.elapsed_secs())
+}
+
+/// Observer recording effect applications.
+pub fn record_effect_applied(
+    ev: On<GameplayEffectAppliedEvent>,
+    mut timeline: ResMut<GasTimeline>,
+    effects: Query<&ActiveGameplayEffect>,
+    time: Option<Res<Time>>,
+) {
+    timeline.record(TimelineRecord {
+        time: elapsed(&time),
+        entity: Some(ev.target),
+        instigator: effects.get(ev.effect).ok().map(|effect| effect.source),
+        kind: TimelineEventKind::EffectApplied {
+            effect: ev.effect,
+            effect_id: ev.effect_id.clone(),
+        },
+    });
+}
+
+/// Observer recording effect removals.
+pub fn record_effect_removed(
+    ev: On<GameplayEffectRemovedEvent>,
+    mut timeline: ResMut<GasTimeline>,
+    effects: Query<&ActiveGameplayEffect>,
+    time: Option<Res<Time>>,
+) {
+    timeline.record(TimelineRecord {
+        time: elapsed(&time),
+        entity: Some(ev.target),
+        instigator: effects.get(ev.effect).ok().map(|effect| effect.source),
+        kind: TimelineEventKind::EffectRemoved {
+            effect: ev.effect,
+            effect_id: ev.effect_id.clone(),
+        },
+    });
+}
+
+/// Observer recording ability activations.
+pub fn record_ability_activated(
+    ev: On<AbilityActivatedEvent>,
+    mut timeline: ResMut<GasTimeline>,
+    time: Option<Res<Time>>,
+) {
+    timeline.record(TimelineRecord {
+        time: elapsed(&time),
+        entity: Some(ev.owner),
+        instigator: None,
+        kind: TimelineEventKind::AbilityActivated {
+            ability_spec: ev.ability_spec,
+            instance: ev.instance,
+        },
+    });
+}
+
+/// Observer recording failed ability activations.
+pub fn record_ability_activation_failed(
+    ev: On<AbilityActivationFailedEvent>,
+    mut timeline: ResMut<GasTimeline>,
+    time: Option<Res<Time>>,
+) {
+    timeline.record(TimelineRecord {
+        time: elapsed(&time),
+        entity: Some(ev.owner),
+        instigator: None,
+        kind: TimelineEventKind::AbilityActivationFailed {
+            ability_spec: ev.ability_spec,
+            reason: ev.reason,
+        },
+    });
+}
+
+/// Observer recording ended ability instances.
+pub fn record_ability_ended(
+    ev: On<OnGameplayAbilityEnded>,
+    mut timeline: ResMut<GasTimeline>,
+    instances: Query<(&AbilitySpecInstance, &ChildOf)>,
+    time: Option<Res<Time>>,
+) {
+    let Ok((instance, child_of)) = instances.get(ev.ability_instance) else {
+        return;
+    };
+    timeline.record(TimelineRecord {
+        time: elapsed(&time),
+        entity: Some(instance.owner),
+        instigator: instance.instigator,
+        kind: TimelineEventKind::AbilityEnded {
+            ability_spec: child_of.parent(),
+            instance: ev.ability_instance,
+            was_cancelled: ev.was_cancelled,
+        },
+    });
+}
+
+/// Observer recording cue triggers.
+pub fn record_cue_triggered(
+    ev: On<TriggerGameplayCueEvent>,
+    mut timeline: ResMut<GasTimeline>,
+    time: Option<Res<Time>>,
+) {
+    timeline.record(TimelineRecord {
+        time: elapsed(&time),
+        entity: ev.parameters.target,
+        instigator: ev.parameters.instigator,
+        kind: TimelineEventKind::CueTriggered {
+            cue_tag: ev.cue_tag.get_tag_name().to_string(),
+            event_type: ev.event_type,
+        },
+    });
+}
+
+/// Observer recording entity cue triggers.
+pub fn record_cue_triggered_on_entity(
+    ev: On<TriggerGameplayCueOnEntityEvent>,
+    mut timeline: ResMut<GasTimeline>,
+    time: Option<Res<Time>>,
+) {
+    timeline.record(TimelineRecord {
+        time: elapsed(&time),
+        entity: Some(ev.target),
+        instigator: ev.parameters.instigator,
+        kind: TimelineEventKind::CueTriggered {
+            cue_tag: ev.cue_tag.get_tag_name().to_string(),
+            event_type: ev.event_type,
+        },
+    });
+}
+
+/// System recording attribute value changes since the last run.
+///
+/// The first value seen for an attribute is its starting point and is not
+/// recorded.
+pub fn record_attribute_changes_system(
+    mut timeline: ResMut<GasTimeline>,
+    attributes: Query<(Entity, &AttributeName, &AttributeData, &ChildOf), Changed<AttributeData>>,
+    mut removed: RemovedComponents<AttributeData>,
+    time: Option<Res<Time>>,
+) {
+    for attribute in removed.read() {
+        timeline.attribute_values.remove(&attribute);
+    }
+    if !timeline.enabled {
+        return;
+    }
+    let now = elapsed(&time);
+    for (attribute, name, data, child_of) in &attributes {
+        let new = (data.base_value, data.current_value);
+        let Some(old) = timeline.attribute_values.insert(attribute, new) else {
+            continue;
+        };
+        if old == new {
+            continue;
+        }
+        timeline.record(TimelineRecord {
+            time: now,
+            entity: Some(child_of.parent()),
+            instigator: None,
+            kind: TimelineEventKind::AttributeChanged {
+                attribute: name.0.clone(),
+                old_base: old.0,
+                new_base: new.0,
+                old_current: old.1,
+                new_current: new.1,
+            },
+        });
+    }
+}
@@ -6,19 +6,40 @@ use super::components::*;
 use super::definition::*;
 use super::systems::GameplayEffectRemovedEvent;
 use crate::abilities::{AbilityOwner, AbilityRegistry, AbilitySpec};
+use crate::core::{AbilityHandle, HandleGenerations};
+use crate::error::GasReportExt;
 use bevy::prelude::*;
+use string_cache::DefaultAtom as Atom;
 
 /// Component that tracks abilities granted by an effect.
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug, Clone)]
 pub struct GrantedAbilities {
     /// List of ability entities granted by this effect.
+    #[entities]
     pub granted_ability_entities: Vec<Entity>,
 }
 
+/// Event triggered for each ability an effect grants, once its spec exists.
+#[derive(Event, Debug, Clone)]
+pub struct AbilityGrantedEvent {
+    /// The granted ability spec entity.
+    pub ability: Entity,
+    /// Handle to the granted ability spec.
+    pub handle: Option<AbilityHandle>,
+    /// The entity the ability was granted to.
+    pub owner: Entity,
+    /// The ability definition ID.
+    pub ability_id: Atom,
+    /// The effect that granted the ability.
+    pub effect: Entity,
+}
+
 /// System that grants abilities when effects are applied.
 ///
 /// This system runs after effects are applied and grants any abilities
-/// specified in the effect definition.
+/// specified in the effect definition, triggering an
+/// [`AbilityGrantedEvent`] for each.
 pub fn grant_abilities_from_effects_system(
     mut commands: Commands,
     registry: Res<GameplayEffectRegistry>,
@@ -42,11 +63,11 @@ pub fn grant_abilities_from_effects_system(
 
         // Grant each ability to the target
         for granted_config in &definition.granted_abilities {
-            if ability_registry.get(&granted_config.ability_id).is_none() {
-                warn!(
-                    "Ability definition not found: {}",
-                    granted_config.ability_id
-                );
+            if ability_registry
+                .try_get(granted_config.ability_id.clone())
+                .or_report(&mut commands)
+                .is_none()
+            {
                 continue;
             }
 
@@ -71,6 +92,19 @@ pub fn grant_abilities_from_effects_system(
                 .id();
 
             granted_entities.push(ability_entity);
+            let mut granted = AbilityGrantedEvent {
+                ability: ability_entity,
+                handle: None,
+                owner: effect_target.0,
+                ability_id: granted_config.ability_id.clone(),
+                effect: effect_entity,
+            };
+            commands.queue(move |world: &mut World| {
+                granted.handle = world
+                    .get_resource::<HandleGenerations<AbilitySpec>>()
+                    .and_then(|generations| generations.handle(ability_entity));
+                world.trigger(granted);
+            });
 
             info!(
                 "Granted ability '{}' to entity {:?} from effect '{}'",
@@ -86,9 +120,11 @@ pub fn grant_abilities_from_effects_system(
 }
 
 /// Component that marks an ability as granted by an effect.
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component, Debug, Clone)]
 pub struct GrantedByEffect {
     /// The effect entity that granted this ability.
+    #[entities]
     pub effect_entity: Entity,
     /// How to handle this ability when the effect is removed.
     pub removal_policy: AbilityRemovalPolicy,
@@ -4,6 +4,7 @@
 
 use crate::attributes::{AttributeData, AttributeName};
 use crate::core::OwnedTags;
+use crate::error::{GasError, GasResult};
 use bevy::prelude::*;
 use std::collections::HashMap;
 use string_cache::DefaultAtom as Atom;
@@ -111,4 +112,12 @@ impl ApplicationRequirementRegistry {
     pub fn get(&self, name: &Atom) -> Option<&dyn ApplicationRequirement> {
         self.requirements.get(name).map(|b| b.as_ref())
     }
+
+    /// Gets a requirement by name, or an error naming the missing requirement.
+    pub fn try_get(&self, name: &Atom) -> GasResult<&dyn ApplicationRequirement> {
+        self.get(name)
+            .ok_or_else(|| GasError::ApplicationRequirementNotFound {
+                requirement_name: name.clone(),
+            })
+    }
 }
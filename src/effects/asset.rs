@@ -0,0 +1,368 @@
+//! Loading effect definitions from asset files.
+//!
+//! With the `effect_assets` feature, `.effect.ron` and `.effect.json` files
+//! hold one [`GameplayEffectDefinition`] or a list of them, in the format
+//! produced by the `serde` feature. Loaded definitions are registered into the
+//! [`GameplayEffectRegistry`], and re-registered whenever the file changes, so
+//! with Bevy's `file_watcher` feature values can be tuned while the game runs.
+//!
+//! Older file versions are migrated before parsing, see
+//! [`migration`](crate::serialization::migration).
+//!
+//! Effect components, on-apply/on-remove callbacks and
+//! `application_tag_requirements` are not stored in files. When a file
+//! replaces a definition, components and callbacks the previous definition
+//! had are kept.
+//!
+//! # Example
+//!
+//! ```ignore
+//! // assets/effects/burning.effect.ron
+//! (
+//!     id: "burning",
+//!     duration_policy: HasDuration,
+//!     duration_magnitude: 6.0,
+//!     period: 1.0,
+//!     stacking_policy: Independent,
+//!     modifiers: [(
+//!         attribute_name: "Health",
+//!         operation: AddCurrent,
+//!         magnitude: ScalableFloat(base_value: -4.0, level_multiplier: 1.0),
+//!     )],
+//! )
+//!
+//! app.add_plugins(EffectAssetPlugin::new().with_path("effects/burning.effect.ron"));
+//! ```
+
+use super::definition::{DurationPolicy, GameplayEffectDefinition, GameplayEffectRegistry};
+use crate::core::validation::GasValidationAssets;
+use crate::serialization::migration::{
+    DefinitionFormat, DefinitionMigrationError, DefinitionMigrations,
+};
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use string_cache::DefaultAtom as Atom;
+
+/// Effect definitions loaded from one file.
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct GameplayEffectAsset {
+    /// The definitions in the file, in order.
+    pub definitions: Vec<GameplayEffectDefinition>,
+}
+
+/// The contents of an effect file: a versioned list, one definition or a list.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EffectFile {
+    Versioned {
+        #[serde(rename = "version")]
+        _version: u32,
+        definitions: Vec<GameplayEffectDefinition>,
+    },
+    Many(Vec<GameplayEffectDefinition>),
+    One(Box<GameplayEffectDefinition>),
+}
+
+/// Error loading an effect file.
+#[derive(Debug)]
+pub enum GameplayEffectAssetError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// The file is not valid RON.
+    Ron(ron::error::SpannedError),
+    /// The file is not valid JSON.
+    Json(serde_json::Error),
+    /// The file could not be migrated to the current format version.
+    Migration(DefinitionMigrationError),
+    /// A definition is invalid.
+    InvalidDefinition {
+        /// The definition's ID.
+        effect_id: Atom,
+        /// What is wrong with it.
+        message: &'static str,
+    },
+}
+
+impl fmt::Display for GameplayEffectAssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "Could not read effect file: {error}"),
+            Self::Ron(error) => write!(f, "Invalid RON effect file: {error}"),
+            Self::Json(error) => write!(f, "Invalid JSON effect file: {error}"),
+            Self::Migration(error) => write!(f, "Outdated effect file: {error}"),
+            Self::InvalidDefinition { effect_id, message } => {
+                write!(f, "Invalid effect definition '{effect_id}': {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GameplayEffectAssetError {}
+
+/// Loads [`GameplayEffectAsset`]s from `.effect.ron` and `.effect.json` files.
+#[derive(TypePath, Default)]
+pub struct GameplayEffectAssetLoader {
+    /// Migrations applied to outdated files.
+    pub migrations: DefinitionMigrations,
+}
+
+impl GameplayEffectAssetLoader {
+    /// Migrates and parses an effect file.
+    pub fn migrate_and_parse(
+        &self,
+        bytes: &[u8],
+        json: bool,
+    ) -> Result<GameplayEffectAsset, GameplayEffectAssetError> {
+        let bytes = self
+            .migrations
+            .migrate(DefinitionFormat::Effect, bytes, json)
+            .map_err(GameplayEffectAssetError::Migration)?;
+        Self::parse(&bytes, json)
+    }
+
+    /// Parses an effect file in the current format. JSON is used when `json`
+    /// is set, RON otherwise.
+    pub fn parse(
+        bytes: &[u8],
+        json: bool,
+    ) -> Result<GameplayEffectAsset, GameplayEffectAssetError> {
+        let file: EffectFile = if json {
+            serde_json::from_slice(bytes).map_err(GameplayEffectAssetError::Json)?
+        } else {
+            ron::de::from_bytes(bytes).map_err(GameplayEffectAssetError::Ron)?
+        };
+        let definitions = match file {
+            EffectFile::Versioned { definitions, .. } | EffectFile::Many(definitions) => {
+                definitions
+            }
+            EffectFile::One(definition) => vec![*definition],
+        };
+
+        // `GameplayEffectRegistry::register` panics on these; reject them here.
+        if let Some(definition) = definitions.iter().find(|definition| {
+            definition.duration_policy == DurationPolicy::Instant
+                && !definition.granted_tags.is_empty()
+        }) {
+            return Err(GameplayEffectAssetError::InvalidDefinition {
+                effect_id: definition.id.clone(),
+                message: "instant effects cannot grant tags",
+            });
+        }
+        Ok(GameplayEffectAsset { definitions })
+    }
+}
+
+impl AssetLoader for GameplayEffectAssetLoader {
+    type Asset = GameplayEffectAsset;
+    type Settings = ();
+    type Error = GameplayEffectAssetError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(GameplayEffectAssetError::Io)?;
+        let json = load_context
+            .path()
+            .path()
+            .extension()
+            .is_some_and(|extension| extension == "json");
+        self.migrate_and_parse(&bytes, json)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["effect.ron", "effect.json"]
+    }
+}
+
+/// Handles of the effect files loaded by [`EffectAssetPlugin`].
+#[derive(Resource, Debug, Default)]
+pub struct EffectAssetHandles(pub Vec<Handle<GameplayEffectAsset>>);
+
+/// IDs each loaded effect file registered, so a reload can drop removed ones.
+#[derive(Resource, Debug, Default)]
+pub struct LoadedEffectDefinitions(pub HashMap<AssetId<GameplayEffectAsset>, Vec<Atom>>);
+
+/// Plugin that loads effect definitions from asset files.
+///
+/// Requires Bevy's `AssetPlugin`. Files listed with [`Self::with_path`] are
+/// loaded at startup; any other [`GameplayEffectAsset`] handle kept alive is
+/// registered too.
+#[derive(Default)]
+pub struct EffectAssetPlugin {
+    /// Asset paths loaded at startup.
+    pub paths: Vec<String>,
+}
+
+impl EffectAssetPlugin {
+    /// Creates a plugin that loads no files by itself.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an effect file to load at startup.
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.paths.push(path.into());
+        self
+    }
+}
+
+impl Plugin for EffectAssetPlugin {
+    fn build(&self, app: &mut App) {
+        let paths = self.paths.clone();
+        let migrations = app
+            .world_mut()
+            .get_resource_or_init::<DefinitionMigrations>()
+            .clone();
+        app.init_asset::<GameplayEffectAsset>()
+            .register_asset_loader(GameplayEffectAssetLoader { migrations })
+            .init_resource::<GameplayEffectRegistry>()
+            .init_resource::<EffectAssetHandles>()
+            .init_resource::<GasValidationAssets>()
+            .init_resource::<LoadedEffectDefinitions>()
+            .add_systems(
+                Startup,
+                move |asset_server: Res<AssetServer>,
+                      mut handles: ResMut<EffectAssetHandles>,
+                      mut validation: ResMut<GasValidationAssets>| {
+                    for path in &paths {
+                        let handle = asset_server.load(path.clone());
+                        validation.0.push(handle.id().untyped());
+                        handles.0.push(handle);
+                    }
+                },
+            )
+            .add_systems(PreUpdate, register_loaded_effects_system);
+    }
+}
+
+/// System that registers definitions from loaded and reloaded effect files.
+pub fn register_loaded_effects_system(
+    mut events: MessageReader<AssetEvent<GameplayEffectAsset>>,
+    assets: Res<Assets<GameplayEffectAsset>>,
+    mut registry: ResMut<GameplayEffectRegistry>,
+    mut loaded: ResMut<LoadedEffectDefinitions>,
+) {
+    for event in events.read() {
+        match *event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => {
+                let Some(asset) = assets.get(id) else {
+                    continue;
+                };
+                let previous = loaded.0.remove(&id).unwrap_or_default();
+                for effect_id in &previous {
+                    if !asset
+                        .definitions
+                        .iter()
+                        .any(|definition| definition.id == *effect_id)
+                    {
+                        registry.definitions.remove(effect_id);
+                    }
+                }
+
+                let mut ids = Vec::with_capacity(asset.definitions.len());
+                for definition in &asset.definitions {
+                    let mut definition = definition.clone();
+                    if let Some(existing) = registry.definitions.get(&definition.id) {
+                        if definition.components.is_empty() {
+                            definition.components = existing.components.clone();
+                        }
+                        if definition.on_applied.is_empty() {
+                            definition.on_applied = existing.on_applied.clone();
+                        }
+                        if definition.on_removed.is_empty() {
+                            definition.on_removed = existing.on_removed.clone();
+                        }
+                    }
+                    debug!("Registering effect '{}' from asset", definition.id);
+                    ids.push(definition.id.clone());
+                    registry.register(definition);
+                }
+                loaded.0.insert(id, ids);
+            }
+            AssetEvent::Removed { id } => {
+                for effect_id in loaded.0.remove(&id).unwrap_or_default() {
+                    registry.definitions.remove(&effect_id);
+                }
+            }
+            AssetEvent::Unused { .. } | AssetEvent::LoadedWithDependencies { .. } => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_single_ron_and_json_list() {
+        let ron = br#"(
+            id: "burning",
+            duration_policy: HasDuration,
+            duration_magnitude: 6.0,
+            stacking_policy: Independent,
+        )"#;
+        let asset = GameplayEffectAssetLoader::parse(ron, false).unwrap();
+        assert_eq!(asset.definitions.len(), 1);
+        assert_eq!(asset.definitions[0].duration_magnitude, 6.0);
+
+        let json = br#"[
+            { "id": "a", "duration_policy": "Instant", "stacking_policy": "Independent" },
+            { "id": "b", "duration_policy": "Infinite", "stacking_policy": "Independent" }
+        ]"#;
+        let asset = GameplayEffectAssetLoader::parse(json, true).unwrap();
+        let ids: Vec<_> = asset.definitions.iter().map(|d| d.id.clone()).collect();
+        assert_eq!(ids, vec![Atom::from("a"), Atom::from("b")]);
+    }
+
+    #[test]
+    fn test_rejects_instant_effect_with_granted_tags() {
+        let ron = br#"(
+            id: "bad",
+            duration_policy: Instant,
+            stacking_policy: Independent,
+            granted_tags: ["State.Stunned"],
+        )"#;
+        assert!(matches!(
+            GameplayEffectAssetLoader::parse(ron, false),
+            Err(GameplayEffectAssetError::InvalidDefinition { .. })
+        ));
+    }
+
+    #[test]
+    fn test_migrates_outdated_file() {
+        let loader = GameplayEffectAssetLoader::default();
+        loader
+            .migrations
+            .register(DefinitionFormat::Effect, 1, |text| {
+                Ok(text.replace("length:", "duration_magnitude:"))
+            });
+        let ron = br#"[(
+            id: "burning",
+            duration_policy: HasDuration,
+            length: 6.0,
+            stacking_policy: Independent,
+        )]"#;
+        let asset = loader.migrate_and_parse(ron, false).unwrap();
+        assert_eq!(asset.definitions[0].duration_magnitude, 6.0);
+
+        let current = br#"(version: 2, definitions: [(
+            id: "burning",
+            duration_policy: HasDuration,
+            duration_magnitude: 3.0,
+            stacking_policy: Independent,
+        )])"#;
+        let asset = loader.migrate_and_parse(current, false).unwrap();
+        assert_eq!(asset.definitions[0].duration_magnitude, 3.0);
+    }
+}
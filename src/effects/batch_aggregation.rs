@@ -2,11 +2,19 @@
 //!
 //! This module provides performance-optimized batch processing for attribute modifiers,
 //! reducing the number of iterations and improving cache locality.
+//!
+//! [`ModifierIndex`] keeps modifier entities grouped by the attribute they
+//! target as they are spawned and despawned, so aggregation looks up each
+//! attribute's modifiers instead of scanning all of them. The same observers
+//! mark the targeted attribute [`AttributeDirty`], so only attributes whose
+//! modifiers or data changed are re-aggregated.
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use std::collections::{BTreeMap, HashMap};
 use string_cache::DefaultAtom as Atom;
 
+use crate::attributes::{AttributeDirty, AttributeName};
 use crate::effects::components::{AttributeModifier, EvaluationChannel, ModifierOperation};
 
 /// Batch of modifiers targeting the same attribute.
@@ -221,6 +229,128 @@ impl ModifierAggregator {
     }
 }
 
+/// Modifier entities grouped by the attribute they target.
+///
+/// Maintained by observers on [`AttributeModifier`], and by
+/// [`aggregate_attribute_modifiers_system`](super::systems::aggregate_attribute_modifiers_system)
+/// for modifiers retargeted in place.
+#[derive(Resource, Debug, Default)]
+pub struct ModifierIndex {
+    // Keyed by owner, then name, so lookups borrow the name instead of
+    // cloning it into an `AttributeKey`
+    by_attribute: HashMap<Entity, HashMap<Atom, Vec<Entity>>>,
+    by_modifier: HashMap<Entity, AttributeKey>,
+}
+
+impl ModifierIndex {
+    /// Indexes `modifier` under `key`. Returns the key it was indexed under
+    /// before if it moved to another attribute.
+    pub fn insert(&mut self, modifier: Entity, key: AttributeKey) -> Option<AttributeKey> {
+        let previous = match self.by_modifier.get(&modifier) {
+            Some(old_key) if *old_key == key => return None,
+            Some(_) => self.remove(modifier),
+            None => None,
+        };
+        self.by_attribute
+            .entry(key.owner)
+            .or_default()
+            .entry(key.attribute_name.clone())
+            .or_default()
+            .push(modifier);
+        self.by_modifier.insert(modifier, key);
+        previous
+    }
+
+    /// Removes `modifier` from the index. Returns the key it was indexed
+    /// under, if any.
+    pub fn remove(&mut self, modifier: Entity) -> Option<AttributeKey> {
+        let key = self.by_modifier.remove(&modifier)?;
+        if let Some(attributes) = self.by_attribute.get_mut(&key.owner) {
+            if let Some(modifiers) = attributes.get_mut(&key.attribute_name) {
+                modifiers.retain(|entity| *entity != modifier);
+                if modifiers.is_empty() {
+                    attributes.remove(&key.attribute_name);
+                }
+            }
+            if attributes.is_empty() {
+                self.by_attribute.remove(&key.owner);
+            }
+        }
+        Some(key)
+    }
+
+    /// Returns the modifier entities targeting `attribute_name` on `owner`.
+    pub fn modifiers(&self, owner: Entity, attribute_name: &Atom) -> &[Entity] {
+        self.by_attribute
+            .get(&owner)
+            .and_then(|attributes| attributes.get(attribute_name))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the number of indexed modifiers.
+    pub fn len(&self) -> usize {
+        self.by_modifier.len()
+    }
+
+    /// Returns true if no modifiers are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.by_modifier.is_empty()
+    }
+}
+
+/// Finds attribute entities by owner and name.
+#[derive(SystemParam)]
+pub struct AttributeLookup<'w, 's> {
+    children: Query<'w, 's, &'static Children>,
+    names: Query<'w, 's, &'static AttributeName>,
+}
+
+impl AttributeLookup<'_, '_> {
+    /// Returns the attribute entity `key` refers to, if its owner has it.
+    pub fn find(&self, key: &AttributeKey) -> Option<Entity> {
+        self.children.get(key.owner).ok()?.iter().find(|child| {
+            self.names
+                .get(*child)
+                .is_ok_and(|name| name.0 == key.attribute_name)
+        })
+    }
+}
+
+/// Observer indexing inserted modifiers and marking their attribute dirty.
+pub fn index_inserted_modifier(
+    ev: On<Insert, AttributeModifier>,
+    modifiers: Query<&AttributeModifier>,
+    lookup: AttributeLookup,
+    mut index: ResMut<ModifierIndex>,
+    mut commands: Commands,
+) {
+    let Ok(modifier) = modifiers.get(ev.event_target()) else {
+        return;
+    };
+    let key = AttributeKey::new(modifier.target_entity, modifier.target_attribute.clone());
+    let previous = index.insert(ev.event_target(), key.clone());
+    for key in previous.iter().chain([&key]) {
+        if let Some(attribute) = lookup.find(key) {
+            commands.entity(attribute).try_insert(AttributeDirty);
+        }
+    }
+}
+
+/// Observer removing modifiers from the index and marking their attribute
+/// dirty.
+pub fn unindex_removed_modifier(
+    ev: On<Remove, AttributeModifier>,
+    lookup: AttributeLookup,
+    mut index: ResMut<ModifierIndex>,
+    mut commands: Commands,
+) {
+    if let Some(key) = index.remove(ev.event_target())
+        && let Some(attribute) = lookup.find(&key)
+    {
+        commands.entity(attribute).try_insert(AttributeDirty);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -372,4 +502,34 @@ mod tests {
         let mana_batch = aggregator.get_batch(owner, &mana).unwrap();
         assert_eq!(mana_batch.evaluate(100.0), 120.0);
     }
+
+    #[test]
+    fn test_modifier_index_tracks_modifiers() {
+        let mut index = ModifierIndex::default();
+        let owner = Entity::from_bits(1);
+        let health = Atom::from("Health");
+        let mana = Atom::from("Mana");
+        let first = Entity::from_bits(2);
+        let second = Entity::from_bits(3);
+
+        assert!(
+            index
+                .insert(first, AttributeKey::new(owner, health.clone()))
+                .is_none()
+        );
+        index.insert(second, AttributeKey::new(owner, health.clone()));
+        assert_eq!(index.modifiers(owner, &health), &[first, second]);
+
+        assert_eq!(
+            index.insert(second, AttributeKey::new(owner, mana.clone())),
+            Some(AttributeKey::new(owner, health.clone()))
+        );
+        assert_eq!(index.modifiers(owner, &health), &[first]);
+        assert_eq!(index.modifiers(owner, &mana), &[second]);
+
+        assert!(index.remove(first).is_some());
+        assert!(index.remove(first).is_none());
+        assert!(index.modifiers(owner, &health).is_empty());
+        assert_eq!(index.len(), 1);
+    }
 }
@@ -0,0 +1,146 @@
+//! Attribute breakdowns.
+//!
+//! [`AttributeBreakdowns::explain_attribute`] lists how an attribute's
+//! current value comes about: its base value, every modifier applied to it
+//! with the effect that created it, and the final value. Tooltips format it
+//! as `Attack 57 = 40 base + 10 (might) + 20% (war_cry)`, and it helps
+//! debugging aggregation.
+//!
+//! # Example
+//!
+//! ```ignore
+//! fn attack_tooltip(breakdowns: AttributeBreakdowns, player: Single<Entity, With<Player>>) {
+//!     if let Some(breakdown) = breakdowns.explain_attribute(*player, "Attack") {
+//!         info!("{breakdown}");
+//!     }
+//! }
+//! ```
+
+use super::batch_aggregation::{ModifierBatch, ModifierIndex};
+use super::components::{
+    ActiveGameplayEffect, AttributeModifier, EvaluationChannel, ModifierOperation, ModifierSource,
+};
+use crate::utils::Gas;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use std::fmt;
+use string_cache::DefaultAtom as Atom;
+
+/// A modifier contributing to an attribute.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModifierContribution {
+    /// The active effect that created the modifier, if any.
+    pub effect: Option<Entity>,
+    /// The definition ID of that effect, if any.
+    pub effect_id: Option<Atom>,
+    /// The operation the modifier performs.
+    pub operation: ModifierOperation,
+    /// The magnitude of the modifier.
+    pub magnitude: f32,
+    /// The evaluation channel of the modifier.
+    pub channel: EvaluationChannel,
+}
+
+/// How an attribute's current value is made up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeBreakdown {
+    /// The attribute owner.
+    pub owner: Entity,
+    /// The attribute name.
+    pub name: Atom,
+    /// The base value.
+    pub base_value: f32,
+    /// The modifiers applied to the attribute, in evaluation order.
+    pub modifiers: Vec<ModifierContribution>,
+    /// The current value, after modifiers and attribute set hooks.
+    pub final_value: f32,
+}
+
+impl AttributeBreakdown {
+    /// Evaluates the modifiers on the base value, before attribute set
+    /// hooks. Differs from `final_value` when a hook clamped the result or
+    /// the modifiers changed since the last aggregation.
+    pub fn evaluate(&self) -> f32 {
+        let mut batch = ModifierBatch::new();
+        for modifier in &self.modifiers {
+            batch.add_modifier(modifier.channel, modifier.operation, modifier.magnitude);
+        }
+        batch.evaluate(self.base_value)
+    }
+}
+
+impl fmt::Display for AttributeBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} = {} base",
+            self.name, self.final_value, self.base_value
+        )?;
+        for modifier in &self.modifiers {
+            let magnitude = modifier.magnitude;
+            match modifier.operation {
+                ModifierOperation::AddBase | ModifierOperation::AddCurrent => {
+                    let sign = if magnitude < 0.0 { '-' } else { '+' };
+                    write!(f, " {sign} {}", magnitude.abs())?;
+                }
+                ModifierOperation::MultiplyAdditive => {
+                    let sign = if magnitude < 0.0 { '-' } else { '+' };
+                    write!(f, " {sign} {}%", (magnitude * 100.0).abs())?;
+                }
+                ModifierOperation::MultiplyMultiplicative => {
+                    write!(f, " x {}%", (1.0 + magnitude) * 100.0)?;
+                }
+                ModifierOperation::Override => write!(f, " = {magnitude}")?,
+            }
+            if let Some(effect_id) = &modifier.effect_id {
+                write!(f, " ({effect_id})")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// System parameter explaining attribute values.
+#[derive(SystemParam)]
+pub struct AttributeBreakdowns<'w, 's> {
+    pub gas: Gas<'w, 's>,
+    pub index: Res<'w, ModifierIndex>,
+    pub modifiers: Query<'w, 's, (&'static AttributeModifier, Option<&'static ModifierSource>)>,
+    pub effects: Query<'w, 's, &'static ActiveGameplayEffect>,
+}
+
+impl AttributeBreakdowns<'_, '_> {
+    /// Returns the breakdown of the `name` attribute of `owner`, or `None` if
+    /// the owner has no such attribute.
+    pub fn explain_attribute(&self, owner: Entity, name: &str) -> Option<AttributeBreakdown> {
+        let data = self.gas.attribute(owner, name)?;
+        let name = Atom::from(name);
+        let mut modifiers: Vec<ModifierContribution> = self
+            .index
+            .modifiers(owner, &name)
+            .iter()
+            .filter_map(|modifier| self.modifiers.get(*modifier).ok())
+            .map(|(modifier, source)| {
+                let effect = source.map(|source| source.0);
+                ModifierContribution {
+                    effect,
+                    effect_id: effect
+                        .and_then(|effect| self.effects.get(effect).ok())
+                        .map(|effect| effect.definition_id.clone()),
+                    operation: modifier.operation,
+                    magnitude: modifier.magnitude,
+                    channel: modifier.channel,
+                }
+            })
+            .collect();
+        modifiers.sort_by_key(|modifier| (modifier.channel, modifier.operation.priority()));
+
+        Some(AttributeBreakdown {
+            owner,
+            name,
+            base_value: data.base_value,
+            modifiers,
+            final_value: data.current_value,
+        })
+    }
+}
@@ -0,0 +1,145 @@
+//! On-apply and on-remove callbacks of effect definitions.
+//!
+//! A [`GameplayEffectDefinition`] can carry Rust callbacks, or one-shot
+//! systems, that run when one of its instances is applied (including
+//! refreshes and new stacks) or removed, for simple custom behavior such as
+//! spawning a companion or flipping an AI state without writing an execution
+//! calculation. Instant effects have no instance and run no callbacks.
+//!
+//! Callbacks get an [`EffectCallbackContext`] and `Commands`; one-shot
+//! systems take the context as `In<EffectCallbackContext>`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let summon = GameplayEffectDefinition::new("summon_wolf")
+//!     .with_duration_policy(DurationPolicy::HasDuration)
+//!     .with_duration(30.0)
+//!     .with_on_applied(|ctx, commands| {
+//!         commands.spawn((Wolf, Companion(ctx.target), SummonedBy(ctx.effect)));
+//!     })
+//!     .with_on_removed_system(despawn_wolves_system_id);
+//! ```
+
+use super::components::{ActiveGameplayEffect, GameplayEffectContext};
+use super::definition::GameplayEffectRegistry;
+use super::systems::GameplayEffectAppliedEvent;
+use bevy::ecs::system::SystemId;
+use bevy::prelude::*;
+use std::fmt;
+use std::sync::Arc;
+use string_cache::DefaultAtom as Atom;
+
+/// What an effect callback is told about the instance.
+#[derive(Debug, Clone)]
+pub struct EffectCallbackContext {
+    /// The active effect entity.
+    pub effect: Entity,
+    /// The entity the effect is on.
+    pub target: Entity,
+    /// The effect definition ID.
+    pub effect_id: Atom,
+    /// Context of the application, naming its source and instigator.
+    pub context: GameplayEffectContext,
+    /// The effect level.
+    pub level: i32,
+    /// The stack count.
+    pub stack_count: i32,
+}
+
+type EffectCallbackFn = dyn Fn(&EffectCallbackContext, &mut Commands) + Send + Sync;
+
+/// A callback run when an effect instance is applied or removed.
+#[derive(Clone)]
+pub struct EffectCallback(Arc<EffectCallbackFn>);
+
+impl EffectCallback {
+    /// Creates a callback from a closure.
+    pub fn new(
+        callback: impl Fn(&EffectCallbackContext, &mut Commands) + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    /// Creates a callback running a registered one-shot system.
+    pub fn system(system: SystemId<In<EffectCallbackContext>>) -> Self {
+        Self::new(move |context, commands| {
+            commands.run_system_with(system, context.clone());
+        })
+    }
+
+    /// Runs the callback.
+    pub fn run(&self, context: &EffectCallbackContext, commands: &mut Commands) {
+        (self.0)(context, commands);
+    }
+}
+
+impl fmt::Debug for EffectCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EffectCallback")
+    }
+}
+
+fn callback_context(
+    effect: Entity,
+    active_effect: &ActiveGameplayEffect,
+    context: Option<&GameplayEffectContext>,
+) -> EffectCallbackContext {
+    EffectCallbackContext {
+        effect,
+        target: active_effect.target,
+        effect_id: active_effect.definition_id.clone(),
+        context: context.cloned().unwrap_or_default(),
+        level: active_effect.level,
+        stack_count: active_effect.stack_count,
+    }
+}
+
+/// Observer that runs the `on_applied` callbacks of applied effects.
+pub fn on_effect_applied_run_callbacks(
+    trigger: On<GameplayEffectAppliedEvent>,
+    mut commands: Commands,
+    registry: Res<GameplayEffectRegistry>,
+    effects: Query<(&ActiveGameplayEffect, Option<&GameplayEffectContext>)>,
+) {
+    let event = trigger.event();
+    let Some(definition) = registry.get(&event.effect_id) else {
+        return;
+    };
+    if definition.on_applied.is_empty() {
+        return;
+    }
+    let Ok((active_effect, context)) = effects.get(event.effect) else {
+        return;
+    };
+    let context = callback_context(event.effect, active_effect, context);
+    for callback in &definition.on_applied {
+        callback.run(&context, &mut commands);
+    }
+}
+
+/// Observer that runs the `on_removed` callbacks of effects going away.
+///
+/// Like Removed cues, it covers expiry, removal and despawning the effect or
+/// its target.
+pub fn on_active_effect_removed_run_callbacks(
+    ev: On<Remove, ActiveGameplayEffect>,
+    mut commands: Commands,
+    registry: Res<GameplayEffectRegistry>,
+    effects: Query<(&ActiveGameplayEffect, Option<&GameplayEffectContext>)>,
+) {
+    let effect = ev.event_target();
+    let Ok((active_effect, context)) = effects.get(effect) else {
+        return;
+    };
+    let Some(definition) = registry.get(&active_effect.definition_id) else {
+        return;
+    };
+    if definition.on_removed.is_empty() {
+        return;
+    }
+    let context = callback_context(effect, active_effect, context);
+    for callback in &definition.on_removed {
+        callback.run(&context, &mut commands);
+    }
+}
@@ -0,0 +1,137 @@
+//! Combat log events.
+//!
+//! Every effect execution that deals damage or heals is reported as a
+//! [`DamageDealtEvent`] or [`HealingDoneEvent`], one per additive modifier, so
+//! combat logs, damage meters and floating numbers read structured data
+//! instead of formatting attribute changes themselves. A damage modifier is
+//! an `AddBase`/`AddCurrent` modifier with a negative magnitude; a healing
+//! modifier is one with a positive magnitude. Mitigated amounts come from
+//! the effect's [`DamageCalculation`](super::damage::DamageCalculation).
+//!
+//! # Example
+//! ```ignore
+//! app.add_observer(|ev: On<DamageDealtEvent>, names: Query<&Name>| {
+//!     let ev = ev.event();
+//!     info!(
+//!         "{:?} hits {:?} with {} for {:.0} ({:.0} mitigated){}",
+//!         ev.source.and_then(|source| names.get(source).ok()),
+//!         names.get(ev.target).ok(),
+//!         ev.effect_id,
+//!         ev.post_mitigation,
+//!         ev.mitigated(),
+//!         if ev.critical { " (critical)" } else { "" },
+//!     );
+//! });
+//! ```
+
+use super::components::ModifierOperation;
+use super::definition::GameplayEffectRegistry;
+use super::systems::GameplayEffectExecutedEvent;
+use crate::abilities::components::AbilitySpec;
+use bevy::prelude::*;
+use string_cache::DefaultAtom as Atom;
+
+/// Event triggered for each damage modifier of an effect execution.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct DamageDealtEvent {
+    /// The entity that applied the effect, if known.
+    pub source: Option<Entity>,
+    /// The damaged entity.
+    pub target: Entity,
+    /// The ability spec that applied the effect, if any.
+    pub ability: Option<Entity>,
+    /// The active effect entity for periodic executions, `None` for instant effects.
+    pub effect: Option<Entity>,
+    /// The effect definition ID.
+    pub effect_id: Atom,
+    /// The damaged attribute.
+    pub attribute: Atom,
+    /// Damage before mitigation, crits included.
+    pub pre_mitigation: f32,
+    /// Damage after mitigation.
+    pub post_mitigation: f32,
+    /// Whether the damage was a critical hit.
+    pub critical: bool,
+}
+
+impl DamageDealtEvent {
+    /// Returns the damage prevented by mitigation.
+    pub fn mitigated(&self) -> f32 {
+        self.pre_mitigation - self.post_mitigation
+    }
+}
+
+/// Event triggered for each healing modifier of an effect execution.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct HealingDoneEvent {
+    /// The entity that applied the effect, if known.
+    pub source: Option<Entity>,
+    /// The healed entity.
+    pub target: Entity,
+    /// The ability spec that applied the effect, if any.
+    pub ability: Option<Entity>,
+    /// The active effect entity for periodic executions, `None` for instant effects.
+    pub effect: Option<Entity>,
+    /// The effect definition ID.
+    pub effect_id: Atom,
+    /// The healed attribute.
+    pub attribute: Atom,
+    /// The amount healed.
+    pub amount: f32,
+}
+
+/// Observer turning effect executions into [`DamageDealtEvent`]s and
+/// [`HealingDoneEvent`]s.
+pub fn on_effect_executed_log_combat(
+    trigger: On<GameplayEffectExecutedEvent>,
+    mut commands: Commands,
+    registry: Res<GameplayEffectRegistry>,
+    abilities: Query<(), With<AbilitySpec>>,
+) {
+    let event = trigger.event();
+    let Some(definition) = registry.get(&event.effect_id) else {
+        return;
+    };
+    let source = event.source();
+    let ability = event
+        .context
+        .instigator
+        .filter(|&instigator| abilities.contains(instigator));
+
+    for ((modifier, (attribute, magnitude)), &pre_mitigation) in definition
+        .modifiers
+        .iter()
+        .zip(&event.magnitudes)
+        .zip(&event.pre_mitigation)
+    {
+        if !matches!(
+            modifier.operation,
+            ModifierOperation::AddBase | ModifierOperation::AddCurrent
+        ) {
+            continue;
+        }
+        if pre_mitigation < 0.0 {
+            commands.trigger(DamageDealtEvent {
+                source,
+                target: event.target,
+                ability,
+                effect: event.effect,
+                effect_id: event.effect_id.clone(),
+                attribute: attribute.clone(),
+                pre_mitigation: -pre_mitigation,
+                post_mitigation: -magnitude,
+                critical: event.critical,
+            });
+        } else if *magnitude > 0.0 {
+            commands.trigger(HealingDoneEvent {
+                source,
+                target: event.target,
+                ability,
+                effect: event.effect,
+                effect_id: event.effect_id.clone(),
+                attribute: attribute.clone(),
+                amount: *magnitude,
+            });
+        }
+    }
+}
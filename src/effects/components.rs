@@ -2,6 +2,7 @@
 //!
 //! This module defines the core components for the gameplay effect system.
 
+use crate::core::{PeriodicTickAlignment, PredictionKey};
 use bevy::prelude::*;
 use bevy_gameplay_tag::{GameplayTag, GameplayTagContainer};
 use std::collections::HashMap;
@@ -11,19 +12,29 @@ use string_cache::DefaultAtom as Atom;
 ///
 /// Each active effect is a separate entity with this component.
 /// The effect modifies attributes on the target entity.
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(opaque)]
+#[reflect(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct ActiveGameplayEffect {
     /// The ID of the effect definition.
     pub definition_id: Atom,
     /// The entity that applied this effect (instigator).
+    #[entities]
     pub source: Entity,
     /// The entity receiving this effect.
+    #[entities]
     pub target: Entity,
     /// The level at which this effect was applied.
     pub level: i32,
     /// The time when this effect was applied (in seconds).
     pub start_time: f32,
     /// Tags granted to the target while this effect is active.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::tag_container_serde")
+    )]
     pub granted_tags: GameplayTagContainer,
     /// The current stack count for this effect.
     pub stack_count: i32,
@@ -64,8 +75,13 @@ impl ActiveGameplayEffect {
 ///         .with_magnitude(crit_chance_tag, 0.25),
 /// ));
 /// ```
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(opaque)]
+#[reflect(Component, Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct SetByCallerMagnitudes {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::tag_map_serde"))]
     magnitudes: HashMap<GameplayTag, f32>,
 }
 
@@ -95,11 +111,31 @@ impl SetByCallerMagnitudes {
     pub fn get_magnitude(&self, tag: &GameplayTag) -> Option<f32> {
         self.magnitudes.get(tag).copied()
     }
+
+    /// Iterates over all data tags and their magnitudes.
+    pub fn iter(&self) -> impl Iterator<Item = (&GameplayTag, f32)> {
+        self.magnitudes
+            .iter()
+            .map(|(tag, magnitude)| (tag, *magnitude))
+    }
 }
 
 /// Component that links an effect to its target entity.
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
-pub struct EffectTarget(pub Entity);
+///
+/// A relationship, so the target lists its effects in [`ActiveEffects`] and
+/// scenes remap it on load.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[relationship(relationship_target = ActiveEffects)]
+pub struct EffectTarget(#[entities] pub Entity);
+
+/// The active effects targeting an entity.
+///
+/// Despawning the entity despawns its effects.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component, FromWorld, Default)]
+#[relationship_target(relationship = EffectTarget, linked_spawn)]
+pub struct ActiveEffects(Vec<Entity>);
 
 /// Component tracking abilities granted by this effect.
 ///
@@ -114,8 +150,18 @@ pub struct EffectGrantedAbilities {
 /// Component that identifies the instigator of an effect.
 ///
 /// This is the entity that caused the effect to be applied.
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
-pub struct EffectInstigator(pub Option<Entity>);
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+pub struct EffectInstigator(#[entities] pub Option<Entity>);
+
+/// Component holding the attribute values an effect snapshotted when it was
+/// applied.
+///
+/// Keyed like [`GameplayEffectSpec::captured_attributes`]. Present on effects
+/// whose calculations declare snapshot captures; their later evaluations,
+/// such as periodic executions, read these values instead of the live ones.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct EffectAttributeSnapshots(pub HashMap<(Entity, Atom), f32>);
 
 /// Context information for a gameplay effect.
 ///
@@ -132,11 +178,14 @@ pub struct EffectInstigator(pub Option<Entity>);
 ///         .with_hit_location(Vec3::new(0.0, 1.0, 0.0)),
 /// ));
 /// ```
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug, Clone, Default)]
 pub struct GameplayEffectContext {
     /// The entity that owns the ability/effect (e.g., the player).
+    #[entities]
     pub source: Option<Entity>,
     /// The entity that directly caused the effect (e.g., a projectile or weapon).
+    #[entities]
     pub instigator: Option<Entity>,
     /// The location where the effect was applied (e.g., hit location).
     pub hit_location: Option<Vec3>,
@@ -144,6 +193,9 @@ pub struct GameplayEffectContext {
     pub hit_normal: Option<Vec3>,
     /// Custom data that can be attached to the context.
     pub custom_data: HashMap<String, f32>,
+    /// Whether this application is reflected damage, which is never
+    /// reflected again.
+    pub reflected: bool,
 }
 
 impl Default for GameplayEffectContext {
@@ -161,6 +213,7 @@ impl GameplayEffectContext {
             hit_location: None,
             hit_normal: None,
             custom_data: HashMap::new(),
+            reflected: false,
         }
     }
 
@@ -198,6 +251,12 @@ impl GameplayEffectContext {
     pub fn get_custom_data(&self, key: &str) -> Option<f32> {
         self.custom_data.get(key).copied()
     }
+
+    /// Marks the application as reflected damage.
+    pub fn as_reflected(mut self) -> Self {
+        self.reflected = true;
+        self
+    }
 }
 
 /// Runtime gameplay effect specification.
@@ -219,6 +278,8 @@ pub struct GameplayEffectSpec {
     /// Captured attribute values for Snapshot mode calculations.
     /// Key: (entity, attribute_name), Value: captured value
     pub captured_attributes: std::collections::HashMap<(Entity, Atom), f32>,
+    /// Key of the predicted activation that applied this spec, if any.
+    pub prediction_key: Option<PredictionKey>,
 }
 
 impl GameplayEffectSpec {
@@ -231,6 +292,7 @@ impl GameplayEffectSpec {
             context: GameplayEffectContext::new(),
             set_by_caller_magnitudes: SetByCallerMagnitudes::new(),
             captured_attributes: std::collections::HashMap::new(),
+            prediction_key: None,
         }
     }
 
@@ -270,6 +332,12 @@ impl GameplayEffectSpec {
         self
     }
 
+    /// Sets the prediction key.
+    pub fn with_prediction_key(mut self, key: PredictionKey) -> Self {
+        self.prediction_key = Some(key);
+        self
+    }
+
     /// Returns the entity used for source-side attribute capture.
     pub fn source_entity(&self) -> Option<Entity> {
         self.context.source.or(self.context.instigator)
@@ -297,7 +365,9 @@ impl GameplayEffectSpec {
 /// Component for effects with a duration.
 ///
 /// This tracks the remaining time for duration-based effects.
-#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EffectDuration {
     /// Remaining time in seconds.
     pub remaining: f32,
@@ -328,7 +398,8 @@ impl EffectDuration {
 /// Component for periodic effects.
 ///
 /// Periodic effects execute their modifiers at regular intervals.
-#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Debug, Clone, PartialEq)]
 pub struct PeriodicEffect {
     /// The period between executions in seconds.
     pub period: f32,
@@ -348,6 +419,29 @@ impl PeriodicEffect {
         }
     }
 
+    /// Creates a periodic effect whose first execution follows `alignment`,
+    /// for an effect applied at `elapsed` seconds.
+    pub fn aligned(period: f32, alignment: PeriodicTickAlignment, elapsed: f32) -> Self {
+        Self {
+            period,
+            time_until_next: alignment.first_delay(period, elapsed),
+        }
+    }
+
+    /// Snaps the timer onto the next beat of
+    /// [`PeriodicTickAlignment::Global`] after an execution at `elapsed`
+    /// seconds, so float error and chunked LOD ticks don't drift it off the
+    /// beat shared with other effects of the same period.
+    pub fn align_to_global_beat(&mut self, elapsed: f32) {
+        let delay = PeriodicTickAlignment::Global.first_delay(self.period, elapsed);
+        // Just short of a beat means the execution belonged to that beat
+        self.time_until_next = if delay < self.period / 2.0 {
+            delay + self.period
+        } else {
+            delay
+        };
+    }
+
     /// Returns true if the effect should execute this frame.
     pub fn should_execute(&self) -> bool {
         self.time_until_next <= 0.0
@@ -369,9 +463,14 @@ impl PeriodicEffect {
 ///
 /// This represents a single modifier applied to an attribute by an effect.
 /// Each modifier is a separate entity linked to both the effect and the target attribute.
-#[derive(Component, Debug, Clone, PartialEq)]
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(opaque)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct AttributeModifier {
     /// The entity that owns the attribute being modified.
+    #[entities]
     pub target_entity: Entity,
     /// The name of the attribute being modified.
     pub target_attribute: Atom,
@@ -384,8 +483,18 @@ pub struct AttributeModifier {
 }
 
 /// The source of a modifier (which effect created it).
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ModifierSource(pub Entity);
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Clone, PartialEq)]
+#[relationship(relationship_target = EffectModifiers)]
+pub struct ModifierSource(#[entities] pub Entity);
+
+/// The modifiers created by an effect.
+///
+/// Despawning the effect despawns its modifiers.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component, FromWorld, Default)]
+#[relationship_target(relationship = ModifierSource, linked_spawn)]
+pub struct EffectModifiers(Vec<Entity>);
 
 /// Evaluation channel for modifier application.
 ///
@@ -400,6 +509,7 @@ pub struct ModifierSource(pub Entity);
 ///
 /// Matches UE GAS's `EGameplayModEvaluationChannel`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EvaluationChannel {
     Channel0 = 0,
     Channel1 = 1,
@@ -445,6 +555,7 @@ impl EvaluationChannel {
 
 /// The type of modification operation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ModifierOperation {
     /// Add to the base value (permanent).
     AddBase,
@@ -477,11 +588,34 @@ impl ModifierOperation {
 /// Tags granted by an active effect.
 ///
 /// These tags are added to the target entity while the effect is active.
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(opaque)]
+#[reflect(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct EffectGrantedTags {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::tag_container_serde")
+    )]
     pub tags: GameplayTagContainer,
 }
 
+/// Marks an effect whose granted tags have been added to its target.
+///
+/// Effects loaded from a scene lack it, so their tags are added on load.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct EffectGrantedTagsApplied;
+
+/// Marks an expired effect left in the
+/// [`ExpiredEffectQueue`](super::systems::ExpiredEffectQueue) because the
+/// frame's removal budget ran out.
+///
+/// Periodic effects stop executing while they wait.
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[component(storage = "SparseSet")]
+pub struct EffectRemovalPending;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -526,6 +660,19 @@ mod tests {
         assert!((periodic.time_until_next - 0.5).abs() < 0.001);
     }
 
+    #[test]
+    fn test_periodic_effect_align_to_global_beat() {
+        let mut periodic = PeriodicEffect::new(2.0);
+        // Executed a hair past the beat at 4s
+        periodic.align_to_global_beat(4.001);
+        assert!((periodic.time_until_next - 1.999).abs() < 0.001);
+        // Executed a hair short of it
+        periodic.align_to_global_beat(3.999);
+        assert!((periodic.time_until_next - 2.001).abs() < 0.001);
+        periodic.align_to_global_beat(4.0);
+        assert_eq!(periodic.time_until_next, 2.0);
+    }
+
     #[test]
     fn test_modifier_operation_priority() {
         assert!(ModifierOperation::Override.priority() < ModifierOperation::AddBase.priority());
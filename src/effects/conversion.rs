@@ -0,0 +1,193 @@
+//! Conversion effects such as lifesteal and thorns.
+//!
+//! An [`EffectConversion`] on a definition watches how much one attribute of
+//! the target changed when the effect executed, and applies a second effect
+//! back to the source with that amount scaled by a coefficient. The amount is
+//! passed as a SetByCaller magnitude, so the returned effect reads it with
+//! [`MagnitudeCalculation::SetByCaller`](super::definition::MagnitudeCalculation::SetByCaller).
+//!
+//! A [`DamageReflection`] works from the other side: while an effect carrying
+//! it is active on a victim, damage other effects deal to the victim is
+//! partly reflected to their source. Reflected applications are marked in
+//! their [`GameplayEffectContext`], and marked damage is never reflected, so
+//! two thorn-bearers can't bounce damage back and forth.
+//!
+//! # Example
+//! ```ignore
+//! let lifesteal_tag = GameplayTag::new("Data.Lifesteal");
+//!
+//! registry.register(
+//!     GameplayEffectDefinition::new("lifesteal_heal").add_modifier(ModifierInfo::new(
+//!         "Health",
+//!         ModifierOperation::AddBase,
+//!         MagnitudeCalculation::set_by_caller(lifesteal_tag.clone()),
+//!     )),
+//! );
+//! registry.register(
+//!     GameplayEffectDefinition::new("vampiric_strike")
+//!         .add_modifier(ModifierInfo::new(
+//!             "Health",
+//!             ModifierOperation::AddBase,
+//!             MagnitudeCalculation::scalar(-40.0),
+//!         ))
+//!         // Heal the attacker for 25% of the health removed.
+//!         .add_conversion(EffectConversion::new("Health", "lifesteal_heal", 0.25, lifesteal_tag)),
+//! );
+//! ```
+
+use super::components::{GameplayEffectContext, GameplayEffectSpec};
+use super::systems::ApplyGameplayEffectEvent;
+use bevy::prelude::*;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use string_cache::DefaultAtom as Atom;
+
+/// Applies an effect back to the source, scaled from what an effect dealt.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EffectConversion {
+    /// Target attribute whose change is converted.
+    pub attribute: Atom,
+    /// Effect applied to the source.
+    pub effect_id: Atom,
+    /// Fraction of the change passed on.
+    pub coefficient: f32,
+    /// SetByCaller tag carrying the converted amount.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::gameplay_tag_serde")
+    )]
+    pub data_tag: GameplayTag,
+}
+
+impl EffectConversion {
+    /// Creates a conversion of `attribute` into `effect_id`.
+    pub fn new(
+        attribute: impl Into<Atom>,
+        effect_id: impl Into<Atom>,
+        coefficient: f32,
+        data_tag: GameplayTag,
+    ) -> Self {
+        Self {
+            attribute: attribute.into(),
+            effect_id: effect_id.into(),
+            coefficient,
+            data_tag,
+        }
+    }
+
+    /// Builds the application for the source of `spec`, given the attribute
+    /// changes the effect made on its target.
+    ///
+    /// The amount is the absolute change, so damage and heals both convert
+    /// into a positive magnitude. Returns `None` without a source or when the
+    /// attribute didn't change.
+    pub(crate) fn application(
+        &self,
+        spec: &GameplayEffectSpec,
+        changes: &[(Atom, f32)],
+    ) -> Option<ApplyGameplayEffectEvent> {
+        let source = spec.source_entity()?;
+        let amount: f32 = changes
+            .iter()
+            .filter(|(name, _)| *name == self.attribute)
+            .map(|(_, delta)| delta.abs())
+            .sum();
+        if amount <= 0.0 {
+            return None;
+        }
+
+        let mut converted = GameplayEffectSpec::new(self.effect_id.clone(), source);
+        converted.level = spec.level;
+        converted.context = spec.context.clone();
+        converted.prediction_key = spec.prediction_key;
+        converted
+            .set_by_caller_magnitudes
+            .set_magnitude(self.data_tag.clone(), amount * self.coefficient);
+        Some(ApplyGameplayEffectEvent::from_spec(converted))
+    }
+}
+
+/// Reflects part of the damage taken while the carrying effect is active.
+///
+/// # Example
+/// ```ignore
+/// registry.register(
+///     GameplayEffectDefinition::new("thorns_aura")
+///         .with_duration_policy(DurationPolicy::Infinite)
+///         // Return 30% of the health lost to the attacker.
+///         .add_reflection(DamageReflection::new("Health", "thorns_damage", 0.3, damage_tag)),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DamageReflection {
+    /// Victim attribute whose losses are reflected.
+    pub attribute: Atom,
+    /// Effect applied to the attacker.
+    pub effect_id: Atom,
+    /// Fraction of the loss reflected.
+    pub fraction: f32,
+    /// SetByCaller tag carrying the reflected amount.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::gameplay_tag_serde")
+    )]
+    pub data_tag: GameplayTag,
+}
+
+impl DamageReflection {
+    /// Creates a reflection of losses to `attribute` as `effect_id`.
+    pub fn new(
+        attribute: impl Into<Atom>,
+        effect_id: impl Into<Atom>,
+        fraction: f32,
+        data_tag: GameplayTag,
+    ) -> Self {
+        Self {
+            attribute: attribute.into(),
+            effect_id: effect_id.into(),
+            fraction,
+            data_tag,
+        }
+    }
+
+    /// Builds the application that reflects the damage `spec` dealt to
+    /// `victim`, given the attribute changes it made.
+    ///
+    /// The reflected magnitude is negative like the loss, so a SetByCaller
+    /// `AddBase` modifier deals it as damage. Returns `None` for damage that
+    /// was itself reflected, damage the victim dealt to themselves, or when
+    /// the attribute didn't drop.
+    pub(crate) fn application(
+        &self,
+        victim: Entity,
+        level: i32,
+        spec: &GameplayEffectSpec,
+        changes: &[(Atom, f32)],
+    ) -> Option<ApplyGameplayEffectEvent> {
+        if spec.context.reflected {
+            return None;
+        }
+        let attacker = spec
+            .source_entity()
+            .filter(|&attacker| attacker != victim)?;
+        let amount: f32 = changes
+            .iter()
+            .filter(|(name, delta)| *name == self.attribute && *delta < 0.0)
+            .map(|(_, delta)| -delta)
+            .sum();
+        if amount <= 0.0 {
+            return None;
+        }
+
+        let mut reflected = GameplayEffectSpec::new(self.effect_id.clone(), attacker);
+        reflected.level = level;
+        reflected.context = GameplayEffectContext::new()
+            .with_source(victim)
+            .as_reflected();
+        reflected
+            .set_by_caller_magnitudes
+            .set_magnitude(self.data_tag.clone(), -amount * self.fraction);
+        Some(ApplyGameplayEffectEvent::from_spec(reflected))
+    }
+}
@@ -3,6 +3,8 @@
 //! Allows users to implement complex magnitude calculations that can capture
 //! multiple attributes from source and target entities.
 
+use super::execution::AttributeCaptureDefinition;
+use crate::error::{GasError, GasResult};
 use bevy::prelude::*;
 use std::collections::HashMap;
 use string_cache::DefaultAtom as Atom;
@@ -85,6 +87,34 @@ pub trait CustomMagnitudeCalculation: Send + Sync {
     fn required_target_attributes(&self) -> &[&'static str] {
         &[]
     }
+
+    /// Returns the attributes this calculator captures.
+    ///
+    /// Defaults to dynamic captures of [`required_source_attributes`] and
+    /// [`required_target_attributes`]. Override it to snapshot attributes at
+    /// application time, e.g. the caster's spell power for a damage over
+    /// time effect.
+    ///
+    /// [`required_source_attributes`]: Self::required_source_attributes
+    /// [`required_target_attributes`]: Self::required_target_attributes
+    fn relevant_attributes_to_capture(&self) -> Vec<AttributeCaptureDefinition> {
+        self.required_source_attributes()
+            .iter()
+            .map(|name| AttributeCaptureDefinition::dynamic_source(*name))
+            .chain(
+                self.required_target_attributes()
+                    .iter()
+                    .map(|name| AttributeCaptureDefinition::dynamic_target(*name)),
+            )
+            .collect()
+    }
+
+    /// Whether every attribute of the source and target is captured.
+    ///
+    /// For calculators whose needs are only known at runtime, such as scripts.
+    fn captures_all_attributes(&self) -> bool {
+        false
+    }
 }
 
 /// Registry for custom magnitude calculators.
@@ -118,4 +148,12 @@ impl CustomCalculationRegistry {
     pub fn get(&self, name: &Atom) -> Option<&dyn CustomMagnitudeCalculation> {
         self.calculators.get(name).map(|b| b.as_ref())
     }
+
+    /// Gets a calculator by name, or an error naming the missing calculator.
+    pub fn try_get(&self, name: &Atom) -> GasResult<&dyn CustomMagnitudeCalculation> {
+        self.get(name)
+            .ok_or_else(|| GasError::CustomCalculationNotFound {
+                calculator_name: name.clone(),
+            })
+    }
 }
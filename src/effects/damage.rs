@@ -0,0 +1,431 @@
+//! Damage calculation building blocks.
+//!
+//! Effects with a [`DamageCalculation`] run their damage modifiers through a
+//! crit roll and a chain of mitigation formulas before the attribute changes.
+//! A damage modifier is a negative `AddBase`/`AddCurrent` modifier on one of
+//! the attributes the calculation declares as taking damage; heals,
+//! multipliers and drains of other attributes (mana, armor) pass through
+//! untouched.
+//!
+//! The crit roll uses the [`CritCalculation`] set on the
+//! [`DamageCalculationRegistry`], which defaults to [`AttributeCrit`] reading
+//! the source's `CritChance` and `CritDamage` attributes. Mitigations are
+//! registered by name and listed on the calculation in the order they apply.
+//! An [`AbsorbMitigation`] spends a target attribute, like a shield, on the
+//! damage it removes before the damage lands.
+//!
+//! # Example
+//! ```ignore
+//! fn setup(
+//!     mut damage: ResMut<DamageCalculationRegistry>,
+//!     mut effects: ResMut<GameplayEffectRegistry>,
+//! ) {
+//!     damage.register_mitigation("Armor", ArmorMitigation::default());
+//!     damage.register_mitigation("FireResistance", ResistanceMitigation::new("FireResistance"));
+//!
+//!     effects.register(
+//!         GameplayEffectDefinition::new("fireball")
+//!             .add_modifier(ModifierInfo::new(
+//!                 "Health",
+//!                 ModifierOperation::AddBase,
+//!                 MagnitudeCalculation::scalar(-50.0),
+//!             ))
+//!             .with_damage(
+//!                 DamageCalculation::new()
+//!                     .with_damage_attribute("Health")
+//!                     .with_crit()
+//!                     .with_mitigation("Armor")
+//!                     .with_mitigation("FireResistance"),
+//!             ),
+//!     );
+//! }
+//! ```
+
+use super::application_requirement::ApplicationAttributeSnapshot;
+use super::components::ModifierOperation;
+use super::definition::ModifierInfo;
+use crate::core::GasRng;
+use crate::error::{GasError, GasReportExt, GasResult};
+use bevy::prelude::*;
+use std::collections::HashMap;
+use string_cache::DefaultAtom as Atom;
+
+/// How an effect turns its negative additive modifiers into damage.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DamageCalculation {
+    /// Attributes taking damage. Modifiers on other attributes are left
+    /// alone.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub damage_attributes: Vec<Atom>,
+    /// Whether the damage may critically hit.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub can_crit: bool,
+    /// Names of the registered mitigations, applied in order.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mitigations: Vec<Atom>,
+}
+
+impl DamageCalculation {
+    /// Creates a calculation with no damaged attribute, no crit and no
+    /// mitigation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares `name` as an attribute taking damage.
+    pub fn with_damage_attribute(mut self, name: impl Into<Atom>) -> Self {
+        self.damage_attributes.push(name.into());
+        self
+    }
+
+    /// Returns whether `modifier`, evaluated to `magnitude`, deals damage.
+    pub fn is_damage(&self, modifier: &ModifierInfo, magnitude: f32) -> bool {
+        matches!(
+            modifier.operation,
+            ModifierOperation::AddBase | ModifierOperation::AddCurrent
+        ) && magnitude < 0.0
+            && self.damage_attributes.contains(&modifier.attribute_name)
+    }
+
+    /// Lets the damage critically hit.
+    pub fn with_crit(mut self) -> Self {
+        self.can_crit = true;
+        self
+    }
+
+    /// Appends a registered mitigation.
+    pub fn with_mitigation(mut self, name: impl Into<Atom>) -> Self {
+        self.mitigations.push(name.into());
+        self
+    }
+}
+
+/// Attributes visible to crit and mitigation formulas.
+#[derive(Debug, Clone, Copy)]
+pub struct DamageContext<'a> {
+    /// The entity dealing the damage, if known.
+    pub source: Option<Entity>,
+    /// The entity taking the damage.
+    pub target: Entity,
+    /// The level of the effect.
+    pub level: i32,
+    /// Attribute values captured when the effect executed.
+    pub attributes: &'a [ApplicationAttributeSnapshot],
+    /// Amounts absorbing mitigations took off attributes since the values
+    /// were captured, by owner and attribute.
+    pub absorbed: &'a [(Entity, Atom, f32)],
+}
+
+impl DamageContext<'_> {
+    /// Gets the current value of a source attribute.
+    pub fn source_attribute(&self, attribute_name: &str) -> Option<f32> {
+        self.source
+            .and_then(|source| self.attribute(source, attribute_name))
+    }
+
+    /// Gets the current value of a target attribute.
+    pub fn target_attribute(&self, attribute_name: &str) -> Option<f32> {
+        self.attribute(self.target, attribute_name)
+    }
+
+    fn attribute(&self, owner: Entity, attribute_name: &str) -> Option<f32> {
+        let value = self
+            .attributes
+            .iter()
+            .find(|snapshot| {
+                snapshot.owner == owner && snapshot.attribute_name.as_ref() == attribute_name
+            })?
+            .current_value;
+        let absorbed: f32 = self
+            .absorbed
+            .iter()
+            .filter(|(entity, name, _)| *entity == owner && name.as_ref() == attribute_name)
+            .map(|(_, _, amount)| amount)
+            .sum();
+        Some(value - absorbed)
+    }
+}
+
+/// Decides whether a hit is critical.
+pub trait CritCalculation: Send + Sync {
+    /// Returns the damage multiplier for a critical hit, or `None` for a
+    /// normal hit. `roll` is uniform in `[0, 1)` and drawn from [`GasRng`].
+    fn crit_multiplier(&self, ctx: &DamageContext, roll: f32) -> Option<f32>;
+}
+
+/// Crits when `roll < CritChance`, multiplying damage by `CritDamage`.
+///
+/// Both attributes are read from the source. Without a chance attribute the
+/// hit never crits; without a damage attribute `default_multiplier` is used.
+#[derive(Debug, Clone)]
+pub struct AttributeCrit {
+    /// Source attribute holding the crit chance (0.0 to 1.0).
+    pub chance_attribute: Atom,
+    /// Source attribute holding the crit damage multiplier.
+    pub damage_attribute: Atom,
+    /// Multiplier used when the source has no damage attribute.
+    pub default_multiplier: f32,
+}
+
+impl Default for AttributeCrit {
+    fn default() -> Self {
+        Self {
+            chance_attribute: Atom::from("CritChance"),
+            damage_attribute: Atom::from("CritDamage"),
+            default_multiplier: 1.5,
+        }
+    }
+}
+
+impl CritCalculation for AttributeCrit {
+    fn crit_multiplier(&self, ctx: &DamageContext, roll: f32) -> Option<f32> {
+        let chance = ctx.source_attribute(&self.chance_attribute)?;
+        (roll < chance).then(|| {
+            ctx.source_attribute(&self.damage_attribute)
+                .unwrap_or(self.default_multiplier)
+        })
+    }
+}
+
+/// Reduces incoming damage.
+pub trait DamageMitigation: Send + Sync {
+    /// Returns the damage left after mitigation. `damage` is positive.
+    fn mitigate(&self, damage: f32, ctx: &DamageContext) -> f32;
+
+    /// Target attribute spent by the damage this mitigation removes, like
+    /// the remaining amount of a shield. `None` for reductions that cost
+    /// nothing.
+    fn absorbing_attribute(&self) -> Option<&Atom> {
+        None
+    }
+}
+
+/// Diminishing armor: `damage * constant / (constant + armor)`.
+///
+/// Negative armor is treated as zero. With no armor and a zero constant the
+/// damage passes through unmitigated.
+#[derive(Debug, Clone)]
+pub struct ArmorMitigation {
+    /// Target attribute holding the armor value.
+    pub attribute: Atom,
+    /// Armor at which damage is halved.
+    pub constant: f32,
+}
+
+impl ArmorMitigation {
+    /// Creates an armor mitigation reading `attribute`.
+    pub fn new(attribute: impl Into<Atom>, constant: f32) -> Self {
+        Self {
+            attribute: attribute.into(),
+            constant,
+        }
+    }
+}
+
+impl Default for ArmorMitigation {
+    fn default() -> Self {
+        Self::new("Armor", 100.0)
+    }
+}
+
+impl DamageMitigation for ArmorMitigation {
+    fn mitigate(&self, damage: f32, ctx: &DamageContext) -> f32 {
+        let armor = ctx
+            .target_attribute(&self.attribute)
+            .unwrap_or(0.0)
+            .max(0.0);
+        let denominator = self.constant + armor;
+        if denominator <= 0.0 {
+            return damage;
+        }
+        damage * self.constant / denominator
+    }
+}
+
+/// Percentage resistance: `damage * (1 - resistance)`.
+///
+/// Resistance is clamped to `[0, 1]`.
+#[derive(Debug, Clone)]
+pub struct ResistanceMitigation {
+    /// Target attribute holding the resistance fraction.
+    pub attribute: Atom,
+}
+
+impl ResistanceMitigation {
+    /// Creates a resistance mitigation reading `attribute`.
+    pub fn new(attribute: impl Into<Atom>) -> Self {
+        Self {
+            attribute: attribute.into(),
+        }
+    }
+}
+
+impl DamageMitigation for ResistanceMitigation {
+    fn mitigate(&self, damage: f32, ctx: &DamageContext) -> f32 {
+        let resistance = ctx
+            .target_attribute(&self.attribute)
+            .unwrap_or(0.0)
+            .clamp(0.0, 1.0);
+        damage * (1.0 - resistance)
+    }
+}
+
+/// Absorbs damage into a target attribute, like a shield.
+///
+/// The attribute's current value soaks up damage until it reaches zero, and
+/// the absorbed amount is taken off its base and current values.
+#[derive(Debug, Clone)]
+pub struct AbsorbMitigation {
+    /// Target attribute holding the amount left to absorb.
+    pub attribute: Atom,
+}
+
+impl AbsorbMitigation {
+    /// Creates an absorb mitigation spending `attribute`.
+    pub fn new(attribute: impl Into<Atom>) -> Self {
+        Self {
+            attribute: attribute.into(),
+        }
+    }
+}
+
+impl DamageMitigation for AbsorbMitigation {
+    fn mitigate(&self, damage: f32, ctx: &DamageContext) -> f32 {
+        let remaining = ctx
+            .target_attribute(&self.attribute)
+            .unwrap_or(0.0)
+            .max(0.0);
+        (damage - remaining).max(0.0)
+    }
+
+    fn absorbing_attribute(&self) -> Option<&Atom> {
+        Some(&self.attribute)
+    }
+}
+
+/// Registry for the crit calculation and named mitigations.
+#[derive(Resource)]
+pub struct DamageCalculationRegistry {
+    crit: Box<dyn CritCalculation>,
+    mitigations: HashMap<Atom, Box<dyn DamageMitigation>>,
+}
+
+impl Default for DamageCalculationRegistry {
+    fn default() -> Self {
+        Self {
+            crit: Box::new(AttributeCrit::default()),
+            mitigations: HashMap::new(),
+        }
+    }
+}
+
+impl DamageCalculationRegistry {
+    /// Replaces the crit calculation.
+    pub fn set_crit_calculation(&mut self, crit: impl CritCalculation + 'static) {
+        self.crit = Box::new(crit);
+    }
+
+    /// Registers a mitigation under `name`.
+    pub fn register_mitigation(
+        &mut self,
+        name: impl Into<Atom>,
+        mitigation: impl DamageMitigation + 'static,
+    ) {
+        self.mitigations.insert(name.into(), Box::new(mitigation));
+    }
+
+    /// Gets a mitigation by name.
+    pub fn mitigation(&self, name: &Atom) -> Option<&dyn DamageMitigation> {
+        self.mitigations.get(name).map(|b| b.as_ref())
+    }
+
+    /// Gets a mitigation by name, or a
+    /// [`GasError::DamageMitigationNotFound`].
+    pub fn try_mitigation(&self, name: &Atom) -> GasResult<&dyn DamageMitigation> {
+        self.mitigation(name)
+            .ok_or_else(|| GasError::DamageMitigationNotFound {
+                mitigation_name: name.clone(),
+            })
+    }
+
+    /// Runs the damage modifiers among `magnitudes` through crit and
+    /// mitigation, in place.
+    ///
+    /// `magnitudes` holds one evaluated magnitude per entry of `modifiers`.
+    /// Without a calculation the magnitudes are left as they are.
+    /// Mitigations missing from the registry are reported and skipped.
+    pub(crate) fn apply(
+        &self,
+        commands: &mut Commands,
+        calculation: Option<&DamageCalculation>,
+        modifiers: &[ModifierInfo],
+        magnitudes: &mut [(Atom, f32)],
+        ctx: &DamageContext,
+        rng: &mut GasRng,
+    ) -> AppliedDamage {
+        let mut applied = AppliedDamage {
+            pre_mitigation: magnitudes.iter().map(|&(_, magnitude)| magnitude).collect(),
+            critical: false,
+            absorbed: Vec::new(),
+        };
+        let Some(calculation) = calculation.filter(|calculation| {
+            modifiers
+                .iter()
+                .zip(magnitudes.iter())
+                .any(|(modifier, (_, magnitude))| calculation.is_damage(modifier, *magnitude))
+        }) else {
+            return applied;
+        };
+
+        // Later damage modifiers of the hit see what earlier ones absorbed
+        let mut absorbed = ctx.absorbed.to_vec();
+        let crit_multiplier = if calculation.can_crit {
+            self.crit.crit_multiplier(ctx, rng.next_f32())
+        } else {
+            None
+        };
+
+        for ((modifier, (_, magnitude)), pre_mitigation) in modifiers
+            .iter()
+            .zip(magnitudes.iter_mut())
+            .zip(applied.pre_mitigation.iter_mut())
+        {
+            if !calculation.is_damage(modifier, *magnitude) {
+                continue;
+            }
+            let mut damage = -*magnitude * crit_multiplier.unwrap_or(1.0);
+            *pre_mitigation = -damage;
+            for name in &calculation.mitigations {
+                let Some(mitigation) = self.try_mitigation(name).or_report(commands) else {
+                    continue;
+                };
+                let mitigation_ctx = DamageContext {
+                    absorbed: &absorbed,
+                    ..*ctx
+                };
+                let mitigated = mitigation.mitigate(damage, &mitigation_ctx).max(0.0);
+                if let Some(attribute) = mitigation.absorbing_attribute()
+                    && mitigated < damage
+                {
+                    absorbed.push((ctx.target, attribute.clone(), damage - mitigated));
+                }
+                damage = mitigated;
+            }
+            *magnitude = -damage.max(0.0);
+        }
+        applied.critical = crit_multiplier.is_some();
+        applied.absorbed = absorbed.split_off(ctx.absorbed.len());
+        applied
+    }
+}
+
+/// What [`DamageCalculationRegistry::apply`] did to a hit.
+pub(crate) struct AppliedDamage {
+    /// The magnitudes with damage after the crit, before mitigation.
+    pub pre_mitigation: Vec<f32>,
+    /// Whether the hit was critical.
+    pub critical: bool,
+    /// Amounts absorbing mitigations took off the target's attributes.
+    pub absorbed: Vec<(Entity, Atom, f32)>,
+}
@@ -2,9 +2,15 @@
 //!
 //! This module defines the structure of gameplay effects and their properties.
 
-use super::components::{EvaluationChannel, ModifierOperation};
-use super::execution::GameplayEffectExecutionCalculation;
+use super::callbacks::{EffectCallback, EffectCallbackContext};
+use super::components::{EvaluationChannel, GameplayEffectSpec, ModifierOperation};
+use super::custom_calculation::CustomCalculationRegistry;
+use super::dispel::DispelCategory;
+use super::execution::{AttributeCaptureDefinition, GameplayEffectExecutionCalculation};
+use crate::core::{DisplayInfo, EffectId, IdTable, Team, format_number, format_template};
 use crate::cues::manager::GameplayCueParameters;
+use crate::error::{GasError, GasResult};
+use bevy::ecs::system::SystemId;
 use bevy::prelude::*;
 use bevy_gameplay_tag::{
     GameplayTagContainer, GameplayTagRequirements, GameplayTagsManager, gameplay_tag::GameplayTag,
@@ -13,7 +19,9 @@ use std::sync::Arc;
 use string_cache::DefaultAtom as Atom;
 
 /// Policy for handling granted abilities when the effect is removed.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AbilityRemovalPolicy {
     /// Cancel the ability immediately when the effect is removed.
     CancelAbilityImmediately,
@@ -31,10 +39,12 @@ impl Default for AbilityRemovalPolicy {
 
 /// Describes an ability to be granted by an effect.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GrantedAbilityConfig {
     /// The ability definition ID to grant.
     pub ability_id: Atom,
     /// How to handle the ability when the effect is removed.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub removal_policy: AbilityRemovalPolicy,
 }
 
@@ -52,6 +62,7 @@ impl GrantedAbilityConfig {
     }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DurationPolicy {
     /// Effect applies instantly and is removed immediately.
     Instant,
@@ -63,6 +74,7 @@ pub enum DurationPolicy {
 
 /// Stacking policy for gameplay effects.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StackingPolicy {
     /// Each application is independent.
     Independent,
@@ -72,11 +84,55 @@ pub enum StackingPolicy {
     StackCount { max_stacks: i32 },
 }
 
+/// Which targets an effect may be applied to, relative to its source's [`Team`].
+///
+/// Actors without a `Team` are neither friendly nor hostile, so team-restricted
+/// effects without a source never apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EffectTeamPolicy {
+    /// Applies to any target.
+    #[default]
+    Any,
+    /// Applies only to targets on another team than the source.
+    HostileOnly,
+    /// Applies only to the source and targets on its team.
+    FriendlyOnly,
+    /// Applies only to the source itself.
+    SelfOnly,
+}
+
+impl EffectTeamPolicy {
+    /// Returns true if an effect from `source` may be applied to `target`.
+    pub fn allows(
+        self,
+        source: Option<Entity>,
+        target: Entity,
+        team_of: impl Fn(Entity) -> Option<Team>,
+    ) -> bool {
+        let is_self = source == Some(target);
+        let same_team = || {
+            let source_team = source.and_then(&team_of);
+            let target_team = team_of(target);
+            source_team
+                .zip(target_team)
+                .map(|(source_team, target_team)| source_team == target_team)
+        };
+        match self {
+            EffectTeamPolicy::Any => true,
+            EffectTeamPolicy::SelfOnly => is_self,
+            EffectTeamPolicy::FriendlyOnly => is_self || same_team() == Some(true),
+            EffectTeamPolicy::HostileOnly => !is_self && same_team() == Some(false),
+        }
+    }
+}
+
 /// Attribute calculation type.
 ///
 /// Defines which value to use when capturing an attribute for magnitude calculation.
 /// Matches UE GAS's `EAttributeBasedFloatCalculationType`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AttributeCalculationType {
     /// Use the final evaluated magnitude (current_value).
     AttributeMagnitude,
@@ -90,6 +146,7 @@ pub enum AttributeCalculationType {
 ///
 /// Defines whether to capture the attribute from the source (instigator) or target.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AttributeCaptureSource {
     /// Capture from the source entity (instigator).
     Source,
@@ -102,6 +159,7 @@ pub enum AttributeCaptureSource {
 /// Defines when the attribute value is captured for magnitude calculation.
 /// Matches UE GAS's snapshot vs dynamic evaluation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AttributeCaptureMode {
     /// Capture attribute value when the effect is created (snapshot).
     /// The captured value never changes, even if the source attribute changes.
@@ -127,6 +185,7 @@ impl Default for AttributeCaptureMode {
 /// Defines how the magnitude of a modifier is calculated.
 /// Follows UE GAS's magnitude calculation system.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MagnitudeCalculation {
     /// A fixed scalar value (optionally scaled by level).
     ///
@@ -183,6 +242,7 @@ pub enum MagnitudeCalculation {
     ///
     /// MagnitudeCalculation::execution(Arc::new(DamageCalculation))
     /// ```
+    #[cfg_attr(feature = "serde", serde(skip))]
     CustomExecution {
         /// The execution calculation to use.
         calculation: Arc<dyn GameplayEffectExecutionCalculation>,
@@ -194,6 +254,10 @@ pub enum MagnitudeCalculation {
     /// If not provided, defaults to 0.0.
     SetByCaller {
         /// Tag identifying this magnitude value.
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::gameplay_tag_serde")
+        )]
         data_tag: GameplayTag,
     },
 }
@@ -420,6 +484,21 @@ impl MagnitudeCalculation {
         }
     }
 
+    /// Returns `true` if the magnitude doesn't depend on the target, so
+    /// applications sharing a level and source can evaluate it once for all
+    /// their targets.
+    pub fn is_target_independent(&self) -> bool {
+        match self {
+            Self::ScalableFloat { .. } => true,
+            Self::AttributeBased { capture_source, .. } => {
+                matches!(capture_source, AttributeCaptureSource::Source)
+            }
+            Self::CustomClass { .. } | Self::CustomExecution { .. } | Self::SetByCaller { .. } => {
+                false
+            }
+        }
+    }
+
     /// Evaluates the magnitude given a level and optional source value.
     ///
     /// For AttributeBased calculations, pass the captured attribute value as `source_value`.
@@ -450,10 +529,8 @@ impl MagnitudeCalculation {
                 source_value.unwrap_or(0.0)
             }
             MagnitudeCalculation::CustomClass { .. } => {
-                // Custom calculators are looked up from a registry
-                // For now, return 0.0 as placeholder
-                warn!("Custom calculation not yet implemented");
-                0.0
+                // The calculator's result, looked up from the registry by the caller
+                source_value.unwrap_or(0.0)
             }
             MagnitudeCalculation::CustomExecution { .. } => {
                 // CustomExecution produces multiple modifiers, not a single magnitude
@@ -467,6 +544,7 @@ impl MagnitudeCalculation {
 
 /// Information about a modifier in an effect.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModifierInfo {
     /// The name of the attribute to modify.
     pub attribute_name: Atom,
@@ -475,6 +553,7 @@ pub struct ModifierInfo {
     /// How to calculate the magnitude.
     pub magnitude: MagnitudeCalculation,
     /// The evaluation channel for this modifier.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub channel: EvaluationChannel,
 }
 
@@ -502,14 +581,27 @@ impl ModifierInfo {
 
 /// GameplayCue configuration attached to an effect definition.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameplayEffectCue {
     /// Tag routed through the cue system.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::gameplay_tag_serde")
+    )]
     pub cue_tag: GameplayTag,
     /// Minimum effect level for this cue to fire.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub min_level: i32,
     /// Maximum effect level for this cue to fire.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub max_level: Option<i32>,
+    /// Attribute whose evaluated modifier supplies the cue's raw magnitude.
+    ///
+    /// `None` uses the first modifier of the effect.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub magnitude_attribute: Option<Atom>,
     /// Override parameters merged onto those derived from the effect spec/context.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub parameters: GameplayCueParameters,
 }
 
@@ -519,6 +611,7 @@ impl GameplayEffectCue {
             cue_tag,
             min_level: 0,
             max_level: None,
+            magnitude_attribute: None,
             parameters: GameplayCueParameters::new(),
         }
     }
@@ -534,6 +627,24 @@ impl GameplayEffectCue {
         self
     }
 
+    pub fn with_magnitude_attribute(mut self, attribute_name: impl Into<Atom>) -> Self {
+        self.magnitude_attribute = Some(attribute_name.into());
+        self
+    }
+
+    /// Maps `level` into `[0, 1]` across the cue's level range.
+    ///
+    /// Open-ended or empty ranges yield `1.0`, so a cue without a configured
+    /// range always plays at full intensity.
+    pub fn normalized_level(&self, level: i32) -> f32 {
+        match self.max_level {
+            Some(max_level) if max_level > self.min_level => ((level - self.min_level) as f32
+                / (max_level - self.min_level) as f32)
+                .clamp(0.0, 1.0),
+            _ => 1.0,
+        }
+    }
+
     pub fn applies_to_level(&self, level: i32) -> bool {
         if level < self.min_level {
             return false;
@@ -551,42 +662,103 @@ impl GameplayEffectCue {
 /// This is the template for creating active effect instances.
 /// Store these in a resource or asset system.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameplayEffectDefinition {
     /// Unique identifier for this effect.
     pub id: Atom,
     /// Duration policy.
     pub duration_policy: DurationPolicy,
     /// Duration in seconds (if HasDuration).
+    #[cfg_attr(feature = "serde", serde(default))]
     pub duration_magnitude: f32,
     /// Period for periodic effects (0.0 = not periodic).
+    #[cfg_attr(feature = "serde", serde(default))]
     pub period: f32,
     /// Modifiers applied by this effect.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub modifiers: Vec<ModifierInfo>,
     /// Tags granted while this effect is active.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::tag_container_serde")
+    )]
     pub granted_tags: GameplayTagContainer,
     /// Tags that identify this effect (for immunity checks).
     /// If a target has any of these tags in their immunity_tags, the effect is rejected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::tag_container_serde")
+    )]
     pub asset_tags: GameplayTagContainer,
     /// Tags that grant immunity to effects.
     /// If this effect has any of these tags, targets with matching immunity_tags will reject it.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::serialization::tag_container_serde")
+    )]
     pub immunity_tags: GameplayTagContainer,
     /// Tag requirements for applying this effect.
+    ///
+    /// Not serialized; `GameplayTagRequirements` does not expose its tags.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub application_tag_requirements: GameplayTagRequirements,
     /// Custom application requirements that must all pass before the effect applies.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub application_requirements: Vec<Atom>,
     /// Stacking policy.
     pub stacking_policy: StackingPolicy,
+    /// Order among same-frame applications to a target: higher priorities
+    /// apply first, equal ones in the order they were sent. A triggered
+    /// application first applies the higher priority ones still queued in
+    /// [`BatchedEvents<ApplyGameplayEffectEvent>`](crate::core::BatchedEvents)
+    /// for its target. A shield at a higher priority than damage absorbs
+    /// that damage.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub priority: i32,
+    /// Targets this effect may be applied to, relative to the source's team.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub team_policy: EffectTeamPolicy,
     /// Abilities granted while this effect is active.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub granted_abilities: Vec<GrantedAbilityConfig>,
     /// Gameplay cues triggered by this effect.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub gameplay_cues: Vec<GameplayEffectCue>,
+    /// Crit and mitigation applied to the damage this effect deals.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub damage: Option<crate::effects::damage::DamageCalculation>,
+    /// Effects applied back to the source from what this effect dealt.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub conversions: Vec<crate::effects::conversion::EffectConversion>,
+    /// Damage reflected while this effect is active on a target.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub reflections: Vec<crate::effects::conversion::DamageReflection>,
+    /// Resources granted to the source each time this effect executes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub resource_gains: Vec<crate::effects::resource_gain::ResourceGain>,
+    /// Name, icon and description shown in buff bars and tooltips.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub display: Option<DisplayInfo>,
     /// Modular components that extend effect behavior (UE 5.3+ feature).
     ///
     /// Components are executed at specific lifecycle points:
     /// - `can_apply`: Before application (can block)
     /// - `on_effect_applied`: After successful application
     /// - `on_effect_removed`: When removed from target
+    ///
+    /// Not serialized; add components in code after loading a definition.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub components: Vec<crate::effects::ge_component::BoxedGameplayEffectComponent>,
+    /// Callbacks run when an instance is applied, refreshed or stacked.
+    ///
+    /// Not serialized; add callbacks in code after loading a definition.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub on_applied: Vec<EffectCallback>,
+    /// Callbacks run when an instance is removed.
+    ///
+    /// Not serialized; add callbacks in code after loading a definition.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub on_removed: Vec<EffectCallback>,
 }
 
 impl std::fmt::Debug for GameplayEffectDefinition {
@@ -606,12 +778,27 @@ impl std::fmt::Debug for GameplayEffectDefinition {
             )
             .field("application_requirements", &self.application_requirements)
             .field("stacking_policy", &self.stacking_policy)
+            .field("priority", &self.priority)
+            .field("team_policy", &self.team_policy)
             .field("granted_abilities", &self.granted_abilities)
             .field("gameplay_cues", &self.gameplay_cues)
+            .field("damage", &self.damage)
+            .field("conversions", &self.conversions)
+            .field("reflections", &self.reflections)
+            .field("resource_gains", &self.resource_gains)
+            .field("display", &self.display)
             .field(
                 "components",
                 &format!("{} components", self.components.len()),
             )
+            .field(
+                "on_applied",
+                &format!("{} callbacks", self.on_applied.len()),
+            )
+            .field(
+                "on_removed",
+                &format!("{} callbacks", self.on_removed.len()),
+            )
             .finish()
     }
 }
@@ -629,9 +816,18 @@ impl PartialEq for GameplayEffectDefinition {
             && self.application_tag_requirements == other.application_tag_requirements
             && self.application_requirements == other.application_requirements
             && self.stacking_policy == other.stacking_policy
+            && self.priority == other.priority
+            && self.team_policy == other.team_policy
             && self.granted_abilities == other.granted_abilities
             && self.gameplay_cues == other.gameplay_cues
+            && self.damage == other.damage
+            && self.conversions == other.conversions
+            && self.reflections == other.reflections
+            && self.resource_gains == other.resource_gains
+            && self.display == other.display
             && self.components.len() == other.components.len()
+            && self.on_applied.len() == other.on_applied.len()
+            && self.on_removed.len() == other.on_removed.len()
     }
 }
 
@@ -650,9 +846,18 @@ impl GameplayEffectDefinition {
             application_tag_requirements: GameplayTagRequirements::default(),
             application_requirements: Vec::new(),
             stacking_policy: StackingPolicy::Independent,
+            priority: 0,
+            team_policy: EffectTeamPolicy::Any,
             granted_abilities: Vec::new(),
             gameplay_cues: Vec::new(),
+            damage: None,
+            conversions: Vec::new(),
+            reflections: Vec::new(),
+            resource_gains: Vec::new(),
+            display: None,
             components: Vec::new(),
+            on_applied: Vec::new(),
+            on_removed: Vec::new(),
         }
     }
 
@@ -697,6 +902,16 @@ impl GameplayEffectDefinition {
         self
     }
 
+    /// Puts the effect in a dispel category, so cleanses of that category
+    /// remove it.
+    pub fn with_dispel_category(
+        self,
+        category: DispelCategory,
+        tags_manager: &Res<GameplayTagsManager>,
+    ) -> Self {
+        self.with_asset_tag(category.tag(), tags_manager)
+    }
+
     /// Adds an immunity tag.
     ///
     /// Effects with these tags can be blocked by targets that have matching immunity.
@@ -727,12 +942,135 @@ impl GameplayEffectDefinition {
         self
     }
 
+    /// Sets the order among same-frame applications to a target; higher
+    /// priorities apply first.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets which targets this effect may be applied to.
+    pub fn with_team_policy(mut self, policy: EffectTeamPolicy) -> Self {
+        self.team_policy = policy;
+        self
+    }
+
     /// Adds a gameplay cue triggered by this effect.
     pub fn add_gameplay_cue(mut self, cue: GameplayEffectCue) -> Self {
         self.gameplay_cues.push(cue);
         self
     }
 
+    /// Runs this effect's damage through crit and mitigation.
+    ///
+    /// See [`DamageCalculation`](crate::effects::damage::DamageCalculation).
+    pub fn with_damage(mut self, damage: crate::effects::damage::DamageCalculation) -> Self {
+        self.damage = Some(damage);
+        self
+    }
+
+    /// Adds a conversion, such as lifesteal, applied back to the source.
+    ///
+    /// See [`EffectConversion`](crate::effects::conversion::EffectConversion).
+    pub fn add_conversion(
+        mut self,
+        conversion: crate::effects::conversion::EffectConversion,
+    ) -> Self {
+        self.conversions.push(conversion);
+        self
+    }
+
+    /// Adds a damage reflection, such as thorns, active while this effect is.
+    ///
+    /// See [`DamageReflection`](crate::effects::conversion::DamageReflection).
+    pub fn add_reflection(
+        mut self,
+        reflection: crate::effects::conversion::DamageReflection,
+    ) -> Self {
+        self.reflections.push(reflection);
+        self
+    }
+
+    /// Adds a resource, such as rage on hit, granted to the source each time
+    /// this effect executes.
+    ///
+    /// See [`ResourceGain`](crate::effects::resource_gain::ResourceGain).
+    pub fn add_resource_gain(
+        mut self,
+        resource_gain: crate::effects::resource_gain::ResourceGain,
+    ) -> Self {
+        self.resource_gains.push(resource_gain);
+        self
+    }
+
+    /// Sets the name, icon and description shown in UI.
+    pub fn with_display(mut self, display: DisplayInfo) -> Self {
+        self.display = Some(display);
+        self
+    }
+
+    /// Resolves the description template of [`Self::display`] for `spec`.
+    ///
+    /// Placeholders:
+    /// - `{level}`, `{duration}`, `{period}`
+    /// - `{modifier.N}`: magnitude of the Nth modifier, as a positive number
+    /// - `{set_by_caller.Tag.Name}`: a SetByCaller magnitude of the spec
+    ///
+    /// Attribute-based magnitudes resolve from the spec's captured
+    /// attributes; custom calculations and executions are left as written.
+    pub fn format_description(&self, spec: &GameplayEffectSpec) -> Option<String> {
+        let template = self.display.as_ref()?.description.as_ref()?;
+        Some(format_template(template, |key| {
+            let value = match key {
+                "level" => spec.level as f32,
+                "duration" => self.duration_magnitude,
+                "period" => self.period,
+                _ => {
+                    if let Some(index) = key.strip_prefix("modifier.") {
+                        let modifier = self.modifiers.get(index.parse::<usize>().ok()?)?;
+                        modifier_display_magnitude(&modifier.magnitude, spec)?.abs()
+                    } else {
+                        let tag = key.strip_prefix("set_by_caller.")?;
+                        spec.set_by_caller_magnitudes
+                            .get_magnitude(&GameplayTag::new(tag))?
+                    }
+                }
+            };
+            Some(format_number(value))
+        }))
+    }
+
+    /// Returns the attribute captures of this effect's custom calculations
+    /// and executions, without duplicates.
+    ///
+    /// The application system resolves them together in one pass over the
+    /// attributes, see [`CapturedAttributes`].
+    ///
+    /// [`CapturedAttributes`]: super::execution::CapturedAttributes
+    pub fn attribute_captures(
+        &self,
+        calculators: &CustomCalculationRegistry,
+    ) -> Vec<AttributeCaptureDefinition> {
+        let mut captures = Vec::new();
+        for modifier in &self.modifiers {
+            let declared = match &modifier.magnitude {
+                MagnitudeCalculation::CustomClass { calculator_name } => calculators
+                    .get(calculator_name)
+                    .map(|calculator| calculator.relevant_attributes_to_capture()),
+                MagnitudeCalculation::CustomExecution { calculation } => {
+                    Some(calculation.relevant_attributes_to_capture())
+                }
+                _ => None,
+            };
+            for capture in declared.into_iter().flatten() {
+                if !captures.contains(&capture) {
+                    captures.push(capture);
+                }
+            }
+        }
+        captures
+    }
+
     /// Grants an ability while this effect is active.
     ///
     /// The ability will be granted when the effect is applied and removed when the effect ends.
@@ -771,12 +1109,85 @@ impl GameplayEffectDefinition {
         self.components.push(component);
         self
     }
+
+    /// Adds a callback run when an instance is applied, refreshed or stacked.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let effect = GameplayEffectDefinition::new("enrage")
+    ///     .with_duration_policy(DurationPolicy::Infinite)
+    ///     .with_on_applied(|ctx, commands| {
+    ///         commands.entity(ctx.target).insert(AiState::Berserk);
+    ///     });
+    /// ```
+    pub fn with_on_applied(
+        mut self,
+        callback: impl Fn(&EffectCallbackContext, &mut Commands) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_applied.push(EffectCallback::new(callback));
+        self
+    }
+
+    /// Adds a one-shot system run when an instance is applied, refreshed or
+    /// stacked.
+    pub fn with_on_applied_system(mut self, system: SystemId<In<EffectCallbackContext>>) -> Self {
+        self.on_applied.push(EffectCallback::system(system));
+        self
+    }
+
+    /// Adds a callback run when an instance is removed.
+    pub fn with_on_removed(
+        mut self,
+        callback: impl Fn(&EffectCallbackContext, &mut Commands) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_removed.push(EffectCallback::new(callback));
+        self
+    }
+
+    /// Adds a one-shot system run when an instance is removed.
+    pub fn with_on_removed_system(mut self, system: SystemId<In<EffectCallbackContext>>) -> Self {
+        self.on_removed.push(EffectCallback::system(system));
+        self
+    }
+}
+
+/// Evaluates a magnitude from the data on a spec, without the world.
+pub(crate) fn modifier_display_magnitude(
+    magnitude: &MagnitudeCalculation,
+    spec: &GameplayEffectSpec,
+) -> Option<f32> {
+    let source_value = match magnitude {
+        MagnitudeCalculation::ScalableFloat { .. } => None,
+        MagnitudeCalculation::AttributeBased {
+            attribute_name,
+            capture_source,
+            ..
+        } => {
+            let entity = match capture_source {
+                AttributeCaptureSource::Source => spec.source_entity()?,
+                AttributeCaptureSource::Target => spec.target,
+            };
+            Some(spec.get_captured_attribute(entity, attribute_name)?)
+        }
+        MagnitudeCalculation::SetByCaller { data_tag } => {
+            Some(spec.set_by_caller_magnitudes.get_magnitude(data_tag)?)
+        }
+        MagnitudeCalculation::CustomClass { .. } | MagnitudeCalculation::CustomExecution { .. } => {
+            return None;
+        }
+    };
+    Some(magnitude.evaluate(spec.level, source_value))
 }
 
 /// Resource that stores all gameplay effect definitions.
+///
+/// Definitions are stored behind an [`Arc`], so [`get_shared`](Self::get_shared)
+/// hands out a cheap handle that outlives the borrow of the registry.
 #[derive(Resource, Default)]
 pub struct GameplayEffectRegistry {
-    pub definitions: std::collections::HashMap<Atom, GameplayEffectDefinition>,
+    pub definitions: std::collections::HashMap<Atom, Arc<GameplayEffectDefinition>>,
+    ids: IdTable<EffectId>,
 }
 
 impl GameplayEffectRegistry {
@@ -785,13 +1196,17 @@ impl GameplayEffectRegistry {
         Self::default()
     }
 
-    /// Registers an effect definition.
+    /// Registers an effect definition, taking either an owned definition or
+    /// one already shared through an [`Arc`]. The effects applying its
+    /// [`ResourceGain`](crate::effects::resource_gain::ResourceGain)s are
+    /// registered too, unless already present.
     ///
     /// # Panics
     ///
     /// Panics if an Instant effect has `granted_tags`, since there is no persistent
     /// entity to hold them and remove them later. Use `HasDuration` or `Infinite` instead.
-    pub fn register(&mut self, definition: GameplayEffectDefinition) {
+    pub fn register(&mut self, definition: impl Into<Arc<GameplayEffectDefinition>>) {
+        let definition = definition.into();
         if definition.duration_policy == DurationPolicy::Instant
             && !definition.granted_tags.is_empty()
         {
@@ -801,12 +1216,50 @@ impl GameplayEffectRegistry {
                 definition.id
             );
         }
+        for resource_gain in &definition.resource_gains {
+            if !self.definitions.contains_key(&resource_gain.effect_id()) {
+                self.register(resource_gain.effect_definition());
+            }
+        }
+        self.ids.intern(definition.id.clone());
         self.definitions.insert(definition.id.clone(), definition);
     }
 
     /// Gets an effect definition by ID.
     pub fn get(&self, id: impl Into<Atom>) -> Option<&GameplayEffectDefinition> {
-        self.definitions.get(&id.into())
+        self.definitions.get(&id.into()).map(Arc::as_ref)
+    }
+
+    /// Gets a shared handle to an effect definition.
+    pub fn get_shared(&self, id: impl Into<Atom>) -> Option<Arc<GameplayEffectDefinition>> {
+        self.definitions.get(&id.into()).cloned()
+    }
+
+    /// Gets an effect definition by ID, or an error naming the missing ID.
+    pub fn try_get(&self, id: impl Into<Atom>) -> GasResult<&GameplayEffectDefinition> {
+        let effect_id = id.into();
+        self.definitions
+            .get(&effect_id)
+            .map(Arc::as_ref)
+            .ok_or(GasError::EffectDefinitionNotFound { effect_id })
+    }
+
+    /// Returns the interned ID of a registered effect.
+    ///
+    /// Returns `None` for names that were never registered, so resolving
+    /// IDs once up front catches misspelled effect names early.
+    pub fn id(&self, name: impl Into<Atom>) -> Option<EffectId> {
+        self.ids.get(name)
+    }
+
+    /// Returns the name of an interned effect ID.
+    pub fn name(&self, id: EffectId) -> Option<&Atom> {
+        self.ids.name(id)
+    }
+
+    /// Gets an effect definition by interned ID.
+    pub fn get_by_id(&self, id: EffectId) -> Option<&GameplayEffectDefinition> {
+        self.definitions.get(self.ids.name(id)?).map(Arc::as_ref)
     }
 }
 
@@ -844,6 +1297,30 @@ mod tests {
         assert_eq!(effect.modifiers.len(), 1);
     }
 
+    #[test]
+    fn test_team_policy_allows() {
+        let mut world = World::new();
+        let source = world.spawn(Team(1)).id();
+        let ally = world.spawn(Team(1)).id();
+        let enemy = world.spawn(Team(2)).id();
+        let neutral = world.spawn_empty().id();
+        let team_of = |entity| world.get::<Team>(entity).copied();
+
+        let allows =
+            |policy: EffectTeamPolicy, target| policy.allows(Some(source), target, team_of);
+        assert!(allows(EffectTeamPolicy::Any, neutral));
+        assert!(allows(EffectTeamPolicy::HostileOnly, enemy));
+        assert!(!allows(EffectTeamPolicy::HostileOnly, ally));
+        assert!(!allows(EffectTeamPolicy::HostileOnly, source));
+        assert!(!allows(EffectTeamPolicy::HostileOnly, neutral));
+        assert!(allows(EffectTeamPolicy::FriendlyOnly, ally));
+        assert!(allows(EffectTeamPolicy::FriendlyOnly, source));
+        assert!(!allows(EffectTeamPolicy::FriendlyOnly, enemy));
+        assert!(allows(EffectTeamPolicy::SelfOnly, source));
+        assert!(!allows(EffectTeamPolicy::SelfOnly, ally));
+        assert!(!EffectTeamPolicy::HostileOnly.allows(None, enemy, team_of));
+    }
+
     #[test]
     fn test_registry() {
         let mut registry = GameplayEffectRegistry::new();
@@ -852,5 +1329,15 @@ mod tests {
 
         assert!(registry.get("test").is_some());
         assert!(registry.get("nonexistent").is_none());
+
+        let id = registry.id("test").unwrap();
+        assert_eq!(registry.get_by_id(id).unwrap().id.as_ref(), "test");
+        assert_eq!(registry.name(id).map(|name| name.as_ref()), Some("test"));
+        assert!(registry.id("nonexistent").is_none());
+
+        let shared = registry.get_shared("test").unwrap();
+        assert!(Arc::ptr_eq(&shared, &registry.get_shared("test").unwrap()));
+        registry.register(shared);
+        assert_eq!(registry.definitions.len(), 1);
     }
 }
@@ -0,0 +1,189 @@
+//! Dispel categories and cleanses.
+//!
+//! Effects are put in dispel categories through their asset tags, with
+//! `with_dispel_category` for the classic [`DispelCategory`]s or
+//! `with_asset_tag` for project-specific ones.
+//! [`CleanseEvent`] removes the active effects on a target matching any of
+//! its categories, up to a count and in a chosen order, then reports them
+//! with [`EffectsCleansedEvent`]. A category matches every tag under it, so
+//! cleansing `Dispel` removes all dispellable effects.
+//!
+//! # Example
+//! ```ignore
+//! effects.register(
+//!     GameplayEffectDefinition::new("venom")
+//!         .with_duration(8.0)
+//!         .with_dispel_category(DispelCategory::Poison, &tags_manager),
+//! );
+//!
+//! // Purify: remove the two newest poisons or curses.
+//! commands.trigger(
+//!     CleanseEvent::new(ally, [DispelCategory::Poison.tag(), DispelCategory::Curse.tag()])
+//!         .with_max_count(2),
+//! );
+//! ```
+
+use super::components::{
+    ActiveEffects, ActiveGameplayEffect, EffectDuration, EffectRemovalPending,
+};
+use super::definition::GameplayEffectRegistry;
+use super::systems::RemoveGameplayEffectEvent;
+use bevy::prelude::*;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+
+/// A classic dispel category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DispelCategory {
+    /// Spells and magical debuffs.
+    Magic,
+    /// Poisons.
+    Poison,
+    /// Curses.
+    Curse,
+    /// Bleeds.
+    Bleed,
+}
+
+impl DispelCategory {
+    /// Every category, in declaration order.
+    pub const ALL: [DispelCategory; 4] = [
+        DispelCategory::Magic,
+        DispelCategory::Poison,
+        DispelCategory::Curse,
+        DispelCategory::Bleed,
+    ];
+
+    /// Returns the name of the asset tag marking the category.
+    pub fn tag_name(self) -> &'static str {
+        match self {
+            DispelCategory::Magic => "Dispel.Magic",
+            DispelCategory::Poison => "Dispel.Poison",
+            DispelCategory::Curse => "Dispel.Curse",
+            DispelCategory::Bleed => "Dispel.Bleed",
+        }
+    }
+
+    /// Returns the asset tag marking the category.
+    pub fn tag(self) -> GameplayTag {
+        GameplayTag::new(self.tag_name())
+    }
+}
+
+/// Which matching effects a cleanse removes first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CleansePriority {
+    /// The most recently applied effects.
+    #[default]
+    Newest,
+    /// The earliest applied effects.
+    Oldest,
+    /// The effects closest to expiring. Infinite effects come last.
+    ShortestRemaining,
+}
+
+/// Event removing the active effects on `target` whose asset tags match any
+/// of `categories`.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct CleanseEvent {
+    /// The entity to cleanse.
+    pub target: Entity,
+    /// The dispel categories to remove.
+    pub categories: Vec<GameplayTag>,
+    /// How many effects to remove at most. `None` removes them all.
+    pub max_count: Option<usize>,
+    /// Which matching effects are removed first.
+    pub priority: CleansePriority,
+}
+
+impl CleanseEvent {
+    /// Creates a cleanse removing every matching effect, newest first.
+    pub fn new(target: Entity, categories: impl IntoIterator<Item = GameplayTag>) -> Self {
+        Self {
+            target,
+            categories: categories.into_iter().collect(),
+            max_count: None,
+            priority: CleansePriority::default(),
+        }
+    }
+
+    /// Sets how many effects to remove at most.
+    pub fn with_max_count(mut self, max_count: usize) -> Self {
+        self.max_count = Some(max_count);
+        self
+    }
+
+    /// Sets which matching effects are removed first.
+    pub fn with_priority(mut self, priority: CleansePriority) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// Event triggered after a cleanse removed at least one effect.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct EffectsCleansedEvent {
+    /// The cleansed entity.
+    pub target: Entity,
+    /// The removed effect entities, in removal order.
+    pub effects: Vec<Entity>,
+}
+
+/// Observer removing the effects matched by a [`CleanseEvent`].
+pub fn on_cleanse(
+    trigger: On<CleanseEvent>,
+    mut commands: Commands,
+    registry: Res<GameplayEffectRegistry>,
+    lists: Query<&ActiveEffects>,
+    effects: Query<(&ActiveGameplayEffect, Option<&EffectDuration>), Without<EffectRemovalPending>>,
+) {
+    let event = trigger.event();
+    let Ok(list) = lists.get(event.target) else {
+        return;
+    };
+    let mut matching: Vec<_> = list
+        .iter()
+        .filter_map(|effect| {
+            let (active_effect, duration) = effects.get(effect).ok()?;
+            let definition = registry.get(&active_effect.definition_id)?;
+            event
+                .categories
+                .iter()
+                .any(|category| definition.asset_tags.has_tag(category))
+                .then_some((effect, active_effect.start_time, duration))
+        })
+        .collect();
+
+    // Sorts are stable and the list is in application order, which breaks
+    // ties between effects applied in the same frame.
+    match event.priority {
+        CleansePriority::Newest => {
+            matching.reverse();
+            matching.sort_by(|a, b| b.1.total_cmp(&a.1));
+        }
+        CleansePriority::Oldest => matching.sort_by(|a, b| a.1.total_cmp(&b.1)),
+        CleansePriority::ShortestRemaining => matching.sort_by(|a, b| {
+            let remaining = |duration: Option<&EffectDuration>| {
+                duration.map_or(f32::INFINITY, |duration| duration.remaining)
+            };
+            remaining(a.2).total_cmp(&remaining(b.2))
+        }),
+    }
+
+    let removed: Vec<Entity> = matching
+        .into_iter()
+        .take(event.max_count.unwrap_or(usize::MAX))
+        .map(|(effect, ..)| effect)
+        .collect();
+    if removed.is_empty() {
+        return;
+    }
+    for &effect in &removed {
+        commands.trigger(RemoveGameplayEffectEvent { effect });
+    }
+    commands.trigger(EffectsCleansedEvent {
+        target: event.target,
+        effects: removed,
+    });
+}
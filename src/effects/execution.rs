@@ -8,6 +8,7 @@ use std::collections::HashMap;
 use std::fmt;
 use string_cache::DefaultAtom as Atom;
 
+use super::application_requirement::ApplicationAttributeSnapshot;
 use super::definition::AttributeCaptureSource;
 
 /// Trait for custom gameplay effect execution calculations.
@@ -82,7 +83,8 @@ pub trait GameplayEffectExecutionCalculation: Send + Sync + fmt::Debug {
 /// Defines an attribute to capture for execution calculations.
 ///
 /// Matches UE GAS's `FGameplayEffectAttributeCaptureDefinition`.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AttributeCaptureDefinition {
     /// Name of the attribute to capture
     pub attribute_name: Atom,
@@ -125,6 +127,94 @@ impl AttributeCaptureDefinition {
     pub fn dynamic_target(attribute_name: impl Into<Atom>) -> Self {
         Self::new(attribute_name, AttributeCaptureSource::Target, false)
     }
+
+    /// Returns the entity this capture reads from.
+    pub fn entity(&self, source: Option<Entity>, target: Entity) -> Option<Entity> {
+        match self.capture_source {
+            AttributeCaptureSource::Source => source,
+            AttributeCaptureSource::Target => Some(target),
+        }
+    }
+}
+
+/// Attribute values captured for an effect application.
+///
+/// Built once per application from every capture its calculations declare,
+/// so calculations read captured values instead of looking attributes up
+/// themselves.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CapturedAttributes {
+    /// Values captured from the source entity.
+    pub source: HashMap<Atom, f32>,
+    /// Values captured from the target entity.
+    pub target: HashMap<Atom, f32>,
+}
+
+impl CapturedAttributes {
+    /// Resolves `captures` in one pass over `attributes`.
+    ///
+    /// Snapshot captures read the value recorded in `snapshots` when there
+    /// is one, and record the current value into it otherwise, so an effect
+    /// keeps the values from when it was applied.
+    pub fn capture(
+        captures: &[AttributeCaptureDefinition],
+        source: Option<Entity>,
+        target: Entity,
+        attributes: &[ApplicationAttributeSnapshot],
+        snapshots: &mut HashMap<(Entity, Atom), f32>,
+    ) -> Self {
+        let mut captured = Self::default();
+        for attribute in attributes {
+            for capture in captures {
+                if capture.entity(source, target) != Some(attribute.owner)
+                    || capture.attribute_name != attribute.attribute_name
+                {
+                    continue;
+                }
+                let value = if capture.snapshot {
+                    *snapshots
+                        .entry((attribute.owner, attribute.attribute_name.clone()))
+                        .or_insert(attribute.current_value)
+                } else {
+                    attribute.current_value
+                };
+                captured.insert(capture, value);
+            }
+        }
+        // Snapshots outlive the attributes they were taken from
+        for capture in captures.iter().filter(|capture| capture.snapshot) {
+            if let Some(entity) = capture.entity(source, target)
+                && let Some(&value) = snapshots.get(&(entity, capture.attribute_name.clone()))
+            {
+                captured
+                    .side_mut(capture.capture_source)
+                    .entry(capture.attribute_name.clone())
+                    .or_insert(value);
+            }
+        }
+        captured
+    }
+
+    /// Returns the value captured for `capture`, if any.
+    pub fn get(&self, capture: &AttributeCaptureDefinition) -> Option<f32> {
+        match capture.capture_source {
+            AttributeCaptureSource::Source => self.source.get(&capture.attribute_name),
+            AttributeCaptureSource::Target => self.target.get(&capture.attribute_name),
+        }
+        .copied()
+    }
+
+    fn insert(&mut self, capture: &AttributeCaptureDefinition, value: f32) {
+        self.side_mut(capture.capture_source)
+            .insert(capture.attribute_name.clone(), value);
+    }
+
+    fn side_mut(&mut self, capture_source: AttributeCaptureSource) -> &mut HashMap<Atom, f32> {
+        match capture_source {
+            AttributeCaptureSource::Source => &mut self.source,
+            AttributeCaptureSource::Target => &mut self.target,
+        }
+    }
 }
 
 /// Evaluated modifier data produced by execution calculations.
@@ -187,6 +277,52 @@ mod tests {
         assert_eq!(data.magnitude, -50.0);
     }
 
+    #[test]
+    fn test_captured_attributes_keep_snapshots() {
+        let source = Entity::from_bits(1);
+        let target = Entity::from_bits(2);
+        let attribute = |owner, name: &str, value| ApplicationAttributeSnapshot {
+            owner,
+            attribute_name: name.into(),
+            base_value: value,
+            current_value: value,
+        };
+        let captures = [
+            AttributeCaptureDefinition::snapshot_source("AttackPower"),
+            AttributeCaptureDefinition::dynamic_target("Defense"),
+        ];
+        let mut snapshots = HashMap::new();
+
+        let captured = CapturedAttributes::capture(
+            &captures,
+            Some(source),
+            target,
+            &[
+                attribute(source, "AttackPower", 10.0),
+                attribute(source, "Defense", 99.0),
+                attribute(target, "Defense", 4.0),
+            ],
+            &mut snapshots,
+        );
+        assert_eq!(captured.get(&captures[0]), Some(10.0));
+        assert_eq!(captured.get(&captures[1]), Some(4.0));
+        assert_eq!(snapshots.len(), 1);
+
+        // Only the dynamic capture follows the attributes
+        let captured = CapturedAttributes::capture(
+            &captures,
+            Some(source),
+            target,
+            &[
+                attribute(source, "AttackPower", 50.0),
+                attribute(target, "Defense", 8.0),
+            ],
+            &mut snapshots,
+        );
+        assert_eq!(captured.get(&captures[0]), Some(10.0));
+        assert_eq!(captured.get(&captures[1]), Some(8.0));
+    }
+
     #[derive(Debug)]
     struct TestCalculation;
 
@@ -6,10 +6,15 @@ use bevy::prelude::*;
 
 use super::ge_component::{EffectRemovalInfo, EffectRemovalReason, GameplayEffectComponent};
 use super::query::GameplayEffectQuery;
+use crate::core::GasRng;
 
 /// Component that applies a probability check before allowing effect application.
 ///
-/// Matches UE GAS's `UChanceToApplyGameplayEffectComponent`.
+/// Matches UE GAS's `UChanceToApplyGameplayEffectComponent`. Rolls with
+/// [`GasRng::next_roll`], salted with the effect, source and target, so
+/// results are reproducible for a given seed and several identical
+/// applications in one tick (multi-hits, a volley of projectiles) roll
+/// independently.
 ///
 /// # Example
 ///
@@ -42,21 +47,35 @@ impl ChanceToApplyComponent {
 impl GameplayEffectComponent for ChanceToApplyComponent {
     fn can_apply(
         &self,
-        _effect_definition_id: &str,
-        _source: Entity,
-        _target: Entity,
-        _world: &World,
+        effect_definition_id: &str,
+        source: Entity,
+        target: Entity,
+        world: &World,
     ) -> bool {
-        // Simple pseudo-random based on system time
-        let random_value = (std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_nanos() as f32)
-            .fract();
-        random_value < self.chance
+        // Roll the seeded `GasRng` with a salt that is stable across Rust
+        // releases, so replays roll the same.
+        let salt = [source.to_bits(), target.to_bits()].into_iter().fold(
+            fnv1a(FNV_OFFSET_BASIS, effect_definition_id.as_bytes()),
+            |hash, value| fnv1a(hash, &value.to_le_bytes()),
+        );
+
+        let roll = world.get_resource::<GasRng>().map_or_else(
+            || GasRng::default().next_roll(salt),
+            |rng| rng.next_roll(salt),
+        );
+        roll < self.chance
     }
 }
 
+const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+
+/// Folds `bytes` into a 64-bit FNV-1a hash.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01B3)
+    })
+}
+
 /// Component that grants immunity to effects matching specific queries.
 ///
 /// Matches UE GAS's `UImmunityGameplayEffectComponent`.
@@ -326,6 +345,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_chance_to_apply_is_reproducible_for_a_seed() {
+        let component = ChanceToApplyComponent::new(0.5);
+        let mut world = World::new();
+        let source = world.spawn_empty().id();
+        let target = world.spawn_empty().id();
+
+        // A replay starts from the same definitions and seed
+        let rolls = |world: &mut World, seed: u64| -> Vec<bool> {
+            let component = component.clone();
+            world.insert_resource(GasRng::new(seed));
+            (0..32)
+                .map(|i| component.can_apply(&format!("effect_{i}"), source, target, world))
+                .collect()
+        };
+        let first = rolls(&mut world, 3);
+        assert_eq!(first, rolls(&mut world, 3));
+        assert!(first.contains(&true) && first.contains(&false));
+    }
+
+    #[test]
+    fn test_chance_to_apply_rolls_identical_applications_independently() {
+        let component = ChanceToApplyComponent::new(0.5);
+        let mut world = World::new();
+        world.insert_resource(GasRng::new(3));
+        let source = world.spawn_empty().id();
+        let target = world.spawn_empty().id();
+
+        // Multi-hits of one effect in the same tick
+        let hits: Vec<_> = (0..32)
+            .map(|_| component.can_apply("volley", source, target, &world))
+            .collect();
+        assert!(hits.contains(&true) && hits.contains(&false));
+    }
+
     #[test]
     fn test_chance_to_apply_clamps() {
         let component = ChanceToApplyComponent::new(1.5);
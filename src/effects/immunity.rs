@@ -0,0 +1,122 @@
+//! Immunity windows.
+//!
+//! An owner with the [`INVULNERABLE_TAG`] is immune to every effect whose
+//! asset tags fall under [`HOSTILE_EFFECT_TAG`]: while the tag is owned, the
+//! plugin keeps the hostile tag in the owner's [`ImmunityTags`], so those
+//! effects are rejected with a [`GameplayEffectBlockedByImmunityEvent`] for
+//! hit feedback ("Immune!" popups, parry sparks).
+//!
+//! [`immunity_window`] builds a short effect granting the tag. Abilities
+//! apply one to their owner on activation with
+//! [`AbilityDefinition::with_immunity_window`], for the i-frames of dodges and
+//! rolls. Granting the tag through `activation_owned_tags` instead keeps the
+//! owner immune for as long as the ability is active. Both tags have to be in
+//! the project's gameplay tag table.
+//!
+//! # Example
+//! ```ignore
+//! effects.register(immunity_window("dodge_iframes", 0.4, &tags_manager));
+//! effects.register(
+//!     GameplayEffectDefinition::new("fireball_damage")
+//!         .with_asset_tag(GameplayTag::new("Effect.Hostile.Fire"), &tags_manager),
+//! );
+//! abilities.register(AbilityDefinition::new("dodge").with_immunity_window("dodge_iframes"));
+//! ```
+//!
+//! [`GameplayEffectBlockedByImmunityEvent`]: super::systems::GameplayEffectBlockedByImmunityEvent
+//! [`AbilityDefinition::with_immunity_window`]: crate::abilities::definition::AbilityDefinition::with_immunity_window
+
+use super::definition::{GameplayEffectDefinition, StackingPolicy};
+use super::systems::ApplyGameplayEffectEvent;
+use crate::abilities::components::AbilitySpec;
+use crate::abilities::definition::AbilityRegistry;
+use crate::abilities::systems::AbilityActivatedEvent;
+use crate::core::ImmunityTags;
+use bevy::prelude::*;
+use bevy_gameplay_tag::GameplayTagsManager;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use bevy_gameplay_tag::gameplay_tag_count_container::{
+    GameplayTagEventType, OnGameplayEffectTagCountChanged,
+};
+use string_cache::DefaultAtom as Atom;
+
+/// Tag making its owner immune to hostile effects.
+pub const INVULNERABLE_TAG: &str = "State.Invulnerable";
+
+/// Asset tag of the effects an invulnerable owner is immune to, including
+/// the tags under it.
+pub const HOSTILE_EFFECT_TAG: &str = "Effect.Hostile";
+
+/// Builds an effect granting [`INVULNERABLE_TAG`] for `duration` seconds.
+/// Reapplying it refreshes the duration.
+pub fn immunity_window(
+    effect_id: impl Into<Atom>,
+    duration: f32,
+    tags_manager: &Res<GameplayTagsManager>,
+) -> GameplayEffectDefinition {
+    GameplayEffectDefinition::new(effect_id)
+        .with_duration(duration)
+        .with_stacking_policy(StackingPolicy::RefreshDuration)
+        .grant_tag(GameplayTag::new(INVULNERABLE_TAG), tags_manager)
+}
+
+/// Observer keeping the owner's immunity to hostile effects in line with its
+/// [`INVULNERABLE_TAG`].
+pub fn on_invulnerable_tag_changed(
+    ev: On<OnGameplayEffectTagCountChanged>,
+    mut commands: Commands,
+    tags_manager: Res<GameplayTagsManager>,
+    mut immunity_tags: Query<&mut ImmunityTags>,
+) {
+    let event = ev.event();
+    if event.event_type != GameplayTagEventType::NewOrRemoved
+        || event.tag.get_tag_name() != INVULNERABLE_TAG
+    {
+        return;
+    }
+    let owner = event.entity;
+    let delta = if event.new_count > 0 { 1 } else { -1 };
+    let tag = GameplayTag::new(HOSTILE_EFFECT_TAG);
+
+    match immunity_tags.get_mut(owner) {
+        Ok(mut immunity) => {
+            immunity
+                .0
+                .update_tag_count(&tag, delta, &tags_manager, &mut commands, owner);
+        }
+        Err(_) if delta > 0 => {
+            let mut immunity = ImmunityTags::default();
+            immunity
+                .0
+                .update_tag_count(&tag, delta, &tags_manager, &mut commands, owner);
+            commands.entity(owner).insert(immunity);
+        }
+        Err(_) => {}
+    }
+}
+
+/// Observer that applies the immunity window of an activated ability to its
+/// owner.
+pub fn on_ability_activated_apply_immunity_window(
+    trigger: On<AbilityActivatedEvent>,
+    mut commands: Commands,
+    registry: Res<AbilityRegistry>,
+    specs: Query<&AbilitySpec>,
+) {
+    let event = trigger.event();
+    let Ok(spec) = specs.get(event.ability_spec) else {
+        return;
+    };
+    let Some(effect_id) = registry
+        .get(&spec.definition_id)
+        .and_then(|definition| definition.immunity_window_effect.as_ref())
+    else {
+        return;
+    };
+    commands.trigger(
+        ApplyGameplayEffectEvent::new(effect_id.clone(), event.owner)
+            .with_source(event.owner)
+            .with_instigator(event.ability_spec)
+            .with_level(spec.level),
+    );
+}
@@ -50,28 +50,52 @@
 
 pub mod ability_granting;
 pub mod application_requirement;
+#[cfg(feature = "effect_assets")]
+pub mod asset;
 pub mod batch_aggregation;
+pub mod breakdown;
 pub mod builtin_requirements;
+pub mod callbacks;
+pub mod combat_log;
 pub mod components;
+pub mod conversion;
 pub mod custom_calculation;
+pub mod damage;
 pub mod definition;
+pub mod dispel;
 pub mod execution;
 pub mod ge_component;
 pub mod ge_components;
+pub mod immunity;
 pub mod plugin;
 pub mod query;
+pub mod resource_gain;
+pub mod status;
 pub mod systems;
+pub mod threat;
 
 pub use ability_granting::*;
 pub use application_requirement::*;
+#[cfg(feature = "effect_assets")]
+pub use asset::*;
 pub use batch_aggregation::*;
+pub use breakdown::*;
 pub use builtin_requirements::*;
+pub use callbacks::*;
+pub use combat_log::*;
 pub use components::*;
+pub use conversion::*;
 pub use custom_calculation::*;
+pub use damage::*;
 pub use definition::*;
+pub use dispel::*;
 pub use execution::*;
 pub use ge_component::*;
 pub use ge_components::*;
+pub use immunity::*;
 pub use plugin::*;
 pub use query::*;
+pub use resource_gain::*;
+pub use status::*;
 pub use systems::*;
+pub use threat::*;
@@ -3,14 +3,25 @@
 //! This plugin registers all effect-related systems and events.
 
 use super::ability_granting::{
-    cleanup_remove_on_end_abilities_system, grant_abilities_from_effects_system,
-    on_gameplay_effect_removed_remove_granted_abilities,
+    GrantedAbilities, GrantedByEffect, cleanup_remove_on_end_abilities_system,
+    grant_abilities_from_effects_system, on_gameplay_effect_removed_remove_granted_abilities,
 };
 use super::application_requirement::ApplicationRequirementRegistry;
+use super::batch_aggregation::{ModifierIndex, index_inserted_modifier, unindex_removed_modifier};
+use super::callbacks::{on_active_effect_removed_run_callbacks, on_effect_applied_run_callbacks};
+use super::combat_log::on_effect_executed_log_combat;
+use super::components::*;
 use super::custom_calculation::CustomCalculationRegistry;
+use super::damage::DamageCalculationRegistry;
 use super::definition::GameplayEffectRegistry;
+use super::dispel::on_cleanse;
+use super::immunity::{on_ability_activated_apply_immunity_window, on_invulnerable_tag_changed};
 use super::systems::*;
-use crate::core::system_sets::{EffectSystemSet, GasSystemSet};
+use crate::core::events::BatchedEvents;
+use crate::core::handles::track_handle_generations;
+use crate::core::settings::GasSettings;
+use crate::core::system_sets::{EffectSystemSet, GasSystemSet, configure_gas_system_sets};
+use crate::core::timestep::{GasTickMode, GlobalGasTimeScale};
 use bevy::prelude::*;
 
 /// Plugin that adds gameplay effect system functionality.
@@ -18,46 +29,97 @@ pub struct EffectPlugin;
 
 impl Plugin for EffectPlugin {
     fn build(&self, app: &mut App) {
+        let simulation_schedule = GasTickMode::of(app).simulation_schedule();
+        configure_gas_system_sets(app);
+        track_handle_generations::<ActiveGameplayEffect>(app);
         app
             // Register resources
             .init_resource::<GameplayEffectRegistry>()
+            .init_resource::<GlobalGasTimeScale>()
+            .init_resource::<GasSettings>()
             .init_resource::<CustomCalculationRegistry>()
+            .init_resource::<DamageCalculationRegistry>()
             .init_resource::<ApplicationRequirementRegistry>()
+            .init_resource::<ModifierIndex>()
+            .init_resource::<ExpiredEffectQueue>()
+            .init_resource::<BatchedEvents<ApplyGameplayEffectEvent>>()
+            // Register reflected components so active effects can be saved in scenes
+            .register_type::<ActiveGameplayEffect>()
+            .register_type::<EffectTarget>()
+            .register_type::<ActiveEffects>()
+            .register_type::<EffectInstigator>()
+            .register_type::<GameplayEffectContext>()
+            .register_type::<SetByCallerMagnitudes>()
+            .register_type::<EffectDuration>()
+            .register_type::<PeriodicEffect>()
+            .register_type::<EffectGrantedTags>()
+            .register_type::<AttributeModifier>()
+            .register_type::<ModifierSource>()
+            .register_type::<EffectModifiers>()
+            .register_type::<GrantedAbilities>()
+            .register_type::<GrantedByEffect>()
             // Register observer for effect application
             .add_observer(on_apply_gameplay_effect)
+            .add_observer(on_remove_gameplay_effect)
             .add_observer(on_gameplay_effect_removed_remove_granted_abilities)
+            .add_observer(on_active_effect_removed_trigger_cues)
+            .add_observer(on_effect_applied_run_callbacks)
+            .add_observer(on_active_effect_removed_run_callbacks)
+            // Combat log
+            .add_observer(on_effect_executed_log_combat)
+            // Dispels
+            .add_observer(on_cleanse)
+            // Immunity windows
+            .add_observer(on_invulnerable_tag_changed)
+            .add_observer(on_ability_activated_apply_immunity_window)
+            // Keep modifiers indexed by the attribute they target
+            .add_observer(index_inserted_modifier)
+            .add_observer(unindex_removed_modifier)
             // Register kept systems with proper system sets
             .add_systems(
-                Update,
-                create_effect_modifiers_system.in_set(EffectSystemSet::CreateModifiers),
-            )
-            .add_systems(
-                Update,
-                update_effect_durations_system.in_set(EffectSystemSet::UpdateDurations),
+                simulation_schedule,
+                apply_batched_effects_system.in_set(EffectSystemSet::Apply),
             )
             .add_systems(
-                Update,
-                execute_periodic_effects_system.in_set(EffectSystemSet::ExecutePeriodic),
-            )
-            .add_systems(
-                Update,
-                remove_expired_effects_system.in_set(EffectSystemSet::RemoveExpired),
+                simulation_schedule,
+                create_effect_modifiers_system.in_set(EffectSystemSet::CreateModifiers),
             )
             .add_systems(
-                Update,
+                simulation_schedule,
                 remove_instant_effects_system.in_set(EffectSystemSet::RemoveInstant),
             )
             .add_systems(
-                Update,
-                aggregate_attribute_modifiers_system.in_set(EffectSystemSet::Aggregate),
+                simulation_schedule,
+                (
+                    mark_dirty_attributes_system,
+                    aggregate_attribute_modifiers_system,
+                )
+                    .chain()
+                    .in_set(EffectSystemSet::Aggregate),
             )
             .add_systems(
-                Update,
+                simulation_schedule,
                 grant_abilities_from_effects_system.in_set(EffectSystemSet::CreateModifiers),
             )
             .add_systems(
-                Update,
+                simulation_schedule,
+                apply_loaded_effect_tags_system.in_set(EffectSystemSet::CreateModifiers),
+            )
+            .add_systems(
+                simulation_schedule,
                 cleanup_remove_on_end_abilities_system.in_set(GasSystemSet::Cleanup),
             );
+
+        // Durations, periods and expiry tick in the schedule chosen by
+        // `GasTickMode`.
+        let tick_schedule = GasTickMode::of(app).schedule();
+        app.add_systems(
+            tick_schedule,
+            (
+                update_effect_durations_system.in_set(EffectSystemSet::UpdateDurations),
+                execute_periodic_effects_system.in_set(EffectSystemSet::ExecutePeriodic),
+                remove_expired_effects_system.in_set(EffectSystemSet::RemoveExpired),
+            ),
+        );
     }
 }
@@ -0,0 +1,163 @@
+//! Resources generated by effect executions.
+//!
+//! A [`ResourceGain`] on a definition grants its source a fixed amount of a
+//! resource attribute each time the effect executes, for rage on hit or
+//! combo points on builders. The gain is applied as an instant effect
+//! through the normal pipeline, so it triggers the usual application and
+//! execution events. Registering a definition with a gain also registers
+//! that effect, [`ResourceGain::effect_id`], which adds the amount passed as
+//! the [`RESOURCE_GAIN_TAG`] SetByCaller magnitude to the attribute's base
+//! value.
+//!
+//! The amount is cut so the resource doesn't exceed the gain's
+//! [`max`](ResourceGain::max) or the current value of its
+//! [`max_attribute`](ResourceGain::max_attribute), as of the execution. Gains
+//! granted earlier in the same pass count towards the cap, so an AoE hitting
+//! five targets at once doesn't overshoot it. No effect is applied when the
+//! source is already at the cap.
+//!
+//! The resource goes to the spec's source, the entity owning the ability or
+//! effect. Its context instigator is the projectile or weapon that caused
+//! the hit, which has no resources; the source falls back to it when unset.
+//!
+//! # Example
+//! ```ignore
+//! registry.register(
+//!     GameplayEffectDefinition::new("sinister_strike")
+//!         .add_modifier(ModifierInfo::new(
+//!             "Health",
+//!             ModifierOperation::AddBase,
+//!             MagnitudeCalculation::scalar(-25.0),
+//!         ))
+//!         // Builds one combo point, up to five.
+//!         .add_resource_gain(ResourceGain::new("ComboPoints", 1.0).with_max(5.0)),
+//! );
+//! ```
+
+use super::application_requirement::ApplicationAttributeSnapshot;
+use super::components::{GameplayEffectContext, GameplayEffectSpec, ModifierOperation};
+use super::definition::{GameplayEffectDefinition, MagnitudeCalculation, ModifierInfo};
+use super::systems::ApplyGameplayEffectEvent;
+use bevy::prelude::Entity;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use string_cache::DefaultAtom as Atom;
+
+/// SetByCaller tag carrying the amount of a resource gain.
+pub const RESOURCE_GAIN_TAG: &str = "Data.ResourceGain";
+
+/// Grants the source an amount of a resource each time the effect executes.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResourceGain {
+    /// Resource attribute of the source.
+    pub attribute: Atom,
+    /// Amount granted per execution.
+    pub amount: f32,
+    /// Cap on the resource.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max: Option<f32>,
+    /// Attribute of the source whose current value caps the resource.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_attribute: Option<Atom>,
+}
+
+impl ResourceGain {
+    /// Creates a gain of `amount` of `attribute`.
+    pub fn new(attribute: impl Into<Atom>, amount: f32) -> Self {
+        Self {
+            attribute: attribute.into(),
+            amount,
+            max: None,
+            max_attribute: None,
+        }
+    }
+
+    /// Caps the resource at `max`.
+    pub fn with_max(mut self, max: f32) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Caps the resource at the current value of `max_attribute`.
+    pub fn with_max_attribute(mut self, max_attribute: impl Into<Atom>) -> Self {
+        self.max_attribute = Some(max_attribute.into());
+        self
+    }
+
+    /// Returns the ID of the effect applying gains of the attribute.
+    pub fn effect_id(&self) -> Atom {
+        format!("resource_gain.{}", self.attribute).into()
+    }
+
+    /// Builds the instant effect applying gains of the attribute.
+    pub(crate) fn effect_definition(&self) -> GameplayEffectDefinition {
+        GameplayEffectDefinition::new(self.effect_id()).add_modifier(ModifierInfo::new(
+            self.attribute.clone(),
+            ModifierOperation::AddBase,
+            MagnitudeCalculation::set_by_caller(GameplayTag::new(RESOURCE_GAIN_TAG)),
+        ))
+    }
+
+    /// Builds the application for the source of `spec`, given the attribute
+    /// values when the pass started and the gains it already granted.
+    ///
+    /// Returns `None` without a source, when the source lacks the attribute
+    /// or when it is already at the cap.
+    pub(crate) fn application(
+        &self,
+        spec: &GameplayEffectSpec,
+        attributes: &[ApplicationAttributeSnapshot],
+        pending: &mut PendingResourceGains,
+    ) -> Option<ApplyGameplayEffectEvent> {
+        let source = spec.source_entity()?;
+        let attribute = |name: &Atom| {
+            attributes
+                .iter()
+                .find(|snapshot| snapshot.owner == source && snapshot.attribute_name == *name)
+        };
+        let value = attribute(&self.attribute)?.base_value + pending.get(source, &self.attribute);
+        let cap = [
+            self.max,
+            self.max_attribute
+                .as_ref()
+                .and_then(attribute)
+                .map(|snapshot| snapshot.current_value),
+        ]
+        .into_iter()
+        .flatten()
+        .reduce(f32::min);
+        let amount = cap.map_or(self.amount, |cap| self.amount.min(cap - value));
+        if amount <= 0.0 {
+            return None;
+        }
+        pending.add(source, &self.attribute, amount);
+
+        let mut gain = GameplayEffectSpec::new(self.effect_id(), source);
+        gain.level = spec.level;
+        gain.context = GameplayEffectContext::new().with_source(source);
+        gain.prediction_key = spec.prediction_key;
+        gain.set_by_caller_magnitudes
+            .set_magnitude(GameplayTag::new(RESOURCE_GAIN_TAG), amount);
+        Some(ApplyGameplayEffectEvent::from_spec(gain))
+    }
+}
+
+/// Gains granted in one pass that haven't reached the attributes yet.
+#[derive(Debug, Default)]
+pub(crate) struct PendingResourceGains(Vec<(Entity, Atom, f32)>);
+
+impl PendingResourceGains {
+    fn get(&self, owner: Entity, attribute: &Atom) -> f32 {
+        self.0
+            .iter()
+            .filter(|(gain_owner, gain_attribute, _)| {
+                *gain_owner == owner && gain_attribute == attribute
+            })
+            .map(|(.., amount)| amount)
+            .sum()
+    }
+
+    fn add(&mut self, owner: Entity, attribute: &Atom, amount: f32) {
+        self.0.push((owner, attribute.clone(), amount));
+    }
+}
@@ -0,0 +1,388 @@
+//! Ready-made status effects.
+//!
+//! [`GasStatusEffectsPlugin`] registers a definition for each classic
+//! [`StatusEffect`], built from the [`StatusEffectLibrary`] resource, and
+//! enforces the statuses on their owner:
+//!
+//! | Status  | Effect id               | Granted tag      | Behavior                                        |
+//! |---------|-------------------------|------------------|-------------------------------------------------|
+//! | Stun    | `effect.status.stun`    | `State.Stunned`  | Blocks abilities tagged `Ability`, no movement  |
+//! | Root    | `effect.status.root`    | `State.Rooted`   | No movement                                     |
+//! | Silence | `effect.status.silence` | `State.Silenced` | Blocks abilities tagged `Ability.Type.Spell`    |
+//! | Slow    | `effect.status.slow`    | `State.Slowed`   | Scales movement down by its magnitude           |
+//! | Burn    | `effect.status.burn`    | `State.Burning`  | Periodic health damage, refreshed on reapply    |
+//! | Poison  | `effect.status.poison`  | `State.Poisoned` | Periodic health damage, stacking                |
+//! | Shield  | `effect.status.shield`  | `State.Shielded` | Adds to `Shield`, which absorbs health damage   |
+//!
+//! Movement is written to the `MoveSpeedMultiplier` attribute for movement
+//! code to read. The shield absorbs damage before it lands through the
+//! [`SHIELD_MITIGATION`] registered with the
+//! [`DamageCalculationRegistry`]; Burn and Poison list it, and other damage
+//! effects opt in with `DamageCalculation::with_mitigation(SHIELD_MITIGATION)`. Ability blocking follows the owner's tags rather than the
+//! effects, so any effect granting `State.Silenced` silences. The tags have to
+//! be in the project's gameplay tag table.
+//!
+//! # Example
+//! ```ignore
+//! app.add_plugins(GasStatusEffectsPlugin).insert_resource(
+//!     StatusEffectLibrary::default()
+//!         .with_config(StatusEffect::Stun, StatusEffectConfig::new(1.5)),
+//! );
+//!
+//! commands.trigger(
+//!     ApplyGameplayEffectEvent::new(StatusEffect::Stun.effect_id(), enemy).with_source(player),
+//! );
+//! ```
+
+use super::batch_aggregation::{AttributeKey, AttributeLookup};
+use super::components::ModifierOperation;
+use super::damage::{AbsorbMitigation, DamageCalculation, DamageCalculationRegistry};
+use super::definition::{
+    GameplayEffectDefinition, GameplayEffectRegistry, MagnitudeCalculation, ModifierInfo,
+    StackingPolicy,
+};
+use crate::attributes::AttributeData;
+use crate::core::BlockedAbilityTags;
+use bevy::prelude::*;
+use bevy_gameplay_tag::GameplayTagsManager;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use bevy_gameplay_tag::gameplay_tag_count_container::{
+    GameplayTagEventType, OnGameplayEffectTagCountChanged,
+};
+use std::collections::HashMap;
+use string_cache::DefaultAtom as Atom;
+
+/// Ability tag blocked while stunned. Matches every ability tagged under it.
+pub const STUN_BLOCKED_ABILITY_TAG: &str = "Ability";
+
+/// Ability tag blocked while silenced.
+pub const SPELL_ABILITY_TAG: &str = "Ability.Type.Spell";
+
+/// Name of the [`AbsorbMitigation`] spending the shield attribute.
+pub const SHIELD_MITIGATION: &str = "StatusShield";
+
+/// Plugin registering and enforcing the [`StatusEffect`]s.
+///
+/// Not added by `GasPlugin`.
+pub struct GasStatusEffectsPlugin;
+
+impl Plugin for GasStatusEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StatusEffectLibrary>()
+            .add_systems(Startup, register_status_effects_system)
+            .add_observer(on_status_tag_changed);
+    }
+}
+
+/// A classic status effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatusEffect {
+    /// Can't act or move.
+    Stun,
+    /// Can't move.
+    Root,
+    /// Can't cast spells.
+    Silence,
+    /// Moves slower.
+    Slow,
+    /// Takes fire damage over time.
+    Burn,
+    /// Takes stacking poison damage over time.
+    Poison,
+    /// Absorbs incoming damage.
+    Shield,
+}
+
+impl StatusEffect {
+    /// Every status, in declaration order.
+    pub const ALL: [StatusEffect; 7] = [
+        StatusEffect::Stun,
+        StatusEffect::Root,
+        StatusEffect::Silence,
+        StatusEffect::Slow,
+        StatusEffect::Burn,
+        StatusEffect::Poison,
+        StatusEffect::Shield,
+    ];
+
+    /// Returns the id the status is registered under.
+    pub fn effect_id(self) -> &'static str {
+        match self {
+            StatusEffect::Stun => "effect.status.stun",
+            StatusEffect::Root => "effect.status.root",
+            StatusEffect::Silence => "effect.status.silence",
+            StatusEffect::Slow => "effect.status.slow",
+            StatusEffect::Burn => "effect.status.burn",
+            StatusEffect::Poison => "effect.status.poison",
+            StatusEffect::Shield => "effect.status.shield",
+        }
+    }
+
+    /// Returns the name of the tag the status grants.
+    pub fn tag_name(self) -> &'static str {
+        match self {
+            StatusEffect::Stun => "State.Stunned",
+            StatusEffect::Root => "State.Rooted",
+            StatusEffect::Silence => "State.Silenced",
+            StatusEffect::Slow => "State.Slowed",
+            StatusEffect::Burn => "State.Burning",
+            StatusEffect::Poison => "State.Poisoned",
+            StatusEffect::Shield => "State.Shielded",
+        }
+    }
+
+    /// Returns the tag the status grants.
+    pub fn tag(self) -> GameplayTag {
+        GameplayTag::new(self.tag_name())
+    }
+
+    /// Returns the ability tag blocked while the status is active.
+    pub fn blocked_ability_tag(self) -> Option<&'static str> {
+        match self {
+            StatusEffect::Stun => Some(STUN_BLOCKED_ABILITY_TAG),
+            StatusEffect::Silence => Some(SPELL_ABILITY_TAG),
+            _ => None,
+        }
+    }
+}
+
+/// Tunables of one status.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatusEffectConfig {
+    /// Duration in seconds.
+    pub duration: f32,
+    /// Seconds between executions, 0 for statuses that don't tick.
+    pub period: f32,
+    /// Slow fraction, damage per tick or shield amount.
+    pub magnitude: f32,
+    /// Stacks allowed. With 1, reapplying refreshes the duration.
+    pub max_stacks: i32,
+}
+
+impl StatusEffectConfig {
+    /// Creates a non-stacking config lasting `duration` seconds.
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            period: 0.0,
+            magnitude: 0.0,
+            max_stacks: 1,
+        }
+    }
+
+    /// Sets the period.
+    pub fn with_period(mut self, period: f32) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// Sets the magnitude.
+    pub fn with_magnitude(mut self, magnitude: f32) -> Self {
+        self.magnitude = magnitude;
+        self
+    }
+
+    /// Sets the maximum stack count.
+    pub fn with_max_stacks(mut self, max_stacks: i32) -> Self {
+        self.max_stacks = max_stacks;
+        self
+    }
+}
+
+/// Configuration the status definitions are built from.
+///
+/// Changes after startup don't affect the registered definitions.
+#[derive(Resource, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatusEffectLibrary {
+    /// Config of each status.
+    pub configs: HashMap<StatusEffect, StatusEffectConfig>,
+    /// Attribute damaged by Burn and Poison and protected by Shield.
+    pub health_attribute: Atom,
+    /// Attribute written by Stun, Root and Slow.
+    pub move_speed_attribute: Atom,
+    /// Attribute holding the remaining shield.
+    pub shield_attribute: Atom,
+}
+
+impl Default for StatusEffectLibrary {
+    fn default() -> Self {
+        let configs = HashMap::from([
+            (StatusEffect::Stun, StatusEffectConfig::new(2.0)),
+            (StatusEffect::Root, StatusEffectConfig::new(3.0)),
+            (StatusEffect::Silence, StatusEffectConfig::new(3.0)),
+            (
+                StatusEffect::Slow,
+                StatusEffectConfig::new(4.0).with_magnitude(0.3),
+            ),
+            (
+                StatusEffect::Burn,
+                StatusEffectConfig::new(6.0)
+                    .with_period(1.0)
+                    .with_magnitude(5.0),
+            ),
+            (
+                StatusEffect::Poison,
+                StatusEffectConfig::new(10.0)
+                    .with_period(2.0)
+                    .with_magnitude(3.0)
+                    .with_max_stacks(5),
+            ),
+            (
+                StatusEffect::Shield,
+                StatusEffectConfig::new(10.0).with_magnitude(50.0),
+            ),
+        ]);
+        Self {
+            configs,
+            health_attribute: Atom::from("Health"),
+            move_speed_attribute: Atom::from("MoveSpeedMultiplier"),
+            shield_attribute: Atom::from("Shield"),
+        }
+    }
+}
+
+impl StatusEffectLibrary {
+    /// Replaces the config of `status`.
+    pub fn with_config(mut self, status: StatusEffect, config: StatusEffectConfig) -> Self {
+        self.configs.insert(status, config);
+        self
+    }
+
+    /// Returns the config of `status`, if it is in the library.
+    pub fn config(&self, status: StatusEffect) -> Option<&StatusEffectConfig> {
+        self.configs.get(&status)
+    }
+
+    /// Builds the definition of `status`, or `None` if it has no config.
+    pub fn definition(
+        &self,
+        status: StatusEffect,
+        tags_manager: &Res<GameplayTagsManager>,
+    ) -> Option<GameplayEffectDefinition> {
+        let config = self.config(status)?;
+        let stacking_policy = if config.max_stacks > 1 {
+            StackingPolicy::StackCount {
+                max_stacks: config.max_stacks,
+            }
+        } else {
+            StackingPolicy::RefreshDuration
+        };
+        let definition = GameplayEffectDefinition::new(status.effect_id())
+            .with_duration(config.duration)
+            .with_period(config.period)
+            .with_stacking_policy(stacking_policy)
+            .grant_tag(status.tag(), tags_manager);
+
+        let modifier = match status {
+            StatusEffect::Stun | StatusEffect::Root => Some(ModifierInfo::new(
+                self.move_speed_attribute.clone(),
+                ModifierOperation::Override,
+                MagnitudeCalculation::scalar(0.0),
+            )),
+            StatusEffect::Slow => Some(ModifierInfo::new(
+                self.move_speed_attribute.clone(),
+                ModifierOperation::MultiplyMultiplicative,
+                MagnitudeCalculation::scalar(-config.magnitude),
+            )),
+            StatusEffect::Burn | StatusEffect::Poison => Some(ModifierInfo::new(
+                self.health_attribute.clone(),
+                ModifierOperation::AddBase,
+                MagnitudeCalculation::scalar(-config.magnitude),
+            )),
+            StatusEffect::Shield => Some(ModifierInfo::new(
+                self.shield_attribute.clone(),
+                ModifierOperation::AddCurrent,
+                MagnitudeCalculation::scalar(config.magnitude),
+            )),
+            StatusEffect::Silence => None,
+        };
+        let definition = match status {
+            StatusEffect::Burn | StatusEffect::Poison => definition.with_damage(
+                DamageCalculation::new()
+                    .with_damage_attribute(self.health_attribute.clone())
+                    .with_mitigation(SHIELD_MITIGATION),
+            ),
+            _ => definition,
+        };
+        Some(match modifier {
+            Some(modifier) => definition.add_modifier(modifier),
+            None => definition,
+        })
+    }
+}
+
+/// System registering the status definitions and the shield mitigation.
+pub fn register_status_effects_system(
+    library: Res<StatusEffectLibrary>,
+    tags_manager: Res<GameplayTagsManager>,
+    mut registry: ResMut<GameplayEffectRegistry>,
+    mut damage: ResMut<DamageCalculationRegistry>,
+) {
+    damage.register_mitigation(
+        SHIELD_MITIGATION,
+        AbsorbMitigation::new(library.shield_attribute.clone()),
+    );
+    for status in StatusEffect::ALL {
+        if let Some(definition) = library.definition(status, &tags_manager) {
+            registry.register(definition);
+        }
+    }
+}
+
+/// Observer keeping the owner's blocked abilities and shield in line with its
+/// status tags.
+fn on_status_tag_changed(
+    ev: On<OnGameplayEffectTagCountChanged>,
+    mut commands: Commands,
+    library: Res<StatusEffectLibrary>,
+    tags_manager: Res<GameplayTagsManager>,
+    mut blocked_ability_tags: Query<&mut BlockedAbilityTags>,
+    lookup: AttributeLookup,
+    mut attributes: Query<&mut AttributeData>,
+) {
+    let event = ev.event();
+    if event.event_type != GameplayTagEventType::NewOrRemoved {
+        return;
+    }
+    let Some(status) = StatusEffect::ALL
+        .into_iter()
+        .find(|status| event.tag.get_tag_name() == status.tag_name())
+    else {
+        return;
+    };
+    let owner = event.entity;
+    let delta = if event.new_count > 0 { 1 } else { -1 };
+
+    if let Some(tag) = status.blocked_ability_tag() {
+        let tag = GameplayTag::new(tag);
+        match blocked_ability_tags.get_mut(owner) {
+            Ok(mut blocked) => {
+                blocked
+                    .0
+                    .update_tag_count(&tag, delta, &tags_manager, &mut commands, owner);
+            }
+            Err(_) if delta > 0 => {
+                let mut blocked = BlockedAbilityTags::default();
+                blocked
+                    .0
+                    .update_tag_count(&tag, delta, &tags_manager, &mut commands, owner);
+                commands.entity(owner).insert(blocked);
+            }
+            Err(_) => {}
+        }
+    }
+
+    // Absorbed damage is taken off the shield's base value, so drop the
+    // debt once the last shield is gone.
+    if status == StatusEffect::Shield
+        && delta < 0
+        && let Some(shield) =
+            lookup.find(&AttributeKey::new(owner, library.shield_attribute.clone()))
+        && let Ok(mut shield) = attributes.get_mut(shield)
+    {
+        shield.base_value = shield.base_value.max(0.0);
+    }
+}
@@ -2,44 +2,59 @@
 //!
 //! This module contains the observer functions and systems that manage gameplay effects.
 
+use super::batch_aggregation::{AttributeKey, AttributeLookup, ModifierBatch, ModifierIndex};
 use super::components::*;
+use super::damage::{AppliedDamage, DamageCalculationRegistry, DamageContext};
 use super::definition::*;
+use super::execution::CapturedAttributes;
+use super::resource_gain::PendingResourceGains;
+use crate::abilities::{PredictionKey, ScopedPredictionKey};
 use crate::attributes::{
-    AttributeData, AttributeLifecycleHooks, AttributeModifyContext, AttributeName, AttributeSetId,
+    AttributeData, AttributeDirty, AttributeLifecycleHooks, AttributeModifyContext, AttributeName,
+    AttributeSetId,
 };
-use crate::core::OwnedTags;
-use crate::cues::manager::{GameplayCueEvent, GameplayCueParameters};
+use crate::core::events::{BatchableEvent, BatchedEvents};
+use crate::core::timestep::GasTime;
+use crate::core::{
+    EffectHandle, GasRng, GasSettings, HandleGenerations, OwnedTags, PeriodicTickAlignment,
+};
+use crate::cues::manager::{GameplayCueEvent, GameplayCueManager, GameplayCueParameters};
 use crate::cues::systems::TriggerGameplayCueEvent;
 use crate::effects::application_requirement::{
     ApplicationAttributeSnapshot, ApplicationContext, ApplicationRequirementRegistry,
 };
+use crate::error::{GasError, GasReportExt};
 use bevy::ecs::relationship::Relationship;
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
-use bevy_gameplay_tag::GameplayTagsManager;
+use bevy_gameplay_tag::{GameplayTagContainer, GameplayTagsManager};
+use std::collections::{HashMap, HashSet, VecDeque};
 use string_cache::DefaultAtom as Atom;
 
 /// Bundled query parameters for applying gameplay effects.
 #[derive(SystemParam)]
 pub struct ApplyEffectParams<'w, 's> {
+    pub prediction_key: Option<Res<'w, ScopedPredictionKey>>,
     pub tag_containers: Query<'w, 's, &'static mut OwnedTags>,
     pub immunity_tags: Query<'w, 's, &'static crate::core::ImmunityTags>,
+    pub teams: Query<'w, 's, &'static crate::core::Team>,
     pub attributes: Query<
         'w,
         's,
         (
+            Entity,
             &'static mut AttributeData,
             &'static AttributeName,
             &'static ChildOf,
         ),
     >,
+    pub active_effects: Query<'w, 's, &'static ActiveEffects>,
+    pub rng: Option<ResMut<'w, GasRng>>,
     pub existing_effects: Query<
         'w,
         's,
         (
-            Entity,
             &'static mut ActiveGameplayEffect,
-            &'static EffectTarget,
             Option<&'static mut EffectDuration>,
             Option<&'static mut EffectInstigator>,
             Option<&'static mut GameplayEffectContext>,
@@ -96,6 +111,12 @@ impl ApplyGameplayEffectEvent {
         self
     }
 
+    /// Sets the prediction key on the contained spec.
+    pub fn with_prediction_key(mut self, key: PredictionKey) -> Self {
+        self.spec.prediction_key = Some(key);
+        self
+    }
+
     /// Returns the effect definition ID.
     pub fn effect_id(&self) -> &Atom {
         &self.spec.effect_id
@@ -122,10 +143,71 @@ impl ApplyGameplayEffectEvent {
 pub struct GameplayEffectAppliedEvent {
     /// The effect entity (None for instant effects that modify base_value directly).
     pub effect: Entity,
+    /// Handle to the effect, `None` for instant effects.
+    pub handle: Option<EffectHandle>,
+    /// The target entity.
+    pub target: Entity,
+    /// The effect definition ID.
+    pub effect_id: Atom,
+    /// Key of the predicted activation that applied the effect, if any.
+    pub prediction_key: Option<PredictionKey>,
+}
+
+impl GameplayEffectAppliedEvent {
+    /// Triggers the event once the queued commands spawned its effect, with
+    /// the effect's handle filled in.
+    fn trigger_with_handle(mut self, commands: &mut Commands) {
+        commands.queue(move |world: &mut World| {
+            self.handle = world
+                .get_resource::<HandleGenerations<ActiveGameplayEffect>>()
+                .and_then(|generations| generations.handle(self.effect));
+            world.trigger(self);
+        });
+    }
+}
+
+/// Event triggered each time an instant effect applies or a periodic effect
+/// executes, with the attribute changes it made.
+///
+/// Consumers such as [`GasThreatPlugin`](super::threat::GasThreatPlugin) use
+/// it to attribute damage and healing to their source.
+#[derive(Event, Debug, Clone)]
+pub struct GameplayEffectExecutedEvent {
+    /// The active effect entity for periodic executions, `None` for instant effects.
+    pub effect: Option<Entity>,
     /// The target entity.
     pub target: Entity,
     /// The effect definition ID.
     pub effect_id: Atom,
+    /// Context of the application, naming its source.
+    pub context: GameplayEffectContext,
+    /// Change of each modified attribute, in modifier order.
+    pub changes: Vec<(Atom, f32)>,
+    /// Final magnitude of each modifier, in modifier order.
+    pub magnitudes: Vec<(Atom, f32)>,
+    /// Magnitude of each modifier before damage mitigation, crits included,
+    /// in modifier order.
+    pub pre_mitigation: Vec<f32>,
+    /// Whether the damage was a critical hit.
+    pub critical: bool,
+    /// Prediction key of the application, if it was predicted.
+    pub prediction_key: Option<PredictionKey>,
+}
+
+impl GameplayEffectExecutedEvent {
+    /// Returns the entity that applied the effect, if known.
+    pub fn source(&self) -> Option<Entity> {
+        self.context.source.or(self.context.instigator)
+    }
+
+    /// Returns the total change made to `attribute_name`.
+    pub fn change(&self, attribute_name: &str) -> f32 {
+        self.changes
+            .iter()
+            .filter(|(name, _)| name.as_ref() == attribute_name)
+            .map(|(_, delta)| delta)
+            .sum()
+    }
 }
 
 /// Event triggered when an effect is removed.
@@ -139,6 +221,17 @@ pub struct GameplayEffectRemovedEvent {
     pub effect_id: Atom,
 }
 
+/// Event requesting the removal of an active effect before it expires.
+///
+/// The effect is queued in the [`ExpiredEffectQueue`] like an expired one, so
+/// its granted tags, [`GameplayEffectRemovedEvent`] and Removed cues are
+/// handled the same way. This is how infinite effects are taken off.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct RemoveGameplayEffectEvent {
+    /// The effect entity.
+    pub effect: Entity,
+}
+
 /// Event triggered when an effect is blocked by immunity.
 #[derive(Event, Debug, Clone)]
 pub struct GameplayEffectBlockedByImmunityEvent {
@@ -152,13 +245,60 @@ pub struct GameplayEffectBlockedByImmunityEvent {
     pub immunity_tag: bevy_gameplay_tag::gameplay_tag::GameplayTag,
 }
 
+/// Event triggered when an effect application is rejected.
+#[derive(Event, Debug, Clone)]
+pub struct GameplayEffectRejectedEvent {
+    /// The effect definition ID that was rejected.
+    pub effect_id: Atom,
+    /// The target entity.
+    pub target: Entity,
+    /// The instigator entity (if any).
+    pub instigator: Option<Entity>,
+    /// Why the application was rejected.
+    pub reason: EffectRejectionReason,
+}
+
+impl GameplayEffectRejectedEvent {
+    fn new(spec: &GameplayEffectSpec, reason: EffectRejectionReason) -> Self {
+        Self {
+            effect_id: spec.effect_id.clone(),
+            target: spec.target,
+            instigator: spec.instigator(),
+            reason,
+        }
+    }
+}
+
+/// Reason why an effect application was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EffectRejectionReason {
+    /// No definition is registered under the effect ID.
+    UnknownDefinition,
+    /// The effect's team policy excludes the target.
+    TeamPolicy,
+    /// The target has an immunity tag matching the effect.
+    Immune,
+    /// The target owns one of the effect's asset tags.
+    AssetTagImmunity,
+    /// The target doesn't meet the application tag requirements.
+    TagRequirements,
+    /// A custom application requirement refused the effect.
+    ApplicationRequirement,
+    /// The target already has `GasSettings::max_active_effects_per_entity`
+    /// active effects.
+    MaxActiveEffects,
+}
+
 fn build_cue_parameters(spec: &GameplayEffectSpec) -> GameplayCueParameters {
     let mut parameters = GameplayCueParameters::new().with_target(spec.target);
 
-    if let Some(instigator) = spec.context.instigator {
+    // The context's `source` owns the effect while its `instigator` is the direct
+    // causer (e.g. a projectile), matching the cue's instigator/effect_causer split.
+    if let Some(instigator) = spec.source_entity() {
         parameters = parameters.with_instigator(instigator);
     }
-    if let Some(effect_causer) = spec.context.source {
+    if let Some(effect_causer) = spec.instigator() {
         parameters = parameters.with_effect_causer(effect_causer);
     }
     if let Some(location) = spec.context.hit_location {
@@ -166,11 +306,25 @@ fn build_cue_parameters(spec: &GameplayEffectSpec) -> GameplayCueParameters {
     }
     if let Some(normal) = spec.context.hit_normal {
         parameters = parameters.with_normal(normal);
+        parameters.normal_impact_normal = Some(normal);
     }
+    parameters.gameplay_effect_level = spec.level as f32;
+    parameters.prediction_key = spec.prediction_key;
 
     parameters
 }
 
+/// Picks the raw magnitude a cue reports from the evaluated modifiers.
+fn cue_raw_magnitude(cue: &GameplayEffectCue, magnitudes: &[(Atom, f32)]) -> Option<f32> {
+    match &cue.magnitude_attribute {
+        Some(attribute_name) => magnitudes
+            .iter()
+            .find(|(name, _)| name == attribute_name)
+            .map(|(_, magnitude)| *magnitude),
+        None => magnitudes.first().map(|(_, magnitude)| *magnitude),
+    }
+}
+
 fn merge_cue_parameters(
     base: GameplayCueParameters,
     override_parameters: &GameplayCueParameters,
@@ -218,59 +372,94 @@ fn merge_cue_parameters(
         },
         source_tags: override_parameters.source_tags.clone().or(base.source_tags),
         target_tags: override_parameters.target_tags.clone().or(base.target_tags),
+        source_effect: override_parameters.source_effect.or(base.source_effect),
+        prediction_key: override_parameters.prediction_key.or(base.prediction_key),
+        critical: override_parameters.critical || base.critical,
+        cue_tag: override_parameters.cue_tag.clone().or(base.cue_tag),
     }
 }
 
+/// Triggers the definition's cues for `event_type`.
+///
+/// `magnitudes` holds the evaluated modifier magnitudes in definition order,
+/// keyed by attribute name. It may be empty when they are not available, in
+/// which case cues report no raw magnitude.
 fn trigger_effect_cues(
     commands: &mut Commands,
     definition: &GameplayEffectDefinition,
     event_type: GameplayCueEvent,
     spec: &GameplayEffectSpec,
+    effect_entity: Option<Entity>,
+    magnitudes: &[(Atom, f32)],
 ) {
-    let base_parameters = build_cue_parameters(spec);
-
-    for cue in &definition.gameplay_cues {
-        if !cue.applies_to_level(spec.level) {
-            continue;
-        }
-
-        commands.trigger(TriggerGameplayCueEvent {
-            cue_tag: cue.cue_tag.clone(),
-            event_type,
-            parameters: merge_cue_parameters(base_parameters.clone(), &cue.parameters),
-        });
+    for cue in effect_cue_events(definition, event_type, spec, effect_entity, magnitudes) {
+        commands.trigger(cue);
     }
 }
 
-fn trigger_effect_cues_from_components(
-    commands: &mut Commands,
+/// Builds the cue triggers [`trigger_effect_cues`] sends.
+fn effect_cue_events(
     definition: &GameplayEffectDefinition,
     event_type: GameplayCueEvent,
-    effect_id: &Atom,
-    target: Entity,
-    level: i32,
+    spec: &GameplayEffectSpec,
+    effect_entity: Option<Entity>,
+    magnitudes: &[(Atom, f32)],
+) -> Vec<TriggerGameplayCueEvent> {
+    let mut base_parameters = build_cue_parameters(spec);
+    base_parameters.source_effect = effect_entity;
+
+    definition
+        .gameplay_cues
+        .iter()
+        .filter(|cue| cue.applies_to_level(spec.level))
+        .map(|cue| {
+            let mut parameters = base_parameters.clone();
+            parameters.normalized_magnitude = cue.normalized_level(spec.level);
+            if let Some(raw_magnitude) = cue_raw_magnitude(cue, magnitudes) {
+                parameters.raw_magnitude = raw_magnitude;
+            }
+
+            TriggerGameplayCueEvent {
+                cue_tag: cue.cue_tag.clone(),
+                event_type,
+                parameters: merge_cue_parameters(parameters, &cue.parameters),
+            }
+        })
+        .collect()
+}
+
+/// Rebuilds the spec of an active effect from its components, for cues.
+fn effect_spec_from_components(
+    definition: &GameplayEffectDefinition,
+    active_effect: &ActiveGameplayEffect,
     context: Option<&GameplayEffectContext>,
-) {
-    let spec = GameplayEffectSpec {
-        effect_id: effect_id.clone(),
-        target,
-        level,
+    prediction_key: Option<&PredictionKey>,
+) -> GameplayEffectSpec {
+    GameplayEffectSpec {
+        effect_id: definition.id.clone(),
+        target: active_effect.target,
+        level: active_effect.level,
         context: context.cloned().unwrap_or_default(),
         set_by_caller_magnitudes: SetByCallerMagnitudes::new(),
         captured_attributes: std::collections::HashMap::new(),
-    };
+        prediction_key: prediction_key.copied(),
+    }
+}
 
-    trigger_effect_cues(commands, definition, event_type, &spec);
+/// What a modifier magnitude is evaluated against.
+struct MagnitudeInputs<'a> {
+    level: i32,
+    source: Option<Entity>,
+    target: Entity,
+    set_by_caller: Option<&'a SetByCallerMagnitudes>,
+    attributes: &'a [ApplicationAttributeSnapshot],
+    captured: &'a CapturedAttributes,
 }
 
 fn calculate_modifier_magnitude(
     magnitude: &MagnitudeCalculation,
-    level: i32,
-    source_entity: Option<Entity>,
-    target_entity: Entity,
-    set_by_caller: Option<&SetByCallerMagnitudes>,
+    inputs: &MagnitudeInputs,
     custom_calculators: &super::custom_calculation::CustomCalculationRegistry,
-    attributes: &[ApplicationAttributeSnapshot],
 ) -> f32 {
     let source_value = match magnitude {
         MagnitudeCalculation::AttributeBased {
@@ -282,12 +471,13 @@ fn calculate_modifier_magnitude(
             use super::definition::{AttributeCalculationType, AttributeCaptureSource};
 
             let capture_entity = match capture_source {
-                AttributeCaptureSource::Source => source_entity,
-                AttributeCaptureSource::Target => Some(target_entity),
+                AttributeCaptureSource::Source => inputs.source,
+                AttributeCaptureSource::Target => Some(inputs.target),
             };
 
             capture_entity.and_then(|entity| {
-                attributes
+                inputs
+                    .attributes
                     .iter()
                     .find(|snapshot| {
                         snapshot.owner == entity && snapshot.attribute_name == *attribute_name
@@ -301,48 +491,48 @@ fn calculate_modifier_magnitude(
                     })
             })
         }
-        MagnitudeCalculation::SetByCaller { data_tag } => {
-            set_by_caller.and_then(|magnitudes| magnitudes.get_magnitude(data_tag))
-        }
+        MagnitudeCalculation::SetByCaller { data_tag } => inputs
+            .set_by_caller
+            .and_then(|magnitudes| magnitudes.get_magnitude(data_tag)),
         MagnitudeCalculation::CustomClass { calculator_name } => {
             if let Some(calculator) = custom_calculators.get(calculator_name) {
                 use super::custom_calculation::CalculationContext;
+                use super::definition::AttributeCaptureSource;
 
                 let mut source_attrs = std::collections::HashMap::new();
                 let mut target_attrs = std::collections::HashMap::new();
 
-                if let Some(source) = source_entity {
-                    for attr_name in calculator.required_source_attributes() {
-                        if let Some(value) = attributes
-                            .iter()
-                            .find(|snapshot| {
-                                snapshot.owner == source
-                                    && snapshot.attribute_name.as_ref() == *attr_name
-                            })
-                            .map(|snapshot| snapshot.current_value)
-                        {
-                            source_attrs.insert((*attr_name).into(), value);
+                if calculator.captures_all_attributes() {
+                    for snapshot in inputs.attributes {
+                        if Some(snapshot.owner) == inputs.source {
+                            source_attrs
+                                .insert(snapshot.attribute_name.clone(), snapshot.current_value);
+                        }
+                        if snapshot.owner == inputs.target {
+                            target_attrs
+                                .insert(snapshot.attribute_name.clone(), snapshot.current_value);
                         }
                     }
                 }
 
-                for attr_name in calculator.required_target_attributes() {
-                    if let Some(value) = attributes
-                        .iter()
-                        .find(|snapshot| {
-                            snapshot.owner == target_entity
-                                && snapshot.attribute_name.as_ref() == *attr_name
-                        })
-                        .map(|snapshot| snapshot.current_value)
-                    {
-                        target_attrs.insert((*attr_name).into(), value);
+                // Captures were resolved for the whole effect up front
+                for capture in calculator.relevant_attributes_to_capture() {
+                    if let Some(value) = inputs.captured.get(&capture) {
+                        match capture.capture_source {
+                            AttributeCaptureSource::Source => {
+                                source_attrs.insert(capture.attribute_name, value)
+                            }
+                            AttributeCaptureSource::Target => {
+                                target_attrs.insert(capture.attribute_name, value)
+                            }
+                        };
                     }
                 }
 
                 let context = CalculationContext {
-                    source: source_entity,
-                    target: target_entity,
-                    level,
+                    source: inputs.source,
+                    target: inputs.target,
+                    level: inputs.level,
                     source_attributes: source_attrs,
                     target_attributes: target_attrs,
                 };
@@ -365,242 +555,597 @@ fn calculate_modifier_magnitude(
         MagnitudeCalculation::ScalableFloat { .. } => None,
     };
 
-    magnitude.evaluate(level, source_value)
+    magnitude.evaluate(inputs.level, source_value)
+}
+
+/// Evaluates the magnitude of each modifier of `definition`.
+///
+/// Target independent magnitudes are read from and recorded into `shared`,
+/// keyed by modifier index, so a batch group evaluates them once.
+fn modifier_magnitudes(
+    definition: &GameplayEffectDefinition,
+    inputs: &MagnitudeInputs,
+    custom_calculators: &super::custom_calculation::CustomCalculationRegistry,
+    shared: &mut HashMap<usize, f32>,
+) -> Vec<(Atom, f32)> {
+    definition
+        .modifiers
+        .iter()
+        .enumerate()
+        .map(|(index, modifier)| {
+            let calculate =
+                || calculate_modifier_magnitude(&modifier.magnitude, inputs, custom_calculators);
+            let magnitude = if modifier.magnitude.is_target_independent() {
+                *shared.entry(index).or_insert_with(calculate)
+            } else {
+                calculate()
+            };
+            (modifier.attribute_name.clone(), magnitude)
+        })
+        .collect()
+}
+
+/// Resources read while applying gameplay effects.
+#[derive(SystemParam)]
+pub struct ApplyEffectResources<'w> {
+    pub registry: Res<'w, GameplayEffectRegistry>,
+    pub application_requirements: Res<'w, ApplicationRequirementRegistry>,
+    pub custom_calculators: Res<'w, super::custom_calculation::CustomCalculationRegistry>,
+    pub damage: Res<'w, DamageCalculationRegistry>,
+    pub tags_manager: Res<'w, GameplayTagsManager>,
+    pub time: Res<'w, Time>,
+    pub settings: Res<'w, GasSettings>,
+}
+
+/// Read access to the effects active on a target.
+#[derive(SystemParam)]
+pub struct TargetEffects<'w, 's> {
+    pub lists: Query<'w, 's, &'static ActiveEffects>,
+    pub effects: Query<'w, 's, &'static ActiveGameplayEffect>,
+}
+
+impl TargetEffects<'_, '_> {
+    /// Returns the effects active on `target`.
+    pub fn of(&self, target: Entity) -> impl Iterator<Item = &ActiveGameplayEffect> {
+        self.lists
+            .get(target)
+            .into_iter()
+            .flat_map(|list| list.iter())
+            .filter_map(|effect| self.effects.get(effect).ok())
+    }
 }
 
 /// Observer for ApplyGameplayEffectEvent.
+///
+/// Applies the effect immediately. Applications to the same target still
+/// queued in [`BatchedEvents<ApplyGameplayEffectEvent>`] with a higher
+/// definition [`priority`](GameplayEffectDefinition::priority) are taken out
+/// of the queue and applied first, so priorities order triggered and batched
+/// applications alike.
 pub fn on_apply_gameplay_effect(
     ev: On<ApplyGameplayEffectEvent>,
     mut commands: Commands,
-    registry: Res<GameplayEffectRegistry>,
-    application_requirements: Res<ApplicationRequirementRegistry>,
-    custom_calculators: Res<super::custom_calculation::CustomCalculationRegistry>,
-    tags_manager: Res<GameplayTagsManager>,
-    time: Res<Time>,
+    mut queued: ResMut<BatchedEvents<ApplyGameplayEffectEvent>>,
+    resources: ApplyEffectResources,
     mut params: ApplyEffectParams,
 ) {
-    let event = ev.event();
-    let spec = &event.spec;
-    let target = spec.target;
-    let effect_id = &spec.effect_id;
-    let level = spec.level;
-
-    let Some(definition) = registry.get(effect_id) else {
-        warn!("Effect definition not found: {}", effect_id);
-        return;
+    let spec = &ev.event().spec;
+    let _span = info_span!("gas::apply_effect", effect_id = %spec.effect_id).entered();
+    let priority = |effect_id: &Atom| {
+        resources
+            .registry
+            .get(effect_id)
+            .map_or(0, |definition| definition.priority)
     };
 
-    // Check immunity: if target has immunity tags matching effect's immunity_tags, reject
-    if let Ok(target_immunity) = params.immunity_tags.get(target) {
-        for immunity_tag in definition.immunity_tags.gameplay_tags.iter() {
-            if target_immunity
-                .0
-                .explicit_tags
-                .gameplay_tags
-                .contains(immunity_tag)
-            {
-                // Target is immune to this effect
-                info!(
-                    "Effect '{}' blocked by immunity tag '{:?}' on target {:?}",
-                    effect_id, immunity_tag, target
-                );
+    let own_priority = priority(&spec.effect_id);
+    let mut ahead: Vec<GameplayEffectSpec> = queued
+        .take_where(|queued| {
+            queued.spec.target == spec.target && priority(&queued.spec.effect_id) > own_priority
+        })
+        .into_iter()
+        .map(|event| event.spec)
+        .collect();
+    // Stable, so equal priorities keep the order they were pushed in
+    ahead.sort_by_key(|spec| std::cmp::Reverse(priority(&spec.effect_id)));
+
+    let mut batch = ApplicationBatch::new(&params);
+    let mut pending = HashMap::new();
+    for spec in ahead.iter().chain([spec]) {
+        apply_effect_group(
+            &mut commands,
+            &spec.effect_id,
+            std::slice::from_ref(spec),
+            &resources,
+            &mut params,
+            &mut batch,
+            &mut pending,
+        );
+    }
+    for cue in batch.cues {
+        commands.trigger(cue);
+    }
+}
 
-                // Trigger immunity event
-                commands.trigger(GameplayEffectBlockedByImmunityEvent {
-                    effect_id: effect_id.clone(),
-                    target,
-                    instigator: spec.instigator(),
-                    immunity_tag: immunity_tag.clone(),
-                });
+/// System applying the effects queued in
+/// [`BatchedEvents<ApplyGameplayEffectEvent>`].
+///
+/// Events are grouped by definition, level, source and instigator, so a mass
+/// application (an aura ticking on a hundred enemies) looks its definition up
+/// once and evaluates target independent magnitudes once for the whole
+/// group. Events that can't be batched form a group of their own. Groups are
+/// applied in descending definition
+/// [`priority`](GameplayEffectDefinition::priority), and in the order they
+/// were pushed within a priority, so same-frame outcomes (shield before
+/// damage) don't depend on which system pushed its event first. The attribute snapshot
+/// used by attribute-based magnitudes and application requirements is
+/// captured once for the whole batch instead of once per event. The batch's
+/// cues fire together inside a [`GameplayCueManager`] batching window.
+pub fn apply_batched_effects_system(
+    mut commands: Commands,
+    mut batch: ResMut<BatchedEvents<ApplyGameplayEffectEvent>>,
+    resources: ApplyEffectResources,
+    mut params: ApplyEffectParams,
+) {
+    let _span = info_span!("gas::apply_batched_effects").entered();
+    if batch.is_empty() {
+        return;
+    }
 
-                return;
-            }
+    let mut groups: Vec<(Atom, Vec<GameplayEffectSpec>)> = Vec::new();
+    let mut group_indices: HashMap<(Atom, i32, Option<Entity>, Option<Entity>), usize> =
+        HashMap::new();
+    for event in batch.drain() {
+        let batchable = event.can_batch();
+        let spec = event.spec;
+        let index = if batchable {
+            let key = (
+                spec.effect_id.clone(),
+                spec.level,
+                spec.source_entity(),
+                spec.instigator(),
+            );
+            *group_indices.entry(key).or_insert(groups.len())
+        } else {
+            groups.len()
+        };
+        if index == groups.len() {
+            groups.push((spec.effect_id.clone(), Vec::new()));
         }
+        groups[index].1.push(spec);
+    }
+    // Stable, so equal priorities keep the order they were sent in
+    groups.sort_by_key(|(effect_id, _)| {
+        std::cmp::Reverse(
+            resources
+                .registry
+                .get(effect_id)
+                .map_or(0, |definition| definition.priority),
+        )
+    });
+
+    let mut application = ApplicationBatch::new(&params);
+    let mut pending = HashMap::new();
+    for (effect_id, specs) in &groups {
+        apply_effect_group(
+            &mut commands,
+            effect_id,
+            specs,
+            &resources,
+            &mut params,
+            &mut application,
+            &mut pending,
+        );
     }
 
-    // Legacy check: if target has any of the effect's asset_tags in their owned tags, reject
-    // This is for backwards compatibility with the old immunity system
-    if let Ok(owner_tags) = params.tag_containers.get(target) {
-        for asset_tag in definition.asset_tags.gameplay_tags.iter() {
-            if owner_tags.0.explicit_tags.gameplay_tags.contains(asset_tag) {
-                // Target is immune to this effect
-                info!(
-                    "Effect '{}' blocked by asset tag immunity '{:?}' on target {:?}",
-                    effect_id, asset_tag, target
-                );
-                return;
-            }
-        }
+    let cues = application.cues;
+    if !cues.is_empty() {
+        commands.queue(move |world: &mut World| {
+            with_cue_batching(world, |world| {
+                for cue in cues {
+                    world.trigger(cue);
+                }
+            });
+        });
     }
+}
 
-    // Check application_tag_requirements
-    if let Ok(owner_tags) = params.tag_containers.get(target) {
-        // OwnedTags wraps GameplayTagCountContainer which has explicit_tags field
-        let tag_container = &owner_tags.0;
-        if !definition
-            .application_tag_requirements
-            .requirements_met(&tag_container.explicit_tags)
-        {
-            return;
+/// Applies `specs` of the definition `effect_id` in order.
+///
+/// Effects spawned earlier in the same pass aren't visible to the stacking
+/// queries yet, so `pending` tracks them and later applications stack onto
+/// them here.
+fn apply_effect_group(
+    commands: &mut Commands,
+    effect_id: &Atom,
+    specs: &[GameplayEffectSpec],
+    resources: &ApplyEffectResources,
+    params: &mut ApplyEffectParams,
+    application: &mut ApplicationBatch,
+    pending: &mut HashMap<(Entity, Atom), PendingEffect>,
+) {
+    let Some(definition) = resources
+        .registry
+        .try_get(effect_id.clone())
+        .or_report(commands)
+    else {
+        for spec in specs {
+            commands.trigger(GameplayEffectRejectedEvent::new(
+                spec,
+                EffectRejectionReason::UnknownDefinition,
+            ));
+        }
+        return;
+    };
+    application.shared_magnitudes.clear();
+    for spec in specs {
+        let key = (spec.target, effect_id.clone());
+        match pending.get_mut(&key) {
+            Some(pending) if !matches!(definition.stacking_policy, StackingPolicy::Independent) => {
+                if effect_application_allowed(
+                    commands,
+                    spec,
+                    definition,
+                    resources,
+                    params,
+                    &application.attribute_snapshots,
+                ) {
+                    stack_onto_pending_effect(commands, spec, definition, pending);
+                }
+            }
+            _ => {
+                if let Some(entity) =
+                    apply_effect_spec(commands, spec, definition, resources, params, application)
+                {
+                    pending.insert(
+                        key,
+                        PendingEffect {
+                            entity,
+                            stack_count: 1,
+                        },
+                    );
+                }
+            }
         }
     }
+}
 
-    // Check custom application requirements.
-    let target_tags = params.tag_containers.get(target).ok();
-    let source_tags = spec
-        .source_entity()
-        .and_then(|source| params.tag_containers.get(source).ok());
+/// Runs `f` inside a [`GameplayCueManager`] batching window, joining one the
+/// caller already opened.
+fn with_cue_batching(world: &mut World, f: impl FnOnce(&mut World)) {
+    let start_batching = world
+        .get_resource::<GameplayCueManager>()
+        .is_some_and(|manager| !manager.batching_active);
+    if start_batching {
+        world.resource_mut::<GameplayCueManager>().start_batching();
+    }
+    f(world);
+    if start_batching {
+        world.flush();
+        world.resource_mut::<GameplayCueManager>().end_batching();
+    }
+}
 
-    let attribute_snapshots: Vec<_> = params
-        .attributes
-        .iter()
-        .map(|(data, name, child_of)| ApplicationAttributeSnapshot::new(child_of.get(), name, data))
-        .collect();
+/// An effect entity spawned earlier in the current batch.
+struct PendingEffect {
+    entity: Entity,
+    stack_count: i32,
+}
 
-    for requirement_name in &definition.application_requirements {
-        let Some(requirement) = application_requirements.get(requirement_name) else {
-            warn!(
-                "Effect '{}' references unknown application requirement '{}'",
-                effect_id, requirement_name
-            );
-            return;
-        };
+/// Refreshes or stacks `pending` the way reapplying onto an existing effect
+/// does.
+fn stack_onto_pending_effect(
+    commands: &mut Commands,
+    spec: &GameplayEffectSpec,
+    definition: &GameplayEffectDefinition,
+    pending: &mut PendingEffect,
+) {
+    if let StackingPolicy::StackCount { max_stacks } = definition.stacking_policy
+        && pending.stack_count < max_stacks
+    {
+        pending.stack_count += 1;
+    }
 
-        let context = ApplicationContext {
-            source: spec.source_entity(),
-            target,
-            level,
-            target_tags: target_tags.as_ref().copied(),
-            source_tags: source_tags.as_ref().copied(),
-            attributes: &attribute_snapshots,
-        };
+    let stack_count = pending.stack_count;
+    let instigator = spec.instigator();
+    let context = spec.context.clone();
+    let set_by_caller = spec.set_by_caller_magnitudes.clone();
+    commands
+        .entity(pending.entity)
+        .queue(move |mut entity: EntityWorldMut| {
+            if let Some(mut active_effect) = entity.get_mut::<ActiveGameplayEffect>() {
+                active_effect.stack_count = stack_count;
+            }
+            if let Some(mut instigator_component) = entity.get_mut::<EffectInstigator>() {
+                instigator_component.0 = instigator;
+            }
+            if let Some(mut context_component) = entity.get_mut::<GameplayEffectContext>() {
+                *context_component = context;
+            }
+            if let Some(mut set_by_caller_component) = entity.get_mut::<SetByCallerMagnitudes>() {
+                *set_by_caller_component = set_by_caller;
+            }
+        });
 
-        if !requirement.can_apply(&context) {
-            return;
-        }
+    GameplayEffectAppliedEvent {
+        effect: pending.entity,
+        handle: None,
+        target: spec.target,
+        effect_id: spec.effect_id.clone(),
+        prediction_key: spec.prediction_key,
     }
+    .trigger_with_handle(commands);
+}
 
-    // Handle stacking
-    match definition.stacking_policy {
-        StackingPolicy::RefreshDuration => {
-            // Find existing effect and refresh its duration
-            for (
-                effect_entity,
-                active_effect,
-                effect_target,
-                duration,
-                effect_instigator,
-                effect_context,
-                set_by_caller,
-            ) in params.existing_effects.iter_mut()
+/// Reflects the damage `spec` dealt to `victim` through the reflections of
+/// the victim's active effects.
+fn trigger_reflections<'a>(
+    commands: &mut Commands,
+    registry: &GameplayEffectRegistry,
+    victim: Entity,
+    victim_effects: impl Iterator<Item = &'a ActiveGameplayEffect>,
+    spec: &GameplayEffectSpec,
+    changes: &[(Atom, f32)],
+) {
+    if spec.context.reflected {
+        return;
+    }
+    for active_effect in victim_effects {
+        let Some(definition) = registry.get(&active_effect.definition_id) else {
+            continue;
+        };
+        for reflection in &definition.reflections {
+            if let Some(application) =
+                reflection.application(victim, active_effect.level, spec, changes)
             {
-                if effect_target.0 == target && active_effect.definition_id == *effect_id {
-                    if let Some(mut dur) = duration {
-                        dur.remaining = definition.duration_magnitude;
-                    }
-                    if let Some(mut instigator_component) = effect_instigator {
-                        instigator_component.0 = spec.instigator();
-                    }
-                    if let Some(mut context_component) = effect_context {
-                        *context_component = spec.context.clone();
-                    }
-                    if let Some(mut set_by_caller_component) = set_by_caller {
-                        *set_by_caller_component = spec.set_by_caller_magnitudes.clone();
-                    }
-                    commands.trigger(GameplayEffectAppliedEvent {
-                        effect: effect_entity,
-                        target,
-                        effect_id: effect_id.clone(),
-                    });
-                    return;
-                }
+                commands.trigger(application);
             }
-            // Fall through to spawn new if no existing found
         }
-        StackingPolicy::StackCount { max_stacks } => {
-            // Find existing effect and increment stack count
-            for (
-                effect_entity,
-                mut active_effect,
-                effect_target,
-                duration,
-                effect_instigator,
-                effect_context,
-                set_by_caller,
-            ) in params.existing_effects.iter_mut()
-            {
-                if effect_target.0 == target && active_effect.definition_id == *effect_id {
-                    if active_effect.stack_count < max_stacks {
-                        active_effect.stack_count += 1;
-                    }
-                    if let Some(mut dur) = duration {
-                        dur.remaining = definition.duration_magnitude;
-                    }
-                    if let Some(mut instigator_component) = effect_instigator {
-                        instigator_component.0 = spec.instigator();
-                    }
-                    if let Some(mut context_component) = effect_context {
-                        *context_component = spec.context.clone();
-                    }
-                    if let Some(mut set_by_caller_component) = set_by_caller {
-                        *set_by_caller_component = spec.set_by_caller_magnitudes.clone();
-                    }
-                    commands.trigger(GameplayEffectAppliedEvent {
-                        effect: effect_entity,
-                        target,
-                        effect_id: effect_id.clone(),
-                    });
-                    return;
-                }
+    }
+}
+
+/// Reports each modifier of `definition` naming an attribute `target` lacks.
+fn report_missing_attributes(
+    commands: &mut Commands,
+    definition: &GameplayEffectDefinition,
+    target: Entity,
+    attribute_snapshots: &[ApplicationAttributeSnapshot],
+) {
+    for modifier in &definition.modifiers {
+        if !attribute_snapshots.iter().any(|snapshot| {
+            snapshot.owner == target && snapshot.attribute_name == modifier.attribute_name
+        }) {
+            GasError::AttributeNotFound {
+                attribute_name: modifier.attribute_name.clone(),
+                owner: target,
             }
-            // Fall through to spawn new if no existing found
-        }
-        StackingPolicy::Independent => {
-            // Always spawn a new effect entity
+            .report(commands);
         }
     }
+}
 
-    match definition.duration_policy {
-        DurationPolicy::Instant => {
-            // Directly modify attribute base_value, no entity spawn
-            for modifier in &definition.modifiers {
-                let magnitude = calculate_modifier_magnitude(
-                    &modifier.magnitude,
-                    level,
-                    spec.source_entity(),
-                    target,
-                    Some(&spec.set_by_caller_magnitudes),
-                    &custom_calculators,
-                    &attribute_snapshots,
-                );
-                for (mut attr_data, attr_name, attr_owner) in params.attributes.iter_mut() {
-                    if attr_owner.0 == target && attr_name.0 == modifier.attribute_name {
-                        let old_value = attr_data.base_value;
-                        let new_value = match modifier.operation {
-                            ModifierOperation::AddBase | ModifierOperation::AddCurrent => {
-                                old_value + magnitude
-                            }
-                            ModifierOperation::MultiplyAdditive
-                            | ModifierOperation::MultiplyMultiplicative => {
-                                old_value * (1.0 + magnitude)
-                            }
-                            ModifierOperation::Override => magnitude,
-                        };
+/// State shared by the specs applied in one pass.
+#[derive(Default)]
+struct ApplicationBatch {
+    /// Every attribute, for magnitude captures and requirement checks.
+    attribute_snapshots: Vec<ApplicationAttributeSnapshot>,
+    /// Attribute entities by owner and name, for writing base values.
+    attribute_entities: HashMap<(Entity, Atom), Entity>,
+    /// Target independent magnitudes of the current group, by modifier index.
+    shared_magnitudes: HashMap<usize, f32>,
+    /// Cues to trigger once the pass is done.
+    cues: Vec<TriggerGameplayCueEvent>,
+    /// Resource gains granted by the pass, counted towards their caps.
+    resource_gains: PendingResourceGains,
+    /// Amounts absorbed by damage mitigations in the pass, which the
+    /// snapshots don't show.
+    absorbed: Vec<(Entity, Atom, f32)>,
+}
 
-                        // Call pre_effect_execute hook (allows clamping/rejection)
-                        // TODO: Get AttributeSetId to look up hooks
-                        // For now, we skip hooks for instant effects
-                        // This will be implemented when we add AttributeSnapshot
+impl ApplicationBatch {
+    /// Snapshots and indexes every attribute.
+    fn new(params: &ApplyEffectParams) -> Self {
+        let mut batch = Self::default();
+        for (entity, data, name, child_of) in params.attributes.iter() {
+            batch
+                .attribute_snapshots
+                .push(ApplicationAttributeSnapshot::new(
+                    child_of.get(),
+                    name,
+                    data,
+                ));
+            batch
+                .attribute_entities
+                .insert((child_of.get(), name.0.clone()), entity);
+        }
+        batch
+    }
+}
 
-                        // Apply the modification
-                        attr_data.base_value = new_value;
-                        // Don't set current_value - let aggregation handle it
+/// Returns the active effect of `target` created from `effect_id`, if any.
+fn find_active_effect(
+    params: &ApplyEffectParams,
+    target: Entity,
+    effect_id: &Atom,
+) -> Option<Entity> {
+    let effects = params.active_effects.get(target).ok()?;
+    effects.iter().find(|&effect| {
+        params
+            .existing_effects
+            .get(effect)
+            .is_ok_and(|(active_effect, ..)| active_effect.definition_id == *effect_id)
+    })
+}
 
-                        // Call post_effect_execute hook
-                        // TODO: Implement when AttributeSnapshot is added
-                    }
+/// Applies `spec` with its already looked-up `definition`.
+///
+/// Returns the effect entity when a new one is spawned. Cues are pushed to
+/// the batch instead of being triggered, so the caller decides when they fire.
+fn apply_effect_spec(
+    commands: &mut Commands,
+    spec: &GameplayEffectSpec,
+    definition: &GameplayEffectDefinition,
+    resources: &ApplyEffectResources,
+    params: &mut ApplyEffectParams,
+    batch: &mut ApplicationBatch,
+) -> Option<Entity> {
+    let attribute_snapshots = &batch.attribute_snapshots;
+    // Effects applied by a predicted ability commit take the scoped key.
+    let scoped_spec;
+    let spec = match (
+        spec.prediction_key,
+        params.prediction_key.as_ref().and_then(|scoped| scoped.0),
+    ) {
+        (None, Some(key)) => {
+            scoped_spec = spec.clone().with_prediction_key(key);
+            &scoped_spec
+        }
+        _ => spec,
+    };
+    if !effect_application_allowed(
+        commands,
+        spec,
+        definition,
+        resources,
+        params,
+        attribute_snapshots,
+    ) {
+        return None;
+    }
+
+    let target = spec.target;
+    let effect_id = &spec.effect_id;
+    let level = spec.level;
+    let prediction_key = spec.prediction_key;
+
+    // Handle stacking: refresh (and for StackCount, stack onto) the target's
+    // existing instance of this effect instead of spawning a new one
+    if definition.stacking_policy != StackingPolicy::Independent
+        && let Some(effect_entity) = find_active_effect(params, target, effect_id)
+        && let Ok((mut active_effect, duration, effect_instigator, effect_context, set_by_caller)) =
+            params.existing_effects.get_mut(effect_entity)
+    {
+        if let StackingPolicy::StackCount { max_stacks } = definition.stacking_policy
+            && active_effect.stack_count < max_stacks
+        {
+            active_effect.stack_count += 1;
+        }
+        if let Some(mut dur) = duration {
+            dur.remaining = definition.duration_magnitude;
+        }
+        if let Some(mut instigator_component) = effect_instigator {
+            instigator_component.0 = spec.instigator();
+        }
+        if let Some(mut context_component) = effect_context {
+            *context_component = spec.context.clone();
+        }
+        if let Some(mut set_by_caller_component) = set_by_caller {
+            *set_by_caller_component = spec.set_by_caller_magnitudes.clone();
+        }
+        GameplayEffectAppliedEvent {
+            effect: effect_entity,
+            handle: None,
+            target,
+            effect_id: effect_id.clone(),
+            prediction_key,
+        }
+        .trigger_with_handle(commands);
+        return None;
+    }
+
+    // Resolve the captures of every calculation at once. Snapshot captures
+    // are kept on the effect entity for its later evaluations
+    let mut snapshotted = spec.captured_attributes.clone();
+    let captured = CapturedAttributes::capture(
+        &definition.attribute_captures(&resources.custom_calculators),
+        spec.source_entity(),
+        target,
+        attribute_snapshots,
+        &mut snapshotted,
+    );
+    let inputs = MagnitudeInputs {
+        level,
+        source: spec.source_entity(),
+        target,
+        set_by_caller: Some(&spec.set_by_caller_magnitudes),
+        attributes: attribute_snapshots,
+        captured: &captured,
+    };
+
+    match definition.duration_policy {
+        DurationPolicy::Instant => {
+            report_missing_attributes(commands, definition, target, attribute_snapshots);
+            // Directly modify attribute base_value, no entity spawn
+            let mut magnitudes = modifier_magnitudes(
+                definition,
+                &inputs,
+                &resources.custom_calculators,
+                &mut batch.shared_magnitudes,
+            );
+            let mut fallback_rng = GasRng::default();
+            let AppliedDamage {
+                pre_mitigation,
+                critical,
+                absorbed,
+            } = resources.damage.apply(
+                commands,
+                definition.damage.as_ref(),
+                &definition.modifiers,
+                &mut magnitudes,
+                &DamageContext {
+                    source: spec.source_entity(),
+                    target,
+                    level,
+                    attributes: attribute_snapshots,
+                    absorbed: &batch.absorbed,
+                },
+                params.rng.as_deref_mut().unwrap_or(&mut fallback_rng),
+            );
+            let mut changes = Vec::new();
+            for (modifier, &(_, magnitude)) in definition.modifiers.iter().zip(&magnitudes) {
+                if let Some(&attribute) = batch
+                    .attribute_entities
+                    .get(&(target, modifier.attribute_name.clone()))
+                    && let Ok((_, mut attr_data, ..)) = params.attributes.get_mut(attribute)
+                {
+                    let old_value = attr_data.base_value;
+                    let new_value = match modifier.operation {
+                        ModifierOperation::AddBase | ModifierOperation::AddCurrent => {
+                            old_value + magnitude
+                        }
+                        ModifierOperation::MultiplyAdditive
+                        | ModifierOperation::MultiplyMultiplicative => {
+                            old_value * (1.0 + magnitude)
+                        }
+                        ModifierOperation::Override => magnitude,
+                    };
+
+                    // Call pre_effect_execute hook (allows clamping/rejection)
+                    // TODO: Get AttributeSetId to look up hooks
+                    // For now, we skip hooks for instant effects
+                    // This will be implemented when we add AttributeSnapshot
+
+                    // Apply the modification
+                    attr_data.base_value = new_value;
+                    changes.push((modifier.attribute_name.clone(), new_value - old_value));
+                    // Don't set current_value - let aggregation handle it
+
+                    // Call post_effect_execute hook
+                    // TODO: Implement when AttributeSnapshot is added
+                }
+            }
+            for (owner, attribute_name, amount) in &absorbed {
+                if let Some(&attribute) = batch
+                    .attribute_entities
+                    .get(&(*owner, attribute_name.clone()))
+                    && let Ok((_, mut attr_data, ..)) = params.attributes.get_mut(attribute)
+                {
+                    attr_data.base_value -= amount;
+                    attr_data.current_value -= amount;
+                    changes.push((attribute_name.clone(), -amount));
                 }
             }
+            batch.absorbed.extend(absorbed);
 
             // Defense-in-depth: registration validates this, but skip tags anyway.
             // Instant effects have no persistent entity, so tags would never be removed.
@@ -610,16 +1155,86 @@ pub fn on_apply_gameplay_effect(
                 effect_id
             );
 
-            trigger_effect_cues(&mut commands, definition, GameplayCueEvent::Executed, spec);
+            batch.cues.extend(
+                effect_cue_events(
+                    definition,
+                    GameplayCueEvent::Executed,
+                    spec,
+                    None,
+                    &magnitudes,
+                )
+                .into_iter()
+                .map(|mut cue| {
+                    cue.parameters.critical |= critical;
+                    cue
+                }),
+            );
 
             // Use PLACEHOLDER since no entity is spawned for instant effects
             commands.trigger(GameplayEffectAppliedEvent {
                 effect: Entity::PLACEHOLDER,
+                handle: None,
+                target,
+                effect_id: effect_id.clone(),
+                prediction_key,
+            });
+            for conversion in &definition.conversions {
+                if let Some(application) = conversion.application(spec, &changes) {
+                    commands.trigger(application);
+                }
+            }
+            for resource_gain in &definition.resource_gains {
+                if let Some(application) =
+                    resource_gain.application(spec, attribute_snapshots, &mut batch.resource_gains)
+                {
+                    commands.trigger(application);
+                }
+            }
+            commands.trigger(GameplayEffectExecutedEvent {
+                effect: None,
                 target,
                 effect_id: effect_id.clone(),
+                context: spec.context.clone(),
+                changes: changes.clone(),
+                magnitudes,
+                pre_mitigation,
+                critical,
+                prediction_key,
             });
+            if let Ok(victim_effects) = params.active_effects.get(target) {
+                trigger_reflections(
+                    commands,
+                    &resources.registry,
+                    target,
+                    victim_effects
+                        .iter()
+                        .filter_map(|effect| params.existing_effects.get(effect).ok())
+                        .map(|(active_effect, ..)| active_effect),
+                    spec,
+                    &changes,
+                );
+            }
+            None
         }
         DurationPolicy::HasDuration | DurationPolicy::Infinite => {
+            if let Some(max) = resources.settings.max_active_effects_per_entity
+                && params
+                    .active_effects
+                    .get(target)
+                    .map_or(0, |effects| effects.len())
+                    >= max
+            {
+                debug!(
+                    "Effect '{}' dropped: target {:?} already has {} active effects",
+                    effect_id, target, max
+                );
+                commands.trigger(GameplayEffectRejectedEvent::new(
+                    spec,
+                    EffectRejectionReason::MaxActiveEffects,
+                ));
+                return None;
+            }
+
             // Spawn effect entity with components
             let source = spec.context.source.unwrap_or(target);
             let mut effect_entity_commands = commands.spawn((
@@ -628,7 +1243,7 @@ pub fn on_apply_gameplay_effect(
                     source,
                     target,
                     level,
-                    time.elapsed_secs(),
+                    resources.time.elapsed_secs(),
                 ),
                 EffectTarget(target),
                 EffectInstigator(spec.instigator()),
@@ -639,6 +1254,15 @@ pub fn on_apply_gameplay_effect(
                 effect_entity_commands.insert(spec.set_by_caller_magnitudes.clone());
             }
 
+            if !snapshotted.is_empty() {
+                effect_entity_commands.insert(EffectAttributeSnapshots(snapshotted));
+            }
+
+            // Stamp effects applied by a predicted activation.
+            if let Some(key) = prediction_key {
+                effect_entity_commands.insert(key);
+            }
+
             // Add duration component for HasDuration
             if definition.duration_policy == DurationPolicy::HasDuration {
                 effect_entity_commands.insert(EffectDuration::new(definition.duration_magnitude));
@@ -646,14 +1270,21 @@ pub fn on_apply_gameplay_effect(
 
             // Add periodic component if needed
             if definition.period > 0.0 {
-                effect_entity_commands.insert(PeriodicEffect::new(definition.period));
+                effect_entity_commands.insert(PeriodicEffect::aligned(
+                    definition.period,
+                    resources.settings.periodic_tick_alignment,
+                    resources.time.elapsed_secs(),
+                ));
             }
 
             // Add granted tags component
             if !definition.granted_tags.is_empty() {
-                effect_entity_commands.insert(EffectGrantedTags {
-                    tags: definition.granted_tags.clone(),
-                });
+                effect_entity_commands.insert((
+                    EffectGrantedTags {
+                        tags: definition.granted_tags.clone(),
+                    },
+                    EffectGrantedTagsApplied,
+                ));
             }
 
             let effect_entity = effect_entity_commands.id();
@@ -665,23 +1296,184 @@ pub fn on_apply_gameplay_effect(
                 target_tags.0.update_tag_container_count(
                     &definition.granted_tags,
                     1,
-                    &tags_manager,
-                    &mut commands,
+                    &resources.tags_manager,
+                    commands,
                     target,
                 );
             }
 
-            trigger_effect_cues(&mut commands, definition, GameplayCueEvent::OnActive, spec);
+            // Modifier entities are created by a later system, so evaluate the
+            // magnitudes here to hand the OnActive cues the same values.
+            let magnitudes = modifier_magnitudes(
+                definition,
+                &inputs,
+                &resources.custom_calculators,
+                &mut batch.shared_magnitudes,
+            );
 
-            commands.trigger(GameplayEffectAppliedEvent {
+            batch.cues.extend(effect_cue_events(
+                definition,
+                GameplayCueEvent::OnActive,
+                spec,
+                Some(effect_entity),
+                &magnitudes,
+            ));
+
+            GameplayEffectAppliedEvent {
                 effect: effect_entity,
+                handle: None,
                 target,
                 effect_id: effect_id.clone(),
-            });
+                prediction_key,
+            }
+            .trigger_with_handle(commands);
+            Some(effect_entity)
         }
     }
 }
 
+/// Runs the team, immunity, tag and custom requirement checks for `spec`.
+fn effect_application_allowed(
+    commands: &mut Commands,
+    spec: &GameplayEffectSpec,
+    definition: &GameplayEffectDefinition,
+    resources: &ApplyEffectResources,
+    params: &ApplyEffectParams,
+    attribute_snapshots: &[ApplicationAttributeSnapshot],
+) -> bool {
+    let target = spec.target;
+    let effect_id = &spec.effect_id;
+    let level = spec.level;
+
+    // Check the team policy against the source's team.
+    if !definition
+        .team_policy
+        .allows(spec.source_entity(), target, |entity| {
+            params.teams.get(entity).ok().copied()
+        })
+    {
+        debug!(
+            "Effect '{}' blocked by team policy {:?} on target {:?}",
+            effect_id, definition.team_policy, target
+        );
+        commands.trigger(GameplayEffectRejectedEvent::new(
+            spec,
+            EffectRejectionReason::TeamPolicy,
+        ));
+        return false;
+    }
+
+    // Check immunity: reject the effect if one of the target's immunity tags
+    // is among its immunity tags or matches one of its asset tags (a target
+    // immune to `Effect.Hostile` blocks `Effect.Hostile.Fire` effects).
+    if let Ok(target_immunity) = params.immunity_tags.get(target)
+        && let Some(immunity_tag) =
+            target_immunity
+                .0
+                .explicit_tags
+                .gameplay_tags
+                .iter()
+                .find(|tag| {
+                    definition.immunity_tags.has_tag_exact(tag)
+                        || definition.asset_tags.has_tag(tag)
+                })
+    {
+        // Target is immune to this effect
+        info!(
+            "Effect '{}' blocked by immunity tag '{:?}' on target {:?}",
+            effect_id, immunity_tag, target
+        );
+
+        // Trigger immunity event
+        commands.trigger(GameplayEffectBlockedByImmunityEvent {
+            effect_id: effect_id.clone(),
+            target,
+            instigator: spec.instigator(),
+            immunity_tag: immunity_tag.clone(),
+        });
+        commands.trigger(GameplayEffectRejectedEvent::new(
+            spec,
+            EffectRejectionReason::Immune,
+        ));
+
+        return false;
+    }
+
+    // Legacy check: if target has any of the effect's asset_tags in their owned tags, reject
+    // This is for backwards compatibility with the old immunity system
+    if let Ok(owner_tags) = params.tag_containers.get(target) {
+        for asset_tag in definition.asset_tags.gameplay_tags.iter() {
+            if owner_tags.0.explicit_tags.gameplay_tags.contains(asset_tag) {
+                // Target is immune to this effect
+                info!(
+                    "Effect '{}' blocked by asset tag immunity '{:?}' on target {:?}",
+                    effect_id, asset_tag, target
+                );
+                commands.trigger(GameplayEffectRejectedEvent::new(
+                    spec,
+                    EffectRejectionReason::AssetTagImmunity,
+                ));
+                return false;
+            }
+        }
+    }
+
+    // Check application_tag_requirements
+    if let Ok(owner_tags) = params.tag_containers.get(target) {
+        // OwnedTags wraps GameplayTagCountContainer which has explicit_tags field
+        let tag_container = &owner_tags.0;
+        if !definition
+            .application_tag_requirements
+            .requirements_met(&tag_container.explicit_tags)
+        {
+            commands.trigger(GameplayEffectRejectedEvent::new(
+                spec,
+                EffectRejectionReason::TagRequirements,
+            ));
+            return false;
+        }
+    }
+
+    // Check custom application requirements.
+    let target_tags = params.tag_containers.get(target).ok();
+    let source_tags = spec
+        .source_entity()
+        .and_then(|source| params.tag_containers.get(source).ok());
+
+    for requirement_name in &definition.application_requirements {
+        let Some(requirement) = resources
+            .application_requirements
+            .try_get(requirement_name)
+            .or_report(commands)
+        else {
+            commands.trigger(GameplayEffectRejectedEvent::new(
+                spec,
+                EffectRejectionReason::ApplicationRequirement,
+            ));
+            return false;
+        };
+
+        let context = ApplicationContext {
+            source: spec.source_entity(),
+            target,
+            level,
+            target_tags: target_tags.as_ref().copied(),
+            source_tags: source_tags.as_ref().copied(),
+            attributes: attribute_snapshots,
+        };
+
+        if !requirement.can_apply(&context) {
+            commands.trigger(GameplayEffectRejectedEvent::new(
+                spec,
+                EffectRejectionReason::ApplicationRequirement,
+            ));
+            return false;
+        }
+    }
+
+    true
+}
+
 /// System that creates modifier entities for active effects.
 ///
 /// This system runs when:
@@ -702,27 +1494,37 @@ pub fn create_effect_modifiers_system(
             Option<&EffectInstigator>,
             Option<&SetByCallerMagnitudes>,
             Option<&GameplayEffectContext>,
+            Option<&EffectAttributeSnapshots>,
+            Option<&EffectModifiers>,
         ),
         (
             Or<(Added<ActiveGameplayEffect>, Changed<ActiveGameplayEffect>)>,
             Without<PeriodicEffect>,
         ),
     >,
-    existing_modifiers: Query<(Entity, &ModifierSource)>,
     attributes: Query<(&AttributeData, &AttributeName, &ChildOf)>,
 ) {
-    for (effect_entity, active_effect, target, instigator, set_by_caller, context) in
-        new_or_changed_effects.iter()
+    let _span = info_span!("gas::create_effect_modifiers").entered();
+    for (
+        effect_entity,
+        active_effect,
+        target,
+        instigator,
+        set_by_caller,
+        context,
+        snapshots,
+        existing,
+    ) in new_or_changed_effects.iter()
     {
-        let Some(definition) = registry.get(&active_effect.definition_id) else {
+        let Some(definition) = registry
+            .try_get(active_effect.definition_id.clone())
+            .or_report(&mut commands)
+        else {
             continue;
         };
 
         // Count existing modifiers for this effect
-        let existing_modifier_count = existing_modifiers
-            .iter()
-            .filter(|(_, source)| source.0 == effect_entity)
-            .count();
+        let existing_modifier_count = existing.map_or(0, |modifiers| modifiers.len());
 
         // Calculate how many modifier sets we need total
         let needed_modifier_sets = active_effect.stack_count as usize;
@@ -737,12 +1539,8 @@ pub fn create_effect_modifiers_system(
         // If we have too many (stack decreased), remove excess
         if existing_modifier_count > needed_total {
             let to_remove = existing_modifier_count - needed_total;
-            let mut removed = 0;
-            for (modifier_entity, source) in existing_modifiers.iter() {
-                if source.0 == effect_entity && removed < to_remove {
-                    commands.entity(modifier_entity).despawn();
-                    removed += 1;
-                }
+            for modifier_entity in existing.into_iter().flat_map(|m| m.iter()).take(to_remove) {
+                commands.entity(modifier_entity).despawn();
             }
             continue;
         }
@@ -759,17 +1557,34 @@ pub fn create_effect_modifiers_system(
         let source_entity = context
             .and_then(|context| context.source.or(context.instigator))
             .or_else(|| instigator.and_then(|instigator| instigator.0));
+        if existing_modifier_count == 0 {
+            report_missing_attributes(&mut commands, definition, target.0, &attribute_snapshots);
+        }
+        let mut snapshotted = snapshots
+            .map(|snapshots| snapshots.0.clone())
+            .unwrap_or_default();
+        let captured = CapturedAttributes::capture(
+            &definition.attribute_captures(&custom_calculators),
+            source_entity,
+            target.0,
+            &attribute_snapshots,
+            &mut snapshotted,
+        );
+        let inputs = MagnitudeInputs {
+            level: active_effect.level,
+            source: source_entity,
+            target: target.0,
+            set_by_caller,
+            attributes: &attribute_snapshots,
+            captured: &captured,
+        };
 
         for _ in 0..missing_stacks {
             for modifier_info in &definition.modifiers {
                 let magnitude = calculate_modifier_magnitude(
                     &modifier_info.magnitude,
-                    active_effect.level,
-                    source_entity,
-                    target.0,
-                    set_by_caller,
+                    &inputs,
                     &custom_calculators,
-                    &attribute_snapshots,
                 );
 
                 commands.spawn((
@@ -787,39 +1602,90 @@ pub fn create_effect_modifiers_system(
     }
 }
 
+/// System that marks attributes [`AttributeDirty`] when modifiers targeting
+/// them were mutated in place, or every attribute when the lifecycle hooks
+/// changed.
+///
+/// Runs right before [`aggregate_attribute_modifiers_system`]. Added and
+/// removed modifiers are handled by the [`ModifierIndex`] observers.
+pub fn mark_dirty_attributes_system(
+    mut commands: Commands,
+    changed_modifiers: Query<(Entity, Ref<AttributeModifier>), Changed<AttributeModifier>>,
+    attributes: Query<Entity, With<AttributeName>>,
+    lookup: AttributeLookup,
+    mut index: ResMut<ModifierIndex>,
+    hooks: Option<Res<AttributeLifecycleHooks>>,
+) {
+    // New hooks may clamp differently, so re-evaluate everything when they change
+    if hooks.is_some_and(|hooks| hooks.is_changed()) {
+        for attribute in &attributes {
+            commands.entity(attribute).insert(AttributeDirty);
+        }
+    }
+
+    // Modifiers mutated in place may have a new magnitude or target
+    for (entity, modifier) in changed_modifiers.iter() {
+        if modifier.is_added() {
+            continue;
+        }
+        let key = AttributeKey::new(modifier.target_entity, modifier.target_attribute.clone());
+        let previous = index.insert(entity, key.clone());
+        for key in previous.iter().chain([&key]) {
+            if let Some(attribute) = lookup.find(key) {
+                commands.entity(attribute).insert(AttributeDirty);
+            }
+        }
+    }
+}
+
 /// System that aggregates attribute modifiers and applies them to attributes.
 ///
 /// Modifiers are evaluated in channel order (Channel0 → Channel1 → ... → Channel9).
 /// Within each channel, modifiers are applied in operation priority order.
 /// The output of one channel becomes the input to the next channel.
 ///
-/// This optimized version uses batch aggregation to reduce iterations and improve cache locality.
+/// Only attributes marked [`AttributeDirty`] or whose `AttributeData` changed
+/// are re-evaluated, so attributes at rest are never written. They are
+/// evaluated in parallel, each from its own slice of the [`ModifierIndex`].
 pub fn aggregate_attribute_modifiers_system(
-    mut attributes: Query<(
-        Entity,
-        &mut AttributeData,
-        &AttributeName,
-        &ChildOf,
-        &AttributeSetId,
-    )>,
+    mut commands: Commands,
+    mut attributes: Query<
+        (
+            Entity,
+            &mut AttributeData,
+            &AttributeName,
+            &ChildOf,
+            Option<&AttributeSetId>,
+        ),
+        Or<(With<AttributeDirty>, Changed<AttributeData>)>,
+    >,
+    marked: Query<Entity, With<AttributeDirty>>,
     modifiers: Query<&AttributeModifier>,
+    index: Res<ModifierIndex>,
     hooks: Option<Res<AttributeLifecycleHooks>>,
 ) {
-    use super::batch_aggregation::ModifierAggregator;
+    let _span = info_span!("gas::aggregate_attribute_modifiers").entered();
 
-    // Build aggregator by collecting all modifiers
-    let mut aggregator = ModifierAggregator::new();
-    for modifier in modifiers.iter() {
-        aggregator.add_modifier(modifier);
+    for attribute in &marked {
+        commands.entity(attribute).try_remove::<AttributeDirty>();
     }
 
-    // Process each attribute using the pre-aggregated batches
-    for (attr_entity, mut attr_data, attr_name, child_of, set_id) in attributes.iter_mut() {
-        let owner = child_of.get();
-
-        // Get the batch for this attribute (if any)
-        if let Some(batch) = aggregator.get_batch(owner, &attr_name.0) {
-            // Evaluate the batch starting from base value
+    let index = &*index;
+    let hooks = hooks.as_deref();
+    attributes.par_iter_mut().for_each(
+        |(attr_entity, mut attr_data, attr_name, child_of, set_id)| {
+            let owner = child_of.get();
+
+            // Evaluate the attribute's modifiers starting from base value. With
+            // no modifiers this resets the current value to the base value.
+            let mut batch = ModifierBatch::new();
+            for modifier in index
+                .modifiers(owner, &attr_name.0)
+                .iter()
+                .filter_map(|modifier| modifiers.get(*modifier).ok())
+            {
+                batch.add_modifier(modifier.channel, modifier.operation, modifier.magnitude);
+            }
             let new_value = batch.evaluate(attr_data.base_value);
 
             // Apply the final value with hooks
@@ -833,50 +1699,22 @@ pub fn aggregate_attribute_modifiers_system(
                     new_value,
                     source_effect: None,
                 };
+                let set_hooks = hooks
+                    .zip(set_id)
+                    .and_then(|(hooks, set_id)| hooks.get(set_id.0));
 
-                if let Some(hooks_res) = &hooks
-                    && let Some(set_hooks) = hooks_res.get(set_id.0)
-                {
+                if let Some(set_hooks) = set_hooks {
                     (set_hooks.pre_change)(&mut context);
                 }
 
                 attr_data.current_value = context.new_value;
 
-                if let Some(hooks_res) = &hooks
-                    && let Some(set_hooks) = hooks_res.get(set_id.0)
-                {
-                    (set_hooks.post_change)(&context);
-                }
-            }
-        } else {
-            // No modifiers for this attribute, reset to base value if needed
-            let old_value = attr_data.current_value;
-            if (attr_data.base_value - old_value).abs() > f32::EPSILON {
-                let mut context = AttributeModifyContext {
-                    owner,
-                    attribute: attr_entity,
-                    attribute_name: attr_name.0.clone(),
-                    old_value,
-                    new_value: attr_data.base_value,
-                    source_effect: None,
-                };
-
-                if let Some(hooks_res) = &hooks
-                    && let Some(set_hooks) = hooks_res.get(set_id.0)
-                {
-                    (set_hooks.pre_change)(&mut context);
-                }
-
-                attr_data.current_value = context.new_value;
-
-                if let Some(hooks_res) = &hooks
-                    && let Some(set_hooks) = hooks_res.get(set_id.0)
-                {
+                if let Some(set_hooks) = set_hooks {
                     (set_hooks.post_change)(&context);
                 }
             }
-        }
-    }
+        },
+    );
 }
 
 /// Evaluates modifiers within a single channel.
@@ -933,74 +1771,281 @@ fn evaluate_channel(input: f32, modifiers: &[&AttributeModifier]) -> f32 {
 }
 
 /// System that updates effect durations.
-pub fn update_effect_durations_system(mut effects: Query<&mut EffectDuration>, time: Res<Time>) {
-    for mut duration in effects.iter_mut() {
-        duration.tick(time.delta_secs());
+pub fn update_effect_durations_system(
+    mut effects: Query<(Entity, &mut EffectDuration, &ActiveGameplayEffect)>,
+    timers: EffectTimers,
+) {
+    let _span = info_span!("gas::update_effect_durations").entered();
+    for (effect_entity, mut duration, effect) in effects.iter_mut() {
+        duration.tick(timers.delta_secs(effect_entity, effect.target));
+    }
+}
+
+/// Frame delta for the duration and periodic timers of effects.
+///
+/// Applies the stasis rule of [`GasSettings::stasis_tag`] on top of
+/// [`GasTime`].
+#[derive(SystemParam)]
+pub struct EffectTimers<'w, 's> {
+    pub time: GasTime<'w, 's>,
+    pub settings: Res<'w, GasSettings>,
+    pub owners: Query<'w, 's, &'static OwnedTags>,
+    pub granted_tags: Query<'w, 's, &'static EffectGrantedTags>,
+}
+
+impl EffectTimers<'_, '_> {
+    /// Returns the delta for the timers of `effect` on `target`: the
+    /// [`GasTime::lod_delta_secs`] of the target, or `0.0` while it is in
+    /// stasis.
+    pub fn delta_secs(&self, effect: Entity, target: Entity) -> f32 {
+        if self.in_stasis(effect, target) {
+            0.0
+        } else {
+            self.time.lod_delta_secs(target)
+        }
+    }
+
+    /// Whether `target` has the stasis tag. Effects granting the tag keep
+    /// ticking, so the stasis runs out.
+    pub fn in_stasis(&self, effect: Entity, target: Entity) -> bool {
+        let Some(stasis_tag) = &self.settings.stasis_tag else {
+            return false;
+        };
+        self.owners
+            .get(target)
+            .is_ok_and(|tags| tags.0.explicit_tags.has_tag(stasis_tag))
+            && !self
+                .granted_tags
+                .get(effect)
+                .is_ok_and(|granted| granted.tags.has_tag(stasis_tag))
+    }
+}
+
+/// System that adds the granted tags of effects loaded from a scene to their
+/// targets.
+///
+/// Scenes store `OwnedTags` empty, so these tags are not yet counted.
+pub fn apply_loaded_effect_tags_system(
+    mut commands: Commands,
+    tags_manager: Res<GameplayTagsManager>,
+    effects: Query<(Entity, &EffectTarget, &EffectGrantedTags), Without<EffectGrantedTagsApplied>>,
+    mut tag_containers: Query<&mut OwnedTags>,
+) {
+    for (effect_entity, target, granted) in effects.iter() {
+        let Ok(mut target_tags) = tag_containers.get_mut(target.0) else {
+            continue;
+        };
+        target_tags.0.update_tag_container_count(
+            &granted.tags,
+            1,
+            &tags_manager,
+            &mut commands,
+            target.0,
+        );
+        commands
+            .entity(effect_entity)
+            .insert(EffectGrantedTagsApplied);
+    }
+}
+
+/// Resource queueing expired effects for removal.
+///
+/// [`remove_expired_effects_system`] queues effects as they expire and
+/// removes at most [`GasSettings::max_effect_removals_per_frame`] of them each
+/// frame, oldest first, so a burst of expiries is spread over several frames.
+#[derive(Resource, Debug, Default)]
+pub struct ExpiredEffectQueue {
+    queue: VecDeque<Entity>,
+    queued: HashSet<Entity>,
+}
+
+impl ExpiredEffectQueue {
+    /// Queues `effect`. Returns `false` if it was already queued.
+    pub fn push(&mut self, effect: Entity) -> bool {
+        let added = self.queued.insert(effect);
+        if added {
+            self.queue.push_back(effect);
+        }
+        added
+    }
+
+    /// Returns `true` if `effect` is waiting for removal.
+    pub fn contains(&self, effect: Entity) -> bool {
+        self.queued.contains(&effect)
+    }
+
+    /// Removes and returns up to `max` queued effects, oldest first.
+    pub fn pop_batch(&mut self, max: usize) -> Vec<Entity> {
+        let count = max.min(self.queue.len());
+        let batch: Vec<Entity> = self.queue.drain(..count).collect();
+        for effect in &batch {
+            self.queued.remove(effect);
+        }
+        batch
+    }
+
+    /// Number of queued effects.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if no effects are queued.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+/// Observer that queues effects for removal on [`RemoveGameplayEffectEvent`].
+pub fn on_remove_gameplay_effect(
+    trigger: On<RemoveGameplayEffectEvent>,
+    mut commands: Commands,
+    mut queue: ResMut<ExpiredEffectQueue>,
+    effects: Query<(), With<ActiveGameplayEffect>>,
+) {
+    let effect = trigger.event().effect;
+    if effects.contains(effect) && queue.push(effect) {
+        // Stops periodic executions until the queue reaches it
+        commands.entity(effect).try_insert(EffectRemovalPending);
     }
 }
 
 /// System that removes expired effects and cleans up granted tags.
+///
+/// Expired effects go through the [`ExpiredEffectQueue`], so at most
+/// [`GasSettings::max_effect_removals_per_frame`] are removed per frame. Tag
+/// removals are grouped by target, and the batch is despawned inside one
+/// [`GameplayCueManager`] batching window so its Removed cues, triggered by
+/// `on_active_effect_removed_trigger_cues`, fire together. Despawning an
+/// effect despawns its modifiers through [`EffectModifiers`].
 pub fn remove_expired_effects_system(
     mut commands: Commands,
-    registry: Res<GameplayEffectRegistry>,
     tags_manager: Res<GameplayTagsManager>,
+    settings: Res<GasSettings>,
+    mut queue: ResMut<ExpiredEffectQueue>,
+    durations: Query<(Entity, &EffectDuration)>,
     effects: Query<(
-        Entity,
-        &EffectDuration,
         &ActiveGameplayEffect,
         &EffectTarget,
         Option<&EffectGrantedTags>,
-        Option<&GameplayEffectContext>,
     )>,
-    modifiers: Query<(Entity, &ModifierSource)>,
     mut tag_containers: Query<&mut OwnedTags>,
 ) {
-    for (effect_entity, duration, active_effect, target, granted_tags, context) in effects.iter() {
-        if duration.is_expired() {
-            // Remove granted_tags from target's OwnedTags
-            if let Some(granted) = granted_tags
-                && let Ok(mut target_tags) = tag_containers.get_mut(target.0)
-            {
-                target_tags.0.update_tag_container_count(
-                    &granted.tags,
-                    -1,
-                    &tags_manager,
-                    &mut commands,
-                    target.0,
-                );
-            }
+    let _span = info_span!("gas::remove_expired_effects").entered();
+    let mut newly_expired = Vec::new();
+    for (effect_entity, duration) in durations.iter() {
+        if duration.is_expired() && queue.push(effect_entity) {
+            newly_expired.push(effect_entity);
+        }
+    }
+    if queue.is_empty() {
+        return;
+    }
 
-            // Remove all modifiers created by this effect
-            for (modifier_entity, source) in modifiers.iter() {
-                if source.0 == effect_entity {
-                    commands.entity(modifier_entity).despawn();
-                }
-            }
+    let budget = settings.max_effect_removals_per_frame.unwrap_or(usize::MAX);
+    let batch = queue.pop_batch(budget);
 
-            // Trigger removal event
-            commands.trigger(GameplayEffectRemovedEvent {
-                effect: effect_entity,
-                target: target.0,
-                effect_id: active_effect.definition_id.clone(),
+    // Group granted tags by target so each target's tags are updated once
+    let mut granted_by_target: Vec<(Entity, Vec<&GameplayTagContainer>)> = Vec::new();
+    let mut target_indices: HashMap<Entity, usize> = HashMap::new();
+    let mut removed = Vec::with_capacity(batch.len());
+    for effect_entity in batch {
+        // Effects despawned some other way while queued are skipped
+        let Ok((active_effect, target, granted_tags)) = effects.get(effect_entity) else {
+            continue;
+        };
+        if let Some(granted) = granted_tags {
+            let index = *target_indices.entry(target.0).or_insert_with(|| {
+                granted_by_target.push((target.0, Vec::new()));
+                granted_by_target.len() - 1
             });
-            if let Some(definition) = registry.get(&active_effect.definition_id) {
-                trigger_effect_cues_from_components(
-                    &mut commands,
-                    definition,
-                    GameplayCueEvent::Removed,
-                    &active_effect.definition_id,
-                    target.0,
-                    active_effect.level,
-                    context,
-                );
-            }
+            granted_by_target[index].1.push(&granted.tags);
+        }
 
-            // Remove the effect
-            commands.entity(effect_entity).despawn();
+        commands.trigger(GameplayEffectRemovedEvent {
+            effect: effect_entity,
+            target: target.0,
+            effect_id: active_effect.definition_id.clone(),
+        });
+        removed.push(effect_entity);
+    }
+
+    // Remove granted_tags from the targets' OwnedTags
+    for (target, granted) in granted_by_target {
+        let Ok(mut target_tags) = tag_containers.get_mut(target) else {
+            continue;
+        };
+        for tags in granted {
+            target_tags.0.update_tag_container_count(
+                tags,
+                -1,
+                &tags_manager,
+                &mut commands,
+                target,
+            );
+        }
+    }
+
+    if !removed.is_empty() {
+        commands.queue(move |world: &mut World| {
+            with_cue_batching(world, |world| {
+                for effect_entity in removed {
+                    if let Ok(entity) = world.get_entity_mut(effect_entity) {
+                        entity.despawn();
+                    }
+                }
+            });
+        });
+    }
+
+    // Effects left for later frames stop their periodic executions
+    for effect_entity in newly_expired {
+        if queue.contains(effect_entity) {
+            commands
+                .entity(effect_entity)
+                .try_insert(EffectRemovalPending);
         }
     }
 }
 
+/// Observer that triggers Removed cues when an active effect goes away.
+///
+/// Observing the component removal covers every path: expiry, despawning the
+/// effect entity directly, or despawning it together with its target. Cue
+/// actors and `while_active` cues started by the effect are released by the
+/// cue systems once they see the Removed event or the missing effect entity.
+pub fn on_active_effect_removed_trigger_cues(
+    ev: On<Remove, ActiveGameplayEffect>,
+    mut commands: Commands,
+    registry: Res<GameplayEffectRegistry>,
+    effects: Query<(
+        &ActiveGameplayEffect,
+        Option<&GameplayEffectContext>,
+        Option<&PredictionKey>,
+    )>,
+) {
+    let effect_entity = ev.event_target();
+    let Ok((active_effect, context, prediction_key)) = effects.get(effect_entity) else {
+        return;
+    };
+    let Some(definition) = registry.get(&active_effect.definition_id) else {
+        return;
+    };
+    // Instant effects only ever fire Executed cues.
+    if definition.duration_policy == DurationPolicy::Instant {
+        return;
+    }
+
+    let spec = effect_spec_from_components(definition, active_effect, context, prediction_key);
+    trigger_effect_cues(
+        &mut commands,
+        definition,
+        GameplayCueEvent::Removed,
+        &spec,
+        Some(effect_entity),
+        &[],
+    );
+}
+
 /// System that executes periodic effects.
 ///
 /// Periodic effects fire their modifiers at discrete intervals (e.g., poison that
@@ -1017,39 +2062,94 @@ pub fn remove_expired_effects_system(
 ///   as discrete events (e.g., "deal 10 damage now").
 pub fn execute_periodic_effects_system(
     mut commands: Commands,
-    mut effects: Query<(
-        &mut PeriodicEffect,
-        &ActiveGameplayEffect,
-        &EffectTarget,
-        Option<&EffectInstigator>,
-        Option<&GameplayEffectContext>,
-        Option<&SetByCallerMagnitudes>,
-    )>,
-    registry: Res<GameplayEffectRegistry>,
-    custom_calculators: Res<super::custom_calculation::CustomCalculationRegistry>,
+    mut effects: Query<
+        (
+            Entity,
+            &mut PeriodicEffect,
+            &ActiveGameplayEffect,
+            &EffectTarget,
+            Option<&EffectInstigator>,
+            Option<&GameplayEffectContext>,
+            Option<&SetByCallerMagnitudes>,
+            Option<&PredictionKey>,
+            Option<&EffectDuration>,
+            Option<&EffectAttributeSnapshots>,
+        ),
+        Without<EffectRemovalPending>,
+    >,
+    resources: ApplyEffectResources,
+    mut rng: Option<ResMut<GasRng>>,
     mut attributes: Query<(&mut AttributeData, &AttributeName, &ChildOf)>,
-    time: Res<Time>,
+    target_effects: TargetEffects,
+    timers: EffectTimers,
 ) {
+    let _span = info_span!("gas::execute_periodic_effects").entered();
+    let ApplyEffectResources {
+        registry,
+        custom_calculators,
+        damage,
+        time: app_time,
+        settings,
+        ..
+    } = resources;
+
+    // Tick every timer first, so frames without a due execution skip the
+    // snapshot and execution work. With global alignment that is every
+    // frame between beats.
+    let global = settings.periodic_tick_alignment == PeriodicTickAlignment::Global;
+    let mut due = Vec::new();
+    for (effect_entity, mut periodic, active_effect, .., duration, _) in effects.iter_mut() {
+        // An effect that expired during the delta only ran until it expired,
+        // which matters when a simulation LOD ticks it in large chunks
+        let mut delta = timers.delta_secs(effect_entity, active_effect.target);
+        if let Some(duration) = duration
+            && duration.remaining < 0.0
+        {
+            delta = (delta + duration.remaining).max(0.0);
+        }
+        let executions = periodic.tick(delta);
+        if executions == 0 {
+            continue;
+        }
+        if global {
+            periodic.align_to_global_beat(app_time.elapsed_secs());
+        }
+        due.push((effect_entity, executions));
+    }
+    if due.is_empty() {
+        return;
+    }
+
     let attribute_snapshots: Vec<_> = attributes
         .iter()
         .map(|(data, name, child_of)| ApplicationAttributeSnapshot::new(child_of.get(), name, data))
         .collect();
-
-    for (mut periodic, active_effect, target, instigator, context, set_by_caller) in
-        effects.iter_mut()
-    {
-        let executions = periodic.tick(time.delta_secs());
-
-        if executions == 0 {
+    let mut resource_gains = PendingResourceGains::default();
+    // Amounts absorbed by damage mitigations, which the snapshots don't show
+    let mut absorbed = Vec::new();
+
+    for (effect_entity, executions) in due {
+        let Ok((
+            _,
+            _,
+            active_effect,
+            target,
+            instigator,
+            context,
+            set_by_caller,
+            prediction_key,
+            _,
+            snapshots,
+        )) = effects.get(effect_entity)
+        else {
             continue;
-        }
+        };
 
         // Get the effect definition
-        let Some(definition) = registry.get(&active_effect.definition_id) else {
-            warn!(
-                "Periodic effect references unknown definition: {}",
-                active_effect.definition_id
-            );
+        let Some(definition) = registry
+            .try_get(active_effect.definition_id.clone())
+            .or_report(&mut commands)
+        else {
             continue;
         };
 
@@ -1057,35 +2157,85 @@ pub fn execute_periodic_effects_system(
             .and_then(|context| context.source.or(context.instigator))
             .or_else(|| instigator.and_then(|instigator| instigator.0));
 
-        if let Some(context) = context {
-            trigger_effect_cues_from_components(
+        let mut spec =
+            effect_spec_from_components(definition, active_effect, context, prediction_key);
+        spec.context.source = spec.context.source.or(source_entity);
+
+        let mut snapshotted = snapshots
+            .map(|snapshots| snapshots.0.clone())
+            .unwrap_or_default();
+        let captured = CapturedAttributes::capture(
+            &definition.attribute_captures(&custom_calculators),
+            source_entity,
+            target.0,
+            &attribute_snapshots,
+            &mut snapshotted,
+        );
+        let inputs = MagnitudeInputs {
+            level: active_effect.level,
+            source: source_entity,
+            target: target.0,
+            set_by_caller,
+            attributes: &attribute_snapshots,
+            captured: &captured,
+        };
+
+        // Apply modifiers for each execution
+        for execution in 0..executions {
+            let mut magnitudes: Vec<_> = definition
+                .modifiers
+                .iter()
+                .map(|modifier| {
+                    let magnitude = calculate_modifier_magnitude(
+                        &modifier.magnitude,
+                        &inputs,
+                        &custom_calculators,
+                    );
+                    (modifier.attribute_name.clone(), magnitude)
+                })
+                .collect();
+            let mut fallback_rng = GasRng::default();
+            let AppliedDamage {
+                pre_mitigation,
+                critical,
+                absorbed: absorbed_now,
+            } = damage.apply(
                 &mut commands,
-                definition,
-                GameplayCueEvent::Executed,
-                &active_effect.definition_id,
-                target.0,
-                active_effect.level,
-                Some(context),
+                definition.damage.as_ref(),
+                &definition.modifiers,
+                &mut magnitudes,
+                &DamageContext {
+                    source: source_entity,
+                    target: target.0,
+                    level: active_effect.level,
+                    attributes: &attribute_snapshots,
+                    absorbed: &absorbed,
+                },
+                rng.as_deref_mut().unwrap_or(&mut fallback_rng),
             );
-        }
 
-        // Apply modifiers for each execution
-        for _ in 0..executions {
-            for modifier in &definition.modifiers {
-                let magnitude = calculate_modifier_magnitude(
-                    &modifier.magnitude,
-                    active_effect.level,
-                    source_entity,
-                    target.0,
-                    set_by_caller,
-                    &custom_calculators,
-                    &attribute_snapshots,
-                );
+            // Cues fire once per frame, for the first execution
+            if execution == 0 && context.is_some() {
+                for mut cue in effect_cue_events(
+                    definition,
+                    GameplayCueEvent::Executed,
+                    &spec,
+                    Some(effect_entity),
+                    &magnitudes,
+                ) {
+                    cue.parameters.critical |= critical;
+                    commands.trigger(cue);
+                }
+            }
 
+            let mut changes = Vec::new();
+            for (modifier, &(_, magnitude)) in definition.modifiers.iter().zip(&magnitudes) {
                 // Find and modify the target attribute
                 for (mut attr_data, attr_name, child_of) in attributes.iter_mut() {
                     let owner = child_of.get();
                     if owner == target.0 && attr_name.0 == modifier.attribute_name {
+                        let (old_base, old_current) =
+                            (attr_data.base_value, attr_data.current_value);
                         match modifier.operation {
                             // AddBase permanently modifies the base value
                             ModifierOperation::AddBase => {
@@ -1103,9 +2253,57 @@ pub fn execute_periodic_effects_system(
                                 attr_data.current_value = magnitude;
                             }
                         }
+                        changes.push((
+                            modifier.attribute_name.clone(),
+                            attr_data.base_value - old_base + attr_data.current_value - old_current,
+                        ));
                     }
                 }
             }
+            for (owner, attribute_name, amount) in &absorbed_now {
+                if let Some((mut attr_data, ..)) =
+                    attributes.iter_mut().find(|(_, name, child_of)| {
+                        child_of.get() == *owner && name.0 == *attribute_name
+                    })
+                {
+                    attr_data.base_value -= amount;
+                    attr_data.current_value -= amount;
+                    changes.push((attribute_name.clone(), -amount));
+                }
+            }
+            absorbed.extend(absorbed_now);
+
+            for conversion in &definition.conversions {
+                if let Some(application) = conversion.application(&spec, &changes) {
+                    commands.trigger(application);
+                }
+            }
+            for resource_gain in &definition.resource_gains {
+                if let Some(application) =
+                    resource_gain.application(&spec, &attribute_snapshots, &mut resource_gains)
+                {
+                    commands.trigger(application);
+                }
+            }
+            trigger_reflections(
+                &mut commands,
+                &registry,
+                target.0,
+                target_effects.of(target.0),
+                &spec,
+                &changes,
+            );
+            commands.trigger(GameplayEffectExecutedEvent {
+                effect: Some(effect_entity),
+                target: target.0,
+                effect_id: definition.id.clone(),
+                context: spec.context.clone(),
+                changes,
+                magnitudes,
+                pre_mitigation,
+                critical,
+                prediction_key: spec.prediction_key,
+            });
         }
     }
 }
@@ -1116,6 +2314,7 @@ pub fn remove_instant_effects_system(
     registry: Res<GameplayEffectRegistry>,
     instant_effects: Query<(Entity, &ActiveGameplayEffect), Added<ActiveGameplayEffect>>,
 ) {
+    let _span = info_span!("gas::remove_instant_effects").entered();
     for (effect_entity, active_effect) in instant_effects.iter() {
         if let Some(definition) = registry.get(&active_effect.definition_id)
             && definition.duration_policy == DurationPolicy::Instant
@@ -1146,7 +2345,10 @@ mod tests {
         app.init_resource::<GameplayEffectRegistry>();
         app.init_resource::<ApplicationRequirementRegistry>();
         app.init_resource::<crate::effects::custom_calculation::CustomCalculationRegistry>();
+        app.init_resource::<crate::effects::damage::DamageCalculationRegistry>();
+        app.init_resource::<BatchedEvents<ApplyGameplayEffectEvent>>();
         app.init_resource::<Time>();
+        app.init_resource::<GasSettings>();
         app.add_observer(on_apply_gameplay_effect);
         app.update();
 
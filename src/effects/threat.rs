@@ -0,0 +1,312 @@
+//! Threat (aggro) tracking.
+//!
+//! [`GasThreatPlugin`] accumulates threat from [`GameplayEffectExecutedEvent`]s
+//! into a [`ThreatTable`] on each entity that has one:
+//!
+//! - Damage to the tracked attribute adds threat for the source on the
+//!   damaged entity's table.
+//! - Healing adds threat for the healer on every table that already lists the
+//!   healed entity.
+//! - Taunt effects put their source on top of the target's table.
+//!
+//! Each table keeps a current target. When another attacker overtakes it by
+//! [`ThreatSettings::switch_threshold`], the plugin updates the target and
+//! triggers a [`ThreatTargetChangedEvent`] for AI to act on.
+//!
+//! # Example
+//! ```ignore
+//! app.add_plugins(GasThreatPlugin)
+//!     .insert_resource(ThreatSettings::default().with_taunt_effect("taunt"));
+//!
+//! commands.spawn((Enemy, ThreatTable::default()));
+//!
+//! app.add_observer(|ev: On<ThreatTargetChangedEvent>, mut enemies: Query<&mut Enemy>| {
+//!     if let Ok(mut enemy) = enemies.get_mut(ev.entity) {
+//!         enemy.target = ev.target;
+//!     }
+//! });
+//! ```
+
+use super::systems::GameplayEffectExecutedEvent;
+use crate::core::{GasSystemSet, GasTickMode, configure_gas_system_sets};
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use string_cache::DefaultAtom as Atom;
+
+/// Plugin that tracks threat in [`ThreatTable`]s.
+///
+/// Not added by `GasPlugin`.
+pub struct GasThreatPlugin;
+
+impl Plugin for GasThreatPlugin {
+    fn build(&self, app: &mut App) {
+        let simulation_schedule = GasTickMode::of(app).simulation_schedule();
+        configure_gas_system_sets(app);
+        app.init_resource::<ThreatSettings>()
+            .add_observer(on_effect_executed_add_threat)
+            .add_systems(
+                simulation_schedule,
+                update_threat_targets_system.in_set(GasSystemSet::Cleanup),
+            );
+    }
+}
+
+/// How effect executions turn into threat.
+#[derive(Resource, Debug, Clone)]
+pub struct ThreatSettings {
+    /// Attribute whose losses count as damage and gains as healing.
+    pub attribute: Atom,
+    /// Threat per point of damage.
+    pub damage_coefficient: f32,
+    /// Threat per point of healing.
+    pub healing_coefficient: f32,
+    /// Extra multipliers for specific effects.
+    pub effect_coefficients: HashMap<Atom, f32>,
+    /// Effects that taunt the target.
+    pub taunt_effects: HashSet<Atom>,
+    /// How far an attacker must exceed the current target's threat to
+    /// take over, as a multiplier.
+    pub switch_threshold: f32,
+}
+
+impl Default for ThreatSettings {
+    fn default() -> Self {
+        Self {
+            attribute: Atom::from("Health"),
+            damage_coefficient: 1.0,
+            healing_coefficient: 0.5,
+            effect_coefficients: HashMap::new(),
+            taunt_effects: HashSet::new(),
+            switch_threshold: 1.1,
+        }
+    }
+}
+
+impl ThreatSettings {
+    /// Sets the attribute damage and healing are read from.
+    pub fn with_attribute(mut self, attribute: impl Into<Atom>) -> Self {
+        self.attribute = attribute.into();
+        self
+    }
+
+    /// Sets the threat per point of damage.
+    pub fn with_damage_coefficient(mut self, coefficient: f32) -> Self {
+        self.damage_coefficient = coefficient;
+        self
+    }
+
+    /// Sets the threat per point of healing.
+    pub fn with_healing_coefficient(mut self, coefficient: f32) -> Self {
+        self.healing_coefficient = coefficient;
+        self
+    }
+
+    /// Multiplies the threat generated by `effect_id`.
+    pub fn with_effect_coefficient(mut self, effect_id: impl Into<Atom>, coefficient: f32) -> Self {
+        self.effect_coefficients
+            .insert(effect_id.into(), coefficient);
+        self
+    }
+
+    /// Makes `effect_id` taunt its target.
+    pub fn with_taunt_effect(mut self, effect_id: impl Into<Atom>) -> Self {
+        self.taunt_effects.insert(effect_id.into());
+        self
+    }
+
+    /// Sets the multiplier needed to take over the current target.
+    pub fn with_switch_threshold(mut self, threshold: f32) -> Self {
+        self.switch_threshold = threshold;
+        self
+    }
+}
+
+/// Threat held by one attacker.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThreatEntry {
+    /// The attacking entity.
+    pub attacker: Entity,
+    /// Accumulated threat.
+    pub threat: f32,
+}
+
+/// Threat each attacker holds on this entity.
+///
+/// Only entities with this component track threat.
+#[derive(Component, Debug, Clone, Default)]
+pub struct ThreatTable {
+    entries: Vec<ThreatEntry>,
+    target: Option<Entity>,
+}
+
+impl ThreatTable {
+    /// Returns the threat `attacker` holds.
+    pub fn threat(&self, attacker: Entity) -> f32 {
+        self.entry(attacker).map_or(0.0, |entry| entry.threat)
+    }
+
+    /// Returns whether `attacker` is on the table.
+    pub fn contains(&self, attacker: Entity) -> bool {
+        self.entry(attacker).is_some()
+    }
+
+    /// Adds threat for `attacker`.
+    pub fn add_threat(&mut self, attacker: Entity, amount: f32) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.attacker == attacker)
+        {
+            Some(entry) => entry.threat += amount,
+            None => self.entries.push(ThreatEntry {
+                attacker,
+                threat: amount,
+            }),
+        }
+    }
+
+    /// Removes `attacker` from the table.
+    pub fn remove(&mut self, attacker: Entity) {
+        self.entries.retain(|entry| entry.attacker != attacker);
+    }
+
+    /// Removes every attacker.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the entries, in the order attackers were added.
+    pub fn entries(&self) -> &[ThreatEntry] {
+        &self.entries
+    }
+
+    /// Returns the entry with the most threat.
+    pub fn highest(&self) -> Option<&ThreatEntry> {
+        self.entries
+            .iter()
+            .max_by(|a, b| a.threat.total_cmp(&b.threat))
+    }
+
+    /// Returns the suggested target.
+    pub fn target(&self) -> Option<Entity> {
+        self.target
+    }
+
+    fn entry(&self, attacker: Entity) -> Option<&ThreatEntry> {
+        self.entries.iter().find(|entry| entry.attacker == attacker)
+    }
+
+    /// Puts `attacker` at or above `threshold` times everyone else's threat
+    /// and makes it the target.
+    fn taunt(&mut self, attacker: Entity, threshold: f32) {
+        let highest_other = self
+            .entries
+            .iter()
+            .filter(|entry| entry.attacker != attacker)
+            .map(|entry| entry.threat)
+            .fold(0.0, f32::max);
+        let threat = self.threat(attacker).max(highest_other * threshold);
+        self.add_threat(attacker, threat - self.threat(attacker));
+        self.target = Some(attacker);
+    }
+
+    /// Picks the target, keeping the current one until it is overtaken by
+    /// `threshold`.
+    fn suggested_target(&self, threshold: f32) -> Option<Entity> {
+        let highest = self.highest()?;
+        match self.target.and_then(|target| self.entry(target)) {
+            Some(current) if highest.threat <= current.threat * threshold => Some(current.attacker),
+            _ => Some(highest.attacker),
+        }
+    }
+}
+
+/// Triggered when a [`ThreatTable`]'s suggested target changes.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThreatTargetChangedEvent {
+    /// The entity owning the table.
+    pub entity: Entity,
+    /// The previous target.
+    pub previous: Option<Entity>,
+    /// The new target, `None` once the table is empty.
+    pub target: Option<Entity>,
+}
+
+/// Observer adding threat for executed effects.
+fn on_effect_executed_add_threat(
+    ev: On<GameplayEffectExecutedEvent>,
+    mut commands: Commands,
+    settings: Res<ThreatSettings>,
+    mut tables: Query<(Entity, &mut ThreatTable)>,
+) {
+    let event = ev.event();
+    let Some(source) = event.source() else {
+        return;
+    };
+    let coefficient = settings
+        .effect_coefficients
+        .get(&event.effect_id)
+        .copied()
+        .unwrap_or(1.0);
+
+    if settings.taunt_effects.contains(&event.effect_id)
+        && let Ok((entity, mut table)) = tables.get_mut(event.target)
+    {
+        let previous = table.target;
+        table.taunt(source, settings.switch_threshold);
+        if previous != table.target {
+            commands.trigger(ThreatTargetChangedEvent {
+                entity,
+                previous,
+                target: table.target,
+            });
+        }
+    }
+
+    let delta = event.change(&settings.attribute);
+    if delta < 0.0 && source != event.target {
+        if let Ok((_, mut table)) = tables.get_mut(event.target) {
+            table.add_threat(source, -delta * settings.damage_coefficient * coefficient);
+        }
+    } else if delta > 0.0 {
+        let threat = delta * settings.healing_coefficient * coefficient;
+        for (entity, mut table) in &mut tables {
+            if entity != source && table.contains(event.target) {
+                table.add_threat(source, threat);
+            }
+        }
+    }
+}
+
+/// System dropping despawned attackers and updating suggested targets.
+pub fn update_threat_targets_system(
+    mut commands: Commands,
+    settings: Res<ThreatSettings>,
+    mut tables: Query<(Entity, &mut ThreatTable)>,
+    entities: Query<()>,
+) {
+    for (entity, mut table) in &mut tables {
+        if table
+            .entries
+            .iter()
+            .any(|entry| !entities.contains(entry.attacker))
+        {
+            table
+                .entries
+                .retain(|entry| entities.contains(entry.attacker));
+        } else if !table.is_changed() {
+            continue;
+        }
+
+        let target = table.suggested_target(settings.switch_threshold);
+        if target != table.target {
+            let previous = table.target;
+            table.target = target;
+            commands.trigger(ThreatTargetChangedEvent {
+                entity,
+                previous,
+                target,
+            });
+        }
+    }
+}
@@ -1,7 +1,15 @@
 //! Unified error types for the Gameplay Ability System.
 //!
 //! This module provides a centralized error handling system for all GAS operations.
+//!
+//! Registries and helpers return [`GasResult`]. Systems that can't return an
+//! error (a missing definition, an unknown attribute name) [`report`] it
+//! instead, and the [`GasErrorPolicy`] resource decides whether it is logged,
+//! sent as a [`GasErrorEvent`] or turned into a panic.
+//!
+//! [`report`]: GasError::report
 
+use crate::core::GasTickMode;
 use bevy::prelude::*;
 use std::fmt;
 use string_cache::DefaultAtom as Atom;
@@ -66,6 +74,12 @@ pub enum GasError {
         requirement_name: Atom,
     },
 
+    /// Damage mitigation not found in registry.
+    DamageMitigationNotFound {
+        /// The name of the missing mitigation.
+        mitigation_name: Atom,
+    },
+
     /// Effect application was blocked by requirements.
     EffectApplicationBlocked {
         /// The effect that was blocked.
@@ -81,6 +95,14 @@ pub enum GasError {
         /// Reason for blocking.
         reason: String,
     },
+
+    /// The `GasTickMode` resource was replaced after `GasPlugin` was built.
+    TickModeChanged {
+        /// The mode that was inserted.
+        inserted: GasTickMode,
+        /// The mode GAS systems were scheduled with.
+        built: GasTickMode,
+    },
 }
 
 impl fmt::Display for GasError {
@@ -126,18 +148,82 @@ impl fmt::Display for GasError {
                 "Application requirement '{}' not found in registry",
                 requirement_name
             ),
+            GasError::DamageMitigationNotFound { mitigation_name } => write!(
+                f,
+                "Damage mitigation '{}' not found in registry",
+                mitigation_name
+            ),
             GasError::EffectApplicationBlocked { effect_id, reason } => {
                 write!(f, "Effect '{}' blocked: {}", effect_id, reason)
             }
             GasError::AbilityActivationBlocked { ability_id, reason } => {
                 write!(f, "Ability '{}' blocked: {}", ability_id, reason)
             }
+            GasError::TickModeChanged { inserted, built } => write!(
+                f,
+                "GasTickMode::{:?} was inserted after GasPlugin was built and is ignored; GAS \
+                 keeps ticking with GasTickMode::{:?}. Use GasPlugin::builder().with_tick_mode(..) \
+                 instead",
+                inserted, built
+            ),
         }
     }
 }
 
 impl std::error::Error for GasError {}
 
+impl GasError {
+    /// Surfaces the error according to the [`GasErrorPolicy`] resource once
+    /// `commands` are applied.
+    pub fn report(self, commands: &mut Commands) {
+        commands.queue(move |world: &mut World| report_gas_error(world, self));
+    }
+}
+
+/// How reported [`GasError`]s surface.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GasErrorPolicy {
+    /// Log a warning. The default.
+    Warn,
+    /// Log a warning and trigger a [`GasErrorEvent`].
+    Event,
+    /// Panic, so data bugs fail tests. The default with the `strict` feature.
+    Panic,
+}
+
+impl Default for GasErrorPolicy {
+    fn default() -> Self {
+        if cfg!(feature = "strict") {
+            Self::Panic
+        } else {
+            Self::Warn
+        }
+    }
+}
+
+/// Event triggered for each reported error under [`GasErrorPolicy::Event`].
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct GasErrorEvent {
+    /// The reported error.
+    pub error: GasError,
+}
+
+/// Surfaces `error` according to the world's [`GasErrorPolicy`].
+pub fn report_gas_error(world: &mut World, error: GasError) {
+    match world
+        .get_resource::<GasErrorPolicy>()
+        .copied()
+        .unwrap_or_default()
+    {
+        GasErrorPolicy::Warn => warn!("{error}"),
+        GasErrorPolicy::Event => {
+            warn!("{error}");
+            world.trigger(GasErrorEvent { error });
+        }
+        GasErrorPolicy::Panic => panic!("{error}"),
+    }
+}
+
 /// Extension trait for converting Options to GasResults.
 pub trait GasResultExt<T> {
     /// Converts an Option to a Result with a GasError.
@@ -150,6 +236,21 @@ impl<T> GasResultExt<T> for Option<T> {
     }
 }
 
+/// Extension trait for reporting the error of a [`GasResult`].
+pub trait GasReportExt<T> {
+    /// Reports the error, if any, and returns the value as an Option.
+    ///
+    /// Lets systems keep their `let ... else` early returns while the error
+    /// still surfaces.
+    fn or_report(self, commands: &mut Commands) -> Option<T>;
+}
+
+impl<T> GasReportExt<T> for GasResult<T> {
+    fn or_report(self, commands: &mut Commands) -> Option<T> {
+        self.map_err(|error| error.report(commands)).ok()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,6 +286,39 @@ mod tests {
         assert!(display.contains("stunned"));
     }
 
+    #[test]
+    fn test_event_policy_triggers_error_event() {
+        #[derive(Resource, Default)]
+        struct Reported(Vec<GasError>);
+
+        let mut world = World::new();
+        world.init_resource::<Reported>();
+        world.insert_resource(GasErrorPolicy::Event);
+        world.add_observer(|ev: On<GasErrorEvent>, mut reported: ResMut<Reported>| {
+            reported.0.push(ev.error.clone());
+        });
+
+        let error = GasError::EffectDefinitionNotFound {
+            effect_id: Atom::from("typo"),
+        };
+        error.clone().report(&mut world.commands());
+        world.flush();
+        assert_eq!(world.resource::<Reported>().0, vec![error]);
+    }
+
+    #[test]
+    #[should_panic(expected = "typo")]
+    fn test_panic_policy_panics() {
+        let mut world = World::new();
+        world.insert_resource(GasErrorPolicy::Panic);
+        report_gas_error(
+            &mut world,
+            GasError::AbilityDefinitionNotFound {
+                ability_id: Atom::from("typo"),
+            },
+        );
+    }
+
     #[test]
     fn test_gas_result_ext() {
         let some_value: Option<i32> = Some(42);
@@ -0,0 +1,374 @@
+//! Ready-to-display GAS state for HUDs.
+//!
+//! Insert a [`GasHud`] on an owner and add [`GasHudPlugin`]; the component is
+//! kept up to date with the owner's attributes, buffs and ability cooldowns,
+//! so UI code reads one component instead of following attribute, effect and
+//! ability events and rebuilding the state itself.
+//!
+//! The HUD is rebuilt when the owner's attributes, effects or abilities
+//! change, and the remaining times of buffs and cooldowns are refreshed every
+//! frame. Both run in `PostUpdate`, after the GAS systems.
+//!
+//! # Example
+//!
+//! ```ignore
+//! app.add_plugins(GasHudPlugin).insert_resource(
+//!     GasHudConfig::default().with_icon("burning", asset_server.load("icons/burning.png")),
+//! );
+//! commands.entity(player).insert(GasHud::default());
+//!
+//! fn update_health_bar(huds: Query<&GasHud, Changed<GasHud>>, mut bars: Query<&mut Node, With<HealthBar>>) {
+//!     for hud in &huds {
+//!         if let Some(percent) = hud.attribute("Health").and_then(|health| health.percent) {
+//!             bars.single_mut().unwrap().width = Val::Percent(percent * 100.0);
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::abilities::components::{AbilityActiveState, AbilityOwner, AbilitySpec, OwnedAbilities};
+use crate::abilities::definition::AbilityRegistry;
+use crate::attributes::components::AttributeData;
+use crate::effects::components::{ActiveGameplayEffect, EffectDuration};
+use crate::effects::systems::GameplayEffectRemovedEvent;
+use crate::utils::Gas;
+use bevy::prelude::*;
+use std::collections::HashMap;
+use string_cache::DefaultAtom as Atom;
+
+/// Plugin keeping [`GasHud`] components up to date.
+pub struct GasHudPlugin;
+
+impl Plugin for GasHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GasHudConfig>()
+            .add_observer(on_effect_removed_for_hud)
+            .add_systems(
+                PostUpdate,
+                (
+                    mark_changed_huds_system,
+                    rebuild_gas_huds_system,
+                    tick_gas_hud_timers_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Resource configuring what [`GasHud`]s show.
+#[derive(Resource, Debug, Clone)]
+pub struct GasHudConfig {
+    /// Attributes holding the maximum of another attribute, e.g.
+    /// `Health -> MaxHealth`.
+    pub max_attributes: HashMap<Atom, Atom>,
+    /// Icons of buffs, by effect ID.
+    pub icons: HashMap<Atom, Handle<Image>>,
+    /// Whether ability cooldown effects are also listed as buffs.
+    pub show_cooldowns_as_buffs: bool,
+}
+
+impl Default for GasHudConfig {
+    fn default() -> Self {
+        Self {
+            max_attributes: HashMap::from([
+                (Atom::from("Health"), Atom::from("MaxHealth")),
+                (Atom::from("Mana"), Atom::from("MaxMana")),
+            ]),
+            icons: HashMap::new(),
+            show_cooldowns_as_buffs: false,
+        }
+    }
+}
+
+impl GasHudConfig {
+    /// Reads the maximum of `attribute` from `max_attribute`.
+    pub fn with_max_attribute(
+        mut self,
+        attribute: impl Into<Atom>,
+        max_attribute: impl Into<Atom>,
+    ) -> Self {
+        self.max_attributes
+            .insert(attribute.into(), max_attribute.into());
+        self
+    }
+
+    /// Shows `icon` for buffs of `effect_id`.
+    pub fn with_icon(mut self, effect_id: impl Into<Atom>, icon: Handle<Image>) -> Self {
+        self.icons.insert(effect_id.into(), icon);
+        self
+    }
+}
+
+/// An attribute as shown on a HUD.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HudAttribute {
+    /// The attribute name.
+    pub name: Atom,
+    /// The current value.
+    pub current: f32,
+    /// The maximum, from the configured max attribute.
+    pub max: Option<f32>,
+    /// `current / max` in `0..=1`, when there is a maximum.
+    pub percent: Option<f32>,
+}
+
+/// An active effect as shown on a HUD.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HudBuff {
+    /// The active effect entity.
+    pub effect: Entity,
+    /// The effect definition ID.
+    pub effect_id: Atom,
+    /// The configured icon.
+    pub icon: Option<Handle<Image>>,
+    /// The stack count.
+    pub stacks: i32,
+    /// Seconds left, for effects with a duration.
+    pub remaining: Option<f32>,
+    /// Total duration, for effects with a duration.
+    pub duration: Option<f32>,
+}
+
+impl HudBuff {
+    /// Returns the fraction of the duration left in `0..=1`, or `None` for
+    /// infinite effects.
+    pub fn remaining_fraction(&self) -> Option<f32> {
+        fraction(self.remaining?, self.duration?)
+    }
+}
+
+/// A granted ability as shown on a HUD.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HudAbility {
+    /// The ability spec entity.
+    pub spec: Entity,
+    /// The ability definition ID.
+    pub ability_id: Atom,
+    /// The input the ability is bound to.
+    pub input_id: Option<i32>,
+    /// Whether an instance is running.
+    pub active: bool,
+    /// The running cooldown effect, if any.
+    pub cooldown_effect: Option<Entity>,
+    /// Seconds of cooldown left.
+    pub cooldown_remaining: f32,
+    /// Total cooldown duration.
+    pub cooldown_duration: f32,
+}
+
+impl HudAbility {
+    /// Returns whether the ability is cooling down.
+    pub fn on_cooldown(&self) -> bool {
+        self.cooldown_effect.is_some()
+    }
+
+    /// Returns cooldown progress in `0..=1`: `0` when the cooldown starts and
+    /// `1` when the ability is ready.
+    pub fn cooldown_progress(&self) -> f32 {
+        if !self.on_cooldown() {
+            return 1.0;
+        }
+        fraction(self.cooldown_remaining, self.cooldown_duration).map_or(0.0, |left| 1.0 - left)
+    }
+}
+
+/// Display state of a GAS owner, kept up to date by [`GasHudPlugin`].
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct GasHud {
+    /// Attributes, sorted by name.
+    pub attributes: Vec<HudAttribute>,
+    /// Active effects, oldest first.
+    pub buffs: Vec<HudBuff>,
+    /// Granted abilities, sorted by input ID then ability ID.
+    pub abilities: Vec<HudAbility>,
+}
+
+impl GasHud {
+    /// Returns the attribute named `name`.
+    pub fn attribute(&self, name: &str) -> Option<&HudAttribute> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name.as_ref() == name)
+    }
+
+    /// Returns the ability with the definition ID `ability_id`.
+    pub fn ability(&self, ability_id: &str) -> Option<&HudAbility> {
+        self.abilities
+            .iter()
+            .find(|ability| ability.ability_id.as_ref() == ability_id)
+    }
+}
+
+/// Marks a [`GasHud`] owner whose HUD must be rebuilt.
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[component(storage = "SparseSet")]
+pub struct GasHudDirty;
+
+fn fraction(part: f32, total: f32) -> Option<f32> {
+    (total > 0.0).then(|| (part / total).clamp(0.0, 1.0))
+}
+
+fn on_effect_removed_for_hud(
+    ev: On<GameplayEffectRemovedEvent>,
+    mut commands: Commands,
+    huds: Query<(), With<GasHud>>,
+) {
+    let target = ev.event().target;
+    if huds.contains(target) {
+        commands.entity(target).try_insert(GasHudDirty);
+    }
+}
+
+/// Marks HUDs whose owner's attributes, effects or abilities changed.
+pub fn mark_changed_huds_system(
+    mut commands: Commands,
+    huds: Query<(Entity, Ref<GasHud>)>,
+    attributes: Query<&ChildOf, Changed<AttributeData>>,
+    effects: Query<&ActiveGameplayEffect, Changed<ActiveGameplayEffect>>,
+    specs: Query<&AbilityOwner, Changed<AbilitySpec>>,
+    active_states: Query<&AbilityOwner, Changed<AbilityActiveState>>,
+    owned_abilities: Query<Entity, Changed<OwnedAbilities>>,
+) {
+    let owners = attributes
+        .iter()
+        .map(ChildOf::parent)
+        .chain(effects.iter().map(|effect| effect.target))
+        .chain(specs.iter().chain(&active_states).map(|owner| owner.0))
+        .chain(owned_abilities.iter())
+        .chain(
+            huds.iter()
+                .filter(|(_, hud)| hud.is_added())
+                .map(|(owner, _)| owner),
+        );
+    for owner in owners {
+        if huds.contains(owner) {
+            commands.entity(owner).insert(GasHudDirty);
+        }
+    }
+}
+
+/// Rebuilds the HUDs marked [`GasHudDirty`].
+pub fn rebuild_gas_huds_system(
+    mut commands: Commands,
+    config: Res<GasHudConfig>,
+    registry: Res<AbilityRegistry>,
+    gas: Gas,
+    durations: Query<&EffectDuration>,
+    active_states: Query<&AbilityActiveState>,
+    mut huds: Query<(Entity, &mut GasHud), With<GasHudDirty>>,
+) {
+    for (owner, mut hud) in &mut huds {
+        commands.entity(owner).remove::<GasHudDirty>();
+
+        let mut attributes: Vec<HudAttribute> = gas
+            .attributes(owner)
+            .map(|(_, name, data)| {
+                let max = config
+                    .max_attributes
+                    .get(&name.0)
+                    .and_then(|max_attribute| gas.attribute_value(owner, max_attribute));
+                HudAttribute {
+                    name: name.0.clone(),
+                    current: data.current_value,
+                    max,
+                    percent: max.and_then(|max| fraction(data.current_value, max)),
+                }
+            })
+            .collect();
+        attributes.sort_by(|a, b| a.name.as_ref().cmp(b.name.as_ref()));
+
+        let cooldown_effects: Vec<&Atom> = gas
+            .abilities(owner)
+            .filter_map(|(_, spec)| registry.get(&spec.definition_id)?.cooldown_effect.as_ref())
+            .collect();
+        let mut buffs: Vec<(f32, HudBuff)> = gas
+            .active_effects(owner)
+            .filter(|(_, effect)| {
+                config.show_cooldowns_as_buffs || !cooldown_effects.contains(&&effect.definition_id)
+            })
+            .map(|(entity, effect)| {
+                let duration = durations.get(entity).ok();
+                let buff = HudBuff {
+                    effect: entity,
+                    effect_id: effect.definition_id.clone(),
+                    icon: config.icons.get(&effect.definition_id).cloned(),
+                    stacks: effect.stack_count,
+                    remaining: duration.map(|duration| duration.remaining),
+                    duration: duration.map(|duration| duration.total),
+                };
+                (effect.start_time, buff)
+            })
+            .collect();
+        buffs.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        let mut abilities: Vec<HudAbility> = gas
+            .abilities(owner)
+            .map(|(entity, spec)| {
+                let cooldown_effect = registry
+                    .get(&spec.definition_id)
+                    .and_then(|definition| definition.cooldown_effect.as_ref());
+                // The running cooldown with the most time left.
+                let cooldown = cooldown_effect.and_then(|cooldown_effect| {
+                    gas.active_effects(owner)
+                        .filter(|(_, effect)| &effect.definition_id == cooldown_effect)
+                        .map(|(effect, _)| (effect, durations.get(effect).ok().copied()))
+                        .max_by(|(_, a), (_, b)| {
+                            let remaining = |d: &Option<EffectDuration>| {
+                                d.map_or(f32::INFINITY, |d| d.remaining)
+                            };
+                            remaining(a).total_cmp(&remaining(b))
+                        })
+                });
+                let duration = cooldown.and_then(|(_, duration)| duration);
+                HudAbility {
+                    spec: entity,
+                    ability_id: spec.definition_id.clone(),
+                    input_id: spec.input_id,
+                    active: active_states.get(entity).is_ok_and(|state| state.is_active),
+                    cooldown_effect: cooldown.map(|(effect, _)| effect),
+                    cooldown_remaining: duration.map_or(0.0, |duration| duration.remaining),
+                    cooldown_duration: duration.map_or(0.0, |duration| duration.total),
+                }
+            })
+            .collect();
+        abilities.sort_by(|a, b| {
+            a.input_id
+                .cmp(&b.input_id)
+                .then_with(|| a.ability_id.as_ref().cmp(b.ability_id.as_ref()))
+        });
+
+        hud.set_if_neq(GasHud {
+            attributes,
+            buffs: buffs.into_iter().map(|(_, buff)| buff).collect(),
+            abilities,
+        });
+    }
+}
+
+/// Refreshes the remaining times of buffs and cooldowns.
+pub fn tick_gas_hud_timers_system(durations: Query<&EffectDuration>, mut huds: Query<&mut GasHud>) {
+    for mut hud in &mut huds {
+        let mut changed = false;
+        let display = hud.bypass_change_detection();
+        for buff in &mut display.buffs {
+            if let Ok(duration) = durations.get(buff.effect)
+                && buff.remaining != Some(duration.remaining)
+            {
+                buff.remaining = Some(duration.remaining);
+                changed = true;
+            }
+        }
+        for ability in &mut display.abilities {
+            if let Some(effect) = ability.cooldown_effect
+                && let Ok(duration) = durations.get(effect)
+                && ability.cooldown_remaining != duration.remaining
+            {
+                ability.cooldown_remaining = duration.remaining;
+                changed = true;
+            }
+        }
+        if changed {
+            hud.set_changed();
+        }
+    }
+}
@@ -43,44 +43,122 @@ pub mod abilities;
 pub mod attributes;
 pub mod core;
 pub mod cues;
+pub mod debug;
 pub mod effects;
 pub mod error;
+#[cfg(not(feature = "headless"))]
+pub mod hud;
+#[cfg(feature = "replicon")]
+pub mod replication;
+#[cfg(feature = "scripting")]
+pub mod scripting;
+#[cfg(feature = "serde")]
+pub mod serialization;
+#[cfg(feature = "test_utils")]
+pub mod test_utils;
 pub mod utils;
 
 /// Prelude module for convenient imports.
 pub mod prelude {
     pub use crate::attributes::components::*;
     pub use crate::attributes::plugin::AttributePlugin;
+    pub use crate::attributes::sync::AttributeSyncAppExt;
     pub use crate::attributes::traits::*;
 
+    pub use crate::effects::ability_granting::AbilityGrantedEvent;
+    pub use crate::effects::breakdown::{
+        AttributeBreakdown, AttributeBreakdowns, ModifierContribution,
+    };
+    pub use crate::effects::combat_log::{DamageDealtEvent, HealingDoneEvent};
     pub use crate::effects::components::*;
     pub use crate::effects::definition::*;
+    pub use crate::effects::dispel::{
+        CleanseEvent, CleansePriority, DispelCategory, EffectsCleansedEvent,
+    };
+    pub use crate::effects::immunity::{HOSTILE_EFFECT_TAG, INVULNERABLE_TAG, immunity_window};
     pub use crate::effects::plugin::EffectPlugin;
+    pub use crate::effects::status::{
+        GasStatusEffectsPlugin, StatusEffect, StatusEffectConfig, StatusEffectLibrary,
+    };
     pub use crate::effects::systems::{
-        ApplyGameplayEffectEvent, GameplayEffectAppliedEvent, GameplayEffectRemovedEvent,
+        ApplyGameplayEffectEvent, GameplayEffectAppliedEvent, GameplayEffectExecutedEvent,
+        GameplayEffectRemovedEvent, RemoveGameplayEffectEvent,
+    };
+    pub use crate::effects::threat::{
+        GasThreatPlugin, ThreatSettings, ThreatTable, ThreatTargetChangedEvent,
     };
 
+    #[cfg(feature = "ai")]
+    pub use crate::abilities::ai::*;
+    pub use crate::abilities::charge::{
+        AbilityCharge, AbilityChargeReleasedEvent, CHARGE_MAGNITUDE_TAG,
+    };
     pub use crate::abilities::components::*;
+    pub use crate::abilities::cooldown::{
+        AbilityCooldown, CooldownEndedEvent, CooldownEventSettings, CooldownStartedEvent,
+        CooldownUpdatedEvent,
+    };
+    pub use crate::abilities::cost_over_time::AbilityCostDepletedEvent;
     pub use crate::abilities::definition::*;
+    pub use crate::abilities::input::{
+        AbilityInputAppExt, AbilityInputEvent, AbilityInputPolicy, ButtonInputAdapter,
+    };
     pub use crate::abilities::plugin::AbilityPlugin;
+    pub use crate::abilities::spender::{
+        AbilitySpend, AbilitySpentEvent, SPENT_COUNT_TAG, SpendResource,
+    };
     pub use crate::abilities::systems::{
         AbilityActivatedEvent, AbilityActivationFailedEvent, ActivationFailureReason,
         CancelAbilityEvent, CommitAbilityEvent, CommitAbilityResultEvent, EndAbilityEvent,
         OnGameplayAbilityEnded, TryActivateAbilityEvent,
     };
+    pub use crate::abilities::tag_relationships::{
+        AbilityTagRelationship, AbilityTagRelationshipMapping,
+    };
+    pub use crate::abilities::usage::{AbilityUsage, AbilityUsageStats};
 
     pub use crate::cues::manager::*;
     pub use crate::cues::notify::*;
-    pub use crate::cues::plugin::CuePlugin;
-    pub use crate::cues::systems::TriggerGameplayCueEvent;
+    pub use crate::cues::plugin::{CuePlugin, GameplayCueAppExt};
+    pub use crate::cues::systems::{TriggerGameplayCueEvent, TriggerGameplayCueOnEntityEvent};
+    pub use crate::cues::translation::{GameplayCueTranslator, SurfaceCueTranslator};
 
+    pub use crate::core::cleanup::GasOwnerDespawnedEvent;
+    pub use crate::core::components::GasDisabled;
+    pub use crate::core::display::{DisplayInfo, format_number, format_template};
     pub use crate::core::events::*;
+    pub use crate::core::handles::{AbilityHandle, AttributeHandle, EffectHandle, GasHandle};
+    pub use crate::core::settings::{GasSettings, PeriodicTickAlignment};
     pub use crate::core::system_sets::*;
+    pub use crate::core::tag_changes::OwnerTagsChangedEvent;
+    pub use crate::core::timestep::{
+        GasSimulationLod, GasTickMode, GasTimeScale, GlobalGasTimeScale,
+    };
+    pub use crate::core::validation::{
+        GasValidationAssets, GasValidationMode, GasValidationReport,
+    };
+
+    #[cfg(feature = "console")]
+    pub use crate::debug::{GasConsoleCommandEvent, GasConsoleOutputEvent, GasConsolePlugin};
+    pub use crate::debug::{
+        GasDebug, GasDiagnosticsPlugin, GasEntityDebugInfo, GasStats, GasStatsPlugin, GasTimeline,
+        GasTimelinePlugin,
+    };
+    #[cfg(feature = "debug_overlay")]
+    pub use crate::debug::{GasDebugOverlay, GasDebugOverlayPlugin, OverlayBar};
+    #[cfg(feature = "egui")]
+    pub use crate::debug::{GasInspector, GasInspectorPlugin};
+    #[cfg(feature = "serde")]
+    pub use crate::debug::{GasStateChange, GasWorldState, dump_gas_state};
 
     pub use crate::error::*;
+    #[cfg(not(feature = "headless"))]
+    pub use crate::hud::{GasHud, GasHudConfig, GasHudPlugin, HudAbility, HudAttribute, HudBuff};
+    #[cfg(feature = "serde")]
+    pub use crate::serialization::{GasStateSnapshot, RestoreGasStateEvent};
     pub use crate::utils::*;
 
-    pub use crate::GasPlugin;
+    pub use crate::{GasPlugin, GasPluginBuilder};
 }
 
 use bevy::prelude::*;
@@ -103,11 +181,63 @@ use bevy::prelude::*;
 ///     .add_plugins(GasPlugin)
 ///     .run();
 /// ```
+///
+/// To run attributes, effects and abilities in `FixedUpdate` (cues stay in
+/// `Update`), add `GasPlugin::builder().in_fixed_update()` instead.
 pub struct GasPlugin;
 
+impl GasPlugin {
+    /// Returns a builder for configuring [`GasSettings`](core::GasSettings).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_gameplay_ability_system::prelude::*;
+    ///
+    /// App::new().add_plugins(
+    ///     GasPlugin::builder()
+    ///         .with_tick_mode(GasTickMode::Fixed)
+    ///         .with_max_active_effects_per_entity(32),
+    /// );
+    /// ```
+    pub fn builder() -> GasPluginBuilder {
+        GasPluginBuilder::default()
+    }
+}
+
 impl Plugin for GasPlugin {
     fn build(&self, app: &mut App) {
+        let mut settings = core::GasSettings::of(app);
+        app.insert_resource(settings.tick_mode);
+        let tick_schedule = settings.tick_mode.schedule();
         core::configure_gas_system_sets(app);
+        app.init_resource::<core::GasRng>()
+            .init_resource::<error::GasErrorPolicy>()
+            .register_type::<core::OwnedTags>();
+        let validation_mode = settings.validation_mode(app);
+        settings.strict_validation = validation_mode == core::GasValidationMode::Strict;
+        app.insert_resource(validation_mode)
+            .insert_resource(settings)
+            .init_resource::<core::GasValidationAssets>();
+        if validation_mode != core::GasValidationMode::Off {
+            app.add_systems(
+                PostUpdate,
+                core::validate_gas_registries_system
+                    .run_if(not(resource_exists::<core::GasValidationReport>)),
+            );
+        }
+        app.add_systems(
+            tick_schedule,
+            core::advance_simulation_lod_system.before(core::GasSystemSet::Attributes),
+        );
+        app.add_systems(
+            First,
+            core::check_gas_tick_mode_system.run_if(resource_changed::<core::GasTickMode>),
+        );
+        app.add_observer(core::on_gas_owner_despawned);
+        #[cfg(feature = "serde")]
+        app.add_observer(serialization::on_restore_gas_state);
 
         app.add_plugins(attributes::AttributePlugin)
             .add_plugins(effects::EffectPlugin)
@@ -115,3 +245,82 @@ impl Plugin for GasPlugin {
             .add_plugins(cues::CuePlugin);
     }
 }
+
+/// Adds [`GasPlugin`] with configured [`GasSettings`](core::GasSettings).
+///
+/// Created by [`GasPlugin::builder`].
+#[derive(Default)]
+pub struct GasPluginBuilder {
+    settings: core::GasSettings,
+}
+
+impl GasPluginBuilder {
+    /// Caps the active effect entities on one target.
+    pub fn with_max_active_effects_per_entity(mut self, max: usize) -> Self {
+        self.settings.max_active_effects_per_entity = Some(max);
+        self
+    }
+
+    /// Caps the expired effects removed per frame, spreading bursts of
+    /// expiries over several frames.
+    pub fn with_max_effect_removals_per_frame(mut self, max: usize) -> Self {
+        self.settings.max_effect_removals_per_frame = Some(max);
+        self
+    }
+
+    /// Sets the rate limit for cue tags without their own.
+    pub fn with_default_cue_rate_limit(mut self, limit: cues::CueRateLimit) -> Self {
+        self.settings.default_cue_rate_limit = Some(limit);
+        self
+    }
+
+    /// Sets how long actors of `Executed` cues live when their config sets
+    /// no lifetime.
+    pub fn with_executed_cue_actor_lifetime(mut self, seconds: f32) -> Self {
+        self.settings.executed_cue_actor_lifetime = seconds;
+        self
+    }
+
+    /// Sets when periodic effects first execute.
+    pub fn with_periodic_tick_alignment(mut self, alignment: core::PeriodicTickAlignment) -> Self {
+        self.settings.periodic_tick_alignment = alignment;
+        self
+    }
+
+    /// Stops the effect timers of targets with `tag`, e.g. `State.Stasis`.
+    pub fn with_stasis_tag(mut self, tag: bevy_gameplay_tag::GameplayTag) -> Self {
+        self.settings.stasis_tag = Some(tag);
+        self
+    }
+
+    /// Panics on startup validation problems.
+    pub fn with_strict_validation(mut self, strict: bool) -> Self {
+        self.settings.strict_validation = strict;
+        self
+    }
+
+    /// Sets where GAS timers tick.
+    pub fn with_tick_mode(mut self, tick_mode: core::GasTickMode) -> Self {
+        self.settings.tick_mode = tick_mode;
+        self
+    }
+
+    /// Runs the attribute, effect and ability systems in `FixedUpdate`, with
+    /// cues staying in `Update`
+    /// ([`GasTickMode::FixedSimulation`](core::GasTickMode::FixedSimulation)).
+    pub fn in_fixed_update(self) -> Self {
+        self.with_tick_mode(core::GasTickMode::FixedSimulation)
+    }
+
+    /// Returns the settings the plugin will insert.
+    pub fn settings(&self) -> &core::GasSettings {
+        &self.settings
+    }
+}
+
+impl Plugin for GasPluginBuilder {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.settings.clone())
+            .add_plugins(GasPlugin);
+    }
+}
@@ -0,0 +1,327 @@
+//! Multiplayer replication through `bevy_replicon`.
+//!
+//! Only compiled with the `replicon` feature. Add [`GasReplicationPlugin`]
+//! after `RepliconPlugins` and `GasPlugin` on both server and client.
+//!
+//! On the server, attribute entities, active effects and granted abilities are
+//! marked [`Replicated`] as they are created, so they follow their owner to
+//! clients. The owners themselves (characters) are game entities and must be
+//! marked `Replicated` by the game. Entity references inside replicated
+//! components (effect targets, ability owners, parents) are mapped to client
+//! entities by replicon, so an owner should reach clients before attributes,
+//! effects or abilities are created for it.
+//!
+//! On clients, replicated state is applied as-is: attribute values are not
+//! re-aggregated, effect durations are not ticked and expired effects are not
+//! removed, since the server owns all of that. Tags granted by replicated
+//! effects (including cooldown tags) are added to the target's [`OwnedTags`],
+//! so tag checks and UI work the same as on the server. Replicated owners need
+//! an `OwnedTags` on the client, e.g. as a required component of the game's
+//! character marker.
+//!
+//! The plugin also keeps [`NetRole`] in sync with the connection state and
+//! installs [`RepliconAbilityTransport`], which carries ability activation
+//! requests to the server and its answers back to the requesting client (see
+//! [`crate::abilities::transport`]). When a server effect stamped with a
+//! prediction key arrives, the client's predicted copy of it is discarded.
+//! Gameplay cues are multicast to relevant clients over an unreliable channel
+//! by [`RepliconCueChannel`] (see [`crate::cues::net`]).
+
+use crate::abilities::{
+    AbilityActivationRequestEvent, AbilityActivationResponseEvent, AbilityActiveState,
+    AbilityNetTransport, AbilityOwner, AbilitySpec, AbilityTransport, ClientActivationRequestEvent,
+    NetClientId, NetRole, PredictedEffectParams, PredictionKey,
+};
+use crate::attributes::{AttributeData, AttributeName};
+use crate::core::{GasSystemSet, OwnedTags, Team};
+use crate::cues::{CueChannel, GameplayCueChannel, GameplayCueMulticastEvent};
+use crate::effects::{
+    ActiveGameplayEffect, EffectDuration, EffectGrantedTags, EffectGrantedTagsApplied, EffectTarget,
+};
+use bevy::prelude::*;
+use bevy_gameplay_tag::GameplayTagsManager;
+use bevy_replicon::prelude::*;
+
+/// Plugin registering GAS replication rules and client-side systems.
+///
+/// # Example
+///
+/// ```ignore
+/// app.add_plugins((RepliconPlugins, MyMessagingPlugins))
+///     .add_plugins(GasPlugin)
+///     .add_plugins(GasReplicationPlugin);
+/// ```
+pub struct GasReplicationPlugin;
+
+impl Plugin for GasReplicationPlugin {
+    fn build(&self, app: &mut App) {
+        app.replicate::<ChildOf>()
+            .replicate::<Team>()
+            .replicate_once::<AttributeName>()
+            .replicate::<AttributeData>()
+            .replicate::<ActiveGameplayEffect>()
+            .replicate::<EffectDuration>()
+            .replicate_once::<EffectGrantedTags>()
+            .replicate::<AbilitySpec>()
+            .replicate_once::<AbilityOwner>()
+            .replicate::<AbilityActiveState>()
+            .replicate_once::<PredictionKey>()
+            .add_mapped_client_event::<AbilityActivationRequestEvent>(Channel::Ordered)
+            .add_mapped_server_event::<AbilityActivationResponseEvent>(Channel::Ordered)
+            .add_mapped_server_event::<GameplayCueMulticastEvent>(Channel::Unreliable)
+            .insert_resource(AbilityTransport::new(RepliconAbilityTransport))
+            .insert_resource(CueChannel::new(RepliconCueChannel))
+            .add_systems(
+                OnEnter(ClientState::Connected),
+                set_net_role(NetRole::Client),
+            )
+            .add_systems(
+                OnExit(ClientState::Connected),
+                set_net_role(NetRole::Standalone),
+            )
+            .add_systems(OnEnter(ServerState::Running), set_net_role(NetRole::Server))
+            .add_systems(
+                OnExit(ServerState::Running),
+                set_net_role(NetRole::Standalone),
+            )
+            // Server: mark GAS entities for replication.
+            .add_observer(replicate_attribute)
+            .add_observer(replicate_active_effect)
+            .add_observer(replicate_ability_spec)
+            // Client: rebuild derived effect state.
+            .add_observer(on_replicated_effect_added)
+            .add_observer(on_replicated_effect_tags_added)
+            .add_observer(on_replicated_effect_tags_removed)
+            .add_observer(on_predicted_effect_replicated)
+            // Activation requests.
+            .add_observer(on_activation_request_from_client)
+            // Server-only logic does not run while connected to a server.
+            .configure_sets(
+                Update,
+                (GasSystemSet::Attributes, GasSystemSet::Effects)
+                    .run_if(in_state(ClientState::Disconnected)),
+            )
+            .configure_sets(
+                FixedUpdate,
+                GasSystemSet::Effects.run_if(in_state(ClientState::Disconnected)),
+            );
+    }
+}
+
+/// Observer that marks new attribute entities for replication.
+pub fn replicate_attribute(
+    trigger: On<Add, AttributeData>,
+    mut commands: Commands,
+    remote: Query<(), With<Remote>>,
+) {
+    mark_replicated(&mut commands, &remote, trigger.entity);
+}
+
+/// Observer that marks new active effect entities for replication.
+pub fn replicate_active_effect(
+    trigger: On<Add, ActiveGameplayEffect>,
+    mut commands: Commands,
+    remote: Query<(), With<Remote>>,
+) {
+    mark_replicated(&mut commands, &remote, trigger.entity);
+}
+
+/// Observer that marks new granted ability entities for replication.
+pub fn replicate_ability_spec(
+    trigger: On<Add, AbilitySpec>,
+    mut commands: Commands,
+    remote: Query<(), With<Remote>>,
+) {
+    mark_replicated(&mut commands, &remote, trigger.entity);
+}
+
+/// Inserts [`Replicated`] unless the entity was itself received from a server.
+fn mark_replicated(commands: &mut Commands, remote: &Query<(), With<Remote>>, entity: Entity) {
+    if remote.contains(entity) {
+        return;
+    }
+    commands.entity(entity).try_insert(Replicated);
+}
+
+/// Observer that inserts [`EffectTarget`] on replicated effects.
+///
+/// The target is taken from `ActiveGameplayEffect` instead of being sent
+/// twice, so each replicated effect carries a single entity-mapped component.
+pub fn on_replicated_effect_added(
+    trigger: On<Add, ActiveGameplayEffect>,
+    mut commands: Commands,
+    effects: Query<&ActiveGameplayEffect, With<Remote>>,
+) {
+    if let Ok(effect) = effects.get(trigger.entity) {
+        commands
+            .entity(trigger.entity)
+            .try_insert(EffectTarget(effect.target));
+    }
+}
+
+/// Observer that adds a replicated effect's granted tags to its target.
+pub fn on_replicated_effect_tags_added(
+    trigger: On<Add, EffectGrantedTags>,
+    mut commands: Commands,
+    effects: Query<(&EffectGrantedTags, &ActiveGameplayEffect), With<Remote>>,
+    tag_containers: Query<&mut OwnedTags>,
+    tags_manager: Res<GameplayTagsManager>,
+) {
+    if !effects.contains(trigger.entity) {
+        return;
+    }
+    commands
+        .entity(trigger.entity)
+        .try_insert(EffectGrantedTagsApplied);
+    update_replicated_effect_tags(
+        trigger.entity,
+        1,
+        commands,
+        effects,
+        tag_containers,
+        &tags_manager,
+    );
+}
+
+/// Observer that removes a replicated effect's granted tags from its target.
+pub fn on_replicated_effect_tags_removed(
+    trigger: On<Remove, EffectGrantedTags>,
+    commands: Commands,
+    effects: Query<(&EffectGrantedTags, &ActiveGameplayEffect), With<Remote>>,
+    tag_containers: Query<&mut OwnedTags>,
+    tags_manager: Res<GameplayTagsManager>,
+) {
+    update_replicated_effect_tags(
+        trigger.entity,
+        -1,
+        commands,
+        effects,
+        tag_containers,
+        &tags_manager,
+    );
+}
+
+fn update_replicated_effect_tags(
+    effect: Entity,
+    count_delta: i32,
+    mut commands: Commands,
+    effects: Query<(&EffectGrantedTags, &ActiveGameplayEffect), With<Remote>>,
+    mut tag_containers: Query<&mut OwnedTags>,
+    tags_manager: &GameplayTagsManager,
+) {
+    let Ok((granted, active_effect)) = effects.get(effect) else {
+        return;
+    };
+    let target = active_effect.target;
+    let Ok(mut target_tags) = tag_containers.get_mut(target) else {
+        warn!("Replicated effect {effect} targets {target} which has no OwnedTags");
+        return;
+    };
+    target_tags.0.update_tag_container_count(
+        &granted.tags,
+        count_delta,
+        tags_manager,
+        &mut commands,
+        target,
+    );
+}
+
+/// Returns a system that sets the [`NetRole`].
+fn set_net_role(role: NetRole) -> impl FnMut(ResMut<NetRole>) {
+    move |mut net_role: ResMut<NetRole>| *net_role = role
+}
+
+/// Sends ability activation traffic as replicon events.
+pub struct RepliconAbilityTransport;
+
+impl RepliconAbilityTransport {
+    /// Encodes a replicon client as a [`NetClientId`].
+    pub fn client_id(client: ClientId) -> NetClientId {
+        match client {
+            ClientId::Client(entity) => NetClientId(entity.to_bits()),
+            ClientId::Server => NetClientId(u64::MAX),
+        }
+    }
+
+    /// Decodes a [`NetClientId`] made by [`Self::client_id`].
+    pub fn replicon_client(client: NetClientId) -> ClientId {
+        match client.0 {
+            u64::MAX => ClientId::Server,
+            bits => ClientId::Client(Entity::from_bits(bits)),
+        }
+    }
+}
+
+impl AbilityNetTransport for RepliconAbilityTransport {
+    fn send_request(&self, commands: &mut Commands, request: AbilityActivationRequestEvent) {
+        commands.client_trigger(request);
+    }
+
+    fn send_response(
+        &self,
+        commands: &mut Commands,
+        client: NetClientId,
+        response: AbilityActivationResponseEvent,
+    ) {
+        commands.server_trigger(ToClients {
+            targets: SendTargets::Single(Self::replicon_client(client)),
+            message: response,
+        });
+    }
+}
+
+/// Sends cue multicasts as unreliable replicon events.
+pub struct RepliconCueChannel;
+
+impl GameplayCueChannel for RepliconCueChannel {
+    fn multicast(
+        &self,
+        commands: &mut Commands,
+        clients: &[NetClientId],
+        cue: &GameplayCueMulticastEvent,
+    ) {
+        for &client in clients {
+            commands.server_trigger(ToClients {
+                targets: SendTargets::Single(RepliconAbilityTransport::replicon_client(client)),
+                message: cue.clone(),
+            });
+        }
+    }
+}
+
+/// Observer that hands activation requests from clients to GAS.
+pub fn on_activation_request_from_client(
+    ev: On<FromClient<AbilityActivationRequestEvent>>,
+    mut commands: Commands,
+) {
+    commands.trigger(ClientActivationRequestEvent {
+        client: RepliconAbilityTransport::client_id(ev.client_id),
+        request: ev.message.clone(),
+    });
+}
+
+/// Observer that replaces predicted effects with the server's copy.
+pub fn on_predicted_effect_replicated(
+    trigger: On<Add, PredictionKey>,
+    mut commands: Commands,
+    remote_effects: Query<(&PredictionKey, &ActiveGameplayEffect), With<Remote>>,
+    predicted_effects: Query<
+        (
+            Entity,
+            &PredictionKey,
+            &ActiveGameplayEffect,
+            Option<&EffectGrantedTags>,
+        ),
+        Without<Remote>,
+    >,
+    mut discard: PredictedEffectParams,
+) {
+    let Ok((key, remote_effect)) = remote_effects.get(trigger.entity) else {
+        return;
+    };
+    for (entity, predicted_key, effect, granted) in predicted_effects.iter() {
+        if predicted_key == key && effect.definition_id == remote_effect.definition_id {
+            discard.discard(&mut commands, entity, effect, granted);
+        }
+    }
+}
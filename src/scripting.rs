@@ -0,0 +1,684 @@
+//! Ability logic and custom magnitudes written as Rhai scripts.
+//!
+//! With the `scripting` feature, scripts registered in the [`GasScriptEngine`]
+//! can be used:
+//! - as an ability's behavior, by naming the script in
+//!   [`AbilityDefinition::script`](crate::abilities::AbilityDefinition::script).
+//!   The script's `activate(gas)` function runs when the ability activates,
+//!   and an optional `can_activate(gas)` can refuse activation;
+//! - as a custom magnitude, by registering a [`ScriptedMagnitude`] in the
+//!   `CustomCalculationRegistry`. Its `magnitude(gas)` function returns the
+//!   value.
+//!
+//! Scripts are sandboxed: they cannot touch the world directly, only the
+//! `gas` object, which offers:
+//! - `gas.source`, `gas.target`, `gas.level`: the entities involved, as
+//!   integers, and the ability or effect level;
+//! - `gas.attribute(entity, name)`: an attribute's current value (0.0 if the
+//!   entity has none);
+//! - `gas.has_tag(entity, tag)`: whether an entity has a tag or a child of it;
+//! - `gas.apply_effect(effect_id, entity)`, `gas.trigger_cue(tag, entity)`,
+//!   `gas.end_ability()`: actions carried out once the script returns.
+//!
+//! Only the source and target are visible. Scripts also run under operation
+//! and size limits, so a runaway script fails instead of hanging the game.
+//!
+//! With Bevy's `AssetPlugin`, [`ScriptingPlugin`] also loads `.rhai` files as
+//! [`GasScript`] assets, registering each under its file name without
+//! extension and recompiling it when the file changes.
+//!
+//! # Example
+//!
+//! ```ignore
+//! // assets/scripts/firebolt.rhai
+//! fn can_activate(gas) {
+//!     gas.attribute(gas.source, "Mana") >= 10.0
+//! }
+//!
+//! fn activate(gas) {
+//!     gas.apply_effect("burning", gas.target);
+//!     gas.trigger_cue("GameplayCue.Fire", gas.target);
+//!     gas.end_ability();
+//! }
+//!
+//! app.add_plugins(ScriptingPlugin::new().with_path("scripts/firebolt.rhai"));
+//! registry.register(AbilityDefinition::new("firebolt").with_script("firebolt"));
+//! ```
+
+use crate::abilities::{
+    AbilityActivationInfo, AbilityBehavior, AbilityOwner, ActivationCheckFailure,
+    ActivationCheckResult, DefaultAbilityBehavior, EndAbilityEvent,
+};
+use crate::attributes::{AttributeData, AttributeName};
+use crate::core::OwnedTags;
+use crate::cues::{GameplayCueEvent, GameplayCueParameters, TriggerGameplayCueEvent};
+use crate::effects::{ApplyGameplayEffectEvent, CalculationContext, CustomMagnitudeCalculation};
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::ecs::relationship::Relationship;
+use bevy::prelude::*;
+use bevy_gameplay_tag::{GameplayTagContainer, GameplayTagsManager, gameplay_tag::GameplayTag};
+use rhai::{AST, Dynamic, Engine, FLOAT, INT, Scope};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, RwLock};
+use string_cache::DefaultAtom as Atom;
+
+/// Error compiling or running a script.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptError {
+    /// No script is registered under this name.
+    NotFound(Atom),
+    /// The script does not compile.
+    Compile {
+        /// The script's name.
+        script: Atom,
+        /// The compiler's message.
+        message: String,
+    },
+    /// The script failed while running.
+    Runtime {
+        /// The script's name.
+        script: Atom,
+        /// The function that failed.
+        function: &'static str,
+        /// Rhai's message.
+        message: String,
+    },
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(script) => write!(f, "Script '{script}' is not registered"),
+            Self::Compile { script, message } => {
+                write!(f, "Script '{script}' does not compile: {message}")
+            }
+            Self::Runtime {
+                script,
+                function,
+                message,
+            } => write!(f, "Script '{script}' failed in {function}(): {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+/// An action a script asked for.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptAction {
+    /// Apply an effect to an entity.
+    ApplyEffect { effect_id: Atom, target: Entity },
+    /// Trigger a cue on an entity.
+    TriggerCue {
+        cue_tag: GameplayTag,
+        target: Entity,
+    },
+    /// End the running ability.
+    EndAbility,
+}
+
+/// The `gas` object scripts receive.
+#[derive(Debug, Clone)]
+pub struct ScriptApi {
+    source: Entity,
+    target: Entity,
+    level: INT,
+    attributes: Arc<HashMap<(Entity, Atom), f32>>,
+    tags: Arc<HashMap<Entity, GameplayTagContainer>>,
+    actions: Arc<Mutex<Vec<ScriptAction>>>,
+}
+
+impl ScriptApi {
+    /// Creates an API for `source` acting on `target`, seeing only the given
+    /// attributes and tags.
+    pub fn new(
+        source: Entity,
+        target: Entity,
+        level: i32,
+        attributes: HashMap<(Entity, Atom), f32>,
+        tags: HashMap<Entity, GameplayTagContainer>,
+    ) -> Self {
+        Self {
+            source,
+            target,
+            level: level as INT,
+            attributes: Arc::new(attributes),
+            tags: Arc::new(tags),
+            actions: Arc::default(),
+        }
+    }
+
+    /// Captures the attributes and tags of `source` and `target` from the world.
+    pub fn capture(world: &mut World, source: Entity, target: Entity, level: i32) -> Self {
+        let visible = |entity: Entity| entity == source || entity == target;
+        let mut attributes = world.query::<(&AttributeName, &AttributeData, &ChildOf)>();
+        let attributes = attributes
+            .iter(world)
+            .filter(|(_, _, child_of)| visible(child_of.get()))
+            .map(|(name, data, child_of)| ((child_of.get(), name.0.clone()), data.current_value))
+            .collect();
+        let mut tags = world.query::<(Entity, &OwnedTags)>();
+        let tags = tags
+            .iter(world)
+            .filter(|(entity, _)| visible(*entity))
+            .map(|(entity, owned)| (entity, owned.0.explicit_tags.clone()))
+            .collect();
+        Self::new(source, target, level, attributes, tags)
+    }
+
+    /// Takes the actions the script asked for.
+    pub fn take_actions(&self) -> Vec<ScriptAction> {
+        std::mem::take(&mut *self.actions.lock().unwrap())
+    }
+
+    fn attribute(&mut self, entity: INT, name: &str) -> FLOAT {
+        self.attributes
+            .get(&(entity_from_int(entity), Atom::from(name)))
+            .map_or(0.0, |value| *value as FLOAT)
+    }
+
+    fn has_tag(&mut self, entity: INT, tag: &str) -> bool {
+        self.tags
+            .get(&entity_from_int(entity))
+            .is_some_and(|tags| tags.has_tag(&GameplayTag::new(tag)))
+    }
+
+    fn push(&self, action: ScriptAction) {
+        self.actions.lock().unwrap().push(action);
+    }
+}
+
+fn entity_to_int(entity: Entity) -> INT {
+    entity.to_bits() as INT
+}
+
+fn entity_from_int(value: INT) -> Entity {
+    Entity::try_from_bits(value as u64).unwrap_or(Entity::PLACEHOLDER)
+}
+
+/// Creates an engine limited to the `gas` API.
+fn sandboxed_engine() -> Engine {
+    let mut engine = Engine::new();
+    engine
+        .set_max_operations(100_000)
+        .set_max_call_levels(32)
+        .set_max_expr_depths(64, 32)
+        .set_max_string_size(4096)
+        .set_max_array_size(1024)
+        .set_max_map_size(1024)
+        .disable_symbol("eval");
+    engine
+        .register_type_with_name::<ScriptApi>("Gas")
+        .register_get("source", |api: &mut ScriptApi| entity_to_int(api.source))
+        .register_get("target", |api: &mut ScriptApi| entity_to_int(api.target))
+        .register_get("level", |api: &mut ScriptApi| api.level)
+        .register_fn("attribute", ScriptApi::attribute)
+        .register_fn("has_tag", ScriptApi::has_tag)
+        .register_fn(
+            "apply_effect",
+            |api: &mut ScriptApi, effect_id: &str, target: INT| {
+                api.push(ScriptAction::ApplyEffect {
+                    effect_id: effect_id.into(),
+                    target: entity_from_int(target),
+                });
+            },
+        )
+        .register_fn(
+            "trigger_cue",
+            |api: &mut ScriptApi, cue_tag: &str, target: INT| {
+                api.push(ScriptAction::TriggerCue {
+                    cue_tag: GameplayTag::new(cue_tag),
+                    target: entity_from_int(target),
+                });
+            },
+        )
+        .register_fn("end_ability", |api: &mut ScriptApi| {
+            api.push(ScriptAction::EndAbility);
+        });
+    engine
+}
+
+/// Resource holding the scripting engine and compiled scripts.
+///
+/// Cloning shares the scripts, so a clone sees later registrations.
+#[derive(Resource, Clone)]
+pub struct GasScriptEngine {
+    engine: Arc<Engine>,
+    scripts: Arc<RwLock<HashMap<Atom, Arc<AST>>>>,
+}
+
+impl Default for GasScriptEngine {
+    fn default() -> Self {
+        Self {
+            engine: Arc::new(sandboxed_engine()),
+            scripts: Arc::default(),
+        }
+    }
+}
+
+impl GasScriptEngine {
+    /// Compiles a script and registers it under `name`, replacing any script
+    /// of that name.
+    pub fn register_script(&self, name: impl Into<Atom>, source: &str) -> Result<(), ScriptError> {
+        let name = name.into();
+        let ast = self
+            .engine
+            .compile(source)
+            .map_err(|error| ScriptError::Compile {
+                script: name.clone(),
+                message: error.to_string(),
+            })?;
+        self.scripts.write().unwrap().insert(name, Arc::new(ast));
+        Ok(())
+    }
+
+    /// Returns true if a script is registered under `name`.
+    pub fn contains(&self, name: &Atom) -> bool {
+        self.scripts.read().unwrap().contains_key(name)
+    }
+
+    /// Returns true if the script defines a function with this name.
+    pub fn has_function(&self, script: &Atom, function: &str) -> bool {
+        self.scripts
+            .read()
+            .unwrap()
+            .get(script)
+            .is_some_and(|ast| ast.iter_functions().any(|f| f.name == function))
+    }
+
+    /// Calls a function of a script with the `gas` object.
+    pub fn call(
+        &self,
+        script: &Atom,
+        function: &'static str,
+        api: &ScriptApi,
+    ) -> Result<Dynamic, ScriptError> {
+        let ast = self
+            .scripts
+            .read()
+            .unwrap()
+            .get(script)
+            .cloned()
+            .ok_or_else(|| ScriptError::NotFound(script.clone()))?;
+        self.engine
+            .call_fn::<Dynamic>(&mut Scope::new(), &ast, function, (api.clone(),))
+            .map_err(|error| ScriptError::Runtime {
+                script: script.clone(),
+                function,
+                message: error.to_string(),
+            })
+    }
+
+    /// Returns a custom magnitude calculator running `script`'s `magnitude`
+    /// function.
+    pub fn magnitude(&self, script: impl Into<Atom>) -> ScriptedMagnitude {
+        ScriptedMagnitude {
+            engine: self.clone(),
+            script: script.into(),
+        }
+    }
+}
+
+/// Queues carrying out a script's actions.
+fn apply_script_actions(
+    commands: &mut Commands,
+    actions: Vec<ScriptAction>,
+    source: Entity,
+    level: i32,
+    instance: Option<Entity>,
+    ability_spec: Entity,
+) {
+    for action in actions {
+        match action {
+            ScriptAction::ApplyEffect { effect_id, target } => {
+                commands.trigger(
+                    ApplyGameplayEffectEvent::new(effect_id, target)
+                        .with_source(source)
+                        .with_instigator(source)
+                        .with_level(level),
+                );
+            }
+            ScriptAction::TriggerCue { cue_tag, target } => {
+                commands.trigger(TriggerGameplayCueEvent::new(
+                    cue_tag,
+                    GameplayCueEvent::Executed,
+                    GameplayCueParameters::new()
+                        .with_target(target)
+                        .with_instigator(source),
+                ));
+            }
+            ScriptAction::EndAbility => {
+                commands.trigger(EndAbilityEvent {
+                    instance,
+                    ability_spec,
+                    owner: source,
+                });
+            }
+        }
+    }
+}
+
+/// Ability behavior running a script.
+///
+/// The default activation checks run first; the script's `can_activate`
+/// can then refuse. Costs, cooldowns and tags are handled as for any ability.
+#[derive(Debug, Clone)]
+pub struct ScriptedAbilityBehavior {
+    /// The script's name in the [`GasScriptEngine`].
+    pub script: Atom,
+}
+
+impl ScriptedAbilityBehavior {
+    /// Creates a behavior running `script`.
+    pub fn new(script: impl Into<Atom>) -> Self {
+        Self {
+            script: script.into(),
+        }
+    }
+}
+
+impl AbilityBehavior for ScriptedAbilityBehavior {
+    fn can_activate(
+        &self,
+        world: &World,
+        ability_entity: Entity,
+        source: Entity,
+        tags_manager: &Res<GameplayTagsManager>,
+    ) -> ActivationCheckResult {
+        DefaultAbilityBehavior.can_activate(world, ability_entity, source, tags_manager)?;
+        let Some(engine) = world.get_resource::<GasScriptEngine>() else {
+            return Err(ActivationCheckFailure::MissingComponents);
+        };
+        if !engine.has_function(&self.script, "can_activate") {
+            return Ok(());
+        }
+
+        // Only the source's own state is visible before targets are chosen.
+        let attributes = world
+            .try_query::<(&AttributeName, &AttributeData, &ChildOf)>()
+            .map(|mut query| {
+                query
+                    .iter(world)
+                    .filter(|(_, _, child_of)| child_of.get() == source)
+                    .map(|(name, data, _)| ((source, name.0.clone()), data.current_value))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let tags = world
+            .get::<OwnedTags>(source)
+            .map(|owned| HashMap::from([(source, owned.0.explicit_tags.clone())]))
+            .unwrap_or_default();
+        let api = ScriptApi::new(source, source, 1, attributes, tags);
+
+        match engine.call(&self.script, "can_activate", &api) {
+            Ok(result) if result.as_bool().unwrap_or(false) => Ok(()),
+            Ok(_) => Err(ActivationCheckFailure::RejectedByBehavior),
+            Err(error) => {
+                warn!("{error}");
+                Err(ActivationCheckFailure::RejectedByBehavior)
+            }
+        }
+    }
+
+    fn activate(
+        &self,
+        commands: &mut Commands,
+        instance_entity: Option<Entity>,
+        spec_entity: Entity,
+        activation_info: &AbilityActivationInfo,
+    ) {
+        let script = self.script.clone();
+        let source = activation_info.owner;
+        let target = activation_info
+            .target_data
+            .primary_target()
+            .unwrap_or(source);
+        let level = activation_info.level;
+        commands.queue(move |world: &mut World| {
+            let Some(engine) = world.get_resource::<GasScriptEngine>().cloned() else {
+                warn!("No GasScriptEngine, cannot run script '{script}'");
+                return;
+            };
+            if !engine.has_function(&script, "activate") {
+                debug!("Script '{script}' has no activate function");
+                return;
+            }
+            let api = ScriptApi::capture(world, source, target, level);
+            if let Err(error) = engine.call(&script, "activate", &api) {
+                warn!("{error}");
+            }
+
+            let owner = world
+                .get::<AbilityOwner>(spec_entity)
+                .map_or(source, |owner| owner.0);
+            apply_script_actions(
+                &mut world.commands(),
+                api.take_actions(),
+                owner,
+                level,
+                instance_entity,
+                spec_entity,
+            );
+            world.flush();
+        });
+    }
+}
+
+/// Custom magnitude calculator running a script's `magnitude` function.
+///
+/// Sees every attribute of the source and target. Actions the script asks for
+/// are ignored; a failing script yields 0.0.
+pub struct ScriptedMagnitude {
+    engine: GasScriptEngine,
+    script: Atom,
+}
+
+impl CustomMagnitudeCalculation for ScriptedMagnitude {
+    fn calculate(&self, ctx: &CalculationContext) -> f32 {
+        let source = ctx.source.unwrap_or(Entity::PLACEHOLDER);
+        let mut attributes = HashMap::new();
+        for (name, value) in &ctx.source_attributes {
+            attributes.insert((source, name.clone()), *value);
+        }
+        for (name, value) in &ctx.target_attributes {
+            attributes.insert((ctx.target, name.clone()), *value);
+        }
+        let api = ScriptApi::new(source, ctx.target, ctx.level, attributes, HashMap::new());
+
+        match self.engine.call(&self.script, "magnitude", &api) {
+            Ok(value) => value
+                .as_float()
+                .or_else(|_| value.as_int().map(|value| value as FLOAT))
+                .unwrap_or_else(|_| {
+                    warn!("Script '{}' magnitude() returned a non-number", self.script);
+                    0.0
+                }) as f32,
+            Err(error) => {
+                warn!("{error}");
+                0.0
+            }
+        }
+    }
+
+    fn captures_all_attributes(&self) -> bool {
+        true
+    }
+}
+
+/// Source of a script loaded from a `.rhai` file.
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct GasScript {
+    /// The script's name: the file name without extension.
+    pub name: Atom,
+    /// The script's source code.
+    pub source: String,
+}
+
+/// Loads [`GasScript`]s from `.rhai` files.
+#[derive(TypePath, Default)]
+pub struct GasScriptLoader;
+
+impl AssetLoader for GasScriptLoader {
+    type Asset = GasScript;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let source = String::from_utf8(bytes)
+            .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))?;
+        let name = load_context
+            .path()
+            .path()
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+            .into();
+        Ok(GasScript { name, source })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["rhai"]
+    }
+}
+
+/// Handles of the script files loaded by [`ScriptingPlugin`].
+#[derive(Resource, Debug, Default)]
+pub struct GasScriptHandles(pub Vec<Handle<GasScript>>);
+
+/// Plugin that sets up the [`GasScriptEngine`] and loads script files.
+///
+/// Script files need Bevy's `AssetPlugin`; without it, register scripts with
+/// [`GasScriptEngine::register_script`].
+#[derive(Default)]
+pub struct ScriptingPlugin {
+    /// Asset paths loaded at startup.
+    pub paths: Vec<String>,
+}
+
+impl ScriptingPlugin {
+    /// Creates a plugin that loads no files by itself.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a script file to load at startup.
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.paths.push(path.into());
+        self
+    }
+}
+
+impl Plugin for ScriptingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GasScriptEngine>();
+        if !app.is_plugin_added::<AssetPlugin>() {
+            return;
+        }
+
+        let paths = self.paths.clone();
+        app.init_asset::<GasScript>()
+            .init_asset_loader::<GasScriptLoader>()
+            .init_resource::<GasScriptHandles>()
+            .add_systems(
+                Startup,
+                move |asset_server: Res<AssetServer>, mut handles: ResMut<GasScriptHandles>| {
+                    handles
+                        .0
+                        .extend(paths.iter().map(|path| asset_server.load(path.clone())));
+                },
+            )
+            .add_systems(PreUpdate, compile_loaded_scripts_system);
+    }
+}
+
+/// System that compiles loaded and reloaded script files.
+///
+/// A script that fails to compile keeps its previous version.
+pub fn compile_loaded_scripts_system(
+    mut events: MessageReader<AssetEvent<GasScript>>,
+    scripts: Res<Assets<GasScript>>,
+    engine: Res<GasScriptEngine>,
+) {
+    for event in events.read() {
+        if let AssetEvent::Added { id } | AssetEvent::Modified { id } = *event
+            && let Some(script) = scripts.get(id)
+        {
+            match engine.register_script(script.name.clone(), &script.source) {
+                Ok(()) => debug!("Compiled script '{}'", script.name),
+                Err(error) => warn!("{error}"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_reads_attributes_and_queues_actions() {
+        let engine = GasScriptEngine::default();
+        engine
+            .register_script(
+                "test",
+                r#"
+                fn activate(gas) {
+                    if gas.attribute(gas.source, "Mana") > 5.0 {
+                        gas.apply_effect("burning", gas.target);
+                    }
+                    gas.end_ability();
+                }
+                "#,
+            )
+            .unwrap();
+        let source = Entity::from_raw_u32(1).unwrap();
+        let target = Entity::from_raw_u32(2).unwrap();
+        let api = ScriptApi::new(
+            source,
+            target,
+            1,
+            HashMap::from([((source, Atom::from("Mana")), 10.0)]),
+            HashMap::new(),
+        );
+
+        assert!(engine.call(&"test".into(), "activate", &api).is_ok());
+        assert_eq!(
+            api.take_actions(),
+            vec![
+                ScriptAction::ApplyEffect {
+                    effect_id: "burning".into(),
+                    target,
+                },
+                ScriptAction::EndAbility,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_runaway_script_is_stopped() {
+        let engine = GasScriptEngine::default();
+        engine
+            .register_script("spin", "fn activate(gas) { loop {} }")
+            .unwrap();
+        let api = ScriptApi::new(
+            Entity::PLACEHOLDER,
+            Entity::PLACEHOLDER,
+            1,
+            HashMap::new(),
+            HashMap::new(),
+        );
+        assert!(matches!(
+            engine.call(&"spin".into(), "activate", &api),
+            Err(ScriptError::Runtime { .. })
+        ));
+    }
+}
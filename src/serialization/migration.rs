@@ -0,0 +1,251 @@
+//! Versioning of definition files.
+//!
+//! Effect, ability and attribute set files may carry a top-level `version`
+//! field. Files without one are version 1. When a definition struct changes
+//! shape, a migration registered for the old version rewrites the file text
+//! into the next version's format before it is parsed, so data written for
+//! older crate versions keeps loading. Migrations chain: a version 1 file is
+//! run through the 1 → 2 migration, then 2 → 3, and so on.
+//!
+//! Effect and ability files holding a list of definitions put the version in
+//! an envelope: `(version: 2, definitions: [...])`. Single definitions and
+//! attribute set files add `version` next to their other fields.
+//!
+//! # Example
+//!
+//! ```ignore
+//! // `magnitude_scale` was renamed to `level_multiplier` in version 2.
+//! app.world()
+//!     .resource::<DefinitionMigrations>()
+//!     .register(DefinitionFormat::Effect, 1, |text| {
+//!         Ok(text.replace("magnitude_scale:", "level_multiplier:"))
+//!     });
+//! ```
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+/// Version of the definition file formats written by this crate version.
+pub const DEFINITION_FORMAT_VERSION: u32 = 1;
+
+/// A kind of definition file. Each kind is versioned on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DefinitionFormat {
+    /// `.effect.ron` and `.effect.json` files.
+    Effect,
+    /// `.ability.ron` files.
+    Ability,
+    /// `.attributes.ron` files.
+    AttributeSet,
+}
+
+/// Rewrites the text of a file from one version into the next.
+pub type DefinitionMigrationFn = Arc<dyn Fn(&str) -> Result<String, String> + Send + Sync>;
+
+/// Error migrating a definition file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DefinitionMigrationError {
+    /// The file is newer than any version this build can read.
+    UnsupportedVersion {
+        /// The file's version.
+        found: u32,
+        /// The newest version that can be read.
+        supported: u32,
+    },
+    /// No migration is registered out of a version older than the current one.
+    MissingMigration {
+        /// The version without a migration.
+        from: u32,
+    },
+    /// A migration rejected the file.
+    Failed {
+        /// The version being migrated from.
+        from: u32,
+        /// The migration's message.
+        message: String,
+    },
+}
+
+impl fmt::Display for DefinitionMigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion { found, supported } => write!(
+                f,
+                "File version {found} is newer than the supported version {supported}"
+            ),
+            Self::MissingMigration { from } => {
+                write!(f, "No migration registered from version {from}")
+            }
+            Self::Failed { from, message } => {
+                write!(f, "Migration from version {from} failed: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DefinitionMigrationError {}
+
+/// The `version` field of a file; everything else is ignored.
+#[derive(Deserialize)]
+struct VersionHeader {
+    #[serde(default = "first_version")]
+    version: u32,
+}
+
+fn first_version() -> u32 {
+    1
+}
+
+/// Resource holding the migrations applied when definition files load.
+///
+/// Clones share the same migrations, so the asset loaders see migrations
+/// registered after they were created.
+#[derive(Resource, Clone, Default)]
+pub struct DefinitionMigrations {
+    migrations: Arc<RwLock<HashMap<(DefinitionFormat, u32), DefinitionMigrationFn>>>,
+}
+
+impl DefinitionMigrations {
+    /// Creates an empty set of migrations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the migration from `from_version` to `from_version + 1`.
+    ///
+    /// Replaces any migration already registered for that version.
+    pub fn register(
+        &self,
+        format: DefinitionFormat,
+        from_version: u32,
+        migration: impl Fn(&str) -> Result<String, String> + Send + Sync + 'static,
+    ) {
+        self.migrations
+            .write()
+            .unwrap()
+            .insert((format, from_version), Arc::new(migration));
+    }
+
+    /// Returns the version files of `format` are migrated to: the crate's
+    /// format version, or one past the newest registered migration.
+    pub fn current_version(&self, format: DefinitionFormat) -> u32 {
+        self.migrations
+            .read()
+            .unwrap()
+            .keys()
+            .filter(|(migration_format, _)| *migration_format == format)
+            .map(|(_, from)| from + 1)
+            .fold(DEFINITION_FORMAT_VERSION, u32::max)
+    }
+
+    /// Returns the version of a file. JSON is read when `json` is set, RON
+    /// otherwise. Files without a `version` field, including top-level lists,
+    /// are version 1.
+    pub fn file_version(bytes: &[u8], json: bool) -> u32 {
+        let header: Option<VersionHeader> = if json {
+            #[cfg(feature = "effect_assets")]
+            {
+                serde_json::from_slice(bytes).ok()
+            }
+            #[cfg(not(feature = "effect_assets"))]
+            {
+                None
+            }
+        } else {
+            ron::de::from_bytes(bytes).ok()
+        };
+        header.map_or(1, |header| header.version)
+    }
+
+    /// Migrates a file to the current version of `format`.
+    ///
+    /// Returns the bytes unchanged when the file is already current.
+    pub fn migrate<'a>(
+        &self,
+        format: DefinitionFormat,
+        bytes: &'a [u8],
+        json: bool,
+    ) -> Result<Cow<'a, [u8]>, DefinitionMigrationError> {
+        let current = self.current_version(format);
+        let mut version = Self::file_version(bytes, json);
+        if version > current {
+            return Err(DefinitionMigrationError::UnsupportedVersion {
+                found: version,
+                supported: current,
+            });
+        }
+        if version == current {
+            return Ok(Cow::Borrowed(bytes));
+        }
+
+        let mut text = String::from_utf8_lossy(bytes).into_owned();
+        let migrations = self.migrations.read().unwrap();
+        while version < current {
+            let Some(migration) = migrations.get(&(format, version)) else {
+                return Err(DefinitionMigrationError::MissingMigration { from: version });
+            };
+            text = migration(&text).map_err(|message| DefinitionMigrationError::Failed {
+                from: version,
+                message,
+            })?;
+            version += 1;
+        }
+        Ok(Cow::Owned(text.into_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reads_file_version() {
+        assert_eq!(
+            DefinitionMigrations::file_version(br#"(version: 3, id: "a")"#, false),
+            3
+        );
+        assert_eq!(
+            DefinitionMigrations::file_version(br#"(id: "a")"#, false),
+            1
+        );
+        assert_eq!(
+            DefinitionMigrations::file_version(br#"[(id: "a")]"#, false),
+            1
+        );
+    }
+
+    #[test]
+    fn test_migrations_chain_to_current_version() {
+        let migrations = DefinitionMigrations::new();
+        migrations.register(DefinitionFormat::Ability, 1, |text| {
+            Ok(text.replace("old_name", "middle_name"))
+        });
+        migrations.register(DefinitionFormat::Ability, 2, |text| {
+            Ok(text.replace("middle_name", "new_name"))
+        });
+        assert_eq!(migrations.current_version(DefinitionFormat::Ability), 3);
+        assert_eq!(migrations.current_version(DefinitionFormat::Effect), 1);
+
+        let migrated = migrations
+            .migrate(DefinitionFormat::Ability, br#"(old_name: 1)"#, false)
+            .unwrap();
+        assert_eq!(&*migrated, b"(new_name: 1)");
+
+        let current = br#"(version: 3, new_name: 1)"#;
+        assert!(matches!(
+            migrations.migrate(DefinitionFormat::Ability, current, false),
+            Ok(Cow::Borrowed(_))
+        ));
+        assert_eq!(
+            migrations.migrate(DefinitionFormat::Ability, br#"(version: 4)"#, false),
+            Err(DefinitionMigrationError::UnsupportedVersion {
+                found: 4,
+                supported: 3
+            })
+        );
+    }
+}
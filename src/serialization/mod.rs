@@ -0,0 +1,198 @@
+//! Serde support: helpers for types from `bevy_gameplay_tag`, save/load of an
+//! entity's runtime GAS state in [`state`], and versioning of definition files
+//! in `migration`.
+//!
+//! Tags are written as their full names. Use the helpers with
+//! `#[serde(with = "...")]` on fields of those types.
+
+#[cfg(any(
+    feature = "effect_assets",
+    feature = "ability_assets",
+    feature = "attribute_assets"
+))]
+pub mod migration;
+pub mod state;
+
+#[cfg(any(
+    feature = "effect_assets",
+    feature = "ability_assets",
+    feature = "attribute_assets"
+))]
+pub use migration::*;
+pub use state::*;
+
+/// Serializes a `GameplayTag` as its name.
+pub mod gameplay_tag_serde {
+    use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(tag: &GameplayTag, serializer: S) -> Result<S::Ok, S::Error> {
+        tag.get_tag_name().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<GameplayTag, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(GameplayTag::new(&name))
+    }
+}
+
+/// Serializes an `Option<GameplayTag>` as an optional name.
+pub mod option_gameplay_tag_serde {
+    use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        tag: &Option<GameplayTag>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        tag.as_ref()
+            .map(GameplayTag::get_tag_name)
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<GameplayTag>, D::Error> {
+        let name = Option::<String>::deserialize(deserializer)?;
+        Ok(name.map(|name| GameplayTag::new(&name)))
+    }
+}
+
+/// Serializes a `GameplayTagContainer` as its explicit tag names.
+///
+/// Parent tags are not written; they are rebuilt from the dotted names on
+/// deserialization, as `GameplayTagsManager` would.
+pub mod tag_container_serde {
+    use bevy_gameplay_tag::GameplayTagContainer;
+    use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        container: &GameplayTagContainer,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let names: Vec<&str> = container
+            .gameplay_tags
+            .iter()
+            .map(GameplayTag::get_tag_name)
+            .collect();
+        names.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<GameplayTagContainer, D::Error> {
+        let names = Vec::<String>::deserialize(deserializer)?;
+        Ok(container_from_names(&names))
+    }
+
+    /// Builds a container with sorted explicit tags and their parents.
+    pub(crate) fn container_from_names(names: &[String]) -> GameplayTagContainer {
+        let mut container = GameplayTagContainer::new();
+        for name in names.iter().filter(|name| !name.is_empty()) {
+            let tag = GameplayTag::new(name);
+            if let Err(index) = container.gameplay_tags.binary_search(&tag) {
+                container.gameplay_tags.insert(index, tag);
+            }
+            for (end, _) in name.match_indices('.') {
+                let parent = GameplayTag::new(&name[..end]);
+                if let Err(index) = container.parent_tags.binary_search(&parent) {
+                    container.parent_tags.insert(index, parent);
+                }
+            }
+        }
+        container
+    }
+}
+
+/// Serializes an `Option<GameplayTagContainer>` as optional tag names.
+pub mod option_tag_container_serde {
+    use super::tag_container_serde::container_from_names;
+    use bevy_gameplay_tag::GameplayTagContainer;
+    use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        container: &Option<GameplayTagContainer>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        container
+            .as_ref()
+            .map(|container| {
+                container
+                    .gameplay_tags
+                    .iter()
+                    .map(GameplayTag::get_tag_name)
+                    .collect::<Vec<_>>()
+            })
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<GameplayTagContainer>, D::Error> {
+        let names = Option::<Vec<String>>::deserialize(deserializer)?;
+        Ok(names.map(|names| container_from_names(&names)))
+    }
+}
+
+/// Serializes a map keyed by `GameplayTag` as a map keyed by tag name.
+pub mod tag_map_serde {
+    use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::{BTreeMap, HashMap};
+
+    pub fn serialize<S: Serializer, V: Serialize>(
+        map: &HashMap<GameplayTag, V>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        map.iter()
+            .map(|(tag, value)| (tag.get_tag_name(), value))
+            .collect::<BTreeMap<_, _>>()
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, V: Deserialize<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<GameplayTag, V>, D::Error> {
+        let names = HashMap::<String, V>::deserialize(deserializer)?;
+        Ok(names
+            .into_iter()
+            .map(|(name, value)| (GameplayTag::new(&name), value))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tag_container_serde::container_from_names;
+    use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+
+    #[test]
+    fn test_container_rebuilds_sorted_tags_and_parents() {
+        let container = container_from_names(&[
+            "State.Stunned".to_string(),
+            "Ability.Fire.Bolt".to_string(),
+            "State.Stunned".to_string(),
+        ]);
+
+        assert_eq!(
+            container.gameplay_tags,
+            vec![
+                GameplayTag::new("Ability.Fire.Bolt"),
+                GameplayTag::new("State.Stunned"),
+            ]
+        );
+        assert_eq!(
+            container.parent_tags,
+            vec![
+                GameplayTag::new("Ability"),
+                GameplayTag::new("Ability.Fire"),
+                GameplayTag::new("State"),
+            ]
+        );
+        assert!(container.has_tag(&GameplayTag::new("Ability.Fire")));
+    }
+}
@@ -0,0 +1,471 @@
+//! Save and load of an entity's runtime GAS state.
+//!
+//! [`GasStateSnapshot::capture`] records an entity's attribute values, active
+//! effects and granted abilities as plain data that can be written to a save
+//! file. Triggering [`RestoreGasStateEvent`] with a loaded snapshot replaces the
+//! state of an entity, re-creating the effect and modifier entities.
+//!
+//! Entity references are not saved: restored effects use their target as
+//! source, and running ability instances are not kept. Cooldowns are effects,
+//! so their remaining time is saved with the other effects.
+//!
+//! # Example
+//!
+//! ```ignore
+//! // Saving, from an exclusive system.
+//! let snapshot = GasStateSnapshot::capture(world, player);
+//! let text = ron::to_string(&snapshot)?;
+//!
+//! // Loading, onto a player spawned with its attribute set.
+//! let snapshot: GasStateSnapshot = ron::from_str(&text)?;
+//! commands.trigger(RestoreGasStateEvent::new(player, snapshot));
+//! ```
+
+use crate::abilities::{AbilityActiveState, AbilityOwner, AbilityRegistry, AbilitySpec};
+use crate::attributes::{AttributeData, AttributeName};
+use crate::core::OwnedTags;
+use crate::effects::{
+    ActiveEffects, ActiveGameplayEffect, AttributeModifier, DurationPolicy, EffectDuration,
+    EffectGrantedTags, EffectGrantedTagsApplied, EffectInstigator, EffectTarget, EvaluationChannel,
+    GameplayEffectContext, GameplayEffectRegistry, GameplayEffectRemovedEvent, GrantedByEffect,
+    ModifierOperation, ModifierSource, PeriodicEffect, SetByCallerMagnitudes,
+};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_gameplay_tag::GameplayTagsManager;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use string_cache::DefaultAtom as Atom;
+
+/// Saved values of one attribute.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttributeState {
+    /// The attribute name.
+    pub name: Atom,
+    /// The base value.
+    pub base_value: f32,
+    /// The current value.
+    pub current_value: f32,
+}
+
+/// Saved modifier of an active effect.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModifierState {
+    /// The modified attribute.
+    pub attribute_name: Atom,
+    /// The operation to perform.
+    pub operation: ModifierOperation,
+    /// The magnitude computed when the effect was applied.
+    pub magnitude: f32,
+    /// The evaluation channel.
+    pub channel: EvaluationChannel,
+}
+
+/// Saved active effect.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActiveEffectState {
+    /// The effect definition ID.
+    pub definition_id: Atom,
+    /// The level the effect was applied at.
+    pub level: i32,
+    /// The current stack count.
+    pub stack_count: i32,
+    /// Seconds since the effect was applied.
+    pub elapsed: f32,
+    /// Remaining duration, for effects with a duration.
+    pub remaining: Option<f32>,
+    /// Time until the next execution, for periodic effects.
+    pub time_until_next_period: Option<f32>,
+    /// SetByCaller magnitudes, keyed by data tag name.
+    #[serde(default)]
+    pub set_by_caller_magnitudes: Vec<(String, f32)>,
+    /// Modifiers of all stacks. Periodic effects have none.
+    #[serde(default)]
+    pub modifiers: Vec<ModifierState>,
+}
+
+/// Saved ability grant.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GrantedAbilityState {
+    /// The ability definition ID.
+    pub definition_id: Atom,
+    /// The level the ability was granted at.
+    pub level: i32,
+    /// The bound input ID.
+    pub input_id: Option<i32>,
+}
+
+/// Serializable runtime GAS state of one entity.
+///
+/// Abilities granted by effects are not listed; restoring the effect grants
+/// them again.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GasStateSnapshot {
+    /// Attribute values, sorted by name.
+    #[serde(default)]
+    pub attributes: Vec<AttributeState>,
+    /// Active effects targeting the entity, oldest first.
+    #[serde(default)]
+    pub effects: Vec<ActiveEffectState>,
+    /// Abilities granted directly to the entity.
+    #[serde(default)]
+    pub abilities: Vec<GrantedAbilityState>,
+}
+
+impl GasStateSnapshot {
+    /// Records the GAS state of `entity`.
+    pub fn capture(world: &World, entity: Entity) -> Self {
+        let now = world.get_resource::<Time>().map_or(0.0, Time::elapsed_secs);
+
+        let mut attributes: Vec<_> = world
+            .try_query::<(&AttributeName, &AttributeData, &ChildOf)>()
+            .map(|mut query| {
+                query
+                    .iter(world)
+                    .filter(|(_, _, child_of)| child_of.parent() == entity)
+                    .map(|(name, data, _)| AttributeState {
+                        name: name.0.clone(),
+                        base_value: data.base_value,
+                        current_value: data.current_value,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        attributes.sort_by(|a, b| a.name.as_ref().cmp(b.name.as_ref()));
+
+        let mut modifiers: HashMap<Entity, Vec<ModifierState>> = HashMap::new();
+        if let Some(mut query) = world.try_query::<(&AttributeModifier, &ModifierSource)>() {
+            for (modifier, source) in query.iter(world) {
+                modifiers.entry(source.0).or_default().push(ModifierState {
+                    attribute_name: modifier.target_attribute.clone(),
+                    operation: modifier.operation,
+                    magnitude: modifier.magnitude,
+                    channel: modifier.channel,
+                });
+            }
+        }
+
+        let mut effects: Vec<_> = world
+            .try_query::<(
+                Entity,
+                &ActiveGameplayEffect,
+                &EffectTarget,
+                Option<&EffectDuration>,
+                Option<&PeriodicEffect>,
+                Option<&SetByCallerMagnitudes>,
+            )>()
+            .map(|mut query| {
+                query
+                    .iter(world)
+                    .filter(|(_, _, target, ..)| target.0 == entity)
+                    .map(
+                        |(effect_entity, effect, _, duration, periodic, set_by_caller)| {
+                            ActiveEffectState {
+                                definition_id: effect.definition_id.clone(),
+                                level: effect.level,
+                                stack_count: effect.stack_count,
+                                elapsed: (now - effect.start_time).max(0.0),
+                                remaining: duration.map(|duration| duration.remaining),
+                                time_until_next_period: periodic
+                                    .map(|periodic| periodic.time_until_next),
+                                set_by_caller_magnitudes: set_by_caller
+                                    .map(|magnitudes| {
+                                        magnitudes
+                                            .iter()
+                                            .map(|(tag, magnitude)| {
+                                                (tag.get_tag_name().to_string(), magnitude)
+                                            })
+                                            .collect()
+                                    })
+                                    .unwrap_or_default(),
+                                modifiers: modifiers.remove(&effect_entity).unwrap_or_default(),
+                            }
+                        },
+                    )
+                    .collect()
+            })
+            .unwrap_or_default();
+        effects.sort_by(|a, b| b.elapsed.total_cmp(&a.elapsed));
+
+        let abilities = world
+            .try_query_filtered::<(&AbilitySpec, &AbilityOwner), Without<GrantedByEffect>>()
+            .map(|mut query| {
+                query
+                    .iter(world)
+                    .filter(|(_, owner)| owner.0 == entity)
+                    .map(|(spec, _)| GrantedAbilityState {
+                        definition_id: spec.definition_id.clone(),
+                        level: spec.level,
+                        input_id: spec.input_id,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            attributes,
+            effects,
+            abilities,
+        }
+    }
+
+    /// Returns the saved cooldown time left for an ability, if it was cooling down.
+    pub fn cooldown_remaining(
+        &self,
+        ability_id: impl Into<Atom>,
+        abilities: &AbilityRegistry,
+    ) -> Option<f32> {
+        let cooldown_effect = abilities.get(ability_id)?.cooldown_effect.as_ref()?;
+        self.effects
+            .iter()
+            .filter(|effect| &effect.definition_id == cooldown_effect)
+            .filter_map(|effect| effect.remaining)
+            .reduce(f32::max)
+    }
+}
+
+/// Event that replaces an entity's GAS state with a snapshot.
+///
+/// The entity's attributes must already exist; values for attributes it does
+/// not have are skipped. Its current effects and directly granted abilities
+/// are removed first.
+#[derive(Event, Debug, Clone)]
+pub struct RestoreGasStateEvent {
+    /// The entity to restore.
+    pub entity: Entity,
+    /// The state to restore.
+    pub snapshot: GasStateSnapshot,
+}
+
+impl RestoreGasStateEvent {
+    /// Creates a restore event.
+    pub fn new(entity: Entity, snapshot: GasStateSnapshot) -> Self {
+        Self { entity, snapshot }
+    }
+}
+
+/// Bundled query parameters for restoring GAS state.
+#[derive(SystemParam)]
+pub struct RestoreGasStateParams<'w, 's> {
+    pub tag_containers: Query<'w, 's, &'static mut OwnedTags>,
+    pub attributes: Query<
+        'w,
+        's,
+        (
+            &'static mut AttributeData,
+            &'static AttributeName,
+            &'static ChildOf,
+        ),
+    >,
+    pub active_effects: Query<'w, 's, &'static ActiveEffects>,
+    pub effects: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static ActiveGameplayEffect,
+            Option<&'static EffectGrantedTags>,
+        ),
+    >,
+    pub abilities: Query<'w, 's, (Entity, &'static AbilityOwner), Without<GrantedByEffect>>,
+}
+
+/// Observer that restores an entity's GAS state from a snapshot.
+pub fn on_restore_gas_state(
+    ev: On<RestoreGasStateEvent>,
+    mut commands: Commands,
+    registry: Res<GameplayEffectRegistry>,
+    tags_manager: Res<GameplayTagsManager>,
+    time: Res<Time>,
+    mut params: RestoreGasStateParams,
+) {
+    let entity = ev.entity;
+    let snapshot = &ev.snapshot;
+    if commands.get_entity(entity).is_err() {
+        warn!("Cannot restore GAS state: entity {entity} does not exist");
+        return;
+    }
+
+    // Remove the current effects the way expiry does. Their modifiers are
+    // despawned with them.
+    if let Ok(current_effects) = params.active_effects.get(entity) {
+        for (effect_entity, effect, granted_tags) in
+            params.effects.iter_many(current_effects.iter())
+        {
+            if let Some(granted) = granted_tags
+                && let Ok(mut target_tags) = params.tag_containers.get_mut(entity)
+            {
+                target_tags.0.update_tag_container_count(
+                    &granted.tags,
+                    -1,
+                    &tags_manager,
+                    &mut commands,
+                    entity,
+                );
+            }
+            commands.trigger(GameplayEffectRemovedEvent {
+                effect: effect_entity,
+                target: entity,
+                effect_id: effect.definition_id.clone(),
+            });
+            commands.entity(effect_entity).despawn();
+        }
+    }
+    for (ability_entity, owner) in params.abilities.iter() {
+        if owner.0 == entity {
+            commands.entity(ability_entity).despawn();
+        }
+    }
+
+    for saved in &snapshot.attributes {
+        let Some((mut data, ..)) = params
+            .attributes
+            .iter_mut()
+            .find(|(_, name, child_of)| child_of.parent() == entity && name.0 == saved.name)
+        else {
+            warn!(
+                "Cannot restore attribute '{}': entity {entity} does not have it",
+                saved.name
+            );
+            continue;
+        };
+        data.base_value = saved.base_value;
+        data.current_value = saved.current_value;
+    }
+
+    for saved in &snapshot.abilities {
+        commands.spawn((
+            AbilitySpec {
+                definition_id: saved.definition_id.clone(),
+                level: saved.level,
+                input_id: saved.input_id,
+            },
+            AbilityOwner(entity),
+            AbilityActiveState::default(),
+        ));
+    }
+
+    for saved in &snapshot.effects {
+        let Some(definition) = registry.get(&saved.definition_id) else {
+            warn!(
+                "Cannot restore effect '{}': definition not found",
+                saved.definition_id
+            );
+            continue;
+        };
+        if definition.duration_policy == DurationPolicy::Instant {
+            continue;
+        }
+
+        let mut active_effect = ActiveGameplayEffect::new(
+            saved.definition_id.clone(),
+            entity,
+            entity,
+            saved.level,
+            time.elapsed_secs() - saved.elapsed,
+        );
+        active_effect.stack_count = saved.stack_count;
+        let mut effect_entity_commands = commands.spawn((
+            active_effect,
+            EffectTarget(entity),
+            EffectInstigator(None),
+            GameplayEffectContext::new(),
+        ));
+
+        if definition.duration_policy == DurationPolicy::HasDuration {
+            effect_entity_commands.insert(EffectDuration {
+                remaining: saved.remaining.unwrap_or(definition.duration_magnitude),
+                total: definition.duration_magnitude,
+            });
+        }
+        if definition.period > 0.0 {
+            effect_entity_commands.insert(PeriodicEffect {
+                period: definition.period,
+                time_until_next: saved.time_until_next_period.unwrap_or(0.0),
+            });
+        }
+        if !saved.set_by_caller_magnitudes.is_empty() {
+            let mut magnitudes = SetByCallerMagnitudes::new();
+            for (tag_name, magnitude) in &saved.set_by_caller_magnitudes {
+                magnitudes.set_magnitude(GameplayTag::new(tag_name), *magnitude);
+            }
+            effect_entity_commands.insert(magnitudes);
+        }
+        if !definition.granted_tags.is_empty() {
+            effect_entity_commands.insert((
+                EffectGrantedTags {
+                    tags: definition.granted_tags.clone(),
+                },
+                EffectGrantedTagsApplied,
+            ));
+        }
+        let effect_entity = effect_entity_commands.id();
+
+        if !definition.granted_tags.is_empty()
+            && let Ok(mut target_tags) = params.tag_containers.get_mut(entity)
+        {
+            target_tags.0.update_tag_container_count(
+                &definition.granted_tags,
+                1,
+                &tags_manager,
+                &mut commands,
+                entity,
+            );
+        }
+
+        // Spawning the saved modifiers keeps magnitudes that were captured
+        // when the effect was applied; `create_effect_modifiers_system` only
+        // fills in sets that are missing.
+        for modifier in &saved.modifiers {
+            commands.spawn((
+                AttributeModifier {
+                    target_entity: entity,
+                    target_attribute: modifier.attribute_name.clone(),
+                    operation: modifier.operation,
+                    magnitude: modifier.magnitude,
+                    channel: modifier.channel,
+                },
+                ModifierSource(effect_entity),
+            ));
+        }
+    }
+
+    debug!(
+        "Restored GAS state of {entity}: {} attributes, {} effects, {} abilities",
+        snapshot.attributes.len(),
+        snapshot.effects.len(),
+        snapshot.abilities.len()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abilities::AbilityDefinition;
+
+    #[test]
+    fn test_cooldown_remaining_reads_cooldown_effect() {
+        let mut abilities = AbilityRegistry::default();
+        abilities.register(AbilityDefinition::new("fireball").with_cooldown_effect("fireball_cd"));
+        let snapshot = GasStateSnapshot {
+            effects: vec![ActiveEffectState {
+                definition_id: "fireball_cd".into(),
+                level: 1,
+                stack_count: 1,
+                elapsed: 1.0,
+                remaining: Some(2.5),
+                time_until_next_period: None,
+                set_by_caller_magnitudes: Vec::new(),
+                modifiers: Vec::new(),
+            }],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            snapshot.cooldown_remaining("fireball", &abilities),
+            Some(2.5)
+        );
+        assert_eq!(snapshot.cooldown_remaining("frostbolt", &abilities), None);
+    }
+}
@@ -0,0 +1,351 @@
+//! [`GasTestApp`], a headless app for testing games built on GAS.
+//!
+//! [`GasTestApp`] wraps an [`App`] running [`GasPlugin`](crate::GasPlugin)
+//! and the gameplay tag plugin, with time advancing by a fixed step per
+//! update so durations and periods tick deterministically.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut app = GasTestApp::new();
+//! app.register_effect(
+//!     GameplayEffectDefinition::new("poison")
+//!         .with_duration(2.0)
+//!         .with_period(1.0)
+//!         .add_modifier(ModifierInfo::new(
+//!             "Health",
+//!             ModifierOperation::AddBase,
+//!             MagnitudeCalculation::scalar(-10.0),
+//!         )),
+//! );
+//! let player = app.spawn_character::<CharacterAttributes>();
+//!
+//! app.apply_effect("poison", player);
+//! app.advance(2.0);
+//!
+//! app.assert_attribute(player, "Health", 80.0);
+//! app.assert_no_effect(player, "poison");
+//! ```
+
+use crate::GasPlugin;
+use crate::abilities::components::{AbilityActiveState, AbilityOwner, AbilitySpec};
+use crate::abilities::definition::{AbilityDefinition, AbilityRegistry};
+use crate::abilities::systems::TryActivateAbilityEvent;
+use crate::attributes::components::{AttributeData, AttributeName};
+use crate::attributes::traits::AttributeSetDefinition;
+use crate::core::{GasTickMode, OwnedTags};
+use crate::effects::definition::{GameplayEffectDefinition, GameplayEffectRegistry};
+use crate::effects::systems::ApplyGameplayEffectEvent;
+use crate::utils::gas::Gas;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use std::ops::{Deref, DerefMut};
+use std::time::Duration;
+use string_cache::DefaultAtom as Atom;
+
+/// Tolerance used when comparing attribute values.
+pub const ATTRIBUTE_TOLERANCE: f32 = 1e-4;
+
+/// A headless app running GAS, advancing time by a fixed step per update.
+///
+/// Dereferences to the wrapped [`App`], so anything not covered by the
+/// helpers is still reachable.
+pub struct GasTestApp {
+    app: App,
+    step: Duration,
+}
+
+impl Default for GasTestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GasTestApp {
+    /// Creates an app loading tags from `assets/gameplay_tags.json`, stepping
+    /// 0.1 seconds per update.
+    pub fn new() -> Self {
+        Self::with_tags_path("assets/gameplay_tags.json")
+    }
+
+    /// Creates an app loading tags from `path`, stepping 0.1 seconds per
+    /// update.
+    pub fn with_tags_path(path: impl Into<String>) -> Self {
+        Self::build(path.into(), GasTickMode::Variable)
+    }
+
+    /// Creates an app loading tags from `assets/gameplay_tags.json` that runs
+    /// the whole simulation in `FixedUpdate`
+    /// ([`GasTickMode::FixedSimulation`]), one fixed step per update.
+    pub fn fixed() -> Self {
+        Self::build(
+            "assets/gameplay_tags.json".to_string(),
+            GasTickMode::FixedSimulation,
+        )
+    }
+
+    fn build(path: String, mode: GasTickMode) -> Self {
+        let step = Duration::from_millis(100);
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            GameplayTagsPlugin::with_data_path(path),
+            GasPlugin::builder().with_tick_mode(mode),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(step))
+        .insert_resource(Time::<Fixed>::from_duration(step));
+        app.update();
+        Self { app, step }
+    }
+
+    /// Sets how much time passes per update, and the fixed timestep.
+    pub fn with_step(mut self, step: Duration) -> Self {
+        self.step = step;
+        self.app
+            .insert_resource(TimeUpdateStrategy::ManualDuration(step))
+            .insert_resource(Time::<Fixed>::from_duration(step));
+        self
+    }
+
+    /// Returns how much time passes per update.
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+
+    /// Consumes the wrapper and returns the app.
+    pub fn into_app(self) -> App {
+        self.app
+    }
+
+    /// Registers an effect definition.
+    pub fn register_effect(&mut self, definition: GameplayEffectDefinition) -> &mut Self {
+        self.app
+            .world_mut()
+            .resource_mut::<GameplayEffectRegistry>()
+            .register(definition);
+        self
+    }
+
+    /// Registers an ability definition.
+    pub fn register_ability(&mut self, definition: AbilityDefinition) -> &mut Self {
+        self.app
+            .world_mut()
+            .resource_mut::<AbilityRegistry>()
+            .register(definition);
+        self
+    }
+
+    /// Spawns a GAS owner with the attributes of `S` at their default values.
+    pub fn spawn_character<S: AttributeSetDefinition>(&mut self) -> Entity {
+        let world = self.app.world_mut();
+        let owner = world.spawn(OwnedTags::default()).id();
+        S::register_hooks(world);
+        S::create_attributes(&mut world.commands(), owner);
+        world.flush();
+        owner
+    }
+
+    /// Spawns a GAS owner with the given attributes and base values.
+    pub fn spawn_with_attributes(&mut self, attributes: &[(&str, f32)]) -> Entity {
+        let world = self.app.world_mut();
+        let owner = world.spawn(OwnedTags::default()).id();
+        for &(name, value) in attributes {
+            world.spawn((
+                AttributeName::new(name),
+                AttributeData::new(value),
+                ChildOf(owner),
+            ));
+        }
+        owner
+    }
+
+    /// Applies the effect `effect_id` to `target` and runs one update.
+    pub fn apply_effect(&mut self, effect_id: impl Into<Atom>, target: Entity) -> &mut Self {
+        self.app
+            .world_mut()
+            .trigger(ApplyGameplayEffectEvent::new(effect_id, target));
+        self.app.update();
+        self
+    }
+
+    /// Grants the ability `ability_id` to `owner` at level 1 and returns its
+    /// spec.
+    pub fn grant_ability(&mut self, owner: Entity, ability_id: impl Into<Atom>) -> Entity {
+        let spec = self
+            .app
+            .world_mut()
+            .spawn((AbilitySpec::new(ability_id.into(), 1), AbilityOwner(owner)))
+            .id();
+        self.app.update();
+        spec
+    }
+
+    /// Tries to activate the ability `spec` of its owner and runs one update.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `spec` is not an ability spec.
+    pub fn try_activate(&mut self, spec: Entity) -> &mut Self {
+        let owner = self
+            .app
+            .world()
+            .get::<AbilityOwner>(spec)
+            .unwrap_or_else(|| panic!("{} is not an ability spec", spec))
+            .0;
+        self.app
+            .world_mut()
+            .trigger(TryActivateAbilityEvent::new(spec, owner));
+        self.app.update();
+        self
+    }
+
+    /// Runs updates until at least `seconds` have passed.
+    pub fn advance(&mut self, seconds: f32) -> &mut Self {
+        let steps = (seconds / self.step.as_secs_f32() - ATTRIBUTE_TOLERANCE).ceil();
+        for _ in 0..steps.max(0.0) as u32 {
+            self.app.update();
+        }
+        self
+    }
+
+    /// Returns the current value of the attribute of `owner` named `name`.
+    pub fn attribute(&mut self, owner: Entity, name: &str) -> Option<f32> {
+        self.attribute_data(owner, name)
+            .map(|data| data.current_value)
+    }
+
+    /// Returns the base value of the attribute of `owner` named `name`.
+    pub fn base_attribute(&mut self, owner: Entity, name: &str) -> Option<f32> {
+        self.attribute_data(owner, name).map(|data| data.base_value)
+    }
+
+    fn attribute_data(&mut self, owner: Entity, name: &str) -> Option<AttributeData> {
+        let name = name.to_string();
+        self.read(move |gas| gas.attribute(owner, &name).cloned())
+    }
+
+    /// Returns how many active effects `effect_id` target `target`.
+    pub fn effect_count(&mut self, target: Entity, effect_id: &str) -> usize {
+        let effect_id = Atom::from(effect_id);
+        self.read(move |gas| {
+            gas.active_effects(target)
+                .filter(|(_, effect)| effect.definition_id == effect_id)
+                .count()
+        })
+    }
+
+    /// Returns whether `owner` has `tag` or one of its child tags.
+    pub fn has_tag(&mut self, owner: Entity, tag: &str) -> bool {
+        let tag = GameplayTag::new(tag);
+        self.read(move |gas| gas.has_tag(owner, &tag))
+    }
+
+    /// Returns whether an instance of the ability `spec` is active.
+    pub fn is_ability_active(&self, spec: Entity) -> bool {
+        self.app
+            .world()
+            .get::<AbilityActiveState>(spec)
+            .is_some_and(|state| state.is_active)
+    }
+
+    /// Runs `read` against the [`Gas`] system parameter.
+    pub fn read<T: 'static>(&mut self, read: impl FnOnce(Gas) -> T + Send + Sync + 'static) -> T {
+        let mut read = Some(read);
+        self.app
+            .world_mut()
+            .run_system_once(move |gas: Gas| (read.take().expect("run once"))(gas))
+            .expect("Gas system parameter should be valid")
+    }
+
+    /// Asserts that the current value of an attribute is `expected`.
+    #[track_caller]
+    pub fn assert_attribute(&mut self, owner: Entity, name: &str, expected: f32) {
+        let value = self.attribute(owner, name);
+        assert!(
+            value.is_some_and(|value| (value - expected).abs() <= ATTRIBUTE_TOLERANCE),
+            "expected {} of {} to be {}, got {:?}",
+            name,
+            owner,
+            expected,
+            value
+        );
+    }
+
+    /// Asserts that the base value of an attribute is `expected`.
+    #[track_caller]
+    pub fn assert_base_attribute(&mut self, owner: Entity, name: &str, expected: f32) {
+        let value = self.base_attribute(owner, name);
+        assert!(
+            value.is_some_and(|value| (value - expected).abs() <= ATTRIBUTE_TOLERANCE),
+            "expected base {} of {} to be {}, got {:?}",
+            name,
+            owner,
+            expected,
+            value
+        );
+    }
+
+    /// Asserts that `target` has at least one active `effect_id` effect.
+    #[track_caller]
+    pub fn assert_has_effect(&mut self, target: Entity, effect_id: &str) {
+        assert!(
+            self.effect_count(target, effect_id) > 0,
+            "expected {} to have effect '{}'",
+            target,
+            effect_id
+        );
+    }
+
+    /// Asserts that `target` has no active `effect_id` effect.
+    #[track_caller]
+    pub fn assert_no_effect(&mut self, target: Entity, effect_id: &str) {
+        let count = self.effect_count(target, effect_id);
+        assert!(
+            count == 0,
+            "expected {} to have no effect '{}', found {}",
+            target,
+            effect_id,
+            count
+        );
+    }
+
+    /// Asserts that `owner` has `tag` or one of its child tags.
+    #[track_caller]
+    pub fn assert_has_tag(&mut self, owner: Entity, tag: &str) {
+        assert!(
+            self.has_tag(owner, tag),
+            "expected {} to have tag '{}'",
+            owner,
+            tag
+        );
+    }
+
+    /// Asserts whether an instance of the ability `spec` is active.
+    #[track_caller]
+    pub fn assert_ability_active(&self, spec: Entity, active: bool) {
+        assert_eq!(
+            self.is_ability_active(spec),
+            active,
+            "expected ability {} active = {}",
+            spec,
+            active
+        );
+    }
+}
+
+impl Deref for GasTestApp {
+    type Target = App;
+
+    fn deref(&self) -> &App {
+        &self.app
+    }
+}
+
+impl DerefMut for GasTestApp {
+    fn deref_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+}
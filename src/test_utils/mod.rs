@@ -0,0 +1,11 @@
+//! Test utilities module.
+//!
+//! Only compiled with the `test_utils` feature. This module provides a
+//! headless test app and a record-and-replay determinism harness for testing
+//! games built on GAS.
+
+pub mod app;
+pub mod replay;
+
+pub use app::*;
+pub use replay::*;
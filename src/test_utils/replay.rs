@@ -0,0 +1,258 @@
+//! Record-and-replay determinism checks.
+//!
+//! [`GasReplay`] runs a scripted session in a fixed-timestep [`GasTestApp`],
+//! recording the [`GasRng`] seed, every [`GasInput`] fed to GAS and the
+//! attribute values after each frame. Replaying the recording in a fresh app
+//! must reproduce the same attribute trajectory bit for bit, so a test that
+//! calls [`GasReplay::assert_deterministic`] fails as soon as a change makes
+//! the simulation depend on anything but its inputs and seed.
+//!
+//! Inputs refer to entities spawned by `setup`, which get the same IDs on
+//! every run as long as `setup` spawns them in the same order.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let replay = GasReplay::new(|app: &mut GasTestApp| {
+//!     app.register_effect(poison_definition());
+//!     app.spawn_character::<CharacterAttributes>()
+//! });
+//! let recording = replay.record(42, 50, |frame, player: &Entity| match frame {
+//!     0 => vec![GasInput::apply_effect("poison", *player)],
+//!     _ => Vec::new(),
+//! });
+//! replay.assert_deterministic(&recording);
+//! ```
+
+use super::app::GasTestApp;
+use crate::abilities::events::SendGameplayEventEvent;
+use crate::abilities::systems::{CancelAbilityEvent, TryActivateAbilityEvent};
+use crate::attributes::components::{AttributeData, AttributeName};
+use crate::core::GasRng;
+use crate::effects::components::GameplayEffectSpec;
+use crate::effects::systems::ApplyGameplayEffectEvent;
+use bevy::prelude::*;
+use string_cache::DefaultAtom as Atom;
+
+/// An input fed to GAS during a recorded session.
+#[derive(Debug, Clone)]
+pub enum GasInput {
+    /// Triggers an [`ApplyGameplayEffectEvent`].
+    ApplyEffect(GameplayEffectSpec),
+    /// Triggers a [`TryActivateAbilityEvent`].
+    TryActivateAbility { ability_spec: Entity, owner: Entity },
+    /// Triggers a [`CancelAbilityEvent`] for every instance of the ability.
+    CancelAbility { ability_spec: Entity, owner: Entity },
+    /// Triggers a [`SendGameplayEventEvent`].
+    GameplayEvent(SendGameplayEventEvent),
+}
+
+impl GasInput {
+    /// Applies the effect `effect_id` to `target` at level 1.
+    pub fn apply_effect(effect_id: impl Into<Atom>, target: Entity) -> Self {
+        GasInput::ApplyEffect(GameplayEffectSpec::new(effect_id, target))
+    }
+
+    /// Tries to activate the ability `ability_spec` of `owner`.
+    pub fn try_activate(ability_spec: Entity, owner: Entity) -> Self {
+        GasInput::TryActivateAbility {
+            ability_spec,
+            owner,
+        }
+    }
+
+    /// Triggers the input's event.
+    pub fn trigger(&self, world: &mut World) {
+        match self {
+            GasInput::ApplyEffect(spec) => {
+                world.trigger(ApplyGameplayEffectEvent::from_spec(spec.clone()));
+            }
+            GasInput::TryActivateAbility {
+                ability_spec,
+                owner,
+            } => world.trigger(TryActivateAbilityEvent::new(*ability_spec, *owner)),
+            GasInput::CancelAbility {
+                ability_spec,
+                owner,
+            } => world.trigger(CancelAbilityEvent {
+                instance: None,
+                ability_spec: *ability_spec,
+                owner: *owner,
+            }),
+            GasInput::GameplayEvent(event) => world.trigger(event.clone()),
+        }
+    }
+}
+
+/// An attribute's values after a frame.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeSample {
+    /// The attribute's owner.
+    pub owner: Entity,
+    /// The attribute name.
+    pub attribute: Atom,
+    /// The base value.
+    pub base_value: f32,
+    /// The current value.
+    pub current_value: f32,
+}
+
+/// The attribute values of every owner after each frame of a session.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GasTrajectory {
+    /// One entry per frame, sorted by owner and attribute name.
+    pub frames: Vec<Vec<AttributeSample>>,
+}
+
+impl GasTrajectory {
+    /// Records the attribute values of every owner as the next frame.
+    pub fn sample(&mut self, world: &mut World) {
+        let mut attributes = world.query::<(&AttributeName, &AttributeData, &ChildOf)>();
+        let mut samples: Vec<AttributeSample> = attributes
+            .iter(world)
+            .map(|(name, data, child_of)| AttributeSample {
+                owner: child_of.parent(),
+                attribute: name.0.clone(),
+                base_value: data.base_value,
+                current_value: data.current_value,
+            })
+            .collect();
+        samples.sort_by(|a, b| (a.owner, &*a.attribute).cmp(&(b.owner, &*b.attribute)));
+        self.frames.push(samples);
+    }
+
+    /// Describes the first difference between this trajectory and `other`,
+    /// or returns `None` if they are identical.
+    ///
+    /// Values are compared bit for bit.
+    pub fn first_divergence(&self, other: &GasTrajectory) -> Option<String> {
+        for (frame, (expected, actual)) in self.frames.iter().zip(&other.frames).enumerate() {
+            if expected.len() != actual.len() {
+                return Some(format!(
+                    "frame {}: expected {} attributes, got {}",
+                    frame,
+                    expected.len(),
+                    actual.len()
+                ));
+            }
+            for (expected, actual) in expected.iter().zip(actual) {
+                if expected.owner != actual.owner || expected.attribute != actual.attribute {
+                    return Some(format!(
+                        "frame {}: expected {} of {}, got {} of {}",
+                        frame, expected.attribute, expected.owner, actual.attribute, actual.owner
+                    ));
+                }
+                if expected.base_value.to_bits() != actual.base_value.to_bits()
+                    || expected.current_value.to_bits() != actual.current_value.to_bits()
+                {
+                    return Some(format!(
+                        "frame {}: {} of {} expected {} (base {}), got {} (base {})",
+                        frame,
+                        expected.attribute,
+                        expected.owner,
+                        expected.current_value,
+                        expected.base_value,
+                        actual.current_value,
+                        actual.base_value
+                    ));
+                }
+            }
+        }
+        if self.frames.len() != other.frames.len() {
+            return Some(format!(
+                "expected {} frames, got {}",
+                self.frames.len(),
+                other.frames.len()
+            ));
+        }
+        None
+    }
+}
+
+/// A recorded session: its seed, its inputs and the resulting trajectory.
+#[derive(Debug, Clone)]
+pub struct GasRecording {
+    /// The [`GasRng`] seed the session ran with.
+    pub seed: u64,
+    /// Number of frames the session ran.
+    pub frames: u32,
+    /// Inputs and the frame they were fed in, in order.
+    pub inputs: Vec<(u32, GasInput)>,
+    /// Attribute values after each frame.
+    pub trajectory: GasTrajectory,
+}
+
+/// Records scripted sessions and replays them to check determinism.
+pub struct GasReplay<T> {
+    setup: Box<dyn Fn(&mut GasTestApp) -> T>,
+}
+
+impl<T> GasReplay<T> {
+    /// Creates a harness whose sessions start with `setup`, which registers
+    /// definitions, spawns entities and returns whatever the script needs to
+    /// refer to them.
+    pub fn new(setup: impl Fn(&mut GasTestApp) -> T + 'static) -> Self {
+        Self {
+            setup: Box::new(setup),
+        }
+    }
+
+    fn start(&self, seed: u64) -> (GasTestApp, T) {
+        let mut app = GasTestApp::fixed();
+        app.world_mut().insert_resource(GasRng::new(seed));
+        let handles = (self.setup)(&mut app);
+        (app, handles)
+    }
+
+    /// Runs `frames` frames seeded with `seed`, feeding the inputs `script`
+    /// returns for each frame before updating.
+    pub fn record(
+        &self,
+        seed: u64,
+        frames: u32,
+        mut script: impl FnMut(u32, &T) -> Vec<GasInput>,
+    ) -> GasRecording {
+        let (mut app, handles) = self.start(seed);
+        let mut inputs = Vec::new();
+        let mut trajectory = GasTrajectory::default();
+        for frame in 0..frames {
+            for input in script(frame, &handles) {
+                input.trigger(app.world_mut());
+                inputs.push((frame, input));
+            }
+            app.update();
+            trajectory.sample(app.world_mut());
+        }
+        GasRecording {
+            seed,
+            frames,
+            inputs,
+            trajectory,
+        }
+    }
+
+    /// Replays `recording` in a fresh app and returns the trajectory.
+    pub fn replay(&self, recording: &GasRecording) -> GasTrajectory {
+        let (mut app, _) = self.start(recording.seed);
+        let mut inputs = recording.inputs.iter().peekable();
+        let mut trajectory = GasTrajectory::default();
+        for frame in 0..recording.frames {
+            while let Some((_, input)) = inputs.next_if(|(input_frame, _)| *input_frame == frame) {
+                input.trigger(app.world_mut());
+            }
+            app.update();
+            trajectory.sample(app.world_mut());
+        }
+        trajectory
+    }
+
+    /// Replays `recording` and panics at the first attribute value that
+    /// differs from the recorded trajectory.
+    #[track_caller]
+    pub fn assert_deterministic(&self, recording: &GasRecording) {
+        let replayed = self.replay(recording);
+        if let Some(divergence) = recording.trajectory.first_divergence(&replayed) {
+            panic!("replay diverged from the recording: {}", divergence);
+        }
+    }
+}
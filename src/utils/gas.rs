@@ -0,0 +1,173 @@
+//! [`Gas`], a system parameter for reading an entity's GAS state.
+//!
+//! Lookups follow the owner's relationships (attribute children,
+//! `ActiveEffects`, `OwnedAbilities`) instead of scanning every attribute,
+//! effect and spec in the world.
+//!
+//! # Example
+//!
+//! ```ignore
+//! fn low_health_warning(gas: Gas, players: Query<Entity, With<Player>>) {
+//!     for player in &players {
+//!         if gas.attribute_value(player, "Health").is_some_and(|health| health < 20.0)
+//!             && !gas.has_tag(player, &GameplayTag::new("State.Dead"))
+//!         {
+//!             // ...
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::abilities::components::{AbilitySpec, OwnedAbilities};
+use crate::attributes::components::{AttributeData, AttributeName};
+use crate::core::OwnedTags;
+use crate::core::handles::{AbilityHandle, AttributeHandle, EffectHandle, HandleGenerations};
+use crate::effects::components::{ActiveEffects, ActiveGameplayEffect};
+use crate::error::{GasError, GasResult};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+
+/// Read-only access to the attributes, active effects, abilities and tags of
+/// GAS entities.
+#[derive(SystemParam)]
+pub struct Gas<'w, 's> {
+    pub children: Query<'w, 's, &'static Children>,
+    pub attributes: Query<'w, 's, (Entity, &'static AttributeName, &'static AttributeData)>,
+    pub active_effects: Query<'w, 's, &'static ActiveEffects>,
+    pub effects: Query<'w, 's, (Entity, &'static ActiveGameplayEffect)>,
+    pub owned_abilities: Query<'w, 's, &'static OwnedAbilities>,
+    pub abilities: Query<'w, 's, (Entity, &'static AbilitySpec)>,
+    pub tags: Query<'w, 's, &'static OwnedTags>,
+    pub attribute_generations: Option<Res<'w, HandleGenerations<AttributeData>>>,
+    pub effect_generations: Option<Res<'w, HandleGenerations<ActiveGameplayEffect>>>,
+    pub ability_generations: Option<Res<'w, HandleGenerations<AbilitySpec>>>,
+}
+
+impl Gas<'_, '_> {
+    /// Returns the attributes of `owner`.
+    pub fn attributes(
+        &self,
+        owner: Entity,
+    ) -> impl Iterator<Item = (Entity, &AttributeName, &AttributeData)> + '_ {
+        self.children
+            .get(owner)
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .filter_map(|child| self.attributes.get(child).ok())
+    }
+
+    /// Returns the attribute of `owner` named `name`.
+    pub fn attribute(&self, owner: Entity, name: &str) -> Option<&AttributeData> {
+        self.attributes(owner)
+            .find(|(_, attribute_name, _)| attribute_name.as_str() == name)
+            .map(|(_, _, data)| data)
+    }
+
+    /// Returns the attribute of `owner` named `name`, or an error naming it.
+    pub fn try_attribute(&self, owner: Entity, name: &str) -> GasResult<&AttributeData> {
+        self.attribute(owner, name)
+            .ok_or_else(|| GasError::AttributeNotFound {
+                attribute_name: name.into(),
+                owner,
+            })
+    }
+
+    /// Returns the current value of the attribute of `owner` named `name`.
+    pub fn attribute_value(&self, owner: Entity, name: &str) -> Option<f32> {
+        self.attribute(owner, name).map(|data| data.current_value)
+    }
+
+    /// Returns the active effects targeting `owner`.
+    pub fn active_effects(
+        &self,
+        owner: Entity,
+    ) -> impl Iterator<Item = (Entity, &ActiveGameplayEffect)> + '_ {
+        self.active_effects
+            .get(owner)
+            .into_iter()
+            .flat_map(|effects| effects.iter())
+            .filter_map(|effect| self.effects.get(effect).ok())
+    }
+
+    /// Returns the ability specs granted to `owner`.
+    pub fn abilities(&self, owner: Entity) -> impl Iterator<Item = (Entity, &AbilitySpec)> + '_ {
+        self.owned_abilities
+            .get(owner)
+            .into_iter()
+            .flat_map(|abilities| abilities.iter())
+            .filter_map(|spec| self.abilities.get(spec).ok())
+    }
+
+    /// Returns a handle to the attribute of `owner` named `name`.
+    pub fn attribute_handle(&self, owner: Entity, name: &str) -> Option<AttributeHandle> {
+        let (attribute, _, _) = self
+            .attributes(owner)
+            .find(|(_, attribute_name, _)| attribute_name.as_str() == name)?;
+        self.attribute_generations.as_ref()?.handle(attribute)
+    }
+
+    /// Returns a handle to the active effect `effect`, e.g. the one in a
+    /// `GameplayEffectAppliedEvent`.
+    pub fn effect_handle(&self, effect: Entity) -> Option<EffectHandle> {
+        self.effect_generations.as_ref()?.handle(effect)
+    }
+
+    /// Returns a handle to the ability spec `spec`.
+    pub fn ability_handle(&self, spec: Entity) -> Option<AbilityHandle> {
+        self.ability_generations.as_ref()?.handle(spec)
+    }
+
+    /// Returns whether `owner` has `tag` or one of its child tags.
+    pub fn has_tag(&self, owner: Entity, tag: &GameplayTag) -> bool {
+        self.tags
+            .get(owner)
+            .is_ok_and(|tags| tags.0.has_matching_gameplay_tag(tag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abilities::components::AbilityOwner;
+    use crate::effects::components::EffectTarget;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_reads_only_the_owners_state() {
+        let mut world = World::new();
+        let owner = world.spawn(OwnedTags::default()).id();
+        let other = world.spawn(OwnedTags::default()).id();
+        for (entity, value) in [(owner, 50.0), (other, 80.0)] {
+            world.spawn((
+                AttributeName::new("Health"),
+                AttributeData::new(value),
+                ChildOf(entity),
+            ));
+            world.spawn((
+                ActiveGameplayEffect::new("burn", entity, entity, 1, 0.0),
+                EffectTarget(entity),
+            ));
+        }
+        world.spawn((AbilitySpec::new("fireball", 1), AbilityOwner(owner)));
+
+        world
+            .run_system_once(move |gas: Gas| {
+                assert_eq!(gas.attribute_value(owner, "Health"), Some(50.0));
+                assert_eq!(gas.attribute_value(owner, "Mana"), None);
+                assert_eq!(
+                    gas.try_attribute(owner, "Mana"),
+                    Err(GasError::AttributeNotFound {
+                        attribute_name: "Mana".into(),
+                        owner,
+                    })
+                );
+                assert_eq!(gas.attributes(owner).count(), 1);
+                assert_eq!(gas.active_effects(owner).count(), 1);
+                assert_eq!(gas.abilities(owner).count(), 1);
+                assert_eq!(gas.abilities(other).count(), 0);
+                assert!(!gas.has_tag(owner, &GameplayTag::new("State.Buffed")));
+            })
+            .unwrap();
+    }
+}
@@ -2,8 +2,10 @@
 //!
 //! This module provides utility functions and helpers used across the GAS system.
 
+pub mod gas;
 pub mod math;
 pub mod query_helpers;
 
+pub use gas::*;
 pub use math::*;
 pub use query_helpers::*;
@@ -0,0 +1,197 @@
+//! Tests that ability files are registered once their effects exist, with
+//! tags resolved, and that reloads wait for active specs to end and never
+//! leave a stale definition registered.
+
+#![cfg(feature = "ability_assets")]
+
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{abilities::*, effects::*};
+use bevy_gameplay_tag::{GameplayTagsPlugin, gameplay_tag::GameplayTag};
+use std::sync::Arc;
+use string_cache::DefaultAtom as Atom;
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        AbilityAssetPlugin::new().with_path("abilities/fireball.ability.ron"),
+    ));
+    app
+}
+
+/// Updates until an ability file is loaded, whether or not it was applied.
+fn wait_for_load(app: &mut App, id: &str) {
+    for _ in 0..200 {
+        app.update();
+        let world = app.world();
+        if world
+            .resource::<PendingAbilityDefinitions>()
+            .0
+            .contains_key(&Atom::from(id))
+            || world.resource::<AbilityRegistry>().get(id).is_some()
+        {
+            return;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+    panic!("ability '{id}' was never loaded");
+}
+
+fn register_cooldown(app: &mut App) {
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(GameplayEffectDefinition::new("fireball_cooldown").with_duration(5.0));
+}
+
+#[test]
+fn test_ability_waits_for_cooldown_effect_and_resolves_tags() {
+    let mut app = create_app();
+    wait_for_load(&mut app, "fireball");
+    app.update();
+    assert!(
+        app.world()
+            .resource::<AbilityRegistry>()
+            .get("fireball")
+            .is_none()
+    );
+
+    register_cooldown(&mut app);
+    app.update();
+
+    let registry = app.world().resource::<AbilityRegistry>();
+    let fireball = registry.get("fireball").unwrap();
+    assert!(
+        fireball
+            .activation_owned_tags
+            .has_tag(&GameplayTag::new("Ability"))
+    );
+    assert!(
+        fireball
+            .activation_blocked_tags
+            .has_tag_exact(&GameplayTag::new("Cooldown.Fireball"))
+    );
+    assert!(
+        app.world()
+            .resource::<PendingAbilityDefinitions>()
+            .0
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_reload_waits_for_active_spec_and_keeps_behavior() {
+    let mut app = create_app();
+    register_cooldown(&mut app);
+    wait_for_load(&mut app, "fireball");
+    app.update();
+    Arc::make_mut(
+        app.world_mut()
+            .resource_mut::<AbilityRegistry>()
+            .definitions
+            .get_mut(&Atom::from("fireball"))
+            .unwrap(),
+    )
+    .behavior = Some(Arc::new(DefaultAbilityBehavior));
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("fireball", 1),
+            AbilityActiveState {
+                is_active: true,
+                active_count: 1,
+            },
+        ))
+        .id();
+
+    let handle = app.world().resource::<AbilityAssetHandles>().0[0].clone();
+    app.world_mut()
+        .resource_mut::<Assets<AbilityDefinitionAsset>>()
+        .get_mut(&handle)
+        .unwrap()
+        .definitions[0]
+        .activation_owned_tags = Default::default();
+    app.update();
+    app.update();
+
+    let registry = app.world().resource::<AbilityRegistry>();
+    assert!(
+        !registry
+            .get("fireball")
+            .unwrap()
+            .activation_owned_tags
+            .is_empty()
+    );
+
+    app.world_mut()
+        .get_mut::<AbilityActiveState>(spec)
+        .unwrap()
+        .is_active = false;
+    app.update();
+
+    let fireball = app
+        .world()
+        .resource::<AbilityRegistry>()
+        .get("fireball")
+        .unwrap();
+    assert!(fireball.activation_owned_tags.is_empty());
+    assert!(fireball.behavior.is_some());
+}
+
+#[test]
+fn test_unknown_tag_is_not_registered() {
+    let mut app = create_app();
+    register_cooldown(&mut app);
+    let asset = AbilityDefinitionAssetLoader::parse(
+        br#"(id: "mystery", ability_tags: ["Ability.Unknown"],
+             default_blocks_other_abilities: false, default_is_cancelable: true)"#,
+    )
+    .unwrap();
+    app.world_mut()
+        .resource_mut::<Assets<AbilityDefinitionAsset>>()
+        .add(asset);
+    app.update();
+    app.update();
+
+    assert!(
+        app.world()
+            .resource::<AbilityRegistry>()
+            .get("mystery")
+            .is_none()
+    );
+}
+
+#[test]
+fn test_reload_with_unknown_tag_unregisters_previous_version() {
+    let mut app = create_app();
+    register_cooldown(&mut app);
+    wait_for_load(&mut app, "fireball");
+    app.update();
+    assert!(
+        app.world()
+            .resource::<AbilityRegistry>()
+            .get("fireball")
+            .is_some()
+    );
+
+    let handle = app.world().resource::<AbilityAssetHandles>().0[0].clone();
+    app.world_mut()
+        .resource_mut::<Assets<AbilityDefinitionAsset>>()
+        .get_mut(&handle)
+        .unwrap()
+        .definitions[0]
+        .ability_tags
+        .gameplay_tags
+        .push(GameplayTag::new("Ability.Unknown"));
+    app.update();
+    app.update();
+
+    assert!(
+        app.world()
+            .resource::<AbilityRegistry>()
+            .get("fireball")
+            .is_none()
+    );
+}
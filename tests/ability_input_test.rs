@@ -0,0 +1,127 @@
+//! Tests for routing input to abilities through `AbilityInputEvent`s and adapters.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{GasPlugin, abilities::*, core::OwnedTags};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use std::time::Duration;
+
+const PRIMARY: i32 = 1;
+
+#[derive(Resource, Default)]
+struct Releases(Vec<f32>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )))
+    .init_resource::<Releases>()
+    .add_observer(
+        |ev: On<AbilityInputReleasedEvent>, mut releases: ResMut<Releases>| {
+            releases.0.push(ev.held_secs);
+        },
+    );
+    app.update();
+    app
+}
+
+fn grant(app: &mut App, policy: AbilityInputPolicy) -> (Entity, Entity) {
+    app.world_mut()
+        .resource_mut::<AbilityRegistry>()
+        .register(AbilityDefinition::new("beam").with_input_policy(policy));
+    let owner = app.world_mut().spawn(OwnedTags::default()).id();
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("beam", 1).with_input_id(PRIMARY),
+            AbilityOwner(owner),
+        ))
+        .id();
+    (owner, spec)
+}
+
+fn input(app: &mut App, event: AbilityInputEvent) {
+    app.world_mut().trigger(event);
+    app.update();
+}
+
+fn is_active(app: &App, spec: Entity) -> bool {
+    app.world()
+        .get::<AbilityActiveState>(spec)
+        .is_some_and(|state| state.is_active)
+}
+
+#[test]
+fn test_button_adapter_activates_bound_ability() {
+    let mut app = create_app();
+    let (owner, spec) = grant(&mut app, AbilityInputPolicy::Pressed);
+    app.init_resource::<ButtonInput<KeyCode>>()
+        .add_ability_input_adapter(ButtonInputAdapter::new(owner).bind(KeyCode::KeyQ, PRIMARY));
+
+    app.update();
+    assert!(!is_active(&app, spec));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyQ);
+    app.update();
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .clear();
+    assert!(is_active(&app, spec));
+    assert!(app.world().get::<AbilityInputHeld>(spec).is_some());
+
+    // Releasing a `Pressed` ability reports the hold but leaves it running.
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(KeyCode::KeyQ);
+    app.update();
+    assert!(is_active(&app, spec));
+    assert!(app.world().get::<AbilityInputHeld>(spec).is_none());
+    assert_eq!(app.world().resource::<Releases>().0, [0.25]);
+}
+
+#[test]
+fn test_while_held_ends_on_release() {
+    let mut app = create_app();
+    let (owner, spec) = grant(&mut app, AbilityInputPolicy::WhileHeld);
+
+    input(&mut app, AbilityInputEvent::pressed(owner, PRIMARY));
+    assert!(is_active(&app, spec));
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(is_active(&app, spec));
+
+    input(&mut app, AbilityInputEvent::released(owner, PRIMARY));
+    assert!(!is_active(&app, spec));
+    assert_eq!(app.world().resource::<Releases>().0, [1.0]);
+}
+
+#[test]
+fn test_toggle_ends_on_second_press() {
+    let mut app = create_app();
+    let (owner, spec) = grant(&mut app, AbilityInputPolicy::Toggle);
+
+    input(&mut app, AbilityInputEvent::pressed(owner, PRIMARY));
+    input(&mut app, AbilityInputEvent::released(owner, PRIMARY));
+    assert!(is_active(&app, spec));
+
+    input(&mut app, AbilityInputEvent::pressed(owner, PRIMARY));
+    assert!(!is_active(&app, spec));
+}
+
+#[test]
+fn test_unbound_input_is_ignored() {
+    let mut app = create_app();
+    let (owner, spec) = grant(&mut app, AbilityInputPolicy::Pressed);
+
+    input(&mut app, AbilityInputEvent::pressed(owner, PRIMARY + 1));
+    assert!(!is_active(&app, spec));
+}
@@ -0,0 +1,153 @@
+//! Tests for the global ability tag relationships.
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::*,
+    core::{BlockedAbilityTags, OwnedTags},
+    effects::*,
+};
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use bevy_gameplay_tag::{GameplayTagsManager, GameplayTagsPlugin};
+
+#[derive(Resource, Default)]
+struct Failures(Vec<ActivationFailureReason>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .init_resource::<Failures>()
+    .add_observer(
+        |ev: On<AbilityActivationFailedEvent>, mut failures: ResMut<Failures>| {
+            failures.0.push(ev.reason);
+        },
+    );
+    app.update();
+
+    app.world_mut()
+        .run_system_once(
+            |tags_manager: Res<GameplayTagsManager>,
+             mut mapping: ResMut<AbilityTagRelationshipMapping>,
+             mut abilities: ResMut<AbilityRegistry>,
+             mut effects: ResMut<GameplayEffectRegistry>| {
+                mapping.add_relationship(
+                    AbilityTagRelationship::new(GameplayTag::new("Ability.Type.Melee"))
+                        .add_activation_blocked_tag(
+                            GameplayTag::new("State.Disarmed"),
+                            &tags_manager,
+                        ),
+                );
+                mapping.add_relationship(
+                    AbilityTagRelationship::new(GameplayTag::new("Ability.Type.Spell"))
+                        .add_cancel_abilities_with_tag(
+                            GameplayTag::new("Ability.Type.Melee"),
+                            &tags_manager,
+                        )
+                        .add_block_abilities_with_tag(
+                            GameplayTag::new("Ability.Type.Melee"),
+                            &tags_manager,
+                        ),
+                );
+                abilities.register(
+                    AbilityDefinition::new("slash")
+                        .with_instancing_policy(InstancingPolicy::InstancedPerActor)
+                        .add_ability_tag(GameplayTag::new("Ability.Type.Melee"), &tags_manager),
+                );
+                abilities.register(
+                    AbilityDefinition::new("fireball")
+                        .with_instancing_policy(InstancingPolicy::InstancedPerActor)
+                        .add_ability_tag(GameplayTag::new("Ability.Type.Spell"), &tags_manager),
+                );
+                effects.register(
+                    GameplayEffectDefinition::new("disarm")
+                        .with_duration_policy(DurationPolicy::Infinite)
+                        .grant_tag(GameplayTag::new("State.Disarmed"), &tags_manager),
+                );
+            },
+        )
+        .unwrap();
+    app
+}
+
+fn spawn_owner(app: &mut App) -> Entity {
+    app.world_mut()
+        .spawn((OwnedTags::default(), BlockedAbilityTags::default()))
+        .id()
+}
+
+fn spawn_spec(app: &mut App, owner: Entity, ability: &str) -> Entity {
+    app.world_mut()
+        .spawn((
+            AbilitySpec::new(ability, 1),
+            AbilityActiveState::default(),
+            AbilityOwner(owner),
+        ))
+        .id()
+}
+
+fn activate(app: &mut App, spec: Entity, owner: Entity) {
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+    app.update();
+}
+
+fn is_active(app: &App, spec: Entity) -> bool {
+    app.world()
+        .get::<AbilityActiveState>(spec)
+        .unwrap()
+        .is_active
+}
+
+#[test]
+fn test_relationship_blocks_activation_while_owner_has_tag() {
+    let mut app = create_app();
+    let owner = spawn_owner(&mut app);
+    let slash = spawn_spec(&mut app, owner, "slash");
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("disarm", owner));
+    app.update();
+
+    activate(&mut app, slash, owner);
+    assert!(!is_active(&app, slash));
+    assert_eq!(
+        app.world().resource::<Failures>().0,
+        vec![ActivationFailureReason::BlockedByTags]
+    );
+}
+
+#[test]
+fn test_relationship_cancels_and_blocks_abilities() {
+    let mut app = create_app();
+    let owner = spawn_owner(&mut app);
+    let slash = spawn_spec(&mut app, owner, "slash");
+    let fireball = spawn_spec(&mut app, owner, "fireball");
+
+    activate(&mut app, slash, owner);
+    assert!(is_active(&app, slash));
+
+    // Casting cancels the slash and blocks melee until the cast ends
+    activate(&mut app, fireball, owner);
+    assert!(is_active(&app, fireball));
+    assert!(!is_active(&app, slash));
+    activate(&mut app, slash, owner);
+    assert!(!is_active(&app, slash));
+    assert_eq!(
+        app.world().resource::<Failures>().0,
+        vec![ActivationFailureReason::BlockedByTags]
+    );
+
+    app.world_mut().trigger(EndAbilityEvent {
+        instance: None,
+        ability_spec: fireball,
+        owner,
+    });
+    app.update();
+    activate(&mut app, slash, owner);
+    assert!(is_active(&app, slash));
+}
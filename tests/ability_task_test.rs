@@ -7,12 +7,14 @@ use bevy::prelude::*;
 use bevy_gameplay_ability_system::{
     GasPlugin,
     abilities::{
-        AbilityActiveState, AbilityDefinition, AbilityOwner, AbilityRegistry, AbilitySpec,
-        AbilitySpecInstance, AbilityTask, ApplyEffectToTargetDataTask, AttributeComparison,
+        AbilityActivatedEvent, AbilityActiveState, AbilityDefinition, AbilityOwner,
+        AbilityRegistry, AbilitySpec, AbilitySpecInstance, AbilityTask, AnimationNotifyEvent,
+        AnimationNotifyReceivedEvent, ApplyEffectToTargetDataTask, AttributeComparison,
         GameplayAbilityTargetData, GameplayEvent, InputAction, InputPressedEvent, OverlapEvent,
         TaskCancelledEvent, TaskCompletedEvent, TaskState, TryActivateAbilityEvent,
-        WaitAttributeChangeTask, WaitDelayTask, WaitEffectAppliedTask, WaitEffectRemovedTask,
-        WaitGameplayEventTask, WaitInputPressTask, WaitOverlapTask, WaitTargetDataTask,
+        WaitAnimationNotifyTask, WaitAttributeChangeTask, WaitDelayTask, WaitEffectAppliedTask,
+        WaitEffectRemovedTask, WaitGameplayEventTask, WaitInputPressTask, WaitOverlapTask,
+        WaitTargetDataTask,
     },
     attributes::{AttributeData, AttributeMetadata, AttributeName, AttributeSetDefinition},
     effects::{
@@ -1190,3 +1192,134 @@ fn test_task_cancelled_when_ability_instance_removed() {
         "Task should be despawned after cancellation"
     );
 }
+
+#[test]
+fn test_wait_animation_notify_task() {
+    let mut app = setup_test_app();
+    let animations = Arc::new(Mutex::new(Vec::new()));
+    let recorded = animations.clone();
+    app.add_observer(move |trigger: On<AbilityActivatedEvent>| {
+        recorded
+            .lock()
+            .unwrap()
+            .push(trigger.event().animation_tag.clone());
+    });
+
+    let player = app
+        .world_mut()
+        .spawn((
+            bevy_gameplay_ability_system::core::OwnedTags::default(),
+            bevy_gameplay_ability_system::core::BlockedAbilityTags::default(),
+        ))
+        .id();
+    app.world_mut().resource_mut::<AbilityRegistry>().register(
+        AbilityDefinition::new("slash").with_animation_tag(GameplayTag::new("Animation.Slash")),
+    );
+    let ability_spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("slash", 1),
+            AbilityOwner(player),
+            AbilityActiveState::default(),
+        ))
+        .id();
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(ability_spec, player));
+    app.update();
+    assert_eq!(
+        *animations.lock().unwrap(),
+        vec![Some(GameplayTag::new("Animation.Slash"))]
+    );
+
+    let hit = GameplayTag::new("AnimNotify.Hit");
+    let task = app
+        .world_mut()
+        .spawn((
+            AbilityTask {
+                ability_instance: None,
+                ability_spec,
+                owner: player,
+            },
+            WaitAnimationNotifyTask::new(hit.clone()),
+            TaskState::Running,
+        ))
+        .id();
+
+    // Other notifies and other entities' notifies are ignored.
+    let other = app.world_mut().spawn_empty().id();
+    app.world_mut().trigger(AnimationNotifyEvent {
+        entity: player,
+        notify_tag: GameplayTag::new("AnimNotify.Footstep"),
+    });
+    app.world_mut().trigger(AnimationNotifyEvent {
+        entity: other,
+        notify_tag: hit.clone(),
+    });
+    app.update();
+    assert_eq!(
+        app.world().get::<TaskState>(task),
+        Some(&TaskState::Running)
+    );
+
+    app.world_mut().trigger(AnimationNotifyEvent {
+        entity: player,
+        notify_tag: hit,
+    });
+    app.update();
+    assert!(
+        app.world()
+            .resource::<TaskEvents>()
+            .completed
+            .lock()
+            .unwrap()
+            .contains(&task)
+    );
+    assert!(app.world().get_entity(task).is_err());
+}
+
+#[test]
+fn test_repeating_wait_animation_notify_task() {
+    let mut app = setup_test_app();
+    let received = Arc::new(Mutex::new(0));
+    let counter = received.clone();
+    app.add_observer(move |_: On<AnimationNotifyReceivedEvent>| {
+        *counter.lock().unwrap() += 1;
+    });
+
+    let owner = app.world_mut().spawn_empty().id();
+    let spec = app.world_mut().spawn(AbilitySpec::new("flurry", 1)).id();
+    let hit = GameplayTag::new("AnimNotify.Hit");
+    let task = app
+        .world_mut()
+        .spawn((
+            AbilityTask {
+                ability_instance: None,
+                ability_spec: spec,
+                owner,
+            },
+            WaitAnimationNotifyTask::new(hit.clone()).with_only_trigger_once(false),
+            TaskState::Running,
+        ))
+        .id();
+
+    for _ in 0..3 {
+        app.world_mut().trigger(AnimationNotifyEvent {
+            entity: owner,
+            notify_tag: hit.clone(),
+        });
+    }
+    app.update();
+
+    assert_eq!(*received.lock().unwrap(), 3);
+    assert_eq!(
+        app.world()
+            .get::<WaitAnimationNotifyTask>(task)
+            .unwrap()
+            .received,
+        3
+    );
+    assert_eq!(
+        app.world().get::<TaskState>(task),
+        Some(&TaskState::Running)
+    );
+}
@@ -0,0 +1,136 @@
+//! Tests for the usage statistics of ability specs.
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::*,
+    attributes::*,
+    core::{BlockedAbilityTags, OwnedTags},
+    effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use std::time::Duration;
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.update();
+
+    app.world_mut().resource_mut::<AbilityRegistry>().register(
+        AbilityDefinition::new("drain_life")
+            .with_instancing_policy(InstancingPolicy::InstancedPerActor),
+    );
+    let mut effects = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    effects.register(
+        GameplayEffectDefinition::new("drain").add_modifier(ModifierInfo::new(
+            "Health",
+            ModifierOperation::AddBase,
+            MagnitudeCalculation::scalar(-15.0),
+        )),
+    );
+    effects.register(
+        GameplayEffectDefinition::new("leech").add_modifier(ModifierInfo::new(
+            "Health",
+            ModifierOperation::AddBase,
+            MagnitudeCalculation::scalar(10.0),
+        )),
+    );
+    app
+}
+
+fn spawn_character(app: &mut App) -> Entity {
+    let character = app
+        .world_mut()
+        .spawn((OwnedTags::default(), BlockedAbilityTags::default()))
+        .id();
+    app.world_mut().spawn((
+        AttributeName::new("Health"),
+        AttributeData::new(100.0),
+        ChildOf(character),
+    ));
+    character
+}
+
+fn usage_of(app: &mut App, owner: Entity) -> Option<AbilityUsageStats> {
+    app.world_mut()
+        .run_system_once(move |usage: AbilityUsage| {
+            usage.of_ability(owner, &"drain_life".into()).cloned()
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_usage_is_tracked_on_specs_with_stats() {
+    let mut app = create_app();
+    let caster = spawn_character(&mut app);
+    let target = spawn_character(&mut app);
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("drain_life", 1),
+            AbilityActiveState::default(),
+            AbilityOwner(caster),
+            AbilityUsageStats::default(),
+        ))
+        .id();
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, caster));
+    app.update();
+    let stats = usage_of(&mut app, caster).unwrap();
+    assert_eq!(stats.activation_count, 1);
+
+    // Damage and healing count when the spec instigated the effect
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("drain", target).with_instigator(spec));
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("leech", caster).with_instigator(spec));
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("drain", target).with_source(caster));
+    app.update();
+    let active_time = stats.active_time;
+    let stats = usage_of(&mut app, caster).unwrap();
+    assert_eq!(stats.damage_dealt, 15.0);
+    assert_eq!(stats.healing_done, 10.0);
+    assert!((stats.active_time - active_time - 0.1).abs() < 1e-4);
+
+    // Time stops counting once the ability ends
+    app.world_mut().trigger(EndAbilityEvent {
+        instance: None,
+        ability_spec: spec,
+        owner: caster,
+    });
+    app.update();
+    let active_time = usage_of(&mut app, caster).unwrap().active_time;
+    app.update();
+    assert_eq!(usage_of(&mut app, caster).unwrap().active_time, active_time);
+}
+
+#[test]
+fn test_specs_without_stats_are_not_tracked() {
+    let mut app = create_app();
+    let caster = spawn_character(&mut app);
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("drain_life", 1),
+            AbilityActiveState::default(),
+            AbilityOwner(caster),
+        ))
+        .id();
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, caster));
+    app.update();
+    assert!(app.world().get::<AbilityUsageStats>(spec).is_none());
+    assert_eq!(usage_of(&mut app, caster), None);
+}
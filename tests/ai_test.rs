@@ -0,0 +1,208 @@
+//! Tests for the AI scorers and actions.
+
+#![cfg(feature = "ai")]
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::*,
+    attributes::{AttributeData, AttributeName},
+    core::OwnedTags,
+    effects::*,
+};
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use bevy_gameplay_tag::{GameplayTagContainer, GameplayTagsManager, GameplayTagsPlugin};
+use std::time::Duration;
+
+/// Stands in for a game's AI components.
+#[derive(Component)]
+struct Brain {
+    actor: Entity,
+    state: GasActionState,
+}
+
+#[derive(Resource, Default)]
+struct Scores(Vec<f32>);
+
+fn step_actions(
+    ai: GasAi,
+    mut commands: Commands,
+    mut activations: Query<(&mut Brain, &mut ActivateAbilityAction)>,
+    mut waits: Query<(&mut Brain, &WaitForCooldownAction), Without<ActivateAbilityAction>>,
+) {
+    for (mut brain, mut action) in &mut activations {
+        brain.state = action.step(&ai, &mut commands, brain.actor, brain.state);
+    }
+    for (mut brain, action) in &mut waits {
+        brain.state = action.step(&ai, brain.actor, brain.state);
+    }
+}
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+        GasAiPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )))
+    .add_systems(Update, step_actions);
+    app.update();
+
+    let mut buffed = GameplayTagContainer::default();
+    buffed.add_tag(
+        GameplayTag::new("State.Buffed"),
+        app.world().resource::<GameplayTagsManager>(),
+    );
+    let mut rage = GameplayEffectDefinition::new("rage")
+        .with_duration_policy(DurationPolicy::HasDuration)
+        .with_duration(10.0);
+    rage.granted_tags = buffed;
+    let mut effects = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    effects.register(rage);
+    effects.register(
+        GameplayEffectDefinition::new("fireball_cooldown")
+            .with_duration_policy(DurationPolicy::HasDuration)
+            .with_duration(3.0),
+    );
+    app.world_mut()
+        .resource_mut::<AbilityRegistry>()
+        .register(AbilityDefinition::new("fireball").with_cooldown_effect("fireball_cooldown"));
+    app
+}
+
+fn spawn_actor(app: &mut App) -> (Entity, Entity) {
+    let actor = app.world_mut().spawn(OwnedTags::default()).id();
+    for (name, value) in [("Health", 25.0), ("MaxHealth", 100.0)] {
+        app.world_mut().spawn((
+            AttributeName::new(name),
+            AttributeData::new(value),
+            ChildOf(actor),
+        ));
+    }
+    let spec = app
+        .world_mut()
+        .spawn((AbilitySpec::new("fireball", 1), AbilityOwner(actor)))
+        .id();
+    (actor, spec)
+}
+
+fn state(app: &App, brain: Entity) -> GasActionState {
+    app.world().get::<Brain>(brain).unwrap().state
+}
+
+#[test]
+fn test_scorers_read_attributes_tags_and_effects() {
+    let mut app = create_app();
+    let (actor, _) = spawn_actor(&mut app);
+    app.init_resource::<Scores>().add_systems(
+        Update,
+        move |ai: GasAi, mut scores: ResMut<Scores>| {
+            scores.0 = vec![
+                AttributePercentScorer::new("Health")
+                    .with_max("MaxHealth")
+                    .score(&ai, actor),
+                AttributePercentScorer::new("Health")
+                    .with_max("MaxHealth")
+                    .inverted()
+                    .score(&ai, actor),
+                HasTagScorer::new(GameplayTag::new("State.Buffed"))
+                    .with_score(0.5)
+                    .score(&ai, actor),
+                EffectActiveScorer::new("rage").score(&ai, actor),
+            ];
+        },
+    );
+
+    app.update();
+    assert_eq!(app.world().resource::<Scores>().0, [0.25, 0.75, 0.0, 0.0]);
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("rage", actor));
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<Scores>().0, [0.25, 0.75, 0.5, 1.0]);
+}
+
+#[test]
+fn test_activate_then_wait_for_cooldown() {
+    let mut app = create_app();
+    let (actor, spec) = spawn_actor(&mut app);
+    let cast = app
+        .world_mut()
+        .spawn((
+            Brain {
+                actor,
+                state: GasActionState::Requested,
+            },
+            ActivateAbilityAction::new("fireball"),
+        ))
+        .id();
+
+    app.update();
+    assert_eq!(state(&app, cast), GasActionState::Executing);
+    for _ in 0..3 {
+        app.update();
+    }
+    // Runs for as long as the ability does.
+    assert_eq!(state(&app, cast), GasActionState::Executing);
+    app.world_mut().trigger(EndAbilityEvent {
+        instance: None,
+        ability_spec: spec,
+        owner: actor,
+    });
+    app.update();
+    assert_eq!(state(&app, cast), GasActionState::Success);
+
+    // Cooling down, so a second cast fails without activating.
+    let recast = app
+        .world_mut()
+        .spawn((
+            Brain {
+                actor,
+                state: GasActionState::Requested,
+            },
+            ActivateAbilityAction::new("fireball"),
+        ))
+        .id();
+    let wait = app
+        .world_mut()
+        .spawn((
+            Brain {
+                actor,
+                state: GasActionState::Requested,
+            },
+            WaitForCooldownAction::new("fireball"),
+        ))
+        .id();
+    app.update();
+    assert_eq!(state(&app, recast), GasActionState::Failure);
+    assert_eq!(state(&app, wait), GasActionState::Executing);
+
+    for _ in 0..12 {
+        app.update();
+    }
+    assert_eq!(state(&app, wait), GasActionState::Success);
+}
+
+#[test]
+fn test_unknown_ability_fails() {
+    let mut app = create_app();
+    let (actor, _) = spawn_actor(&mut app);
+    let cast = app
+        .world_mut()
+        .spawn((
+            Brain {
+                actor,
+                state: GasActionState::Requested,
+            },
+            ActivateAbilityAction::new("frostbolt"),
+        ))
+        .id();
+    app.update();
+    assert_eq!(state(&app, cast), GasActionState::Failure);
+}
@@ -163,3 +163,140 @@ fn test_add_base_modifier_recomputes_from_base_value() {
         "AddBase modifiers should contribute during aggregation without mutating stored base_value"
     );
 }
+
+#[test]
+fn test_modifier_index_follows_modifier_changes() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("attack_buff")
+                .with_duration(5.0)
+                .add_modifier(ModifierInfo::new(
+                    "AttackPower",
+                    ModifierOperation::AddCurrent,
+                    MagnitudeCalculation::scalar(5.0),
+                )),
+        );
+
+    let owners: Vec<Entity> = (0..3)
+        .map(|_| {
+            let mut commands = app.world_mut().commands();
+            let owner = commands.spawn_empty().id();
+            TestAttributeSet::create_attributes(&mut commands, owner);
+            owner
+        })
+        .collect();
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("attack_buff", owners[1]));
+    app.update();
+
+    let attack_power = |app: &mut App, owner: Entity| {
+        let attr = find_attribute_entity(app.world_mut(), owner, "AttackPower");
+        app.world()
+            .get::<AttributeData>(attr)
+            .unwrap()
+            .current_value
+    };
+    assert_eq!(attack_power(&mut app, owners[0]), 10.0);
+    assert_eq!(attack_power(&mut app, owners[1]), 15.0);
+    assert_eq!(app.world().resource::<ModifierIndex>().len(), 1);
+
+    // Mutating a modifier in place is picked up on the next aggregation
+    let modifier = app
+        .world_mut()
+        .query_filtered::<Entity, With<AttributeModifier>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut()
+        .get_mut::<AttributeModifier>(modifier)
+        .unwrap()
+        .magnitude = 8.0;
+    app.update();
+    assert_eq!(attack_power(&mut app, owners[1]), 18.0);
+
+    // Despawning it resets the attribute to its base value
+    app.world_mut().despawn(modifier);
+    app.update();
+    assert_eq!(attack_power(&mut app, owners[1]), 10.0);
+    assert!(app.world().resource::<ModifierIndex>().is_empty());
+    assert_eq!(attack_power(&mut app, owners[2]), 10.0);
+}
+
+#[test]
+fn test_aggregation_leaves_attributes_at_rest_unchanged() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("attack_buff")
+                .with_duration(5.0)
+                .add_modifier(ModifierInfo::new(
+                    "AttackPower",
+                    ModifierOperation::AddCurrent,
+                    MagnitudeCalculation::scalar(5.0),
+                )),
+        );
+
+    let owner = {
+        let mut commands = app.world_mut().commands();
+        let owner = commands.spawn_empty().id();
+        TestAttributeSet::create_attributes(&mut commands, owner);
+        owner
+    };
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("attack_buff", owner));
+    app.update();
+    app.update();
+
+    let attack_power = find_attribute_entity(app.world_mut(), owner, "AttackPower");
+    let health = find_attribute_entity(app.world_mut(), owner, "Health");
+    assert!(app.world().get::<AttributeDirty>(attack_power).is_none());
+    let changed_tick = |app: &App, attribute: Entity| {
+        app.world()
+            .entity(attribute)
+            .get_change_ticks::<AttributeData>()
+            .unwrap()
+            .changed
+    };
+    let attack_power_tick = changed_tick(&app, attack_power);
+    let health_tick = changed_tick(&app, health);
+
+    app.update();
+    app.update();
+    assert_eq!(changed_tick(&app, attack_power), attack_power_tick);
+    assert_eq!(changed_tick(&app, health), health_tick);
+
+    // Removing the effect re-aggregates only the attribute it modified
+    let effect = app
+        .world_mut()
+        .query_filtered::<Entity, With<ActiveGameplayEffect>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut().despawn(effect);
+    assert!(app.world().get::<AttributeDirty>(attack_power).is_some());
+    app.update();
+    assert!(app.world().get::<AttributeDirty>(attack_power).is_none());
+    assert_eq!(
+        app.world()
+            .get::<AttributeData>(attack_power)
+            .unwrap()
+            .current_value,
+        10.0
+    );
+    assert_eq!(changed_tick(&app, health), health_tick);
+}
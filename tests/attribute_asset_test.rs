@@ -0,0 +1,114 @@
+//! Tests that data-defined attribute sets are created on entities, and that
+//! derived and regenerating attributes update.
+
+#![cfg(feature = "attribute_assets")]
+
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{GasPlugin, attributes::*};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use std::time::Duration;
+
+fn create_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+        AttributeAssetPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        500,
+    )));
+    app.update();
+
+    let handle = app
+        .world()
+        .resource::<AssetServer>()
+        .load("attributes/character.attributes.ron");
+    let owner = app.world_mut().spawn(AttributeSetHandle(handle)).id();
+    for _ in 0..200 {
+        app.update();
+        if attribute(&mut app, owner, "AttackPower").is_some() {
+            return (app, owner);
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    panic!("attribute set was never created");
+}
+
+fn attribute(app: &mut App, owner: Entity, name: &str) -> Option<(Entity, AttributeData)> {
+    let mut query = app
+        .world_mut()
+        .query::<(Entity, &AttributeName, &AttributeData, &ChildOf)>();
+    query
+        .iter(app.world())
+        .find(|(_, attribute_name, _, child_of)| {
+            child_of.parent() == owner && attribute_name.as_str() == name
+        })
+        .map(|(entity, _, data, _)| (entity, *data))
+}
+
+#[test]
+fn test_attributes_are_created_and_derived() {
+    let (mut app, owner) = create_app();
+    app.update();
+
+    assert_eq!(
+        attribute(&mut app, owner, "Strength").unwrap().1.base_value,
+        10.0
+    );
+    assert_eq!(
+        attribute(&mut app, owner, "AttackPower")
+            .unwrap()
+            .1
+            .current_value,
+        25.0
+    );
+
+    let (strength, _) = attribute(&mut app, owner, "Strength").unwrap();
+    app.world_mut()
+        .get_mut::<AttributeData>(strength)
+        .unwrap()
+        .set_base_value(20.0);
+    app.update();
+    app.update();
+    assert_eq!(
+        attribute(&mut app, owner, "AttackPower")
+            .unwrap()
+            .1
+            .current_value,
+        45.0
+    );
+    assert!(
+        app.world()
+            .resource::<AttributeSetRegistry>()
+            .has_attribute("HealthRegen")
+    );
+}
+
+#[test]
+fn test_health_regenerates_up_to_max() {
+    let (mut app, owner) = create_app();
+    let (health, _) = attribute(&mut app, owner, "Health").unwrap();
+    app.world_mut()
+        .get_mut::<AttributeData>(health)
+        .unwrap()
+        .set_base_value(90.0);
+
+    // One second of quarter-second frames at 2 per second.
+    for _ in 0..4 {
+        app.update();
+    }
+    let health_value = attribute(&mut app, owner, "Health").unwrap().1.base_value;
+    assert!((health_value - 92.0).abs() < 1e-3, "{health_value}");
+
+    for _ in 0..40 {
+        app.update();
+    }
+    let data = attribute(&mut app, owner, "Health").unwrap().1;
+    assert_eq!(data.base_value, 100.0);
+    assert_eq!(data.current_value, 100.0);
+}
@@ -0,0 +1,101 @@
+//! Tests for attribute modifier breakdowns.
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{GasPlugin, attributes::*, core::OwnedTags, effects::*};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.update();
+
+    let mut registry = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    registry.register(
+        GameplayEffectDefinition::new("might")
+            .with_duration_policy(DurationPolicy::Infinite)
+            .add_modifier(ModifierInfo::new(
+                "Attack",
+                ModifierOperation::AddCurrent,
+                MagnitudeCalculation::scalar(10.0),
+            )),
+    );
+    registry.register(
+        GameplayEffectDefinition::new("war_cry")
+            .with_duration_policy(DurationPolicy::Infinite)
+            .add_modifier(ModifierInfo::new(
+                "Attack",
+                ModifierOperation::MultiplyAdditive,
+                MagnitudeCalculation::scalar(0.25),
+            )),
+    );
+    app
+}
+
+fn explain(app: &mut App, owner: Entity, name: &'static str) -> Option<AttributeBreakdown> {
+    app.world_mut()
+        .run_system_once(move |breakdowns: AttributeBreakdowns| {
+            breakdowns.explain_attribute(owner, name)
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_breakdown_lists_base_modifiers_and_final_value() {
+    let mut app = create_app();
+    let actor = app.world_mut().spawn(OwnedTags::default()).id();
+    app.world_mut().spawn((
+        AttributeName::new("Attack"),
+        AttributeData::new(40.0),
+        ChildOf(actor),
+    ));
+    // Applied in reverse evaluation order.
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("war_cry", actor));
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("might", actor));
+    app.update();
+    app.update();
+
+    let breakdown = explain(&mut app, actor, "Attack").unwrap();
+    assert_eq!(breakdown.base_value, 40.0);
+    assert_eq!(breakdown.final_value, 62.5);
+    assert_eq!(breakdown.evaluate(), 62.5);
+    let operations: Vec<_> = breakdown
+        .modifiers
+        .iter()
+        .map(|modifier| (modifier.effect_id.clone().unwrap(), modifier.operation))
+        .collect();
+    assert_eq!(
+        operations,
+        vec![
+            ("might".into(), ModifierOperation::AddCurrent),
+            ("war_cry".into(), ModifierOperation::MultiplyAdditive),
+        ]
+    );
+    assert_eq!(
+        breakdown.to_string(),
+        "Attack 62.5 = 40 base + 10 (might) + 25% (war_cry)"
+    );
+}
+
+#[test]
+fn test_breakdown_of_unmodified_and_missing_attributes() {
+    let mut app = create_app();
+    let actor = app.world_mut().spawn(OwnedTags::default()).id();
+    app.world_mut().spawn((
+        AttributeName::new("Attack"),
+        AttributeData::new(40.0),
+        ChildOf(actor),
+    ));
+    app.update();
+
+    let breakdown = explain(&mut app, actor, "Attack").unwrap();
+    assert!(breakdown.modifiers.is_empty());
+    assert_eq!(breakdown.to_string(), "Attack 40 = 40 base");
+    assert!(explain(&mut app, actor, "Defense").is_none());
+}
@@ -0,0 +1,113 @@
+//! Tests for the attribute captures declared by custom calculations.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{GasPlugin, attributes::*, effects::*};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use std::time::Duration;
+
+/// Burn damage from the caster's spell power at cast time, reduced by the
+/// target's current fire resistance.
+struct BurnCalculation;
+
+impl CustomMagnitudeCalculation for BurnCalculation {
+    fn calculate(&self, ctx: &CalculationContext) -> f32 {
+        let spell_power = ctx
+            .get_source_attribute(&"SpellPower".into())
+            .unwrap_or(0.0);
+        let resistance = ctx
+            .get_target_attribute(&"FireResistance".into())
+            .unwrap_or(0.0);
+        -(spell_power - resistance)
+    }
+
+    fn relevant_attributes_to_capture(&self) -> Vec<AttributeCaptureDefinition> {
+        vec![
+            AttributeCaptureDefinition::snapshot_source("SpellPower"),
+            AttributeCaptureDefinition::dynamic_target("FireResistance"),
+        ]
+    }
+}
+
+#[derive(Resource, Default)]
+struct Executions(Vec<f32>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)))
+    .init_resource::<Executions>()
+    .add_observer(
+        |ev: On<GameplayEffectExecutedEvent>, mut executions: ResMut<Executions>| {
+            executions.0.push(ev.magnitudes[0].1);
+        },
+    );
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<CustomCalculationRegistry>()
+        .register("Burn", Box::new(BurnCalculation));
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("burn")
+                .with_duration(10.0)
+                .with_period(1.0)
+                .add_modifier(ModifierInfo::new(
+                    "Health",
+                    ModifierOperation::AddCurrent,
+                    MagnitudeCalculation::custom("Burn"),
+                )),
+        );
+    app
+}
+
+fn spawn_attribute(app: &mut App, owner: Entity, name: &str, value: f32) -> Entity {
+    app.world_mut()
+        .spawn((
+            AttributeName::new(name),
+            AttributeData::new(value),
+            ChildOf(owner),
+        ))
+        .id()
+}
+
+fn set_attribute(app: &mut App, attribute: Entity, value: f32) {
+    *app.world_mut().get_mut::<AttributeData>(attribute).unwrap() = AttributeData::new(value);
+}
+
+#[test]
+fn test_snapshot_captures_keep_application_values() {
+    let mut app = create_app();
+    let caster = app.world_mut().spawn_empty().id();
+    let spell_power = spawn_attribute(&mut app, caster, "SpellPower", 10.0);
+    let target = app.world_mut().spawn_empty().id();
+    spawn_attribute(&mut app, target, "Health", 100.0);
+    let resistance = spawn_attribute(&mut app, target, "FireResistance", 2.0);
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("burn", target).with_source(caster));
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<Executions>().0, [-8.0]);
+
+    let mut effects = app
+        .world_mut()
+        .query::<(&ActiveGameplayEffect, &EffectAttributeSnapshots)>();
+    let (_, snapshots) = effects.single(app.world()).unwrap();
+    assert_eq!(
+        snapshots.0.get(&(caster, "SpellPower".into())).copied(),
+        Some(10.0)
+    );
+
+    // The spell power stays as it was cast, the resistance is read live
+    set_attribute(&mut app, spell_power, 50.0);
+    set_attribute(&mut app, resistance, 4.0);
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<Executions>().0, [-8.0, -6.0]);
+}
@@ -0,0 +1,98 @@
+//! Tests for syncing attributes into components.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{GasPlugin, attributes::*, effects::*};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+
+#[derive(Component, Default)]
+struct Movement {
+    speed: f32,
+}
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .sync_attribute_to_component::<Movement>("MoveSpeed", |movement, value| {
+        movement.speed = value;
+    })
+    .sync_attribute_to_component::<Transform>("Scale", |transform, value| {
+        transform.scale = Vec3::splat(value);
+    });
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("haste")
+                .with_duration_policy(DurationPolicy::Infinite)
+                .add_modifier(ModifierInfo::new(
+                    "MoveSpeed",
+                    ModifierOperation::MultiplyAdditive,
+                    MagnitudeCalculation::scalar(1.0),
+                )),
+        );
+    app
+}
+
+fn spawn_attribute(app: &mut App, owner: Entity, name: &str, value: f32) -> Entity {
+    app.world_mut()
+        .spawn((
+            AttributeName::new(name),
+            AttributeData::new(value),
+            ChildOf(owner),
+        ))
+        .id()
+}
+
+#[test]
+fn test_changed_attributes_are_written_to_components() {
+    let mut app = create_app();
+    let owner = app
+        .world_mut()
+        .spawn((Movement::default(), Transform::default()))
+        .id();
+    spawn_attribute(&mut app, owner, "MoveSpeed", 5.0);
+    let scale = spawn_attribute(&mut app, owner, "Scale", 2.0);
+    app.update();
+    assert_eq!(app.world().get::<Movement>(owner).unwrap().speed, 5.0);
+    assert_eq!(
+        app.world().get::<Transform>(owner).unwrap().scale,
+        Vec3::splat(2.0)
+    );
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("haste", owner));
+    app.update();
+    app.update();
+    assert_eq!(app.world().get::<Movement>(owner).unwrap().speed, 10.0);
+
+    app.world_mut()
+        .get_mut::<AttributeData>(scale)
+        .unwrap()
+        .base_value = 3.0;
+    app.update();
+    app.update();
+    assert_eq!(
+        app.world().get::<Transform>(owner).unwrap().scale,
+        Vec3::splat(3.0)
+    );
+}
+
+#[test]
+fn test_component_added_later_gets_current_value() {
+    let mut app = create_app();
+    let owner = app.world_mut().spawn_empty().id();
+    spawn_attribute(&mut app, owner, "MoveSpeed", 7.0);
+    app.update();
+    app.update();
+
+    app.world_mut()
+        .entity_mut(owner)
+        .insert(Movement::default());
+    app.update();
+    assert_eq!(app.world().get::<Movement>(owner).unwrap().speed, 7.0);
+}
@@ -0,0 +1,184 @@
+//! Tests for charge-up abilities firing on input release.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin, abilities::*, attributes::*, core::OwnedTags, effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use std::time::Duration;
+
+const PRIMARY: i32 = 1;
+
+#[derive(Resource)]
+struct Target(Entity);
+
+#[derive(Resource, Default)]
+struct Released(Vec<AbilityChargeReleasedEvent>);
+
+fn fire(
+    ev: On<AbilityChargeReleasedEvent>,
+    mut commands: Commands,
+    target: Res<Target>,
+    mut released: ResMut<Released>,
+) {
+    let event = ev.event();
+    commands.trigger(event.effect("arrow", target.0));
+    commands.trigger(EndAbilityEvent {
+        instance: event.instance,
+        ability_spec: event.ability_spec,
+        owner: event.owner,
+    });
+    released.0.push(event.clone());
+}
+
+fn create_app() -> (App, Entity, Entity, Entity) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )))
+    .init_resource::<Released>()
+    .add_observer(fire);
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("arrow").add_modifier(ModifierInfo::new(
+                "Damage",
+                ModifierOperation::AddBase,
+                MagnitudeCalculation::set_by_caller(GameplayTag::new(CHARGE_MAGNITUDE_TAG)),
+            )),
+        );
+    app.world_mut().resource_mut::<AbilityRegistry>().register(
+        AbilityDefinition::new("charged_shot").with_charge(
+            AbilityCharge::new(1.0)
+                .with_magnitude_range(20.0, 80.0)
+                .with_charging_tag(GameplayTag::new("State.Charging"))
+                .with_fully_charged_tag(GameplayTag::new("State.FullyCharged")),
+        ),
+    );
+
+    let owner = app.world_mut().spawn(OwnedTags::default()).id();
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("charged_shot", 1).with_input_id(PRIMARY),
+            AbilityOwner(owner),
+        ))
+        .id();
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    let damage = app
+        .world_mut()
+        .spawn((
+            AttributeName::new("Damage"),
+            AttributeData::new(0.0),
+            ChildOf(target),
+        ))
+        .id();
+    app.insert_resource(Target(target));
+    (app, owner, spec, damage)
+}
+
+fn input(app: &mut App, event: AbilityInputEvent) {
+    app.world_mut().trigger(event);
+    app.update();
+}
+
+fn has_tag(app: &App, owner: Entity, tag: &str) -> bool {
+    app.world()
+        .get::<OwnedTags>(owner)
+        .unwrap()
+        .0
+        .has_matching_gameplay_tag(&GameplayTag::new(tag))
+}
+
+fn is_active(app: &App, spec: Entity) -> bool {
+    app.world()
+        .get::<AbilityActiveState>(spec)
+        .is_some_and(|state| state.is_active)
+}
+
+#[test]
+fn test_release_fires_with_magnitude_scaled_by_charge() {
+    let (mut app, owner, spec, damage) = create_app();
+
+    input(&mut app, AbilityInputEvent::pressed(owner, PRIMARY));
+    assert!(is_active(&app, spec));
+    assert!(has_tag(&app, owner, "State.Charging"));
+    app.update();
+    input(&mut app, AbilityInputEvent::released(owner, PRIMARY));
+    app.update();
+
+    let released = &app.world().resource::<Released>().0;
+    assert_eq!(released.len(), 1);
+    let event = &released[0];
+    assert_eq!(event.ability_spec, spec);
+    assert!(event.charge > 0.0 && event.charge < 1.0);
+    assert_eq!(event.magnitude, 20.0 + 60.0 * event.charge);
+    let magnitude = event.magnitude;
+    assert_eq!(
+        app.world().get::<AttributeData>(damage).unwrap().base_value,
+        magnitude
+    );
+    assert!(!is_active(&app, spec));
+    assert!(!has_tag(&app, owner, "State.Charging"));
+}
+
+#[test]
+fn test_charge_caps_at_max_and_grants_fully_charged_tag() {
+    let (mut app, owner, spec, damage) = create_app();
+
+    input(&mut app, AbilityInputEvent::pressed(owner, PRIMARY));
+    for _ in 0..8 {
+        app.update();
+    }
+    assert!(has_tag(&app, owner, "State.FullyCharged"));
+
+    input(&mut app, AbilityInputEvent::released(owner, PRIMARY));
+    app.update();
+    let released = &app.world().resource::<Released>().0;
+    assert_eq!(released[0].charged_secs, 1.0);
+    assert_eq!(released[0].charge, 1.0);
+    assert_eq!(released[0].magnitude, 80.0);
+    assert_eq!(
+        app.world().get::<AttributeData>(damage).unwrap().base_value,
+        80.0
+    );
+    assert!(!is_active(&app, spec));
+    assert!(!has_tag(&app, owner, "State.Charging"));
+    assert!(!has_tag(&app, owner, "State.FullyCharged"));
+}
+
+#[test]
+fn test_interrupted_charge_is_cancelled() {
+    let (mut app, owner, spec, damage) = create_app();
+
+    input(&mut app, AbilityInputEvent::pressed(owner, PRIMARY));
+    app.update();
+    app.world_mut().trigger(CancelAbilityEvent {
+        instance: None,
+        ability_spec: spec,
+        owner,
+    });
+    app.update();
+    app.update();
+    assert!(!is_active(&app, spec));
+    assert!(!has_tag(&app, owner, "State.Charging"));
+    let mut tasks = app.world_mut().query::<&ChargeTask>();
+    assert_eq!(tasks.iter(app.world()).count(), 0);
+
+    input(&mut app, AbilityInputEvent::released(owner, PRIMARY));
+    app.update();
+    assert!(app.world().resource::<Released>().0.is_empty());
+    assert_eq!(
+        app.world().get::<AttributeData>(damage).unwrap().base_value,
+        0.0
+    );
+}
@@ -0,0 +1,138 @@
+//! Tests for the combat log events of effect executions.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{GasPlugin, abilities::*, attributes::*, effects::*};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+
+#[derive(Resource, Default)]
+struct CombatLog {
+    damage: Vec<DamageDealtEvent>,
+    healing: Vec<HealingDoneEvent>,
+}
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .init_resource::<CombatLog>()
+    .add_observer(|ev: On<DamageDealtEvent>, mut log: ResMut<CombatLog>| {
+        log.damage.push(ev.event().clone());
+    })
+    .add_observer(|ev: On<HealingDoneEvent>, mut log: ResMut<CombatLog>| {
+        log.healing.push(ev.event().clone());
+    });
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<DamageCalculationRegistry>()
+        .register_mitigation("Armor", ArmorMitigation::default());
+    let mut registry = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    registry.register(
+        GameplayEffectDefinition::new("strike")
+            .add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddBase,
+                MagnitudeCalculation::scalar(-40.0),
+            ))
+            .add_modifier(ModifierInfo::new(
+                "Rage",
+                ModifierOperation::MultiplyAdditive,
+                MagnitudeCalculation::scalar(0.5),
+            ))
+            .with_damage(
+                DamageCalculation::new()
+                    .with_damage_attribute("Health")
+                    .with_crit()
+                    .with_mitigation("Armor"),
+            ),
+    );
+    registry.register(
+        GameplayEffectDefinition::new("renew")
+            .with_duration(10.0)
+            .with_period(1.0)
+            .add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddCurrent,
+                MagnitudeCalculation::scalar(5.0),
+            )),
+    );
+    app
+}
+
+fn spawn_with(app: &mut App, attributes: &[(&str, f32)]) -> Entity {
+    let owner = app.world_mut().spawn_empty().id();
+    for (name, value) in attributes {
+        app.world_mut().spawn((
+            AttributeName::new(*name),
+            AttributeData::new(*value),
+            ChildOf(owner),
+        ));
+    }
+    owner
+}
+
+#[test]
+fn test_damage_reports_crit_and_mitigation() {
+    let mut app = create_app();
+    let source = spawn_with(&mut app, &[("CritChance", 1.0), ("CritDamage", 2.0)]);
+    let target = spawn_with(
+        &mut app,
+        &[("Health", 100.0), ("Armor", 100.0), ("Rage", 10.0)],
+    );
+    let spec = app
+        .world_mut()
+        .spawn((AbilitySpec::new("strike", 1), AbilityOwner(source)))
+        .id();
+
+    app.world_mut().trigger(
+        ApplyGameplayEffectEvent::new("strike", target)
+            .with_source(source)
+            .with_instigator(spec),
+    );
+    app.update();
+
+    let log = app.world().resource::<CombatLog>();
+    // The multiplier on Rage is neither damage nor healing.
+    assert!(log.healing.is_empty());
+    assert_eq!(
+        log.damage,
+        [DamageDealtEvent {
+            source: Some(source),
+            target,
+            ability: Some(spec),
+            effect: None,
+            effect_id: "strike".into(),
+            attribute: "Health".into(),
+            pre_mitigation: 80.0,
+            post_mitigation: 40.0,
+            critical: true,
+        }]
+    );
+    assert_eq!(log.damage[0].mitigated(), 40.0);
+}
+
+#[test]
+fn test_periodic_healing_is_reported() {
+    let mut app = create_app();
+    let healer = spawn_with(&mut app, &[]);
+    let target = spawn_with(&mut app, &[("Health", 50.0)]);
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("renew", target).with_source(healer));
+    app.update();
+    app.update();
+
+    let log = app.world().resource::<CombatLog>();
+    assert!(log.damage.is_empty());
+    assert_eq!(log.healing.len(), 1);
+    let heal = &log.healing[0];
+    assert_eq!(heal.source, Some(healer));
+    assert_eq!(heal.target, target);
+    assert_eq!(heal.ability, None);
+    assert!(heal.effect.is_some());
+    assert_eq!(heal.effect_id.as_ref(), "renew");
+    assert_eq!(heal.amount, 5.0);
+}
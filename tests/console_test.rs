@@ -0,0 +1,181 @@
+//! Tests for the `gas.*` developer console commands.
+
+#![cfg(feature = "console")]
+
+use bevy::prelude::*;
+use bevy_console::{ConsoleCommandEntered, ConsoleConfiguration, PrintConsoleLine};
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::{AbilityDefinition, AbilityRegistry, OwnedAbilities},
+    attributes::{AttributeData, AttributeName},
+    core::OwnedTags,
+    debug::*,
+    effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+
+#[derive(Resource, Default)]
+struct Outputs(Vec<Result<String, GasConsoleError>>);
+
+fn create_app() -> (App, Entity, Entity) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+        GasConsolePlugin,
+    ))
+    .init_resource::<Outputs>()
+    .add_observer(
+        |ev: On<GasConsoleOutputEvent>, mut outputs: ResMut<Outputs>| {
+            outputs.0.push(ev.result.clone());
+        },
+    );
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("burn").with_duration_policy(DurationPolicy::Infinite),
+        );
+    app.world_mut()
+        .resource_mut::<AbilityRegistry>()
+        .register(AbilityDefinition::new("fireball"));
+    let player = app
+        .world_mut()
+        .spawn((Name::new("Player"), OwnedTags::default()))
+        .id();
+    let health = app
+        .world_mut()
+        .spawn((
+            AttributeName::new("Health"),
+            AttributeData::new(100.0),
+            ChildOf(player),
+        ))
+        .id();
+    (app, player, health)
+}
+
+fn run(app: &mut App, line: &str) -> Result<String, GasConsoleError> {
+    app.world_mut().trigger(GasConsoleCommandEvent::new(line));
+    app.update();
+    app.world_mut()
+        .resource_mut::<Outputs>()
+        .0
+        .pop()
+        .expect("console output")
+}
+
+#[test]
+fn test_console_commands_change_gas_state() {
+    let (mut app, player, health) = create_app();
+
+    run(&mut app, "gas.set Player Health 40").unwrap();
+    assert_eq!(
+        app.world().get::<AttributeData>(health).unwrap().base_value,
+        40.0
+    );
+
+    run(&mut app, &format!("gas.apply {} burn 2", player.index())).unwrap();
+    app.update();
+    let effects = app.world().get::<ActiveEffects>(player).unwrap();
+    assert_eq!(effects.len(), 1);
+
+    run(&mut app, &format!("gas.give {player} fireball")).unwrap();
+    assert_eq!(app.world().get::<OwnedAbilities>(player).unwrap().len(), 1);
+
+    let dump = run(&mut app, "gas.dump Player").unwrap();
+    assert!(dump.contains("Health = 40"));
+    assert!(dump.contains("burn level 2"));
+    assert!(dump.contains("fireball level 1"));
+}
+
+#[test]
+fn test_console_reports_bad_commands() {
+    let (mut app, player, _) = create_app();
+
+    assert_eq!(
+        run(&mut app, "gas.apply Player"),
+        Err(GasConsoleError::Usage(
+            "gas.apply <entity> <effect_id> [level]"
+        ))
+    );
+    assert_eq!(
+        run(&mut app, "gas.explode Player"),
+        Err(GasConsoleError::UnknownCommand("gas.explode".to_string()))
+    );
+    assert_eq!(
+        run(&mut app, "gas.dump Enemy"),
+        Err(GasConsoleError::EntityNotFound("Enemy".to_string()))
+    );
+    assert!(matches!(
+        run(&mut app, "gas.give Player icebolt"),
+        Err(GasConsoleError::Gas(_))
+    ));
+    assert!(matches!(
+        run(&mut app, "gas.set Player Mana 5"),
+        Err(GasConsoleError::Gas(_))
+    ));
+    assert!(app.world().get::<ActiveEffects>(player).is_none());
+}
+
+#[derive(Resource, Default)]
+struct ConsoleLines(Vec<String>);
+
+fn collect_console_lines(
+    mut prints: MessageReader<PrintConsoleLine>,
+    mut lines: ResMut<ConsoleLines>,
+) {
+    lines
+        .0
+        .extend(prints.read().map(|print| print.line.clone()));
+}
+
+/// Enters `line` in `bevy_console` and returns the lines it printed.
+fn enter(app: &mut App, line: &str) -> Vec<String> {
+    let mut args = line.split_whitespace().map(str::to_string);
+    let command_name = args.next().unwrap();
+    app.world_mut().write_message(ConsoleCommandEntered {
+        command_name,
+        args: args.collect(),
+    });
+    app.update();
+    std::mem::take(&mut app.world_mut().resource_mut::<ConsoleLines>().0)
+}
+
+#[test]
+fn test_bevy_console_runs_gas_commands() {
+    let (mut app, player, health) = create_app();
+    // The resources `ConsolePlugin` adds, without its egui window.
+    app.init_resource::<ConsoleConfiguration>()
+        .add_message::<ConsoleCommandEntered>()
+        .add_message::<PrintConsoleLine>()
+        .init_resource::<ConsoleLines>()
+        .add_systems(Last, collect_console_lines);
+    app.finish();
+
+    assert_eq!(
+        enter(&mut app, "gas.set Player Health 40"),
+        [format!("Set Health of {player} to 40"), "[ok]".to_string()]
+    );
+    assert_eq!(
+        app.world().get::<AttributeData>(health).unwrap().base_value,
+        40.0
+    );
+
+    enter(&mut app, "gas.apply Player burn 2");
+    enter(&mut app, "gas.give Player fireball");
+    app.update();
+    assert_eq!(app.world().get::<ActiveEffects>(player).unwrap().len(), 1);
+    assert_eq!(app.world().get::<OwnedAbilities>(player).unwrap().len(), 1);
+
+    let dump = enter(&mut app, "gas.dump Player");
+    assert!(dump[0].contains("burn level 2"));
+    assert_eq!(
+        enter(&mut app, "gas.dump Enemy"),
+        [
+            GasConsoleError::EntityNotFound("Enemy".to_string()).to_string(),
+            "[failed]".to_string()
+        ]
+    );
+}
@@ -0,0 +1,248 @@
+//! Tests for the cooldown start, update and end events of ability specs.
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{GasPlugin, abilities::*, core::OwnedTags, effects::*};
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use bevy_gameplay_tag::{GameplayTagsManager, GameplayTagsPlugin};
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+struct Recorded {
+    started: Vec<CooldownStartedEvent>,
+    updated: Vec<CooldownUpdatedEvent>,
+    ended: Vec<CooldownEndedEvent>,
+}
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )))
+    .insert_resource(CooldownEventSettings::default().with_update_interval(0.5))
+    .init_resource::<Recorded>()
+    .add_observer(
+        |ev: On<CooldownStartedEvent>, mut recorded: ResMut<Recorded>| {
+            recorded.started.push(*ev.event());
+        },
+    )
+    .add_observer(
+        |ev: On<CooldownUpdatedEvent>, mut recorded: ResMut<Recorded>| {
+            recorded.updated.push(*ev.event());
+        },
+    )
+    .add_observer(
+        |ev: On<CooldownEndedEvent>, mut recorded: ResMut<Recorded>| {
+            recorded.ended.push(*ev.event());
+        },
+    );
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("fireball_cooldown")
+                .with_duration_policy(DurationPolicy::HasDuration)
+                .with_duration(2.0),
+        );
+    let mut abilities = app.world_mut().resource_mut::<AbilityRegistry>();
+    abilities
+        .register(AbilityDefinition::new("fireball").with_cooldown_effect("fireball_cooldown"));
+    abilities.register(AbilityDefinition::new("punch"));
+    app
+}
+
+fn grant(app: &mut App, owner: Entity, ability_id: &str) -> Entity {
+    app.world_mut()
+        .spawn((
+            AbilitySpec::new(ability_id, 1),
+            AbilityActiveState::default(),
+            AbilityOwner(owner),
+        ))
+        .id()
+}
+
+fn activate(app: &mut App, spec: Entity, owner: Entity) {
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+}
+
+#[test]
+fn test_cooldown_events_follow_the_cooldown_effect() {
+    let mut app = create_app();
+    let player = app.world_mut().spawn(OwnedTags::default()).id();
+    let fireball = grant(&mut app, player, "fireball");
+    let punch = grant(&mut app, player, "punch");
+
+    activate(&mut app, fireball, player);
+    activate(&mut app, punch, player);
+    let recorded = app.world().resource::<Recorded>();
+    assert_eq!(recorded.started.len(), 1);
+    let started = recorded.started[0];
+    assert_eq!(started.ability_spec, fireball);
+    assert_eq!(started.owner, player);
+    assert_eq!(started.duration, 2.0);
+    let cooldown = app.world().get::<AbilityCooldown>(fireball).unwrap();
+    assert_eq!(cooldown.effect, started.effect);
+    assert!(app.world().get::<AbilityCooldown>(punch).is_none());
+
+    for _ in 0..10 {
+        app.update();
+    }
+    let recorded = app.world().resource::<Recorded>();
+    // Throttled to one update per 0.5s rather than one per frame.
+    assert!((2..=4).contains(&recorded.updated.len()));
+    assert!(
+        recorded
+            .updated
+            .iter()
+            .all(|ev| ev.ability_spec == fireball)
+    );
+    assert!(
+        recorded
+            .updated
+            .windows(2)
+            .all(|pair| pair[1].remaining < pair[0].remaining)
+    );
+    assert_eq!(recorded.ended.len(), 1);
+    assert_eq!(recorded.ended[0].ability_spec, fireball);
+    assert_eq!(recorded.ended[0].effect, started.effect);
+    assert!(app.world().get::<AbilityCooldown>(fireball).is_none());
+}
+
+#[test]
+fn test_cooldown_ends_when_effect_is_despawned() {
+    let mut app = create_app();
+    let player = app.world_mut().spawn(OwnedTags::default()).id();
+    let fireball = grant(&mut app, player, "fireball");
+
+    activate(&mut app, fireball, player);
+    let effect = app.world().get::<AbilityCooldown>(fireball).unwrap().effect;
+    app.world_mut().despawn(effect);
+    app.update();
+
+    let recorded = app.world().resource::<Recorded>();
+    assert_eq!(recorded.ended.len(), 1);
+    assert!(app.world().get::<AbilityCooldown>(fireball).is_none());
+}
+
+#[test]
+fn test_cooldown_follows_effects_granting_the_cooldown_tags() {
+    let mut app = create_app();
+    app.world_mut()
+        .run_system_once(
+            |tags_manager: Res<GameplayTagsManager>,
+             mut registry: ResMut<GameplayEffectRegistry>| {
+                registry.register(
+                    GameplayEffectDefinition::new("fireball_cooldown")
+                        .with_duration_policy(DurationPolicy::HasDuration)
+                        .with_duration(2.0)
+                        .grant_tag(GameplayTag::new("Cooldown.Fireball"), &tags_manager),
+                );
+                registry.register(
+                    GameplayEffectDefinition::new("fire_lockout")
+                        .with_duration_policy(DurationPolicy::HasDuration)
+                        .with_duration(1.0)
+                        .grant_tag(GameplayTag::new("Cooldown.Fireball"), &tags_manager),
+                );
+            },
+        )
+        .unwrap();
+    let player = app.world_mut().spawn(OwnedTags::default()).id();
+    let fireball = grant(&mut app, player, "fireball");
+    let punch = grant(&mut app, player, "punch");
+
+    // The lockout grants the fireball's cooldown tag, so it is a cooldown too
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("fire_lockout", player));
+    app.update();
+    let recorded = app.world().resource::<Recorded>();
+    assert_eq!(recorded.started.len(), 1);
+    assert_eq!(recorded.started[0].ability_spec, fireball);
+    assert!(app.world().get::<AbilityCooldown>(punch).is_none());
+    let lockout = recorded.started[0].effect;
+
+    // The longer cooldown takes over, a shorter lockout doesn't replace it
+    // and picks up again when the cooldown is removed early
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("fireball_cooldown", player));
+    app.update();
+    let cooldown = *app.world().get::<AbilityCooldown>(fireball).unwrap();
+    assert_ne!(cooldown.effect, lockout);
+    assert_eq!(app.world().resource::<Recorded>().started.len(), 2);
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("fire_lockout", player));
+    app.update();
+    assert_eq!(app.world().resource::<Recorded>().started.len(), 2);
+
+    app.world_mut().trigger(RemoveGameplayEffectEvent {
+        effect: cooldown.effect,
+    });
+    app.update();
+    let recorded = app.world().resource::<Recorded>();
+    assert!(recorded.ended.is_empty());
+    assert_eq!(recorded.started.len(), 3);
+    let lockout = recorded.started[2].effect;
+    assert_ne!(lockout, cooldown.effect);
+
+    for _ in 0..5 {
+        app.update();
+    }
+    let recorded = app.world().resource::<Recorded>();
+    assert_eq!(recorded.ended.len(), 1);
+    assert_eq!(recorded.ended[0].effect, lockout);
+    assert!(app.world().get::<AbilityCooldown>(fireball).is_none());
+}
+
+#[test]
+fn test_cooldown_category_is_shared() {
+    let mut app = create_app();
+    app.world_mut()
+        .run_system_once(
+            |tags_manager: Res<GameplayTagsManager>,
+             mut effects: ResMut<GameplayEffectRegistry>,
+             mut abilities: ResMut<AbilityRegistry>| {
+                effects.register(
+                    GameplayEffectDefinition::new("potion_cooldown")
+                        .with_duration_policy(DurationPolicy::HasDuration)
+                        .with_duration(2.0)
+                        .grant_tag(GameplayTag::new("Cooldown.Potion"), &tags_manager),
+                );
+                abilities.register(
+                    AbilityDefinition::new("health_potion").with_cooldown_category("Potion"),
+                );
+                abilities.register(
+                    AbilityDefinition::new("mana_potion").with_cooldown_category("Potion"),
+                );
+                abilities.register_cooldown_category("Potion", "potion_cooldown");
+            },
+        )
+        .unwrap();
+    let player = app.world_mut().spawn(OwnedTags::default()).id();
+    let health_potion = grant(&mut app, player, "health_potion");
+    let mana_potion = grant(&mut app, player, "mana_potion");
+    let fireball = grant(&mut app, player, "fireball");
+
+    // Drinking one potion puts every potion on the shared cooldown
+    activate(&mut app, health_potion, player);
+    let recorded = app.world().resource::<Recorded>();
+    assert_eq!(recorded.started.len(), 2);
+    let effect = recorded.started[0].effect;
+    for potion in [health_potion, mana_potion] {
+        assert_eq!(
+            app.world().get::<AbilityCooldown>(potion).unwrap().effect,
+            effect
+        );
+    }
+    assert!(app.world().get::<AbilityCooldown>(fireball).is_none());
+
+    activate(&mut app, mana_potion, player);
+    assert_eq!(app.world().resource::<Recorded>().started.len(), 2);
+}
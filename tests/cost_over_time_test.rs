@@ -0,0 +1,147 @@
+//! Tests for ability costs that drain over time while the ability is active.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin, abilities::*, attributes::*, core::OwnedTags, effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+struct Depleted(Vec<AbilityCostDepletedEvent>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )))
+    .init_resource::<Depleted>()
+    .add_observer(
+        |ev: On<AbilityCostDepletedEvent>, mut depleted: ResMut<Depleted>| {
+            depleted.0.push(ev.event().clone());
+        },
+    );
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("channel_drain")
+                .with_duration_policy(DurationPolicy::Infinite)
+                .with_period(1.0)
+                .add_modifier(ModifierInfo::new(
+                    "Mana",
+                    ModifierOperation::AddBase,
+                    MagnitudeCalculation::scalar(-10.0),
+                )),
+        );
+    app.world_mut()
+        .resource_mut::<AbilityRegistry>()
+        .register(AbilityDefinition::new("channel").with_cost_over_time_effect("channel_drain"));
+    app
+}
+
+fn spawn_caster(app: &mut App, mana: f32) -> (Entity, Entity, Entity) {
+    let owner = app.world_mut().spawn(OwnedTags::default()).id();
+    let mana = app
+        .world_mut()
+        .spawn((
+            AttributeName::new("Mana"),
+            AttributeData::new(mana),
+            ChildOf(owner),
+        ))
+        .id();
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("channel", 1),
+            AbilityActiveState::default(),
+            AbilityOwner(owner),
+        ))
+        .id();
+    (owner, mana, spec)
+}
+
+fn run_secs(app: &mut App, secs: f32) {
+    for _ in 0..(secs / 0.25) as usize {
+        app.update();
+    }
+}
+
+fn mana(app: &App, attribute: Entity) -> f32 {
+    app.world()
+        .get::<AttributeData>(attribute)
+        .unwrap()
+        .base_value
+}
+
+fn is_active(app: &App, spec: Entity) -> bool {
+    app.world()
+        .get::<AbilityActiveState>(spec)
+        .unwrap()
+        .is_active
+}
+
+fn drain_count(app: &mut App) -> usize {
+    let mut effects = app.world_mut().query::<&ActiveGameplayEffect>();
+    effects.iter(app.world()).count()
+}
+
+#[test]
+fn test_drain_runs_while_active_and_stops_on_end() {
+    let mut app = create_app();
+    let (owner, mana_attribute, spec) = spawn_caster(&mut app, 100.0);
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+    assert!(is_active(&app, spec));
+    assert_eq!(drain_count(&mut app), 1);
+
+    run_secs(&mut app, 2.5);
+    assert!(mana(&app, mana_attribute) <= 80.0);
+
+    app.world_mut().trigger(EndAbilityEvent {
+        instance: None,
+        ability_spec: spec,
+        owner,
+    });
+    app.update();
+    app.update();
+    assert_eq!(drain_count(&mut app), 0);
+
+    let drained = mana(&app, mana_attribute);
+    run_secs(&mut app, 2.0);
+    assert_eq!(mana(&app, mana_attribute), drained);
+    assert!(app.world().resource::<Depleted>().0.is_empty());
+}
+
+#[test]
+fn test_running_out_ends_the_ability() {
+    let mut app = create_app();
+    let (owner, mana_attribute, spec) = spawn_caster(&mut app, 25.0);
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+    run_secs(&mut app, 3.0);
+
+    // 25 pays two periods; the 5 left can't pay a third.
+    assert_eq!(mana(&app, mana_attribute), 5.0);
+    assert!(!is_active(&app, spec));
+    assert_eq!(drain_count(&mut app), 0);
+    assert_eq!(
+        app.world().resource::<Depleted>().0,
+        vec![AbilityCostDepletedEvent {
+            ability_spec: spec,
+            owner,
+            attribute: "Mana".into(),
+        }]
+    );
+}
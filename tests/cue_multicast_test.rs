@@ -0,0 +1,109 @@
+//! Tests that server cues are multicast to relevant clients and that
+//! received cues play locally on clients.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{GasPlugin, abilities::*, cues::*};
+use bevy_gameplay_tag::{GameplayTagsPlugin, gameplay_tag::GameplayTag};
+use std::sync::{Arc, Mutex};
+
+type Multicast = (Vec<NetClientId>, GameplayCueMulticastEvent);
+
+/// Channel that records what it is asked to send.
+#[derive(Clone, Default)]
+struct RecordingChannel(Arc<Mutex<Vec<Multicast>>>);
+
+impl GameplayCueChannel for RecordingChannel {
+    fn multicast(
+        &self,
+        _commands: &mut Commands,
+        clients: &[NetClientId],
+        cue: &GameplayCueMulticastEvent,
+    ) {
+        self.0.lock().unwrap().push((clients.to_vec(), cue.clone()));
+    }
+}
+
+#[derive(Resource, Default)]
+struct Played(Vec<TriggerGameplayCueEvent>);
+
+fn create_app(role: NetRole) -> (App, RecordingChannel) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.update();
+    let channel = RecordingChannel::default();
+    app.insert_resource(role)
+        .insert_resource(CueChannel::new(channel.clone()))
+        .insert_resource(CueMulticastSettings::default().with_relevancy_distance(20.0))
+        .init_resource::<Played>();
+    app.add_observer(
+        |ev: On<TriggerGameplayCueEvent>, mut played: ResMut<Played>| {
+            played.0.push(ev.event().clone());
+        },
+    );
+    (app, channel)
+}
+
+fn spawn_viewer(app: &mut App, client: u64, position: Vec3) {
+    app.world_mut().spawn((
+        CueViewer(NetClientId(client)),
+        GlobalTransform::from_translation(position),
+    ));
+}
+
+#[test]
+fn test_server_multicasts_to_viewers_in_range() {
+    let (mut app, channel) = create_app(NetRole::Server);
+    spawn_viewer(&mut app, 1, Vec3::ZERO);
+    spawn_viewer(&mut app, 2, Vec3::new(100.0, 0.0, 0.0));
+    let target = app
+        .world_mut()
+        .spawn(GlobalTransform::from_translation(Vec3::new(5.0, 0.0, 0.0)))
+        .id();
+
+    let parameters = GameplayCueParameters::new()
+        .with_target(target)
+        .with_magnitude(25.0, 0.5)
+        .with_prediction_key(PredictionKey(4));
+    app.world_mut().trigger(TriggerGameplayCueEvent::new(
+        GameplayTag::new("GameplayCue.Hit"),
+        GameplayCueEvent::Executed,
+        parameters.clone(),
+    ));
+    // Explicit locations win over the target's position.
+    app.world_mut().trigger(TriggerGameplayCueEvent::new(
+        GameplayTag::new("GameplayCue.Hit"),
+        GameplayCueEvent::Executed,
+        GameplayCueParameters::new().with_location(Vec3::new(95.0, 0.0, 0.0)),
+    ));
+    app.update();
+
+    let sent = channel.0.lock().unwrap();
+    assert_eq!(sent.len(), 2);
+    assert_eq!(sent[0].0, vec![NetClientId(1)]);
+    assert_eq!(sent[0].1.cue_tag, GameplayTag::new("GameplayCue.Hit"));
+    assert_eq!(sent[0].1.parameters.to_parameters(), parameters);
+    assert_eq!(sent[1].0, vec![NetClientId(2)]);
+}
+
+#[test]
+fn test_client_plays_received_cue_without_multicasting() {
+    let (mut app, channel) = create_app(NetRole::Client);
+    spawn_viewer(&mut app, 1, Vec3::ZERO);
+
+    app.world_mut().trigger(GameplayCueMulticastEvent {
+        cue_tag: GameplayTag::new("GameplayCue.Hit"),
+        event_type: GameplayCueEvent::Executed,
+        parameters: NetCueParameters::from(&GameplayCueParameters::new().with_location(Vec3::ONE)),
+    });
+    app.update();
+
+    let played = &app.world().resource::<Played>().0;
+    assert_eq!(played.len(), 1);
+    assert_eq!(played[0].cue_tag, GameplayTag::new("GameplayCue.Hit"));
+    assert_eq!(played[0].parameters.location, Vec3::ONE);
+    assert!(channel.0.lock().unwrap().is_empty());
+}
@@ -0,0 +1,202 @@
+//! Tests for crit and mitigation in the damage path.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    attributes::*,
+    cues::*,
+    effects::*,
+    error::{GasError, GasErrorEvent, GasErrorPolicy},
+};
+use bevy_gameplay_tag::{GameplayTag, GameplayTagsPlugin};
+
+#[derive(Resource, Default)]
+struct CriticalCues(Vec<bool>);
+
+#[derive(Resource, Default)]
+struct Reported(Vec<GasError>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(GasErrorPolicy::Event)
+    .init_resource::<CriticalCues>()
+    .init_resource::<Reported>()
+    .add_observer(
+        |ev: On<TriggerGameplayCueEvent>, mut cues: ResMut<CriticalCues>| {
+            cues.0.push(ev.parameters.critical);
+        },
+    )
+    .add_observer(|ev: On<GasErrorEvent>, mut reported: ResMut<Reported>| {
+        reported.0.push(ev.event().error.clone());
+    });
+    app.update();
+
+    let mut damage = app.world_mut().resource_mut::<DamageCalculationRegistry>();
+    damage.register_mitigation("Armor", ArmorMitigation::default());
+    damage.register_mitigation(
+        "FireResistance",
+        ResistanceMitigation::new("FireResistance"),
+    );
+    app
+}
+
+fn spawn_with(app: &mut App, attributes: &[(&str, f32)]) -> Entity {
+    let owner = app.world_mut().spawn_empty().id();
+    for (name, value) in attributes {
+        app.world_mut().spawn((
+            AttributeName::new(*name),
+            AttributeData::new(*value),
+            ChildOf(owner),
+        ));
+    }
+    owner
+}
+
+fn register_hit(app: &mut App, damage: DamageCalculation) {
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("hit")
+                .add_modifier(ModifierInfo::new(
+                    "Health",
+                    ModifierOperation::AddBase,
+                    MagnitudeCalculation::scalar(-40.0),
+                ))
+                .add_modifier(ModifierInfo::new(
+                    "Mana",
+                    ModifierOperation::AddBase,
+                    MagnitudeCalculation::scalar(-10.0),
+                ))
+                .add_gameplay_cue(GameplayEffectCue::new(GameplayTag::new("GameplayCue.Test")))
+                .with_damage(damage.with_damage_attribute("Health")),
+        );
+}
+
+fn hit(app: &mut App, source: Entity, target: Entity) {
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("hit", target).with_source(source));
+    app.update();
+}
+
+fn attribute(app: &mut App, owner: Entity, attribute_name: &str) -> f32 {
+    let mut attributes = app
+        .world_mut()
+        .query::<(&AttributeName, &AttributeData, &ChildOf)>();
+    attributes
+        .iter(app.world())
+        .find(|(name, _, child_of)| child_of.parent() == owner && name.as_str() == attribute_name)
+        .map(|(_, data, _)| data.base_value)
+        .unwrap()
+}
+
+fn health(app: &mut App, owner: Entity) -> f32 {
+    attribute(app, owner, "Health")
+}
+
+#[test]
+fn test_armor_and_resistance_mitigate_in_order() {
+    let mut app = create_app();
+    register_hit(
+        &mut app,
+        DamageCalculation::new()
+            .with_mitigation("Armor")
+            .with_mitigation("FireResistance"),
+    );
+    let source = spawn_with(&mut app, &[]);
+    let target = spawn_with(
+        &mut app,
+        &[
+            ("Health", 100.0),
+            ("Armor", 100.0),
+            ("FireResistance", 0.25),
+        ],
+    );
+
+    hit(&mut app, source, target);
+    // 40 halved by armor, then reduced by a quarter.
+    assert_eq!(health(&mut app, target), 85.0);
+    assert_eq!(app.world().resource::<CriticalCues>().0, [false]);
+}
+
+#[test]
+fn test_crit_uses_source_attributes_and_flags_cues() {
+    let mut app = create_app();
+    register_hit(&mut app, DamageCalculation::new().with_crit());
+    let source = spawn_with(&mut app, &[("CritChance", 1.0), ("CritDamage", 2.0)]);
+    let target = spawn_with(&mut app, &[("Health", 100.0)]);
+
+    hit(&mut app, source, target);
+    assert_eq!(health(&mut app, target), 20.0);
+    assert_eq!(app.world().resource::<CriticalCues>().0, [true]);
+}
+
+#[test]
+fn test_crit_requires_opt_in_and_chance() {
+    let mut app = create_app();
+    register_hit(&mut app, DamageCalculation::new());
+    let source = spawn_with(&mut app, &[("CritChance", 1.0)]);
+    let target = spawn_with(&mut app, &[("Health", 100.0)]);
+    hit(&mut app, source, target);
+    assert_eq!(health(&mut app, target), 60.0);
+
+    register_hit(&mut app, DamageCalculation::new().with_crit());
+    let no_chance = spawn_with(&mut app, &[]);
+    hit(&mut app, no_chance, target);
+    assert_eq!(health(&mut app, target), 20.0);
+    assert_eq!(app.world().resource::<CriticalCues>().0, [false, false]);
+}
+
+#[test]
+fn test_only_declared_attributes_take_damage() {
+    let mut app = create_app();
+    register_hit(&mut app, DamageCalculation::new().with_mitigation("Armor"));
+    let source = spawn_with(&mut app, &[]);
+    let target = spawn_with(
+        &mut app,
+        &[("Health", 100.0), ("Mana", 50.0), ("Armor", 100.0)],
+    );
+
+    hit(&mut app, source, target);
+    // Armor halves the health damage but not the mana drain.
+    assert_eq!(health(&mut app, target), 80.0);
+    assert_eq!(attribute(&mut app, target, "Mana"), 40.0);
+}
+
+#[test]
+fn test_missing_mitigation_is_reported() {
+    let mut app = create_app();
+    register_hit(&mut app, DamageCalculation::new().with_mitigation("Ward"));
+    let source = spawn_with(&mut app, &[]);
+    let target = spawn_with(&mut app, &[("Health", 100.0), ("Mana", 50.0)]);
+
+    hit(&mut app, source, target);
+    assert_eq!(health(&mut app, target), 60.0);
+    assert_eq!(
+        app.world().resource::<Reported>().0,
+        [GasError::DamageMitigationNotFound {
+            mitigation_name: "Ward".into(),
+        }]
+    );
+}
+
+#[test]
+fn test_armor_without_constant_or_armor_passes_damage_through() {
+    let mut app = create_app();
+    app.world_mut()
+        .resource_mut::<DamageCalculationRegistry>()
+        .register_mitigation("Plating", ArmorMitigation::new("Armor", 0.0));
+    register_hit(
+        &mut app,
+        DamageCalculation::new().with_mitigation("Plating"),
+    );
+    let source = spawn_with(&mut app, &[]);
+    let target = spawn_with(&mut app, &[("Health", 100.0)]);
+
+    hit(&mut app, source, target);
+    assert_eq!(health(&mut app, target), 60.0);
+}
@@ -0,0 +1,115 @@
+//! Tests for reflecting damage back to attackers (thorns).
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{GasPlugin, attributes::*, effects::*};
+use bevy_gameplay_tag::{GameplayTag, GameplayTagsPlugin};
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.update();
+
+    let data_tag = GameplayTag::new("Data.Damage");
+    let mut registry = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    registry.register(GameplayEffectDefinition::new("thorns_damage").add_modifier(
+        ModifierInfo::new(
+            "Health",
+            ModifierOperation::AddBase,
+            MagnitudeCalculation::set_by_caller(data_tag.clone()),
+        ),
+    ));
+    registry.register(
+        GameplayEffectDefinition::new("thorns_aura")
+            .with_duration_policy(DurationPolicy::Infinite)
+            .add_reflection(DamageReflection::new(
+                "Health",
+                "thorns_damage",
+                0.5,
+                data_tag,
+            )),
+    );
+    registry.register(
+        GameplayEffectDefinition::new("slash").add_modifier(ModifierInfo::new(
+            "Health",
+            ModifierOperation::AddBase,
+            MagnitudeCalculation::scalar(-20.0),
+        )),
+    );
+    app
+}
+
+fn spawn_with_health(app: &mut App) -> (Entity, Entity) {
+    let owner = app.world_mut().spawn_empty().id();
+    let attribute = app
+        .world_mut()
+        .spawn((
+            AttributeName::new("Health"),
+            AttributeData::new(100.0),
+            ChildOf(owner),
+        ))
+        .id();
+    (owner, attribute)
+}
+
+fn give_thorns(app: &mut App, owner: Entity) {
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("thorns_aura", owner));
+    app.update();
+}
+
+fn slash(app: &mut App, attacker: Entity, victim: Entity) {
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("slash", victim).with_source(attacker));
+    app.update();
+}
+
+fn base_value(app: &App, attribute: Entity) -> f32 {
+    app.world()
+        .get::<AttributeData>(attribute)
+        .unwrap()
+        .base_value
+}
+
+#[test]
+fn test_thorns_reflect_damage_to_attacker() {
+    let mut app = create_app();
+    let (attacker, attacker_health) = spawn_with_health(&mut app);
+    let (victim, victim_health) = spawn_with_health(&mut app);
+    give_thorns(&mut app, victim);
+
+    slash(&mut app, attacker, victim);
+    assert_eq!(base_value(&app, victim_health), 80.0);
+    assert_eq!(base_value(&app, attacker_health), 90.0);
+}
+
+#[test]
+fn test_reflected_damage_is_not_reflected_again() {
+    let mut app = create_app();
+    let (attacker, attacker_health) = spawn_with_health(&mut app);
+    let (victim, victim_health) = spawn_with_health(&mut app);
+    give_thorns(&mut app, attacker);
+    give_thorns(&mut app, victim);
+
+    slash(&mut app, attacker, victim);
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(base_value(&app, victim_health), 80.0);
+    assert_eq!(base_value(&app, attacker_health), 90.0);
+}
+
+#[test]
+fn test_thorns_ignore_sourceless_damage() {
+    let mut app = create_app();
+    let (victim, victim_health) = spawn_with_health(&mut app);
+    give_thorns(&mut app, victim);
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("slash", victim));
+    app.update();
+    assert_eq!(base_value(&app, victim_health), 80.0);
+}
@@ -0,0 +1,141 @@
+//! Tests that effect and ability definitions round-trip through serde and
+//! can be written by hand as data.
+
+#![cfg(feature = "serde")]
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{abilities::*, cues::GameplayCueParameters, effects::*};
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use bevy_gameplay_tag::{GameplayTagContainer, GameplayTagsManager, GameplayTagsPlugin};
+
+fn tags_manager() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+    ));
+    app.update();
+    app
+}
+
+fn container(names: &[&str], manager: &GameplayTagsManager) -> GameplayTagContainer {
+    let mut container = GameplayTagContainer::new();
+    for name in names {
+        container.add_tag(GameplayTag::new(name), manager);
+    }
+    container
+}
+
+#[test]
+fn test_effect_definition_round_trips() {
+    let app = tags_manager();
+    let manager = app.world().resource::<GameplayTagsManager>();
+
+    let mut definition = GameplayEffectDefinition::new("burning")
+        .with_duration(6.0)
+        .with_period(1.0)
+        .with_stacking_policy(StackingPolicy::StackCount { max_stacks: 3 })
+        .with_team_policy(EffectTeamPolicy::HostileOnly)
+        .add_modifier(ModifierInfo::new(
+            "Health",
+            ModifierOperation::AddCurrent,
+            MagnitudeCalculation::from_source_attribute("AttackPower", -0.5)
+                .with_capture_mode(AttributeCaptureMode::Dynamic),
+        ))
+        .add_modifier(ModifierInfo::new(
+            "Health",
+            ModifierOperation::AddCurrent,
+            MagnitudeCalculation::set_by_caller(GameplayTag::new("Data.TickDamage")),
+        ))
+        .add_application_requirement("level_range")
+        .add_gameplay_cue(
+            GameplayEffectCue::new(GameplayTag::new("GameplayCue.Burn"))
+                .with_level_range(1, Some(5))
+                .with_parameters(
+                    GameplayCueParameters::new()
+                        .with_physical_material("Flesh")
+                        .with_source_tags(container(&["State.Buffed"], manager)),
+                ),
+        );
+    definition.granted_tags = container(&["State.Stunned", "Cooldown.Fireball"], manager);
+    definition.granted_abilities.push(
+        GrantedAbilityConfig::new("panic").with_removal_policy(AbilityRemovalPolicy::DoNothing),
+    );
+
+    let json = serde_json::to_string(&definition).unwrap();
+    let loaded: GameplayEffectDefinition = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, definition);
+    assert!(loaded.granted_tags.has_tag(&GameplayTag::new("State")));
+}
+
+#[test]
+fn test_ability_definition_round_trips() {
+    let app = tags_manager();
+    let manager = app.world().resource::<GameplayTagsManager>();
+
+    let mut definition = AbilityDefinition::new("fireball")
+        .with_instancing_policy(InstancingPolicy::InstancedPerActor)
+        .with_net_execution_policy(NetExecutionPolicy::ServerOnly)
+        .with_cost_effect("fireball_cost")
+        .with_cooldown_effect("fireball_cooldown")
+        .with_cancelable(false)
+        .with_target_filter(
+            TargetFilter::new()
+                .exclude_self()
+                .hostile()
+                .block_tags(container(&["State.Stunned"], manager)),
+        )
+        .add_trigger(AbilityTriggerData::new(
+            GameplayTag::new("Ability.Casting"),
+            AbilityTriggerSource::OwnedTagAdded,
+        ));
+    definition.activation_owned_tags = container(&["Ability.Casting"], manager);
+    definition.activation_blocked_tags = container(&["State.Stunned", "State.Disarmed"], manager);
+
+    let json = serde_json::to_string(&definition).unwrap();
+    let loaded: AbilityDefinition = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, definition);
+}
+
+#[test]
+fn test_definitions_load_from_hand_written_data() {
+    let effect: GameplayEffectDefinition = serde_json::from_str(
+        r#"{
+            "id": "regen",
+            "duration_policy": "Infinite",
+            "period": 1.0,
+            "stacking_policy": "Independent",
+            "modifiers": [{
+                "attribute_name": "Health",
+                "operation": "AddCurrent",
+                "magnitude": { "ScalableFloat": { "base_value": 2.0, "level_multiplier": 1.0 } }
+            }],
+            "granted_tags": ["State.Buffed"]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(effect.duration_policy, DurationPolicy::Infinite);
+    assert_eq!(
+        effect.modifiers[0].magnitude,
+        MagnitudeCalculation::scalar(2.0)
+    );
+    assert_eq!(effect.team_policy, EffectTeamPolicy::Any);
+    assert!(effect.granted_tags.has_tag(&GameplayTag::new("State")));
+
+    let ability: AbilityDefinition = serde_json::from_str(
+        r#"{
+            "id": "dash",
+            "net_execution_policy": "LocalOnly",
+            "cooldown_effect": "dash_cooldown",
+            "default_blocks_other_abilities": false,
+            "default_is_cancelable": true
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        ability.instancing_policy,
+        InstancingPolicy::InstancedPerExecution
+    );
+    assert_eq!(ability.cooldown_effect.as_deref(), Some("dash_cooldown"));
+    assert!(ability.behavior.is_none());
+}
@@ -0,0 +1,57 @@
+//! Tests for the GAS performance diagnostics.
+
+use bevy::diagnostic::{DiagnosticPath, DiagnosticsStore};
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    attributes::{AttributeData, AttributeName},
+    core::OwnedTags,
+    debug::GasDiagnosticsPlugin,
+    effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+
+#[test]
+fn test_diagnostics_measure_effects_and_modifiers() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+        GasDiagnosticsPlugin,
+    ));
+    app.update();
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("fortify")
+                .with_duration_policy(DurationPolicy::Infinite)
+                .add_modifier(ModifierInfo::new(
+                    "Armor",
+                    ModifierOperation::AddCurrent,
+                    MagnitudeCalculation::scalar(5.0),
+                )),
+        );
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    app.world_mut().spawn((
+        AttributeName::new("Armor"),
+        AttributeData::new(10.0),
+        ChildOf(target),
+    ));
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("fortify", target));
+    app.update();
+    app.update();
+
+    let store = app.world().resource::<DiagnosticsStore>();
+    let value = |path: DiagnosticPath| {
+        store
+            .get(&path)
+            .and_then(|diagnostic| diagnostic.value())
+            .expect("measurement")
+    };
+    assert_eq!(value(GasDiagnosticsPlugin::ACTIVE_EFFECTS), 1.0);
+    assert_eq!(value(GasDiagnosticsPlugin::MODIFIERS), 1.0);
+    assert_eq!(value(GasDiagnosticsPlugin::ACTIVATIONS_PER_SECOND), 0.0);
+    assert!(value(GasDiagnosticsPlugin::AGGREGATION_TIME) >= 0.0);
+}
@@ -0,0 +1,152 @@
+//! Tests for dispel categories and cleanses.
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{GasPlugin, core::OwnedTags, effects::*};
+use bevy_gameplay_tag::GameplayTagsManager;
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+struct Cleansed(Vec<EffectsCleansedEvent>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )))
+    .init_resource::<Cleansed>()
+    .add_observer(
+        |ev: On<EffectsCleansedEvent>, mut cleansed: ResMut<Cleansed>| {
+            cleansed.0.push(ev.event().clone());
+        },
+    );
+    app.update();
+
+    app.world_mut()
+        .run_system_once(
+            |tags_manager: Res<GameplayTagsManager>,
+             mut registry: ResMut<GameplayEffectRegistry>| {
+                registry.register(
+                    GameplayEffectDefinition::new("venom")
+                        .with_duration(8.0)
+                        .with_dispel_category(DispelCategory::Poison, &tags_manager),
+                );
+                registry.register(
+                    GameplayEffectDefinition::new("rot")
+                        .with_duration(3.0)
+                        .with_dispel_category(DispelCategory::Curse, &tags_manager),
+                );
+                registry.register(
+                    GameplayEffectDefinition::new("arcane_mark")
+                        .with_duration_policy(DurationPolicy::Infinite)
+                        .with_dispel_category(DispelCategory::Magic, &tags_manager),
+                );
+                registry.register(
+                    GameplayEffectDefinition::new("blessing")
+                        .with_duration_policy(DurationPolicy::Infinite),
+                );
+            },
+        )
+        .unwrap();
+    app
+}
+
+/// Spawns a target with venom, rot, the arcane mark and a blessing, applied
+/// a frame apart in that order.
+fn spawn_afflicted(app: &mut App) -> Entity {
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    for effect_id in ["venom", "rot", "arcane_mark", "blessing"] {
+        app.world_mut()
+            .trigger(ApplyGameplayEffectEvent::new(effect_id, target));
+        app.update();
+    }
+    app.update();
+    target
+}
+
+fn cleanse(app: &mut App, event: CleanseEvent) {
+    app.world_mut().trigger(event);
+    app.update();
+    app.update();
+}
+
+fn active_ids(app: &mut App) -> Vec<String> {
+    let mut effects = app.world_mut().query::<&ActiveGameplayEffect>();
+    let mut ids: Vec<String> = effects
+        .iter(app.world())
+        .map(|effect| effect.definition_id.to_string())
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn test_cleanse_removes_matching_effects_newest_first() {
+    let mut app = create_app();
+    let target = spawn_afflicted(&mut app);
+
+    cleanse(
+        &mut app,
+        CleanseEvent::new(
+            target,
+            [DispelCategory::Poison.tag(), DispelCategory::Curse.tag()],
+        )
+        .with_max_count(1),
+    );
+    assert_eq!(active_ids(&mut app), ["arcane_mark", "blessing", "venom"]);
+
+    cleanse(
+        &mut app,
+        CleanseEvent::new(target, [DispelCategory::Poison.tag()]),
+    );
+    assert_eq!(active_ids(&mut app), ["arcane_mark", "blessing"]);
+    assert_eq!(app.world().resource::<Cleansed>().0.len(), 2);
+
+    // Nothing left to match.
+    cleanse(
+        &mut app,
+        CleanseEvent::new(target, [DispelCategory::Bleed.tag()]),
+    );
+    assert_eq!(app.world().resource::<Cleansed>().0.len(), 2);
+}
+
+#[test]
+fn test_cleanse_priorities_and_parent_categories() {
+    let mut app = create_app();
+    let target = spawn_afflicted(&mut app);
+
+    cleanse(
+        &mut app,
+        CleanseEvent::new(target, [GameplayTag::new("Dispel")])
+            .with_max_count(1)
+            .with_priority(CleansePriority::Oldest),
+    );
+    assert_eq!(active_ids(&mut app), ["arcane_mark", "blessing", "rot"]);
+
+    // Infinite effects come after ones that expire.
+    cleanse(
+        &mut app,
+        CleanseEvent::new(target, [GameplayTag::new("Dispel")])
+            .with_max_count(1)
+            .with_priority(CleansePriority::ShortestRemaining),
+    );
+    assert_eq!(active_ids(&mut app), ["arcane_mark", "blessing"]);
+
+    cleanse(
+        &mut app,
+        CleanseEvent::new(target, [GameplayTag::new("Dispel")]),
+    );
+    assert_eq!(active_ids(&mut app), ["blessing"]);
+    let cleansed = &app.world().resource::<Cleansed>().0;
+    assert_eq!(cleansed.len(), 3);
+    assert!(cleansed.iter().all(|event| event.target == target));
+    assert!(cleansed.iter().all(|event| event.effects.len() == 1));
+}
@@ -0,0 +1,94 @@
+//! Tests for display metadata and description formatting.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{abilities::*, core::DisplayInfo, effects::*};
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+
+fn effects() -> GameplayEffectRegistry {
+    let mut effects = GameplayEffectRegistry::new();
+    effects.register(
+        GameplayEffectDefinition::new("poison")
+            .with_duration(6.0)
+            .with_period(1.0)
+            .add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddCurrent,
+                MagnitudeCalculation::scaled(-5.0, 1.5),
+            ))
+            .add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddCurrent,
+                MagnitudeCalculation::set_by_caller(GameplayTag::new("Data.Damage")),
+            ))
+            .with_display(
+                DisplayInfo::new("Poison")
+                    .with_icon("icons/poison.png")
+                    .with_description(
+                        "Deals {modifier.0} damage every {period}s for {duration}s, \
+                         plus {modifier.1} ({set_by_caller.Data.Damage}). {unknown}",
+                    ),
+            ),
+    );
+    effects.register(GameplayEffectDefinition::new("fireball_cost").add_modifier(
+        ModifierInfo::new(
+            "Mana",
+            ModifierOperation::AddBase,
+            MagnitudeCalculation::scaled(-20.0, 1.1),
+        ),
+    ));
+    effects.register(GameplayEffectDefinition::new("fireball_cooldown").with_duration(2.5));
+    effects
+}
+
+#[test]
+fn test_effect_description_resolves_from_spec() {
+    let effects = effects();
+    let poison = effects.get("poison").unwrap();
+    let display = poison.display.as_ref().unwrap();
+    assert_eq!(display.name.as_deref(), Some("Poison"));
+    assert_eq!(display.icon.as_deref(), Some("icons/poison.png"));
+
+    let spec = GameplayEffectSpec::new("poison", Entity::PLACEHOLDER)
+        .with_level(2)
+        .with_set_by_caller_magnitude(GameplayTag::new("Data.Damage"), -3.0);
+    assert_eq!(
+        poison.format_description(&spec).as_deref(),
+        Some("Deals 7.5 damage every 1s for 6s, plus 3 (-3). {unknown}")
+    );
+
+    // Without the SetByCaller value, its placeholders stay as written.
+    let spec = GameplayEffectSpec::new("poison", Entity::PLACEHOLDER);
+    assert_eq!(
+        poison.format_description(&spec).as_deref(),
+        Some(
+            "Deals 5 damage every 1s for 6s, plus {modifier.1} ({set_by_caller.Data.Damage}). \
+             {unknown}"
+        )
+    );
+    assert_eq!(
+        effects
+            .get("fireball_cost")
+            .unwrap()
+            .format_description(&spec),
+        None
+    );
+}
+
+#[test]
+fn test_ability_description_resolves_cost_and_cooldown() {
+    let effects = effects();
+    let fireball = AbilityDefinition::new("fireball")
+        .with_cost_effect("fireball_cost")
+        .with_cooldown_effect("fireball_cooldown")
+        .with_display(
+            DisplayInfo::new("Fireball")
+                .with_description("Level {level}: costs {cost.Mana} Mana, {cooldown}s cooldown."),
+        );
+
+    assert_eq!(
+        fireball
+            .format_description(&AbilitySpec::new("fireball", 3), &effects)
+            .as_deref(),
+        Some("Level 3: costs 24.2 Mana, 2.5s cooldown.")
+    );
+}
@@ -0,0 +1,67 @@
+//! Tests that effect files are registered on load and re-registered when they
+//! change.
+
+#![cfg(feature = "effect_assets")]
+
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::effects::*;
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        EffectAssetPlugin::new().with_path("effects/burning.effect.ron"),
+    ));
+    app
+}
+
+fn wait_for_effect(app: &mut App, id: &str) {
+    for _ in 0..200 {
+        app.update();
+        if app
+            .world()
+            .resource::<GameplayEffectRegistry>()
+            .get(id)
+            .is_some()
+        {
+            return;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+    panic!("effect '{id}' was never registered");
+}
+
+#[test]
+fn test_effect_file_is_registered_and_reloaded() {
+    let mut app = create_app();
+    wait_for_effect(&mut app, "burning");
+
+    let registry = app.world().resource::<GameplayEffectRegistry>();
+    let burning = registry.get("burning").unwrap();
+    assert_eq!(burning.duration_policy, DurationPolicy::HasDuration);
+    assert_eq!(burning.duration_magnitude, 6.0);
+    assert_eq!(
+        burning.modifiers[0].magnitude,
+        MagnitudeCalculation::scaled(-4.0, 1.2)
+    );
+
+    // Simulate a hot reload that renames the effect and changes its duration.
+    let handle = app.world().resource::<EffectAssetHandles>().0[0].clone();
+    {
+        let mut assets = app
+            .world_mut()
+            .resource_mut::<Assets<GameplayEffectAsset>>();
+        let asset = assets.get_mut(&handle).unwrap();
+        asset.definitions[0].id = "burning_v2".into();
+        asset.definitions[0].duration_magnitude = 3.0;
+    }
+    // The change is announced at the end of this frame and applied the next.
+    app.update();
+    app.update();
+
+    let registry = app.world().resource::<GameplayEffectRegistry>();
+    assert!(registry.get("burning").is_none());
+    assert_eq!(registry.get("burning_v2").unwrap().duration_magnitude, 3.0);
+}
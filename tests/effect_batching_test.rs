@@ -0,0 +1,237 @@
+//! Tests for applying gameplay effects through `BatchedEvents`.
+
+#![cfg(not(feature = "headless"))]
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    attributes::{AttributeData, AttributeName},
+    core::{BatchedEvents, OwnedTags},
+    cues::*,
+    effects::*,
+};
+use bevy_gameplay_tag::{GameplayTag, GameplayTagsPlugin};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct HitCounter(Arc<AtomicUsize>);
+
+impl GameplayCueNotifyStatic for HitCounter {
+    fn on_execute(&self, _: Entity, _: &GameplayCueParameters, _: &mut Commands) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn create_app() -> (App, Arc<AtomicUsize>) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    let hits = Arc::new(AtomicUsize::new(0));
+    app.register_gameplay_cue_handler("GameplayCue.Hit", HitCounter(hits.clone()));
+    app.update();
+
+    let mut registry = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    registry.register(
+        GameplayEffectDefinition::new("blast")
+            .add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddBase,
+                MagnitudeCalculation::scalar(-10.0),
+            ))
+            .add_gameplay_cue(GameplayEffectCue::new(GameplayTag::new("GameplayCue.Hit"))),
+    );
+    registry.register(
+        GameplayEffectDefinition::new("frenzy")
+            .with_duration_policy(DurationPolicy::HasDuration)
+            .with_duration(5.0)
+            .with_stacking_policy(StackingPolicy::StackCount { max_stacks: 5 }),
+    );
+    (app, hits)
+}
+
+fn spawn_target(app: &mut App) -> Entity {
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    app.world_mut().spawn((
+        AttributeName::new("Health"),
+        AttributeData::new(100.0),
+        ChildOf(target),
+    ));
+    target
+}
+
+fn health(app: &mut App, target: Entity) -> f32 {
+    let mut query = app
+        .world_mut()
+        .query::<(&AttributeName, &AttributeData, &ChildOf)>();
+    query
+        .iter(app.world())
+        .find(|(name, _, child_of)| name.as_str() == "Health" && child_of.parent() == target)
+        .map(|(_, data, _)| data.base_value)
+        .unwrap()
+}
+
+fn push(app: &mut App, event: ApplyGameplayEffectEvent) {
+    app.world_mut()
+        .resource_mut::<BatchedEvents<ApplyGameplayEffectEvent>>()
+        .push(event);
+}
+
+#[test]
+fn test_batched_area_hit_applies_to_every_target() {
+    let (mut app, hits) = create_app();
+    let targets: Vec<_> = (0..4).map(|_| spawn_target(&mut app)).collect();
+    for &target in &targets {
+        push(&mut app, ApplyGameplayEffectEvent::new("blast", target));
+        push(&mut app, ApplyGameplayEffectEvent::new("blast", target));
+    }
+    push(
+        &mut app,
+        ApplyGameplayEffectEvent::new("missing", targets[0]),
+    );
+    app.update();
+
+    for &target in &targets {
+        assert_eq!(health(&mut app, target), 80.0);
+    }
+    assert_eq!(hits.load(Ordering::SeqCst), 8);
+    assert!(
+        app.world()
+            .resource::<BatchedEvents<ApplyGameplayEffectEvent>>()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_batched_applications_stack_like_triggered_ones() {
+    let (mut app, _) = create_app();
+    let target = spawn_target(&mut app);
+    for _ in 0..3 {
+        push(&mut app, ApplyGameplayEffectEvent::new("frenzy", target));
+    }
+    app.update();
+
+    let mut query = app.world_mut().query::<&ActiveGameplayEffect>();
+    let effects: Vec<_> = query.iter(app.world()).collect();
+    assert_eq!(effects.len(), 1);
+    assert_eq!(effects[0].stack_count, 3);
+}
+
+#[test]
+fn test_same_frame_applications_follow_priority() {
+    let (mut app, _) = create_app();
+    let mut registry = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    registry.register(
+        GameplayEffectDefinition::new("reset")
+            .with_priority(10)
+            .add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::Override,
+                MagnitudeCalculation::scalar(50.0),
+            )),
+    );
+    let target = spawn_target(&mut app);
+
+    // Sent after the blast, the higher priority reset still applies first.
+    push(&mut app, ApplyGameplayEffectEvent::new("blast", target));
+    push(&mut app, ApplyGameplayEffectEvent::new("reset", target));
+    app.update();
+    assert_eq!(health(&mut app, target), 40.0);
+
+    // Equal priorities keep the order they were sent in.
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("reset").add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::Override,
+                MagnitudeCalculation::scalar(50.0),
+            )),
+        );
+    push(&mut app, ApplyGameplayEffectEvent::new("blast", target));
+    push(&mut app, ApplyGameplayEffectEvent::new("reset", target));
+    app.update();
+    assert_eq!(health(&mut app, target), 50.0);
+}
+
+#[test]
+fn test_triggered_and_batched_applications_follow_priority() {
+    let (mut app, _) = create_app();
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("reset")
+                .with_priority(10)
+                .add_modifier(ModifierInfo::new(
+                    "Health",
+                    ModifierOperation::Override,
+                    MagnitudeCalculation::scalar(50.0),
+                )),
+        );
+    let target = spawn_target(&mut app);
+    let other = spawn_target(&mut app);
+
+    // A triggered blast first applies the queued higher priority reset, and
+    // leaves applications queued for other targets alone.
+    push(&mut app, ApplyGameplayEffectEvent::new("reset", target));
+    push(&mut app, ApplyGameplayEffectEvent::new("reset", other));
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("blast", target));
+    assert_eq!(health(&mut app, target), 40.0);
+    assert_eq!(health(&mut app, other), 100.0);
+    app.update();
+    assert_eq!(health(&mut app, target), 40.0);
+    assert_eq!(health(&mut app, other), 50.0);
+
+    // A triggered reset lands before a queued lower priority blast.
+    push(&mut app, ApplyGameplayEffectEvent::new("blast", target));
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("reset", target));
+    app.update();
+    assert_eq!(health(&mut app, target), 40.0);
+}
+
+#[test]
+fn test_batched_groups_evaluate_magnitudes_per_level() {
+    let (mut app, _) = create_app();
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("aura_tick").add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddBase,
+                MagnitudeCalculation::scaled(-10.0, 2.0),
+            )),
+        );
+    let targets: Vec<_> = (0..3).map(|_| spawn_target(&mut app)).collect();
+    push(
+        &mut app,
+        ApplyGameplayEffectEvent::new("aura_tick", targets[0]),
+    );
+    push(
+        &mut app,
+        ApplyGameplayEffectEvent::new("aura_tick", targets[1]).with_level(2),
+    );
+    push(
+        &mut app,
+        ApplyGameplayEffectEvent::new("aura_tick", targets[2]),
+    );
+    // Levels fall in separate groups, but still stack on one effect
+    let stacked = targets[0];
+    push(&mut app, ApplyGameplayEffectEvent::new("frenzy", stacked));
+    push(
+        &mut app,
+        ApplyGameplayEffectEvent::new("frenzy", stacked).with_level(2),
+    );
+    app.update();
+
+    assert_eq!(health(&mut app, targets[0]), 90.0);
+    assert_eq!(health(&mut app, targets[1]), 80.0);
+    assert_eq!(health(&mut app, targets[2]), 90.0);
+    let mut query = app.world_mut().query::<&ActiveGameplayEffect>();
+    let effects: Vec<_> = query.iter(app.world()).collect();
+    assert_eq!(effects.len(), 1);
+    assert_eq!(effects[0].stack_count, 2);
+}
@@ -0,0 +1,104 @@
+//! Tests for the on-apply and on-remove callbacks of effect definitions.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{GasPlugin, core::OwnedTags, effects::*};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+
+#[derive(Component)]
+struct Enraged {
+    level: i32,
+}
+
+#[derive(Resource, Default)]
+struct Removed(Vec<EffectCallbackContext>);
+
+fn record_removed(In(context): In<EffectCallbackContext>, mut removed: ResMut<Removed>) {
+    removed.0.push(context);
+}
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .init_resource::<Removed>();
+    app.update();
+
+    let record = app.world_mut().register_system(record_removed);
+    let mut registry = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    registry.register(
+        GameplayEffectDefinition::new("enrage")
+            .with_duration_policy(DurationPolicy::Infinite)
+            .with_on_applied(|ctx, commands| {
+                commands
+                    .entity(ctx.target)
+                    .insert(Enraged { level: ctx.level });
+            })
+            .with_on_removed(|ctx, commands| {
+                commands.entity(ctx.target).remove::<Enraged>();
+            })
+            .with_on_removed_system(record),
+    );
+    registry.register(
+        GameplayEffectDefinition::new("smite").with_on_applied(|ctx, commands| {
+            commands.entity(ctx.target).insert(Enraged { level: 0 });
+        }),
+    );
+    app
+}
+
+#[test]
+fn test_callbacks_run_on_apply_and_remove() {
+    let mut app = create_app();
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    let source = app.world_mut().spawn_empty().id();
+
+    app.world_mut().trigger(
+        ApplyGameplayEffectEvent::new("enrage", target)
+            .with_source(source)
+            .with_level(3),
+    );
+    app.update();
+    app.update();
+    assert_eq!(app.world().get::<Enraged>(target).unwrap().level, 3);
+
+    let effect = app
+        .world_mut()
+        .query_filtered::<Entity, With<ActiveGameplayEffect>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut()
+        .trigger(RemoveGameplayEffectEvent { effect });
+    app.update();
+    app.update();
+    assert!(app.world().get::<Enraged>(target).is_none());
+
+    let removed = &app.world().resource::<Removed>().0;
+    assert_eq!(removed.len(), 1);
+    assert_eq!(removed[0].effect, effect);
+    assert_eq!(removed[0].target, target);
+    assert_eq!(removed[0].effect_id.as_ref(), "enrage");
+    assert_eq!(removed[0].context.source, Some(source));
+}
+
+#[test]
+fn test_despawned_target_runs_remove_callbacks_and_instants_run_none() {
+    let mut app = create_app();
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("smite", target));
+    app.update();
+    app.update();
+    assert!(app.world().get::<Enraged>(target).is_none());
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("enrage", target));
+    app.update();
+    app.update();
+    app.world_mut().despawn(target);
+    app.update();
+    assert_eq!(app.world().resource::<Removed>().0.len(), 1);
+}
@@ -0,0 +1,149 @@
+//! Tests for conversion effects such as lifesteal.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{GasPlugin, attributes::*, effects::*};
+use bevy_gameplay_tag::{GameplayTag, GameplayTagsPlugin};
+use std::time::Duration;
+use string_cache::DefaultAtom as Atom;
+
+#[derive(Resource, Default)]
+struct Applications(Vec<(Atom, Entity, Option<Entity>)>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )))
+    .init_resource::<Applications>()
+    .add_observer(
+        |ev: On<ApplyGameplayEffectEvent>, mut applications: ResMut<Applications>| {
+            applications.0.push((
+                ev.spec.effect_id.clone(),
+                ev.spec.target,
+                ev.spec.context.source,
+            ));
+        },
+    );
+    app.update();
+
+    let data_tag = GameplayTag::new("Data.Damage");
+    let mut registry = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    registry.register(
+        GameplayEffectDefinition::new("lifesteal_heal").add_modifier(ModifierInfo::new(
+            "Health",
+            ModifierOperation::AddBase,
+            MagnitudeCalculation::set_by_caller(data_tag.clone()),
+        )),
+    );
+    registry.register(
+        GameplayEffectDefinition::new("vampiric_strike")
+            .add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddBase,
+                MagnitudeCalculation::scalar(-40.0),
+            ))
+            .add_conversion(EffectConversion::new(
+                "Health",
+                "lifesteal_heal",
+                0.25,
+                data_tag.clone(),
+            )),
+    );
+    registry.register(
+        GameplayEffectDefinition::new("draining_curse")
+            .with_duration(1.0)
+            .with_period(0.5)
+            .add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddCurrent,
+                MagnitudeCalculation::scalar(-10.0),
+            ))
+            .add_conversion(EffectConversion::new(
+                "Health",
+                "lifesteal_heal",
+                0.5,
+                data_tag,
+            )),
+    );
+    app
+}
+
+fn spawn_with_health(app: &mut App, health: f32) -> (Entity, Entity) {
+    let owner = app.world_mut().spawn_empty().id();
+    let attribute = app
+        .world_mut()
+        .spawn((
+            AttributeName::new("Health"),
+            AttributeData::new(health),
+            ChildOf(owner),
+        ))
+        .id();
+    (owner, attribute)
+}
+
+fn base_value(app: &App, attribute: Entity) -> f32 {
+    app.world()
+        .get::<AttributeData>(attribute)
+        .unwrap()
+        .base_value
+}
+
+#[test]
+fn test_instant_conversion_heals_source() {
+    let mut app = create_app();
+    let (attacker, attacker_health) = spawn_with_health(&mut app, 50.0);
+    let (victim, victim_health) = spawn_with_health(&mut app, 100.0);
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("vampiric_strike", victim).with_source(attacker));
+    app.update();
+
+    assert_eq!(base_value(&app, victim_health), 60.0);
+    assert_eq!(base_value(&app, attacker_health), 60.0);
+    // The heal goes through the normal pipeline with the strike's context.
+    let applications = &app.world().resource::<Applications>().0;
+    assert!(applications.contains(&("lifesteal_heal".into(), attacker, Some(attacker))));
+}
+
+#[test]
+fn test_conversion_needs_source() {
+    let mut app = create_app();
+    let (victim, victim_health) = spawn_with_health(&mut app, 100.0);
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("vampiric_strike", victim));
+    app.update();
+
+    assert_eq!(base_value(&app, victim_health), 60.0);
+    assert_eq!(app.world().resource::<Applications>().0.len(), 1);
+}
+
+#[test]
+fn test_periodic_conversion_converts_each_tick() {
+    let mut app = create_app();
+    let (attacker, attacker_health) = spawn_with_health(&mut app, 50.0);
+    let (victim, _) = spawn_with_health(&mut app, 100.0);
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("draining_curse", victim).with_source(attacker));
+    for _ in 0..8 {
+        app.update();
+    }
+
+    let heals = app
+        .world()
+        .resource::<Applications>()
+        .0
+        .iter()
+        .filter(|(effect_id, ..)| effect_id.as_ref() == "lifesteal_heal")
+        .count();
+    // Each tick removes 10 health and heals the attacker for half of it.
+    assert!(heals >= 2);
+    assert_eq!(base_value(&app, attacker_health), 50.0 + 5.0 * heals as f32);
+}
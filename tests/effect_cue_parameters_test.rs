@@ -0,0 +1,143 @@
+//! Tests that effect-triggered cues derive their parameters from the effect
+//! spec, context and evaluated modifiers.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{GasPlugin, attributes::*, cues::*, effects::*};
+use bevy_gameplay_tag::{GameplayTag, GameplayTagsPlugin};
+
+struct TestAttributeSet;
+
+impl AttributeSetDefinition for TestAttributeSet {
+    fn attribute_names() -> &'static [&'static str] {
+        &["Health", "Armor"]
+    }
+
+    fn attribute_metadata(name: &str) -> Option<AttributeMetadata> {
+        match name {
+            "Health" => Some(AttributeMetadata::new("Health").with_min(0.0)),
+            "Armor" => Some(AttributeMetadata::new("Armor").with_min(0.0)),
+            _ => None,
+        }
+    }
+
+    fn default_value(name: &str) -> f32 {
+        match name {
+            "Health" => 100.0,
+            "Armor" => 10.0,
+            _ => 0.0,
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+struct ReceivedCues(Vec<(GameplayCueEvent, GameplayCueParameters)>);
+
+fn setup() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.init_resource::<ReceivedCues>();
+    app.add_observer(
+        |ev: On<TriggerGameplayCueEvent>, mut received: ResMut<ReceivedCues>| {
+            received.0.push((ev.event_type, ev.parameters.clone()));
+        },
+    );
+    app.update();
+
+    let target = {
+        let mut commands = app.world_mut().commands();
+        let target = commands.spawn_empty().id();
+        TestAttributeSet::create_attributes(&mut commands, target);
+        target
+    };
+    app.update();
+
+    (app, target)
+}
+
+#[test]
+fn test_instant_effect_cue_parameters_come_from_context_and_modifier() {
+    let (mut app, target) = setup();
+
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("fire_hit")
+                .add_modifier(ModifierInfo::new(
+                    "Armor",
+                    ModifierOperation::AddCurrent,
+                    MagnitudeCalculation::scalar(-2.0),
+                ))
+                .add_modifier(ModifierInfo::new(
+                    "Health",
+                    ModifierOperation::AddCurrent,
+                    MagnitudeCalculation::scalar(-25.0),
+                ))
+                .add_gameplay_cue(
+                    GameplayEffectCue::new(GameplayTag::new("GameplayCue.Test"))
+                        .with_level_range(1, Some(5))
+                        .with_magnitude_attribute("Health"),
+                ),
+        );
+
+    let owner = app.world_mut().spawn_empty().id();
+    let projectile = app.world_mut().spawn_empty().id();
+    app.world_mut().trigger(ApplyGameplayEffectEvent::from_spec(
+        GameplayEffectSpec::new("fire_hit", target)
+            .with_level(3)
+            .with_context(
+                GameplayEffectContext::new()
+                    .with_source(owner)
+                    .with_instigator(projectile)
+                    .with_hit_location(Vec3::new(1.0, 2.0, 3.0))
+                    .with_hit_normal(Vec3::X),
+            ),
+    ));
+    app.update();
+
+    let received = app.world().resource::<ReceivedCues>();
+    assert_eq!(received.0.len(), 1);
+    let (event_type, parameters) = &received.0[0];
+    assert_eq!(*event_type, GameplayCueEvent::Executed);
+    assert_eq!(parameters.instigator, Some(owner));
+    assert_eq!(parameters.effect_causer, Some(projectile));
+    assert_eq!(parameters.target, Some(target));
+    assert_eq!(parameters.location, Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(parameters.normal, Vec3::X);
+    assert_eq!(parameters.normal_impact_normal, Some(Vec3::X));
+    assert_eq!(parameters.raw_magnitude, -25.0);
+    assert_eq!(parameters.normalized_magnitude, 0.5);
+    assert_eq!(parameters.gameplay_effect_level, 3.0);
+}
+
+#[test]
+fn test_duration_effect_on_active_cue_uses_first_modifier() {
+    let (mut app, target) = setup();
+
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("armor_buff")
+                .with_duration(5.0)
+                .add_modifier(ModifierInfo::new(
+                    "Armor",
+                    ModifierOperation::AddCurrent,
+                    MagnitudeCalculation::scalar(15.0),
+                ))
+                .add_gameplay_cue(GameplayEffectCue::new(GameplayTag::new("GameplayCue.Test"))),
+        );
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("armor_buff", target).with_level(2));
+    app.update();
+
+    let received = app.world().resource::<ReceivedCues>();
+    let (event_type, parameters) = &received.0[0];
+    assert_eq!(*event_type, GameplayCueEvent::OnActive);
+    assert_eq!(parameters.raw_magnitude, 15.0);
+    assert_eq!(parameters.normalized_magnitude, 1.0);
+    assert!(parameters.source_effect.is_some());
+}
@@ -0,0 +1,147 @@
+//! Tests that removing an active effect by any path fires its Removed cues,
+//! and that cue actors never outlive their cue.
+
+#![cfg(not(feature = "headless"))]
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    core::{GasSettings, OwnedTags},
+    cues::*,
+    effects::*,
+};
+use bevy_gameplay_tag::{GameplayTag, GameplayTagsPlugin};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+struct RemoveCounter(Arc<AtomicUsize>);
+
+impl GameplayCueNotifyStatic for RemoveCounter {
+    fn on_execute(&self, _: Entity, _: &GameplayCueParameters, _: &mut Commands) {}
+
+    fn on_remove(&self, _: Entity, _: &GameplayCueParameters, _: &mut Commands) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn setup() -> (App, Entity, Arc<AtomicUsize>) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    let removals = Arc::new(AtomicUsize::new(0));
+    app.register_gameplay_cue_handler("GameplayCue.Aura", RemoveCounter(removals.clone()))
+        .register_gameplay_cue_actor("GameplayCue.Shield", GameplayCueActorConfig::new());
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("shielded_aura")
+                .with_duration_policy(DurationPolicy::Infinite)
+                .add_gameplay_cue(GameplayEffectCue::new(GameplayTag::new("GameplayCue.Aura")))
+                .add_gameplay_cue(GameplayEffectCue::new(GameplayTag::new(
+                    "GameplayCue.Shield",
+                ))),
+        );
+
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    (app, target, removals)
+}
+
+fn apply(app: &mut App, target: Entity) -> Entity {
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("shielded_aura", target));
+    app.update();
+
+    let mut query = app
+        .world_mut()
+        .query_filtered::<Entity, With<ActiveGameplayEffect>>();
+    query
+        .iter(app.world())
+        .last()
+        .expect("infinite effect should spawn an effect entity")
+}
+
+fn shield_actors(app: &mut App) -> usize {
+    let mut query = app.world_mut().query::<&GameplayCueNotifyActor>();
+    query.iter(app.world()).count()
+}
+
+#[test]
+fn test_despawned_infinite_effect_fires_removed_cues() {
+    let (mut app, target, removals) = setup();
+    let effect = apply(&mut app, target);
+    assert_eq!(shield_actors(&mut app), 1);
+
+    app.world_mut().despawn(effect);
+    app.update();
+    app.update();
+
+    assert_eq!(removals.load(Ordering::SeqCst), 1);
+    assert_eq!(shield_actors(&mut app), 0);
+}
+
+#[test]
+fn test_removing_one_effect_keeps_other_effects_actors() {
+    let (mut app, target, _) = setup();
+    let first = apply(&mut app, target);
+    apply(&mut app, target);
+    assert_eq!(shield_actors(&mut app), 2);
+
+    app.world_mut().despawn(first);
+    app.update();
+    app.update();
+
+    assert_eq!(shield_actors(&mut app), 1);
+}
+
+#[test]
+fn test_executed_actor_cues_expire_without_removed_event() {
+    let (mut app, target, _) = setup();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )));
+    for _ in 0..5 {
+        app.world_mut().trigger(TriggerGameplayCueEvent::new(
+            GameplayTag::new("GameplayCue.Shield"),
+            GameplayCueEvent::Executed,
+            GameplayCueParameters::new().with_target(target),
+        ));
+        app.update();
+    }
+    assert!(shield_actors(&mut app) > 0);
+
+    let frames = (DEFAULT_EXECUTED_ACTOR_LIFETIME / 0.25) as usize + 2;
+    for _ in 0..frames {
+        app.update();
+    }
+    assert_eq!(shield_actors(&mut app), 0);
+}
+
+#[test]
+fn test_executed_actor_lifetime_comes_from_settings() {
+    let (mut app, target, _) = setup();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )));
+    app.world_mut()
+        .resource_mut::<GasSettings>()
+        .executed_cue_actor_lifetime = 0.5;
+    app.world_mut().trigger(TriggerGameplayCueEvent::new(
+        GameplayTag::new("GameplayCue.Shield"),
+        GameplayCueEvent::Executed,
+        GameplayCueParameters::new().with_target(target),
+    ));
+    app.update();
+    assert_eq!(shield_actors(&mut app), 1);
+
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(shield_actors(&mut app), 0);
+}
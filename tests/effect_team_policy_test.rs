@@ -0,0 +1,91 @@
+//! Tests that effect team policies block heals on enemies and damage on allies.
+
+use bevy::ecs::relationship::Relationship;
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{GasPlugin, attributes::*, core::Team, effects::*};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+
+struct TestAttributeSet;
+
+impl AttributeSetDefinition for TestAttributeSet {
+    fn attribute_names() -> &'static [&'static str] {
+        &["Health"]
+    }
+
+    fn attribute_metadata(name: &str) -> Option<AttributeMetadata> {
+        match name {
+            "Health" => Some(AttributeMetadata::new("Health").with_min(0.0)),
+            _ => None,
+        }
+    }
+
+    fn default_value(name: &str) -> f32 {
+        match name {
+            "Health" => 100.0,
+            _ => 0.0,
+        }
+    }
+}
+
+fn get_health(world: &mut World, owner: Entity) -> f32 {
+    let mut query = world.query::<(&AttributeData, &AttributeName, &ChildOf)>();
+    query
+        .iter(world)
+        .find(|(_, name, child_of)| child_of.get() == owner && name.as_str() == "Health")
+        .map(|(data, _, _)| data.current_value)
+        .expect("health attribute should exist")
+}
+
+fn spawn_actor(app: &mut App, team: u32) -> Entity {
+    let mut commands = app.world_mut().commands();
+    let actor = commands.spawn(Team(team)).id();
+    TestAttributeSet::create_attributes(&mut commands, actor);
+    actor
+}
+
+#[test]
+fn test_team_policy_blocks_wrong_side() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.update();
+
+    let mut registry = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    registry.register(
+        GameplayEffectDefinition::new("heal")
+            .with_team_policy(EffectTeamPolicy::FriendlyOnly)
+            .add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddCurrent,
+                MagnitudeCalculation::scalar(10.0),
+            )),
+    );
+    registry.register(
+        GameplayEffectDefinition::new("damage")
+            .with_team_policy(EffectTeamPolicy::HostileOnly)
+            .add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddCurrent,
+                MagnitudeCalculation::scalar(-10.0),
+            )),
+    );
+
+    let caster = spawn_actor(&mut app, 1);
+    let ally = spawn_actor(&mut app, 1);
+    let enemy = spawn_actor(&mut app, 2);
+    app.update();
+
+    for target in [ally, enemy] {
+        for effect in ["heal", "damage"] {
+            app.world_mut()
+                .trigger(ApplyGameplayEffectEvent::new(effect, target).with_instigator(caster));
+        }
+    }
+    app.update();
+
+    assert_eq!(get_health(app.world_mut(), ally), 110.0);
+    assert_eq!(get_health(app.world_mut(), enemy), 90.0);
+}
@@ -0,0 +1,204 @@
+//! Tests for effects applied by `GameplayEffectVolume`s.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{
+    GasPlugin, abilities::*, attributes::*, core::OwnedTags, core::Team, effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+
+#[derive(Resource, Default)]
+struct Crossings {
+    entered: Vec<VolumeEnteredEvent>,
+    exited: Vec<VolumeExitedEvent>,
+}
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .init_resource::<Crossings>()
+    .add_observer(
+        |ev: On<VolumeEnteredEvent>, mut crossings: ResMut<Crossings>| {
+            crossings.entered.push(*ev.event());
+        },
+    )
+    .add_observer(
+        |ev: On<VolumeExitedEvent>, mut crossings: ResMut<Crossings>| {
+            crossings.exited.push(*ev.event());
+        },
+    );
+    app.update();
+
+    let mut registry = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    registry.register(
+        GameplayEffectDefinition::new("capture_buff")
+            .with_duration_policy(DurationPolicy::Infinite)
+            .add_modifier(ModifierInfo::new(
+                "Attack",
+                ModifierOperation::AddCurrent,
+                MagnitudeCalculation::scalar(10.0),
+            )),
+    );
+    registry.register(
+        GameplayEffectDefinition::new("fountain_heal")
+            .with_duration_policy(DurationPolicy::HasDuration)
+            .with_duration(5.0),
+    );
+    app
+}
+
+fn spawn_actor(app: &mut App, position: Vec3) -> (Entity, Entity) {
+    let actor = app
+        .world_mut()
+        .spawn((OwnedTags::default(), Transform::from_translation(position)))
+        .id();
+    let attack = app
+        .world_mut()
+        .spawn((
+            AttributeName::new("Attack"),
+            AttributeData::new(20.0),
+            ChildOf(actor),
+        ))
+        .id();
+    (actor, attack)
+}
+
+fn move_to(app: &mut App, entity: Entity, position: Vec3) {
+    app.world_mut()
+        .get_mut::<Transform>(entity)
+        .unwrap()
+        .translation = position;
+    // Transform propagation, the volume check, the application or removal
+    // and the aggregation each take a frame.
+    for _ in 0..4 {
+        app.update();
+    }
+}
+
+fn attack(app: &App, attribute: Entity) -> f32 {
+    app.world()
+        .get::<AttributeData>(attribute)
+        .unwrap()
+        .current_value
+}
+
+fn effect_count(app: &mut App) -> usize {
+    let mut effects = app.world_mut().query::<&ActiveGameplayEffect>();
+    effects.iter(app.world()).count()
+}
+
+#[test]
+fn test_entering_applies_and_leaving_removes_infinite_effect() {
+    let mut app = create_app();
+    let (actor, attack_attribute) = spawn_actor(&mut app, Vec3::new(10.0, 0.0, 0.0));
+    app.update();
+    let volume = app
+        .world_mut()
+        .spawn(GameplayEffectVolume::new(
+            GameplayEffectSpec::new("capture_buff", Entity::PLACEHOLDER),
+            TargetShape::sphere(5.0),
+        ))
+        .id();
+    app.update();
+    app.update();
+    assert_eq!(attack(&app, attack_attribute), 20.0);
+
+    move_to(&mut app, actor, Vec3::new(2.0, 0.0, 0.0));
+    assert_eq!(attack(&app, attack_attribute), 30.0);
+    assert!(
+        app.world()
+            .get::<VolumeOccupants>(volume)
+            .unwrap()
+            .contains(actor)
+    );
+
+    // Staying inside doesn't apply it again.
+    move_to(&mut app, actor, Vec3::new(1.0, 0.0, 0.0));
+    assert_eq!(app.world().resource::<Crossings>().entered.len(), 1);
+
+    move_to(&mut app, actor, Vec3::new(10.0, 0.0, 0.0));
+    assert_eq!(attack(&app, attack_attribute), 20.0);
+    assert_eq!(effect_count(&mut app), 0);
+    let crossings = app.world().resource::<Crossings>();
+    assert_eq!(
+        crossings.exited,
+        vec![VolumeExitedEvent {
+            volume,
+            entity: actor
+        }]
+    );
+}
+
+#[test]
+fn test_actors_entering_together_are_applied_as_one_batch() {
+    #[derive(Resource, Default)]
+    struct Triggered(usize);
+
+    let mut app = create_app();
+    app.init_resource::<Triggered>().add_observer(
+        |_: On<ApplyGameplayEffectEvent>, mut triggered: ResMut<Triggered>| {
+            triggered.0 += 1;
+        },
+    );
+    app.world_mut().spawn(GameplayEffectVolume::new(
+        GameplayEffectSpec::new("fountain_heal", Entity::PLACEHOLDER),
+        TargetShape::sphere(5.0),
+    ));
+    for x in [-1.0, 0.0, 1.0] {
+        spawn_actor(&mut app, Vec3::new(x, 0.0, 0.0));
+    }
+    app.update();
+
+    // Queued rather than triggered per actor, and applied the same frame.
+    assert_eq!(app.world().resource::<Triggered>().0, 0);
+    assert_eq!(effect_count(&mut app), 3);
+    assert_eq!(app.world().resource::<Crossings>().entered.len(), 3);
+}
+
+#[test]
+fn test_duration_effects_outlast_the_volume() {
+    let mut app = create_app();
+    app.world_mut().spawn(GameplayEffectVolume::new(
+        GameplayEffectSpec::new("fountain_heal", Entity::PLACEHOLDER),
+        TargetShape::sphere(5.0),
+    ));
+    let (actor, _) = spawn_actor(&mut app, Vec3::ZERO);
+    app.update();
+    assert_eq!(effect_count(&mut app), 1);
+
+    move_to(&mut app, actor, Vec3::new(10.0, 0.0, 0.0));
+    assert_eq!(effect_count(&mut app), 1);
+}
+
+#[test]
+fn test_filter_and_despawned_volume() {
+    let mut app = create_app();
+    let owner = app.world_mut().spawn((OwnedTags::default(), Team(1))).id();
+    let volume = app
+        .world_mut()
+        .spawn(
+            GameplayEffectVolume::new(
+                GameplayEffectSpec::new("capture_buff", Entity::PLACEHOLDER).with_source(owner),
+                TargetShape::sphere(5.0),
+            )
+            .with_filter(TargetFilter::new().friendly()),
+        )
+        .id();
+    let (ally, ally_attack) = spawn_actor(&mut app, Vec3::ZERO);
+    app.world_mut().entity_mut(ally).insert(Team(1));
+    let (enemy, enemy_attack) = spawn_actor(&mut app, Vec3::ZERO);
+    app.world_mut().entity_mut(enemy).insert(Team(2));
+    app.update();
+    app.update();
+    assert_eq!(attack(&app, ally_attack), 30.0);
+    assert_eq!(attack(&app, enemy_attack), 20.0);
+
+    app.world_mut().despawn(volume);
+    app.update();
+    app.update();
+    assert_eq!(attack(&app, ally_attack), 20.0);
+}
@@ -0,0 +1,137 @@
+//! Tests for driving abilities from `bevy_enhanced_input` actions through
+//! `EnhancedInputAdapter`.
+
+#![cfg(feature = "enhanced_input")]
+
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_enhanced_input::prelude::*;
+use bevy_gameplay_ability_system::{GasPlugin, abilities::*, core::OwnedTags};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use std::time::Duration;
+
+const PRIMARY: i32 = 1;
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Beam;
+
+#[derive(Resource, Default)]
+struct Releases(Vec<f32>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        EnhancedInputPlugin,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .add_input_context::<Player>()
+    .add_ability_input_adapter(EnhancedInputAdapter::<Player>::new().bind::<Beam>(PRIMARY))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )))
+    .init_resource::<Releases>()
+    .add_observer(
+        |ev: On<AbilityInputReleasedEvent>, mut releases: ResMut<Releases>| {
+            releases.0.push(ev.held_secs);
+        },
+    );
+    // Enhanced input creates its context storage when the app finishes.
+    app.finish();
+    app.update();
+    app
+}
+
+/// Grants the beam ability with `policy` to a player, returning the action
+/// and the spec.
+fn grant(app: &mut App, policy: AbilityInputPolicy) -> (Entity, Entity) {
+    app.world_mut()
+        .resource_mut::<AbilityRegistry>()
+        .register(AbilityDefinition::new("beam").with_input_policy(policy));
+    let owner = app
+        .world_mut()
+        .spawn((
+            Player,
+            OwnedTags::default(),
+            actions!(Player[Action::<Beam>::new()]),
+        ))
+        .id();
+    let action = app.world().get::<Actions<Player>>(owner).unwrap()[0];
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("beam", 1).with_input_id(PRIMARY),
+            AbilityOwner(owner),
+        ))
+        .id();
+    app.update();
+    (action, spec)
+}
+
+fn set_action(app: &mut App, action: Entity, state: TriggerState) {
+    app.world_mut().entity_mut(action).insert(ActionMock::new(
+        state,
+        state == TriggerState::Fired,
+        MockSpan::Manual,
+    ));
+    app.update();
+}
+
+fn is_active(app: &App, spec: Entity) -> bool {
+    app.world()
+        .get::<AbilityActiveState>(spec)
+        .is_some_and(|state| state.is_active)
+}
+
+#[test]
+fn test_while_held_channels_until_action_stops_firing() {
+    let mut app = create_app();
+    let (action, spec) = grant(&mut app, AbilityInputPolicy::WhileHeld);
+
+    set_action(&mut app, action, TriggerState::Fired);
+    assert!(is_active(&app, spec));
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(is_active(&app, spec));
+    assert!(app.world().get::<AbilityInputHeld>(spec).is_some());
+
+    set_action(&mut app, action, TriggerState::None);
+    assert!(!is_active(&app, spec));
+    assert!(app.world().get::<AbilityInputHeld>(spec).is_none());
+    assert_eq!(app.world().resource::<Releases>().0, [1.0]);
+}
+
+#[test]
+fn test_toggle_flips_on_each_new_fire() {
+    let mut app = create_app();
+    let (action, spec) = grant(&mut app, AbilityInputPolicy::Toggle);
+
+    set_action(&mut app, action, TriggerState::Fired);
+    app.update();
+    set_action(&mut app, action, TriggerState::None);
+    assert!(is_active(&app, spec));
+
+    set_action(&mut app, action, TriggerState::Fired);
+    assert!(!is_active(&app, spec));
+}
+
+#[test]
+fn test_ongoing_action_does_not_press() {
+    let mut app = create_app();
+    let (action, spec) = grant(&mut app, AbilityInputPolicy::Pressed);
+
+    // e.g. a `Hold` condition still waiting for its hold time
+    set_action(&mut app, action, TriggerState::Ongoing);
+    assert!(!is_active(&app, spec));
+
+    set_action(&mut app, action, TriggerState::Fired);
+    assert!(is_active(&app, spec));
+}
@@ -0,0 +1,100 @@
+//! Tests that data errors surface through the configured `GasErrorPolicy`.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::*,
+    core::{BlockedAbilityTags, OwnedTags},
+    effects::*,
+    error::{GasError, GasErrorEvent, GasErrorPolicy},
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+
+#[derive(Resource, Default)]
+struct Reported(Vec<GasError>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(GasErrorPolicy::Event)
+    .init_resource::<Reported>()
+    .add_observer(|ev: On<GasErrorEvent>, mut reported: ResMut<Reported>| {
+        reported.0.push(ev.error.clone());
+    });
+    app.update();
+    app
+}
+
+#[test]
+fn test_missing_definitions_and_attributes_are_reported() {
+    let mut app = create_app();
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("smite").add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddBase,
+                MagnitudeCalculation::scalar(-5.0),
+            )),
+        );
+    let target = app
+        .world_mut()
+        .spawn((OwnedTags::default(), BlockedAbilityTags::default()))
+        .id();
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("unregistered", 1),
+            AbilityActiveState::default(),
+            AbilityOwner(target),
+        ))
+        .id();
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("smiet", target));
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("smite", target));
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, target));
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<Reported>().0,
+        vec![
+            GasError::EffectDefinitionNotFound {
+                effect_id: "smiet".into()
+            },
+            GasError::AttributeNotFound {
+                attribute_name: "Health".into(),
+                owner: target,
+            },
+            GasError::AbilityDefinitionNotFound {
+                ability_id: "unregistered".into()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_registries_return_errors_for_unknown_ids() {
+    let app = create_app();
+    assert_eq!(
+        app.world()
+            .resource::<GameplayEffectRegistry>()
+            .try_get("nope")
+            .err(),
+        Some(GasError::EffectDefinitionNotFound {
+            effect_id: "nope".into()
+        })
+    );
+    assert!(
+        app.world()
+            .resource::<AbilityRegistry>()
+            .try_get("nope")
+            .is_err()
+    );
+}
@@ -0,0 +1,149 @@
+//! Tests for sending gameplay events with payloads to one entity.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::{
+        AbilityActivationInfo, AbilityBehavior, AbilityDefinition, AbilityOwner, AbilityRegistry,
+        AbilitySpec, AbilityTask, AbilityTriggerData, AbilityTriggers, GameplayEventData,
+        SendGameplayEventEvent, TaskState, WaitGameplayEventTask,
+    },
+    core::OwnedTags,
+};
+use bevy_gameplay_tag::{GameplayTag, GameplayTagsPlugin};
+use std::sync::{Arc, Mutex};
+
+#[derive(Reflect, Debug, Clone, PartialEq)]
+struct HitInfo {
+    critical: bool,
+}
+
+/// Records the payload each activation received.
+struct RecordPayload(Arc<Mutex<Vec<Option<GameplayEventData>>>>);
+
+impl AbilityBehavior for RecordPayload {
+    fn activate(
+        &self,
+        _commands: &mut Commands,
+        _instance_entity: Option<Entity>,
+        _spec_entity: Entity,
+        activation_info: &AbilityActivationInfo,
+    ) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(activation_info.event_payload.clone());
+    }
+}
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.update();
+    app
+}
+
+#[test]
+fn test_event_activates_only_the_targets_triggered_abilities() {
+    let mut app = create_app();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    app.world_mut().resource_mut::<AbilityRegistry>().register(
+        AbilityDefinition::new("counter").with_behavior(Arc::new(RecordPayload(received.clone()))),
+    );
+
+    let hit = GameplayTag::new("Event.Test.Hit");
+    let triggers =
+        AbilityTriggers::new().add_trigger(AbilityTriggerData::gameplay_event(hit.clone()));
+    let attacker = app.world_mut().spawn_empty().id();
+    let defender = app.world_mut().spawn(OwnedTags::default()).id();
+    let bystander = app.world_mut().spawn(OwnedTags::default()).id();
+    for owner in [defender, bystander] {
+        app.world_mut().spawn((
+            AbilitySpec::new("counter", 1),
+            AbilityOwner(owner),
+            triggers.clone(),
+        ));
+    }
+
+    // Observers on the target receive the event too.
+    let observed = Arc::new(Mutex::new(0));
+    let counter = observed.clone();
+    app.world_mut()
+        .entity_mut(defender)
+        .observe(move |_: On<SendGameplayEventEvent>| *counter.lock().unwrap() += 1);
+
+    app.world_mut().trigger(SendGameplayEventEvent::new(
+        defender,
+        GameplayTag::new("Event.Test.Other"),
+    ));
+    app.world_mut().trigger(
+        SendGameplayEventEvent::new(defender, hit).with_payload(
+            GameplayEventData::new(attacker)
+                .with_magnitude(25.0)
+                .with_data(HitInfo { critical: true }),
+        ),
+    );
+    app.update();
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 1);
+    let payload = received[0].as_ref().unwrap();
+    assert_eq!(payload.instigator, attacker);
+    assert_eq!(payload.target, Some(defender));
+    assert_eq!(payload.magnitude, Some(25.0));
+    assert_eq!(payload.event_tag.as_deref(), Some("Event.Test.Hit"));
+    assert_eq!(
+        payload.data_as::<HitInfo>(),
+        Some(&HitInfo { critical: true })
+    );
+    assert_eq!(*observed.lock().unwrap(), 2);
+}
+
+#[test]
+fn test_event_completes_wait_task_of_target_with_payload() {
+    let mut app = create_app();
+    let tag = GameplayTag::new("Event.Test.Trigger");
+    let owner = app.world_mut().spawn_empty().id();
+    let other = app.world_mut().spawn_empty().id();
+    let spec = app
+        .world_mut()
+        .spawn(AbilitySpec::new("test_ability", 1))
+        .id();
+    let task = app
+        .world_mut()
+        .spawn((
+            AbilityTask {
+                ability_instance: None,
+                ability_spec: spec,
+                owner,
+            },
+            WaitGameplayEventTask::new(tag.clone()).with_only_trigger_once(false),
+            TaskState::Running,
+        ))
+        .id();
+
+    app.world_mut()
+        .trigger(SendGameplayEventEvent::new(other, tag.clone()));
+    app.update();
+    assert!(
+        !app.world()
+            .get::<WaitGameplayEventTask>(task)
+            .unwrap()
+            .triggered
+    );
+
+    app.world_mut().trigger(
+        SendGameplayEventEvent::new(owner, tag)
+            .with_payload(GameplayEventData::new(other).with_magnitude(3.0)),
+    );
+    app.update();
+    let wait = app.world().get::<WaitGameplayEventTask>(task).unwrap();
+    assert!(wait.triggered);
+    let payload = wait.payload.as_ref().unwrap();
+    assert_eq!(payload.instigator, other);
+    assert_eq!(payload.magnitude, Some(3.0));
+}
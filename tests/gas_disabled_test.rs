@@ -0,0 +1,121 @@
+//! Tests for suspending GAS on one entity with `GasDisabled`.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::*,
+    core::{BlockedAbilityTags, GasDisabled, OwnedTags},
+    effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+struct Failures(Vec<ActivationFailureReason>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )))
+    .init_resource::<Failures>();
+    app.add_observer(
+        |ev: On<AbilityActivationFailedEvent>, mut failures: ResMut<Failures>| {
+            failures.0.push(ev.reason);
+        },
+    );
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("stun")
+                .with_duration_policy(DurationPolicy::HasDuration)
+                .with_duration(1.0),
+        );
+    app.world_mut()
+        .resource_mut::<AbilityRegistry>()
+        .register(AbilityDefinition::new("dash"));
+    app
+}
+
+fn has_effect(app: &App, target: Entity) -> bool {
+    app.world()
+        .get::<ActiveEffects>(target)
+        .is_some_and(|effects| !effects.is_empty())
+}
+
+#[test]
+fn test_disabled_entity_keeps_its_effects_until_enabled() {
+    let mut app = create_app();
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("stun", target));
+    app.update();
+
+    app.world_mut().entity_mut(target).insert(GasDisabled);
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(has_effect(&app, target));
+
+    app.world_mut().entity_mut(target).remove::<GasDisabled>();
+    for _ in 0..6 {
+        app.update();
+    }
+    assert!(!has_effect(&app, target));
+}
+
+#[test]
+fn test_disabled_owner_cannot_activate_abilities() {
+    let mut app = create_app();
+    let owner = app
+        .world_mut()
+        .spawn((
+            OwnedTags::default(),
+            BlockedAbilityTags::default(),
+            GasDisabled,
+        ))
+        .id();
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("dash", 1),
+            AbilityActiveState::default(),
+            AbilityOwner(owner),
+        ))
+        .id();
+    app.update();
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+    assert_eq!(
+        app.world().resource::<Failures>().0,
+        vec![ActivationFailureReason::OwnerDisabled]
+    );
+    assert!(
+        !app.world()
+            .get::<AbilityActiveState>(spec)
+            .unwrap()
+            .is_active
+    );
+
+    app.world_mut().entity_mut(owner).remove::<GasDisabled>();
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+    assert_eq!(app.world().resource::<Failures>().0.len(), 1);
+    assert!(
+        app.world()
+            .get::<AbilityActiveState>(spec)
+            .unwrap()
+            .is_active
+    );
+}
@@ -0,0 +1,178 @@
+//! Tests for the HUD display state kept by `GasHudPlugin`.
+
+#![cfg(not(feature = "headless"))]
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::*,
+    attributes::{AttributeData, AttributeName},
+    core::OwnedTags,
+    effects::*,
+    hud::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use std::time::Duration;
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+        GasHudPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )));
+    app.update();
+
+    let mut effects = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    effects.register(
+        GameplayEffectDefinition::new("burn")
+            .with_duration_policy(DurationPolicy::HasDuration)
+            .with_duration(4.0)
+            .with_stacking_policy(StackingPolicy::StackCount { max_stacks: 3 }),
+    );
+    effects.register(
+        GameplayEffectDefinition::new("fireball_cooldown")
+            .with_duration_policy(DurationPolicy::HasDuration)
+            .with_duration(2.0),
+    );
+    app.world_mut()
+        .resource_mut::<AbilityRegistry>()
+        .register(AbilityDefinition::new("fireball").with_cooldown_effect("fireball_cooldown"));
+    app
+}
+
+fn spawn_player(app: &mut App) -> (Entity, Entity, Entity) {
+    let player = app
+        .world_mut()
+        .spawn((OwnedTags::default(), GasHud::default()))
+        .id();
+    let mut health = Entity::PLACEHOLDER;
+    for (name, value) in [("Health", 50.0), ("MaxHealth", 100.0), ("Mana", 30.0)] {
+        let attribute = app
+            .world_mut()
+            .spawn((
+                AttributeName::new(name),
+                AttributeData::new(value),
+                ChildOf(player),
+            ))
+            .id();
+        if name == "Health" {
+            health = attribute;
+        }
+    }
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("fireball", 1).with_input_id(1),
+            AbilityOwner(player),
+        ))
+        .id();
+    (player, health, spec)
+}
+
+fn hud(app: &App, player: Entity) -> &GasHud {
+    app.world().get::<GasHud>(player).unwrap()
+}
+
+#[test]
+fn test_hud_tracks_attributes() {
+    let mut app = create_app();
+    let (player, health, _) = spawn_player(&mut app);
+    app.update();
+
+    let names: Vec<&str> = hud(&app, player)
+        .attributes
+        .iter()
+        .map(|attribute| attribute.name.as_ref())
+        .collect();
+    assert_eq!(names, ["Health", "Mana", "MaxHealth"]);
+    let health_row = hud(&app, player).attribute("Health").unwrap();
+    assert_eq!(health_row.max, Some(100.0));
+    assert_eq!(health_row.percent, Some(0.5));
+    // No `MaxMana` attribute, so no percent.
+    assert_eq!(hud(&app, player).attribute("Mana").unwrap().percent, None);
+
+    app.world_mut()
+        .get_mut::<AttributeData>(health)
+        .unwrap()
+        .set_base_value(25.0);
+    app.update();
+    assert_eq!(
+        hud(&app, player).attribute("Health").unwrap().percent,
+        Some(0.25)
+    );
+}
+
+#[test]
+fn test_hud_tracks_buffs_and_stacks() {
+    let mut app = create_app();
+    let (player, _, _) = spawn_player(&mut app);
+    app.update();
+    assert!(hud(&app, player).buffs.is_empty());
+
+    for _ in 0..2 {
+        app.world_mut()
+            .trigger(ApplyGameplayEffectEvent::new("burn", player));
+        app.update();
+    }
+    let buff = &hud(&app, player).buffs[0];
+    assert_eq!(buff.effect_id.as_ref(), "burn");
+    assert_eq!(buff.stacks, 2);
+    assert_eq!(buff.duration, Some(4.0));
+    assert!(buff.icon.is_none());
+
+    app.update();
+    let buff = &hud(&app, player).buffs[0];
+    assert!(buff.remaining.unwrap() < 4.0);
+    assert!(buff.remaining_fraction().unwrap() < 1.0);
+
+    for _ in 0..20 {
+        app.update();
+    }
+    assert!(hud(&app, player).buffs.is_empty());
+}
+
+#[test]
+fn test_hud_tracks_ability_cooldown() {
+    let mut app = create_app();
+    let (player, _, spec) = spawn_player(&mut app);
+    app.update();
+    let fireball = hud(&app, player).ability("fireball").unwrap();
+    assert_eq!(fireball.input_id, Some(1));
+    assert!(!fireball.on_cooldown());
+    assert_eq!(fireball.cooldown_progress(), 1.0);
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, player));
+    app.update();
+    app.update();
+    let fireball = hud(&app, player).ability("fireball").unwrap();
+    assert!(fireball.active);
+    assert!(fireball.on_cooldown());
+    assert_eq!(fireball.cooldown_duration, 2.0);
+    let progress = fireball.cooldown_progress();
+    assert!(progress < 1.0);
+    // The cooldown is shown on the ability, not as a buff.
+    assert!(hud(&app, player).buffs.is_empty());
+
+    app.update();
+    assert!(
+        hud(&app, player)
+            .ability("fireball")
+            .unwrap()
+            .cooldown_progress()
+            > progress
+    );
+
+    for _ in 0..10 {
+        app.update();
+    }
+    let fireball = hud(&app, player).ability("fireball").unwrap();
+    assert!(!fireball.on_cooldown());
+    assert_eq!(fireball.cooldown_progress(), 1.0);
+}
@@ -0,0 +1,288 @@
+//! Tests for global `GasSettings` configured through `GasPlugin::builder()`.
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    attributes::{AttributeData, AttributeName},
+    core::{GasSettings, GasTickMode, GasValidationMode, OwnedTags, PeriodicTickAlignment},
+    effects::*,
+};
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use bevy_gameplay_tag::{GameplayTagsManager, GameplayTagsPlugin};
+use std::time::Duration;
+
+fn create_app(plugin: impl Plugin) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        plugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )));
+    app.update();
+    app
+}
+
+fn effect_count(app: &App, target: Entity) -> usize {
+    app.world()
+        .get::<ActiveEffects>(target)
+        .map_or(0, |effects| effects.len())
+}
+
+#[test]
+fn test_builder_inserts_settings_and_derived_resources() {
+    let app = create_app(
+        GasPlugin::builder()
+            .with_tick_mode(GasTickMode::Fixed)
+            .with_strict_validation(true)
+            .with_max_active_effects_per_entity(4),
+    );
+    let settings = app.world().resource::<GasSettings>();
+    assert_eq!(settings.max_active_effects_per_entity, Some(4));
+    assert_eq!(*app.world().resource::<GasTickMode>(), GasTickMode::Fixed);
+    assert_eq!(
+        *app.world().resource::<GasValidationMode>(),
+        GasValidationMode::Strict
+    );
+
+    let app = create_app(GasPlugin);
+    assert_eq!(
+        *app.world().resource::<GasSettings>(),
+        GasSettings::default()
+    );
+}
+
+#[test]
+fn test_max_active_effects_per_entity_drops_new_effects() {
+    let mut app = create_app(GasPlugin::builder().with_max_active_effects_per_entity(2));
+    let mut registry = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    for id in ["slow", "burn", "poison"] {
+        registry.register(
+            GameplayEffectDefinition::new(id).with_duration_policy(DurationPolicy::Infinite),
+        );
+    }
+
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    for id in ["slow", "burn", "poison"] {
+        app.world_mut()
+            .trigger(ApplyGameplayEffectEvent::new(id, target));
+        app.update();
+    }
+    assert_eq!(effect_count(&app, target), 2);
+
+    // Raising the cap at runtime takes effect on the next application.
+    app.world_mut()
+        .resource_mut::<GasSettings>()
+        .max_active_effects_per_entity = None;
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("poison", target));
+    app.update();
+    assert_eq!(effect_count(&app, target), 3);
+}
+
+#[test]
+fn test_periodic_alignment_delays_first_execution() {
+    let mut app = create_app(
+        GasPlugin::builder().with_periodic_tick_alignment(PeriodicTickAlignment::AfterPeriod),
+    );
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("regen")
+                .with_duration_policy(DurationPolicy::Infinite)
+                .with_period(1.0)
+                .add_modifier(ModifierInfo::new(
+                    "Health",
+                    ModifierOperation::AddBase,
+                    MagnitudeCalculation::scalar(10.0),
+                )),
+        );
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    let health = app
+        .world_mut()
+        .spawn((
+            AttributeName::new("Health"),
+            AttributeData::new(100.0),
+            ChildOf(target),
+        ))
+        .id();
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("regen", target));
+    app.update();
+    let base = |app: &App| app.world().get::<AttributeData>(health).unwrap().base_value;
+    assert_eq!(base(&app), 100.0);
+
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(base(&app), 110.0);
+}
+
+#[test]
+fn test_global_alignment_ticks_effects_on_a_shared_beat() {
+    let mut app = create_app(
+        GasPlugin::builder().with_periodic_tick_alignment(PeriodicTickAlignment::Global),
+    );
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("burn")
+                .with_duration_policy(DurationPolicy::Infinite)
+                .with_period(1.0)
+                .add_modifier(ModifierInfo::new(
+                    "Health",
+                    ModifierOperation::AddBase,
+                    MagnitudeCalculation::scalar(-10.0),
+                )),
+        );
+    let spawn_target = |app: &mut App| {
+        let target = app.world_mut().spawn(OwnedTags::default()).id();
+        let health = app
+            .world_mut()
+            .spawn((
+                AttributeName::new("Health"),
+                AttributeData::new(100.0),
+                ChildOf(target),
+            ))
+            .id();
+        (target, health)
+    };
+    let base =
+        |app: &App, health: Entity| app.world().get::<AttributeData>(health).unwrap().base_value;
+
+    // Applied a quarter and half a period apart
+    let (first, first_health) = spawn_target(&mut app);
+    let (second, second_health) = spawn_target(&mut app);
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("burn", first));
+    app.update();
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("burn", second));
+    app.update();
+    app.update();
+
+    let mut ticks = 0;
+    for _ in 0..16 {
+        let before = (base(&app, first_health), base(&app, second_health));
+        app.update();
+        let after = (base(&app, first_health), base(&app, second_health));
+        let first_ticked = after.0 != before.0;
+        let second_ticked = after.1 != before.1;
+        assert_eq!(first_ticked, second_ticked);
+        ticks += usize::from(first_ticked);
+    }
+    // One beat per second of the four simulated
+    assert_eq!(ticks, 4);
+}
+
+#[test]
+fn test_max_effect_removals_per_frame_spreads_expiries() {
+    let mut app = create_app(GasPlugin::builder().with_max_effect_removals_per_frame(2));
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("burn")
+                .with_duration_policy(DurationPolicy::HasDuration)
+                .with_duration(0.5),
+        );
+
+    let targets: Vec<Entity> = (0..5)
+        .map(|_| app.world_mut().spawn(OwnedTags::default()).id())
+        .collect();
+    for &target in &targets {
+        app.world_mut()
+            .trigger(ApplyGameplayEffectEvent::new("burn", target));
+    }
+    app.update();
+    let remaining = |app: &App| {
+        targets
+            .iter()
+            .map(|&target| effect_count(app, target))
+            .sum::<usize>()
+    };
+    assert_eq!(remaining(&app), 5);
+
+    // Every effect expires on the same frame; two are removed per frame.
+    app.update();
+    assert_eq!(remaining(&app), 3);
+    assert_eq!(app.world().resource::<ExpiredEffectQueue>().len(), 3);
+    app.update();
+    assert_eq!(remaining(&app), 1);
+    app.update();
+    assert_eq!(remaining(&app), 0);
+    assert!(app.world().resource::<ExpiredEffectQueue>().is_empty());
+}
+
+#[test]
+fn test_stasis_tag_stops_effect_timers() {
+    let mut app =
+        create_app(GasPlugin::builder().with_stasis_tag(GameplayTag::new("State.Stasis")));
+    app.world_mut()
+        .run_system_once(
+            |tags_manager: Res<GameplayTagsManager>,
+             mut registry: ResMut<GameplayEffectRegistry>| {
+                registry.register(
+                    GameplayEffectDefinition::new("stasis")
+                        .with_duration(1.0)
+                        .grant_tag(GameplayTag::new("State.Stasis"), &tags_manager),
+                );
+                registry.register(
+                    GameplayEffectDefinition::new("burn")
+                        .with_duration(2.0)
+                        .with_period(1.0)
+                        .add_modifier(ModifierInfo::new(
+                            "Health",
+                            ModifierOperation::AddBase,
+                            MagnitudeCalculation::scalar(-10.0),
+                        )),
+                );
+            },
+        )
+        .unwrap();
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    let health = app
+        .world_mut()
+        .spawn((
+            AttributeName::new("Health"),
+            AttributeData::new(100.0),
+            ChildOf(target),
+        ))
+        .id();
+    let base = |app: &App| app.world().get::<AttributeData>(health).unwrap().base_value;
+    let burn_remaining = |app: &mut App| {
+        let mut durations = app.world_mut().query::<&EffectDuration>();
+        durations
+            .iter(app.world())
+            .map(|duration| duration.remaining)
+            .fold(f32::MIN, f32::max)
+    };
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("burn", target));
+    app.update();
+    assert_eq!(base(&app), 90.0);
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("stasis", target));
+    app.update();
+    let frozen = burn_remaining(&mut app);
+
+    // The burn holds still for the second of stasis
+    for _ in 0..3 {
+        app.update();
+        assert_eq!(base(&app), 90.0);
+        assert_eq!(burn_remaining(&mut app), frozen);
+    }
+
+    // Then picks up where it stopped
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(effect_count(&app, target), 1);
+    assert_eq!(base(&app), 80.0);
+    assert!(burn_remaining(&mut app) < frozen);
+}
@@ -0,0 +1,80 @@
+//! Tests for the `GasStats` counters.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::{
+        AbilityActiveState, AbilityDefinition, AbilityOwner, AbilityRegistry, AbilitySpec,
+        TryActivateAbilityEvent,
+    },
+    core::OwnedTags,
+    debug::{GasStats, GasStatsPlugin},
+    effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+
+#[test]
+fn test_stats_count_effects_activations_and_rejections() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin::builder().with_max_active_effects_per_entity(1),
+        GasStatsPlugin,
+    ));
+    app.update();
+    for effect_id in ["burn", "shield"] {
+        app.world_mut()
+            .resource_mut::<GameplayEffectRegistry>()
+            .register(
+                GameplayEffectDefinition::new(effect_id)
+                    .with_duration_policy(DurationPolicy::Infinite),
+            );
+    }
+    app.world_mut()
+        .resource_mut::<AbilityRegistry>()
+        .register(AbilityDefinition::new("dash"));
+    let player = app.world_mut().spawn(OwnedTags::default()).id();
+    let dash = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("dash", 1),
+            AbilityActiveState::default(),
+            AbilityOwner(player),
+        ))
+        .id();
+    app.update();
+
+    for effect_id in ["burn", "shield", "frostbite"] {
+        app.world_mut()
+            .trigger(ApplyGameplayEffectEvent::new(effect_id, player));
+        app.update();
+    }
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(dash, player));
+    app.update();
+
+    let stats = app.world().resource::<GasStats>();
+    assert_eq!(stats.active_effects("burn"), 1);
+    assert_eq!(stats.active_effects("shield"), 0);
+    assert_eq!(stats.effects_applied("burn"), 1);
+    assert_eq!(stats.activations("dash"), 1);
+    assert_eq!(stats.rejections(EffectRejectionReason::MaxActiveEffects), 1);
+    assert_eq!(
+        stats.rejections(EffectRejectionReason::UnknownDefinition),
+        1
+    );
+
+    let burn = app
+        .world()
+        .get::<ActiveEffects>(player)
+        .unwrap()
+        .iter()
+        .next()
+        .unwrap();
+    app.world_mut().despawn(burn);
+    let stats = app.world().resource::<GasStats>();
+    assert_eq!(stats.active_effects("burn"), 0);
+    assert_eq!(stats.iter_active_effects().count(), 0);
+    assert_eq!(stats.effects_applied("burn"), 1);
+}
@@ -0,0 +1,149 @@
+//! Tests for generation-checked GAS handles.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::{AbilityDefinition, AbilityOwner, AbilityRegistry, AbilitySpec},
+    attributes::{AttributeData, AttributeName},
+    core::{AbilityHandle, EffectHandle, OwnedTags},
+    effects::*,
+    utils::Gas,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+struct Handles(Vec<EffectHandle>);
+
+#[derive(Resource, Default)]
+struct AbilityHandles(Vec<AbilityHandle>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )))
+    .init_resource::<Handles>()
+    .init_resource::<AbilityHandles>()
+    .add_observer(
+        |ev: On<GameplayEffectAppliedEvent>, gas: Gas, mut handles: ResMut<Handles>| {
+            assert_eq!(ev.handle, gas.effect_handle(ev.effect));
+            handles.0.extend(ev.handle);
+        },
+    )
+    .add_observer(
+        |ev: On<AbilityGrantedEvent>, mut handles: ResMut<AbilityHandles>| {
+            handles.0.extend(ev.handle);
+        },
+    );
+    app.update();
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("haste")
+                .with_duration_policy(DurationPolicy::HasDuration)
+                .with_duration(0.5),
+        );
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("empower")
+                .with_duration_policy(DurationPolicy::Infinite)
+                .grant_ability_simple("smite"),
+        );
+    app.world_mut()
+        .resource_mut::<AbilityRegistry>()
+        .register(AbilityDefinition::new("smite"));
+    app
+}
+
+#[test]
+fn test_applied_effect_handle_expires_with_the_effect() {
+    let mut app = create_app();
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("haste", target));
+    app.update();
+
+    let handle = app.world().resource::<Handles>().0[0];
+    assert!(handle.resolve(app.world()).is_some());
+
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(handle.resolve(app.world()), None);
+}
+
+#[test]
+fn test_ability_and_attribute_handles_track_their_components() {
+    let mut app = create_app();
+    let owner = app.world_mut().spawn(OwnedTags::default()).id();
+    let health = app
+        .world_mut()
+        .spawn((
+            AttributeName::new("Health"),
+            AttributeData::new(100.0),
+            ChildOf(owner),
+        ))
+        .id();
+    let spec = app
+        .world_mut()
+        .spawn((AbilitySpec::new("dash", 1), AbilityOwner(owner)))
+        .id();
+
+    let mut state = bevy::ecs::system::SystemState::<Gas>::new(app.world_mut());
+    let gas = state.get(app.world());
+    let ability = gas.ability_handle(spec).unwrap();
+    let attribute = gas.attribute_handle(owner, "Health").unwrap();
+    assert_eq!(attribute.entity(), health);
+    assert!(gas.attribute_handle(owner, "Mana").is_none());
+
+    // Regranting on the same entity invalidates the old handle.
+    app.world_mut().entity_mut(spec).remove::<AbilitySpec>();
+    app.world_mut()
+        .entity_mut(spec)
+        .insert(AbilitySpec::new("dash", 2));
+    assert!(!ability.is_valid(app.world()));
+
+    app.world_mut().despawn(owner);
+    assert!(!attribute.is_valid(app.world()));
+}
+
+#[test]
+fn test_handles_from_apply_and_grant_do_not_follow_a_respawned_target() {
+    let mut app = create_app();
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("empower", target));
+    app.update();
+    app.update();
+
+    let effect = app.world().resource::<Handles>().0[0];
+    let ability = app.world().resource::<AbilityHandles>().0[0];
+    assert!(effect.is_valid(app.world()));
+    assert!(ability.is_valid(app.world()));
+
+    // The target dies and a new one takes its place with the same loadout.
+    app.world_mut().despawn(target);
+    app.update();
+    let respawned = app.world_mut().spawn(OwnedTags::default()).id();
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("empower", respawned));
+    app.update();
+    app.update();
+
+    assert_eq!(effect.resolve(app.world()), None);
+    assert_eq!(ability.resolve(app.world()), None);
+    let handles = &app.world().resource::<Handles>().0;
+    let ability_handles = &app.world().resource::<AbilityHandles>().0;
+    assert_eq!(handles.len(), 2);
+    assert_eq!(ability_handles.len(), 2);
+    assert!(handles[1].is_valid(app.world()));
+    assert!(ability_handles[1].is_valid(app.world()));
+}
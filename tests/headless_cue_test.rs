@@ -0,0 +1,54 @@
+//! Tests that headless builds record cues without executing handlers.
+
+#![cfg(feature = "headless")]
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{GasPlugin, cues::*};
+use bevy_gameplay_tag::{GameplayTagsPlugin, gameplay_tag::GameplayTag};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Handler that counts how often it runs.
+#[derive(Clone, Default)]
+struct CountingCue(Arc<AtomicU32>);
+
+impl GameplayCueNotifyStatic for CountingCue {
+    fn on_execute(&self, _: Entity, _: &GameplayCueParameters, _: &mut Commands) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn test_headless_records_cues_without_executing_handlers() {
+    let handler = CountingCue::default();
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .register_gameplay_cue_handler("GameplayCue.Hit", handler.clone());
+    app.update();
+
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut().trigger(TriggerGameplayCueEvent::new(
+        GameplayTag::new("GameplayCue.Hit"),
+        GameplayCueEvent::Executed,
+        GameplayCueParameters::new().with_magnitude(10.0, 0.1),
+    ));
+    app.world_mut()
+        .trigger(TriggerGameplayCueOnEntityEvent::new(
+            target,
+            GameplayTag::new("GameplayCue.Hit"),
+            GameplayCueEvent::OnActive,
+        ));
+    app.update();
+    app.update();
+
+    assert_eq!(handler.0.load(Ordering::Relaxed), 0);
+    let recorded = app.world_mut().resource_mut::<HeadlessCueLog>().drain();
+    assert_eq!(recorded.len(), 2);
+    assert_eq!(recorded[0].parameters.raw_magnitude, 10.0);
+    assert_eq!(recorded[1].event_type, GameplayCueEvent::OnActive);
+    assert_eq!(recorded[1].parameters.target, Some(target));
+}
@@ -0,0 +1,143 @@
+//! Tests for immunity windows blocking hostile effects.
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin, abilities::*, attributes::*, core::OwnedTags, effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsManager;
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+struct Blocked(Vec<GameplayEffectBlockedByImmunityEvent>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )))
+    .init_resource::<Blocked>()
+    .add_observer(
+        |ev: On<GameplayEffectBlockedByImmunityEvent>, mut blocked: ResMut<Blocked>| {
+            blocked.0.push(ev.event().clone());
+        },
+    );
+    app.update();
+
+    app.world_mut()
+        .run_system_once(
+            |tags_manager: Res<GameplayTagsManager>,
+             mut registry: ResMut<GameplayEffectRegistry>| {
+                registry.register(immunity_window("iframes", 0.5, &tags_manager));
+                registry.register(
+                    GameplayEffectDefinition::new("fireball")
+                        .with_asset_tag(GameplayTag::new("Effect.Hostile.Fire"), &tags_manager)
+                        .add_modifier(ModifierInfo::new(
+                            "Health",
+                            ModifierOperation::AddBase,
+                            MagnitudeCalculation::scalar(-10.0),
+                        )),
+                );
+                registry.register(GameplayEffectDefinition::new("heal").add_modifier(
+                    ModifierInfo::new(
+                        "Health",
+                        ModifierOperation::AddBase,
+                        MagnitudeCalculation::scalar(5.0),
+                    ),
+                ));
+            },
+        )
+        .unwrap();
+    app.world_mut()
+        .resource_mut::<AbilityRegistry>()
+        .register(AbilityDefinition::new("dodge").with_immunity_window("iframes"));
+    app
+}
+
+fn spawn_actor(app: &mut App) -> (Entity, Entity) {
+    let actor = app.world_mut().spawn(OwnedTags::default()).id();
+    let health = app
+        .world_mut()
+        .spawn((
+            AttributeName::new("Health"),
+            AttributeData::new(100.0),
+            ChildOf(actor),
+        ))
+        .id();
+    (actor, health)
+}
+
+fn health(app: &App, attribute: Entity) -> f32 {
+    app.world()
+        .get::<AttributeData>(attribute)
+        .unwrap()
+        .base_value
+}
+
+fn hit(app: &mut App, target: Entity) {
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("fireball", target));
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("heal", target));
+    app.update();
+    app.update();
+}
+
+#[test]
+fn test_window_blocks_hostile_effects_until_it_expires() {
+    let mut app = create_app();
+    let (actor, health_attribute) = spawn_actor(&mut app);
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("iframes", actor));
+    app.update();
+    hit(&mut app, actor);
+
+    // The fireball is blocked, the heal still lands.
+    assert_eq!(health(&app, health_attribute), 105.0);
+    let blocked = &app.world().resource::<Blocked>().0;
+    assert_eq!(blocked.len(), 1);
+    assert_eq!(blocked[0].effect_id.as_ref(), "fireball");
+    assert_eq!(blocked[0].target, actor);
+    assert_eq!(
+        blocked[0].immunity_tag,
+        GameplayTag::new(HOSTILE_EFFECT_TAG)
+    );
+
+    for _ in 0..4 {
+        app.update();
+    }
+    hit(&mut app, actor);
+    assert_eq!(health(&app, health_attribute), 100.0);
+    assert_eq!(app.world().resource::<Blocked>().0.len(), 1);
+}
+
+#[test]
+fn test_ability_grants_its_immunity_window_on_activation() {
+    let mut app = create_app();
+    let (actor, health_attribute) = spawn_actor(&mut app);
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("dodge", 1),
+            AbilityActiveState::default(),
+            AbilityOwner(actor),
+        ))
+        .id();
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, actor));
+    app.update();
+    hit(&mut app, actor);
+
+    assert_eq!(health(&app, health_attribute), 105.0);
+    assert_eq!(app.world().resource::<Blocked>().0.len(), 1);
+}
@@ -0,0 +1,100 @@
+//! Tests that despawning a GAS owner tears down its whole GAS sub-graph.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::*,
+    attributes::{AttributeData, AttributeName},
+    core::{GasOwnerDespawnedEvent, OwnedTags},
+    effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+
+#[derive(Resource, Default)]
+struct Despawned(Vec<GasOwnerDespawnedEvent>);
+
+fn count<C: Component>(app: &mut App) -> usize {
+    let mut query = app.world_mut().query_filtered::<(), With<C>>();
+    query.iter(app.world()).count()
+}
+
+#[test]
+fn test_despawning_owner_despawns_its_gas_entities() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .init_resource::<Despawned>()
+    .add_observer(
+        |ev: On<GasOwnerDespawnedEvent>, mut despawned: ResMut<Despawned>| {
+            despawned.0.push(ev.event().clone());
+        },
+    );
+    app.update();
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("might")
+                .with_duration_policy(DurationPolicy::Infinite)
+                .add_modifier(ModifierInfo::new(
+                    "Health",
+                    ModifierOperation::AddCurrent,
+                    MagnitudeCalculation::scalar(10.0),
+                )),
+        );
+
+    let owner = app.world_mut().spawn(OwnedTags::default()).id();
+    let health = app
+        .world_mut()
+        .spawn((
+            AttributeName::new("Health"),
+            AttributeData::new(100.0),
+            ChildOf(owner),
+        ))
+        .id();
+    let spec = app
+        .world_mut()
+        .spawn((AbilitySpec::new("dash", 1), AbilityOwner(owner)))
+        .id();
+    app.world_mut().spawn((
+        AbilityTask {
+            ability_instance: None,
+            ability_spec: spec,
+            owner,
+        },
+        WaitDelayTask::new(10.0),
+        TaskState::Running,
+    ));
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("might", owner));
+    app.update();
+    app.update();
+    let effect = app
+        .world()
+        .get::<ActiveEffects>(owner)
+        .unwrap()
+        .iter()
+        .next()
+        .unwrap();
+    assert_eq!(count::<AttributeModifier>(&mut app), 1);
+
+    app.world_mut().despawn(owner);
+    app.update();
+
+    assert_eq!(count::<AttributeData>(&mut app), 0);
+    assert_eq!(count::<AbilitySpec>(&mut app), 0);
+    assert_eq!(count::<ActiveGameplayEffect>(&mut app), 0);
+    assert_eq!(count::<AttributeModifier>(&mut app), 0);
+    assert_eq!(count::<AbilityTask>(&mut app), 0);
+    assert_eq!(
+        app.world().resource::<Despawned>().0,
+        vec![GasOwnerDespawnedEvent {
+            owner,
+            attributes: vec![health],
+            abilities: vec![spec],
+            effects: vec![effect],
+        }]
+    );
+}
@@ -0,0 +1,165 @@
+//! Tests for owner tag change events and the tag triggers driven by them.
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::*,
+    core::{OwnedTags, OwnerTagsChangedEvent},
+    effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsManager;
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+struct TagEvents(Vec<OwnerTagsChangedEvent>);
+
+#[derive(Resource, Default)]
+struct Activations(Vec<Entity>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )))
+    .init_resource::<TagEvents>()
+    .init_resource::<Activations>()
+    .add_observer(
+        |ev: On<OwnerTagsChangedEvent>, mut events: ResMut<TagEvents>| {
+            events.0.push(ev.event().clone());
+        },
+    )
+    .add_observer(
+        |ev: On<AbilityActivatedEvent>, mut activations: ResMut<Activations>| {
+            activations.0.push(ev.ability_spec);
+        },
+    );
+    app.update();
+
+    app.world_mut()
+        .run_system_once(
+            |tags_manager: Res<GameplayTagsManager>,
+             mut registry: ResMut<GameplayEffectRegistry>| {
+                registry.register(
+                    GameplayEffectDefinition::new("stun")
+                        .with_duration(1.0)
+                        .grant_tag(GameplayTag::new("State.Stunned"), &tags_manager),
+                );
+                registry.register(
+                    GameplayEffectDefinition::new("slow")
+                        .with_duration(2.0)
+                        .grant_tag(GameplayTag::new("State.Slowed"), &tags_manager),
+                );
+            },
+        )
+        .unwrap();
+    app.world_mut()
+        .resource_mut::<AbilityRegistry>()
+        .register(AbilityDefinition::new("stunned_reaction"));
+    app
+}
+
+fn apply(app: &mut App, effect_id: &str, target: Entity) {
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new(effect_id, target));
+    app.update();
+}
+
+#[test]
+fn test_event_reports_gained_and_lost_tags_only() {
+    let mut app = create_app();
+    let owner = app.world_mut().spawn(OwnedTags::default()).id();
+    let stunned = GameplayTag::new("State.Stunned");
+
+    apply(&mut app, "stun", owner);
+    // A second stun only raises the tag's count.
+    apply(&mut app, "stun", owner);
+    for _ in 0..6 {
+        app.update();
+    }
+
+    let events = &app.world().resource::<TagEvents>().0;
+    assert_eq!(
+        events,
+        &[
+            OwnerTagsChangedEvent {
+                owner,
+                added: vec![stunned.clone()],
+                removed: Vec::new(),
+            },
+            OwnerTagsChangedEvent {
+                owner,
+                added: Vec::new(),
+                removed: vec![stunned],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_tag_triggers_react_to_owner_tag_changes() {
+    let mut app = create_app();
+    let owner = app.world_mut().spawn(OwnedTags::default()).id();
+    let on_added = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("stunned_reaction", 1),
+            AbilityActiveState::default(),
+            AbilityOwner(owner),
+            AbilityTriggers::new().add_trigger(AbilityTriggerData::owned_tag_added(
+                GameplayTag::new("State"),
+            )),
+        ))
+        .id();
+    let while_present = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("stunned_reaction", 1),
+            AbilityActiveState::default(),
+            AbilityOwner(owner),
+            AbilityTriggers::new().add_trigger(AbilityTriggerData::owned_tag_present(
+                GameplayTag::new("State.Stunned"),
+            )),
+        ))
+        .id();
+
+    apply(&mut app, "stun", owner);
+    app.update();
+    let activations = &app.world().resource::<Activations>().0;
+    assert_eq!(activations.len(), 2);
+    assert!(activations.contains(&on_added) && activations.contains(&while_present));
+    assert!(
+        app.world()
+            .get::<AbilityActiveState>(while_present)
+            .unwrap()
+            .is_active
+    );
+
+    // Another tag under the trigger tag fires the added trigger again, but
+    // not the present trigger.
+    apply(&mut app, "slow", owner);
+    app.update();
+    let activations = &app.world().resource::<Activations>().0;
+    assert_eq!(activations.len(), 3);
+    assert_eq!(activations[2], on_added);
+
+    // Losing the stun cancels the ability triggered by it.
+    for _ in 0..4 {
+        app.update();
+    }
+    assert!(
+        !app.world()
+            .get::<AbilityActiveState>(while_present)
+            .unwrap()
+            .is_active
+    );
+    assert_eq!(app.world().resource::<Activations>().0.len(), 3);
+}
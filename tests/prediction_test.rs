@@ -0,0 +1,345 @@
+//! Tests that net execution policies predict, defer and roll back ability
+//! activations on clients and resolve predictions on the server.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::*,
+    attributes::{AttributeData, AttributeName},
+    core::{BlockedAbilityTags, OwnedTags},
+    cues::systems::TriggerGameplayCueEvent,
+    effects::*,
+};
+use bevy_gameplay_tag::{
+    GameplayTagContainer, GameplayTagsManager, GameplayTagsPlugin, gameplay_tag::GameplayTag,
+};
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+struct Requests(Vec<AbilityActivationRequestEvent>);
+
+#[derive(Resource, Default)]
+struct Resolutions(Vec<AbilityPredictionResolvedEvent>);
+
+fn create_app(role: NetRole, policy: NetExecutionPolicy) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.update();
+    app.insert_resource(role);
+    app.init_resource::<Requests>()
+        .init_resource::<Resolutions>();
+    app.add_observer(
+        |ev: On<AbilityActivationRequestEvent>, mut requests: ResMut<Requests>| {
+            requests.0.push(ev.event().clone());
+        },
+    );
+    app.add_observer(
+        |ev: On<AbilityPredictionResolvedEvent>, mut resolutions: ResMut<Resolutions>| {
+            resolutions.0.push(ev.event().clone());
+        },
+    );
+
+    let tags_manager = app.world().resource::<GameplayTagsManager>();
+    let mut cooldown_tags = GameplayTagContainer::new();
+    cooldown_tags.add_tag(GameplayTag::new("Cooldown.Fireball"), tags_manager);
+    let mut casting_tags = GameplayTagContainer::new();
+    casting_tags.add_tag(GameplayTag::new("Ability.Casting"), tags_manager);
+
+    let mut cooldown = GameplayEffectDefinition::new("fireball_cooldown")
+        .with_duration(5.0)
+        .add_gameplay_cue(GameplayEffectCue::new(GameplayTag::new(
+            "GameplayCue.Fireball",
+        )));
+    cooldown.granted_tags = cooldown_tags;
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(cooldown);
+
+    let mut fireball = AbilityDefinition::new("fireball")
+        .with_net_execution_policy(policy)
+        .with_cooldown_effect("fireball_cooldown");
+    fireball.activation_owned_tags = casting_tags;
+    app.world_mut()
+        .resource_mut::<AbilityRegistry>()
+        .register(fireball);
+    app
+}
+
+fn spawn_caster(app: &mut App) -> (Entity, Entity) {
+    let owner = app
+        .world_mut()
+        .spawn((OwnedTags::default(), BlockedAbilityTags::default()))
+        .id();
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("fireball", 1),
+            AbilityActiveState::default(),
+            AbilityOwner(owner),
+        ))
+        .id();
+    (owner, spec)
+}
+
+fn has_tag(app: &App, entity: Entity, tag: &str) -> bool {
+    app.world()
+        .get::<OwnedTags>(entity)
+        .unwrap()
+        .0
+        .has_matching_gameplay_tag(&GameplayTag::new(tag))
+}
+
+#[test]
+fn test_rejected_prediction_rolls_back_tags_and_cooldown() {
+    let mut app = create_app(NetRole::Client, NetExecutionPolicy::LocalPredicted);
+    let (owner, spec) = spawn_caster(&mut app);
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+
+    let requests = &app.world().resource::<Requests>().0;
+    assert_eq!(requests.len(), 1);
+    let key = requests[0]
+        .prediction_key
+        .expect("activation should be predicted");
+    assert!(app.world().resource::<AbilityPredictions>().is_pending(key));
+    assert!(
+        app.world()
+            .get::<AbilityActiveState>(spec)
+            .unwrap()
+            .is_active
+    );
+    assert!(has_tag(&app, owner, "Ability.Casting"));
+    assert!(has_tag(&app, owner, "Cooldown.Fireball"));
+
+    let predicted_effect = app
+        .world_mut()
+        .query::<(Entity, &PredictionKey)>()
+        .single(app.world())
+        .map(|(entity, effect_key)| {
+            assert_eq!(*effect_key, key);
+            entity
+        })
+        .unwrap();
+
+    app.world_mut().trigger(AbilityPredictionResolvedEvent {
+        ability_spec: spec,
+        prediction_key: key,
+        accepted: false,
+    });
+    app.update();
+
+    assert!(!app.world().resource::<AbilityPredictions>().is_pending(key));
+    assert!(
+        !app.world()
+            .get::<AbilityActiveState>(spec)
+            .unwrap()
+            .is_active
+    );
+    assert!(!has_tag(&app, owner, "Ability.Casting"));
+    assert!(!has_tag(&app, owner, "Cooldown.Fireball"));
+    assert!(app.world().get_entity(predicted_effect).is_err());
+}
+
+#[test]
+fn test_rejected_prediction_only_undoes_its_own_changes() {
+    let mut app = create_app(NetRole::Client, NetExecutionPolicy::LocalPredicted);
+    let mut effects = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    effects.register(
+        GameplayEffectDefinition::new("frostbolt_cost").add_modifier(ModifierInfo::new(
+            "Mana",
+            ModifierOperation::AddBase,
+            MagnitudeCalculation::scalar(-30.0),
+        )),
+    );
+    effects.register(
+        GameplayEffectDefinition::new("mana_burn").add_modifier(ModifierInfo::new(
+            "Mana",
+            ModifierOperation::AddBase,
+            MagnitudeCalculation::scalar(-10.0),
+        )),
+    );
+    app.world_mut().resource_mut::<AbilityRegistry>().register(
+        AbilityDefinition::new("frostbolt")
+            .with_net_execution_policy(NetExecutionPolicy::LocalPredicted)
+            .with_cost_effect("frostbolt_cost"),
+    );
+    let (owner, _) = spawn_caster(&mut app);
+    app.world_mut().spawn((
+        AttributeName::new("Mana"),
+        AttributeData::new(100.0),
+        ChildOf(owner),
+    ));
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("frostbolt", 1),
+            AbilityActiveState::default(),
+            AbilityOwner(owner),
+        ))
+        .id();
+    let mana = |app: &mut App| {
+        app.world_mut()
+            .query::<(&AttributeName, &AttributeData)>()
+            .iter(app.world())
+            .find(|(name, _)| name.0 == *"Mana")
+            .map(|(_, data)| data.base_value)
+            .unwrap()
+    };
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+    let key = app.world().resource::<Requests>().0[0]
+        .prediction_key
+        .unwrap();
+    assert_eq!(mana(&mut app), 70.0);
+
+    // Damage lands while the client waits for the server.
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("mana_burn", owner));
+    app.update();
+    assert_eq!(mana(&mut app), 60.0);
+
+    app.world_mut().trigger(AbilityPredictionResolvedEvent {
+        ability_spec: spec,
+        prediction_key: key,
+        accepted: false,
+    });
+    app.update();
+
+    // The cost is refunded, the burn stands.
+    assert_eq!(mana(&mut app), 90.0);
+}
+
+#[test]
+fn test_server_only_ability_is_requested_not_activated() {
+    let mut app = create_app(NetRole::Client, NetExecutionPolicy::ServerOnly);
+    let (owner, spec) = spawn_caster(&mut app);
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+
+    let requests = &app.world().resource::<Requests>().0;
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].prediction_key, None);
+    assert!(
+        !app.world()
+            .get::<AbilityActiveState>(spec)
+            .unwrap()
+            .is_active
+    );
+    assert!(!has_tag(&app, owner, "Cooldown.Fireball"));
+}
+
+#[test]
+fn test_server_stamps_effects_and_resolves_prediction() {
+    let mut app = create_app(NetRole::Server, NetExecutionPolicy::LocalPredicted);
+    let (owner, spec) = spawn_caster(&mut app);
+    let key = PredictionKey(7);
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner).with_prediction_key(key));
+    app.update();
+
+    assert!(app.world().resource::<Requests>().0.is_empty());
+    let resolutions = &app.world().resource::<Resolutions>().0;
+    assert_eq!(resolutions.len(), 1);
+    assert_eq!(resolutions[0].prediction_key, key);
+    assert!(resolutions[0].accepted);
+
+    let effect_key = *app
+        .world_mut()
+        .query_filtered::<&PredictionKey, With<ActiveGameplayEffect>>()
+        .single(app.world())
+        .unwrap();
+    assert_eq!(effect_key, key);
+
+    // A second prediction is rejected while the cooldown is active.
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner).with_prediction_key(PredictionKey(8)));
+    app.update();
+    let resolutions = &app.world().resource::<Resolutions>().0;
+    assert_eq!(resolutions.len(), 2);
+    assert!(!resolutions[1].accepted);
+}
+
+#[test]
+fn test_prediction_key_reaches_applied_events_and_cues() {
+    #[derive(Resource, Default)]
+    struct Keys {
+        activated: Vec<Option<PredictionKey>>,
+        applied: Vec<Option<PredictionKey>>,
+        cues: Vec<Option<PredictionKey>>,
+    }
+
+    let mut app = create_app(NetRole::Client, NetExecutionPolicy::LocalPredicted);
+    app.init_resource::<Keys>();
+    app.add_observer(|ev: On<AbilityActivatedEvent>, mut keys: ResMut<Keys>| {
+        keys.activated.push(ev.prediction_key);
+    });
+    app.add_observer(
+        |ev: On<GameplayEffectAppliedEvent>, mut keys: ResMut<Keys>| {
+            keys.applied.push(ev.prediction_key);
+        },
+    );
+    app.add_observer(|ev: On<TriggerGameplayCueEvent>, mut keys: ResMut<Keys>| {
+        keys.cues.push(ev.parameters.prediction_key);
+    });
+    let (owner, spec) = spawn_caster(&mut app);
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+
+    let key = app.world().resource::<Requests>().0[0].prediction_key;
+    assert!(key.is_some());
+    let keys = app.world().resource::<Keys>();
+    assert_eq!(keys.activated, vec![key]);
+    assert_eq!(keys.applied, vec![key]);
+    assert_eq!(keys.cues, vec![key]);
+}
+
+#[test]
+fn test_unanswered_prediction_expires_and_rolls_back() {
+    let mut app = create_app(NetRole::Client, NetExecutionPolicy::LocalPredicted);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(AbilityPredictions::default().with_expiry(0.5));
+    app.update();
+    let (owner, spec) = spawn_caster(&mut app);
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+    let key = app.world().resource::<Requests>().0[0]
+        .prediction_key
+        .unwrap();
+    assert!(app.world().resource::<AbilityPredictions>().is_pending(key));
+    assert!(has_tag(&app, owner, "Cooldown.Fireball"));
+
+    for _ in 0..6 {
+        app.update();
+    }
+
+    assert!(!app.world().resource::<AbilityPredictions>().is_pending(key));
+    assert!(!has_tag(&app, owner, "Ability.Casting"));
+    assert!(!has_tag(&app, owner, "Cooldown.Fireball"));
+
+    // A late answer for the expired prediction is ignored.
+    app.world_mut().trigger(AbilityPredictionResolvedEvent {
+        ability_spec: spec,
+        prediction_key: key,
+        accepted: true,
+    });
+    app.update();
+    assert!(!has_tag(&app, owner, "Cooldown.Fireball"));
+}
@@ -0,0 +1,143 @@
+//! Tests that projectiles apply their effect spec with hit data and despawn,
+//! and move on their owner's GAS time.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::{Projectile, ProjectileHitEvent, ProjectileHitRadius, TargetFilter},
+    core::{GasTimeScale, OwnedTags, Team},
+    effects::{ApplyGameplayEffectEvent, GameplayEffectContext, GameplayEffectSpec},
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+struct AppliedSpecs(Vec<GameplayEffectSpec>);
+
+#[derive(Resource, Default)]
+struct Hits(Vec<ProjectileHitEvent>);
+
+#[test]
+fn test_projectile_applies_spec_to_first_hostile_hit() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.init_resource::<AppliedSpecs>().init_resource::<Hits>();
+    app.add_observer(
+        |ev: On<ApplyGameplayEffectEvent>, mut applied: ResMut<AppliedSpecs>| {
+            applied.0.push(ev.spec.clone());
+        },
+    );
+    app.add_observer(|ev: On<ProjectileHitEvent>, mut hits: ResMut<Hits>| {
+        hits.0.push(*ev.event());
+    });
+    app.update();
+
+    let caster = app
+        .world_mut()
+        .spawn((Team(1), OwnedTags::default(), GlobalTransform::IDENTITY))
+        .id();
+    let ally = app
+        .world_mut()
+        .spawn((
+            Team(1),
+            OwnedTags::default(),
+            GlobalTransform::from_translation(Vec3::new(2.0, 0.0, 0.0)),
+        ))
+        .id();
+    let enemy = app
+        .world_mut()
+        .spawn((
+            Team(2),
+            OwnedTags::default(),
+            ProjectileHitRadius(0.5),
+            GlobalTransform::from_translation(Vec3::new(4.0, 0.0, 0.0)),
+        ))
+        .id();
+
+    let spec = GameplayEffectSpec::new("fireball_damage", Entity::PLACEHOLDER)
+        .with_context(GameplayEffectContext::new().with_source(caster));
+    let projectile = app
+        .world_mut()
+        .spawn(
+            Projectile::new(spec, Vec3::new(10.0, 0.0, 0.0))
+                .with_filter(TargetFilter::new().hostile()),
+        )
+        .id();
+
+    for _ in 0..5 {
+        app.update();
+    }
+
+    let applied = &app.world().resource::<AppliedSpecs>().0;
+    assert_eq!(applied.len(), 1);
+    assert_eq!(applied[0].target, enemy);
+    assert_ne!(applied[0].target, ally);
+    assert_eq!(applied[0].context.source, Some(caster));
+    assert_eq!(
+        applied[0].context.hit_location,
+        Some(Vec3::new(3.5, 0.0, 0.0))
+    );
+    assert_eq!(applied[0].context.hit_normal, Some(Vec3::NEG_X));
+
+    let hits = &app.world().resource::<Hits>().0;
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].target, Some(enemy));
+    assert!(app.world().get_entity(projectile).is_err());
+}
+
+#[test]
+fn test_projectile_moves_on_owner_time_scale() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.update();
+
+    let caster = app
+        .world_mut()
+        .spawn((OwnedTags::default(), GasTimeScale(0.5)))
+        .id();
+    let spec = GameplayEffectSpec::new("fireball_damage", Entity::PLACEHOLDER)
+        .with_context(GameplayEffectContext::new().with_source(caster));
+    let projectile = app
+        .world_mut()
+        .spawn((
+            Projectile::new(spec, Vec3::new(10.0, 0.0, 0.0)),
+            Transform::default(),
+        ))
+        .id();
+
+    for _ in 0..4 {
+        app.update();
+    }
+    let x = app
+        .world()
+        .get::<Transform>(projectile)
+        .unwrap()
+        .translation
+        .x;
+    assert!((x - 2.0).abs() < 1e-4, "moved {x}");
+
+    app.world_mut().entity_mut(caster).insert(GasTimeScale(0.0));
+    app.update();
+    let x = app
+        .world()
+        .get::<Transform>(projectile)
+        .unwrap()
+        .translation
+        .x;
+    assert!((x - 2.0).abs() < 1e-4, "moved {x}");
+}
@@ -0,0 +1,103 @@
+//! Tests for the `GasReplay` determinism harness.
+
+#![cfg(feature = "test_utils")]
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{
+    attributes::{AttributeMetadata, AttributeSetDefinition},
+    effects::*,
+    test_utils::{GasInput, GasReplay, GasTestApp},
+};
+
+struct CharacterAttributes;
+
+impl AttributeSetDefinition for CharacterAttributes {
+    fn attribute_names() -> &'static [&'static str] {
+        &["Health", "Armor"]
+    }
+
+    fn attribute_metadata(name: &str) -> Option<AttributeMetadata> {
+        Some(AttributeMetadata::new(name))
+    }
+
+    fn default_value(name: &str) -> f32 {
+        match name {
+            "Health" => 100.0,
+            "Armor" => 10.0,
+            _ => 0.0,
+        }
+    }
+}
+
+fn combat_replay() -> GasReplay<(Entity, Entity)> {
+    GasReplay::new(|app: &mut GasTestApp| {
+        app.register_effect(
+            GameplayEffectDefinition::new("poison")
+                .with_duration_policy(DurationPolicy::HasDuration)
+                .with_duration(2.0)
+                .with_period(0.3)
+                .add_modifier(ModifierInfo::new(
+                    "Health",
+                    ModifierOperation::AddBase,
+                    MagnitudeCalculation::scalar(-3.0),
+                )),
+        );
+        app.register_effect(GameplayEffectDefinition::new("strike").add_modifier(
+            ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddBase,
+                MagnitudeCalculation::scalar(-7.0),
+            ),
+        ));
+        app.register_effect(
+            GameplayEffectDefinition::new("fortify")
+                .with_duration_policy(DurationPolicy::HasDuration)
+                .with_duration(1.0)
+                .add_modifier(ModifierInfo::new(
+                    "Armor",
+                    ModifierOperation::AddCurrent,
+                    MagnitudeCalculation::scalar(5.0),
+                )),
+        );
+        let player = app.spawn_character::<CharacterAttributes>();
+        let enemy = app.spawn_character::<CharacterAttributes>();
+        (player, enemy)
+    })
+}
+
+fn combat_script(frame: u32, &(player, enemy): &(Entity, Entity)) -> Vec<GasInput> {
+    match frame {
+        0 => vec![GasInput::apply_effect("poison", enemy)],
+        5 => vec![GasInput::apply_effect("fortify", player)],
+        f if f % 3 == 0 => vec![
+            GasInput::apply_effect("strike", player),
+            GasInput::apply_effect("strike", enemy),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+#[test]
+fn test_replay_reproduces_recorded_trajectory() {
+    let replay = combat_replay();
+    let recording = replay.record(7, 40, combat_script);
+
+    assert_eq!(recording.trajectory.frames.len(), 40);
+    let last = recording.trajectory.frames.last().unwrap();
+    let enemy_health = last
+        .iter()
+        .find(|sample| sample.owner != last[0].owner && &*sample.attribute == "Health")
+        .unwrap();
+    assert!(enemy_health.base_value < 100.0);
+
+    replay.assert_deterministic(&recording);
+}
+
+#[test]
+#[should_panic(expected = "replay diverged from the recording")]
+fn test_replay_detects_divergence() {
+    let replay = combat_replay();
+    let mut recording = replay.record(7, 40, combat_script);
+    recording.inputs.remove(0);
+    replay.assert_deterministic(&recording);
+}
@@ -0,0 +1,348 @@
+//! Tests that attributes and effects replicate to clients through bevy_replicon
+//! and that clients apply effect tags without running effect logic.
+
+#![cfg(feature = "replicon")]
+
+use bevy::ecs::relationship::Relationship;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::*,
+    attributes::*,
+    core::{OwnedTags, Team},
+    cues::*,
+    effects::*,
+    replication::{GasReplicationPlugin, RepliconAbilityTransport},
+};
+use bevy_gameplay_tag::{
+    GameplayTagContainer, GameplayTagsManager, GameplayTagsPlugin, gameplay_tag::GameplayTag,
+};
+use bevy_replicon::{prelude::*, test_app::ServerTestAppExt};
+
+struct TestAttributeSet;
+
+impl AttributeSetDefinition for TestAttributeSet {
+    fn attribute_names() -> &'static [&'static str] {
+        &["Health"]
+    }
+
+    fn attribute_metadata(name: &str) -> Option<AttributeMetadata> {
+        match name {
+            "Health" => Some(AttributeMetadata::new("Health").with_min(0.0)),
+            _ => None,
+        }
+    }
+
+    fn default_value(name: &str) -> f32 {
+        match name {
+            "Health" => 100.0,
+            _ => 0.0,
+        }
+    }
+}
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        StatesPlugin,
+        RepliconPlugins.set(ServerPlugin::new(PostUpdate)),
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+        GasReplicationPlugin,
+    ));
+    app.update();
+
+    let cooldown_tag = GameplayTag::new("Cooldown.Fireball");
+    let mut granted_tags = GameplayTagContainer::new();
+    granted_tags.add_tag(cooldown_tag, app.world().resource::<GameplayTagsManager>());
+    let mut cooldown = GameplayEffectDefinition::new("fireball_cooldown").with_duration(100.0);
+    cooldown.granted_tags = granted_tags;
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(cooldown);
+    let mut stunned_tags = GameplayTagContainer::new();
+    stunned_tags.add_tag(
+        GameplayTag::new("State.Stunned"),
+        app.world().resource::<GameplayTagsManager>(),
+    );
+    let mut smite =
+        AbilityDefinition::new("smite").with_net_execution_policy(NetExecutionPolicy::ServerOnly);
+    smite.source_required_tags = stunned_tags;
+    let mut abilities = app.world_mut().resource_mut::<AbilityRegistry>();
+    abilities
+        .register(AbilityDefinition::new("fireball").with_cooldown_effect("fireball_cooldown"));
+    abilities.register(smite);
+    app.finish();
+    app
+}
+
+fn client_health(world: &mut World, owner: Entity) -> Option<f32> {
+    let mut query = world.query::<(&AttributeData, &AttributeName, &ChildOf)>();
+    query
+        .iter(world)
+        .find(|(_, name, child_of)| child_of.get() == owner && name.as_str() == "Health")
+        .map(|(data, _, _)| data.current_value)
+}
+
+#[test]
+fn test_attributes_and_effect_tags_replicate_to_client() {
+    let mut server_app = create_app();
+    let mut client_app = create_app();
+    client_app.register_required_components::<Team, OwnedTags>();
+    server_app.connect_client(&mut client_app);
+
+    let character = server_app
+        .world_mut()
+        .spawn((Replicated, Team(1), OwnedTags::default()))
+        .id();
+    server_app.update();
+    server_app.exchange_with_client(&mut client_app);
+    client_app.update();
+
+    TestAttributeSet::create_attributes(&mut server_app.world_mut().commands(), character);
+    server_app.update();
+    server_app
+        .world_mut()
+        .trigger(ApplyGameplayEffectEvent::new(
+            "fireball_cooldown",
+            character,
+        ));
+
+    server_app.update();
+    server_app.exchange_with_client(&mut client_app);
+    client_app.update();
+
+    let client_character = client_app
+        .world_mut()
+        .query_filtered::<Entity, (With<Team>, With<Remote>)>()
+        .single(client_app.world())
+        .unwrap();
+    assert_eq!(
+        client_health(client_app.world_mut(), client_character),
+        Some(100.0)
+    );
+
+    let cooldown_tag = GameplayTag::new("Cooldown.Fireball");
+    let has_cooldown = |app: &App| {
+        app.world()
+            .get::<OwnedTags>(client_character)
+            .unwrap()
+            .0
+            .has_matching_gameplay_tag(&cooldown_tag)
+    };
+    assert!(has_cooldown(&client_app));
+
+    let client_effect = |app: &mut App| {
+        app.world_mut()
+            .query_filtered::<(&EffectTarget, &EffectDuration), With<Remote>>()
+            .single(app.world())
+            .map(|(target, duration)| (target.0, duration.remaining))
+            .unwrap()
+    };
+    let (effect_target, remaining) = client_effect(&mut client_app);
+    assert_eq!(effect_target, client_character);
+
+    // The client does not tick replicated durations itself.
+    client_app.update();
+    client_app.update();
+    assert_eq!(client_effect(&mut client_app).1, remaining);
+
+    // Removing the effect on the server removes the tag on the client.
+    let server_effect = server_app
+        .world_mut()
+        .query_filtered::<Entity, With<ActiveGameplayEffect>>()
+        .single(server_app.world())
+        .unwrap();
+    server_app.world_mut().entity_mut(server_effect).despawn();
+    server_app.update();
+    server_app.exchange_with_client(&mut client_app);
+    client_app.update();
+    assert!(!has_cooldown(&client_app));
+}
+
+#[test]
+fn test_predicted_cooldown_is_replaced_by_server_effect() {
+    let mut server_app = create_app();
+    let mut client_app = create_app();
+    client_app.register_required_components::<Team, OwnedTags>();
+    server_app.connect_client(&mut client_app);
+
+    let character = server_app
+        .world_mut()
+        .spawn((Replicated, Team(1), OwnedTags::default()))
+        .id();
+    server_app.update();
+    server_app.exchange_with_client(&mut client_app);
+    client_app.update();
+
+    server_app.world_mut().spawn((
+        AbilitySpec::new("fireball", 1),
+        AbilityActiveState::default(),
+        AbilityOwner(character),
+    ));
+    server_app.update();
+    server_app.exchange_with_client(&mut client_app);
+    client_app.update();
+
+    let (client_spec, client_character) = client_app
+        .world_mut()
+        .query_filtered::<(Entity, &AbilityOwner), With<Remote>>()
+        .single(client_app.world())
+        .map(|(entity, owner)| (entity, owner.0))
+        .unwrap();
+    assert_eq!(*client_app.world().resource::<NetRole>(), NetRole::Client);
+
+    // The client predicts the cooldown immediately.
+    client_app
+        .world_mut()
+        .trigger(TryActivateAbilityEvent::new(client_spec, client_character));
+    client_app.update();
+    let cooldown_tag = GameplayTag::new("Cooldown.Fireball");
+    let cooldown_count = |app: &App| {
+        app.world()
+            .get::<OwnedTags>(client_character)
+            .unwrap()
+            .0
+            .get_tag_count(&cooldown_tag)
+    };
+    assert_eq!(cooldown_count(&client_app), 1);
+    let key = client_app
+        .world_mut()
+        .query_filtered::<&PredictionKey, Without<Remote>>()
+        .single(client_app.world())
+        .copied()
+        .unwrap();
+
+    // The server activates under the same key and confirms.
+    server_app.exchange_with_client(&mut client_app);
+    server_app.update();
+    server_app.exchange_with_client(&mut client_app);
+    client_app.update();
+
+    assert!(
+        !client_app
+            .world()
+            .resource::<AbilityPredictions>()
+            .is_pending(key)
+    );
+    let client_keys: Vec<_> = client_app
+        .world_mut()
+        .query_filtered::<&PredictionKey, (With<ActiveGameplayEffect>, With<Remote>)>()
+        .iter(client_app.world())
+        .copied()
+        .collect();
+    assert_eq!(client_keys, vec![key]);
+    assert!(
+        client_app
+            .world_mut()
+            .query_filtered::<(), (With<ActiveGameplayEffect>, Without<Remote>)>()
+            .iter(client_app.world())
+            .next()
+            .is_none()
+    );
+    assert_eq!(cooldown_count(&client_app), 1);
+}
+
+#[test]
+fn test_server_only_rejection_reaches_client() {
+    #[derive(Resource, Default)]
+    struct Failures(Vec<AbilityActivationFailedEvent>);
+
+    let mut server_app = create_app();
+    let mut client_app = create_app();
+    client_app.register_required_components::<Team, OwnedTags>();
+    client_app.init_resource::<Failures>().add_observer(
+        |ev: On<AbilityActivationFailedEvent>, mut failures: ResMut<Failures>| {
+            failures.0.push(ev.event().clone());
+        },
+    );
+    server_app.connect_client(&mut client_app);
+
+    let character = server_app
+        .world_mut()
+        .spawn((Replicated, Team(1), OwnedTags::default()))
+        .id();
+    server_app.update();
+    server_app.exchange_with_client(&mut client_app);
+    client_app.update();
+
+    server_app.world_mut().spawn((
+        AbilitySpec::new("smite", 1),
+        AbilityActiveState::default(),
+        AbilityOwner(character),
+    ));
+    server_app.update();
+    server_app.exchange_with_client(&mut client_app);
+    client_app.update();
+
+    let (client_spec, client_character) = client_app
+        .world_mut()
+        .query_filtered::<(Entity, &AbilityOwner), With<Remote>>()
+        .single(client_app.world())
+        .map(|(entity, owner)| (entity, owner.0))
+        .unwrap();
+
+    // The client only asks; the server refuses since the owner is not stunned.
+    client_app
+        .world_mut()
+        .trigger(TryActivateAbilityEvent::new(client_spec, client_character));
+    client_app.update();
+    assert!(client_app.world().resource::<Failures>().0.is_empty());
+
+    server_app.exchange_with_client(&mut client_app);
+    server_app.update();
+    server_app.exchange_with_client(&mut client_app);
+    client_app.update();
+
+    let failures = &client_app.world().resource::<Failures>().0;
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].ability_spec, client_spec);
+    assert_eq!(failures[0].owner, client_character);
+    assert_eq!(
+        failures[0].reason,
+        ActivationFailureReason::MissingRequiredTags
+    );
+}
+
+#[test]
+fn test_cue_is_multicast_to_connected_client() {
+    #[derive(Resource, Default)]
+    struct Played(Vec<TriggerGameplayCueEvent>);
+
+    let mut server_app = create_app();
+    let mut client_app = create_app();
+    client_app.init_resource::<Played>().add_observer(
+        |ev: On<TriggerGameplayCueEvent>, mut played: ResMut<Played>| {
+            played.0.push(ev.event().clone());
+        },
+    );
+    server_app.connect_client(&mut client_app);
+
+    let client = server_app
+        .world_mut()
+        .query_filtered::<Entity, With<ConnectedClient>>()
+        .single(server_app.world())
+        .unwrap();
+    server_app.world_mut().spawn((
+        CueViewer(RepliconAbilityTransport::client_id(ClientId::Client(
+            client,
+        ))),
+        GlobalTransform::IDENTITY,
+    ));
+    server_app.world_mut().trigger(TriggerGameplayCueEvent::new(
+        GameplayTag::new("GameplayCue.Hit"),
+        GameplayCueEvent::Executed,
+        GameplayCueParameters::new().with_location(Vec3::new(1.0, 2.0, 3.0)),
+    ));
+    server_app.update();
+    server_app.exchange_with_client(&mut client_app);
+    client_app.update();
+
+    let played = &client_app.world().resource::<Played>().0;
+    assert_eq!(played.len(), 1);
+    assert_eq!(played[0].cue_tag, GameplayTag::new("GameplayCue.Hit"));
+    assert_eq!(played[0].event_type, GameplayCueEvent::Executed);
+    assert_eq!(played[0].parameters.location, Vec3::new(1.0, 2.0, 3.0));
+}
@@ -0,0 +1,173 @@
+//! Tests for resources granted to the source when an effect executes.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{GasPlugin, attributes::*, core::BatchedEvents, effects::*};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use std::time::Duration;
+use string_cache::DefaultAtom as Atom;
+
+/// Effect ID, target and attribute changes of each execution.
+type Execution = (Atom, Entity, Vec<(Atom, f32)>);
+
+#[derive(Resource, Default)]
+struct Executions(Vec<Execution>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )))
+    .init_resource::<Executions>()
+    .add_observer(
+        |ev: On<GameplayEffectExecutedEvent>, mut executions: ResMut<Executions>| {
+            executions
+                .0
+                .push((ev.effect_id.clone(), ev.target, ev.changes.clone()));
+        },
+    );
+    app.update();
+
+    let mut registry = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    registry.register(
+        GameplayEffectDefinition::new("heroic_strike")
+            .add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddBase,
+                MagnitudeCalculation::scalar(-10.0),
+            ))
+            .add_resource_gain(ResourceGain::new("Rage", 4.0).with_max(10.0)),
+    );
+    registry.register(
+        GameplayEffectDefinition::new("rupture")
+            .with_duration(1.0)
+            .with_period(0.25)
+            .add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddCurrent,
+                MagnitudeCalculation::scalar(-1.0),
+            ))
+            .add_resource_gain(
+                ResourceGain::new("ComboPoints", 1.0).with_max_attribute("MaxComboPoints"),
+            ),
+    );
+    app
+}
+
+fn spawn_with(app: &mut App, attributes: &[(&str, f32)]) -> Entity {
+    let owner = app.world_mut().spawn_empty().id();
+    for (name, value) in attributes {
+        app.world_mut().spawn((
+            AttributeName::new(*name),
+            AttributeData::new(*value),
+            ChildOf(owner),
+        ));
+    }
+    owner
+}
+
+fn base_value(app: &mut App, owner: Entity, name: &str) -> f32 {
+    let mut attributes = app
+        .world_mut()
+        .query::<(&AttributeName, &AttributeData, &ChildOf)>();
+    attributes
+        .iter(app.world())
+        .find(|(attribute, _, child_of)| attribute.0 == *name && child_of.parent() == owner)
+        .map(|(_, data, _)| data.base_value)
+        .unwrap()
+}
+
+#[test]
+fn test_gain_is_applied_to_the_source_up_to_the_cap() {
+    let mut app = create_app();
+    let warrior = spawn_with(&mut app, &[("Rage", 0.0)]);
+    let target = spawn_with(&mut app, &[("Health", 100.0)]);
+
+    let mut rage = Vec::new();
+    for _ in 0..3 {
+        app.world_mut()
+            .trigger(ApplyGameplayEffectEvent::new("heroic_strike", target).with_source(warrior));
+        app.update();
+        rage.push(base_value(&mut app, warrior, "Rage"));
+    }
+    assert_eq!(rage, [4.0, 8.0, 10.0]);
+
+    // The gain goes through the pipeline as its own execution
+    let executions = &app.world().resource::<Executions>().0;
+    let gains: Vec<_> = executions
+        .iter()
+        .filter(|(effect_id, ..)| *effect_id == ResourceGain::new("Rage", 0.0).effect_id())
+        .collect();
+    assert_eq!(gains.len(), 3);
+    assert!(gains.iter().all(|(_, entity, _)| *entity == warrior));
+    assert_eq!(gains[2].2, vec![(Atom::from("Rage"), 2.0)]);
+
+    // At the cap, hits grant nothing more
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("heroic_strike", target).with_source(warrior));
+    app.update();
+    assert_eq!(base_value(&mut app, warrior, "Rage"), 10.0);
+    assert_eq!(app.world().resource::<Executions>().0.len(), 7);
+}
+
+#[test]
+fn test_periodic_executions_gain_up_to_the_max_attribute() {
+    let mut app = create_app();
+    let rogue = spawn_with(&mut app, &[("ComboPoints", 0.0), ("MaxComboPoints", 2.0)]);
+    let target = spawn_with(&mut app, &[("Health", 100.0)]);
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("rupture", target).with_source(rogue));
+    for _ in 0..6 {
+        app.update();
+    }
+    assert_eq!(base_value(&mut app, rogue, "ComboPoints"), 2.0);
+
+    // Without a source nobody gains anything
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("heroic_strike", target));
+    app.update();
+    assert_eq!(base_value(&mut app, target, "Health"), 90.0);
+    let rage_gain = ResourceGain::new("Rage", 0.0).effect_id();
+    assert!(
+        app.world()
+            .resource::<Executions>()
+            .0
+            .iter()
+            .all(|(effect_id, ..)| *effect_id != rage_gain)
+    );
+}
+
+#[test]
+fn test_gains_in_one_pass_share_the_cap() {
+    let mut app = create_app();
+    let warrior = spawn_with(&mut app, &[("Rage", 4.0)]);
+    let targets: Vec<_> = (0..3)
+        .map(|_| spawn_with(&mut app, &[("Health", 100.0)]))
+        .collect();
+
+    // An area hit applied in one batch
+    for &target in &targets {
+        app.world_mut()
+            .resource_mut::<BatchedEvents<ApplyGameplayEffectEvent>>()
+            .push(ApplyGameplayEffectEvent::new("heroic_strike", target).with_source(warrior));
+    }
+    app.update();
+    assert_eq!(base_value(&mut app, warrior, "Rage"), 10.0);
+
+    // Bleeds on two targets ticking in the same frame
+    let rogue = spawn_with(&mut app, &[("ComboPoints", 0.0), ("MaxComboPoints", 1.0)]);
+    for &target in &targets[..2] {
+        app.world_mut()
+            .trigger(ApplyGameplayEffectEvent::new("rupture", target).with_source(rogue));
+    }
+    for _ in 0..2 {
+        app.update();
+    }
+    assert_eq!(base_value(&mut app, rogue, "ComboPoints"), 1.0);
+}
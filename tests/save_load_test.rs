@@ -0,0 +1,228 @@
+//! Tests that an entity's GAS state survives a save/load round trip.
+
+#![cfg(feature = "serde")]
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin, abilities::*, attributes::*, core::OwnedTags, effects::*, serialization::*,
+};
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use bevy_gameplay_tag::{GameplayTagContainer, GameplayTagsManager, GameplayTagsPlugin};
+use std::time::Duration;
+
+struct TestAttributeSet;
+
+impl AttributeSetDefinition for TestAttributeSet {
+    fn attribute_names() -> &'static [&'static str] {
+        &["Health", "AttackPower"]
+    }
+
+    fn attribute_metadata(_name: &str) -> Option<AttributeMetadata> {
+        None
+    }
+
+    fn default_value(name: &str) -> f32 {
+        match name {
+            "Health" => 100.0,
+            "AttackPower" => 10.0,
+            _ => 0.0,
+        }
+    }
+}
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )));
+    app.update();
+
+    let mut buffed = GameplayTagContainer::default();
+    buffed.add_tag(
+        GameplayTag::new("State.Buffed"),
+        app.world().resource::<GameplayTagsManager>(),
+    );
+    let mut rage = GameplayEffectDefinition::new("rage")
+        .with_duration_policy(DurationPolicy::HasDuration)
+        .with_duration(10.0)
+        .with_stacking_policy(StackingPolicy::StackCount { max_stacks: 3 })
+        .add_modifier(ModifierInfo::new(
+            "AttackPower",
+            ModifierOperation::AddCurrent,
+            MagnitudeCalculation::scalar(5.0),
+        ));
+    rage.granted_tags = buffed;
+    let mut effects = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    effects.register(rage);
+    effects.register(
+        GameplayEffectDefinition::new("fireball_cooldown")
+            .with_duration_policy(DurationPolicy::HasDuration)
+            .with_duration(4.0),
+    );
+    app.world_mut()
+        .resource_mut::<AbilityRegistry>()
+        .register(AbilityDefinition::new("fireball").with_cooldown_effect("fireball_cooldown"));
+    app
+}
+
+fn spawn_character(app: &mut App) -> Entity {
+    let mut commands = app.world_mut().commands();
+    let character = commands.spawn(OwnedTags::default()).id();
+    TestAttributeSet::create_attributes(&mut commands, character);
+    app.world_mut().flush();
+    character
+}
+
+fn attribute(app: &mut App, owner: Entity, name: &str) -> AttributeData {
+    let mut query = app
+        .world_mut()
+        .query::<(&AttributeName, &AttributeData, &ChildOf)>();
+    query
+        .iter(app.world())
+        .find(|(attribute_name, _, child_of)| {
+            child_of.parent() == owner && attribute_name.as_str() == name
+        })
+        .map(|(_, data, _)| *data)
+        .unwrap()
+}
+
+#[test]
+fn test_state_round_trips_through_save_file() {
+    let mut app = create_app();
+    let player = spawn_character(&mut app);
+    app.world_mut()
+        .spawn((AbilitySpec::new("fireball", 2), AbilityOwner(player)));
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("rage", player));
+    app.update();
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("rage", player));
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("fireball_cooldown", player));
+    app.update();
+    let mut query = app
+        .world_mut()
+        .query::<(&AttributeName, &mut AttributeData)>();
+    for (name, mut data) in query.iter_mut(app.world_mut()) {
+        if name.as_str() == "Health" {
+            data.set_base_value(70.0);
+        }
+    }
+    for _ in 0..4 {
+        app.update();
+    }
+
+    let snapshot = GasStateSnapshot::capture(app.world(), player);
+    assert_eq!(snapshot.effects.len(), 2);
+    let rage = snapshot
+        .effects
+        .iter()
+        .find(|effect| effect.definition_id.as_ref() == "rage")
+        .unwrap();
+    assert_eq!(rage.stack_count, 2);
+    assert_eq!(rage.modifiers.len(), 2);
+    let cooldown = snapshot
+        .cooldown_remaining("fireball", app.world().resource::<AbilityRegistry>())
+        .unwrap();
+    assert!((cooldown - 3.0).abs() < 0.3, "{cooldown}");
+    let saved = serde_json::to_string(&snapshot).unwrap();
+
+    // Load into a fresh app.
+    let mut app = create_app();
+    let player = spawn_character(&mut app);
+    app.update();
+    let loaded: GasStateSnapshot = serde_json::from_str(&saved).unwrap();
+    app.world_mut()
+        .trigger(RestoreGasStateEvent::new(player, loaded));
+    app.update();
+
+    assert_eq!(attribute(&mut app, player, "Health").base_value, 70.0);
+    assert_eq!(
+        attribute(&mut app, player, "AttackPower").current_value,
+        20.0
+    );
+    assert!(
+        app.world()
+            .get::<OwnedTags>(player)
+            .unwrap()
+            .0
+            .explicit_tags
+            .has_tag(&GameplayTag::new("State.Buffed"))
+    );
+    let mut specs = app.world_mut().query::<(&AbilitySpec, &AbilityOwner)>();
+    let levels: Vec<_> = specs
+        .iter(app.world())
+        .filter(|(_, owner)| owner.0 == player)
+        .map(|(spec, _)| spec.level)
+        .collect();
+    assert_eq!(levels, vec![2]);
+
+    // The restored modifiers are kept rather than respawned.
+    let modifiers = app
+        .world_mut()
+        .query::<&AttributeModifier>()
+        .iter(app.world())
+        .count();
+    assert_eq!(modifiers, 2);
+
+    // The cooldown runs out on the saved schedule.
+    let resaved = GasStateSnapshot::capture(app.world(), player);
+    let remaining = resaved
+        .cooldown_remaining("fireball", app.world().resource::<AbilityRegistry>())
+        .unwrap();
+    assert!((remaining - (cooldown - 0.25)).abs() < 1e-3, "{remaining}");
+    for _ in 0..16 {
+        app.update();
+    }
+    let resaved = GasStateSnapshot::capture(app.world(), player);
+    assert_eq!(resaved.effects.len(), 1);
+    assert_eq!(resaved.effects[0].definition_id.as_ref(), "rage");
+}
+
+#[test]
+fn test_restore_replaces_existing_state() {
+    let mut app = create_app();
+    let player = spawn_character(&mut app);
+    app.world_mut()
+        .spawn((AbilitySpec::new("fireball", 1), AbilityOwner(player)));
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("rage", player));
+    app.update();
+
+    app.world_mut().trigger(RestoreGasStateEvent::new(
+        player,
+        GasStateSnapshot::default(),
+    ));
+    app.update();
+    app.update();
+
+    assert_eq!(
+        GasStateSnapshot::capture(app.world(), player).effects,
+        vec![]
+    );
+    assert_eq!(
+        app.world_mut()
+            .query::<&AbilitySpec>()
+            .iter(app.world())
+            .count(),
+        0
+    );
+    assert_eq!(
+        attribute(&mut app, player, "AttackPower").current_value,
+        10.0
+    );
+    assert!(
+        !app.world()
+            .get::<OwnedTags>(player)
+            .unwrap()
+            .0
+            .explicit_tags
+            .has_tag(&GameplayTag::new("State.Buffed"))
+    );
+}
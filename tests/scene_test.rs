@@ -0,0 +1,171 @@
+//! Tests that a character's GAS entities survive a `DynamicScene` round trip.
+
+#![cfg(feature = "serde")]
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use bevy::scene::serde::SceneDeserializer;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::*,
+    attributes::*,
+    core::{OwnedTags, extract_gas_scene},
+    effects::*,
+};
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use bevy_gameplay_tag::{GameplayTagContainer, GameplayTagsManager, GameplayTagsPlugin};
+use serde::de::DeserializeSeed;
+
+struct TestAttributeSet;
+
+impl AttributeSetDefinition for TestAttributeSet {
+    fn attribute_names() -> &'static [&'static str] {
+        &["Health", "AttackPower"]
+    }
+
+    fn attribute_metadata(_name: &str) -> Option<AttributeMetadata> {
+        None
+    }
+
+    fn default_value(name: &str) -> f32 {
+        match name {
+            "Health" => 100.0,
+            "AttackPower" => 10.0,
+            _ => 0.0,
+        }
+    }
+}
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.update();
+
+    let mut buffed = GameplayTagContainer::default();
+    buffed.add_tag(
+        GameplayTag::new("State.Buffed"),
+        app.world().resource::<GameplayTagsManager>(),
+    );
+    let mut rage = GameplayEffectDefinition::new("rage")
+        .with_duration_policy(DurationPolicy::HasDuration)
+        .with_duration(10.0)
+        .with_stacking_policy(StackingPolicy::StackCount { max_stacks: 3 })
+        .add_modifier(ModifierInfo::new(
+            "AttackPower",
+            ModifierOperation::AddCurrent,
+            MagnitudeCalculation::scalar(5.0),
+        ));
+    rage.granted_tags = buffed;
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(rage);
+    app.world_mut()
+        .resource_mut::<AttributeSetRegistry>()
+        .register::<TestAttributeSet>();
+    app
+}
+
+fn attribute(app: &mut App, owner: Entity, name: &str) -> AttributeData {
+    let mut query = app
+        .world_mut()
+        .query::<(&AttributeName, &AttributeData, &ChildOf)>();
+    query
+        .iter(app.world())
+        .find(|(attribute_name, _, child_of)| {
+            child_of.parent() == owner && attribute_name.as_str() == name
+        })
+        .map(|(_, data, _)| *data)
+        .unwrap()
+}
+
+fn count<C: Component>(app: &mut App) -> usize {
+    app.world_mut().query::<&C>().iter(app.world()).count()
+}
+
+#[test]
+fn test_character_round_trips_through_scene() {
+    let mut app = create_app();
+    let player = {
+        let mut commands = app.world_mut().commands();
+        let player = commands.spawn(OwnedTags::default()).id();
+        TestAttributeSet::create_attributes(&mut commands, player);
+        player
+    };
+    app.world_mut().flush();
+    app.world_mut()
+        .spawn((AbilitySpec::new("fireball", 2), AbilityOwner(player)));
+    for _ in 0..2 {
+        app.world_mut()
+            .trigger(ApplyGameplayEffectEvent::new("rage", player));
+        app.update();
+    }
+    let mut query = app
+        .world_mut()
+        .query::<(&AttributeName, &mut AttributeData)>();
+    for (name, mut data) in query.iter_mut(app.world_mut()) {
+        if name.as_str() == "Health" {
+            data.set_base_value(70.0);
+        }
+    }
+    app.update();
+
+    let scene = extract_gas_scene(app.world(), player);
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+    let text = scene.serialize(&registry.read()).unwrap();
+
+    // Load into a fresh app.
+    let mut app = create_app();
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+    let mut deserializer = ron::de::Deserializer::from_str(&text).unwrap();
+    let scene = SceneDeserializer {
+        type_registry: &registry.read(),
+    }
+    .deserialize(&mut deserializer)
+    .unwrap();
+    let mut entity_map = EntityHashMap::default();
+    scene
+        .write_to_world(app.world_mut(), &mut entity_map)
+        .unwrap();
+    let player = entity_map[&player];
+    app.update();
+
+    assert_eq!(attribute(&mut app, player, "Health").base_value, 70.0);
+    assert_eq!(
+        attribute(&mut app, player, "AttackPower").current_value,
+        20.0
+    );
+    assert_eq!(count::<AttributeSetId>(&mut app), 2);
+    assert!(
+        app.world()
+            .get::<OwnedTags>(player)
+            .unwrap()
+            .0
+            .explicit_tags
+            .has_tag(&GameplayTag::new("State.Buffed"))
+    );
+
+    let effects = app.world().get::<ActiveEffects>(player).unwrap();
+    assert_eq!(effects.len(), 1);
+    let effect = effects.iter().next().unwrap();
+    let active_effect = app.world().get::<ActiveGameplayEffect>(effect).unwrap();
+    assert_eq!(active_effect.stack_count, 2);
+    assert_eq!(active_effect.target, player);
+    assert_eq!(count::<AttributeModifier>(&mut app), 2);
+
+    let abilities = app.world().get::<OwnedAbilities>(player).unwrap();
+    assert_eq!(abilities.len(), 1);
+    let spec = abilities.iter().next().unwrap();
+    assert_eq!(app.world().get::<AbilitySpec>(spec).unwrap().level, 2);
+    assert!(app.world().get::<AbilityActiveState>(spec).is_some());
+
+    // Despawning the character takes its effects and abilities with it.
+    app.world_mut().despawn(player);
+    app.update();
+    assert_eq!(count::<ActiveGameplayEffect>(&mut app), 0);
+    assert_eq!(count::<AttributeModifier>(&mut app), 0);
+    assert_eq!(count::<AbilitySpec>(&mut app), 0);
+}
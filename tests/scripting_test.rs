@@ -0,0 +1,161 @@
+//! Tests scripted ability behaviors and scripted magnitude calculations.
+
+#![cfg(feature = "scripting")]
+
+use bevy::ecs::relationship::Relationship;
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::*,
+    attributes::*,
+    core::{BlockedAbilityTags, OwnedTags},
+    effects::*,
+    scripting::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+
+const FIREBOLT: &str = r#"
+fn can_activate(gas) {
+    gas.attribute(gas.source, "Mana") >= 10.0
+}
+
+fn activate(gas) {
+    gas.apply_effect("drain", gas.target);
+    gas.end_ability();
+}
+
+fn magnitude(gas) {
+    -2.0 * gas.attribute(gas.source, "Mana")
+}
+"#;
+
+struct Stats;
+
+impl AttributeSetDefinition for Stats {
+    fn attribute_names() -> &'static [&'static str] {
+        &["Health", "Mana"]
+    }
+
+    fn attribute_metadata(_name: &str) -> Option<AttributeMetadata> {
+        None
+    }
+
+    fn default_value(name: &str) -> f32 {
+        match name {
+            "Health" => 100.0,
+            _ => 20.0,
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+struct Failures(Vec<ActivationFailureReason>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+        ScriptingPlugin::new(),
+    ))
+    .init_resource::<Failures>();
+    app.add_observer(
+        |ev: On<AbilityActivationFailedEvent>, mut failures: ResMut<Failures>| {
+            failures.0.push(ev.reason);
+        },
+    );
+    app.update();
+
+    let engine = app.world().resource::<GasScriptEngine>().clone();
+    engine.register_script("firebolt", FIREBOLT).unwrap();
+    app.world_mut()
+        .resource_mut::<CustomCalculationRegistry>()
+        .register("firebolt", Box::new(engine.magnitude("firebolt")));
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("drain").add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddBase,
+                MagnitudeCalculation::custom("firebolt"),
+            )),
+        );
+    app.world_mut()
+        .resource_mut::<AbilityRegistry>()
+        .register(AbilityDefinition::new("firebolt").with_script("firebolt"));
+    app
+}
+
+fn spawn_caster(app: &mut App) -> (Entity, Entity) {
+    let owner = app
+        .world_mut()
+        .spawn((OwnedTags::default(), BlockedAbilityTags::default()))
+        .id();
+    Stats::create_attributes(&mut app.world_mut().commands(), owner);
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("firebolt", 1),
+            AbilityActiveState::default(),
+            AbilityOwner(owner),
+        ))
+        .id();
+    app.update();
+    (owner, spec)
+}
+
+fn attribute(app: &mut App, owner: Entity, name: &str) -> (Entity, f32) {
+    let mut query = app
+        .world_mut()
+        .query::<(Entity, &AttributeData, &AttributeName, &ChildOf)>();
+    query
+        .iter(app.world())
+        .find(|(_, _, attribute_name, child_of)| {
+            child_of.get() == owner && attribute_name.as_str() == name
+        })
+        .map(|(entity, data, _, _)| (entity, data.base_value))
+        .unwrap()
+}
+
+#[test]
+fn test_scripted_ability_applies_effect_with_scripted_magnitude() {
+    let mut app = create_app();
+    let (owner, spec) = spawn_caster(&mut app);
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+    app.update();
+
+    // The script applied "drain" to the caster: -2 * Mana (20).
+    assert_eq!(attribute(&mut app, owner, "Health").1, 60.0);
+    assert!(
+        !app.world()
+            .get::<AbilityActiveState>(spec)
+            .unwrap()
+            .is_active
+    );
+}
+
+#[test]
+fn test_script_refuses_activation() {
+    let mut app = create_app();
+    let (owner, spec) = spawn_caster(&mut app);
+    let (mana, _) = attribute(&mut app, owner, "Mana");
+    app.world_mut()
+        .get_mut::<AttributeData>(mana)
+        .unwrap()
+        .set_base_value(5.0);
+    app.update();
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<Failures>().0,
+        vec![ActivationFailureReason::RejectedByBehavior]
+    );
+    assert_eq!(attribute(&mut app, owner, "Health").1, 100.0);
+}
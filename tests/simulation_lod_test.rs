@@ -0,0 +1,100 @@
+//! Tests for ticking GAS timers in chunks with `GasSimulationLod`.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    attributes::{AttributeData, AttributeName},
+    core::{GasSimulationLod, OwnedTags},
+    effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use std::time::Duration;
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )));
+    app.update();
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("poison")
+                .with_duration_policy(DurationPolicy::HasDuration)
+                .with_duration(3.0)
+                .with_period(0.5)
+                .add_modifier(ModifierInfo::new(
+                    "Health",
+                    ModifierOperation::AddBase,
+                    MagnitudeCalculation::scalar(-5.0),
+                )),
+        );
+    app
+}
+
+fn spawn_target(app: &mut App, lod: Option<GasSimulationLod>) -> (Entity, Entity) {
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    if let Some(lod) = lod {
+        app.world_mut().entity_mut(target).insert(lod);
+    }
+    let health = app
+        .world_mut()
+        .spawn((
+            AttributeName::new("Health"),
+            AttributeData::new(100.0),
+            ChildOf(target),
+        ))
+        .id();
+    (target, health)
+}
+
+#[test]
+fn test_lod_target_ticks_in_chunks_and_catches_up() {
+    let mut app = create_app();
+    let (normal, normal_health) = spawn_target(&mut app, None);
+    let (distant, distant_health) = spawn_target(&mut app, Some(GasSimulationLod::new(1.0)));
+    for target in [normal, distant] {
+        app.world_mut()
+            .trigger(ApplyGameplayEffectEvent::new("poison", target));
+    }
+    app.update();
+
+    let base =
+        |app: &App, health: Entity| app.world().get::<AttributeData>(health).unwrap().base_value;
+    let remaining = |app: &App, target: Entity| {
+        let effect = app
+            .world()
+            .get::<ActiveEffects>(target)
+            .unwrap()
+            .iter()
+            .next()
+            .unwrap();
+        app.world().get::<EffectDuration>(effect).unwrap().remaining
+    };
+
+    // Half a second in, only the full-rate target has ticked again.
+    for _ in 0..2 {
+        app.update();
+    }
+    assert_eq!(base(&app, normal_health), 90.0);
+    assert_eq!(base(&app, distant_health), 95.0);
+    assert_eq!(remaining(&app, distant), 3.0);
+
+    // The chunk released after a second catches up on the missed executions.
+    app.update();
+    assert_eq!(base(&app, distant_health), base(&app, normal_health));
+    assert_eq!(remaining(&app, distant), remaining(&app, normal));
+
+    for _ in 0..12 {
+        app.update();
+    }
+    assert!(app.world().get::<ActiveEffects>(distant).is_none());
+    assert_eq!(base(&app, distant_health), base(&app, normal_health));
+    assert_eq!(base(&app, distant_health), 65.0);
+}
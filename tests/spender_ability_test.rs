@@ -0,0 +1,139 @@
+//! Tests for spender abilities consuming combo points or effect stacks.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{
+    GasPlugin, abilities::*, attributes::*, core::OwnedTags, effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+
+#[derive(Resource, Default)]
+struct Spent(Vec<AbilitySpentEvent>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .init_resource::<Spent>()
+    .add_observer(
+        |ev: On<AbilitySpentEvent>, mut commands: Commands, mut spent: ResMut<Spent>| {
+            let ev = ev.event();
+            commands.trigger(ev.effect("guard", ev.owner));
+            spent.0.push(ev.clone());
+        },
+    );
+    app.update();
+
+    let mut effects = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    effects.register(
+        GameplayEffectDefinition::new("sunder")
+            .with_duration(30.0)
+            .with_stacking_policy(StackingPolicy::StackCount { max_stacks: 5 }),
+    );
+    effects.register(
+        GameplayEffectDefinition::new("guard").add_modifier(ModifierInfo::new(
+            "Armor",
+            ModifierOperation::AddBase,
+            MagnitudeCalculation::set_by_caller(GameplayTag::new(SPENT_COUNT_TAG)),
+        )),
+    );
+    let mut abilities = app.world_mut().resource_mut::<AbilityRegistry>();
+    abilities.register(
+        AbilityDefinition::new("eviscerate").with_spend(AbilitySpend::attribute("ComboPoints")),
+    );
+    abilities.register(
+        AbilityDefinition::new("shatter")
+            .with_spend(AbilitySpend::effect_stacks("sunder").with_max_count(3)),
+    );
+    app
+}
+
+fn spawn_character(app: &mut App) -> Entity {
+    let character = app.world_mut().spawn(OwnedTags::default()).id();
+    for (name, value) in [("ComboPoints", 4.0), ("Armor", 0.0)] {
+        app.world_mut().spawn((
+            AttributeName::new(name),
+            AttributeData::new(value),
+            ChildOf(character),
+        ));
+    }
+    character
+}
+
+fn activate(app: &mut App, owner: Entity, ability_id: &str) {
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new(ability_id, 1),
+            AbilityActiveState::default(),
+            AbilityOwner(owner),
+        ))
+        .id();
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+}
+
+fn base_value(app: &mut App, owner: Entity, name: &str) -> f32 {
+    let mut attributes = app
+        .world_mut()
+        .query::<(&AttributeName, &AttributeData, &ChildOf)>();
+    attributes
+        .iter(app.world())
+        .find(|(attribute, _, child_of)| attribute.0 == *name && child_of.parent() == owner)
+        .map(|(_, data, _)| data.base_value)
+        .unwrap()
+}
+
+fn sunder_stacks(app: &mut App) -> Vec<i32> {
+    let mut effects = app.world_mut().query::<&ActiveGameplayEffect>();
+    effects
+        .iter(app.world())
+        .filter(|effect| effect.definition_id == *"sunder")
+        .map(|effect| effect.stack_count)
+        .collect()
+}
+
+#[test]
+fn test_spender_consumes_all_points_and_passes_the_count() {
+    let mut app = create_app();
+    let rogue = spawn_character(&mut app);
+
+    activate(&mut app, rogue, "eviscerate");
+    assert_eq!(base_value(&mut app, rogue, "ComboPoints"), 0.0);
+    let spent = &app.world().resource::<Spent>().0;
+    assert_eq!(spent.len(), 1);
+    assert_eq!(spent[0].count, 4);
+    // The count reaches the applied effect as a SetByCaller magnitude
+    assert_eq!(base_value(&mut app, rogue, "Armor"), 4.0);
+
+    // Nothing left to spend
+    activate(&mut app, rogue, "eviscerate");
+    assert_eq!(app.world().resource::<Spent>().0[1].count, 0);
+    assert_eq!(base_value(&mut app, rogue, "Armor"), 4.0);
+}
+
+#[test]
+fn test_spender_consumes_effect_stacks_up_to_the_max() {
+    let mut app = create_app();
+    let warrior = spawn_character(&mut app);
+    for _ in 0..5 {
+        app.world_mut()
+            .trigger(ApplyGameplayEffectEvent::new("sunder", warrior));
+        app.update();
+    }
+    assert_eq!(sunder_stacks(&mut app), [5]);
+
+    activate(&mut app, warrior, "shatter");
+    assert_eq!(app.world().resource::<Spent>().0[0].count, 3);
+    assert_eq!(sunder_stacks(&mut app), [2]);
+
+    activate(&mut app, warrior, "shatter");
+    assert_eq!(app.world().resource::<Spent>().0[1].count, 2);
+    // Consuming the last stacks removes the effect
+    app.update();
+    assert!(sunder_stacks(&mut app).is_empty());
+}
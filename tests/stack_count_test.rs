@@ -210,3 +210,79 @@ fn test_stack_count_removes_modifiers_on_decrease() {
         "Should have 1 modifier after stack decrease"
     );
 }
+
+#[test]
+fn test_stacks_are_tracked_per_target_and_expire_with_their_modifiers() {
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("stack_buff")
+                .with_duration_policy(DurationPolicy::HasDuration)
+                .with_duration(0.5)
+                .with_stacking_policy(StackingPolicy::StackCount { max_stacks: 3 })
+                .add_modifier(ModifierInfo::new(
+                    "AttackPower",
+                    ModifierOperation::AddCurrent,
+                    MagnitudeCalculation::scalar(5.0),
+                )),
+        );
+
+    let (first, second) = {
+        let mut commands = app.world_mut().commands();
+        let first = commands.spawn_empty().id();
+        let second = commands.spawn_empty().id();
+        TestAttributeSet::create_attributes(&mut commands, first);
+        TestAttributeSet::create_attributes(&mut commands, second);
+        (first, second)
+    };
+    app.world_mut().flush();
+
+    for target in [first, first, second] {
+        app.world_mut()
+            .trigger(ApplyGameplayEffectEvent::new("stack_buff", target));
+        app.update();
+    }
+
+    let stacks = |app: &App, target: Entity| {
+        let effects = app.world().get::<ActiveEffects>(target).unwrap();
+        assert_eq!(effects.len(), 1);
+        let effect = effects.iter().next().unwrap();
+        let modifiers = app.world().get::<EffectModifiers>(effect).unwrap().len();
+        let stack_count = app
+            .world()
+            .get::<ActiveGameplayEffect>(effect)
+            .unwrap()
+            .stack_count;
+        assert_eq!(modifiers, stack_count as usize);
+        stack_count
+    };
+    assert_eq!(stacks(&app, first), 2);
+    assert_eq!(stacks(&app, second), 1);
+
+    for _ in 0..10 {
+        app.update();
+    }
+
+    assert!(app.world().get::<ActiveEffects>(first).is_none());
+    assert!(app.world().get::<ActiveEffects>(second).is_none());
+    let modifier_count = app
+        .world_mut()
+        .query::<&AttributeModifier>()
+        .iter(app.world())
+        .count();
+    assert_eq!(modifier_count, 0);
+}
@@ -0,0 +1,141 @@
+//! Tests for GAS state dumps and diffs.
+
+#![cfg(feature = "serde")]
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::{
+        AbilityActiveState, AbilityDefinition, AbilityOwner, AbilityRegistry, AbilitySpec,
+        TryActivateAbilityEvent,
+    },
+    attributes::{AttributeData, AttributeName},
+    core::OwnedTags,
+    debug::{GasStateChange, GasWorldState, dump_gas_state},
+    effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use serde_json::json;
+
+fn create_app() -> (App, Entity, Entity) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.update();
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("burn")
+                .with_duration_policy(DurationPolicy::Infinite)
+                .add_modifier(ModifierInfo::new(
+                    "Health",
+                    ModifierOperation::AddCurrent,
+                    MagnitudeCalculation::scalar(-10.0),
+                )),
+        );
+    app.world_mut()
+        .resource_mut::<AbilityRegistry>()
+        .register(AbilityDefinition::new("dash"));
+    let player = app
+        .world_mut()
+        .spawn((Name::new("Player"), OwnedTags::default()))
+        .id();
+    app.world_mut().spawn((
+        AttributeName::new("Health"),
+        AttributeData::new(100.0),
+        ChildOf(player),
+    ));
+    let dash = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new("dash", 1),
+            AbilityActiveState::default(),
+            AbilityOwner(player),
+        ))
+        .id();
+    app.update();
+    (app, player, dash)
+}
+
+#[test]
+fn test_dump_matches_golden_state() {
+    let (mut app, player, dash) = create_app();
+
+    assert_eq!(
+        dump_gas_state(app.world_mut()),
+        json!({
+            player.to_string(): {
+                "name": "Player",
+                "attributes": { "Health": { "base": 100.0, "current": 100.0 } },
+                "effects": {},
+                "abilities": {
+                    dash.to_string(): {
+                        "id": "dash",
+                        "level": 1,
+                        "active": false,
+                        "active_count": 0,
+                        "cooldown_remaining": null,
+                    }
+                },
+                "tags": [],
+            }
+        })
+    );
+}
+
+#[test]
+fn test_diff_reports_frame_to_frame_changes() {
+    let (mut app, player, dash) = create_app();
+    let before = GasWorldState::capture(app.world_mut());
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("burn", player));
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(dash, player));
+    app.update();
+    let after = GasWorldState::capture(app.world_mut());
+
+    let changes = before.diff(&after);
+    let effect = after.owners[0].effects[0].entity;
+    assert_eq!(
+        changes,
+        vec![
+            GasStateChange::AttributeChanged {
+                owner: player,
+                attribute: "Health".into(),
+                old_base: 100.0,
+                new_base: 100.0,
+                old_current: 100.0,
+                new_current: 90.0,
+            },
+            GasStateChange::EffectAdded {
+                owner: player,
+                effect,
+                effect_id: "burn".into(),
+            },
+            GasStateChange::AbilityStateChanged {
+                owner: player,
+                ability: dash,
+                ability_id: "dash".into(),
+                is_active: true,
+            },
+        ]
+    );
+    assert_eq!(
+        changes[0].to_string(),
+        format!("{player}: Health 100 -> 90 (base 100 -> 100, delta -10)")
+    );
+
+    app.world_mut().despawn(effect);
+    app.update();
+    let removed = after.diff(&GasWorldState::capture(app.world_mut()));
+    assert!(removed.contains(&GasStateChange::EffectRemoved {
+        owner: player,
+        effect,
+        effect_id: "burn".into(),
+    }));
+    assert!(after.diff(&after).is_empty());
+}
@@ -0,0 +1,241 @@
+//! Tests for the ready-made status effects of `GasStatusEffectsPlugin`.
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::*,
+    attributes::*,
+    core::{BatchedEvents, OwnedTags},
+    effects::*,
+};
+use bevy_gameplay_tag::gameplay_tag::GameplayTag;
+use bevy_gameplay_tag::{GameplayTagsManager, GameplayTagsPlugin};
+use std::time::Duration;
+
+#[derive(Resource, Default)]
+struct Failures(Vec<(Entity, ActivationFailureReason)>);
+
+#[derive(Resource, Default)]
+struct HealthChanges(Vec<f32>);
+
+struct Actor {
+    owner: Entity,
+    health: Entity,
+    move_speed: Entity,
+    shield: Entity,
+}
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+        GasStatusEffectsPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )))
+    .init_resource::<Failures>()
+    .add_observer(
+        |ev: On<AbilityActivationFailedEvent>, mut failures: ResMut<Failures>| {
+            failures.0.push((ev.ability_spec, ev.reason));
+        },
+    );
+    app.update();
+
+    app.world_mut()
+        .run_system_once(
+            |tags_manager: Res<GameplayTagsManager>, mut registry: ResMut<AbilityRegistry>| {
+                registry.register(
+                    AbilityDefinition::new("fireball")
+                        .add_ability_tag(GameplayTag::new("Ability.Type.Spell"), &tags_manager),
+                );
+                registry.register(AbilityDefinition::new("punch"));
+            },
+        )
+        .unwrap();
+    app
+}
+
+fn spawn_actor(app: &mut App) -> Actor {
+    let owner = app.world_mut().spawn(OwnedTags::default()).id();
+    let mut attribute = |name: &str, value: f32| {
+        app.world_mut()
+            .spawn((
+                AttributeName::new(name),
+                AttributeData::new(value),
+                ChildOf(owner),
+            ))
+            .id()
+    };
+    Actor {
+        owner,
+        health: attribute("Health", 100.0),
+        move_speed: attribute("MoveSpeedMultiplier", 1.0),
+        shield: attribute("Shield", 0.0),
+    }
+}
+
+fn grant(app: &mut App, owner: Entity, ability_id: &str) -> Entity {
+    app.world_mut()
+        .spawn((
+            AbilitySpec::new(ability_id, 1),
+            AbilityActiveState::default(),
+            AbilityOwner(owner),
+        ))
+        .id()
+}
+
+fn apply(app: &mut App, status: StatusEffect, target: Entity) {
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new(status.effect_id(), target));
+    app.update();
+}
+
+fn try_activate(app: &mut App, spec: Entity, owner: Entity) -> bool {
+    let failures = app.world().resource::<Failures>().0.len();
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+    app.world().resource::<Failures>().0.len() == failures
+}
+
+fn advance(app: &mut App, seconds: f32) {
+    for _ in 0..(seconds / 0.25).ceil() as usize {
+        app.update();
+    }
+}
+
+fn value(app: &App, attribute: Entity) -> f32 {
+    app.world()
+        .get::<AttributeData>(attribute)
+        .unwrap()
+        .current_value
+}
+
+#[test]
+fn test_silence_blocks_spells_until_it_expires() {
+    let mut app = create_app();
+    let actor = spawn_actor(&mut app);
+    let fireball = grant(&mut app, actor.owner, "fireball");
+    let punch = grant(&mut app, actor.owner, "punch");
+
+    apply(&mut app, StatusEffect::Silence, actor.owner);
+    assert!(!try_activate(&mut app, fireball, actor.owner));
+    assert_eq!(
+        app.world().resource::<Failures>().0,
+        vec![(fireball, ActivationFailureReason::BlockedByTags)]
+    );
+    assert!(try_activate(&mut app, punch, actor.owner));
+
+    advance(&mut app, 3.5);
+    assert!(try_activate(&mut app, fireball, actor.owner));
+}
+
+#[test]
+fn test_movement_statuses_write_move_speed() {
+    let mut app = create_app();
+    let actor = spawn_actor(&mut app);
+
+    apply(&mut app, StatusEffect::Slow, actor.owner);
+    app.update();
+    assert!((value(&app, actor.move_speed) - 0.7).abs() < 1e-5);
+
+    apply(&mut app, StatusEffect::Root, actor.owner);
+    app.update();
+    assert_eq!(value(&app, actor.move_speed), 0.0);
+
+    advance(&mut app, 4.5);
+    assert_eq!(value(&app, actor.move_speed), 1.0);
+}
+
+#[test]
+fn test_stun_blocks_tagged_abilities() {
+    let mut app = create_app();
+    let actor = spawn_actor(&mut app);
+    let fireball = grant(&mut app, actor.owner, "fireball");
+
+    apply(&mut app, StatusEffect::Stun, actor.owner);
+    app.update();
+    assert!(!try_activate(&mut app, fireball, actor.owner));
+    assert_eq!(value(&app, actor.move_speed), 0.0);
+}
+
+#[test]
+fn test_shield_absorbs_burn_then_resets() {
+    let mut app = create_app();
+    let actor = spawn_actor(&mut app);
+
+    apply(&mut app, StatusEffect::Shield, actor.owner);
+    app.update();
+    assert_eq!(value(&app, actor.shield), 50.0);
+
+    apply(&mut app, StatusEffect::Burn, actor.owner);
+    advance(&mut app, 3.0);
+    assert_eq!(value(&app, actor.health), 100.0);
+    assert!(value(&app, actor.shield) < 50.0);
+
+    // The shield expires and its absorbed amount doesn't linger.
+    advance(&mut app, 8.0);
+    assert_eq!(value(&app, actor.health), 100.0);
+    assert_eq!(value(&app, actor.shield), 0.0);
+}
+
+#[test]
+fn test_shield_absorbs_before_damage_lands() {
+    let mut app = create_app();
+    app.init_resource::<HealthChanges>().add_observer(
+        |ev: On<GameplayEffectExecutedEvent>, mut changes: ResMut<HealthChanges>| {
+            changes.0.push(ev.change("Health"));
+        },
+    );
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("hit")
+                .add_modifier(ModifierInfo::new(
+                    "Health",
+                    ModifierOperation::AddBase,
+                    MagnitudeCalculation::scalar(-30.0),
+                ))
+                .with_damage(
+                    DamageCalculation::new()
+                        .with_damage_attribute("Health")
+                        .with_mitigation(SHIELD_MITIGATION),
+                ),
+        );
+    let actor = spawn_actor(&mut app);
+    apply(&mut app, StatusEffect::Shield, actor.owner);
+    app.update();
+
+    // Both hits land in one batch: the shield soaks 50 of the 60 damage and
+    // health only ever takes the remaining 10.
+    for _ in 0..2 {
+        app.world_mut()
+            .resource_mut::<BatchedEvents<ApplyGameplayEffectEvent>>()
+            .push(ApplyGameplayEffectEvent::new("hit", actor.owner));
+    }
+    app.update();
+    assert_eq!(app.world().resource::<HealthChanges>().0, [0.0, -10.0]);
+    assert_eq!(value(&app, actor.health), 90.0);
+    assert_eq!(value(&app, actor.shield), 0.0);
+}
+
+#[test]
+fn test_poison_stacks_and_damages_health() {
+    let mut app = create_app();
+    let actor = spawn_actor(&mut app);
+
+    for _ in 0..3 {
+        apply(&mut app, StatusEffect::Poison, actor.owner);
+    }
+    let mut effects = app.world_mut().query::<&ActiveGameplayEffect>();
+    let poison = effects.single(app.world()).unwrap();
+    assert_eq!(poison.stack_count, 3);
+
+    advance(&mut app, 2.5);
+    assert!(value(&app, actor.health) < 100.0);
+}
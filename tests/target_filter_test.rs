@@ -0,0 +1,65 @@
+//! Tests that an ability definition's target filter is applied when its
+//! ApplyEffectToTargetData tasks run.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::{
+        AbilityDefinition, AbilityRegistry, AbilitySpec, AbilityTask, ApplyEffectToTargetDataTask,
+        Dead, GameplayAbilityTargetData, TargetFilter, TaskState,
+    },
+    core::{OwnedTags, Team},
+    effects::ApplyGameplayEffectEvent,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+
+#[derive(Resource, Default)]
+struct AppliedTargets(Vec<Entity>);
+
+#[test]
+fn test_definition_filter_skips_friendly_and_dead_targets() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.init_resource::<AppliedTargets>();
+    app.add_observer(
+        |ev: On<ApplyGameplayEffectEvent>, mut applied: ResMut<AppliedTargets>| {
+            applied.0.push(ev.spec.target);
+        },
+    );
+    app.update();
+
+    app.world_mut().resource_mut::<AbilityRegistry>().register(
+        AbilityDefinition::new("fireball")
+            .with_target_filter(TargetFilter::new().hostile().alive_only()),
+    );
+
+    let caster = app.world_mut().spawn((Team(1), OwnedTags::default())).id();
+    let ally = app.world_mut().spawn((Team(1), OwnedTags::default())).id();
+    let enemy = app.world_mut().spawn((Team(2), OwnedTags::default())).id();
+    let corpse = app
+        .world_mut()
+        .spawn((Team(2), OwnedTags::default(), Dead))
+        .id();
+    let spec = app.world_mut().spawn(AbilitySpec::new("fireball", 1)).id();
+
+    app.world_mut().spawn((
+        AbilityTask {
+            ability_instance: None,
+            ability_spec: spec,
+            owner: caster,
+        },
+        ApplyEffectToTargetDataTask::new(
+            "burn",
+            GameplayAbilityTargetData::from_actors(vec![ally, enemy, corpse]),
+            1,
+        ),
+        TaskState::Running,
+    ));
+    app.update();
+
+    assert_eq!(app.world().resource::<AppliedTargets>().0, vec![enemy]);
+}
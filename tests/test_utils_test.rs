@@ -0,0 +1,77 @@
+//! Tests for the `GasTestApp` test harness.
+
+#![cfg(feature = "test_utils")]
+
+use bevy_gameplay_ability_system::{
+    abilities::AbilityDefinition,
+    attributes::{AttributeMetadata, AttributeSetDefinition},
+    effects::*,
+    test_utils::GasTestApp,
+};
+
+struct CharacterAttributes;
+
+impl AttributeSetDefinition for CharacterAttributes {
+    fn attribute_names() -> &'static [&'static str] {
+        &["Health", "Armor"]
+    }
+
+    fn attribute_metadata(name: &str) -> Option<AttributeMetadata> {
+        Some(AttributeMetadata::new(name))
+    }
+
+    fn default_value(name: &str) -> f32 {
+        match name {
+            "Health" => 100.0,
+            "Armor" => 10.0,
+            _ => 0.0,
+        }
+    }
+}
+
+#[test]
+fn test_harness_applies_and_expires_effects() {
+    let mut app = GasTestApp::new();
+    app.register_effect(
+        GameplayEffectDefinition::new("fortify")
+            .with_duration_policy(DurationPolicy::HasDuration)
+            .with_duration(1.0)
+            .add_modifier(ModifierInfo::new(
+                "Armor",
+                ModifierOperation::AddCurrent,
+                MagnitudeCalculation::scalar(5.0),
+            )),
+    );
+    let player = app.spawn_character::<CharacterAttributes>();
+
+    app.apply_effect("fortify", player);
+    app.assert_has_effect(player, "fortify");
+    app.assert_attribute(player, "Armor", 15.0);
+    app.assert_base_attribute(player, "Armor", 10.0);
+    app.assert_attribute(player, "Health", 100.0);
+
+    app.advance(1.0);
+    app.assert_no_effect(player, "fortify");
+    app.assert_attribute(player, "Armor", 10.0);
+}
+
+#[test]
+fn test_harness_grants_and_activates_abilities() {
+    let mut app = GasTestApp::new();
+    app.register_ability(AbilityDefinition::new("dash"));
+    let player = app.spawn_with_attributes(&[("Stamina", 50.0)]);
+
+    let dash = app.grant_ability(player, "dash");
+    app.assert_ability_active(dash, false);
+    app.try_activate(dash);
+    app.assert_ability_active(dash, true);
+    app.assert_attribute(player, "Stamina", 50.0);
+}
+
+#[test]
+#[should_panic(expected = "expected Health")]
+fn test_harness_assertions_report_mismatches() {
+    let mut app = GasTestApp::new();
+    let player = app.spawn_with_attributes(&[("Health", 100.0)]);
+    app.assert_attribute(player, "Health", 90.0);
+}
@@ -0,0 +1,130 @@
+//! Tests for threat tracking with `GasThreatPlugin`.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{GasPlugin, attributes::*, effects::*};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+
+#[derive(Resource, Default)]
+struct TargetChanges(Vec<ThreatTargetChangedEvent>);
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+        GasThreatPlugin,
+    ))
+    .insert_resource(ThreatSettings::default().with_taunt_effect("taunt"))
+    .init_resource::<TargetChanges>()
+    .add_observer(
+        |ev: On<ThreatTargetChangedEvent>, mut changes: ResMut<TargetChanges>| {
+            changes.0.push(*ev.event());
+        },
+    );
+    app.update();
+
+    let mut registry = app.world_mut().resource_mut::<GameplayEffectRegistry>();
+    for (id, magnitude) in [("hit", -10.0), ("big_hit", -30.0), ("heal", 40.0)] {
+        registry.register(
+            GameplayEffectDefinition::new(id).add_modifier(ModifierInfo::new(
+                "Health",
+                ModifierOperation::AddBase,
+                MagnitudeCalculation::scalar(magnitude),
+            )),
+        );
+    }
+    registry.register(GameplayEffectDefinition::new("taunt"));
+    app
+}
+
+fn spawn_with_health(app: &mut App) -> Entity {
+    let owner = app.world_mut().spawn_empty().id();
+    app.world_mut().spawn((
+        AttributeName::new("Health"),
+        AttributeData::new(100.0),
+        ChildOf(owner),
+    ));
+    owner
+}
+
+fn apply(app: &mut App, effect_id: &str, source: Entity, target: Entity) {
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new(effect_id, target).with_source(source));
+    app.update();
+}
+
+fn table(app: &App, entity: Entity) -> &ThreatTable {
+    app.world().get::<ThreatTable>(entity).unwrap()
+}
+
+#[test]
+fn test_damage_builds_threat_and_picks_target() {
+    let mut app = create_app();
+    let boss = spawn_with_health(&mut app);
+    app.world_mut()
+        .entity_mut(boss)
+        .insert(ThreatTable::default());
+    let tank = spawn_with_health(&mut app);
+    let rogue = spawn_with_health(&mut app);
+
+    apply(&mut app, "hit", tank, boss);
+    assert_eq!(table(&app, boss).threat(tank), 10.0);
+    assert_eq!(table(&app, boss).target(), Some(tank));
+
+    // 10.5 doesn't beat 10 by the 10% switch threshold.
+    app.world_mut()
+        .get_mut::<ThreatTable>(boss)
+        .unwrap()
+        .add_threat(rogue, 10.5);
+    app.update();
+    assert_eq!(table(&app, boss).target(), Some(tank));
+
+    apply(&mut app, "big_hit", rogue, boss);
+    assert_eq!(table(&app, boss).target(), Some(rogue));
+    let changes = &app.world().resource::<TargetChanges>().0;
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[1].previous, Some(tank));
+    assert_eq!(changes[1].target, Some(rogue));
+}
+
+#[test]
+fn test_healing_adds_threat_on_tables_listing_the_healed() {
+    let mut app = create_app();
+    let boss = spawn_with_health(&mut app);
+    let bystander = spawn_with_health(&mut app);
+    app.world_mut()
+        .entity_mut(boss)
+        .insert(ThreatTable::default());
+    app.world_mut()
+        .entity_mut(bystander)
+        .insert(ThreatTable::default());
+    let tank = spawn_with_health(&mut app);
+    let healer = spawn_with_health(&mut app);
+
+    apply(&mut app, "hit", tank, boss);
+    apply(&mut app, "heal", healer, tank);
+    assert_eq!(table(&app, boss).threat(healer), 20.0);
+    assert!(!table(&app, bystander).contains(healer));
+}
+
+#[test]
+fn test_taunt_takes_target_and_despawned_attackers_drop() {
+    let mut app = create_app();
+    let boss = spawn_with_health(&mut app);
+    app.world_mut()
+        .entity_mut(boss)
+        .insert(ThreatTable::default());
+    let tank = spawn_with_health(&mut app);
+    let rogue = spawn_with_health(&mut app);
+
+    apply(&mut app, "big_hit", rogue, boss);
+    apply(&mut app, "taunt", tank, boss);
+    assert_eq!(table(&app, boss).target(), Some(tank));
+    assert!(table(&app, boss).threat(tank) >= 33.0);
+
+    app.world_mut().despawn(tank);
+    app.update();
+    assert!(!table(&app, boss).contains(tank));
+    assert_eq!(table(&app, boss).target(), Some(rogue));
+}
@@ -0,0 +1,228 @@
+//! Tests that `GasTickMode::Fixed` ticks effects by the fixed timestep rather
+//! than by the frame's delta, and that `GasTickMode::FixedSimulation` runs
+//! the rest of the simulation in `FixedUpdate` too.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    attributes::{AttributeData, AttributeMetadata, AttributeSetDefinition},
+    core::{EffectSystemSet, GasTickMode},
+    effects::*,
+    error::{GasError, GasErrorEvent, GasErrorPolicy},
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use std::time::Duration;
+
+/// Number of `FixedUpdate` runs that ticked an active effect.
+#[derive(Resource, Default)]
+struct FixedSteps(u32);
+
+fn count_fixed_steps(mut steps: ResMut<FixedSteps>, effects: Query<(), With<EffectDuration>>) {
+    if !effects.is_empty() {
+        steps.0 += 1;
+    }
+}
+
+fn create_app(mode: GasTickMode, frame_millis: u64) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin::builder().with_tick_mode(mode),
+    ));
+    app.insert_resource(Time::<Fixed>::from_seconds(0.1))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            frame_millis,
+        )))
+        .init_resource::<FixedSteps>()
+        .add_systems(
+            FixedUpdate,
+            count_fixed_steps.before(EffectSystemSet::UpdateDurations),
+        );
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(GameplayEffectDefinition::new("haste").with_duration(1.0));
+    app
+}
+
+/// Applies the effect and returns its target.
+fn apply_haste(app: &mut App) -> Entity {
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("haste", target));
+    target
+}
+
+fn remaining(app: &mut App) -> Option<f32> {
+    let mut query = app.world_mut().query::<&EffectDuration>();
+    query
+        .iter(app.world())
+        .next()
+        .map(|duration| duration.remaining)
+}
+
+#[test]
+fn test_fixed_mode_ticks_by_fixed_timestep() {
+    let mut app = create_app(GasTickMode::Fixed, 250);
+    assert_eq!(*app.world().resource::<GasTickMode>(), GasTickMode::Fixed);
+    apply_haste(&mut app);
+
+    for _ in 0..3 {
+        app.update();
+        let steps = app.world().resource::<FixedSteps>().0;
+        let remaining = remaining(&mut app).expect("effect should still be active");
+        assert!((remaining - (1.0 - steps as f32 * 0.1)).abs() < 1e-4);
+    }
+
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(remaining(&mut app).is_none());
+}
+
+#[test]
+fn test_fixed_mode_is_independent_of_frame_rate() {
+    let mut results = Vec::new();
+    for frame_millis in [30, 70, 240] {
+        let mut app = create_app(GasTickMode::Fixed, frame_millis);
+        apply_haste(&mut app);
+        while app.world().resource::<FixedSteps>().0 < 4 {
+            app.update();
+        }
+        if app.world().resource::<FixedSteps>().0 == 4 {
+            results.push(remaining(&mut app).unwrap());
+        }
+    }
+    assert!(!results.is_empty());
+    for remaining in results {
+        assert!((remaining - 0.6).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn test_variable_mode_ticks_by_frame_delta() {
+    let mut app = create_app(GasTickMode::Variable, 250);
+    apply_haste(&mut app);
+
+    app.update();
+    assert!((remaining(&mut app).unwrap() - 0.75).abs() < 1e-4);
+}
+
+struct PowerSet;
+
+impl AttributeSetDefinition for PowerSet {
+    fn attribute_names() -> &'static [&'static str] {
+        &["AttackPower"]
+    }
+
+    fn attribute_metadata(_name: &str) -> Option<AttributeMetadata> {
+        None
+    }
+
+    fn default_value(_name: &str) -> f32 {
+        10.0
+    }
+}
+
+#[test]
+fn test_fixed_simulation_applies_modifiers_on_fixed_steps() {
+    let mut app = create_app(GasTickMode::FixedSimulation, 30);
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("rage")
+                .with_duration_policy(DurationPolicy::Infinite)
+                .add_modifier(ModifierInfo::new(
+                    "AttackPower",
+                    ModifierOperation::AddCurrent,
+                    MagnitudeCalculation::scalar(5.0),
+                )),
+        );
+    let target = {
+        let mut commands = app.world_mut().commands();
+        let target = commands.spawn_empty().id();
+        PowerSet::create_attributes(&mut commands, target);
+        target
+    };
+    app.world_mut().flush();
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("rage", target));
+
+    let attack_power = |app: &mut App| {
+        let mut query = app.world_mut().query::<&AttributeData>();
+        query.iter(app.world()).next().unwrap().current_value
+    };
+
+    // Frames of 30ms don't reach the first 100ms fixed step.
+    app.update();
+    app.update();
+    assert_eq!(attack_power(&mut app), 10.0);
+
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(attack_power(&mut app), 15.0);
+}
+
+#[test]
+fn test_in_fixed_update_builds_the_fixed_simulation() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin::builder().in_fixed_update(),
+    ));
+    assert_eq!(
+        *app.world().resource::<GasTickMode>(),
+        GasTickMode::FixedSimulation
+    );
+}
+
+#[test]
+fn test_tick_mode_inserted_after_the_plugin_is_reset_and_reported() {
+    #[derive(Resource, Default)]
+    struct Reported(Vec<GasError>);
+
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(GasErrorPolicy::Event)
+    .init_resource::<Reported>()
+    .add_observer(|ev: On<GasErrorEvent>, mut reported: ResMut<Reported>| {
+        reported.0.push(ev.error.clone());
+    });
+    app.insert_resource(GasTickMode::Fixed);
+    app.update();
+    assert_eq!(
+        *app.world().resource::<GasTickMode>(),
+        GasTickMode::Variable
+    );
+
+    // Replacing it at runtime is caught too
+    app.insert_resource(GasTickMode::FixedSimulation);
+    app.update();
+    app.update();
+    assert_eq!(
+        *app.world().resource::<GasTickMode>(),
+        GasTickMode::Variable
+    );
+    assert_eq!(
+        app.world().resource::<Reported>().0,
+        vec![
+            GasError::TickModeChanged {
+                inserted: GasTickMode::Fixed,
+                built: GasTickMode::Variable,
+            },
+            GasError::TickModeChanged {
+                inserted: GasTickMode::FixedSimulation,
+                built: GasTickMode::Variable,
+            },
+        ]
+    );
+}
@@ -0,0 +1,91 @@
+//! Tests for per-entity and global scaling of GAS timers.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    core::{GasTimeScale, GlobalGasTimeScale, OwnedTags},
+    effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use std::time::Duration;
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )));
+    app.update();
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("slow")
+                .with_duration_policy(DurationPolicy::HasDuration)
+                .with_duration(1.0),
+        );
+    app
+}
+
+fn has_effect(app: &mut App, target: Entity) -> bool {
+    app.world()
+        .get::<ActiveEffects>(target)
+        .is_some_and(|effects| !effects.is_empty())
+}
+
+#[test]
+fn test_effects_tick_at_their_targets_time_scale() {
+    let mut app = create_app();
+    let normal = app.world_mut().spawn(OwnedTags::default()).id();
+    let frozen = app
+        .world_mut()
+        .spawn((OwnedTags::default(), GasTimeScale(0.0)))
+        .id();
+    let slowed = app
+        .world_mut()
+        .spawn((OwnedTags::default(), GasTimeScale(0.5)))
+        .id();
+    for target in [normal, frozen, slowed] {
+        app.world_mut()
+            .trigger(ApplyGameplayEffectEvent::new("slow", target));
+    }
+    app.update();
+
+    for _ in 0..6 {
+        app.update();
+    }
+    assert!(!has_effect(&mut app, normal));
+    assert!(has_effect(&mut app, frozen));
+    assert!(has_effect(&mut app, slowed));
+
+    for _ in 0..4 {
+        app.update();
+    }
+    assert!(!has_effect(&mut app, slowed));
+    assert!(has_effect(&mut app, frozen));
+}
+
+#[test]
+fn test_global_pause_stops_all_timers() {
+    let mut app = create_app();
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("slow", target));
+    app.update();
+
+    app.world_mut().resource_mut::<GlobalGasTimeScale>().paused = true;
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(has_effect(&mut app, target));
+
+    app.world_mut().resource_mut::<GlobalGasTimeScale>().paused = false;
+    for _ in 0..6 {
+        app.update();
+    }
+    assert!(!has_effect(&mut app, target));
+}
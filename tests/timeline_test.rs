@@ -0,0 +1,116 @@
+//! Tests for the GAS event timeline recorder.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    attributes::{AttributeData, AttributeName},
+    core::OwnedTags,
+    debug::{GasTimeline, GasTimelinePlugin, TimelineEventKind},
+    effects::*,
+};
+use bevy_gameplay_tag::GameplayTagsPlugin;
+use std::time::Duration;
+
+fn create_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+        GasTimelinePlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        250,
+    )));
+    app.update();
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(
+            GameplayEffectDefinition::new("fortify")
+                .with_duration_policy(DurationPolicy::HasDuration)
+                .with_duration(0.5)
+                .add_modifier(ModifierInfo::new(
+                    "Armor",
+                    ModifierOperation::AddCurrent,
+                    MagnitudeCalculation::scalar(5.0),
+                )),
+        );
+    app
+}
+
+#[test]
+fn test_timeline_records_effect_lifecycle_per_entity() {
+    let mut app = create_app();
+    let source = app.world_mut().spawn(OwnedTags::default()).id();
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    app.world_mut().spawn((
+        AttributeName::new("Armor"),
+        AttributeData::new(10.0),
+        ChildOf(target),
+    ));
+    app.update();
+
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("fortify", target).with_source(source));
+    for _ in 0..5 {
+        app.update();
+    }
+
+    let timeline = app.world().resource::<GasTimeline>();
+    let kinds: Vec<_> = timeline
+        .for_entity(target)
+        .map(|record| &record.kind)
+        .collect();
+    let applied = kinds
+        .iter()
+        .position(|kind| matches!(kind, TimelineEventKind::EffectApplied { .. }))
+        .expect("effect applied");
+    let removed = kinds
+        .iter()
+        .position(|kind| matches!(kind, TimelineEventKind::EffectRemoved { .. }))
+        .expect("effect removed");
+    assert!(applied < removed);
+    assert!(kinds.iter().any(|kind| matches!(
+        kind,
+        TimelineEventKind::AttributeChanged {
+            old_current: 10.0,
+            new_current: 15.0,
+            ..
+        }
+    )));
+    assert!(
+        timeline
+            .for_entity(source)
+            .any(|record| record.instigator == Some(source))
+    );
+}
+
+#[test]
+fn test_timeline_drops_oldest_records() {
+    let mut app = create_app();
+    app.insert_resource(GasTimeline::with_capacity(2));
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+
+    for _ in 0..3 {
+        app.world_mut()
+            .trigger(ApplyGameplayEffectEvent::new("fortify", target));
+        app.update();
+    }
+
+    assert_eq!(app.world().resource::<GasTimeline>().len(), 2);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_timeline_dumps_to_json() {
+    let mut app = create_app();
+    let target = app.world_mut().spawn(OwnedTags::default()).id();
+    app.world_mut()
+        .trigger(ApplyGameplayEffectEvent::new("fortify", target));
+    app.update();
+
+    let json = app.world().resource::<GasTimeline>().to_json();
+    let records: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(records[0]["kind"]["EffectApplied"]["effect_id"], "fortify");
+}
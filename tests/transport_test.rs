@@ -0,0 +1,212 @@
+//! Tests that activation requests are routed through the ability transport,
+//! validated on the server, and that rejections reach the client.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{
+    GasPlugin,
+    abilities::*,
+    core::{BlockedAbilityTags, OwnedTags},
+    effects::*,
+};
+use bevy_gameplay_tag::{
+    GameplayTagContainer, GameplayTagsManager, GameplayTagsPlugin, gameplay_tag::GameplayTag,
+};
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct Sent {
+    requests: Vec<AbilityActivationRequestEvent>,
+    responses: Vec<(NetClientId, AbilityActivationResponseEvent)>,
+}
+
+/// Transport that records what it is asked to send.
+#[derive(Clone, Default)]
+struct RecordingTransport(Arc<Mutex<Sent>>);
+
+impl AbilityNetTransport for RecordingTransport {
+    fn send_request(&self, _commands: &mut Commands, request: AbilityActivationRequestEvent) {
+        self.0.lock().unwrap().requests.push(request);
+    }
+
+    fn send_response(
+        &self,
+        _commands: &mut Commands,
+        client: NetClientId,
+        response: AbilityActivationResponseEvent,
+    ) {
+        self.0.lock().unwrap().responses.push((client, response));
+    }
+}
+
+#[derive(Resource, Default)]
+struct Failures(Vec<AbilityActivationFailedEvent>);
+
+fn create_app(role: NetRole) -> (App, RecordingTransport) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.update();
+    let transport = RecordingTransport::default();
+    app.insert_resource(role)
+        .insert_resource(AbilityTransport::new(transport.clone()))
+        .init_resource::<Failures>();
+    app.add_observer(
+        |ev: On<AbilityActivationFailedEvent>, mut failures: ResMut<Failures>| {
+            failures.0.push(ev.event().clone());
+        },
+    );
+
+    let tags_manager = app.world().resource::<GameplayTagsManager>();
+    let mut cooldown_tags = GameplayTagContainer::new();
+    cooldown_tags.add_tag(GameplayTag::new("Cooldown.Fireball"), tags_manager);
+    let mut cooldown = GameplayEffectDefinition::new("fireball_cooldown").with_duration(5.0);
+    cooldown.granted_tags = cooldown_tags;
+    app.world_mut()
+        .resource_mut::<GameplayEffectRegistry>()
+        .register(cooldown);
+
+    let mut registry = app.world_mut().resource_mut::<AbilityRegistry>();
+    registry.register(
+        AbilityDefinition::new("fireball")
+            .with_net_execution_policy(NetExecutionPolicy::ServerOnly)
+            .with_cooldown_effect("fireball_cooldown"),
+    );
+    registry.register(
+        AbilityDefinition::new("dash").with_net_execution_policy(NetExecutionPolicy::LocalOnly),
+    );
+    (app, transport)
+}
+
+fn spawn_caster(app: &mut App, ability: &str) -> (Entity, Entity) {
+    let owner = app
+        .world_mut()
+        .spawn((OwnedTags::default(), BlockedAbilityTags::default()))
+        .id();
+    let spec = app
+        .world_mut()
+        .spawn((
+            AbilitySpec::new(ability, 1),
+            AbilityActiveState::default(),
+            AbilityOwner(owner),
+        ))
+        .id();
+    (owner, spec)
+}
+
+fn request(app: &mut App, client: NetClientId, spec: Entity, owner: Entity) {
+    app.world_mut().trigger(ClientActivationRequestEvent {
+        client,
+        request: AbilityActivationRequestEvent {
+            ability_spec: spec,
+            owner,
+            prediction_key: None,
+        },
+    });
+    app.update();
+}
+
+#[test]
+fn test_client_sends_server_only_request_through_transport() {
+    let (mut app, transport) = create_app(NetRole::Client);
+    let (owner, spec) = spawn_caster(&mut app, "fireball");
+
+    app.world_mut()
+        .trigger(TryActivateAbilityEvent::new(spec, owner));
+    app.update();
+
+    let sent = transport.0.lock().unwrap();
+    assert_eq!(sent.requests.len(), 1);
+    assert_eq!(sent.requests[0].ability_spec, spec);
+    assert_eq!(sent.requests[0].prediction_key, None);
+}
+
+#[test]
+fn test_server_accepts_then_rejects_on_cooldown() {
+    let (mut app, transport) = create_app(NetRole::Server);
+    let (owner, spec) = spawn_caster(&mut app, "fireball");
+    let client = NetClientId(3);
+
+    request(&mut app, client, spec, owner);
+    assert!(
+        app.world()
+            .get::<AbilityActiveState>(spec)
+            .unwrap()
+            .is_active
+    );
+    assert!(app.world().get::<RemoteActivationRequest>(spec).is_none());
+
+    app.world_mut().trigger(EndAbilityEvent {
+        instance: None,
+        ability_spec: spec,
+        owner,
+    });
+    request(&mut app, client, spec, owner);
+
+    let sent = transport.0.lock().unwrap();
+    assert_eq!(sent.responses.len(), 2);
+    assert_eq!(sent.responses[0].0, client);
+    assert_eq!(sent.responses[0].1.rejection, None);
+    assert_eq!(sent.responses[1].0, client);
+    assert_eq!(
+        sent.responses[1].1.rejection,
+        Some(ActivationFailureReason::OnCooldown)
+    );
+}
+
+#[test]
+fn test_server_refuses_invalid_requests() {
+    let (mut app, transport) = create_app(NetRole::Server);
+    let (_, spec) = spawn_caster(&mut app, "fireball");
+    let (dash_owner, dash) = spawn_caster(&mut app, "dash");
+    let client = NetClientId(1);
+
+    // Wrong owner, then a LocalOnly ability.
+    request(&mut app, client, spec, dash_owner);
+    request(&mut app, client, dash, dash_owner);
+
+    assert!(
+        !app.world()
+            .get::<AbilityActiveState>(spec)
+            .unwrap()
+            .is_active
+    );
+    assert!(
+        !app.world()
+            .get::<AbilityActiveState>(dash)
+            .unwrap()
+            .is_active
+    );
+    let sent = transport.0.lock().unwrap();
+    let rejections: Vec<_> = sent
+        .responses
+        .iter()
+        .map(|(_, response)| response.rejection)
+        .collect();
+    assert_eq!(
+        rejections,
+        vec![Some(ActivationFailureReason::InvalidRequest); 2]
+    );
+}
+
+#[test]
+fn test_client_surfaces_rejection_as_failed_event() {
+    let (mut app, _transport) = create_app(NetRole::Client);
+    let (owner, spec) = spawn_caster(&mut app, "fireball");
+
+    app.world_mut().trigger(AbilityActivationResponseEvent {
+        ability_spec: spec,
+        owner,
+        prediction_key: None,
+        rejection: Some(ActivationFailureReason::OnCooldown),
+    });
+    app.update();
+
+    let failures = &app.world().resource::<Failures>().0;
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].ability_spec, spec);
+    assert_eq!(failures[0].owner, owner);
+    assert_eq!(failures[0].reason, ActivationFailureReason::OnCooldown);
+}
@@ -0,0 +1,119 @@
+//! Tests the startup validation of definitions registered by the game or
+//! loaded from files.
+
+use bevy::prelude::*;
+use bevy_gameplay_ability_system::{GasPlugin, abilities::*, core::*, cues::*, effects::*};
+use bevy_gameplay_tag::{GameplayTagsPlugin, gameplay_tag::GameplayTag};
+
+fn register_definitions(
+    mut abilities: ResMut<AbilityRegistry>,
+    mut effects: ResMut<GameplayEffectRegistry>,
+) {
+    abilities.register(AbilityDefinition::new("fireball").with_cooldown_effect("missing"));
+    effects.register(
+        GameplayEffectDefinition::new("burning")
+            .with_duration(3.0)
+            .add_gameplay_cue(GameplayEffectCue::new(GameplayTag::new(
+                "GameplayCue.Burning",
+            ))),
+    );
+}
+
+fn create_app(mode: GasValidationMode) -> App {
+    let mut app = App::new();
+    app.insert_resource(mode).add_plugins((
+        MinimalPlugins,
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+    ));
+    app.add_systems(Startup, register_definitions);
+    app
+}
+
+#[test]
+fn test_warn_mode_keeps_report() {
+    let mut app = create_app(GasValidationMode::Warn);
+    app.update();
+
+    let report = app.world().resource::<GasValidationReport>();
+    assert_eq!(
+        report.issues,
+        vec![
+            GasValidationIssue::MissingCooldownEffect {
+                ability_id: "fireball".into(),
+                effect_id: "missing".into(),
+            },
+            GasValidationIssue::UnhandledCue {
+                effect_id: "burning".into(),
+                cue_tag: GameplayTag::new("GameplayCue.Burning"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_registered_cue_handler_resolves() {
+    let mut app = create_app(GasValidationMode::Warn);
+    app.world_mut()
+        .resource_mut::<GameplayCueManager>()
+        .register_static_cue(GameplayTag::new("GameplayCue"));
+    app.update();
+
+    let report = app.world().resource::<GasValidationReport>();
+    assert_eq!(report.issues.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "cooldown effect 'missing' is not registered")]
+fn test_strict_mode_panics() {
+    create_app(GasValidationMode::Strict).update();
+}
+
+#[test]
+fn test_off_mode_skips_validation() {
+    let mut app = create_app(GasValidationMode::Off);
+    app.update();
+    assert!(app.world().get_resource::<GasValidationReport>().is_none());
+}
+
+#[cfg(feature = "attribute_assets")]
+#[test]
+fn test_validation_waits_for_attribute_set_files() {
+    use bevy::asset::AssetPlugin;
+    use bevy_gameplay_ability_system::attributes::AttributeSetHandle;
+
+    let mut app = App::new();
+    app.insert_resource(GasValidationMode::Strict).add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        GameplayTagsPlugin::with_data_path("assets/gameplay_tags.json".to_string()),
+        GasPlugin,
+        bevy_gameplay_ability_system::attributes::AttributeAssetPlugin,
+    ));
+    app.add_systems(
+        Startup,
+        |mut commands: Commands,
+         asset_server: Res<AssetServer>,
+         mut effects: ResMut<GameplayEffectRegistry>| {
+            effects.register(GameplayEffectDefinition::new("weaken").add_modifier(
+                ModifierInfo::new(
+                    "Strength",
+                    ModifierOperation::AddBase,
+                    MagnitudeCalculation::scalar(-2.0),
+                ),
+            ));
+            commands.spawn(AttributeSetHandle(
+                asset_server.load("attributes/character.attributes.ron"),
+            ));
+        },
+    );
+
+    for _ in 0..200 {
+        app.update();
+        if app.world().contains_resource::<GasValidationReport>() {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+    assert!(app.world().resource::<GasValidationReport>().is_ok());
+}